@@ -0,0 +1,187 @@
+//! Python bindings: `import qdum_vault`
+//!
+//! ```python
+//! import asyncio
+//! import qdum_vault
+//!
+//! public_path, private_path = qdum_vault.generate_keypair("keys/", algorithm="ml-dsa-65")
+//! signature = qdum_vault.sign(b"hello", private_path)
+//! assert qdum_vault.verify(b"hello", signature, public_path)
+//!
+//! async def on_progress(step, total, message):
+//!     print(f"[{step}/{total}] {message}")
+//!
+//! receipt = asyncio.run(qdum_vault.unlock("main", progress=on_progress))
+//! ```
+//!
+//! Vaults, keys, networks and the security policy come from the same config
+//! as the CLI. Failures raise `qdum_vault.VaultError(message, exit_code)`,
+//! with the exit code the CLI would have used.
+
+// pyo3 0.22's #[pyfunction] expansion converts PyErr into itself
+#![allow(clippy::useless_conversion)]
+
+use pqcoin::crypto::algorithm::Algorithm;
+use pqcoin::crypto::sphincs::SphincsKeyManager;
+use pqcoin::exit_code::{self, ExitKind};
+use pqcoin::solana::client::{self, ProgressCallback, UnlockOptions, UnlockSigner, VaultClient};
+use pqcoin::solana::receipt::Receipt;
+use pqcoin::vault_manager::{VaultConfig, VaultProfile};
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+create_exception!(qdum_vault, VaultError, PyException, "A vault operation failed; args are (message, exit_code)");
+
+fn vault_error(error: anyhow::Error) -> PyErr {
+    VaultError::new_err((format!("{:#}", error), exit_code::classify(&error).code()))
+}
+
+fn parse_algorithm(name: &str) -> PyResult<Algorithm> {
+    Algorithm::ALL.into_iter().find(|algorithm| algorithm.cli_name() == name).ok_or_else(|| {
+        let names: Vec<&str> = Algorithm::ALL.iter().map(|algorithm| algorithm.cli_name()).collect();
+        PyValueError::new_err(format!("Unknown algorithm '{}' (expected one of: {})", name, names.join(", ")))
+    })
+}
+
+/// generate_keypair(out_dir=None, algorithm="slh-dsa-sha2-128s", overwrite=False) -> (public_key_path, private_key_path)
+///
+/// Writes sphincs_public.key and sphincs_private.key to `out_dir` (the data
+/// directory by default). Keys already there are only replaced with
+/// `overwrite=True`.
+#[pyfunction]
+#[pyo3(signature = (out_dir=None, algorithm=None, overwrite=false))]
+fn generate_keypair(py: Python<'_>, out_dir: Option<String>, algorithm: Option<&str>, overwrite: bool) -> PyResult<(String, String)> {
+    let algorithm = algorithm.map(parse_algorithm).transpose()?.unwrap_or_default();
+    py.allow_threads(|| -> anyhow::Result<(String, String)> {
+        let manager = SphincsKeyManager::new(out_dir)?.with_algorithm(algorithm);
+        let dir = manager.key_dir();
+        let (public_path, private_path) = (dir.join("sphincs_public.key"), dir.join("sphincs_private.key"));
+        if !overwrite && (public_path.exists() || private_path.exists()) {
+            return Err(ExitKind::Usage.error(format!("Keys already exist at {} (pass overwrite=True to replace them)", dir.display())));
+        }
+        manager.generate_and_save_keypair()?;
+        Ok((public_path.display().to_string(), private_path.display().to_string()))
+    })
+    .map_err(vault_error)
+}
+
+/// sign(message, private_key_path=None) -> bytes
+#[pyfunction]
+#[pyo3(signature = (message, private_key_path=None))]
+fn sign<'py>(py: Python<'py>, message: &[u8], private_key_path: Option<String>) -> PyResult<Bound<'py, PyBytes>> {
+    let signature = py
+        .allow_threads(|| -> anyhow::Result<Vec<u8>> {
+            load_config()?.policy.check_signing()?;
+            let manager = SphincsKeyManager::new(None)?;
+            let key = manager.load_private_key(private_key_path)?;
+            manager.sign_message(message, &key)
+        })
+        .map_err(vault_error)?;
+    Ok(PyBytes::new_bound(py, &signature))
+}
+
+/// verify(message, signature, public_key_path=None) -> bool
+#[pyfunction]
+#[pyo3(signature = (message, signature, public_key_path=None))]
+fn verify(py: Python<'_>, message: &[u8], signature: &[u8], public_key_path: Option<String>) -> PyResult<bool> {
+    py.allow_threads(|| -> anyhow::Result<bool> {
+        let key = SphincsKeyManager::new(None)?.load_public_key(public_key_path)?;
+        SphincsKeyManager::verify_signature(message, signature, &key)
+    })
+    .map_err(vault_error)
+}
+
+/// The CLI's config, with its policy applied to every client built after
+/// this, as the CLI does
+fn load_config() -> anyhow::Result<VaultConfig> {
+    let config = VaultConfig::load()?;
+    client::set_default_policy(config.policy.clone());
+    Ok(config)
+}
+
+/// The named vault, or the active one
+fn load_vault(name: Option<&str>) -> anyhow::Result<VaultProfile> {
+    let config = load_config()?;
+    let vault = match name {
+        Some(name) => config.vaults.get(name),
+        None => config.get_active_vault(),
+    };
+    vault.cloned().ok_or_else(|| match name {
+        Some(name) => ExitKind::Usage.error(format!("Vault '{}' does not exist", name)),
+        None => ExitKind::Config.error("No active vault"),
+    })
+}
+
+/// Forward unlock progress to a Python callable. Coroutines it returns are
+/// scheduled on the caller's event loop.
+fn progress_callback(progress: PyObject, event_loop: PyObject) -> ProgressCallback {
+    Box::new(move |step, total, message| {
+        Python::with_gil(|py| {
+            let result = progress.call1(py, (step, total, message)).and_then(|result| {
+                let asyncio = py.import_bound("asyncio")?;
+                if asyncio.call_method1("iscoroutine", (&result,))?.is_truthy()? {
+                    asyncio.call_method1("run_coroutine_threadsafe", (result, &event_loop))?;
+                }
+                Ok(())
+            });
+            // A broken callback must not abort an unlock that is already paying fees
+            if let Err(error) = result {
+                error.print(py);
+            }
+        })
+    })
+}
+
+async fn unlock_vault(vault: VaultProfile, progress: Option<ProgressCallback>, delay_slots: u64) -> anyhow::Result<Receipt> {
+    let manager = SphincsKeyManager::new(None)?;
+    let private_key = manager.load_private_key(Some(vault.sphincs_private_key_path.clone()))?;
+    let public_key = manager.load_public_key(Some(vault.sphincs_public_key_path.clone()))?;
+    let client = VaultClient::builder()
+        .rpc_url(vault.network_rpc_url())
+        .program_id(vault.network_program_id()?)
+        .build()?;
+    let options = UnlockOptions { lock_mode: vault.lock_mode, delay_slots, resume_after: 0 };
+    client
+        .unlock_vault(vault.wallet()?, &vault.solana_keypair_path, UnlockSigner::PrivateKey(&private_key), &public_key, options, progress)
+        .await
+}
+
+/// unlock(vault=None, progress=None, delay_slots=0) -> dict
+///
+/// Coroutine running the full unlock of `vault` (the active vault by
+/// default) and returning its receipt. `progress(step, total, message)` may
+/// be a plain or an async function.
+#[pyfunction]
+#[pyo3(signature = (vault=None, progress=None, delay_slots=0))]
+fn unlock<'py>(
+    py: Python<'py>,
+    vault: Option<String>,
+    progress: Option<PyObject>,
+    delay_slots: u64,
+) -> PyResult<Bound<'py, PyAny>> {
+    let profile = load_vault(vault.as_deref()).map_err(vault_error)?;
+    let event_loop = pyo3_async_runtimes::tokio::get_current_locals(py)?.event_loop(py).unbind();
+    let callback = progress.map(|progress| progress_callback(progress, event_loop));
+
+    pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let receipt = unlock_vault(profile, callback, delay_slots).await.map_err(vault_error)?;
+        let json = serde_json::to_string(&receipt).map_err(|e| vault_error(e.into()))?;
+        Python::with_gil(|py| Ok(py.import_bound("json")?.call_method1("loads", (json,))?.unbind()))
+    })
+}
+
+#[pymodule]
+fn qdum_vault(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    // Progress goes to the callback rather than terminal progress bars
+    pqcoin::output::set_quiet(true);
+
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add("VaultError", m.py().get_type_bound::<VaultError>())?;
+    m.add_function(wrap_pyfunction!(generate_keypair, m)?)?;
+    m.add_function(wrap_pyfunction!(sign, m)?)?;
+    m.add_function(wrap_pyfunction!(verify, m)?)?;
+    m.add_function(wrap_pyfunction!(unlock, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//! The application log
+//!
+//! One line per entry in `<data dir>/qdum.log`:
+//!
+//! ```text
+//! 2026-03-01T12:00:00Z INFO  bridge: Wrapping 5 QDUM → pqQDUM...
+//! ```
+//!
+//! The dashboard can't print while it owns the terminal, so anything worth
+//! keeping (key events, bridge steps, network queries) goes here and is read
+//! back by the dashboard's log viewer. Writing never fails the caller; a log
+//! that can't be opened is silently skipped. The file is rotated to
+//! `qdum.log.1` once it passes `MAX_LOG_BYTES`.
+
+use chrono::{SecondsFormat, Utc};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::paths;
+
+const LOG_FILE: &str = "qdum.log";
+
+const MAX_LOG_BYTES: u64 = 5 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Level {
+    fn parse(level: &str) -> Option<Self> {
+        match level {
+            "DEBUG" => Some(Level::Debug),
+            "INFO" => Some(Level::Info),
+            "WARN" => Some(Level::Warn),
+            "ERROR" => Some(Level::Error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        })
+    }
+}
+
+/// Where the log is written
+pub fn path() -> PathBuf {
+    paths::data_dir().join(LOG_FILE)
+}
+
+/// Append `message` under `target` (the part of the app it's from). Each
+/// line of a multi-line message gets its own prefix, so filtering by level
+/// or target never splits an entry.
+pub fn write(level: Level, target: &str, message: impl fmt::Display) {
+    let path = path();
+    if fs::metadata(&path).is_ok_and(|meta| meta.len() > MAX_LOG_BYTES) {
+        let _ = fs::rename(&path, path.with_extension("log.1"));
+    }
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&path) else {
+        return;
+    };
+
+    let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+    let mut entry = String::new();
+    for line in message.to_string().lines() {
+        entry.push_str(&format!("{} {:<5} {}: {}\n", timestamp, level, target, line));
+    }
+    let _ = file.write_all(entry.as_bytes());
+}
+
+/// A line read back from the log
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine<'a> {
+    pub timestamp: &'a str,
+    pub level: Level,
+    pub target: &'a str,
+    pub message: &'a str,
+}
+
+impl<'a> LogLine<'a> {
+    /// None for lines not written by `write`
+    pub fn parse(line: &'a str) -> Option<Self> {
+        let (timestamp, rest) = line.split_once(' ')?;
+        let (level, rest) = rest.trim_start().split_once(' ')?;
+        let (target, message) = rest.trim_start().split_once(": ")?;
+        Some(LogLine { timestamp, level: Level::parse(level)?, target, message })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log_line() {
+        let line = format!("2026-03-01T12:00:00Z {:<5} bridge: Bridge PDA: abc", Level::Info);
+        assert_eq!(
+            LogLine::parse(&line),
+            Some(LogLine { timestamp: "2026-03-01T12:00:00Z", level: Level::Info, target: "bridge", message: "Bridge PDA: abc" })
+        );
+        assert_eq!(LogLine::parse("2026-03-01T12:00:00Z ERROR network: x").unwrap().level, Level::Error);
+        assert_eq!(LogLine::parse("free-form text"), None);
+    }
+}
@@ -0,0 +1,307 @@
+//! Tamper-evident record of signing operations
+//!
+//! One JSON object per line in `<data dir>/audit/audit.log`, for every
+//! confirmed transaction and every signature made with a vault key:
+//!
+//! ```text
+//! {"seq":3,"timestamp":"2026-03-01T12:00:00Z","command":"transfer --to 9xQ... --amount 5","operation":"transfer",...,"prev_hash":"9f2c...","hash":"4e1a..."}
+//! ```
+//!
+//! Each entry's `hash` covers the entry and the previous entry's hash, so
+//! editing, removing or reordering a line breaks the chain from there on.
+//! `audit/head` holds the sequence number and hash of the newest entry, which
+//! catches lines cut off the end. Someone who can write the directory can
+//! still rebuild the whole chain; the log makes tampering evident, it doesn't
+//! prevent it. Like the application log, recording never fails the caller.
+
+use anyhow::{bail, Context, Result};
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::path::PathBuf;
+
+use crate::applog::{self, Level};
+use crate::paths;
+
+const AUDIT_DIR: &str = "audit";
+const LOG_FILE: &str = "audit.log";
+const HEAD_FILE: &str = "head";
+
+/// What one signing operation did
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    /// e.g. "transfer", "unlock", "sign_challenge"
+    pub operation: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wallet: Option<String>,
+    /// Token amount in base units
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    /// Transaction signatures, or the SHA-256 of an off-chain signature
+    #[serde(default)]
+    pub signatures: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub details: BTreeMap<String, String>,
+}
+
+impl Event {
+    pub fn new(operation: &str) -> Self {
+        Event { operation: operation.to_string(), ..Default::default() }
+    }
+
+    pub fn wallet(mut self, wallet: impl ToString) -> Self {
+        self.wallet = Some(wallet.to_string());
+        self
+    }
+
+    pub fn signature(mut self, signature: impl ToString) -> Self {
+        self.signatures.push(signature.to_string());
+        self
+    }
+
+    pub fn detail(mut self, key: &str, value: impl ToString) -> Self {
+        self.details.insert(key.to_string(), value.to_string());
+        self
+    }
+}
+
+/// A line of the audit log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// Position in the log, from 0
+    pub seq: u64,
+    pub timestamp: String,
+    /// Arguments of the process that signed
+    pub command: String,
+    #[serde(flatten)]
+    pub event: Event,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+impl Entry {
+    /// SHA-256 of the entry with `hash` left empty
+    fn compute_hash(&self) -> String {
+        let unhashed = Entry { hash: String::new(), ..self.clone() };
+        let json = serde_json::to_string(&unhashed).unwrap_or_default();
+        hex::encode(Sha256::digest(json.as_bytes()))
+    }
+}
+
+/// `prev_hash` of the first entry
+fn genesis_hash() -> String {
+    "0".repeat(64)
+}
+
+/// Record `event` in the audit log under the data directory
+pub fn record(event: Event) {
+    let operation = event.operation.clone();
+    if let Err(e) = AuditLog::default().append(event) {
+        applog::write(Level::Warn, "audit", format!("Failed to record {} in the audit log: {:#}", operation, e));
+    }
+}
+
+/// Where the audit log is kept
+pub fn default_dir() -> PathBuf {
+    paths::data_dir().join(AUDIT_DIR)
+}
+
+/// An audit log directory
+pub struct AuditLog {
+    dir: PathBuf,
+}
+
+impl Default for AuditLog {
+    fn default() -> Self {
+        AuditLog::new(default_dir())
+    }
+}
+
+impl AuditLog {
+    pub fn new(dir: PathBuf) -> Self {
+        AuditLog { dir }
+    }
+
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(LOG_FILE)
+    }
+
+    fn head_path(&self) -> PathBuf {
+        self.dir.join(HEAD_FILE)
+    }
+
+    /// Chain `event` onto the newest entry and move the head marker to it
+    pub fn append(&self, event: Event) -> Result<Entry> {
+        fs::create_dir_all(&self.dir).with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let path = self.path();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        // Held until `file` is dropped, so concurrent processes don't fork the chain
+        lock(&file)?;
+
+        // A damaged last line is chained on as-is, so `verify` still reports it
+        let (mut seq, mut prev_hash) = (0, genesis_hash());
+        for line in BufReader::new(&file).lines() {
+            let line = line?;
+            prev_hash = match serde_json::from_str::<Entry>(&line) {
+                Ok(entry) => entry.hash,
+                Err(_) => hex::encode(Sha256::digest(line.as_bytes())),
+            };
+            seq += 1;
+        }
+
+        let mut entry = Entry {
+            seq,
+            timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            command: std::env::args().skip(1).collect::<Vec<_>>().join(" "),
+            event,
+            prev_hash,
+            hash: String::new(),
+        };
+        entry.hash = entry.compute_hash();
+
+        let mut line = serde_json::to_string(&entry).context("Failed to serialize audit entry")?;
+        line.push('\n');
+        file.write_all(line.as_bytes()).context("Failed to write audit entry")?;
+        file.sync_data()?;
+        fs::write(self.head_path(), format!("{} {}\n", entry.seq, entry.hash)).context("Failed to update audit head")?;
+        Ok(entry)
+    }
+
+    /// Check every link of the chain and the head marker, returning the
+    /// entries. The error names the first line that doesn't check out.
+    pub fn verify(&self) -> Result<Vec<Entry>> {
+        let entries = match fs::read_to_string(self.path()) {
+            Ok(contents) => check_chain(&contents)?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", self.path().display())),
+        };
+
+        let head = match fs::read_to_string(self.head_path()) {
+            Ok(head) => Some(parse_head(&head)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => None,
+            Err(e) => return Err(e).context("Failed to read audit head"),
+        };
+        match (entries.last(), head) {
+            (None, None) => {}
+            (Some(_), None) => bail!("Head marker is missing; the log can't be checked for truncation"),
+            (None, Some((seq, _))) => bail!("Log is empty but the head marker records entry #{}: entries were removed", seq),
+            (Some(last), Some((seq, hash))) => {
+                if seq > last.seq {
+                    bail!("Log ends at entry #{} but the head marker records #{}: entries were cut off the end", last.seq, seq);
+                }
+                if seq != last.seq || hash != last.hash {
+                    bail!("Newest entry #{} doesn't match the head marker (#{} {})", last.seq, seq, hash);
+                }
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// Parse the log and check each entry's hash, position and link to the one before
+fn check_chain(contents: &str) -> Result<Vec<Entry>> {
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut prev_hash = genesis_hash();
+    for (index, line) in contents.lines().enumerate() {
+        let number = index + 1;
+        let entry: Entry = serde_json::from_str(line).with_context(|| format!("Line {} is not an audit entry", number))?;
+        if entry.compute_hash() != entry.hash {
+            bail!("Line {}: contents don't match the entry's hash (entry was modified)", number);
+        }
+        if entry.seq != index as u64 {
+            bail!("Line {}: expected entry #{}, found #{} (entries were removed or reordered)", number, index, entry.seq);
+        }
+        if entry.prev_hash != prev_hash {
+            bail!("Line {}: doesn't chain onto the entry before it (an earlier entry was modified)", number);
+        }
+        prev_hash = entry.hash.clone();
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// `<seq> <hash>`
+fn parse_head(head: &str) -> Result<(u64, String)> {
+    let (seq, hash) = head.trim().split_once(' ').context("Audit head marker is malformed")?;
+    Ok((seq.parse().context("Audit head marker is malformed")?, hash.to_string()))
+}
+
+#[cfg(unix)]
+fn lock(file: &File) -> Result<()> {
+    use std::os::unix::io::AsRawFd;
+    if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) } != 0 {
+        return Err(std::io::Error::last_os_error()).context("Failed to lock the audit log");
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn lock(_file: &File) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch(name: &str) -> AuditLog {
+        let dir = std::env::temp_dir().join(format!("qdum-audit-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        AuditLog::new(dir)
+    }
+
+    fn fill(log: &AuditLog) {
+        for amount in [1, 2, 3] {
+            log.append(Event { amount: Some(amount), ..Event::new("transfer") }.signature("sig")).unwrap();
+        }
+    }
+
+    fn cleanup(log: &AuditLog) {
+        let _ = fs::remove_dir_all(&log.dir);
+    }
+
+    #[test]
+    fn test_chain_verifies() {
+        let log = scratch("intact");
+        assert!(log.verify().unwrap().is_empty());
+        fill(&log);
+        let entries = log.verify().unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].prev_hash, genesis_hash());
+        assert_eq!(entries[2].prev_hash, entries[1].hash);
+        cleanup(&log);
+    }
+
+    #[test]
+    fn test_modification_is_detected() {
+        let log = scratch("modified");
+        fill(&log);
+        let contents = fs::read_to_string(log.path()).unwrap();
+        fs::write(log.path(), contents.replacen("\"amount\":2", "\"amount\":200", 1)).unwrap();
+        assert!(log.verify().unwrap_err().to_string().starts_with("Line 2"));
+        cleanup(&log);
+    }
+
+    #[test]
+    fn test_removal_and_truncation_are_detected() {
+        let log = scratch("truncated");
+        fill(&log);
+        let contents = fs::read_to_string(log.path()).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+
+        fs::write(log.path(), format!("{}\n{}\n", lines[0], lines[2])).unwrap();
+        assert!(log.verify().unwrap_err().to_string().starts_with("Line 2"));
+
+        fs::write(log.path(), format!("{}\n{}\n", lines[0], lines[1])).unwrap();
+        assert!(log.verify().unwrap_err().to_string().contains("cut off the end"));
+        cleanup(&log);
+    }
+}
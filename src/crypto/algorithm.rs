@@ -0,0 +1,117 @@
+use clap::builder::PossibleValue;
+use std::fmt;
+
+use super::mldsa::MlDsaParams;
+use super::sphincs::SphincsParams;
+
+/// Post-quantum signature algorithm a vault key uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    SlhDsa(SphincsParams),
+    MlDsa(MlDsaParams),
+}
+
+impl Default for Algorithm {
+    fn default() -> Self {
+        Algorithm::SlhDsa(SphincsParams::default())
+    }
+}
+
+impl Algorithm {
+    pub const ALL: [Algorithm; 9] = [
+        Algorithm::SlhDsa(SphincsParams::Sha2_128s),
+        Algorithm::SlhDsa(SphincsParams::Sha2_128f),
+        Algorithm::SlhDsa(SphincsParams::Sha2_192s),
+        Algorithm::SlhDsa(SphincsParams::Sha2_192f),
+        Algorithm::SlhDsa(SphincsParams::Sha2_256s),
+        Algorithm::SlhDsa(SphincsParams::Sha2_256f),
+        Algorithm::MlDsa(MlDsaParams::MlDsa44),
+        Algorithm::MlDsa(MlDsaParams::MlDsa65),
+        Algorithm::MlDsa(MlDsaParams::MlDsa87),
+    ];
+
+    /// Human-readable name, e.g. "SPHINCS+-SHA2-128s" or "ML-DSA-65"
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::SlhDsa(p) => p.name(),
+            Algorithm::MlDsa(p) => p.name(),
+        }
+    }
+
+    /// Name accepted by `--algorithm`
+    pub fn cli_name(&self) -> &'static str {
+        match self {
+            Algorithm::SlhDsa(SphincsParams::Sha2_128s) => "slh-dsa-sha2-128s",
+            Algorithm::SlhDsa(SphincsParams::Sha2_128f) => "slh-dsa-sha2-128f",
+            Algorithm::SlhDsa(SphincsParams::Sha2_192s) => "slh-dsa-sha2-192s",
+            Algorithm::SlhDsa(SphincsParams::Sha2_192f) => "slh-dsa-sha2-192f",
+            Algorithm::SlhDsa(SphincsParams::Sha2_256s) => "slh-dsa-sha2-256s",
+            Algorithm::SlhDsa(SphincsParams::Sha2_256f) => "slh-dsa-sha2-256f",
+            Algorithm::MlDsa(MlDsaParams::MlDsa44) => "ml-dsa-44",
+            Algorithm::MlDsa(MlDsaParams::MlDsa65) => "ml-dsa-65",
+            Algorithm::MlDsa(MlDsaParams::MlDsa87) => "ml-dsa-87",
+        }
+    }
+
+    /// Algorithm byte stored in the PQ account and key file header
+    pub fn algorithm_id(&self) -> u8 {
+        match self {
+            Algorithm::SlhDsa(p) => p.algorithm_id(),
+            Algorithm::MlDsa(p) => p.algorithm_id(),
+        }
+    }
+
+    pub fn from_algorithm_id(id: u8) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.algorithm_id() == id)
+    }
+
+    pub fn public_key_size(&self) -> usize {
+        match self {
+            Algorithm::SlhDsa(p) => p.public_key_size(),
+            Algorithm::MlDsa(p) => p.public_key_size(),
+        }
+    }
+
+    pub fn private_key_size(&self) -> usize {
+        match self {
+            Algorithm::SlhDsa(p) => p.private_key_size(),
+            Algorithm::MlDsa(p) => p.private_key_size(),
+        }
+    }
+
+    pub fn signature_size(&self) -> usize {
+        match self {
+            Algorithm::SlhDsa(p) => p.signature_size(),
+            Algorithm::MlDsa(p) => p.signature_size(),
+        }
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl clap::ValueEnum for Algorithm {
+    fn value_variants<'a>() -> &'a [Self] {
+        &Self::ALL
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(PossibleValue::new(self.cli_name()))
+    }
+}
+
+/// A post-quantum public or private key tagged with its algorithm
+#[derive(Clone)]
+pub struct PqKey {
+    pub algorithm: Algorithm,
+    pub bytes: Vec<u8>,
+}
+
+impl AsRef<[u8]> for PqKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
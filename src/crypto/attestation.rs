@@ -0,0 +1,78 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+
+use super::algorithm::{Algorithm, PqKey};
+
+/// Detached signature file written by `sign` and read by `verify`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetachedSignature {
+    /// Algorithm name as accepted by `--algorithm`
+    pub algorithm: String,
+    /// SHA-256 of the signer's public key (hex), to tell which key signed
+    pub public_key_sha256: String,
+    /// SHA-256 of the signed message (hex)
+    pub message_sha256: String,
+    /// Raw signature bytes (hex)
+    pub signature: String,
+    pub created_at: String,
+}
+
+impl DetachedSignature {
+    pub fn new(public_key: &PqKey, message: &[u8], signature: &[u8]) -> Self {
+        Self {
+            algorithm: public_key.algorithm.cli_name().to_string(),
+            public_key_sha256: sha256_hex(&public_key.bytes),
+            message_sha256: sha256_hex(message),
+            signature: hex::encode(signature),
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    pub fn algorithm(&self) -> Result<Algorithm> {
+        Algorithm::ALL
+            .into_iter()
+            .find(|a| a.cli_name() == self.algorithm)
+            .ok_or_else(|| anyhow::anyhow!("Unknown signature algorithm '{}'", self.algorithm))
+    }
+
+    pub fn signature_bytes(&self) -> Result<Vec<u8>> {
+        hex::decode(&self.signature).context("Signature is not valid hex")
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let data = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read signature file: {}", path.display()))?;
+        serde_json::from_str(&data)
+            .with_context(|| format!("Invalid signature file: {}", path.display()))
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+pub fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detached_signature_roundtrip() {
+        let key = PqKey {
+            algorithm: Algorithm::default(),
+            bytes: vec![7u8; 32],
+        };
+        let sig = DetachedSignature::new(&key, b"hello", &[1, 2, 3]);
+        let parsed: DetachedSignature = serde_json::from_str(&sig.to_json().unwrap()).unwrap();
+
+        assert_eq!(parsed.algorithm().unwrap(), Algorithm::default());
+        assert_eq!(parsed.signature_bytes().unwrap(), vec![1, 2, 3]);
+        assert_eq!(parsed.message_sha256, sha256_hex(b"hello"));
+    }
+}
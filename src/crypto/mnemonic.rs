@@ -0,0 +1,66 @@
+use anyhow::{Context, Result};
+use bip39::{Language, Mnemonic};
+use sha2::{Digest, Sha256};
+
+/// Number of words in a SPHINCS+ backup mnemonic (256 bits of entropy)
+pub const MNEMONIC_WORD_COUNT: usize = 24;
+
+/// Domain separator so the same mnemonic never yields the same seed as a wallet
+const SPHINCS_SEED_DOMAIN: &[u8] = b"qdum-vault/sphincs-seed/v1";
+
+/// Generate a fresh 24-word BIP39 mnemonic
+pub fn generate_mnemonic() -> Result<Mnemonic> {
+    Mnemonic::generate_in(Language::English, MNEMONIC_WORD_COUNT)
+        .map_err(|e| anyhow::anyhow!("Failed to generate mnemonic: {}", e))
+}
+
+/// Parse and checksum-validate a mnemonic phrase
+pub fn parse_mnemonic(phrase: &str) -> Result<Mnemonic> {
+    let normalized = phrase.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+
+    let mnemonic = Mnemonic::parse_in(Language::English, &normalized)
+        .context("Invalid mnemonic phrase")?;
+
+    if mnemonic.word_count() != MNEMONIC_WORD_COUNT {
+        anyhow::bail!(
+            "Expected a {}-word mnemonic, got {} words",
+            MNEMONIC_WORD_COUNT,
+            mnemonic.word_count()
+        );
+    }
+
+    Ok(mnemonic)
+}
+
+/// Derive the 32-byte SPHINCS+ key generation seed from a mnemonic
+pub fn sphincs_seed_from_mnemonic(mnemonic: &Mnemonic) -> [u8; 32] {
+    let bip39_seed = mnemonic.to_seed("");
+
+    let mut hasher = Sha256::new();
+    hasher.update(SPHINCS_SEED_DOMAIN);
+    hasher.update(bip39_seed);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon abandon abandon art";
+
+    #[test]
+    fn test_seed_is_deterministic() {
+        let a = parse_mnemonic(PHRASE).unwrap();
+        let b = parse_mnemonic(&PHRASE.to_uppercase()).unwrap();
+        assert_eq!(sphincs_seed_from_mnemonic(&a), sphincs_seed_from_mnemonic(&b));
+    }
+
+    #[test]
+    fn test_rejects_short_mnemonic() {
+        let twelve = "abandon abandon abandon abandon abandon abandon \
+            abandon abandon abandon abandon abandon about";
+        assert!(parse_mnemonic(twelve).is_err());
+    }
+}
@@ -1 +1,6 @@
 pub mod sphincs;
+pub mod mnemonic;
+pub mod mldsa;
+pub mod algorithm;
+pub mod attestation;
+pub mod seed;
@@ -1,74 +1,231 @@
 use anyhow::{Context, Result};
 use colored::Colorize;
-use fips205::slh_dsa_sha2_128s;
-use fips205::traits::{SerDes, Signer, Verifier};
+use fips205::traits::{KeyGen, SerDes, Signer, Verifier};
+use hkdf::Hkdf;
 use sha2::{Digest, Sha256};
+use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-/// SPHINCS+ key sizes
-pub const SPHINCS_PUBKEY_SIZE: usize = 32;
-pub const SPHINCS_PRIVKEY_SIZE: usize = 64;
-pub const SPHINCS_SIGNATURE_SIZE: usize = 7856;
+use super::algorithm::{Algorithm, PqKey};
+use super::mldsa;
+use crate::paths;
 
+/// Key file header: magic + format version + algorithm byte
+/// Files without a header are legacy raw SPHINCS+-SHA2-128s keys
+const KEY_FILE_MAGIC: &[u8; 4] = b"QPQK";
+const KEY_FILE_VERSION: u8 = 1;
+const KEY_FILE_HEADER_SIZE: usize = 6;
+
+/// HKDF labels for the SLH-DSA seeds (SK.seed, SK.prf, PK.seed) derived from
+/// a 32-byte recovery seed
+const SK_SEED_LABEL: &[u8] = b"qdum-vault/slh-dsa/sk-seed/v1";
+const SK_PRF_LABEL: &[u8] = b"qdum-vault/slh-dsa/sk-prf/v1";
+const PK_SEED_LABEL: &[u8] = b"qdum-vault/slh-dsa/pk-seed/v1";
+
+/// SLH-DSA (FIPS 205) parameter sets supported by the vault program
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SphincsParams {
+    #[default]
+    Sha2_128s,
+    Sha2_128f,
+    Sha2_192s,
+    Sha2_192f,
+    Sha2_256s,
+    Sha2_256f,
+}
+
+/// Run `$body` with `$m` bound to the fips205 module for a parameter set
+macro_rules! with_params {
+    ($params:expr, $m:ident => $body:expr) => {
+        match $params {
+            SphincsParams::Sha2_128s => { use fips205::slh_dsa_sha2_128s as $m; $body }
+            SphincsParams::Sha2_128f => { use fips205::slh_dsa_sha2_128f as $m; $body }
+            SphincsParams::Sha2_192s => { use fips205::slh_dsa_sha2_192s as $m; $body }
+            SphincsParams::Sha2_192f => { use fips205::slh_dsa_sha2_192f as $m; $body }
+            SphincsParams::Sha2_256s => { use fips205::slh_dsa_sha2_256s as $m; $body }
+            SphincsParams::Sha2_256f => { use fips205::slh_dsa_sha2_256f as $m; $body }
+        }
+    };
+}
+
+impl SphincsParams {
+    /// Human-readable name, e.g. "SPHINCS+-SHA2-128s"
+    pub fn name(&self) -> &'static str {
+        match self {
+            SphincsParams::Sha2_128s => "SPHINCS+-SHA2-128s",
+            SphincsParams::Sha2_128f => "SPHINCS+-SHA2-128f",
+            SphincsParams::Sha2_192s => "SPHINCS+-SHA2-192s",
+            SphincsParams::Sha2_192f => "SPHINCS+-SHA2-192f",
+            SphincsParams::Sha2_256s => "SPHINCS+-SHA2-256s",
+            SphincsParams::Sha2_256f => "SPHINCS+-SHA2-256f",
+        }
+    }
+
+    /// Algorithm byte stored in the PQ account and key file header
+    pub fn algorithm_id(&self) -> u8 {
+        match self {
+            SphincsParams::Sha2_128s => 2,
+            SphincsParams::Sha2_128f => 3,
+            SphincsParams::Sha2_192s => 4,
+            SphincsParams::Sha2_192f => 5,
+            SphincsParams::Sha2_256s => 6,
+            SphincsParams::Sha2_256f => 7,
+        }
+    }
+
+    pub fn public_key_size(&self) -> usize {
+        with_params!(self, m => m::PK_LEN)
+    }
+
+    pub fn private_key_size(&self) -> usize {
+        with_params!(self, m => m::SK_LEN)
+    }
+
+    pub fn signature_size(&self) -> usize {
+        with_params!(self, m => m::SIG_LEN)
+    }
+
+    /// Number of hypertree layers (d), one on-chain verification round each
+    pub fn layers(&self) -> usize {
+        match self {
+            SphincsParams::Sha2_128s | SphincsParams::Sha2_192s => 7,
+            SphincsParams::Sha2_256s => 8,
+            SphincsParams::Sha2_256f => 17,
+            SphincsParams::Sha2_128f | SphincsParams::Sha2_192f => 22,
+        }
+    }
+
+    /// Number of FORS trees (k)
+    pub fn fors_trees(&self) -> usize {
+        match self {
+            SphincsParams::Sha2_128s => 14,
+            SphincsParams::Sha2_192s => 17,
+            SphincsParams::Sha2_256s => 22,
+            SphincsParams::Sha2_128f | SphincsParams::Sha2_192f => 33,
+            SphincsParams::Sha2_256f => 35,
+        }
+    }
+}
+
+impl fmt::Display for SphincsParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An `N`-byte key generation seed expanded from `seed` under `label`
+fn expand_seed<const N: usize>(seed: &[u8; 32], label: &[u8]) -> [u8; N] {
+    let mut out = [0u8; N];
+    Hkdf::<Sha256>::new(None, seed)
+        .expand(label, &mut out)
+        .expect("SLH-DSA seeds are at most 32 bytes");
+    out
+}
+
+/// Generates, stores and uses post-quantum vault keys (SPHINCS+ by default, or ML-DSA)
 pub struct SphincsKeyManager {
     key_dir: PathBuf,
+    algorithm: Algorithm,
 }
 
 impl SphincsKeyManager {
     /// Create a new key manager with the specified directory
-    /// Defaults to ~/.qdum/ if no directory is specified
+    /// Defaults to the data directory (see `paths`) if no directory is specified
     pub fn new(output_dir: Option<String>) -> Result<Self> {
-        let key_dir = if let Some(dir) = output_dir {
-            PathBuf::from(dir)
-        } else {
-            let home = dirs::home_dir().context("Failed to get home directory")?;
-            home.join(".qdum")
+        let key_dir = match output_dir {
+            Some(dir) => PathBuf::from(dir),
+            None => paths::data_dir(),
         };
 
-        Ok(Self { key_dir })
+        Ok(Self { key_dir, algorithm: Algorithm::default() })
     }
 
-    /// Generate a new SPHINCS+ keypair and save it to disk
+    /// Use the given algorithm when generating keys
+    pub fn with_algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Generate a new keypair and save it to disk
     pub fn generate_and_save_keypair(&self) -> Result<()> {
-        println!("Generating SPHINCS+-SHA2-128s keypair...");
+        println!("Generating {} keypair...", self.algorithm);
+        println!();
+
+        // Generate keypair
+        let (pk, sk) = match self.algorithm {
+            Algorithm::SlhDsa(params) => with_params!(params, m => {
+                let (pk, sk) = m::try_keygen()
+                    .map_err(|e| anyhow::anyhow!("Key generation failed: {:?}", e))?;
+                (pk.into_bytes().to_vec(), sk.into_bytes().to_vec())
+            }),
+            Algorithm::MlDsa(params) => mldsa::generate_keypair(params)?,
+        };
+
+        self.save_keypair(&pk, &sk)
+    }
+
+    /// Deterministically generate a keypair from a 32-byte seed and save it to disk
+    /// The same seed always produces the same keypair (used for mnemonic backup/restore)
+    pub fn generate_and_save_keypair_from_seed(&self, seed: &[u8; 32]) -> Result<()> {
+        println!("Deriving {} keypair from seed...", self.algorithm);
         println!();
 
+        let (pk, sk) = self.keypair_from_seed(seed)?;
+        self.save_keypair(&pk, &sk)
+    }
+
+    /// Public key the seed derives, without writing anything, e.g. to check
+    /// a recovery phrase against existing keys
+    pub fn public_key_from_seed(&self, seed: &[u8; 32]) -> Result<Vec<u8>> {
+        Ok(self.keypair_from_seed(seed)?.0)
+    }
+
+    fn keypair_from_seed(&self, seed: &[u8; 32]) -> Result<(Vec<u8>, Vec<u8>)> {
+        match self.algorithm {
+            // FIPS 205 key generation from explicit seeds, so the result
+            // doesn't depend on how the crate draws from an RNG
+            Algorithm::SlhDsa(params) => with_params!(params, m => {
+                let (pk, sk) = m::KG::keygen_with_seeds(
+                    &expand_seed::<{ m::N }>(seed, SK_SEED_LABEL),
+                    &expand_seed::<{ m::N }>(seed, SK_PRF_LABEL),
+                    &expand_seed::<{ m::N }>(seed, PK_SEED_LABEL),
+                );
+                Ok((pk.into_bytes().to_vec(), sk.into_bytes().to_vec()))
+            }),
+            Algorithm::MlDsa(params) => mldsa::generate_keypair_from_seed(params, seed),
+        }
+    }
+
+    /// Write a keypair to the key directory and print a summary
+    fn save_keypair(&self, public_key: &[u8], secret_key: &[u8]) -> Result<()> {
         // Create key directory if it doesn't exist
         fs::create_dir_all(&self.key_dir)
             .context("Failed to create key directory")?;
 
-        // Generate keypair
-        let (pk, sk) = slh_dsa_sha2_128s::try_keygen()
-            .map_err(|e| anyhow::anyhow!("Key generation failed: {:?}", e))?;
-
-        // Convert to bytes
-        let public_key = pk.into_bytes();
-        let secret_key = sk.into_bytes();
-
         // Save private key
         let privkey_path = self.key_dir.join("sphincs_private.key");
-        fs::write(&privkey_path, &secret_key)
+        fs::write(&privkey_path, self.encode_key_file(secret_key))
             .context("Failed to write private key")?;
 
         println!("{}", "✅ Private Key Generated".green().bold());
         println!("   Location: {}", privkey_path.display());
-        println!("   Size: {} bytes", SPHINCS_PRIVKEY_SIZE);
+        println!("   Size: {} bytes", secret_key.len());
         println!();
 
         // Save public key
         let pubkey_path = self.key_dir.join("sphincs_public.key");
-        fs::write(&pubkey_path, &public_key)
+        fs::write(&pubkey_path, self.encode_key_file(public_key))
             .context("Failed to write public key")?;
 
         println!("{}", "✅ Public Key Generated".green().bold());
         println!("   Location: {}", pubkey_path.display());
-        println!("   Size: {} bytes", SPHINCS_PUBKEY_SIZE);
+        println!("   Size: {} bytes", public_key.len());
         println!();
 
         // Display public key in hex
         println!("{}", "📋 Public Key (hex):".cyan().bold());
-        println!("   {}", hex::encode(&public_key));
+        println!("   {}", hex::encode(public_key));
         println!();
 
         // Security warning
@@ -83,8 +240,62 @@ impl SphincsKeyManager {
         Ok(())
     }
 
+    /// Prefix key bytes with the key file header for this manager's algorithm
+    fn encode_key_file(&self, key: &[u8]) -> Vec<u8> {
+        let mut data = Vec::with_capacity(KEY_FILE_HEADER_SIZE + key.len());
+        data.extend_from_slice(KEY_FILE_MAGIC);
+        data.push(KEY_FILE_VERSION);
+        data.push(self.algorithm.algorithm_id());
+        data.extend_from_slice(key);
+        data
+    }
+
+    /// Parse a key file, returning the algorithm and raw key bytes
+    pub fn decode_key_file(data: &[u8], path: &Path) -> Result<(Algorithm, Vec<u8>)> {
+        if data.len() < KEY_FILE_HEADER_SIZE || &data[..4] != KEY_FILE_MAGIC {
+            // Legacy headerless key file
+            return Ok((Algorithm::SlhDsa(SphincsParams::Sha2_128s), data.to_vec()));
+        }
+
+        if data[4] != KEY_FILE_VERSION {
+            anyhow::bail!("Unsupported key file version {} in {}", data[4], path.display());
+        }
+
+        let algorithm = Algorithm::from_algorithm_id(data[5])
+            .ok_or_else(|| anyhow::anyhow!("Unknown algorithm byte {} in {}", data[5], path.display()))?;
+
+        Ok((algorithm, data[KEY_FILE_HEADER_SIZE..].to_vec()))
+    }
+
+    /// Directory this key manager reads and writes keys in
+    pub fn key_dir(&self) -> &Path {
+        &self.key_dir
+    }
+
+    /// Move an existing keypair into a timestamped archive directory
+    /// (<key_dir>/archive/<timestamp>/) and return the archive location
+    pub fn archive_keypair(&self, public_key_path: &Path, private_key_path: &Path) -> Result<PathBuf> {
+        let stamp = chrono::Utc::now().format("%Y%m%d-%H%M%S").to_string();
+        let archive_dir = self.key_dir.join("archive").join(stamp);
+
+        fs::create_dir_all(&archive_dir)
+            .context("Failed to create key archive directory")?;
+
+        for path in [public_key_path, private_key_path] {
+            if !path.exists() {
+                continue;
+            }
+            let file_name = path.file_name()
+                .ok_or_else(|| anyhow::anyhow!("Invalid key path: {}", path.display()))?;
+            fs::rename(path, archive_dir.join(file_name))
+                .with_context(|| format!("Failed to archive {}", path.display()))?;
+        }
+
+        Ok(archive_dir)
+    }
+
     /// Load public key from file
-    pub fn load_public_key(&self, path: Option<String>) -> Result<[u8; SPHINCS_PUBKEY_SIZE]> {
+    pub fn load_public_key(&self, path: Option<String>) -> Result<PqKey> {
         let pubkey_path = if let Some(p) = path {
             PathBuf::from(p)
         } else {
@@ -93,22 +304,22 @@ impl SphincsKeyManager {
 
         let data = fs::read(&pubkey_path)
             .with_context(|| format!("Failed to read public key from {}", pubkey_path.display()))?;
+        let (algorithm, bytes) = Self::decode_key_file(&data, &pubkey_path)?;
 
-        if data.len() != SPHINCS_PUBKEY_SIZE {
+        if bytes.len() != algorithm.public_key_size() {
             anyhow::bail!(
-                "Invalid public key size: expected {} bytes, got {}",
-                SPHINCS_PUBKEY_SIZE,
-                data.len()
+                "Invalid {} public key size: expected {} bytes, got {}",
+                algorithm,
+                algorithm.public_key_size(),
+                bytes.len()
             );
         }
 
-        let mut pubkey = [0u8; SPHINCS_PUBKEY_SIZE];
-        pubkey.copy_from_slice(&data);
-        Ok(pubkey)
+        Ok(PqKey { algorithm, bytes })
     }
 
     /// Load private key from file
-    pub fn load_private_key(&self, path: Option<String>) -> Result<[u8; SPHINCS_PRIVKEY_SIZE]> {
+    pub fn load_private_key(&self, path: Option<String>) -> Result<PqKey> {
         let privkey_path = if let Some(p) = path {
             PathBuf::from(p)
         } else {
@@ -117,57 +328,71 @@ impl SphincsKeyManager {
 
         let data = fs::read(&privkey_path)
             .with_context(|| format!("Failed to read private key from {}", privkey_path.display()))?;
+        let (algorithm, bytes) = Self::decode_key_file(&data, &privkey_path)?;
 
-        if data.len() != SPHINCS_PRIVKEY_SIZE {
+        if bytes.len() != algorithm.private_key_size() {
             anyhow::bail!(
-                "Invalid private key size: expected {} bytes, got {}",
-                SPHINCS_PRIVKEY_SIZE,
-                data.len()
+                "Invalid {} private key size: expected {} bytes, got {}",
+                algorithm,
+                algorithm.private_key_size(),
+                bytes.len()
             );
         }
 
-        let mut privkey = [0u8; SPHINCS_PRIVKEY_SIZE];
-        privkey.copy_from_slice(&data);
-        Ok(privkey)
+        Ok(PqKey { algorithm, bytes })
     }
 
-    /// Sign a message with SPHINCS+ private key
-    pub fn sign_message(
-        &self,
-        message: &[u8],
-        private_key: &[u8; SPHINCS_PRIVKEY_SIZE],
-    ) -> Result<[u8; SPHINCS_SIGNATURE_SIZE]> {
-        // Deserialize private key
-        let sk = slh_dsa_sha2_128s::PrivateKey::try_from_bytes(private_key)
-            .map_err(|e| anyhow::anyhow!("Failed to deserialize private key: {:?}", e))?;
+    /// Sign a message with a private key
+    pub fn sign_message(&self, message: &[u8], private_key: &PqKey) -> Result<Vec<u8>> {
+        let params = match private_key.algorithm {
+            Algorithm::SlhDsa(params) => params,
+            Algorithm::MlDsa(params) => return mldsa::sign(params, &private_key.bytes, message),
+        };
+
+        with_params!(params, m => {
+            let bytes: [u8; m::SK_LEN] = private_key.bytes.as_slice().try_into()
+                .context("Invalid private key length")?;
+
+            // Deserialize private key
+            let sk = m::PrivateKey::try_from_bytes(&bytes)
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize private key: {:?}", e))?;
 
-        // Sign message
-        let signature = sk.try_sign(message, &[], true)
-            .map_err(|e| anyhow::anyhow!("Signature generation failed: {:?}", e))?;
+            // Sign message
+            let signature = sk.try_sign(message, &[], true)
+                .map_err(|e| anyhow::anyhow!("Signature generation failed: {:?}", e))?;
 
-        Ok(signature)
+            Ok(signature.to_vec())
+        })
     }
 
-    /// Verify a SPHINCS+ signature
-    #[allow(dead_code)]
-    pub fn verify_signature(
-        message: &[u8],
-        signature: &[u8; SPHINCS_SIGNATURE_SIZE],
-        public_key: &[u8; SPHINCS_PUBKEY_SIZE],
-    ) -> Result<bool> {
-        // Deserialize public key
-        let pk = slh_dsa_sha2_128s::PublicKey::try_from_bytes(public_key)
-            .map_err(|e| anyhow::anyhow!("Failed to deserialize public key: {:?}", e))?;
+    /// Verify a signature against a public key
+    pub fn verify_signature(message: &[u8], signature: &[u8], public_key: &PqKey) -> Result<bool> {
+        let params = match public_key.algorithm {
+            Algorithm::SlhDsa(params) => params,
+            Algorithm::MlDsa(params) => return mldsa::verify(params, &public_key.bytes, message, signature),
+        };
+
+        with_params!(params, m => {
+            let bytes: [u8; m::PK_LEN] = public_key.bytes.as_slice().try_into()
+                .context("Invalid public key length")?;
+            let signature: [u8; m::SIG_LEN] = match signature.try_into() {
+                Ok(sig) => sig,
+                Err(_) => return Ok(false),
+            };
 
-        // Verify using Verifier trait (takes 3 params: message, sig bytes, context)
-        // Empty context slice for standard SPHINCS+ verification
-        let is_valid = pk.verify(message, signature, &[]);
-        Ok(is_valid)
+            // Deserialize public key
+            let pk = m::PublicKey::try_from_bytes(&bytes)
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize public key: {:?}", e))?;
+
+            // Verify using Verifier trait (takes 3 params: message, sig bytes, context)
+            // Empty context slice for standard SPHINCS+ verification
+            Ok(pk.verify(message, &signature, &[]))
+        })
     }
 
     /// Hash a signature to 32 bytes (for on-chain storage)
     #[allow(dead_code)]
-    pub fn hash_signature(signature: &[u8; SPHINCS_SIGNATURE_SIZE]) -> [u8; 32] {
+    pub fn hash_signature(signature: &[u8]) -> [u8; 32] {
         let mut hasher = Sha256::new();
         hasher.update(signature);
         hasher.finalize().into()
@@ -176,3 +401,39 @@ impl SphincsKeyManager {
 
 // We need hex crate for displaying keys
 // Add this to Cargo.toml if not already present
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_file_header_roundtrip() {
+        let manager = SphincsKeyManager::new(Some("/tmp".to_string()))
+            .unwrap()
+            .with_algorithm(Algorithm::SlhDsa(SphincsParams::Sha2_256s));
+        let key = vec![7u8; 64];
+
+        let encoded = manager.encode_key_file(&key);
+        let (algorithm, bytes) = SphincsKeyManager::decode_key_file(&encoded, Path::new("key")).unwrap();
+
+        assert_eq!(algorithm, Algorithm::SlhDsa(SphincsParams::Sha2_256s));
+        assert_eq!(bytes, key);
+    }
+
+    #[test]
+    fn test_legacy_key_file_is_128s() {
+        let (algorithm, bytes) = SphincsKeyManager::decode_key_file(&[1u8; 32], Path::new("key")).unwrap();
+
+        assert_eq!(algorithm, Algorithm::SlhDsa(SphincsParams::Sha2_128s));
+        assert_eq!(bytes.len(), 32);
+    }
+
+    #[test]
+    fn test_slh_dsa_keys_from_seed_are_pinned() {
+        paths::use_test_root();
+        // Recovery phrases must keep deriving the same keys across crate upgrades
+        let manager = SphincsKeyManager::new(None).unwrap();
+        let public_key = manager.public_key_from_seed(&[7u8; 32]).unwrap();
+        assert_eq!(hex::encode(Sha256::digest(&public_key)), "e9234899dab6fa4e065ab8a19148f6b41e1a793020595baae57b4cf10cdaad46");
+    }
+}
@@ -1,5 +1,7 @@
 // Module declarations
 pub mod types;
+pub mod keymap;
+pub mod toast;
 pub mod utils;
 pub mod actions;
 pub mod ui;
@@ -7,18 +9,20 @@ pub mod ui;
 // Re-export commonly used types
 pub use types::*;
 pub use utils::*;
+pub use keymap::{BoundAction, Keymap};
+pub use toast::{ToastLevel, Toasts};
 
 use anyhow::Result;
 use arboard::Clipboard;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers},
+    event::{self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, BorderType, Clear, Paragraph, Row, Table, Wrap},
     Frame, Terminal,
@@ -26,16 +30,18 @@ use ratatui::{
 use solana_sdk::pubkey::Pubkey;
 use std::io::{self, Write as _};
 use std::path::PathBuf;
-use std::fs::{self, OpenOptions};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
+use crate::applog::{self, Level};
 use crate::crypto::sphincs::SphincsKeyManager;
-use crate::solana::client::VaultClient;
+use crate::solana::client::{Leaderboard, VaultClient};
+use crate::solana::receipt::Operation;
+use crate::solana::units;
 use crate::icons::Icons;
-use crate::theme::Theme;
-use crate::vault_manager::VaultConfig;
+use crate::theme::{theme, ThemeName};
+use crate::vault_manager::{DashboardSettings, VaultConfig};
 
 // Types are now defined in the types module and re-exported above
 
@@ -48,10 +54,18 @@ impl Dashboard {
         rpc_url: String,
         program_id: Pubkey,
         mint: Pubkey,
+        keymap: Keymap,
+        settings: DashboardSettings,
     ) -> Result<Self> {
-        let vault_client = VaultClient::new(&rpc_url, program_id)?;
-
-        Ok(Self {
+        let vault_client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+        let network_name = VaultConfig::load()
+            .unwrap_or_default()
+            .network_profiles()
+            .into_iter()
+            .find(|(_, network)| network.rpc_url == rpc_url)
+            .map_or_else(|| "custom".to_string(), |(name, _)| name);
+
+        let mut dashboard = Self {
             wallet,
             keypair_path,
             sphincs_public_key_path,
@@ -67,6 +81,8 @@ impl Dashboard {
             balance: None,
             pq_balance: None,
             standard_balance: None,
+            pq_extensions: None,
+            standard_extensions: None,
             is_loading: false,
             action_steps: Vec::new(),
             vault_client,
@@ -75,6 +91,7 @@ impl Dashboard {
             pending_transfer: false,
             unlock_complete: None,
             unlock_success_message: None,
+            unlock_session: None,
             lock_complete: None,
             lock_success_message: None,
             transfer_recipient: String::new(),
@@ -82,14 +99,21 @@ impl Dashboard {
             transfer_focused_field: TransferInputField::TokenType,
             transfer_token_type: TransferTokenType::StandardQcoin,
             in_transfer_form: false,
+            transfer_recipient_check: RecipientCheck::default(),
+            transfer_recipient_confirmed: false,
+            transfer_confirm_input: String::new(),
+            transfer_confirm_return: AppMode::Normal,
             bridge_amount: String::new(),
-            standard_mint: Pubkey::from_str("GS2tyNMdpiKnQ9AxFhB74SbzYF7NmoTREoKZC6pzxds7").unwrap(),
+            standard_mint: Pubkey::from_str(DEVNET_STANDARD_MINT).unwrap(),
             pq_mint: mint, // Use the mint passed in (pqcoin)
             new_vault_name: String::new(),
             vault_management_mode: VaultManagementMode::List,
             vault_list: Vec::new(),
             selected_vault_index: 0,
             in_vault_list: false,
+            vault_edit_field: VaultEditField::Name,
+            vault_edit_target: String::new(),
+            vault_edit_input: String::new(),
             vault_to_delete: String::new(),
             delete_confirmation_input: String::new(),
             vault_to_close: String::new(),
@@ -98,10 +122,46 @@ impl Dashboard {
             last_animation_update: std::time::Instant::now(),
             chart_type: ChartType::LockedAmount,
             chart_timeframe: ChartTimeframe::All,
+            chart_style: ChartStyle::Line,
+            show_price: true,
+            portfolio: Vec::new(),
+            portfolio_selected: 0,
+            compare_marked: Vec::new(),
+            comparison: Vec::new(),
+            leaderboard: Leaderboard::default(),
+            leaderboard_distribution: false,
+            keymap,
+            network_status: Arc::new(std::sync::Mutex::new(NetworkStatus::default())),
+            toasts: Arc::new(std::sync::Mutex::new(Toasts::default())),
+            log_view: LogView::default(),
+            receive_amount: String::new(),
+            receive_token: TransferTokenType::StandardQcoin,
+            settings,
+            settings_theme: ThemeName::Light,
+            settings_selected: 0,
+            last_refresh: std::time::Instant::now(),
+            last_input: std::time::Instant::now(),
+            idle_lock_deadline: None,
+            idle_lock_return: AppMode::Normal,
+            idle_lock_pending: false,
+            refresh_paused: Arc::new(AtomicBool::new(false)),
+            quit_pending: false,
+            help_scroll: 0,
+            popup_scroll: 0,
+            popup_scroll_max: std::cell::Cell::new(0),
+            snapshot_poller: None,
+            network_name,
+            network_profiles: Vec::new(),
+            network_selected: 0,
+            network_poller: None,
+            next_airdrop_claim: None,
             airdrop_timeframe: ChartTimeframe::All,
             airdrop_distributed: 0,
             airdrop_remaining: 0,
-        })
+        };
+        dashboard.transfer_token_type = dashboard.default_token_type();
+        dashboard.receive_token = dashboard.default_token_type();
+        Ok(dashboard)
     }
 
     // Get animated scanning dots
@@ -115,18 +175,31 @@ impl Dashboard {
         enable_raw_mode()?;
         let mut stdout = io::stdout();
         execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        // Pasted text arrives as one event instead of a burst of key presses;
+        // terminals without bracketed paste still have Ctrl+V
+        let _ = execute!(stdout, EnableBracketedPaste);
         let backend = CrosstermBackend::new(stdout);
         let mut terminal = Terminal::new(backend)?;
 
         // Initial refresh with a welcome message
         self.status_message = Some("Dashboard loaded! Press any key to test...".to_string());
         self.refresh_data();
+        self.network_poller = Some(self.spawn_network_poller());
+        self.restart_snapshot_poller();
+        self.offer_unlock_resume();
 
         // Run the app
         let res = self.run_app(&mut terminal);
+        if let Some(network_poller) = self.network_poller.take() {
+            network_poller.abort();
+        }
+        if let Some(snapshot_poller) = self.snapshot_poller.take() {
+            snapshot_poller.abort();
+        }
 
         // Restore terminal
         disable_raw_mode()?;
+        let _ = execute!(terminal.backend_mut(), DisableBracketedPaste);
         execute!(
             terminal.backend_mut(),
             LeaveAlternateScreen,
@@ -142,16 +215,8 @@ impl Dashboard {
     }
 
     fn run_app(&mut self, terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()> {
-        // Open debug log file
-        let mut log = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open("/tmp/qdum-debug.log")
-            .ok();
-
-        if let Some(ref mut f) = log {
-            let _ = writeln!(f, "\n=== Dashboard started ===");
-        }
+        applog::write(Level::Info, "dashboard", "Dashboard started");
+        let mut last_mode = self.mode;
 
         loop {
             // Update animation frame periodically
@@ -170,6 +235,30 @@ impl Dashboard {
                 self.last_animation_update = std::time::Instant::now();
             }
 
+            self.toasts.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).expire();
+            if self.mode == AppMode::LogViewer {
+                self.reload_log_view();
+            }
+
+            // Auto-refresh balances and lock status, but not under an open
+            // popup or while a lock, unlock or transfer is in flight
+            let busy = self.unlock_complete.is_some() || self.lock_complete.is_some() || self.pending_transfer;
+            let paused = self.refresh_paused.load(Ordering::Relaxed);
+            if let (Some(interval), false) = (self.settings.refresh_interval(), paused) {
+                if self.mode == AppMode::Normal && !busy && self.last_refresh.elapsed() >= interval {
+                    self.auto_refresh();
+                }
+            }
+            if let Some(idle) = self.settings.idle_lock() {
+                self.check_idle_lock(idle, busy);
+            }
+
+            // Every popup opens scrolled to the top
+            if self.mode != last_mode {
+                self.popup_scroll = 0;
+                last_mode = self.mode;
+            }
+
             // Clear terminal if needed (before rendering)
             if self.needs_clear {
                 terminal.clear()?;
@@ -194,55 +283,30 @@ impl Dashboard {
                     self.needs_clear = true;
                     self.action_steps.clear();
 
-                    // Refresh vault status (use block_in_place to avoid nested runtime)
-                    let vault_client = &self.vault_client;
-                    let wallet = self.wallet;
-                    let mint = self.mint;
+                    // The action ran on its own client, so this one's cached PQ account is stale
+                    self.vault_client.invalidate_pq_account(self.wallet);
 
-                    // Use block_in_place + Handle::current() to safely call async from sync context
-                    let status_result = tokio::task::block_in_place(|| {
-                        tokio::runtime::Handle::current().block_on(async {
-                            vault_client.get_vault_status(wallet).await
-                        })
-                    });
+                    // Refresh vault status and balances
+                    let snapshot = self.fetch_snapshot();
 
-                    if let Ok((is_locked, pda)) = status_result {
+                    if let Ok((is_locked, pda)) = snapshot.status {
                         self.vault_status = Some(VaultStatus {
                             is_locked,
                             pda: Some(pda),
                         });
+                        self.idle_lock_pending = false;
                         self.status_message = Some("✅ Vault unlocked successfully!".to_string());
                     } else {
                         self.status_message = Some("❌ Failed to verify vault status".to_string());
                     }
 
-                    // Refresh balance
-                    let balance_result = tokio::task::block_in_place(|| {
-                        tokio::runtime::Handle::current().block_on(async {
-                            vault_client.get_balance(wallet, mint).await
-                        })
-                    });
-                    if let Ok(bal) = balance_result {
+                    if let Ok(bal) = snapshot.balance {
                         self.balance = Some(bal);
                     }
-
-                    // Refresh pq_balance
-                    let pq_balance_result = tokio::task::block_in_place(|| {
-                        tokio::runtime::Handle::current().block_on(async {
-                            vault_client.get_balance(wallet, self.pq_mint).await
-                        })
-                    });
-                    if let Ok(bal) = pq_balance_result {
+                    if let Ok(bal) = snapshot.pq_balance {
                         self.pq_balance = Some(bal);
                     }
-
-                    // Refresh standard_balance
-                    let standard_balance_result = tokio::task::block_in_place(|| {
-                        tokio::runtime::Handle::current().block_on(async {
-                            vault_client.get_balance(wallet, self.standard_mint).await
-                        })
-                    });
-                    if let Ok(bal) = standard_balance_result {
+                    if let Ok(bal) = snapshot.standard_balance {
                         self.standard_balance = Some(bal);
                     }
 
@@ -259,55 +323,30 @@ impl Dashboard {
                     self.needs_clear = true;
                     self.action_steps.clear();
 
-                    // Refresh vault status (use block_in_place to avoid nested runtime)
-                    let vault_client = &self.vault_client;
-                    let wallet = self.wallet;
-                    let mint = self.mint;
+                    // The action ran on its own client, so this one's cached PQ account is stale
+                    self.vault_client.invalidate_pq_account(self.wallet);
 
-                    // Use block_in_place + Handle::current() to safely call async from sync context
-                    let status_result = tokio::task::block_in_place(|| {
-                        tokio::runtime::Handle::current().block_on(async {
-                            vault_client.get_vault_status(wallet).await
-                        })
-                    });
+                    // Refresh vault status and balances
+                    let snapshot = self.fetch_snapshot();
 
-                    if let Ok((is_locked, pda)) = status_result {
+                    if let Ok((is_locked, pda)) = snapshot.status {
                         self.vault_status = Some(VaultStatus {
                             is_locked,
                             pda: Some(pda),
                         });
+                        self.idle_lock_pending = false;
                         self.status_message = Some("✅ Vault locked successfully!".to_string());
                     } else {
                         self.status_message = Some("❌ Failed to verify vault status".to_string());
                     }
 
-                    // Refresh balance
-                    let balance_result = tokio::task::block_in_place(|| {
-                        tokio::runtime::Handle::current().block_on(async {
-                            vault_client.get_balance(wallet, mint).await
-                        })
-                    });
-                    if let Ok(bal) = balance_result {
+                    if let Ok(bal) = snapshot.balance {
                         self.balance = Some(bal);
                     }
-
-                    // Refresh pq_balance
-                    let pq_balance_result = tokio::task::block_in_place(|| {
-                        tokio::runtime::Handle::current().block_on(async {
-                            vault_client.get_balance(wallet, self.pq_mint).await
-                        })
-                    });
-                    if let Ok(bal) = pq_balance_result {
+                    if let Ok(bal) = snapshot.pq_balance {
                         self.pq_balance = Some(bal);
                     }
-
-                    // Refresh standard_balance
-                    let standard_balance_result = tokio::task::block_in_place(|| {
-                        tokio::runtime::Handle::current().block_on(async {
-                            vault_client.get_balance(wallet, self.standard_mint).await
-                        })
-                    });
-                    if let Ok(bal) = standard_balance_result {
+                    if let Ok(bal) = snapshot.standard_balance {
                         self.standard_balance = Some(bal);
                     }
 
@@ -343,50 +382,62 @@ impl Dashboard {
 
             match event::read()? {
                 Event::Key(key) => {
-                    if let Some(ref mut f) = log {
-                        let _ = writeln!(f, "Event::Key received - kind={:?} code={:?} mods={:?}",
-                            key.kind, key.code, key.modifiers);
-                    }
+                    applog::write(
+                        Level::Debug,
+                        "dashboard",
+                        format!("Event::Key received - kind={:?} code={:?} mods={:?}", key.kind, key.code, key.modifiers),
+                    );
 
                     // CRITICAL: On Windows/WSL, we get both Press and Release events
                     // We only want to handle Press events to avoid double-triggering
                     if key.kind == KeyEventKind::Press {
-                        if let Some(ref mut f) = log {
-                            let _ = writeln!(f, "  -> Processing KeyPress: {:?}", key.code);
-                        }
+                        self.last_input = std::time::Instant::now();
                         self.handle_key_event(key.code, key.modifiers);
                     }
                 }
+                Event::Paste(text) => {
+                    self.last_input = std::time::Instant::now();
+                    self.handle_paste(&text);
+                }
                 Event::Resize(w, h) => {
-                    if let Some(ref mut f) = log {
-                        let _ = writeln!(f, "Event::Resize {}x{}", w, h);
-                    }
+                    applog::write(Level::Debug, "dashboard", format!("Event::Resize {}x{}", w, h));
+                    // Layouts follow the new size on the next draw; clear what the old one left behind
+                    self.needs_clear = true;
                 }
                 other => {
-                    if let Some(ref mut f) = log {
-                        let _ = writeln!(f, "Event::Other {:?}", other);
-                    }
+                    applog::write(Level::Debug, "dashboard", format!("Event::Other {:?}", other));
                 }
             }
 
             if self.should_quit {
-                if let Some(ref mut f) = log {
-                    let _ = writeln!(f, "=== Dashboard quit ===");
-                }
+                applog::write(Level::Info, "dashboard", "Dashboard quit");
                 return Ok(());
             }
         }
     }
 
-    fn handle_key_event(&mut self, code: KeyCode, _modifiers: KeyModifiers) {
+    fn handle_key_event(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        if modifiers.contains(KeyModifiers::CONTROL) && matches!(code, KeyCode::Char('v') | KeyCode::Char('V')) {
+            self.paste_from_clipboard();
+            return;
+        }
+
         match self.mode {
             AppMode::Help => {
-                // Any key exits help mode
-                self.mode = AppMode::Normal;
-                self.status_message = None;
+                // Up/Down scroll help that doesn't fit; any other key exits help mode
+                match code {
+                    KeyCode::Up | KeyCode::Char('k') => self.help_scroll = self.help_scroll.saturating_sub(1),
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        self.help_scroll = (self.help_scroll + 1).min(self.help_lines().len() as u16);
+                    }
+                    _ => {
+                        self.mode = AppMode::Normal;
+                        self.status_message = None;
+                    }
+                }
             }
             AppMode::ChartPopup => {
-                // TAB or arrows switch chart type, Esc closes, R refreshes, m/1/5/7/3/a changes timeframe
+                // TAB or arrows switch chart type, Esc closes, R refreshes, E exports, P toggles price, O toggles OHLC, m/1/5/7/3/a changes timeframe
                 match code {
                     KeyCode::Tab | KeyCode::Right => {
                         // Switch to next chart type
@@ -438,31 +489,33 @@ impl Dashboard {
                         let _ = self.record_lock_history(true);
                         // Status message is set by record_lock_history
                     }
+                    KeyCode::Char('e') | KeyCode::Char('E') => {
+                        self.export_lock_history();
+                    }
+                    KeyCode::Char('o') | KeyCode::Char('O') => {
+                        self.chart_style = match self.chart_style {
+                            ChartStyle::Line => ChartStyle::Candles,
+                            ChartStyle::Candles => ChartStyle::Line,
+                        };
+                        self.status_message = Some(format!("📊 {} chart", self.chart_style.to_string()));
+                    }
+                    KeyCode::Char('p') | KeyCode::Char('P') => {
+                        self.show_price = !self.show_price;
+                        self.status_message = Some(format!("💲 Price overlay {}", if self.show_price { "on" } else { "off" }));
+                    }
                     KeyCode::Char('l') | KeyCode::Char('L') => {
-                        // Show network query log
-                        self.action_steps.clear();
-                        self.action_steps.push(ActionStep::InProgress("📋 Network Query Log:".to_string()));
-                        self.action_steps.push(ActionStep::InProgress("".to_string()));
-
-                        if let Ok(log_content) = std::fs::read_to_string("/tmp/qdum-network-query.log") {
-                            for line in log_content.lines().take(30) {
-                                self.action_steps.push(ActionStep::InProgress(line.to_string()));
-                            }
-                        } else {
-                            self.action_steps.push(ActionStep::Error("❌ Failed to read log file".to_string()));
-                        }
-
-                        self.action_steps.push(ActionStep::InProgress("".to_string()));
-                        self.action_steps.push(ActionStep::InProgress("Press [Esc] to close".to_string()));
-                        self.mode = AppMode::ResultPopup;
-                        self.needs_clear = true;
+                        // Network query log, in the log viewer
+                        self.open_log_viewer("network:");
                     }
                     _ => {}
                 }
             }
             AppMode::RegisterPopup | AppMode::LockPopup | AppMode::UnlockPopup | AppMode::ResultPopup => {
-                // In action popups, Esc closes, R refreshes
+                // In action popups, Esc closes, R refreshes, arrows and PageUp/PageDown scroll
                 match code {
+                    KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown | KeyCode::Home | KeyCode::End => {
+                        self.scroll_popup(code);
+                    }
                     KeyCode::Esc => {
                         self.mode = AppMode::Normal;
                         self.action_steps.clear();
@@ -484,7 +537,7 @@ impl Dashboard {
                         self.transfer_recipient.clear();
                         self.transfer_amount.clear();
                         self.transfer_focused_field = TransferInputField::TokenType;
-                        self.transfer_token_type = TransferTokenType::StandardQcoin;
+                        self.transfer_token_type = self.default_token_type();
                         self.status_message = Some("Transfer cancelled".to_string());
                         self.needs_clear = true;
                     }
@@ -520,10 +573,13 @@ impl Dashboard {
                             }
                             TransferInputField::Recipient => {
                                 self.transfer_recipient.push(c);
+                                self.check_recipient();
                             }
                             TransferInputField::Amount => {
-                                // Only allow numbers and decimal point
-                                if c.is_ascii_digit() || c == '.' {
+                                if let Some(amount) = amount_shortcut(c, self.transfer_balance().unwrap_or(0), units::decimals(&self.transfer_mint())) {
+                                    self.transfer_amount = amount;
+                                } else if c.is_ascii_digit() || c == '.' {
+                                    // Only allow numbers and decimal point
                                     self.transfer_amount.push(c);
                                 }
                             }
@@ -536,6 +592,7 @@ impl Dashboard {
                             }
                             TransferInputField::Recipient => {
                                 self.transfer_recipient.pop();
+                                self.check_recipient();
                             }
                             TransferInputField::Amount => {
                                 self.transfer_amount.pop();
@@ -582,6 +639,35 @@ impl Dashboard {
                     _ => {}
                 }
             }
+            AppMode::VaultEditPopup => {
+                // Handle rename / description input
+                match code {
+                    KeyCode::Esc => {
+                        self.end_vault_edit();
+                        self.status_message = Some("Edit cancelled".to_string());
+                    }
+                    KeyCode::Char(c) => {
+                        match self.vault_edit_field {
+                            // Same character set as vault creation
+                            VaultEditField::Name => {
+                                if c.is_alphanumeric() || c == '-' || c == '_' {
+                                    self.vault_edit_input.push(c);
+                                }
+                            }
+                            VaultEditField::Description => {
+                                self.vault_edit_input.push(c);
+                            }
+                        }
+                    }
+                    KeyCode::Backspace => {
+                        self.vault_edit_input.pop();
+                    }
+                    KeyCode::Enter => {
+                        self.perform_vault_edit();
+                    }
+                    _ => {}
+                }
+            }
             AppMode::CloseConfirmPopup => {
                 // Handle close confirmation input
                 match code {
@@ -622,8 +708,10 @@ impl Dashboard {
                         self.needs_clear = true;
                     }
                     KeyCode::Char(c) => {
-                        // Only allow numbers and decimal point
-                        if c.is_ascii_digit() || c == '.' {
+                        if let Some(amount) = amount_shortcut(c, self.standard_balance.unwrap_or(0), units::decimals(&self.standard_mint)) {
+                            self.bridge_amount = amount;
+                        } else if c.is_ascii_digit() || c == '.' {
+                            // Only allow numbers and decimal point
                             self.bridge_amount.push(c);
                         }
                     }
@@ -634,7 +722,14 @@ impl Dashboard {
                         // Validate amount
                         if !self.bridge_amount.is_empty() {
                             if let Ok(amount_f64) = self.bridge_amount.parse::<f64>() {
-                                let amount = (amount_f64 * 1_000_000.0) as u64;
+                                let decimals = match self.signing_decimals(self.standard_mint) {
+                                    Ok(decimals) => decimals,
+                                    Err(e) => {
+                                        self.status_message = Some(format!("❌ {:#}", e));
+                                        return;
+                                    }
+                                };
+                                let amount = units::to_base_units(amount_f64, decimals);
                                 let keypair_path = self.keypair_path.clone();
                                 let vault_client = self.vault_client.clone();
                                 let standard_mint = self.standard_mint;
@@ -651,20 +746,22 @@ impl Dashboard {
                                 // Perform wrap synchronously (blocking)
                                 let result = tokio::task::block_in_place(|| {
                                     tokio::runtime::Handle::current().block_on(async {
-                                        vault_client.bridge_wrap(
+                                        let result = vault_client.bridge_wrap(
                                             &keypair_path.to_string_lossy(),
                                             amount,
                                             standard_mint,
                                             pq_mint,
-                                        ).await
+                                        ).await;
+                                        crate::notify::finished(Operation::Wrap, &result).await;
+                                        result
                                     })
                                 });
 
                                 self.action_steps.clear();
                                 match result {
-                                    Ok(sig) => {
+                                    Ok(receipt) => {
                                         self.action_steps.push(ActionStep::Success(format!("✅ Wrapped {:.6} qcoin → {:.6} pqcoin", amount_f64, amount_f64)));
-                                        self.action_steps.push(ActionStep::Success(format!("Transaction: {}", sig)));
+                                        self.action_steps.push(ActionStep::Success(format!("Transaction: {}", receipt.last_signature().unwrap_or("-"))));
 
                                         // Auto-refresh balances after successful wrap
                                         self.refresh_data();
@@ -692,8 +789,10 @@ impl Dashboard {
                         self.needs_clear = true;
                     }
                     KeyCode::Char(c) => {
-                        // Only allow numbers and decimal point
-                        if c.is_ascii_digit() || c == '.' {
+                        if let Some(amount) = amount_shortcut(c, self.pq_balance.unwrap_or(0), units::decimals(&self.pq_mint)) {
+                            self.bridge_amount = amount;
+                        } else if c.is_ascii_digit() || c == '.' {
+                            // Only allow numbers and decimal point
                             self.bridge_amount.push(c);
                         }
                     }
@@ -704,7 +803,14 @@ impl Dashboard {
                         // Validate amount
                         if !self.bridge_amount.is_empty() {
                             if let Ok(amount_f64) = self.bridge_amount.parse::<f64>() {
-                                let amount = (amount_f64 * 1_000_000.0) as u64;
+                                let decimals = match self.signing_decimals(self.pq_mint) {
+                                    Ok(decimals) => decimals,
+                                    Err(e) => {
+                                        self.status_message = Some(format!("❌ {:#}", e));
+                                        return;
+                                    }
+                                };
+                                let amount = units::to_base_units(amount_f64, decimals);
                                 let keypair_path = self.keypair_path.clone();
                                 let vault_client = self.vault_client.clone();
                                 let standard_mint = self.standard_mint;
@@ -721,20 +827,22 @@ impl Dashboard {
                                 // Perform unwrap synchronously (blocking)
                                 let result = tokio::task::block_in_place(|| {
                                     tokio::runtime::Handle::current().block_on(async {
-                                        vault_client.bridge_unwrap(
+                                        let result = vault_client.bridge_unwrap(
                                             &keypair_path.to_string_lossy(),
                                             amount,
                                             standard_mint,
                                             pq_mint,
-                                        ).await
+                                        ).await;
+                                        crate::notify::finished(Operation::Unwrap, &result).await;
+                                        result
                                     })
                                 });
 
                                 self.action_steps.clear();
                                 match result {
-                                    Ok(sig) => {
+                                    Ok(receipt) => {
                                         self.action_steps.push(ActionStep::Success(format!("✅ Unwrapped {:.6} pqcoin → {:.6} qcoin", amount_f64, amount_f64)));
-                                        self.action_steps.push(ActionStep::Success(format!("Transaction: {}", sig)));
+                                        self.action_steps.push(ActionStep::Success(format!("Transaction: {}", receipt.last_signature().unwrap_or("-"))));
 
                                         // Auto-refresh balances after successful unwrap
                                         self.refresh_data();
@@ -795,6 +903,12 @@ impl Dashboard {
                                     self.status_message = Some(format!("Type '{}' to confirm deletion", selected_vault.name));
                                 }
                             }
+                            KeyCode::Char('r') | KeyCode::Char('R') => {
+                                self.begin_vault_edit(VaultEditField::Name);
+                            }
+                            KeyCode::Char('e') | KeyCode::Char('E') => {
+                                self.begin_vault_edit(VaultEditField::Description);
+                            }
                             KeyCode::Enter => {
                                 use std::io::Write;
                                 let _ = std::fs::write("/tmp/vault-enter-pressed.log",
@@ -857,6 +971,8 @@ impl Dashboard {
                 // Clear unlock/lock success messages on any keypress
                 self.unlock_success_message = None;
                 self.lock_success_message = None;
+                // A quit confirmation only lasts until the next key
+                let quit_armed = std::mem::take(&mut self.quit_pending);
 
                 // Special handling when actively in Transfer form (selected_action == 4 AND in_transfer_form)
                 if self.selected_action == 4 && self.in_transfer_form {
@@ -896,10 +1012,13 @@ impl Dashboard {
                                 }
                                 TransferInputField::Recipient => {
                                     self.transfer_recipient.push(c);
+                                    self.check_recipient();
                                 }
                                 TransferInputField::Amount => {
-                                    // Only allow numbers and decimal point
-                                    if c.is_ascii_digit() || c == '.' {
+                                    if let Some(amount) = amount_shortcut(c, self.transfer_balance().unwrap_or(0), units::decimals(&self.transfer_mint())) {
+                                        self.transfer_amount = amount;
+                                    } else if c.is_ascii_digit() || c == '.' {
+                                        // Only allow numbers and decimal point
                                         self.transfer_amount.push(c);
                                     }
                                 }
@@ -913,6 +1032,7 @@ impl Dashboard {
                                 }
                                 TransferInputField::Recipient => {
                                     self.transfer_recipient.pop();
+                                    self.check_recipient();
                                 }
                                 TransferInputField::Amount => {
                                     self.transfer_amount.pop();
@@ -933,7 +1053,7 @@ impl Dashboard {
                             self.transfer_recipient.clear();
                             self.transfer_amount.clear();
                             self.transfer_focused_field = TransferInputField::TokenType;
-                            self.transfer_token_type = TransferTokenType::StandardQcoin;
+                            self.transfer_token_type = self.default_token_type();
                             self.status_message = Some("Transfer cancelled".to_string());
                             return;
                         }
@@ -981,6 +1101,14 @@ impl Dashboard {
                                     }
                                     return;
                                 }
+                                KeyCode::Char('r') | KeyCode::Char('R') => {
+                                    self.begin_vault_edit(VaultEditField::Name);
+                                    return;
+                                }
+                                KeyCode::Char('e') | KeyCode::Char('E') => {
+                                    self.begin_vault_edit(VaultEditField::Description);
+                                    return;
+                                }
                                 KeyCode::Enter => {
                                     if self.selected_vault_index < self.vault_list.len() {
                                         let selected_vault = &self.vault_list[self.selected_vault_index];
@@ -1021,22 +1149,50 @@ impl Dashboard {
                     }
                 }
 
+                // Shift+L; a plain L is the lock key by default
+                if code == KeyCode::Char('L') {
+                    self.open_log_viewer("");
+                    return;
+                }
+
+                if let Some(action) = self.keymap.action(code) {
+                    match action {
+                        BoundAction::Quit => self.request_quit(quit_armed),
+                        BoundAction::Lock => self.execute_lock(),
+                        BoundAction::Unlock => self.execute_unlock(),
+                        BoundAction::Transfer => {
+                            // Navigate to Transfer (index 4)
+                            self.selected_action = 4;
+                        }
+                        BoundAction::Chart => {
+                            // Navigate to Chart (index 10)
+                            self.selected_action = 10;
+                        }
+                        BoundAction::VaultSwitch => self.open_vault_list(),
+                    }
+                    return;
+                }
+
                 match code {
-                    KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
-                        self.should_quit = true;
+                    KeyCode::Esc => {
+                        self.request_quit(quit_armed);
+                    }
+                    KeyCode::Char(',') => {
+                        self.open_settings();
+                    }
+                    KeyCode::Char('n') | KeyCode::Char('N') => {
+                        self.open_network_selector();
+                    }
+                    KeyCode::Char(' ') => {
+                        self.toggle_refresh_pause();
                     }
                     KeyCode::Char('h') | KeyCode::Char('H') | KeyCode::Char('?') | KeyCode::F(1) => {
                         self.mode = AppMode::Help;
+                        self.help_scroll = 0;
                     }
                     KeyCode::Char('r') | KeyCode::Char('R') => {
                         self.refresh_data();
                     }
-                    KeyCode::Char('l') | KeyCode::Char('L') => {
-                        self.execute_lock();
-                    }
-                    KeyCode::Char('u') | KeyCode::Char('U') => {
-                        self.execute_unlock();
-                    }
                     KeyCode::Char('s') | KeyCode::Char('S') => {
                         // Navigate to Portfolio (index 0)
                         self.selected_action = 0;
@@ -1045,10 +1201,6 @@ impl Dashboard {
                         // Navigate to Register (index 1)
                         self.selected_action = 1;
                     }
-                    KeyCode::Char('t') | KeyCode::Char('T') => {
-                        // Navigate to Transfer (index 4)
-                        self.selected_action = 4;
-                    }
                     KeyCode::Char('a') | KeyCode::Char('A') => {
                         self.execute_claim_airdrop();
                     }
@@ -1063,8 +1215,8 @@ impl Dashboard {
                             self.airdrop_remaining = remaining;
 
                             // Save to history
-                            let distributed_qdum = distributed as f64 / 1_000_000.0;
-                            let remaining_qdum = remaining as f64 / 1_000_000.0;
+                            let distributed_qdum = units::to_tokens(distributed, units::decimals(&self.pq_mint));
+                            let remaining_qdum = units::to_tokens(remaining, units::decimals(&self.pq_mint));
                             if let Ok(mut history) = AirdropHistory::load() {
                                 history.add_entry(distributed_qdum, remaining_qdum);
                                 let _ = history.save();
@@ -1081,36 +1233,17 @@ impl Dashboard {
                         // Navigate to Close (index 9)
                         self.selected_action = 9;
                     }
-                    KeyCode::Char('m') | KeyCode::Char('M') => {
-                        // Navigate to Chart (index 10)
-                        self.selected_action = 10;
-                    }
                     KeyCode::Char('c') | KeyCode::Char('C') => {
                         self.copy_wallet_to_clipboard();
                     }
-                    KeyCode::Char('v') | KeyCode::Char('V') => {
-                        // Navigate to Vaults (index 11) and load vault list
-                        self.selected_action = 11;
-
-                        // Load vault list
-                        if let Ok(config) = VaultConfig::load() {
-                            self.vault_list = config.list_vaults().into_iter().cloned().collect();
-
-                            // Find active vault and select it
-                            if let Some(active_name) = &config.active_vault {
-                                for (i, vault) in self.vault_list.iter().enumerate() {
-                                    if &vault.name == active_name {
-                                        self.selected_vault_index = i;
-                                        break;
-                                    }
-                                }
-                            }
-                        } else {
-                            self.vault_list = Vec::new();
-                        }
-
-                        // Start in list mode
-                        self.vault_management_mode = VaultManagementMode::List;
+                    KeyCode::Char('i') | KeyCode::Char('I') => {
+                        self.open_receive();
+                    }
+                    KeyCode::Char('o') | KeyCode::Char('O') => {
+                        self.execute_portfolio();
+                    }
+                    KeyCode::Char('b') | KeyCode::Char('B') => {
+                        self.open_leaderboard();
                     }
                     KeyCode::Char('w') | KeyCode::Char('W') => {
                         // Navigate to Wrap (index 5)
@@ -1159,8 +1292,8 @@ impl Dashboard {
                                     self.airdrop_distributed = distributed;
                                     self.airdrop_remaining = remaining;
 
-                                    let distributed_qdum = distributed as f64 / 1_000_000.0;
-                                    let remaining_qdum = remaining as f64 / 1_000_000.0;
+                                    let distributed_qdum = units::to_tokens(distributed, units::decimals(&self.pq_mint));
+                                    let remaining_qdum = units::to_tokens(remaining, units::decimals(&self.pq_mint));
                                     if let Ok(mut history) = AirdropHistory::load() {
                                         history.add_entry(distributed_qdum, remaining_qdum);
                                         let _ = history.save();
@@ -1204,8 +1337,11 @@ impl Dashboard {
                 }
             }
             AppMode::AirdropClaimPopup => {
-                // Esc closes popup, A shows stats
+                // Esc closes popup, A shows stats, arrows and PageUp/PageDown scroll
                 match code {
+                    KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown | KeyCode::Home | KeyCode::End => {
+                        self.scroll_popup(code);
+                    }
                     KeyCode::Char('a') | KeyCode::Char('A') => {
                         self.mode = AppMode::AirdropStatsPopup;
                         self.needs_clear = true;
@@ -1217,6 +1353,60 @@ impl Dashboard {
                     _ => {}
                 }
             }
+            AppMode::LogViewer => self.handle_log_viewer_key(code),
+            AppMode::ReceivePopup => self.handle_receive_key(code),
+            AppMode::SettingsPopup => self.handle_settings_key(code),
+            AppMode::NetworkPopup => self.handle_network_key(code),
+            AppMode::TransferConfirmPopup => self.handle_transfer_confirm_key(code),
+            AppMode::NewRecipientPopup => self.handle_new_recipient_key(code),
+            AppMode::IdleLockPopup => self.handle_idle_lock_key(code),
+            AppMode::UnlockResumePopup => self.handle_unlock_resume_key(code),
+            AppMode::PortfolioPopup => {
+                // Esc closes, R refreshes, Space marks vaults and C compares them
+                let count = self.portfolio.len().max(1);
+                match code {
+                    KeyCode::Esc => {
+                        self.mode = AppMode::Normal;
+                        self.needs_clear = true;
+                    }
+                    KeyCode::Char('r') | KeyCode::Char('R') => {
+                        self.refresh_portfolio();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => self.portfolio_selected = (self.portfolio_selected + count - 1) % count,
+                    KeyCode::Down | KeyCode::Char('j') => self.portfolio_selected = (self.portfolio_selected + 1) % count,
+                    KeyCode::Char(' ') => self.toggle_compare_mark(),
+                    KeyCode::Char('c') | KeyCode::Char('C') => self.open_vault_compare(),
+                    _ => {}
+                }
+            }
+            AppMode::VaultComparePopup => {
+                match code {
+                    KeyCode::Esc => {
+                        self.mode = AppMode::PortfolioPopup;
+                        self.needs_clear = true;
+                    }
+                    KeyCode::Char('r') | KeyCode::Char('R') => {
+                        self.open_vault_compare();
+                    }
+                    _ => {}
+                }
+            }
+            AppMode::LeaderboardPopup => {
+                match code {
+                    KeyCode::Esc => {
+                        self.mode = AppMode::Normal;
+                        self.needs_clear = true;
+                    }
+                    KeyCode::Char('r') | KeyCode::Char('R') => {
+                        self.refresh_leaderboard(true);
+                    }
+                    KeyCode::Char('d') | KeyCode::Char('D') => {
+                        self.leaderboard_distribution = !self.leaderboard_distribution;
+                        self.needs_clear = true;
+                    }
+                    _ => {}
+                }
+            }
             AppMode::AirdropStatsPopup => {
                 // Esc closes, m/1/5/7/3/a changes timeframe
                 match code {
@@ -1264,51 +1454,89 @@ impl Dashboard {
         }
     }
 
-    fn refresh_data(&mut self) {
-        self.is_loading = true;
-        self.status_message = Some("🔄 Refreshing data...".to_string());
-
-        // Fetch actual vault status and balance from blockchain
-        let wallet = self.wallet;
-        let mint = self.mint;
+    /// Fetch vault status and all three balances concurrently
+    fn fetch_snapshot(&self) -> VaultSnapshot {
         let vault_client = &self.vault_client;
+        let wallet = self.wallet;
 
-        let status_result = tokio::task::block_in_place(|| {
+        // Use block_in_place + Handle::current() to safely call async from sync context
+        tokio::task::block_in_place(|| {
             tokio::runtime::Handle::current().block_on(async {
-                vault_client.get_vault_status(wallet).await
+                let (status, balance, pq_balance, standard_balance, next_airdrop, pq_extensions, standard_extensions) = tokio::join!(
+                    vault_client.get_vault_status(wallet),
+                    vault_client.get_balance(wallet, self.mint),
+                    vault_client.get_balance(wallet, self.pq_mint),
+                    vault_client.get_balance(wallet, self.standard_mint),
+                    vault_client.next_airdrop_claim(wallet),
+                    vault_client.mint_extensions(self.pq_mint),
+                    vault_client.mint_extensions(self.standard_mint),
+                );
+                // Only the first lookup of each mint hits the network; rendering
+                // then reads the cached decimals
+                for mint in [self.mint, self.pq_mint, self.standard_mint] {
+                    let _ = vault_client.mint_decimals(mint).await;
+                }
+                VaultSnapshot { status, balance, pq_balance, standard_balance, next_airdrop, pq_extensions, standard_extensions }
             })
-        });
+        })
+    }
 
-        let balance_result = tokio::task::block_in_place(|| {
-            tokio::runtime::Handle::current().block_on(async {
-                vault_client.get_balance(wallet, mint).await
-            })
-        });
+    /// What the refresh interval runs: `refresh_data` without the status
+    /// line and toast. Failures are left to the network poller to report.
+    fn auto_refresh(&mut self) {
+        self.last_refresh = std::time::Instant::now();
+        let snapshot = self.fetch_snapshot();
+        if let Ok((is_locked, pda)) = snapshot.status {
+            self.vault_status = Some(VaultStatus { is_locked, pda: Some(pda) });
+            self.idle_lock_pending = false;
+            self.balance = snapshot.balance.ok().or(self.balance);
+            self.pq_balance = snapshot.pq_balance.ok().or(self.pq_balance);
+            self.standard_balance = snapshot.standard_balance.ok().or(self.standard_balance);
+            self.next_airdrop_claim = snapshot.next_airdrop.unwrap_or(self.next_airdrop_claim);
+            self.pq_extensions = snapshot.pq_extensions.ok().or(self.pq_extensions.take());
+            self.standard_extensions = snapshot.standard_extensions.ok().or(self.standard_extensions.take());
+        }
+    }
 
-        let pq_balance_result = tokio::task::block_in_place(|| {
-            tokio::runtime::Handle::current().block_on(async {
-                vault_client.get_balance(wallet, self.pq_mint).await
-            })
-        });
+    /// Stop or restart every background RPC call: auto-refresh and the
+    /// network and snapshot pollers. `R` still refreshes while paused.
+    fn toggle_refresh_pause(&mut self) {
+        let paused = !self.refresh_paused.load(Ordering::Relaxed);
+        self.refresh_paused.store(paused, Ordering::Relaxed);
+        if paused {
+            self.toast(ToastLevel::Info, "Auto-refresh paused (Space to resume)");
+        } else {
+            self.toast(ToastLevel::Info, "Auto-refresh resumed");
+            // Whatever is on screen may be stale by now
+            self.auto_refresh();
+        }
+    }
 
-        let standard_balance_result = tokio::task::block_in_place(|| {
-            tokio::runtime::Handle::current().block_on(async {
-                vault_client.get_balance(wallet, self.standard_mint).await
-            })
-        });
+    fn refresh_data(&mut self) {
+        self.last_refresh = std::time::Instant::now();
+        self.is_loading = true;
+        self.status_message = Some("🔄 Refreshing data...".to_string());
+
+        // Fetch actual vault status and balance from blockchain
+        let snapshot = self.fetch_snapshot();
 
-        match status_result {
+        match snapshot.status {
             Ok((is_locked, pda)) => {
                 self.vault_status = Some(VaultStatus {
                     is_locked,
                     pda: Some(pda),
                 });
+                self.idle_lock_pending = false;
                 // Fetch actual balance
-                self.balance = balance_result.ok();
-                self.pq_balance = pq_balance_result.ok();
-                self.standard_balance = standard_balance_result.ok();
+                self.balance = snapshot.balance.ok();
+                self.pq_balance = snapshot.pq_balance.ok();
+                self.standard_balance = snapshot.standard_balance.ok();
+                self.next_airdrop_claim = snapshot.next_airdrop.ok().flatten();
+                self.pq_extensions = snapshot.pq_extensions.ok();
+                self.standard_extensions = snapshot.standard_extensions.ok();
                 self.is_loading = false;
-                self.status_message = Some("✓ Data refreshed successfully".to_string());
+                self.status_message = None;
+                self.toast(ToastLevel::Success, "Data refreshed");
             }
             Err(e) => {
                 // Account might not exist yet (not registered)
@@ -1350,25 +1578,31 @@ impl Dashboard {
 
         // Render white background for modern clean look
         let bg_block = Block::default()
-            .style(Style::default().bg(Theme::BASE));  // White background
+            .style(Style::default().bg(theme().base));  // White background
         f.render_widget(bg_block, size);
 
+        let Some(layout) = ScreenLayout::for_size(size.width, size.height) else {
+            self.render_too_small(f, size);
+            return;
+        };
+
         // Early return for result popup to avoid flash - only render popup on dark background
         if self.mode == AppMode::ResultPopup {
             self.render_transfer_result_popup(f, size);
+            self.render_toasts(f, size);
             return;
         }
 
         // Create main layout
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .margin(2)
+            .margin(layout.margin)
             .constraints(
                 [
-                    Constraint::Length(5),  // Header
-                    Constraint::Length(6),  // Wallet info (expanded for PQ account)
-                    Constraint::Min(8),     // Main content
-                    Constraint::Length(6),  // Footer + status (3 lines each = 6 total)
+                    Constraint::Length(layout.header),   // Header
+                    Constraint::Length(layout.account),  // Wallet info (expanded for PQ account)
+                    Constraint::Min(8),                  // Main content
+                    Constraint::Length(layout.footer),   // Footer + status (3 lines each) + status bar
                 ]
                 .as_ref(),
             )
@@ -1380,13 +1614,16 @@ impl Dashboard {
         let content_width = width.saturating_sub(2);
 
         // Static gray border color
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         // Modern title text - Bloomberg style
         let main_title = "PQCOIN TERMINAL █";
-        let subtitle = "POST-QUANTUM SECURE  │  SPHINCS+ SHA2-128s  │  NIST FIPS 205  │  SOLANA DEVNET";
+        let subtitle = format!(
+            "POST-QUANTUM SECURE  │  SPHINCS+ SHA2-128s  │  NIST FIPS 205  │  SOLANA {}",
+            self.network_name.to_uppercase()
+        );
 
-        let header = vec![
+        let mut header = vec![
             Line::from(Span::styled(
                 format!("┏{}┓", border_line),
                 Style::default()
@@ -1399,8 +1636,8 @@ impl Dashboard {
                 Span::styled(
                     format!("{:^width$}", main_title, width = content_width),
                     Style::default()
-                        .fg(Theme::BLOOMBERG_ORANGE)
-                        .bg(Theme::BASE)
+                        .fg(theme().bloomberg_orange)
+                        .bg(theme().base)
                         .add_modifier(Modifier::BOLD)
                 ),
                 Span::styled("┃", Style::default().fg(border_color).add_modifier(Modifier::BOLD)),
@@ -1411,8 +1648,8 @@ impl Dashboard {
                 Span::styled(
                     format!("{:^width$}", subtitle, width = content_width),
                     Style::default()
-                        .fg(Theme::TEXT)
-                        .bg(Theme::BASE)
+                        .fg(theme().text)
+                        .bg(theme().base)
                         .add_modifier(Modifier::BOLD)
                 ),
                 Span::styled("┃", Style::default().fg(border_color).add_modifier(Modifier::BOLD)),
@@ -1425,9 +1662,14 @@ impl Dashboard {
             )),
         ];
 
+        // No room for the subtitle in the compact layout
+        if layout.is_compact() {
+            header.remove(2);
+        }
+
         let header_paragraph = Paragraph::new(header)
             .alignment(Alignment::Left)
-            .style(Style::default().bg(Theme::BASE));
+            .style(Style::default().bg(theme().base));
         f.render_widget(header_paragraph, chunks[0]);
 
         // Get active vault name for account info
@@ -1441,10 +1683,10 @@ impl Dashboard {
         let mut account_rows = vec![
             // Wallet address row
             Row::new(vec![
-                Line::from(Span::styled("WALLET", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled("WALLET", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
                 Line::from(vec![
-                    Span::styled(self.wallet.to_string(), Style::default().fg(Theme::TEXT).add_modifier(Modifier::BOLD)),
-                    Span::styled("  [C] COPY", Style::default().fg(Theme::SUBTEXT0)),
+                    Span::styled(self.wallet.to_string(), Style::default().fg(theme().text).add_modifier(Modifier::BOLD)),
+                    Span::styled("  [C] COPY", Style::default().fg(theme().subtext0)),
                 ]),
             ]),
         ];
@@ -1453,37 +1695,37 @@ impl Dashboard {
         if let Some(ref status) = self.vault_status {
             if let Some(pda) = status.pda {
                 let state_text = if status.is_locked { "🔒 LOCKED" } else { "🔓 UNLOCKED" };
-                let state_color = if status.is_locked { Theme::RED_NEON } else { Theme::GREEN_NEON };
+                let state_color = if status.is_locked { theme().red_neon } else { theme().green_neon };
 
                 account_rows.push(Row::new(vec![
-                    Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-                    Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+                    Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+                    Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
                 ]));
 
                 account_rows.push(Row::new(vec![
-                    Line::from(Span::styled("PQ ACCOUNT", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
-                    Line::from(Span::styled(pda.to_string(), Style::default().fg(Theme::PURPLE).add_modifier(Modifier::BOLD))),
+                    Line::from(Span::styled("PQ ACCOUNT", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
+                    Line::from(Span::styled(pda.to_string(), Style::default().fg(theme().purple).add_modifier(Modifier::BOLD))),
                 ]));
 
                 account_rows.push(Row::new(vec![
-                    Line::from(Span::styled("STATE", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+                    Line::from(Span::styled("STATE", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
                     Line::from(Span::styled(state_text, Style::default().fg(state_color).add_modifier(Modifier::BOLD))),
                 ]));
 
                 account_rows.push(Row::new(vec![
-                    Line::from(Span::styled("VAULT", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
-                    Line::from(Span::styled(vault_name.to_uppercase(), Style::default().fg(Theme::TEXT).add_modifier(Modifier::BOLD))),
+                    Line::from(Span::styled("VAULT", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
+                    Line::from(Span::styled(vault_name.to_uppercase(), Style::default().fg(theme().text).add_modifier(Modifier::BOLD))),
                 ]));
             } else {
                 // PDA not available - vault not registered
                 account_rows.push(Row::new(vec![
-                    Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-                    Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+                    Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+                    Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
                 ]));
 
                 account_rows.push(Row::new(vec![
-                    Line::from(Span::styled("PQ ACCOUNT", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
-                    Line::from(Span::styled("NOT REGISTERED - Use [G]", Style::default().fg(Theme::ORANGE_NEON).add_modifier(Modifier::BOLD))),
+                    Line::from(Span::styled("PQ ACCOUNT", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
+                    Line::from(Span::styled("NOT REGISTERED - Use [G]", Style::default().fg(theme().orange_neon).add_modifier(Modifier::BOLD))),
                 ]));
             }
         }
@@ -1491,7 +1733,7 @@ impl Dashboard {
         let account_widths = [Constraint::Length(20), Constraint::Min(40)];
 
         // Static gray border color matching splash screen
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
         let account_table = Table::new(account_rows, account_widths)
             .block(
                 Block::default()
@@ -1499,9 +1741,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(format!(" {} ACCOUNT INFO ", Icons::INFO))
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .column_spacing(2);
 
         f.render_widget(account_table, chunks[1]);
@@ -1529,18 +1771,33 @@ impl Dashboard {
 
         // Render popups on top of dashboard (NO early returns, NO full screen clears)
         match self.mode {
-            AppMode::RegisterPopup => self.render_action_popup(f, size, "REGISTER", Color::Green),
+            AppMode::RegisterPopup => self.render_action_popup(f, size, "REGISTER", theme().green_neon),
             AppMode::TransferPopup => self.render_transfer_popup(f, size),
             AppMode::WrapPopup => self.render_wrap_popup(f, size),
             AppMode::UnwrapPopup => self.render_unwrap_popup(f, size),
-            AppMode::AirdropClaimPopup => self.render_action_popup(f, size, "CLAIM AIRDROP", Theme::CYAN_NEON),
+            AppMode::AirdropClaimPopup => self.render_action_popup(f, size, "CLAIM AIRDROP", theme().cyan_neon),
             AppMode::AirdropStatsPopup => self.render_airdrop_stats_popup(f, size),
             AppMode::VaultSwitchPopup => self.render_vault_switch_popup(f, size),
             AppMode::DeleteConfirmPopup => self.render_delete_confirm_popup(f, size),
+            AppMode::VaultEditPopup => self.render_vault_edit_popup(f, size),
             AppMode::CloseConfirmPopup => self.render_close_confirm_popup(f, size),
             AppMode::ChartPopup => self.render_chart_popup(f, size),
+            AppMode::PortfolioPopup => self.render_portfolio_popup(f, size),
+            AppMode::VaultComparePopup => self.render_vault_compare_popup(f, size),
+            AppMode::LeaderboardPopup => self.render_leaderboard_popup(f, size),
+            AppMode::LogViewer => self.render_log_viewer(f, size),
+            AppMode::ReceivePopup => self.render_receive_popup(f, size),
+            AppMode::SettingsPopup => self.render_settings_popup(f, size),
+            AppMode::NetworkPopup => self.render_network_popup(f, size),
+            AppMode::TransferConfirmPopup => self.render_transfer_confirm_popup(f, size),
+            AppMode::NewRecipientPopup => self.render_new_recipient_popup(f, size),
+            AppMode::IdleLockPopup => self.render_idle_lock_popup(f, size),
+            AppMode::UnlockResumePopup => self.render_unlock_resume_popup(f, size),
             _ => {}
         }
+
+        // Toasts go over everything, popups included
+        self.render_toasts(f, size);
     }
 
 
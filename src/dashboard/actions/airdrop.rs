@@ -1,8 +1,21 @@
+use crate::dashboard::toast::ToastLevel;
 use crate::dashboard::types::{Dashboard, AppMode, ActionStep};
-use crate::dashboard::utils::suppress_output;
+use crate::dashboard::utils::{format_countdown, suppress_output};
 
 impl Dashboard {
+    /// Seconds until the airdrop can be claimed again, while it is cooling down
+    pub fn airdrop_cooldown(&self) -> Option<i64> {
+        let remaining = self.next_airdrop_claim? - chrono::Utc::now().timestamp();
+        (remaining > 0).then_some(remaining)
+    }
+
     pub fn execute_claim_airdrop(&mut self) {
+        // The program would reject it anyway, with a far less useful error
+        if let Some(remaining) = self.airdrop_cooldown() {
+            self.toast(ToastLevel::Warning, format!("Next airdrop claim in {}", format_countdown(remaining)));
+            return;
+        }
+
         // Keep mode as Normal - render in content area instead of popup
         self.action_steps.clear();
         self.action_steps.push(ActionStep::Starting);
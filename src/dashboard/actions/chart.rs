@@ -1,5 +1,13 @@
 use anyhow::Result;
-use crate::dashboard::types::{Dashboard, AppMode, LockHistory};
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use crate::applog::{self, Level};
+use crate::dashboard::toast::ToastLevel;
+use crate::dashboard::types::{Dashboard, AppMode, ExportFormat, LockHistory};
+use crate::vault_manager::VaultConfig;
+
+/// Program transactions walked when backfilling an empty history
+const BACKFILL_SIGNATURES: usize = 2_000;
 
 impl Dashboard {
     pub fn record_lock_history(&mut self, force_refresh: bool) -> Result<(f64, usize)> {
@@ -9,36 +17,108 @@ impl Dashboard {
 
         self.status_message = Some("🔍 Querying network for locked tokens...".to_string());
 
-        // Get total locked qcoin across all holders
+        // Same snapshot the `snapshot` command takes
         let result = tokio::task::block_in_place(|| {
             tokio::runtime::Handle::current().block_on(async {
-                vault_client.get_network_locked_total(mint, force_refresh).await
+                LockHistory::record(vault_client, mint, force_refresh).await
             })
         });
 
         match result {
-            Ok((total_locked, holder_count)) => {
-
-                // Load history, add entry, and save
-                if let Ok(mut history) = LockHistory::load() {
-                    history.add_entry(total_locked, holder_count);
-                    if let Err(e) = history.save() {
-                        self.status_message = Some(format!("⚠️  Failed to save history: {}", e));
-                        return Err(e);
-                    }
-                }
-
-                self.status_message = Some(format!("✅ Recorded: {:.2} qcoin locked ({} holders)", total_locked, holder_count));
-                Ok((total_locked, holder_count))
+            Ok(entry) => {
+                self.status_message = None;
+                self.toast(
+                    ToastLevel::Success,
+                    format!("Snapshot saved: {:.2} qcoin locked ({} holders)", entry.locked_amount, entry.holder_count),
+                );
+                Ok((entry.locked_amount, entry.holder_count))
             }
             Err(e) => {
-                self.status_message = Some(format!("❌ Failed to query network: {}", e));
+                self.status_message = None;
+                self.toast(ToastLevel::Error, format!("Failed to record snapshot: {}", e));
                 Err(e)
             }
         }
     }
 
+    /// Take a network lock snapshot every `interval` in the background, so
+    /// the chart fills in while the dashboard is open. Abort the returned
+    /// handle to stop.
+    pub fn spawn_snapshot_poller(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let client = self.vault_client.clone();
+        let mint = self.mint;
+        let toasts = self.toasts.clone();
+        let paused = self.refresh_paused.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            // The first tick is immediate; wait a full interval instead
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                if paused.load(Ordering::Relaxed) {
+                    continue;
+                }
+                match LockHistory::record(&client, mint, false).await {
+                    Ok(entry) => applog::write(
+                        Level::Info,
+                        "snapshot",
+                        format!("{:.2} locked ({} holders)", entry.locked_amount, entry.holder_count),
+                    ),
+                    Err(e) => {
+                        applog::write(Level::Error, "snapshot", format!("{:#}", e));
+                        toasts
+                            .lock()
+                            .unwrap_or_else(|poisoned| poisoned.into_inner())
+                            .push(ToastLevel::Error, format!("Scheduled snapshot failed: {:#}", e));
+                    }
+                }
+            }
+        })
+    }
+
+    /// Reconstruct past lock totals from on-chain history, so the chart isn't
+    /// empty the first time it's opened
+    pub fn backfill_lock_history(&mut self) -> Result<usize> {
+        let mint = self.mint;
+        let vault_client = &self.vault_client;
+
+        let snapshots = tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(async {
+                vault_client.lock_history(mint, BACKFILL_SIGNATURES).await
+            })
+        })?;
+
+        let mut history = LockHistory::load()?;
+        let retention = VaultConfig::load().unwrap_or_default().snapshot_retention();
+        let added = history.backfill(&snapshots, retention);
+        history.save()?;
+        Ok(added)
+    }
+
+    /// Write the chart data to a CSV file in the data directory
+    pub fn export_lock_history(&mut self) {
+        let path = crate::paths::data_dir().join(format!("network_lock_history.{}", ExportFormat::Csv.extension()));
+        let result = (|| -> Result<usize> {
+            let history = LockHistory::load()?;
+            std::fs::write(&path, history.export(ExportFormat::Csv)?)?;
+            Ok(history.entries.len())
+        })();
+
+        match result {
+            Ok(count) => self.toast(ToastLevel::Success, format!("Exported {} entries to {}", count, path.display())),
+            Err(e) => self.toast(ToastLevel::Error, format!("Failed to export history: {}", e)),
+        }
+    }
+
     pub fn execute_chart(&mut self) {
+        // First run: fill in history from before the dashboard was ever open
+        if LockHistory::load().is_ok_and(|history| history.entries.is_empty()) {
+            self.status_message = Some("🔍 Backfilling lock history from chain...".to_string());
+            if let Err(e) = self.backfill_lock_history() {
+                self.status_message = Some(format!("⚠️  Failed to backfill history: {}", e));
+            }
+        }
+
         // Record current lock status before showing chart (use cache if available)
         let _ = self.record_lock_history(false);
 
@@ -0,0 +1,26 @@
+use crate::dashboard::toast::ToastLevel;
+use crate::dashboard::types::{AppMode, Dashboard};
+
+impl Dashboard {
+    pub fn open_leaderboard(&mut self) {
+        self.refresh_leaderboard(false);
+        self.mode = AppMode::LeaderboardPopup;
+        self.needs_clear = true;
+    }
+
+    /// Rank locked holders, from the network lock cache unless `force_refresh`
+    pub fn refresh_leaderboard(&mut self, force_refresh: bool) {
+        self.status_message = Some("🔍 Querying locked holders...".to_string());
+        let (client, mint) = (&self.vault_client, self.mint);
+
+        let result = tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(async { client.lock_leaderboard(mint, force_refresh).await })
+        });
+
+        self.status_message = None;
+        match result {
+            Ok(leaderboard) => self.leaderboard = leaderboard,
+            Err(e) => self.toast(ToastLevel::Error, format!("Failed to load leaderboard: {:#}", e)),
+        }
+    }
+}
@@ -1,6 +1,10 @@
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
-use crate::dashboard::types::{Dashboard, AppMode};
+use std::time::{Duration, Instant};
+use crossterm::event::KeyCode;
+use crate::applog::{self, Level};
+use crate::dashboard::toast::ToastLevel;
+use crate::dashboard::types::{Dashboard, AppMode, IDLE_LOCK_GRACE};
 use crate::solana::client::VaultClient;
 
 impl Dashboard {
@@ -50,7 +54,7 @@ impl Dashboard {
                 }
 
                 // Create VaultClient
-                let vault_client = match VaultClient::new(&rpc_url, program_id) {
+                let vault_client = match VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build() {
                     Ok(client) => client,
                     Err(_) => {
                         unsafe {
@@ -79,4 +83,61 @@ impl Dashboard {
             }); // End rt.block_on
         }); // End std::thread::spawn
     }
+
+    /// Prompt once the vault has sat unlocked for `idle` without input, and
+    /// lock it if the prompt goes unanswered for `IDLE_LOCK_GRACE`
+    pub fn check_idle_lock(&mut self, idle: Duration, busy: bool) {
+        let unlocked = self.vault_status.as_ref().is_some_and(|status| !status.is_locked);
+        if busy || !unlocked || self.idle_lock_pending {
+            if self.mode == AppMode::IdleLockPopup {
+                self.mode = self.idle_lock_return;
+                self.needs_clear = true;
+            }
+            self.idle_lock_deadline = None;
+            return;
+        }
+
+        match self.idle_lock_deadline {
+            None if self.last_input.elapsed() >= idle => {
+                self.idle_lock_return = self.mode;
+                self.idle_lock_deadline = Some(Instant::now() + IDLE_LOCK_GRACE);
+                self.mode = AppMode::IdleLockPopup;
+                self.needs_clear = true;
+            }
+            Some(deadline) if Instant::now() >= deadline => {
+                applog::write(
+                    Level::Warn,
+                    "dashboard",
+                    format!("Locked {} after {}s without input", self.wallet, self.last_input.elapsed().as_secs()),
+                );
+                self.toast(ToastLevel::Warning, "Vault locked after being left idle");
+                self.lock_idle_vault();
+            }
+            _ => {}
+        }
+    }
+
+    pub fn handle_idle_lock_key(&mut self, code: KeyCode) {
+        if code == KeyCode::Enter {
+            applog::write(Level::Info, "dashboard", format!("Locked {} from the idle prompt", self.wallet));
+            self.lock_idle_vault();
+            return;
+        }
+        applog::write(Level::Info, "dashboard", "Idle auto-lock dismissed");
+        self.idle_lock_deadline = None;
+        self.mode = self.idle_lock_return;
+        self.needs_clear = true;
+    }
+
+    /// Lock from the idle prompt. The idle clock restarts and the prompt
+    /// stays off until a refreshed status shows how the lock went, so a
+    /// failed or slow lock isn't re-triggered straight away.
+    fn lock_idle_vault(&mut self) {
+        self.idle_lock_deadline = None;
+        self.idle_lock_pending = true;
+        self.last_input = Instant::now();
+        self.mode = AppMode::Normal;
+        self.needs_clear = true;
+        self.execute_lock();
+    }
 }
@@ -0,0 +1,86 @@
+use crossterm::event::KeyCode;
+use crate::applog;
+use crate::dashboard::types::{AppMode, Dashboard};
+
+/// Newest lines of the log kept in the viewer
+const LOG_VIEW_LINES: usize = 2_000;
+
+/// Lines moved by PageUp / PageDown
+const LOG_PAGE: usize = 20;
+
+impl Dashboard {
+    /// Show the application log, optionally already searching for `search`
+    pub fn open_log_viewer(&mut self, search: &str) {
+        self.log_view.search = search.to_string();
+        self.log_view.editing_search = false;
+        self.log_view.scroll = 0;
+        self.log_view.loaded_len = u64::MAX;
+        self.reload_log_view();
+        self.mode = AppMode::LogViewer;
+        self.needs_clear = true;
+    }
+
+    /// Re-read the tail of the log if it changed since the last read. When
+    /// scrolled back, the view stays on the same lines as new ones arrive.
+    pub fn reload_log_view(&mut self) {
+        let path = applog::path();
+        let len = std::fs::metadata(&path).map_or(0, |meta| meta.len());
+        if len == self.log_view.loaded_len {
+            return;
+        }
+        let before = self.log_view.visible().len();
+
+        let content = std::fs::read(&path).unwrap_or_default();
+        let content = String::from_utf8_lossy(&content);
+        let lines: Vec<&str> = content.lines().collect();
+        let start = lines.len().saturating_sub(LOG_VIEW_LINES);
+        self.log_view.lines = lines[start..].iter().map(|line| line.to_string()).collect();
+        self.log_view.loaded_len = len;
+
+        if self.log_view.scroll > 0 {
+            let added = self.log_view.visible().len().saturating_sub(before);
+            self.log_view.scroll += added;
+        }
+    }
+
+    pub fn handle_log_viewer_key(&mut self, code: KeyCode) {
+        let view = &mut self.log_view;
+        if view.editing_search {
+            match code {
+                KeyCode::Enter => view.editing_search = false,
+                KeyCode::Esc => {
+                    view.search.clear();
+                    view.editing_search = false;
+                }
+                KeyCode::Backspace => {
+                    view.search.pop();
+                }
+                KeyCode::Char(c) => view.search.push(c),
+                _ => return,
+            }
+            view.scroll = 0;
+            return;
+        }
+
+        let max_scroll = view.visible().len().saturating_sub(1);
+        match code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.mode = AppMode::Normal;
+                self.needs_clear = true;
+            }
+            KeyCode::Up | KeyCode::Char('k') => view.scroll = (view.scroll + 1).min(max_scroll),
+            KeyCode::Down | KeyCode::Char('j') => view.scroll = view.scroll.saturating_sub(1),
+            KeyCode::PageUp => view.scroll = (view.scroll + LOG_PAGE).min(max_scroll),
+            KeyCode::PageDown => view.scroll = view.scroll.saturating_sub(LOG_PAGE),
+            KeyCode::Home | KeyCode::Char('g') => view.scroll = max_scroll,
+            KeyCode::End | KeyCode::Char('G') => view.scroll = 0,
+            KeyCode::Char('/') => view.editing_search = true,
+            KeyCode::Char('f') | KeyCode::Char('F') => view.cycle_level(),
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                view.search.clear();
+                view.scroll = 0;
+            }
+            _ => {}
+        }
+    }
+}
@@ -7,6 +7,12 @@ mod unwrap;
 mod airdrop;
 mod vault_management;
 mod chart;
+mod portfolio;
+mod network;
+mod leaderboard;
+mod logs;
+mod receive;
+mod settings;
 
 pub use register::*;
 pub use lock::*;
@@ -17,3 +23,9 @@ pub use unwrap::*;
 pub use airdrop::*;
 pub use vault_management::*;
 pub use chart::*;
+pub use portfolio::*;
+pub use network::*;
+pub use leaderboard::*;
+pub use logs::*;
+pub use receive::*;
+pub use settings::*;
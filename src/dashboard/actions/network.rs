@@ -0,0 +1,128 @@
+use std::str::FromStr;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use crossterm::event::KeyCode;
+use solana_sdk::pubkey::Pubkey;
+use crate::applog::{self, Level};
+use crate::dashboard::toast::ToastLevel;
+use crate::dashboard::types::{AppMode, Dashboard, NetworkStatus, DEVNET_PQ_MINT, DEVNET_STANDARD_MINT};
+use crate::solana::client::{VaultClient, DEFAULT_PROGRAM_ID};
+use crate::vault_manager::{NetworkProfile, VaultConfig};
+
+/// How often the status bar's slot, epoch and latency are refreshed
+const NETWORK_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+impl Dashboard {
+    /// Keep `network_status` fresh in the background, so the status bar never
+    /// waits on the RPC. Abort the returned handle when the dashboard exits.
+    pub fn spawn_network_poller(&self) -> tokio::task::JoinHandle<()> {
+        let client = self.vault_client.clone();
+        let status = self.network_status.clone();
+        let toasts = self.toasts.clone();
+        let paused = self.refresh_paused.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(NETWORK_POLL_INTERVAL);
+            loop {
+                interval.tick().await;
+                if paused.load(Ordering::Relaxed) {
+                    continue;
+                }
+                let result = client.cluster_status().await;
+                let mut status = status.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                let mut toasts = toasts.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                // Only the transitions are worth a toast; the status bar shows the rest
+                match result {
+                    Ok(cluster) => {
+                        if status.error.take().is_some() {
+                            toasts.push(ToastLevel::Success, "RPC reachable again");
+                        }
+                        status.cluster = Some(cluster);
+                    }
+                    Err(e) => {
+                        if status.error.is_none() {
+                            toasts.push(ToastLevel::Error, format!("RPC error: {:#}", e));
+                        }
+                        status.error = Some(format!("{:#}", e));
+                    }
+                }
+            }
+        })
+    }
+
+    pub fn open_network_selector(&mut self) {
+        self.network_profiles = VaultConfig::load().unwrap_or_default().network_profiles();
+        self.network_selected = self
+            .network_profiles
+            .iter()
+            .position(|(name, _)| *name == self.network_name)
+            .unwrap_or(0);
+        self.mode = AppMode::NetworkPopup;
+        self.needs_clear = true;
+    }
+
+    pub fn handle_network_key(&mut self, code: KeyCode) {
+        let count = self.network_profiles.len().max(1);
+        match code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.mode = AppMode::Normal;
+                self.needs_clear = true;
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.network_selected = (self.network_selected + count - 1) % count,
+            KeyCode::Down | KeyCode::Char('j') => self.network_selected = (self.network_selected + 1) % count,
+            KeyCode::Enter => {
+                self.mode = AppMode::Normal;
+                self.needs_clear = true;
+                if let Some((name, network)) = self.network_profiles.get(self.network_selected).cloned() {
+                    if name != self.network_name {
+                        self.switch_network(name, &network);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Point the dashboard at another network: a fresh client, fresh pollers,
+    /// and every panel reloaded from the new RPC
+    fn switch_network(&mut self, name: String, network: &NetworkProfile) {
+        let address = |value: &Option<String>, default: Pubkey| -> anyhow::Result<Pubkey> {
+            Ok(value.as_deref().map(Pubkey::from_str).transpose()?.unwrap_or(default))
+        };
+        let switched = (|| -> anyhow::Result<_> {
+            let program_id = address(&network.program_id, DEFAULT_PROGRAM_ID)?;
+            let mint = address(&network.mint, Pubkey::from_str(DEVNET_PQ_MINT)?)?;
+            let standard_mint = address(&network.standard_mint, Pubkey::from_str(DEVNET_STANDARD_MINT)?)?;
+            let client = VaultClient::builder().rpc_url(&network.rpc_url).program_id(program_id).build()?;
+            Ok((program_id, mint, standard_mint, client))
+        })();
+        let (program_id, mint, standard_mint, client) = match switched {
+            Ok(switched) => switched,
+            Err(e) => {
+                self.toast(ToastLevel::Error, format!("Failed to switch to {}: {:#}", name, e));
+                return;
+            }
+        };
+
+        self.rpc_url = network.rpc_url.clone();
+        self.program_id = program_id;
+        self.mint = mint;
+        self.pq_mint = mint;
+        self.standard_mint = standard_mint;
+        self.vault_client = client;
+        self.network_name = name;
+        self.vault_status = None;
+        self.next_airdrop_claim = None;
+        *self.network_status.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = NetworkStatus::default();
+
+        // The pollers hold a clone of the old client
+        if let Some(poller) = self.network_poller.take() {
+            poller.abort();
+        }
+        self.network_poller = Some(self.spawn_network_poller());
+        self.restart_snapshot_poller();
+
+        applog::write(Level::Info, "network", format!("Switched to {} ({})", self.network_name, self.rpc_url));
+        self.refresh_data();
+        self.toast(ToastLevel::Info, format!("Switched to {}", self.network_name));
+    }
+}
@@ -0,0 +1,156 @@
+use std::str::FromStr;
+use anyhow::Result;
+use solana_sdk::pubkey::Pubkey;
+use crate::dashboard::types::{Dashboard, AppMode, PortfolioRow, VaultComparison};
+use crate::solana::client::VaultClient;
+use crate::vault_manager::{VaultConfig, VaultProfile};
+
+impl Dashboard {
+    pub fn execute_portfolio(&mut self) {
+        self.refresh_portfolio();
+        self.mode = AppMode::PortfolioPopup;
+        self.needs_clear = true;
+    }
+
+    /// Query every vault profile on its own network
+    pub fn refresh_portfolio(&mut self) {
+        self.status_message = Some("🔍 Loading all vaults...".to_string());
+
+        let config = match VaultConfig::load() {
+            Ok(config) => config,
+            Err(e) => {
+                self.status_message = Some(format!("❌ Failed to load vaults: {}", e));
+                return;
+            }
+        };
+        let (pq_mint, standard_mint) = (self.pq_mint, self.standard_mint);
+
+        let rows = tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(async {
+                let mut rows = Vec::new();
+                for vault in config.list_vaults() {
+                    let is_active = config.active_vault.as_deref() == Some(vault.name.as_str());
+                    rows.push(portfolio_row(vault, is_active, pq_mint, standard_mint).await);
+                }
+                rows
+            })
+        });
+
+        let failed = rows.iter().filter(|row| row.error.is_some()).count();
+        self.status_message = Some(if failed == 0 {
+            format!("✓ {} vaults loaded", rows.len())
+        } else {
+            format!("⚠ {} vaults loaded, {} failed", rows.len() - failed, failed)
+        });
+        self.portfolio_selected = self.portfolio_selected.min(rows.len().saturating_sub(1));
+        self.compare_marked.retain(|name| rows.iter().any(|row| row.name == *name));
+        self.portfolio = rows;
+    }
+
+    /// Mark or unmark the selected vault; marking a third drops the oldest mark
+    pub fn toggle_compare_mark(&mut self) {
+        let Some(row) = self.portfolio.get(self.portfolio_selected) else {
+            return;
+        };
+        if let Some(index) = self.compare_marked.iter().position(|name| *name == row.name) {
+            self.compare_marked.remove(index);
+            return;
+        }
+        self.compare_marked.push(row.name.clone());
+        if self.compare_marked.len() > 2 {
+            self.compare_marked.remove(0);
+        }
+    }
+
+    /// Load the two marked vaults side by side
+    pub fn open_vault_compare(&mut self) {
+        if self.compare_marked.len() != 2 {
+            self.status_message = Some("Mark two vaults with Space to compare them".to_string());
+            return;
+        }
+        let config = match VaultConfig::load() {
+            Ok(config) => config,
+            Err(e) => {
+                self.status_message = Some(format!("❌ Failed to load vaults: {}", e));
+                return;
+            }
+        };
+        let (pq_mint, standard_mint) = (self.pq_mint, self.standard_mint);
+
+        self.status_message = Some("🔍 Comparing vaults...".to_string());
+        let vaults: Vec<&VaultProfile> = self.compare_marked.iter().filter_map(|name| config.get_vault(name)).collect();
+        let comparison = tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(async {
+                let mut columns = Vec::new();
+                for vault in vaults {
+                    let is_active = config.active_vault.as_deref() == Some(vault.name.as_str());
+                    columns.push(vault_comparison(vault, is_active, pq_mint, standard_mint).await);
+                }
+                columns
+            })
+        });
+        self.status_message = None;
+        self.comparison = comparison;
+        self.mode = AppMode::VaultComparePopup;
+        self.needs_clear = true;
+    }
+}
+
+/// Client, wallet and mints for querying `vault` on its own network
+fn vault_setup(vault: &VaultProfile, pq_mint: Pubkey, standard_mint: Pubkey) -> Result<(VaultClient, Pubkey, Pubkey, Pubkey)> {
+    let client = VaultClient::builder()
+        .rpc_url(vault.network_rpc_url())
+        .program_id(vault.network_program_id()?)
+        .build()?;
+    // Vaults pinned to another cluster carry their own mints
+    let pq_mint = vault.mint.as_deref().map(Pubkey::from_str).transpose()?.unwrap_or(pq_mint);
+    let standard_mint = vault.standard_mint.as_deref().map(Pubkey::from_str).transpose()?.unwrap_or(standard_mint);
+    Ok((client, vault.wallet()?, pq_mint, standard_mint))
+}
+
+async fn vault_comparison(vault: &VaultProfile, is_active: bool, pq_mint: Pubkey, standard_mint: Pubkey) -> VaultComparison {
+    let row = portfolio_row(vault, is_active, pq_mint, standard_mint).await;
+    let footprint = match vault_setup(vault, pq_mint, standard_mint) {
+        Ok((client, wallet, _, _)) => client.vault_footprint(wallet).await.ok(),
+        Err(_) => None,
+    };
+    VaultComparison { row, wallet: vault.wallet().ok().map(|wallet| wallet.to_string()), footprint }
+}
+
+async fn portfolio_row(vault: &VaultProfile, is_active: bool, pq_mint: Pubkey, standard_mint: Pubkey) -> PortfolioRow {
+    let network = vault.network_rpc_url();
+    let mut row = PortfolioRow {
+        name: vault.name.clone(),
+        is_active,
+        network: network.clone(),
+        is_locked: None,
+        pq_balance: None,
+        standard_balance: None,
+        sol_lamports: None,
+        error: None,
+    };
+
+    let (client, wallet, pq_mint, standard_mint) = match vault_setup(vault, pq_mint, standard_mint) {
+        Ok(setup) => setup,
+        Err(e) => {
+            row.error = Some(e.to_string());
+            return row;
+        }
+    };
+
+    let (status, pq_balance, standard_balance, sol) = tokio::join!(
+        client.get_vault_status(wallet),
+        client.get_balance(wallet, pq_mint),
+        client.get_balance(wallet, standard_mint),
+        client.get_sol_balance(wallet),
+    );
+    // An unregistered vault has no PQ account but can still hold tokens
+    row.is_locked = status.ok().map(|(is_locked, _)| is_locked);
+    row.pq_balance = pq_balance.ok();
+    row.standard_balance = standard_balance.ok();
+    row.sol_lamports = sol.ok();
+    if row.pq_balance.is_none() && row.standard_balance.is_none() && row.sol_lamports.is_none() {
+        row.error = Some(format!("{} unreachable", network));
+    }
+    row
+}
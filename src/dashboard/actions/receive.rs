@@ -0,0 +1,56 @@
+use arboard::Clipboard;
+use crossterm::event::KeyCode;
+use crate::dashboard::toast::ToastLevel;
+use crate::dashboard::types::{AppMode, Dashboard, TransferTokenType};
+use crate::solana::pay::TransferRequest;
+use crate::solana::units;
+
+impl Dashboard {
+    pub fn open_receive(&mut self) {
+        self.receive_amount.clear();
+        self.receive_token = self.default_token_type();
+        self.mode = AppMode::ReceivePopup;
+        self.needs_clear = true;
+    }
+
+    /// What the QR code encodes: the bare wallet address, or a Solana Pay
+    /// request once an amount has been entered
+    pub fn receive_payload(&self) -> String {
+        let mint = match self.receive_token {
+            TransferTokenType::StandardQcoin => self.standard_mint,
+            TransferTokenType::Pqcoin => self.pq_mint,
+        };
+        let amount = match self.receive_amount.parse::<f64>() {
+            Ok(amount) if amount > 0.0 => units::to_base_units(amount, units::decimals(&mint)),
+            _ => return self.wallet.to_string(),
+        };
+        TransferRequest { amount: Some(amount), spl_token: Some(mint), ..TransferRequest::new(self.wallet) }.uri()
+    }
+
+    pub fn handle_receive_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Esc => {
+                self.mode = AppMode::Normal;
+                self.needs_clear = true;
+            }
+            KeyCode::Tab | KeyCode::Left | KeyCode::Right => {
+                self.receive_token = match self.receive_token {
+                    TransferTokenType::StandardQcoin => TransferTokenType::Pqcoin,
+                    TransferTokenType::Pqcoin => TransferTokenType::StandardQcoin,
+                };
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                let payload = self.receive_payload();
+                match Clipboard::new().and_then(|mut clipboard| clipboard.set_text(payload)) {
+                    Ok(()) => self.toast(ToastLevel::Success, "Copied to clipboard"),
+                    Err(e) => self.toast(ToastLevel::Error, format!("Failed to copy to clipboard: {}", e)),
+                }
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() || c == '.' => self.receive_amount.push(c),
+            KeyCode::Backspace => {
+                self.receive_amount.pop();
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::crypto::sphincs::SphincsKeyManager;
+use crate::vault_manager::VaultConfig;
 use crate::dashboard::types::{Dashboard, ActionStep, AppMode};
 use crate::dashboard::utils::suppress_output;
 
@@ -70,6 +71,9 @@ impl Dashboard {
         // Execute the register call
         let keypair_path = self.keypair_path.to_str().unwrap();
         let keypair_path_str = keypair_path.to_string();
+        let lock_mode = VaultConfig::load()
+            .map(|config| config.lock_mode_for_keypair(&keypair_path_str))
+            .unwrap_or_default();
 
         let result = suppress_output(|| {
             tokio::task::block_in_place(|| {
@@ -78,6 +82,7 @@ impl Dashboard {
                         wallet,
                         &keypair_path_str,
                         &sphincs_pubkey,
+                        lock_mode,
                     ).await
                 })
             })
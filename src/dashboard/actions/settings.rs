@@ -0,0 +1,129 @@
+use clap::ValueEnum;
+use crossterm::event::KeyCode;
+use crate::dashboard::toast::ToastLevel;
+use crate::dashboard::types::{
+    cycle_preset, AppMode, Dashboard, SettingsField, TransferTokenType, IDLE_LOCK_PRESETS, REFRESH_PRESETS, SNAPSHOT_PRESETS,
+    THRESHOLD_PRESETS,
+};
+use crate::theme::ThemeName;
+use crate::vault_manager::{DefaultToken, VaultConfig, DEFAULT_LARGE_TRANSFER_THRESHOLD, DEFAULT_REFRESH_INTERVAL};
+
+impl Dashboard {
+    pub fn open_settings(&mut self) {
+        // The theme is chosen at startup, so the saved name may differ from the one on screen
+        let config = VaultConfig::load().unwrap_or_default();
+        self.settings_theme = ThemeName::parse(config.theme.as_deref().unwrap_or("light")).unwrap_or(ThemeName::Light);
+        self.settings_selected = 0;
+        self.mode = AppMode::SettingsPopup;
+        self.needs_clear = true;
+    }
+
+    /// Token the transfer and receive forms start on
+    pub fn default_token_type(&self) -> TransferTokenType {
+        match self.settings.default_token() {
+            DefaultToken::Standard => TransferTokenType::StandardQcoin,
+            DefaultToken::Pq => TransferTokenType::Pqcoin,
+        }
+    }
+
+    /// How a settings row's current value is shown
+    pub fn setting_value(&self, field: SettingsField) -> String {
+        let on_off = |enabled: bool| if enabled { "on" } else { "off" }.to_string();
+        match field {
+            SettingsField::RefreshInterval => {
+                self.settings.refresh_interval.clone().unwrap_or_else(|| DEFAULT_REFRESH_INTERVAL.to_string())
+            }
+            SettingsField::DefaultToken => match self.settings.default_token() {
+                DefaultToken::Standard => "qcoin".to_string(),
+                DefaultToken::Pq => "pqcoin".to_string(),
+            },
+            SettingsField::Theme => self.settings_theme.name().to_string(),
+            SettingsField::ConfirmRecipients => on_off(self.settings.confirm_unknown_recipients()),
+            SettingsField::ConfirmQuit => on_off(self.settings.confirm_quit()),
+            SettingsField::SnapshotInterval => self.settings.snapshot_interval.clone().unwrap_or_else(|| "off".to_string()),
+            SettingsField::LargeTransferThreshold => self
+                .settings
+                .large_transfer_threshold
+                .clone()
+                .unwrap_or_else(|| DEFAULT_LARGE_TRANSFER_THRESHOLD.to_string()),
+            SettingsField::IdleLock => self.settings.idle_lock.clone().unwrap_or_else(|| "off".to_string()),
+        }
+    }
+
+    /// Step the selected setting forward or back, then save
+    fn change_setting(&mut self, step: isize) {
+        let field = SettingsField::ALL[self.settings_selected];
+        let current = self.setting_value(field);
+        match field {
+            SettingsField::RefreshInterval => {
+                self.settings.refresh_interval = Some(cycle_preset(&REFRESH_PRESETS, &current, step));
+            }
+            SettingsField::DefaultToken => {
+                self.settings.default_token = Some(match self.settings.default_token() {
+                    DefaultToken::Standard => DefaultToken::Pq,
+                    DefaultToken::Pq => DefaultToken::Standard,
+                });
+            }
+            SettingsField::Theme => {
+                let themes = ThemeName::value_variants();
+                let index = themes.iter().position(|theme| *theme == self.settings_theme).unwrap_or(0) as isize;
+                self.settings_theme = themes[(index + step).rem_euclid(themes.len() as isize) as usize];
+            }
+            SettingsField::ConfirmRecipients => {
+                self.settings.confirm_unknown_recipients = Some(!self.settings.confirm_unknown_recipients());
+            }
+            SettingsField::ConfirmQuit => self.settings.confirm_quit = Some(!self.settings.confirm_quit()),
+            SettingsField::SnapshotInterval => {
+                self.settings.snapshot_interval = Some(cycle_preset(&SNAPSHOT_PRESETS, &current, step));
+                self.restart_snapshot_poller();
+            }
+            SettingsField::LargeTransferThreshold => {
+                self.settings.large_transfer_threshold = Some(cycle_preset(&THRESHOLD_PRESETS, &current, step));
+            }
+            SettingsField::IdleLock => {
+                self.settings.idle_lock = Some(cycle_preset(&IDLE_LOCK_PRESETS, &current, step));
+                self.last_input = std::time::Instant::now();
+            }
+        }
+
+        let result = VaultConfig::load()
+            .and_then(|mut config| config.set_dashboard_settings(self.settings.clone(), self.settings_theme.name()));
+        if let Err(e) = result {
+            self.toast(ToastLevel::Error, format!("Failed to save settings: {:#}", e));
+        }
+    }
+
+    /// (Re)start background snapshots at the configured interval, or stop them
+    pub fn restart_snapshot_poller(&mut self) {
+        if let Some(poller) = self.snapshot_poller.take() {
+            poller.abort();
+        }
+        self.snapshot_poller = self.settings.snapshot_interval().map(|interval| self.spawn_snapshot_poller(interval));
+    }
+
+    /// Quit, or with `confirm_quit` on, ask for the key again first.
+    /// `armed` is whether the previous key press already asked.
+    pub fn request_quit(&mut self, armed: bool) {
+        if armed || !self.settings.confirm_quit() {
+            self.should_quit = true;
+        } else {
+            self.quit_pending = true;
+            self.status_message = Some("Press again to quit".to_string());
+        }
+    }
+
+    pub fn handle_settings_key(&mut self, code: KeyCode) {
+        let count = SettingsField::ALL.len();
+        match code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => {
+                self.mode = AppMode::Normal;
+                self.needs_clear = true;
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.settings_selected = (self.settings_selected + count - 1) % count,
+            KeyCode::Down | KeyCode::Char('j') => self.settings_selected = (self.settings_selected + 1) % count,
+            KeyCode::Left | KeyCode::Char('h') => self.change_setting(-1),
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Enter | KeyCode::Char(' ') => self.change_setting(1),
+            _ => {}
+        }
+    }
+}
@@ -1,7 +1,16 @@
 use std::str::FromStr;
 use solana_sdk::pubkey::Pubkey;
-use crate::dashboard::types::{Dashboard, AppMode, ActionStep, TransferInputField, TransferTokenType};
+use crossterm::event::KeyCode;
+use crate::dashboard::types::{
+    parse_recipient, transfer_confirmation_matches, Dashboard, AppMode, ActionStep, RecipientCheck, TransferInputField,
+    TransferTokenType,
+};
 use crate::dashboard::utils::suppress_output;
+use crate::recipients::RecipientHistory;
+use crate::solana::client::RecipientKind;
+use crate::solana::receipt::Operation;
+use crate::solana::units;
+use crate::vault_manager::VaultConfig;
 
 impl Dashboard {
     pub fn execute_transfer(&mut self) {
@@ -10,10 +19,97 @@ impl Dashboard {
         self.transfer_recipient.clear();
         self.transfer_amount.clear();
         self.transfer_focused_field = TransferInputField::TokenType;
-        self.transfer_token_type = TransferTokenType::StandardQcoin;
+        self.transfer_token_type = self.default_token_type();
         self.status_message = Some("Select token type and enter transfer details...".to_string());
     }
 
+    /// Balance of the token selected in the transfer form
+    pub fn transfer_balance(&self) -> Option<u64> {
+        match self.transfer_token_type {
+            TransferTokenType::StandardQcoin => self.standard_balance,
+            TransferTokenType::Pqcoin => self.pq_balance,
+        }
+    }
+
+    /// Fee and net amount for the transfer form's amount, when the selected
+    /// mint charges a transfer fee
+    pub fn transfer_fee_summary(&self) -> Option<String> {
+        let extensions = match self.transfer_token_type {
+            TransferTokenType::StandardQcoin => self.standard_extensions.as_ref(),
+            TransferTokenType::Pqcoin => self.pq_extensions.as_ref(),
+        }?;
+        let fee = extensions.transfer_fee?;
+        let decimals = units::decimals(&self.transfer_mint());
+        let amount = units::to_base_units(self.transfer_amount.parse::<f64>().ok()?, decimals);
+        Some(format!(
+            "Fee {} ({}) - recipient gets {}",
+            units::to_tokens(fee.fee(amount), decimals),
+            fee.rate(),
+            units::to_tokens(extensions.net_amount(amount), decimals)
+        ))
+    }
+
+    /// Decimals of `mint` for an amount about to be signed, looked up on the
+    /// network if they aren't cached. Unlike `units::decimals` there's no
+    /// fallback: a wrong guess would send the wrong amount.
+    pub fn signing_decimals(&self, mint: Pubkey) -> anyhow::Result<u8> {
+        let vault_client = &self.vault_client;
+        suppress_output(|| {
+            tokio::task::block_in_place(|| tokio::runtime::Handle::current().block_on(vault_client.mint_decimals(mint)))
+        })
+    }
+
+    /// Mint of the token selected in the transfer form
+    pub fn transfer_mint(&self) -> Pubkey {
+        match self.transfer_token_type {
+            TransferTokenType::StandardQcoin => self.standard_mint,
+            TransferTokenType::Pqcoin => self.pq_mint,
+        }
+    }
+
+    /// Re-check the recipient after an edit. Typos are caught offline; a
+    /// complete address is then looked up in the address book and on chain.
+    pub fn check_recipient(&mut self) {
+        self.transfer_recipient_confirmed = false;
+        let recipient = match parse_recipient(&self.transfer_recipient) {
+            Ok(recipient) => recipient,
+            Err(check) => {
+                self.transfer_recipient_check = check;
+                return;
+            }
+        };
+
+        let vault_client = &self.vault_client;
+        let kind = suppress_output(|| {
+            tokio::task::block_in_place(|| {
+                tokio::runtime::Handle::current().block_on(async {
+                    vault_client.recipient_kind(recipient).await
+                })
+            })
+        });
+        let label = VaultConfig::load().ok().and_then(|config| config.address_label(&recipient));
+
+        self.transfer_recipient_check = match (kind, label) {
+            (Ok(RecipientKind::TokenAccount { owner, .. }), _) => {
+                RecipientCheck::Invalid(format!("this is a token account, send to its owner {}", owner))
+            }
+            (Ok(RecipientKind::Program), _) => RecipientCheck::Invalid("this is a program, not a wallet".to_string()),
+            (_, Some(label)) => RecipientCheck::Known(label),
+            (Ok(RecipientKind::Wallet { exists: true }), None) => {
+                RecipientCheck::Unverified("Not in your address book".to_string())
+            }
+            (Ok(RecipientKind::Wallet { exists: false }), None) => {
+                RecipientCheck::Unverified("Not in your address book and never used on chain".to_string())
+            }
+            (Ok(RecipientKind::ProgramAccount { program }), None) => {
+                RecipientCheck::Unverified(format!("Not in your address book and owned by program {}", program))
+            }
+            (Err(_), None) => {
+                RecipientCheck::Unverified("Not in your address book (account lookup failed)".to_string())
+            }
+        };
+    }
+
     pub fn validate_transfer_inputs(&mut self) -> bool {
         // Check recipient
         if self.transfer_recipient.is_empty() {
@@ -21,9 +117,30 @@ impl Dashboard {
             return false;
         }
 
-        if Pubkey::from_str(&self.transfer_recipient).is_err() {
-            self.status_message = Some("Invalid recipient address".to_string());
-            return false;
+        match &self.transfer_recipient_check {
+            RecipientCheck::Incomplete => {
+                self.status_message = Some("Recipient address is incomplete".to_string());
+                return false;
+            }
+            RecipientCheck::Invalid(reason) => {
+                self.status_message = Some(format!("Invalid recipient: {}", reason));
+                return false;
+            }
+            RecipientCheck::Unverified(reason) if !self.transfer_recipient_confirmed && self.settings.confirm_unknown_recipients() => {
+                let never_sent = parse_recipient(&self.transfer_recipient)
+                    .is_ok_and(|recipient| !RecipientHistory::load().contains(&recipient));
+                if never_sent {
+                    // A first transfer gets the interstitial instead of a second Enter
+                    self.transfer_confirm_return = self.mode;
+                    self.mode = AppMode::NewRecipientPopup;
+                    self.needs_clear = true;
+                } else {
+                    self.transfer_recipient_confirmed = true;
+                    self.status_message = Some(format!("⚠ {} - press Enter again to send anyway", reason));
+                }
+                return false;
+            }
+            RecipientCheck::Unverified(_) | RecipientCheck::Known(_) => {}
         }
 
         // Check amount
@@ -43,9 +160,86 @@ impl Dashboard {
             return false;
         }
 
+        // Checked again by the client; this just reports it before anything is built
+        let mint = self.transfer_mint();
+        let decimals = match self.signing_decimals(mint) {
+            Ok(decimals) => decimals,
+            Err(e) => {
+                self.status_message = Some(format!("❌ {:#}", e));
+                return false;
+            }
+        };
+        let amount_base_units = units::to_base_units(amount, decimals);
+        let policy = self.vault_client.policy();
+        if let Ok(recipient) = Pubkey::from_str(&self.transfer_recipient) {
+            if let Err(e) = policy.check_transfer(&recipient, &mint, amount_base_units) {
+                self.status_message = Some(format!("⛔ {}", e));
+                return false;
+            }
+        }
+
+        // Large transfers go through the typed confirmation, which submits them itself
+        let over_threshold = self.settings.large_transfer_threshold().is_some_and(|threshold| amount > threshold);
+        if over_threshold || policy.requires_confirmation(&mint, amount_base_units) {
+            self.transfer_confirm_input.clear();
+            self.transfer_confirm_return = self.mode;
+            self.mode = AppMode::TransferConfirmPopup;
+            self.needs_clear = true;
+            return false;
+        }
+
         true
     }
 
+    pub fn handle_new_recipient_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Esc => {
+                self.mode = self.transfer_confirm_return;
+                self.status_message = Some("Transfer not sent".to_string());
+                self.needs_clear = true;
+            }
+            KeyCode::Enter => {
+                self.transfer_recipient_confirmed = true;
+                self.mode = self.transfer_confirm_return;
+                self.needs_clear = true;
+                // The rest of the checks may still stop it or open the large transfer confirmation
+                if self.validate_transfer_inputs() {
+                    self.pending_transfer = true;
+                    self.mode = AppMode::Normal;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn handle_transfer_confirm_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Esc => {
+                self.mode = self.transfer_confirm_return;
+                self.transfer_confirm_input.clear();
+                self.status_message = Some("Transfer not confirmed".to_string());
+                self.needs_clear = true;
+            }
+            KeyCode::Char(c) => self.transfer_confirm_input.push(c),
+            KeyCode::Backspace => {
+                self.transfer_confirm_input.pop();
+            }
+            KeyCode::Enter => {
+                if transfer_confirmation_matches(&self.transfer_confirm_input, &self.transfer_amount, &self.transfer_recipient) {
+                    // Sent after the next draw, like a transfer from the popup form
+                    self.pending_transfer = true;
+                    self.mode = AppMode::Normal;
+                } else {
+                    self.mode = self.transfer_confirm_return;
+                    self.status_message = Some("❌ Confirmation did not match - transfer cancelled".to_string());
+                }
+                self.transfer_confirm_input.clear();
+                self.needs_clear = true;
+            }
+            _ => {}
+        }
+    }
+
     pub fn perform_transfer_action(&mut self) {
         // Check which token type is selected
         let (mint, balance, token_name, requires_unlock) = match self.transfer_token_type {
@@ -105,7 +299,14 @@ impl Dashboard {
             }
         };
 
-        let amount_base_units = (amount_qdum * 1_000_000.0) as u64;
+        let decimals = match self.signing_decimals(mint) {
+            Ok(decimals) => decimals,
+            Err(e) => {
+                self.status_message = Some(format!("❌ {:#}", e));
+                return;
+            }
+        };
+        let amount_base_units = units::to_base_units(amount_qdum, decimals);
 
         if amount_base_units == 0 {
             self.status_message = Some("❌ Amount must be greater than 0".to_string());
@@ -174,7 +375,7 @@ impl Dashboard {
         // Check if user has sufficient balance
         if let Some(bal) = balance {
             if bal < amount_base_units {
-                let balance_qdum = bal as f64 / 1_000_000.0;
+                let balance_qdum = units::to_tokens(bal, units::decimals(&self.transfer_mint()));
                 self.mode = AppMode::Normal;
                 self.action_steps.clear();
                 self.action_steps.push(ActionStep::Error(format!("❌ Insufficient {} balance!", token_name)));
@@ -219,13 +420,15 @@ impl Dashboard {
         let result = suppress_output(|| {
             tokio::task::block_in_place(|| {
                 tokio::runtime::Handle::current().block_on(async {
-                    vault_client.transfer_tokens_with_confirm(
+                    let result = vault_client.transfer_tokens_with_confirm(
                         &keypair,
                         recipient,
                         mint,
                         amount_base_units,
                         true,  // skip_confirm = true (no interactive prompt)
-                    ).await
+                    ).await;
+                    crate::notify::finished(Operation::Transfer, &result).await;
+                    result
                 })
             })
         });
@@ -1,19 +1,86 @@
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use crossterm::event::KeyCode;
+use solana_sdk::signature::{read_keypair_file, Signer};
+use crate::dashboard::toast::ToastLevel;
 use crate::dashboard::types::{Dashboard, AppMode};
-use crate::solana::client::VaultClient;
+use crate::solana::client::{UnlockOptions, UnlockSession, UnlockSigner, VaultClient};
 use crate::crypto::sphincs::SphincsKeyManager;
+use crate::solana::receipt::Operation;
+use crate::vault_manager::VaultConfig;
 
 impl Dashboard {
     pub fn execute_unlock(&mut self) {
+        // An unlock that was cut short can continue instead of starting over
+        if self.offer_unlock_resume() {
+            return;
+        }
+        self.start_unlock(0);
+    }
+
+    fn start_unlock(&mut self, resume_after: usize) {
         // Stay in Normal mode - will render splash animation in content area
+        self.mode = AppMode::Normal;
+        self.needs_clear = true;
         self.action_steps.clear();
         self.status_message = Some("Unlocking...".to_string());
         // Execute immediately
-        self.perform_unlock_action();
+        self.perform_unlock_action(resume_after);
+    }
+
+    /// Open the resume prompt if an earlier unlock of this wallet got part
+    /// way through; a session whose vault is no longer locked is dropped
+    pub fn offer_unlock_resume(&mut self) -> bool {
+        let session = match UnlockSession::load(&self.wallet) {
+            Ok(Some(session)) => session,
+            _ => return false,
+        };
+        if self.vault_status.as_ref().is_some_and(|status| !status.is_locked) {
+            let _ = UnlockSession::clear(&self.wallet);
+            return false;
+        }
+        if session.completed_step == 0 {
+            return false;
+        }
+        self.unlock_session = Some(session);
+        self.mode = AppMode::UnlockResumePopup;
+        self.needs_clear = true;
+        true
+    }
+
+    pub fn handle_unlock_resume_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Enter => {
+                let resume_after = self.unlock_session.take().map_or(0, |session| session.completed_step);
+                self.start_unlock(resume_after);
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.unlock_session = None;
+                if let Err(e) = UnlockSession::clear(&self.wallet) {
+                    self.toast(ToastLevel::Warning, format!("Failed to remove unlock session: {:#}", e));
+                }
+                self.start_unlock(0);
+            }
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                self.unlock_session = None;
+                self.mode = AppMode::Normal;
+                self.needs_clear = true;
+                match UnlockSession::clear(&self.wallet) {
+                    Ok(()) => self.toast(ToastLevel::Info, "Interrupted unlock discarded"),
+                    Err(e) => self.toast(ToastLevel::Error, format!("Failed to remove unlock session: {:#}", e)),
+                }
+            }
+            KeyCode::Esc => {
+                // Kept on disk; the next unlock asks again
+                self.unlock_session = None;
+                self.mode = AppMode::Normal;
+                self.needs_clear = true;
+            }
+            _ => {}
+        }
     }
 
-    pub fn perform_unlock_action(&mut self) {
+    pub fn perform_unlock_action(&mut self, resume_after: usize) {
         // Flag to indicate unlock is complete
         let unlock_complete = Arc::new(AtomicBool::new(false));
         let unlock_complete_clone = Arc::clone(&unlock_complete);
@@ -21,6 +88,9 @@ impl Dashboard {
 
         // Spawn unlock operation in background thread
         let keypair_path_str = self.keypair_path.to_str().unwrap().to_string();
+        let lock_mode = VaultConfig::load()
+            .map(|config| config.lock_mode_for_keypair(&keypair_path_str))
+            .unwrap_or_default();
         let sphincs_public_key_path = self.sphincs_public_key_path.clone();
         let sphincs_private_key_path = self.sphincs_private_key_path.clone();
         let wallet = self.wallet;
@@ -93,7 +163,7 @@ impl Dashboard {
                 };
 
                 // Create VaultClient
-                let vault_client = match VaultClient::new(&rpc_url, program_id) {
+                let vault_client = match VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build() {
                     Ok(client) => client,
                     Err(_) => {
                         unsafe {
@@ -106,14 +176,29 @@ impl Dashboard {
                     }
                 };
 
+                // Record each step, so a crash or closed terminal can resume from it
+                let progress = read_keypair_file(&keypair_path_str).ok().map(|keypair| {
+                    let mut session = vault_client.unlock_session(wallet, keypair.pubkey(), sphincs_pubkey.algorithm, &sphincs_pubkey.bytes);
+                    Box::new(move |step: usize, total: usize, _message: String| {
+                        session.completed_step = step.saturating_sub(1);
+                        session.total_steps = total;
+                        let _ = session.save();
+                    }) as crate::solana::client::ProgressCallback
+                });
+
                 // Call unlock_vault
-                let _result = vault_client.unlock_vault(
+                let result = vault_client.unlock_vault(
                     wallet,
                     &keypair_path_str,
-                    &sphincs_privkey,
+                    UnlockSigner::PrivateKey(&sphincs_privkey),
                     &sphincs_pubkey,
-                    None,
+                    UnlockOptions { lock_mode, delay_slots: 0, resume_after },
+                    progress,
                 ).await;
+                if result.is_ok() {
+                    let _ = UnlockSession::clear(&wallet);
+                }
+                crate::notify::finished(Operation::Unlock, &result).await;
 
                 // Restore stdout/stderr before task ends
                 unsafe {
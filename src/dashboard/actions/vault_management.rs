@@ -2,7 +2,7 @@ use std::path::PathBuf;
 use std::fs;
 use solana_sdk::signature::{read_keypair_file, Signer, Keypair};
 use std::io::Write;
-use crate::dashboard::types::{Dashboard, AppMode, ActionStep, VaultManagementMode};
+use crate::dashboard::types::{Dashboard, AppMode, ActionStep, VaultEditField, VaultManagementMode};
 use crate::vault_manager::VaultConfig;
 use crate::crypto::sphincs::SphincsKeyManager;
 
@@ -348,16 +348,7 @@ impl Dashboard {
             return;
         }
 
-        let home = match dirs::home_dir() {
-            Some(h) => h,
-            None => {
-                self.action_steps.push(ActionStep::Error("Could not determine home directory".to_string()));
-                self.status_message = Some("❌ Failed to create vault".to_string());
-                self.mode = AppMode::ResultPopup;
-                return;
-            }
-        };
-        let qdum_dir = home.join(".qdum");
+        let qdum_dir = crate::paths::data_dir();
         let vault_dir = qdum_dir.join(&self.new_vault_name);
 
         // Create vault directory
@@ -493,4 +484,101 @@ impl Dashboard {
             }
         }
     }
+
+    /// Navigate to Vaults (index 11) with the vault list loaded and the active vault selected
+    pub fn open_vault_list(&mut self) {
+        self.selected_action = 11;
+
+        if let Ok(config) = VaultConfig::load() {
+            self.vault_list = config.list_vaults().into_iter().cloned().collect();
+
+            // Find active vault and select it
+            if let Some(active_name) = &config.active_vault {
+                if let Some(i) = self.vault_list.iter().position(|vault| &vault.name == active_name) {
+                    self.selected_vault_index = i;
+                }
+            }
+        } else {
+            self.vault_list = Vec::new();
+        }
+
+        // Start in list mode
+        self.vault_management_mode = VaultManagementMode::List;
+    }
+
+    pub fn begin_vault_edit(&mut self, field: VaultEditField) {
+        if self.selected_vault_index >= self.vault_list.len() {
+            return;
+        }
+
+        let vault = &self.vault_list[self.selected_vault_index];
+        self.vault_edit_field = field;
+        self.vault_edit_target = vault.name.clone();
+        self.vault_edit_input = match field {
+            VaultEditField::Name => vault.name.clone(),
+            VaultEditField::Description => vault.description.clone().unwrap_or_default(),
+        };
+        self.mode = AppMode::VaultEditPopup;
+        self.needs_clear = true;
+        self.status_message = Some(match field {
+            VaultEditField::Name => format!("Enter a new name for '{}'", vault.name),
+            VaultEditField::Description => format!("Edit description for '{}'", vault.name),
+        });
+    }
+
+    /// Leave the edit popup, returning to wherever the vault list was shown
+    pub fn end_vault_edit(&mut self) {
+        self.mode = if self.in_vault_list { AppMode::Normal } else { AppMode::VaultSwitchPopup };
+        self.vault_management_mode = VaultManagementMode::List;
+        self.vault_edit_target.clear();
+        self.vault_edit_input.clear();
+        self.needs_clear = true;
+    }
+
+    pub fn perform_vault_edit(&mut self) {
+        let target = self.vault_edit_target.clone();
+        let input = self.vault_edit_input.trim().to_string();
+
+        let mut config = match VaultConfig::load() {
+            Ok(c) => c,
+            Err(e) => {
+                self.status_message = Some(format!("❌ Failed to load vault config: {}", e));
+                self.end_vault_edit();
+                return;
+            }
+        };
+
+        let result = match self.vault_edit_field {
+            VaultEditField::Name => {
+                if input.is_empty() {
+                    self.status_message = Some("❌ Vault name cannot be empty".to_string());
+                    return;
+                }
+                if input == target {
+                    self.status_message = Some("Name unchanged".to_string());
+                    self.end_vault_edit();
+                    return;
+                }
+                config.rename_vault(&target, input.clone())
+                    .map(|_| format!("✅ Renamed vault '{}' → '{}'", target, input))
+            }
+            VaultEditField::Description => {
+                let description = if input.is_empty() { None } else { Some(input) };
+                config.update_description(&target, description)
+                    .map(|_| format!("✅ Updated description for '{}'", target))
+            }
+        };
+
+        match result {
+            Ok(message) => {
+                self.status_message = Some(message);
+                self.vault_list = config.list_vaults().into_iter().cloned().collect();
+            }
+            Err(e) => {
+                self.status_message = Some(format!("❌ {}", e));
+            }
+        }
+
+        self.end_vault_edit();
+    }
 }
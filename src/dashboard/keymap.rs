@@ -0,0 +1,218 @@
+//! Remappable dashboard keys
+//!
+//! The `keybindings` section of the vault config can move lock, unlock,
+//! transfer, chart, vault switch and quit to other keys. Everything else
+//! (navigation, help, refresh, the remaining action shortcuts) is fixed, so a
+//! binding may not reuse one of those keys or another binding's key.
+
+use anyhow::{bail, Result};
+use crossterm::event::KeyCode;
+
+use crate::vault_manager::Keybindings;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoundAction {
+    Lock,
+    Unlock,
+    Transfer,
+    Chart,
+    VaultSwitch,
+    Quit,
+}
+
+impl BoundAction {
+    const ALL: [BoundAction; 6] = [
+        BoundAction::Lock,
+        BoundAction::Unlock,
+        BoundAction::Transfer,
+        BoundAction::Chart,
+        BoundAction::VaultSwitch,
+        BoundAction::Quit,
+    ];
+
+    /// Key in the config's `keybindings` section
+    pub fn config_name(self) -> &'static str {
+        match self {
+            BoundAction::Lock => "lock",
+            BoundAction::Unlock => "unlock",
+            BoundAction::Transfer => "transfer",
+            BoundAction::Chart => "chart",
+            BoundAction::VaultSwitch => "vault_switch",
+            BoundAction::Quit => "quit",
+        }
+    }
+
+    fn default_key(self) -> KeyCode {
+        KeyCode::Char(match self {
+            BoundAction::Lock => 'l',
+            BoundAction::Unlock => 'u',
+            BoundAction::Transfer => 't',
+            BoundAction::Chart => 'm',
+            BoundAction::VaultSwitch => 'v',
+            BoundAction::Quit => 'q',
+        })
+    }
+
+    fn configured(self, bindings: &Keybindings) -> Option<&str> {
+        match self {
+            BoundAction::Lock => bindings.lock.as_deref(),
+            BoundAction::Unlock => bindings.unlock.as_deref(),
+            BoundAction::Transfer => bindings.transfer.as_deref(),
+            BoundAction::Chart => bindings.chart.as_deref(),
+            BoundAction::VaultSwitch => bindings.vault_switch.as_deref(),
+            BoundAction::Quit => bindings.quit.as_deref(),
+        }
+    }
+}
+
+/// Keys the dashboard handles itself in normal mode, with what they do
+const FIXED_KEYS: [(KeyCode, &str); 25] = [
+    (KeyCode::Char('h'), "help"),
+    (KeyCode::Char('?'), "help"),
+    (KeyCode::F(1), "help"),
+    (KeyCode::Char('r'), "refresh"),
+    (KeyCode::Char('s'), "portfolio"),
+    (KeyCode::Char('g'), "register"),
+    (KeyCode::Char('a'), "airdrop"),
+    (KeyCode::Char('p'), "airdrop stats"),
+    (KeyCode::Char('x'), "close"),
+    (KeyCode::Char('c'), "copy wallet"),
+    (KeyCode::Char('i'), "receive"),
+    (KeyCode::Char(','), "settings"),
+    (KeyCode::Char('n'), "network"),
+    (KeyCode::Char(' '), "pause refresh"),
+    (KeyCode::Char('o'), "all vaults"),
+    (KeyCode::Char('b'), "leaderboard"),
+    (KeyCode::Char('w'), "wrap"),
+    (KeyCode::Char('e'), "unwrap"),
+    (KeyCode::Char('k'), "navigate up"),
+    (KeyCode::Char('j'), "navigate down"),
+    (KeyCode::Up, "navigate up"),
+    (KeyCode::Down, "navigate down"),
+    (KeyCode::Enter, "execute"),
+    (KeyCode::Esc, "quit"),
+    (KeyCode::Tab, "form navigation"),
+];
+
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    keys: Vec<(BoundAction, KeyCode)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self { keys: BoundAction::ALL.iter().map(|&action| (action, action.default_key())).collect() }
+    }
+}
+
+impl Keymap {
+    /// Defaults with the configured keys applied; fails on an unparseable key
+    /// or two things sharing one
+    pub fn from_config(bindings: &Keybindings) -> Result<Self> {
+        let mut keys: Vec<(BoundAction, KeyCode)> = Vec::new();
+        for action in BoundAction::ALL {
+            let key = match action.configured(bindings) {
+                Some(key) => parse_key(key).map_err(|e| anyhow::anyhow!("keybindings.{}: {}", action.config_name(), e))?,
+                None => action.default_key(),
+            };
+            if let Some((_, used_by)) = FIXED_KEYS.iter().find(|(fixed, _)| *fixed == key) {
+                bail!("keybindings.{}: {} is already used for {}", action.config_name(), key_label(key), used_by);
+            }
+            if let Some((other, _)) = keys.iter().find(|(_, existing)| *existing == key) {
+                bail!(
+                    "keybindings.{}: {} is already bound to {}",
+                    action.config_name(),
+                    key_label(key),
+                    other.config_name()
+                );
+            }
+            keys.push((action, key));
+        }
+        Ok(Self { keys })
+    }
+
+    /// The action `code` is bound to; letters match in either case
+    pub fn action(&self, code: KeyCode) -> Option<BoundAction> {
+        let code = match code {
+            KeyCode::Char(c) => KeyCode::Char(c.to_ascii_lowercase()),
+            other => other,
+        };
+        self.keys.iter().find(|(_, key)| *key == code).map(|(action, _)| *action)
+    }
+
+    /// How the key for `action` is shown in the help text and footer
+    pub fn label(&self, action: BoundAction) -> String {
+        self.keys
+            .iter()
+            .find(|(bound, _)| *bound == action)
+            .map_or_else(String::new, |(_, key)| key_label(*key))
+    }
+}
+
+/// A single character ("l", "+") or a named key ("f5", "delete", "pageup")
+fn parse_key(key: &str) -> Result<KeyCode> {
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if c.is_whitespace() || c.is_control() {
+            bail!("'{}' can't be bound", key.escape_default());
+        }
+        return Ok(KeyCode::Char(c.to_ascii_lowercase()));
+    }
+    let lower = key.to_ascii_lowercase();
+    Ok(match lower.as_str() {
+        "esc" | "escape" => KeyCode::Esc,
+        "enter" => KeyCode::Enter,
+        "tab" => KeyCode::Tab,
+        "space" => KeyCode::Char(' '),
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        _ => match lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+            Some(n @ 1..=12) => KeyCode::F(n),
+            _ => bail!("unknown key '{}' (use a single character or a name like f5, delete, pageup)", key),
+        },
+    })
+}
+
+fn key_label(key: KeyCode) -> String {
+    match key {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_ascii_uppercase().to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        other => format!("{:?}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keymap_from_config() {
+        let keymap = Keymap::from_config(&Keybindings {
+            lock: Some("F5".to_string()),
+            quit: Some("Z".to_string()),
+            ..Keybindings::default()
+        })
+        .unwrap();
+        assert_eq!(keymap.action(KeyCode::F(5)), Some(BoundAction::Lock));
+        assert_eq!(keymap.action(KeyCode::Char('z')), Some(BoundAction::Quit));
+        assert_eq!(keymap.action(KeyCode::Char('U')), Some(BoundAction::Unlock));
+        assert_eq!(keymap.action(KeyCode::Char('l')), None);
+        assert_eq!(keymap.label(BoundAction::Lock), "F5");
+
+        // Clashes with a fixed key, with another binding, and nonsense
+        let clash = |bindings: Keybindings| Keymap::from_config(&bindings).unwrap_err().to_string();
+        assert!(clash(Keybindings { lock: Some("r".to_string()), ..Keybindings::default() }).contains("refresh"));
+        assert!(clash(Keybindings { chart: Some("u".to_string()), ..Keybindings::default() }).contains("unlock"));
+        assert!(clash(Keybindings { quit: Some("f13".to_string()), ..Keybindings::default() }).contains("unknown key"));
+    }
+}
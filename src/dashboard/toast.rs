@@ -0,0 +1,88 @@
+//! Transient notifications
+//!
+//! Toasts stack in the top-right corner and disappear on their own, for
+//! events that don't need the user to stop what they're doing (data
+//! refreshed, address copied, RPC trouble, snapshot recorded). Anything that
+//! needs an answer still goes through a popup.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::dashboard::types::Dashboard;
+
+/// How long a toast stays on screen
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// Older toasts are dropped once this many are showing
+const MAX_TOASTS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub level: ToastLevel,
+    pub message: String,
+    shown_at: Instant,
+}
+
+#[derive(Debug, Default)]
+pub struct Toasts {
+    queue: VecDeque<Toast>,
+}
+
+impl Toasts {
+    pub fn push(&mut self, level: ToastLevel, message: impl Into<String>) {
+        let message = message.into();
+        // Repeating the newest toast just restarts its timer
+        self.queue.retain(|toast| toast.message != message);
+        if self.queue.len() == MAX_TOASTS {
+            self.queue.pop_front();
+        }
+        self.queue.push_back(Toast { level, message, shown_at: Instant::now() });
+    }
+
+    /// Drop toasts that have been up for long enough
+    pub fn expire(&mut self) {
+        self.expire_at(Instant::now());
+    }
+
+    fn expire_at(&mut self, now: Instant) {
+        self.queue.retain(|toast| now.duration_since(toast.shown_at) < TOAST_DURATION);
+    }
+
+    /// Showing toasts, oldest first
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Toast> {
+        self.queue.iter()
+    }
+}
+
+impl Dashboard {
+    pub fn toast(&self, level: ToastLevel, message: impl Into<String>) {
+        self.toasts.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).push(level, message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toasts_cap_dedupe_and_expire() {
+        let mut toasts = Toasts::default();
+        for i in 0..MAX_TOASTS + 2 {
+            toasts.push(ToastLevel::Info, format!("toast {}", i));
+        }
+        toasts.push(ToastLevel::Info, "toast 3");
+        let messages: Vec<&str> = toasts.iter().map(|toast| toast.message.as_str()).collect();
+        assert_eq!(messages, ["toast 2", "toast 4", "toast 5", "toast 3"]);
+
+        toasts.expire_at(Instant::now() + TOAST_DURATION);
+        assert_eq!(toasts.iter().count(), 0);
+    }
+}
@@ -1,9 +1,14 @@
 use solana_sdk::pubkey::Pubkey;
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::sync::atomic::AtomicBool;
 
-use crate::solana::client::VaultClient;
+use crate::applog::{Level, LogLine};
+use crate::dashboard::keymap::Keymap;
+use crate::dashboard::toast::Toasts;
+use crate::solana::client::{ClusterStatus, Leaderboard, MintExtensions, UnlockSession, VaultClient, VaultFootprint};
+use crate::theme::ThemeName;
+use crate::vault_manager::{DashboardSettings, NetworkProfile};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SelectedAction {
@@ -29,8 +34,20 @@ pub enum AppMode {
     AirdropStatsPopup,
     VaultSwitchPopup,
     DeleteConfirmPopup,
+    VaultEditPopup,
     CloseConfirmPopup,
     ChartPopup,
+    PortfolioPopup,
+    LogViewer,
+    ReceivePopup,
+    SettingsPopup,
+    NetworkPopup,
+    TransferConfirmPopup,
+    NewRecipientPopup,
+    IdleLockPopup,
+    UnlockResumePopup,
+    VaultComparePopup,
+    LeaderboardPopup,
     ResultPopup,
 }
 
@@ -41,6 +58,53 @@ pub enum TransferInputField {
     Amount,
 }
 
+/// What the amount shortcuts fill in: `m` for MAX, `h` for HALF and `q` for
+/// 25% of `balance` (base units of a mint with `decimals`). Network fees are
+/// paid in SOL, so the whole token balance can move.
+pub fn amount_shortcut(key: char, balance: u64, decimals: u8) -> Option<String> {
+    let amount = match key.to_ascii_lowercase() {
+        'm' => balance,
+        'h' => balance / 2,
+        'q' => balance / 4,
+        _ => return None,
+    };
+    let unit = 10u64.pow(decimals as u32);
+    let decimal = format!("{}.{:0width$}", amount / unit, amount % unit, width = decimals as usize);
+    Some(decimal.trim_end_matches('0').trim_end_matches('.').to_string())
+}
+
+/// Verdict on the transfer recipient, refreshed as it's typed
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum RecipientCheck {
+    /// Empty, or still short enough to be a partly typed address
+    #[default]
+    Incomplete,
+    /// Can't be sent to; Enter is refused
+    Invalid(String),
+    /// In the address book or one of this machine's vaults
+    Known(String),
+    /// A valid address that isn't in the address book; Enter needs a second press
+    Unverified(String),
+}
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The offline half of the recipient check: base58 and length only
+pub fn parse_recipient(input: &str) -> Result<Pubkey, RecipientCheck> {
+    if let Some(bad) = input.chars().find(|c| !BASE58_ALPHABET.contains(*c)) {
+        return Err(RecipientCheck::Invalid(format!("'{}' is not a base58 character", bad)));
+    }
+    if input.len() > 44 {
+        return Err(RecipientCheck::Invalid(format!("too long for an address ({} characters, max 44)", input.len())));
+    }
+    let bytes = bs58::decode(input).into_vec().unwrap_or_default();
+    match bytes.len() {
+        32 => Ok(Pubkey::try_from(bytes.as_slice()).expect("32 bytes")),
+        len if len < 32 && input.len() < 44 => Err(RecipientCheck::Incomplete),
+        len => Err(RecipientCheck::Invalid(format!("decodes to {} bytes, an address is 32", len))),
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TransferTokenType {
     StandardQcoin,
@@ -53,6 +117,12 @@ pub enum VaultManagementMode {
     Create,    // Creating new vault
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VaultEditField {
+    Name,
+    Description,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ChartType {
     LockedAmount,
@@ -68,6 +138,65 @@ impl ChartType {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChartStyle {
+    Line,
+    Candles,  // OHLC bars per time bucket
+}
+
+impl ChartStyle {
+    pub fn to_string(self) -> &'static str {
+        match self {
+            ChartStyle::Line => "LINE",
+            ChartStyle::Candles => "OHLC",
+        }
+    }
+}
+
+/// One time bucket of lock history
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candle {
+    pub start: chrono::DateTime<chrono::Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+/// Bucket sizes candles snap to, in seconds
+const CANDLE_INTERVALS: [i64; 9] = [60, 5 * 60, 15 * 60, 3600, 4 * 3600, 6 * 3600, 12 * 3600, 86400, 7 * 86400];
+
+/// Smallest bucket size from [`CANDLE_INTERVALS`] that fits `span_secs` into
+/// at most `max_candles` buckets
+pub fn candle_interval(span_secs: i64, max_candles: usize) -> i64 {
+    let needed = span_secs / max_candles.max(1) as i64;
+    CANDLE_INTERVALS.iter().copied().find(|&interval| interval >= needed).unwrap_or(CANDLE_INTERVALS[8])
+}
+
+/// Bucket time-ordered entries into `interval_secs` candles of `value`
+pub fn candles(entries: &[&LockHistoryEntry], interval_secs: i64, value: impl Fn(&LockHistoryEntry) -> f64) -> Vec<Candle> {
+    let mut candles: Vec<Candle> = Vec::new();
+    for entry in entries {
+        let Ok(time) = chrono::DateTime::parse_from_rfc3339(&entry.timestamp) else {
+            continue;
+        };
+        let seconds = time.timestamp();
+        let Some(start) = chrono::DateTime::from_timestamp(seconds - seconds.rem_euclid(interval_secs), 0) else {
+            continue;
+        };
+        let v = value(entry);
+        match candles.last_mut() {
+            Some(candle) if candle.start == start => {
+                candle.high = candle.high.max(v);
+                candle.low = candle.low.min(v);
+                candle.close = v;
+            }
+            _ => candles.push(Candle { start, open: v, high: v, low: v, close: v }),
+        }
+    }
+    candles
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ChartTimeframe {
     FiveMinutes,
@@ -117,11 +246,219 @@ pub struct VaultStatus {
     pub pda: Option<Pubkey>,
 }
 
+/// On-chain state fetched together on each dashboard refresh
+pub struct VaultSnapshot {
+    pub status: anyhow::Result<(bool, Pubkey)>,
+    pub balance: anyhow::Result<u64>,
+    pub pq_balance: anyhow::Result<u64>,
+    pub standard_balance: anyhow::Result<u64>,
+    pub next_airdrop: anyhow::Result<Option<i64>>,
+    pub pq_extensions: anyhow::Result<MintExtensions>,
+    pub standard_extensions: anyhow::Result<MintExtensions>,
+}
+
+/// One vault's row in the all-vaults portfolio view
+#[derive(Debug, Clone)]
+pub struct PortfolioRow {
+    pub name: String,
+    pub is_active: bool,
+    pub network: String,
+    /// `None` when the vault isn't registered
+    pub is_locked: Option<bool>,
+    pub pq_balance: Option<u64>,
+    pub standard_balance: Option<u64>,
+    pub sol_lamports: Option<u64>,
+    /// Why the vault couldn't be queried at all
+    pub error: Option<String>,
+}
+
+/// One side of the vault comparison view
+#[derive(Debug, Clone)]
+pub struct VaultComparison {
+    pub row: PortfolioRow,
+    pub wallet: Option<String>,
+    /// `None` when the activity and PDA lookups failed
+    pub footprint: Option<VaultFootprint>,
+}
+
+/// Cluster status for the status bar, kept fresh by a background poller
+#[derive(Debug, Clone, Default)]
+pub struct NetworkStatus {
+    /// Last successful poll; kept when a later one fails
+    pub cluster: Option<ClusterStatus>,
+    pub error: Option<String>,
+}
+
+/// Devnet mints the dashboard uses unless the active vault or network pins others
+pub const DEVNET_PQ_MINT: &str = "Cj5wfxiGdaxdymPjxVbt4HXJbx1H9PN3fSbnjThMJxEv";
+pub const DEVNET_STANDARD_MINT: &str = "GS2tyNMdpiKnQ9AxFhB74SbzYF7NmoTREoKZC6pzxds7";
+
+/// Smallest terminal the dashboard draws in; below this it asks to be enlarged
+pub const MIN_WIDTH: u16 = 80;
+pub const MIN_HEIGHT: u16 = 24;
+
+/// Below this size the compact layout is used and popups fill the screen
+pub const COMPACT_WIDTH: u16 = 120;
+pub const COMPACT_HEIGHT: u16 = 34;
+
+/// Heights of the main screen's fixed rows; the content area gets the rest
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScreenLayout {
+    pub margin: u16,
+    pub header: u16,
+    pub account: u16,
+    pub footer: u16,
+}
+
+impl ScreenLayout {
+    /// The layout for a `width` x `height` terminal, or `None` if it's too
+    /// small for any. Short terminals lose the margin and the header subtitle.
+    pub fn for_size(width: u16, height: u16) -> Option<Self> {
+        if width < MIN_WIDTH || height < MIN_HEIGHT {
+            return None;
+        }
+        if height < COMPACT_HEIGHT {
+            Some(Self { margin: 0, header: 3, account: 6, footer: 7 })
+        } else {
+            Some(Self { margin: 2, header: 5, account: 6, footer: 7 })
+        }
+    }
+
+    pub fn is_compact(&self) -> bool {
+        self.margin == 0
+    }
+}
+
+/// Rows of the settings screen (`,`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingsField {
+    RefreshInterval,
+    DefaultToken,
+    Theme,
+    ConfirmRecipients,
+    ConfirmQuit,
+    SnapshotInterval,
+    LargeTransferThreshold,
+    IdleLock,
+}
+
+impl SettingsField {
+    pub const ALL: [SettingsField; 8] = [
+        SettingsField::RefreshInterval,
+        SettingsField::DefaultToken,
+        SettingsField::Theme,
+        SettingsField::ConfirmRecipients,
+        SettingsField::ConfirmQuit,
+        SettingsField::SnapshotInterval,
+        SettingsField::LargeTransferThreshold,
+        SettingsField::IdleLock,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SettingsField::RefreshInterval => "Auto-refresh",
+            SettingsField::DefaultToken => "Default token",
+            SettingsField::Theme => "Theme",
+            SettingsField::ConfirmRecipients => "Confirm unknown recipients",
+            SettingsField::ConfirmQuit => "Confirm quit",
+            SettingsField::SnapshotInterval => "Network snapshots",
+            SettingsField::LargeTransferThreshold => "Type to confirm transfers over",
+            SettingsField::IdleLock => "Lock when idle for",
+        }
+    }
+}
+
+/// Choices the settings screen steps through for the refresh interval
+pub const REFRESH_PRESETS: [&str; 6] = ["off", "10s", "30s", "1m", "5m", "15m"];
+
+/// Choices the settings screen steps through for the snapshot interval
+pub const SNAPSHOT_PRESETS: [&str; 6] = ["off", "5m", "15m", "1h", "6h", "1d"];
+
+/// Choices the settings screen steps through for the idle auto-lock
+pub const IDLE_LOCK_PRESETS: [&str; 6] = ["off", "5m", "10m", "15m", "30m", "1h"];
+
+/// How long the idle auto-lock prompt waits for a key before locking
+pub const IDLE_LOCK_GRACE: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Choices the settings screen steps through for the large transfer threshold
+pub const THRESHOLD_PRESETS: [&str; 5] = ["off", "100", "1000", "10000", "100000"];
+
+/// Holders listed in the leaderboard popup; your own row is added below them
+/// when you rank lower
+pub const LEADERBOARD_SIZE: usize = 15;
+
+/// The preset `step` places from `current`, wrapping around. A value that
+/// isn't a preset (hand-edited config) steps from the start of the list.
+pub fn cycle_preset(presets: &[&str], current: &str, step: isize) -> String {
+    let len = presets.len() as isize;
+    let index = match presets.iter().position(|preset| preset.eq_ignore_ascii_case(current)) {
+        Some(index) => (index as isize + step).rem_euclid(len),
+        None => 0,
+    };
+    presets[index as usize].to_string()
+}
+
+/// Whether `input` confirms a large transfer: the amount (as a number, so
+/// "1000" confirms "1000.0") or the last 4 characters of the recipient
+pub fn transfer_confirmation_matches(input: &str, amount: &str, recipient: &str) -> bool {
+    let input = input.trim();
+    if input.is_empty() {
+        return false;
+    }
+    let same_amount = matches!((input.parse::<f64>(), amount.parse::<f64>()), (Ok(a), Ok(b)) if a == b);
+    same_amount || (input.chars().count() == 4 && recipient.trim().ends_with(input))
+}
+
+/// The application log viewer (Shift+L)
+#[derive(Debug, Clone, Default)]
+pub struct LogView {
+    /// Tail of the log file, oldest first
+    pub lines: Vec<String>,
+    /// File size at the last read, so the tail is only re-read when it changes
+    pub loaded_len: u64,
+    /// Lines scrolled back from the newest; 0 follows the tail
+    pub scroll: usize,
+    pub search: String,
+    pub editing_search: bool,
+    /// Hide entries below this level; `None` shows everything, including
+    /// lines the log didn't write itself
+    pub min_level: Option<Level>,
+}
+
+impl LogView {
+    /// Next level filter: all, info, warn, error, then all again
+    pub fn cycle_level(&mut self) {
+        self.min_level = match self.min_level {
+            None => Some(Level::Info),
+            Some(Level::Debug) | Some(Level::Info) => Some(Level::Warn),
+            Some(Level::Warn) => Some(Level::Error),
+            Some(Level::Error) => None,
+        };
+        self.scroll = 0;
+    }
+
+    /// Lines passing the level filter and (case-insensitive) search, oldest first
+    pub fn visible(&self) -> Vec<&str> {
+        let search = self.search.to_lowercase();
+        self.lines
+            .iter()
+            .map(String::as_str)
+            .filter(|line| match self.min_level {
+                None => true,
+                Some(min) => LogLine::parse(line).is_some_and(|entry| entry.level >= min),
+            })
+            .filter(|line| search.is_empty() || line.to_lowercase().contains(&search))
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct LockHistoryEntry {
     pub timestamp: String,      // ISO 8601 format
     pub locked_amount: f64,     // Total amount of qcoin locked network-wide
     pub holder_count: usize,    // Number of addresses with locked tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,     // Token price from the configured price feed
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -138,8 +475,7 @@ pub struct AirdropHistory {
 
 impl AirdropHistory {
     pub fn load() -> anyhow::Result<Self> {
-        let home = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
-        let history_path = home.join(".qdum").join("airdrop_history.json");
+        let history_path = crate::paths::data_dir().join("airdrop_history.json");
 
         if history_path.exists() {
             let contents = std::fs::read_to_string(&history_path)?;
@@ -151,8 +487,7 @@ impl AirdropHistory {
     }
 
     pub fn save(&self) -> anyhow::Result<()> {
-        let home = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
-        let vault_dir = home.join(".qdum");
+        let vault_dir = crate::paths::data_dir();
         std::fs::create_dir_all(&vault_dir)?;
 
         let history_path = vault_dir.join("airdrop_history.json");
@@ -172,6 +507,22 @@ impl AirdropHistory {
     }
 }
 
+/// File formats lock history can be exported to
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct LockHistory {
     pub entries: Vec<LockHistoryEntry>,
@@ -179,8 +530,7 @@ pub struct LockHistory {
 
 impl LockHistory {
     pub fn load() -> anyhow::Result<Self> {
-        let home = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
-        let history_path = home.join(".qdum").join("network_lock_history.json");
+        let history_path = crate::paths::data_dir().join("network_lock_history.json");
 
         if history_path.exists() {
             let contents = std::fs::read_to_string(&history_path)?;
@@ -192,8 +542,7 @@ impl LockHistory {
     }
 
     pub fn save(&self) -> anyhow::Result<()> {
-        let home = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
-        let vault_dir = home.join(".qdum");
+        let vault_dir = crate::paths::data_dir();
         std::fs::create_dir_all(&vault_dir)?;
 
         let history_path = vault_dir.join("network_lock_history.json");
@@ -202,18 +551,84 @@ impl LockHistory {
         Ok(())
     }
 
-    pub fn add_entry(&mut self, locked_amount: f64, holder_count: usize) {
+    pub fn add_entry(&mut self, locked_amount: f64, holder_count: usize, price: Option<f64>) {
         use chrono::Utc;
         let entry = LockHistoryEntry {
             timestamp: Utc::now().to_rfc3339(),
             locked_amount,
             holder_count,
+            price,
         };
         self.entries.push(entry);
+    }
 
-        // Keep only last 30 days of entries (hourly snapshots)
-        if self.entries.len() > 720 {  // 30 days * 24 hours = 720 entries
-            self.entries.remove(0);
+    /// Drop entries older than `retention`
+    pub fn prune(&mut self, retention: chrono::Duration) -> usize {
+        let cutoff = chrono::Utc::now() - retention;
+        let before = self.entries.len();
+        self.entries.retain(|entry| {
+            chrono::DateTime::parse_from_rfc3339(&entry.timestamp)
+                .is_ok_and(|time| time > cutoff)
+        });
+        before - self.entries.len()
+    }
+
+    /// Take a network lock snapshot (and a price, if a feed is configured),
+    /// append it and apply the configured retention
+    pub async fn record(client: &VaultClient, mint: Pubkey, force_refresh: bool) -> anyhow::Result<LockHistoryEntry> {
+        let config = crate::vault_manager::VaultConfig::load().unwrap_or_default();
+        let price = match &config.price_feed {
+            // A price API being down shouldn't cost us the lock snapshot
+            Some(feed) => feed.fetch(&mint).await.ok(),
+            None => None,
+        };
+        let (total_locked, holder_count) = client.get_network_locked_total(mint, force_refresh).await?;
+
+        let mut history = LockHistory::load()?;
+        history.add_entry(total_locked, holder_count, price);
+        history.prune(config.snapshot_retention());
+        history.save()?;
+        Ok(history.entries.last().cloned().expect("entry was just added"))
+    }
+
+    /// Prepend reconstructed snapshots (oldest first) from before the first
+    /// recorded entry, within `retention`. Returns how many were added.
+    pub fn backfill(&mut self, snapshots: &[crate::solana::client::LockSnapshot], retention: chrono::Duration) -> usize {
+        let first = self.entries.first()
+            .and_then(|entry| chrono::DateTime::parse_from_rfc3339(&entry.timestamp).ok())
+            .map(|time| time.timestamp());
+        let cutoff = (chrono::Utc::now() - retention).timestamp();
+
+        let older: Vec<LockHistoryEntry> = snapshots.iter()
+            .filter(|snapshot| snapshot.timestamp > cutoff)
+            .filter(|snapshot| first.is_none_or(|first| snapshot.timestamp < first))
+            .filter_map(|snapshot| {
+                let timestamp = chrono::DateTime::from_timestamp(snapshot.timestamp, 0)?;
+                Some(LockHistoryEntry {
+                    timestamp: timestamp.to_rfc3339(),
+                    locked_amount: snapshot.locked_amount,
+                    holder_count: snapshot.holder_count,
+                    price: None,
+                })
+            })
+            .collect();
+        let added = older.len();
+        self.entries.splice(0..0, older);
+        added
+    }
+
+    /// Entries as CSV (timestamp, locked_amount, holder_count, price) or a JSON array
+    pub fn export(&self, format: ExportFormat) -> anyhow::Result<String> {
+        match format {
+            ExportFormat::Csv => {
+                let mut csv = String::from("timestamp,locked_amount,holder_count,price\n");
+                for entry in &self.entries {
+                    let price = entry.price.map(|price| price.to_string()).unwrap_or_default();
+                    csv.push_str(&format!("{},{},{},{}\n", entry.timestamp, entry.locked_amount, entry.holder_count, price));
+                }
+                Ok(csv)
+            }
+            ExportFormat::Json => Ok(serde_json::to_string_pretty(&self.entries)? + "\n"),
         }
     }
 }
@@ -235,6 +650,8 @@ pub struct Dashboard {
     pub balance: Option<u64>,
     pub pq_balance: Option<u64>,      // pqcoin balance
     pub standard_balance: Option<u64>, // Standard qcoin balance
+    pub pq_extensions: Option<MintExtensions>,  // Transfer fee etc. of the pqcoin mint
+    pub standard_extensions: Option<MintExtensions>,
     pub is_loading: bool,
     pub action_steps: Vec<ActionStep>,
     pub vault_client: VaultClient,
@@ -243,6 +660,7 @@ pub struct Dashboard {
     pub pending_transfer: bool,  // Flag specifically for transfer action
     pub unlock_complete: Option<Arc<AtomicBool>>,  // Flag to detect when unlock finishes
     pub unlock_success_message: Option<String>,  // Success message to display
+    pub unlock_session: Option<UnlockSession>,  // Interrupted unlock offered for resuming
     pub lock_complete: Option<Arc<AtomicBool>>,  // Flag to detect when lock finishes
     pub lock_success_message: Option<String>,  // Success message to display
     // Transfer state
@@ -251,6 +669,10 @@ pub struct Dashboard {
     pub transfer_focused_field: TransferInputField,
     pub transfer_token_type: TransferTokenType,
     pub in_transfer_form: bool,  // True when actively editing transfer form
+    pub transfer_recipient_check: RecipientCheck,
+    pub transfer_recipient_confirmed: bool,  // Enter already pressed once for an unverified recipient
+    pub transfer_confirm_input: String,  // Typed confirmation for a transfer over the threshold
+    pub transfer_confirm_return: AppMode,  // Where Esc goes back to from the confirmation
     // Bridge state
     pub bridge_amount: String,
     pub standard_mint: Pubkey,  // Standard qcoin mint
@@ -262,6 +684,10 @@ pub struct Dashboard {
     pub vault_list: Vec<crate::vault_manager::VaultProfile>,
     pub selected_vault_index: usize,
     pub in_vault_list: bool,  // True when actively in vault list
+    // Rename / description edit state
+    pub vault_edit_field: VaultEditField,
+    pub vault_edit_target: String,
+    pub vault_edit_input: String,
     // Delete confirmation state
     pub vault_to_delete: String,
     pub delete_confirmation_input: String,
@@ -274,8 +700,152 @@ pub struct Dashboard {
     // Chart state
     pub chart_type: ChartType,
     pub chart_timeframe: ChartTimeframe,
+    pub chart_style: ChartStyle,
+    pub show_price: bool,  // Overlay the price feed on the lock chart
+    // All-vaults portfolio view
+    pub portfolio: Vec<PortfolioRow>,
+    pub portfolio_selected: usize,
+    pub compare_marked: Vec<String>,  // Vault names marked with Space for comparison (at most 2)
+    pub comparison: Vec<VaultComparison>,
+    // Locked-holders leaderboard
+    pub leaderboard: Leaderboard,
+    pub leaderboard_distribution: bool,  // Showing the balance histogram instead of the ranking
+    // Remapped keys from the config
+    pub keymap: Keymap,
+    // Status bar (slot, epoch, RPC latency)
+    pub network_status: Arc<Mutex<NetworkStatus>>,
+    // Transient notifications (shared with the network poller)
+    pub toasts: Arc<Mutex<Toasts>>,
+    pub log_view: LogView,
+    // Receive (QR code) popup
+    pub receive_amount: String,
+    pub receive_token: TransferTokenType,
+    // Settings screen; saved to the vault config on every change
+    pub settings: DashboardSettings,
+    pub settings_theme: ThemeName,
+    pub settings_selected: usize,
+    pub last_refresh: std::time::Instant,
+    pub last_input: std::time::Instant,  // Key press or paste, for the idle auto-lock
+    pub idle_lock_deadline: Option<std::time::Instant>,  // When the open idle prompt locks
+    pub idle_lock_return: AppMode,  // Where a dismissed idle prompt goes back to
+    pub idle_lock_pending: bool,  // An idle lock ran; no prompt until a refreshed status confirms it
+    pub refresh_paused: Arc<AtomicBool>,  // Space; also stops the network and snapshot pollers
+    pub quit_pending: bool,
+    pub help_scroll: u16,
+    // Result and action popups; the maximum is worked out while drawing
+    pub popup_scroll: u16,
+    pub popup_scroll_max: std::cell::Cell<u16>,  // Lines scrolled in the help overlay, for short terminals  // Quit key pressed once with confirm_quit on
+    pub snapshot_poller: Option<tokio::task::JoinHandle<()>>,
+    // Network selector (N)
+    pub network_name: String,  // Profile the RPC URL belongs to, or "custom"
+    pub network_profiles: Vec<(String, NetworkProfile)>,
+    pub network_selected: usize,
+    pub network_poller: Option<tokio::task::JoinHandle<()>>,
+    pub next_airdrop_claim: Option<i64>,  // Unix time the airdrop cooldown ends, from the PQ account
     pub airdrop_timeframe: ChartTimeframe,
     // Cached airdrop stats
     pub airdrop_distributed: u64,
     pub airdrop_remaining: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp: &str, locked_amount: f64) -> LockHistoryEntry {
+        LockHistoryEntry { timestamp: timestamp.to_string(), locked_amount, holder_count: 0, price: None }
+    }
+
+    #[test]
+    fn test_candles_bucket_by_interval() {
+        let entries = [
+            entry("2025-01-01T10:05:00Z", 10.0),
+            entry("2025-01-01T10:20:00Z", 30.0),
+            entry("2025-01-01T10:50:00Z", 5.0),
+            entry("2025-01-01T11:10:00Z", 20.0),
+        ];
+        let refs: Vec<&LockHistoryEntry> = entries.iter().collect();
+
+        let result = candles(&refs, 3600, |entry| entry.locked_amount);
+        assert_eq!(result.len(), 2);
+        assert_eq!((result[0].open, result[0].high, result[0].low, result[0].close), (10.0, 30.0, 5.0, 5.0));
+        assert_eq!(result[1].start.to_rfc3339(), "2025-01-01T11:00:00+00:00");
+
+        assert_eq!(candle_interval(30 * 86400, 60), 43200);
+        assert_eq!(candle_interval(600, 60), 60);
+    }
+
+    #[test]
+    fn test_log_view_filters() {
+        let mut view = LogView {
+            lines: vec![
+                "2026-03-01T12:00:00Z DEBUG dashboard: Event::Resize 80x24".to_string(),
+                "2026-03-01T12:00:01Z INFO  bridge: Wrapping 5 QDUM".to_string(),
+                "2026-03-01T12:00:02Z ERROR bridge: Wrap transaction failed".to_string(),
+                "stray line".to_string(),
+            ],
+            ..LogView::default()
+        };
+        assert_eq!(view.visible().len(), 4);
+
+        view.cycle_level();
+        assert_eq!(view.visible().len(), 2);
+
+        view.search = "FAILED".to_string();
+        assert_eq!(view.visible(), ["2026-03-01T12:00:02Z ERROR bridge: Wrap transaction failed"]);
+    }
+
+    #[test]
+    fn test_amount_shortcut() {
+        assert_eq!(amount_shortcut('m', 12_500_000, 6).as_deref(), Some("12.5"));
+        assert_eq!(amount_shortcut('H', 3_000_001, 6).as_deref(), Some("1.5"));
+        assert_eq!(amount_shortcut('q', 3, 6).as_deref(), Some("0"));
+        assert_eq!(amount_shortcut('m', 7, 6).as_deref(), Some("0.000007"));
+        assert_eq!(amount_shortcut('x', 7, 6), None);
+        assert_eq!(amount_shortcut('h', 1_000_000_000, 9).as_deref(), Some("0.5"));
+        assert_eq!(amount_shortcut('m', 40, 0).as_deref(), Some("40"));
+        // Filled amounts survive the float round trip back to base units
+        assert_eq!(("0.000007".parse::<f64>().unwrap() * 1_000_000.0).round() as u64, 7);
+    }
+
+    #[test]
+    fn test_parse_recipient() {
+        let address = Pubkey::new_unique().to_string();
+        assert_eq!(parse_recipient(&address).unwrap().to_string(), address);
+        assert_eq!(parse_recipient(""), Err(RecipientCheck::Incomplete));
+        assert_eq!(parse_recipient(&address[..20]), Err(RecipientCheck::Incomplete));
+        assert!(matches!(parse_recipient("So1111O"), Err(RecipientCheck::Invalid(msg)) if msg.contains("'O'")));
+        assert!(matches!(parse_recipient(&format!("{}11", address)), Err(RecipientCheck::Invalid(_))));
+    }
+
+    #[test]
+    fn test_cycle_preset() {
+        assert_eq!(cycle_preset(&REFRESH_PRESETS, "30s", 1), "1m");
+        assert_eq!(cycle_preset(&REFRESH_PRESETS, "15m", 1), "off");
+        assert_eq!(cycle_preset(&REFRESH_PRESETS, "off", -1), "15m");
+        assert_eq!(cycle_preset(&SNAPSHOT_PRESETS, "90m", 1), "off");
+    }
+
+    #[test]
+    fn test_transfer_confirmation_matches() {
+        let recipient = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU";
+        assert!(transfer_confirmation_matches("1000", "1000.0", recipient));
+        assert!(transfer_confirmation_matches(" gAsU ", "1000", recipient));
+        assert!(!transfer_confirmation_matches("gasu", "1000", recipient));
+        assert!(!transfer_confirmation_matches("100", "1000", recipient));
+        assert!(!transfer_confirmation_matches("", "1000", recipient));
+    }
+
+    #[test]
+    fn test_screen_layout() {
+        assert_eq!(ScreenLayout::for_size(79, 40), None);
+        assert_eq!(ScreenLayout::for_size(120, 23), None);
+
+        // 80x24 fits, with the minimum content height
+        let compact = ScreenLayout::for_size(80, 24).unwrap();
+        assert!(compact.is_compact());
+        assert_eq!(24 - 2 * compact.margin - compact.header - compact.account - compact.footer, 8);
+
+        assert!(!ScreenLayout::for_size(160, 50).unwrap().is_compact());
+    }
+}
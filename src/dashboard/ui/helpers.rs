@@ -1,5 +1,14 @@
 use arboard::Clipboard;
-use crate::dashboard::types::Dashboard;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Wrap},
+};
+use crate::dashboard::toast::ToastLevel;
+use crate::dashboard::types::{AppMode, Dashboard, RecipientCheck, TransferInputField, VaultManagementMode};
+use crate::theme::theme;
 
 impl Dashboard {
     pub fn get_animated_dots(&self) -> &'static str {
@@ -25,16 +34,98 @@ impl Dashboard {
                 let wallet_str = self.wallet.to_string();
                 match clipboard.set_text(wallet_str) {
                     Ok(_) => {
-                        self.status_message = Some("✓ Wallet address copied to clipboard!".to_string());
+                        self.toast(ToastLevel::Success, "Wallet address copied to clipboard");
                     }
                     Err(e) => {
-                        self.status_message = Some(format!("Failed to copy to clipboard: {}", e));
+                        self.toast(ToastLevel::Error, format!("Failed to copy to clipboard: {}", e));
                     }
                 }
             }
             Err(e) => {
-                self.status_message = Some(format!("Failed to access clipboard: {}", e));
+                self.toast(ToastLevel::Error, format!("Failed to access clipboard: {}", e));
             }
         }
     }
+
+    /// Ctrl+V, for terminals that don't send bracketed paste
+    pub fn paste_from_clipboard(&mut self) {
+        match Clipboard::new().and_then(|mut clipboard| clipboard.get_text()) {
+            Ok(text) => self.handle_paste(&text),
+            Err(e) => {
+                self.toast(ToastLevel::Error, format!("Failed to read clipboard: {}", e));
+            }
+        }
+    }
+
+    /// Insert pasted text into the focused transfer or vault-name field,
+    /// dropping characters that field wouldn't accept when typed
+    pub fn handle_paste(&mut self, text: &str) {
+        let in_transfer_form = self.mode == AppMode::TransferPopup
+            || (self.mode == AppMode::Normal && self.selected_action == 4 && self.in_transfer_form);
+        let in_vault_create = self.vault_management_mode == VaultManagementMode::Create
+            && (self.mode == AppMode::VaultSwitchPopup
+                || (self.mode == AppMode::Normal && self.selected_action == 11 && self.in_vault_list && !self.vault_list.is_empty()));
+
+        if in_transfer_form {
+            match self.transfer_focused_field {
+                TransferInputField::TokenType => {}
+                // Copied addresses often carry a trailing newline or spaces
+                TransferInputField::Recipient => {
+                    self.transfer_recipient.extend(text.chars().filter(|c| !c.is_whitespace()));
+                    self.check_recipient();
+                }
+                TransferInputField::Amount => self.transfer_amount.extend(text.chars().filter(|c| c.is_ascii_digit() || *c == '.')),
+            }
+        } else if in_vault_create {
+            self.new_vault_name.extend(text.chars().filter(|c| c.is_alphanumeric() || *c == '-' || *c == '_'));
+        }
+    }
+
+    /// `lines` in `block`, wrapped and scrolled to `popup_scroll`. The scroll
+    /// is clamped so the last line stays at the bottom of `area`, and content
+    /// taller than the popup gets a position marker on the bottom border.
+    pub fn scrollable_popup<'a>(&self, lines: Vec<Line<'a>>, block: Block<'a>, area: Rect) -> Paragraph<'a> {
+        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+        let height = paragraph.clone().block(block.clone()).line_count(area.width) as u16;
+        let max = height.saturating_sub(area.height);
+        self.popup_scroll_max.set(max);
+
+        let scroll = self.popup_scroll.min(max);
+        let block = if max > 0 {
+            block.title_bottom(Line::from(format!(" ↑↓ PgUp PgDn  {}/{} ", scroll, max)).right_aligned())
+        } else {
+            block
+        };
+        paragraph.block(block).scroll((scroll, 0))
+    }
+
+    /// Up/Down/PageUp/PageDown/Home/End in a popup drawn with `scrollable_popup`
+    pub fn scroll_popup(&mut self, code: KeyCode) {
+        const PAGE: u16 = 10;
+        let max = self.popup_scroll_max.get();
+        self.popup_scroll = match code {
+            KeyCode::Up => self.popup_scroll.saturating_sub(1),
+            KeyCode::Down => self.popup_scroll + 1,
+            KeyCode::PageUp => self.popup_scroll.saturating_sub(PAGE),
+            KeyCode::PageDown => self.popup_scroll + PAGE,
+            KeyCode::Home => 0,
+            KeyCode::End => max,
+            _ => self.popup_scroll,
+        }
+        .min(max);
+    }
+
+    /// The line under the recipient field saying what's wrong (or right) with it
+    pub fn recipient_check_span(&self) -> Option<Span<'static>> {
+        if self.transfer_recipient.is_empty() {
+            return None;
+        }
+        let (text, color) = match &self.transfer_recipient_check {
+            RecipientCheck::Incomplete => (format!("… {} characters, an address has 32-44", self.transfer_recipient.len()), theme().dim),
+            RecipientCheck::Invalid(reason) => (format!("✗ {}", reason), theme().error()),
+            RecipientCheck::Known(label) => (format!("✓ {}", label), theme().success()),
+            RecipientCheck::Unverified(reason) => (format!("⚠ {}", reason), theme().warning()),
+        };
+        Some(Span::styled(text, Style::default().fg(color)))
+    }
 }
@@ -5,16 +5,53 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, BorderType, Paragraph, Row, Table, Wrap},
 };
+use crate::dashboard::keymap::BoundAction;
 use crate::dashboard::types::*;
+use crate::dashboard::utils::format_countdown;
+use crate::solana::units::{decimals, to_tokens};
 use crate::icons::Icons;
-use crate::theme::Theme;
+use crate::theme::theme;
 use crate::vault_manager::VaultConfig;
 
 impl Dashboard {
+    /// Shown instead of the dashboard when the terminal is under the minimum size
+    pub fn render_too_small(&self, f: &mut Frame, area: Rect) {
+        let size_ok = |current: u16, min: u16| if current < min { theme().red_neon } else { theme().green_neon };
+        let lines = vec![
+            Line::from(Span::styled("TERMINAL TOO SMALL", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Current  ", Style::default().fg(theme().subtext1)),
+                Span::styled(area.width.to_string(), Style::default().fg(size_ok(area.width, MIN_WIDTH)).add_modifier(Modifier::BOLD)),
+                Span::styled(" x ", Style::default().fg(theme().subtext1)),
+                Span::styled(area.height.to_string(), Style::default().fg(size_ok(area.height, MIN_HEIGHT)).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(vec![
+                Span::styled("Needed   ", Style::default().fg(theme().subtext1)),
+                Span::styled(format!("{} x {}", MIN_WIDTH, MIN_HEIGHT), Style::default().fg(theme().text).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled("Enlarge the window or reduce the font size", Style::default().fg(theme().text))),
+            Line::from(Span::styled(
+                format!("{} to quit", self.keymap.label(BoundAction::Quit)),
+                Style::default().fg(theme().dim),
+            )),
+        ];
+
+        // Vertically centered, as far as the space allows
+        let top = area.height.saturating_sub(lines.len() as u16) / 2;
+        let text_area = Rect { y: area.y + top, height: area.height - top, ..area };
+        let paragraph = Paragraph::new(lines)
+            .style(Style::default().bg(theme().base))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        f.render_widget(paragraph, text_area);
+    }
+
     pub fn render_status_panel(&self, f: &mut Frame, area: Rect) {
         // Format balances with truncated addresses
         let pq_balance_text = if let Some(balance) = self.pq_balance {
-            let balance_tokens = balance as f64 / 1_000_000.0;
+            let balance_tokens = to_tokens(balance, decimals(&self.pq_mint));
             format!("{:>15.2}", balance_tokens)
         } else {
             format!("{:>15}", "---")
@@ -24,7 +61,7 @@ impl Dashboard {
         let pq_mint_truncated = format!("{}...{}", &pq_mint_str[..4], &pq_mint_str[pq_mint_str.len()-4..]);
 
         let standard_balance_text = if let Some(balance) = self.standard_balance {
-            let balance_tokens = balance as f64 / 1_000_000.0;
+            let balance_tokens = to_tokens(balance, decimals(&self.standard_mint));
             format!("{:>15.2}", balance_tokens)
         } else {
             format!("{:>15}", "---")
@@ -35,7 +72,7 @@ impl Dashboard {
 
         // Calculate total portfolio value
         let total_balance = if let (Some(pq), Some(std)) = (self.pq_balance, self.standard_balance) {
-            let total = (pq + std) as f64 / 1_000_000.0;
+            let total = to_tokens(pq, decimals(&self.pq_mint)) + to_tokens(std, decimals(&self.standard_mint));
             format!("{:>15.2}", total)
         } else {
             format!("{:>15}", "---")
@@ -44,35 +81,35 @@ impl Dashboard {
         // Build Bloomberg-style table with dense info
         let rows = vec![
             Row::new(vec![
-                Line::from(Span::styled("qcoin", Style::default().fg(Theme::BLOOMBERG_ORANGE))),
-                Line::from(Span::styled(standard_balance_text, Style::default().fg(Theme::TEXT).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled("qcoin", Style::default().fg(theme().bloomberg_orange))),
+                Line::from(Span::styled(standard_balance_text, Style::default().fg(theme().text).add_modifier(Modifier::BOLD))),
             ]).height(1),
             Row::new(vec![
-                Line::from(Span::styled(&standard_mint_truncated, Style::default().fg(Theme::DIM))),
+                Line::from(Span::styled(&standard_mint_truncated, Style::default().fg(theme().dim))),
                 Line::from(Span::styled("", Style::default())),
             ]).height(1),
             Row::new(vec![
-                Line::from(Span::styled("pqcoin", Style::default().fg(Theme::BLOOMBERG_ORANGE))),
-                Line::from(Span::styled(pq_balance_text, Style::default().fg(Theme::TEXT).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled("pqcoin", Style::default().fg(theme().bloomberg_orange))),
+                Line::from(Span::styled(pq_balance_text, Style::default().fg(theme().text).add_modifier(Modifier::BOLD))),
             ]).height(1),
             Row::new(vec![
-                Line::from(Span::styled(&pq_mint_truncated, Style::default().fg(Theme::DIM))),
+                Line::from(Span::styled(&pq_mint_truncated, Style::default().fg(theme().dim))),
                 Line::from(Span::styled("", Style::default())),
             ]).height(1),
             Row::new(vec![
-                Line::from(Span::styled("─────────────────────", Style::default().fg(Theme::DIM))),
-                Line::from(Span::styled("─────────────────────", Style::default().fg(Theme::DIM))),
+                Line::from(Span::styled("─────────────────────", Style::default().fg(theme().dim))),
+                Line::from(Span::styled("─────────────────────", Style::default().fg(theme().dim))),
             ]).height(1),
             Row::new(vec![
-                Line::from(Span::styled("TOTAL", Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))),
-                Line::from(Span::styled(total_balance, Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled("TOTAL", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled(total_balance, Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD))),
             ]).height(1),
         ];
 
         let widths = [Constraint::Length(18), Constraint::Min(20)];
 
         // Static gray border color matching splash screen
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let table = Table::new(rows, widths)
             .block(
@@ -81,28 +118,34 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ PORTFOLIO SUMMARY ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .column_spacing(1);
 
         f.render_widget(table, area);
     }
     pub fn render_actions_panel(&self, f: &mut Frame, area: Rect) {
         // Bloomberg-style professional action menu
+        let airdrop_cooldown = self.airdrop_cooldown();
+        let airdrop_desc = match airdrop_cooldown {
+            Some(remaining) => format!("Next claim in {}", format_countdown(remaining)),
+            None => "Claim 100 tokens (24h limit)".to_string(),
+        };
+        let airdrop_color = if airdrop_cooldown.is_some() { theme().dim } else { theme().bloomberg_orange };
         let actions = vec![
-            ("PORTFOLIO", "S", "View detailed portfolio summary", Theme::BLOOMBERG_ORANGE),
-            ("REGISTER", "G", "Initialize PQ account on-chain", Theme::BLOOMBERG_ORANGE),
-            ("LOCK", "L", "Secure vault with challenge", Theme::BLOOMBERG_ORANGE),
-            ("UNLOCK", "U", "44-step SPHINCS+ verification", Theme::BLOOMBERG_ORANGE),
-            ("TRANSFER", "T", "Send tokens to recipient", Theme::BLOOMBERG_ORANGE),
-            ("WRAP", "W", "Standard -> PQ-Secured", Theme::BLOOMBERG_ORANGE),
-            ("UNWRAP", "E", "PQ-Secured -> Standard", Theme::BLOOMBERG_ORANGE),
-            ("AIRDROP", "A", "Claim 100 tokens (24h limit)", Theme::BLOOMBERG_ORANGE),
-            ("STATS", "P", "View network statistics", Theme::BLOOMBERG_ORANGE),
-            ("CLOSE", "X", "Close vault & reclaim rent", Theme::BLOOMBERG_ORANGE),
-            ("CHART", "M", "Network metrics & charts", Theme::BLOOMBERG_ORANGE),
-            ("VAULTS", "V", "Switch/manage vaults", Theme::BLOOMBERG_ORANGE),
+            ("PORTFOLIO", "S".to_string(), "View detailed portfolio summary", theme().bloomberg_orange),
+            ("REGISTER", "G".to_string(), "Initialize PQ account on-chain", theme().bloomberg_orange),
+            ("LOCK", self.keymap.label(BoundAction::Lock), "Secure vault with challenge", theme().bloomberg_orange),
+            ("UNLOCK", self.keymap.label(BoundAction::Unlock), "44-step SPHINCS+ verification", theme().bloomberg_orange),
+            ("TRANSFER", self.keymap.label(BoundAction::Transfer), "Send tokens to recipient", theme().bloomberg_orange),
+            ("WRAP", "W".to_string(), "Standard -> PQ-Secured", theme().bloomberg_orange),
+            ("UNWRAP", "E".to_string(), "PQ-Secured -> Standard", theme().bloomberg_orange),
+            ("AIRDROP", "A".to_string(), airdrop_desc.as_str(), airdrop_color),
+            ("STATS", "P".to_string(), "View network statistics", theme().bloomberg_orange),
+            ("CLOSE", "X".to_string(), "Close vault & reclaim rent", theme().bloomberg_orange),
+            ("CHART", self.keymap.label(BoundAction::Chart), "Network metrics & charts", theme().bloomberg_orange),
+            ("VAULTS", self.keymap.label(BoundAction::VaultSwitch), "Switch/manage vaults", theme().bloomberg_orange),
         ];
 
         // Build table rows with selection highlighting
@@ -113,13 +156,12 @@ impl Dashboard {
             .map(|(idx, (action, key, desc, color))| {
                 let is_selected = idx == self.selected_action;
 
-                // Animated background for selected row - light purple tint on white
+                // Animated background for selected row - pulsing theme highlight
                 let row_style = if is_selected {
-                    Style::default().bg(Color::Rgb(
-                        (230 + pulse / 8).min(255),
-                        (220 + pulse / 8).min(255),
-                        (245).min(255)
-                    ))
+                    Style::default().bg(match theme().highlight {
+                        Color::Rgb(r, g, b) => Color::Rgb(r.saturating_add(pulse / 8), g.saturating_add(pulse / 8), b),
+                        highlight => highlight,
+                    })
                 } else {
                     Style::default()
                 };
@@ -147,8 +189,8 @@ impl Dashboard {
 
                 Row::new(vec![
                     Line::from(Span::styled(action_text, Style::default().fg(action_color).add_modifier(Modifier::BOLD))),
-                    Line::from(Span::styled(*key, Style::default().fg(if is_selected { Theme::YELLOW_NEON } else { Theme::CYAN_BRIGHT }).add_modifier(Modifier::BOLD))),
-                    Line::from(Span::styled(*desc, Style::default().fg(if is_selected { Theme::TEXT } else { Theme::SUBTEXT1 }))),
+                    Line::from(Span::styled(key.clone(), Style::default().fg(if is_selected { theme().yellow_neon } else { theme().cyan_bright }).add_modifier(Modifier::BOLD))),
+                    Line::from(Span::styled(*desc, Style::default().fg(if is_selected { theme().text } else { theme().subtext1 }))),
                 ])
                 .style(row_style)
             })
@@ -161,7 +203,7 @@ impl Dashboard {
         ];
 
         // Static gray border color matching splash screen
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let table = Table::new(rows, widths)
             .block(
@@ -170,74 +212,74 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ QUICK ACTIONS ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .column_spacing(2)
             .header(
                 Row::new(vec![
-                    Line::from(Span::styled("ACTION", Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))),
-                    Line::from(Span::styled("KEY", Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))),
-                    Line::from(Span::styled("DESCRIPTION", Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))),
+                    Line::from(Span::styled("ACTION", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))),
+                    Line::from(Span::styled("KEY", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))),
+                    Line::from(Span::styled("DESCRIPTION", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))),
                 ])
-                .style(Style::default().bg(Theme::GLASS_1))
+                .style(Style::default().bg(theme().glass_1))
                 .bottom_margin(1)
             );
 
         f.render_widget(table, area);
     }
     pub fn render_footer(&self, f: &mut Frame, area: Rect) {
-        // Always split footer into controls + status
+        // Always split footer into controls + status + network status bar
         let footer_chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(3), Constraint::Length(3)].as_ref())
+            .constraints([Constraint::Length(3), Constraint::Length(3), Constraint::Length(1)].as_ref())
             .split(area);
 
         // Controls with Bloomberg-style badges
         let footer_text = vec![Line::from(vec![
             Span::styled(
-                " Q/ESC ",
+                format!(" {}/ESC ", self.keymap.label(BoundAction::Quit)),
                 Style::default()
-                    .fg(Theme::BASE)
-                    .bg(Theme::RED_NEON)
+                    .fg(theme().base)
+                    .bg(theme().red_neon)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" Quit  ", Style::default().fg(Theme::TEXT)),
+            Span::styled(" Quit  ", Style::default().fg(theme().text)),
             Span::styled(
                 " H/? ",
                 Style::default()
-                    .fg(Theme::BASE)
-                    .bg(Theme::BLOOMBERG_ORANGE)
+                    .fg(theme().base)
+                    .bg(theme().bloomberg_orange)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" Help  ", Style::default().fg(Theme::TEXT)),
+            Span::styled(" Help  ", Style::default().fg(theme().text)),
             Span::styled(
                 " R ",
                 Style::default()
-                    .fg(Theme::BASE)
-                    .bg(Theme::CYAN_NEON)
+                    .fg(theme().base)
+                    .bg(theme().cyan_neon)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" Refresh  ", Style::default().fg(Theme::TEXT)),
+            Span::styled(" Refresh  ", Style::default().fg(theme().text)),
             Span::styled(
                 " ↑↓/JK ",
                 Style::default()
-                    .fg(Theme::BASE)
-                    .bg(Theme::YELLOW_NEON)
+                    .fg(theme().base)
+                    .bg(theme().yellow_neon)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" Navigate  ", Style::default().fg(Theme::TEXT)),
+            Span::styled(" Navigate  ", Style::default().fg(theme().text)),
             Span::styled(
                 " ENTER ",
                 Style::default()
-                    .fg(Theme::BASE)
-                    .bg(Theme::GREEN_NEON)
+                    .fg(theme().base)
+                    .bg(theme().green_neon)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" Execute", Style::default().fg(Theme::TEXT)),
+            Span::styled(" Execute", Style::default().fg(theme().text)),
         ])];
         // Static gray border color matching splash screen
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
         let footer = Paragraph::new(footer_text)
             .block(
                 Block::default()
@@ -246,10 +288,10 @@ impl Dashboard {
                     .border_type(BorderType::Double)
                     .title(format!(" {} CONTROLS ", Icons::KEYBOARD))
                     .title_style(Style::default()
-                        .fg(Theme::BLOOMBERG_ORANGE)
+                        .fg(theme().bloomberg_orange)
                         .add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .alignment(Alignment::Center);
         f.render_widget(footer, footer_chunks[0]);
 
@@ -270,19 +312,19 @@ impl Dashboard {
             || self.lock_success_message.as_ref()
             .map(|m| m.starts_with("✓"))
             .unwrap_or(false) {
-            Theme::GREEN_NEON
+            theme().green_neon
         } else if self.unlock_success_message.as_ref()
             .map(|m| m.starts_with("✗"))
             .unwrap_or(false)
             || self.lock_success_message.as_ref()
             .map(|m| m.starts_with("✗"))
             .unwrap_or(false) {
-            Theme::RED_NEON
+            theme().red_neon
         } else {
-            Theme::CYAN_NEON
+            theme().cyan_neon
         };
 
-        let status_border = if status_color == Theme::GREEN_NEON || status_color == Theme::RED_NEON {
+        let status_border = if status_color == theme().green_neon || status_color == theme().red_neon {
             status_color
         } else {
             border_color
@@ -297,11 +339,86 @@ impl Dashboard {
                     .border_style(Style::default().fg(status_border).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" STATUS ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
             )
-            .style(Style::default().bg(Theme::BASE));
+            .style(Style::default().bg(theme().base));
 
         f.render_widget(status_widget, footer_chunks[1]);
+
+        self.render_network_bar(f, footer_chunks[2]);
+    }
+
+    /// One line of cluster health: slot, epoch progress, endpoint, RPC latency
+    fn render_network_bar(&self, f: &mut Frame, area: Rect) {
+        let status = self.network_status.lock().map(|status| status.clone()).unwrap_or_default();
+        let label = Style::default().fg(theme().subtext0);
+        let value = Style::default().fg(theme().text).add_modifier(Modifier::BOLD);
+        let separator = || Span::styled("  │  ", Style::default().fg(theme().dim));
+
+        let mut spans = Vec::new();
+        match &status.cluster {
+            Some(cluster) => {
+                let progress = cluster.epoch_progress();
+                let filled = (progress * 10.0).round() as usize;
+                spans.extend([
+                    Span::styled("SLOT ", label),
+                    Span::styled(cluster.slot.to_string(), value),
+                    separator(),
+                    Span::styled("EPOCH ", label),
+                    Span::styled(format!("{} ", cluster.epoch), value),
+                    Span::styled("█".repeat(filled), Style::default().fg(theme().bloomberg_orange)),
+                    Span::styled("░".repeat(10 - filled), Style::default().fg(theme().dim)),
+                    Span::styled(format!(" {:.0}%", progress * 100.0), value),
+                ]);
+            }
+            None if status.error.is_none() => spans.push(Span::styled("Connecting...", label)),
+            None => {}
+        }
+        if !spans.is_empty() {
+            spans.push(separator());
+        }
+        spans.extend([
+            Span::styled("NET ", label),
+            Span::styled(self.network_name.to_uppercase(), value),
+            separator(),
+            Span::styled("RPC ", label),
+            Span::styled(rpc_host(&self.rpc_url), value),
+        ]);
+
+        spans.push(separator());
+        spans.push(Span::styled("REFRESH ", label));
+        if self.refresh_paused.load(std::sync::atomic::Ordering::Relaxed) {
+            spans.push(Span::styled("PAUSED", Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)));
+        } else if let Some(interval) = self.settings.refresh_interval() {
+            let remaining = interval.saturating_sub(self.last_refresh.elapsed());
+            spans.push(Span::styled(format!("{}s", remaining.as_secs()), value));
+        } else {
+            spans.push(Span::styled("OFF", Style::default().fg(theme().dim)));
+        }
+
+        if let Some(error) = &status.error {
+            // A failed poll means the network (or endpoint) is the problem, not the app
+            spans.push(separator());
+            spans.push(Span::styled(
+                format!("{} RPC UNREACHABLE: {}", Icons::SECURITY, error),
+                Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD),
+            ));
+        } else if let Some(cluster) = &status.cluster {
+            let millis = cluster.latency.as_millis();
+            let latency_color = match millis {
+                0..=299 => theme().green_neon,
+                300..=999 => theme().yellow_neon,
+                _ => theme().red_neon,
+            };
+            spans.push(separator());
+            spans.push(Span::styled("LATENCY ", label));
+            spans.push(Span::styled(format!("{} ms", millis), Style::default().fg(latency_color).add_modifier(Modifier::BOLD)));
+        }
+
+        let bar = Paragraph::new(Line::from(spans))
+            .style(Style::default().bg(theme().base))
+            .alignment(Alignment::Center);
+        f.render_widget(bar, area);
     }
 
     pub fn render_content_area(&self, f: &mut Frame, area: Rect) {
@@ -351,14 +468,14 @@ impl Dashboard {
     fn render_portfolio_content(&self, f: &mut Frame, area: Rect) {
         // Enhanced portfolio view with more details
         let pq_balance_text = if let Some(balance) = self.pq_balance {
-            let balance_tokens = balance as f64 / 1_000_000.0;
+            let balance_tokens = to_tokens(balance, decimals(&self.pq_mint));
             format!("{:.6}", balance_tokens)
         } else {
             "---".to_string()
         };
 
         let standard_balance_text = if let Some(balance) = self.standard_balance {
-            let balance_tokens = balance as f64 / 1_000_000.0;
+            let balance_tokens = to_tokens(balance, decimals(&self.standard_mint));
             format!("{:.6}", balance_tokens)
         } else {
             "---".to_string()
@@ -368,7 +485,7 @@ impl Dashboard {
         let standard_mint_str = self.standard_mint.to_string();
 
         let total_balance = if let (Some(pq), Some(std)) = (self.pq_balance, self.standard_balance) {
-            let total = (pq + std) as f64 / 1_000_000.0;
+            let total = to_tokens(pq, decimals(&self.pq_mint)) + to_tokens(std, decimals(&self.standard_mint));
             format!("{:.6}", total)
         } else {
             "---".to_string()
@@ -377,40 +494,40 @@ impl Dashboard {
         // Build detailed portfolio table
         let rows = vec![
             Row::new(vec![
-                Line::from(Span::styled("TOKEN", Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))),
-                Line::from(Span::styled("BALANCE", Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))),
-                Line::from(Span::styled("MINT ADDRESS", Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled("TOKEN", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled("BALANCE", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled("MINT ADDRESS", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))),
             ]).height(1),
             Row::new(vec![
-                Line::from(Span::styled("━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-                Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-                Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+                Line::from(Span::styled("━━━━━━━━━━━", Style::default().fg(theme().dim))),
+                Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+                Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
             ]).height(1),
             Row::new(vec![
-                Line::from(Span::styled("qcoin", Style::default().fg(Theme::CYAN_NEON).add_modifier(Modifier::BOLD))),
-                Line::from(Span::styled(standard_balance_text, Style::default().fg(Theme::TEXT).add_modifier(Modifier::BOLD))),
-                Line::from(Span::styled(standard_mint_str, Style::default().fg(Theme::SUBTEXT1))),
+                Line::from(Span::styled("qcoin", Style::default().fg(theme().cyan_neon).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled(standard_balance_text, Style::default().fg(theme().text).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled(standard_mint_str, Style::default().fg(theme().subtext1))),
             ]).height(1),
             Row::new(vec![
-                Line::from(Span::styled("pqcoin", Style::default().fg(Theme::PURPLE_NEON).add_modifier(Modifier::BOLD))),
-                Line::from(Span::styled(pq_balance_text, Style::default().fg(Theme::TEXT).add_modifier(Modifier::BOLD))),
-                Line::from(Span::styled(pq_mint_str, Style::default().fg(Theme::SUBTEXT1))),
+                Line::from(Span::styled("pqcoin", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled(pq_balance_text, Style::default().fg(theme().text).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled(pq_mint_str, Style::default().fg(theme().subtext1))),
             ]).height(1),
             Row::new(vec![
-                Line::from(Span::styled("━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-                Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-                Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+                Line::from(Span::styled("━━━━━━━━━━━", Style::default().fg(theme().dim))),
+                Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+                Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
             ]).height(1),
             Row::new(vec![
-                Line::from(Span::styled("TOTAL", Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))),
-                Line::from(Span::styled(total_balance, Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled("TOTAL", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled(total_balance, Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD))),
                 Line::from(Span::styled("", Style::default())),
             ]).height(1),
         ];
 
         let widths = [Constraint::Length(12), Constraint::Length(20), Constraint::Min(45)];
 
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let table = Table::new(rows, widths)
             .block(
@@ -419,9 +536,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ PORTFOLIO DETAILS ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .column_spacing(2);
 
         f.render_widget(table, area);
@@ -429,23 +546,23 @@ impl Dashboard {
 
     fn render_action_progress(&self, f: &mut Frame, area: Rect) {
         // Static gray border color matching splash screen
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         // Build content lines from action steps
         let mut content_lines = vec![Line::from("")];
 
         if self.action_steps.is_empty() {
             content_lines.push(Line::from(vec![
-                Span::styled("STATUS: ", Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
-                Span::styled("Initializing...", Style::default().fg(Theme::SUBTEXT0).add_modifier(Modifier::ITALIC)),
+                Span::styled("STATUS: ", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+                Span::styled("Initializing...", Style::default().fg(theme().subtext0).add_modifier(Modifier::ITALIC)),
             ]));
         } else {
             for (idx, step) in self.action_steps.iter().enumerate() {
                 let (icon, message, color) = match step {
-                    ActionStep::Starting => ("⏳", "Preparing...", Theme::YELLOW_NEON),
-                    ActionStep::InProgress(msg) => ("⚡", msg.as_str(), Theme::CYAN_NEON),
-                    ActionStep::Success(msg) => ("✓", msg.as_str(), Theme::GREEN_NEON),
-                    ActionStep::Error(msg) => ("✗", msg.as_str(), Theme::RED_NEON),
+                    ActionStep::Starting => ("⏳", "Preparing...", theme().yellow_neon),
+                    ActionStep::InProgress(msg) => ("⚡", msg.as_str(), theme().cyan_neon),
+                    ActionStep::Success(msg) => ("✓", msg.as_str(), theme().green_neon),
+                    ActionStep::Error(msg) => ("✗", msg.as_str(), theme().red_neon),
                 };
 
                 let step_label = format!("STEP {}:", idx + 1);
@@ -455,7 +572,7 @@ impl Dashboard {
                 if message.len() > max_width {
                     // First line with step label
                     content_lines.push(Line::from(vec![
-                        Span::styled(step_label, Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                        Span::styled(step_label, Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
                     ]));
 
                     // Message on next line with icon
@@ -466,7 +583,7 @@ impl Dashboard {
                 } else {
                     // Single line for short messages
                     content_lines.push(Line::from(vec![
-                        Span::styled(format!("{} ", step_label), Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                        Span::styled(format!("{} ", step_label), Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
                         Span::styled(format!("{} ", icon), Style::default().fg(color).add_modifier(Modifier::BOLD)),
                         Span::styled(message, Style::default().fg(color)),
                     ]));
@@ -474,7 +591,7 @@ impl Dashboard {
 
                 // Add separator between steps
                 if idx < self.action_steps.len() - 1 {
-                    content_lines.push(Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))));
+                    content_lines.push(Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))));
                 }
                 content_lines.push(Line::from(""));
             }
@@ -487,9 +604,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ ACTION IN PROGRESS ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .wrap(Wrap { trim: true })
             .alignment(Alignment::Left);
 
@@ -498,34 +615,34 @@ impl Dashboard {
 
     fn render_default_content(&self, f: &mut Frame, area: Rect) {
         // Static gray border color matching splash screen
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let text = vec![
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
                 "WELCOME TO PQCOIN TERMINAL",
-                Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "Post-Quantum Secure Digital Currency",
-                Style::default().fg(Theme::TEXT)
+                Style::default().fg(theme().text)
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "Select an action from the sidebar to get started",
-                Style::default().fg(Theme::SUBTEXT1)
+                Style::default().fg(theme().subtext1)
             )),
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
                 "Security: SPHINCS+ SHA2-128s (NIST FIPS 205)",
-                Style::default().fg(Theme::SUBTEXT0)
+                Style::default().fg(theme().subtext0)
             )),
             Line::from(Span::styled(
                 "Network: Solana Devnet",
-                Style::default().fg(Theme::SUBTEXT0)
+                Style::default().fg(theme().subtext0)
             )),
         ];
 
@@ -536,9 +653,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ OVERVIEW ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .alignment(Alignment::Center);
 
         f.render_widget(welcome, area);
@@ -546,42 +663,42 @@ impl Dashboard {
 
     fn render_register_content(&self, f: &mut Frame, area: Rect) {
         // Static gray border color matching splash screen
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let text = vec![
             Line::from(""),
             Line::from(Span::styled(
                 "REGISTER PQ ACCOUNT",
-                Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "Initialize your post-quantum account on-chain",
-                Style::default().fg(Theme::TEXT)
+                Style::default().fg(theme().text)
             )),
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
                 "This will:",
-                Style::default().fg(Theme::SUBTEXT1).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme().subtext1).add_modifier(Modifier::BOLD)
             )),
             Line::from(Span::styled(
                 "  • Create a PQ account associated with your wallet",
-                Style::default().fg(Theme::SUBTEXT1)
+                Style::default().fg(theme().subtext1)
             )),
             Line::from(Span::styled(
                 "  • Store your SPHINCS+ public key on-chain",
-                Style::default().fg(Theme::SUBTEXT1)
+                Style::default().fg(theme().subtext1)
             )),
             Line::from(Span::styled(
                 "  • Enable post-quantum secure operations",
-                Style::default().fg(Theme::SUBTEXT1)
+                Style::default().fg(theme().subtext1)
             )),
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
                 "Press ENTER to register or ESC to cancel",
-                Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)
             )),
         ];
 
@@ -592,9 +709,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ REGISTER ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .alignment(Alignment::Center);
 
         f.render_widget(content, area);
@@ -602,46 +719,46 @@ impl Dashboard {
 
     fn render_lock_content(&self, f: &mut Frame, area: Rect) {
         // Static gray border color matching splash screen
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let text = vec![
             Line::from(""),
             Line::from(Span::styled(
                 "LOCK VAULT",
-                Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "Secure your vault with post-quantum cryptography",
-                Style::default().fg(Theme::TEXT)
+                Style::default().fg(theme().text)
             )),
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
                 "Locking your vault will:",
-                Style::default().fg(Theme::SUBTEXT1).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme().subtext1).add_modifier(Modifier::BOLD)
             )),
             Line::from(Span::styled(
                 "  • Generate a random 32-byte challenge",
-                Style::default().fg(Theme::SUBTEXT1)
+                Style::default().fg(theme().subtext1)
             )),
             Line::from(Span::styled(
                 "  • Create SPHINCS+ signature (44 steps)",
-                Style::default().fg(Theme::SUBTEXT1)
+                Style::default().fg(theme().subtext1)
             )),
             Line::from(Span::styled(
                 "  • Store encrypted signature on-chain",
-                Style::default().fg(Theme::SUBTEXT1)
+                Style::default().fg(theme().subtext1)
             )),
             Line::from(Span::styled(
                 "  • Mark vault as LOCKED",
-                Style::default().fg(Theme::SUBTEXT1)
+                Style::default().fg(theme().subtext1)
             )),
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
                 "Press ENTER to lock or ESC to cancel",
-                Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)
             )),
         ];
 
@@ -652,9 +769,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ LOCK VAULT ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .alignment(Alignment::Center);
 
         f.render_widget(content, area);
@@ -662,47 +779,47 @@ impl Dashboard {
 
     fn render_unlock_content(&self, f: &mut Frame, area: Rect) {
         // Static gray border color matching splash screen
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let text = vec![
             Line::from(""),
             Line::from(Span::styled(
                 "UNLOCK VAULT",
-                Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "Verify post-quantum signature to unlock",
-                Style::default().fg(Theme::TEXT)
+                Style::default().fg(theme().text)
             )),
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
                 "Unlocking requires:",
-                Style::default().fg(Theme::SUBTEXT1).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme().subtext1).add_modifier(Modifier::BOLD)
             )),
             Line::from(Span::styled(
                 "  • 44-step SPHINCS+ signature verification",
-                Style::default().fg(Theme::SUBTEXT1)
+                Style::default().fg(theme().subtext1)
             )),
             Line::from(Span::styled(
                 "  • Challenge-response authentication",
-                Style::default().fg(Theme::SUBTEXT1)
+                Style::default().fg(theme().subtext1)
             )),
             Line::from(Span::styled(
                 "  • On-chain signature validation",
-                Style::default().fg(Theme::SUBTEXT1)
+                Style::default().fg(theme().subtext1)
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "⚠️  This process takes ~30 seconds",
-                Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)
             )),
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
                 "Press ENTER to unlock or ESC to cancel",
-                Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)
             )),
         ];
 
@@ -713,9 +830,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ UNLOCK VAULT ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .alignment(Alignment::Center);
 
         f.render_widget(content, area);
@@ -723,7 +840,7 @@ impl Dashboard {
 
     // Placeholder stubs for other content views
     fn render_transfer_content(&self, f: &mut Frame, area: Rect) {
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         // Build transfer form
         let mut rows = vec![];
@@ -734,12 +851,12 @@ impl Dashboard {
             TransferTokenType::StandardQcoin => "Standard qcoin",
             TransferTokenType::Pqcoin => "pqcoin (PQ-Secured)",
         };
-        let token_type_color = if token_type_focused { Theme::CYAN_NEON } else { Theme::TEXT };
+        let token_type_color = if token_type_focused { theme().cyan_neon } else { theme().text };
 
         rows.push(Row::new(vec![
             Line::from(Span::styled(
                 if token_type_focused { "▶ TOKEN TYPE" } else { "  TOKEN TYPE" },
-                Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD),
             )),
         ]).height(1));
 
@@ -755,19 +872,19 @@ impl Dashboard {
         ]).height(1));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("    [← →] Toggle token type", Style::default().fg(Theme::SUBTEXT1))),
+            Line::from(Span::styled("    [← →] Toggle token type", Style::default().fg(theme().subtext1))),
         ]).height(1));
 
         rows.push(Row::new(vec![Line::from("")]));
 
         // Recipient Field
         let recipient_focused = self.transfer_focused_field == TransferInputField::Recipient;
-        let recipient_color = if recipient_focused { Theme::CYAN_NEON } else { Theme::TEXT };
+        let recipient_color = if recipient_focused { theme().cyan_neon } else { theme().text };
 
         rows.push(Row::new(vec![
             Line::from(Span::styled(
                 if recipient_focused { "▶ RECIPIENT" } else { "  RECIPIENT" },
-                Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD),
             )),
         ]).height(1));
 
@@ -781,21 +898,25 @@ impl Dashboard {
             Line::from(Span::styled(
                 format!("    {}", recipient_display),
                 Style::default()
-                    .fg(if self.transfer_recipient.is_empty() { Theme::SUBTEXT1 } else { recipient_color })
+                    .fg(if self.transfer_recipient.is_empty() { theme().subtext1 } else { recipient_color })
                     .add_modifier(if recipient_focused { Modifier::BOLD } else { Modifier::empty() }),
             )),
         ]).height(1));
 
+        if let Some(check) = self.recipient_check_span() {
+            rows.push(Row::new(vec![Line::from(vec![Span::raw("    "), check])]).height(1));
+        }
+
         rows.push(Row::new(vec![Line::from("")]));
 
         // Amount Field
         let amount_focused = self.transfer_focused_field == TransferInputField::Amount;
-        let amount_color = if amount_focused { Theme::CYAN_NEON } else { Theme::TEXT };
+        let amount_color = if amount_focused { theme().cyan_neon } else { theme().text };
 
         rows.push(Row::new(vec![
             Line::from(Span::styled(
                 if amount_focused { "▶ AMOUNT" } else { "  AMOUNT" },
-                Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD),
             )),
         ]).height(1));
 
@@ -809,26 +930,36 @@ impl Dashboard {
             Line::from(Span::styled(
                 format!("    {}", amount_display),
                 Style::default()
-                    .fg(if self.transfer_amount.is_empty() { Theme::SUBTEXT1 } else { amount_color })
+                    .fg(if self.transfer_amount.is_empty() { theme().subtext1 } else { amount_color })
                     .add_modifier(if amount_focused { Modifier::BOLD } else { Modifier::empty() }),
             )),
         ]).height(1));
 
+        if let Some(fee) = self.transfer_fee_summary() {
+            rows.push(Row::new(vec![
+                Line::from(Span::styled(format!("    {}", fee), Style::default().fg(theme().yellow_neon))),
+            ]).height(1));
+        }
+
         rows.push(Row::new(vec![Line::from("")]));
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
         rows.push(Row::new(vec![Line::from("")]));
 
         // Controls
         rows.push(Row::new(vec![
             Line::from(vec![
-                Span::styled("[Tab/↑↓] ", Style::default().fg(Theme::CYAN_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("Navigate  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[Enter] ", Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("Execute  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[Esc] ", Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("Cancel", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("[Tab/↑↓] ", Style::default().fg(theme().cyan_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Navigate  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Enter] ", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Execute  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Ctrl+V] ", Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Paste  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[M/H/Q] ", Style::default().fg(theme().cyan_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Max/Half/25%  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Esc] ", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Cancel", Style::default().fg(theme().subtext1)),
             ]),
         ]));
 
@@ -841,9 +972,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ TRANSFER TOKENS ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .column_spacing(0);
 
         f.render_widget(table, area);
@@ -858,7 +989,15 @@ impl Dashboard {
     }
 
     fn render_airdrop_content(&self, f: &mut Frame, area: Rect) {
-        self.render_placeholder_content(f, area, "AIRDROP", "Claim 100 tokens (24-hour cooldown)");
+        match self.airdrop_cooldown() {
+            Some(remaining) => self.render_placeholder_content(
+                f,
+                area,
+                "AIRDROP",
+                &format!("Already claimed - next claim in {}", format_countdown(remaining)),
+            ),
+            None => self.render_placeholder_content(f, area, "AIRDROP", "Claim 100 tokens (24-hour cooldown)"),
+        }
     }
 
     fn render_stats_content(&self, f: &mut Frame, area: Rect) {
@@ -884,18 +1023,18 @@ impl Dashboard {
     fn render_vault_list_content(&self, f: &mut Frame, area: Rect) {
         // Reuse the popup rendering but without Clear widget
         // This is a temporary solution - ideally we'd refactor to share code
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         // Build vault list rows
         let mut rows = vec![];
 
         if self.vault_list.is_empty() {
             rows.push(Row::new(vec![
-                Line::from(Span::styled("No vaults found", Style::default().fg(Theme::YELLOW_NEON))),
+                Line::from(Span::styled("No vaults found", Style::default().fg(theme().yellow_neon))),
             ]));
             rows.push(Row::new(vec![Line::from("")]));
             rows.push(Row::new(vec![
-                Line::from(Span::styled("Press [N] to create your first vault", Style::default().fg(Theme::SUBTEXT1))),
+                Line::from(Span::styled("Press [N] to create your first vault", Style::default().fg(theme().subtext1))),
             ]));
         } else {
             for (i, vault) in self.vault_list.iter().enumerate() {
@@ -906,7 +1045,7 @@ impl Dashboard {
                     Line::from(Span::styled(
                         format!("{} {}", indicator, vault.name),
                         Style::default()
-                            .fg(if is_selected { Theme::CYAN_NEON } else { Theme::TEXT })
+                            .fg(if is_selected { theme().cyan_neon } else { theme().text })
                             .add_modifier(if is_selected { Modifier::BOLD } else { Modifier::empty() }),
                     )),
                 ]));
@@ -916,28 +1055,43 @@ impl Dashboard {
                         format!("   Wallet: {}...{}",
                             &vault.wallet_address[..8],
                             &vault.wallet_address[vault.wallet_address.len()-8..]),
-                        Style::default().fg(Theme::SUBTEXT1),
+                        Style::default().fg(theme().subtext1),
                     )),
                 ]));
+
+                if let Some(description) = &vault.description {
+                    rows.push(Row::new(vec![
+                        Line::from(Span::styled(format!("   {}", description), Style::default().fg(theme().dim))),
+                    ]));
+                }
             }
         }
 
         rows.push(Row::new(vec![Line::from("")]));
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
         rows.push(Row::new(vec![Line::from("")]));
 
         rows.push(Row::new(vec![
             Line::from(vec![
-                Span::styled("[↑↓] ", Style::default().fg(Theme::CYAN_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("Navigate  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[Enter] ", Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("Switch  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[N] ", Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("New  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[D] ", Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("Delete", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("[↑↓] ", Style::default().fg(theme().cyan_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Navigate  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Enter] ", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Switch  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[N] ", Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("New  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[D] ", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Delete", Style::default().fg(theme().subtext1)),
+            ]),
+        ]));
+
+        rows.push(Row::new(vec![
+            Line::from(vec![
+                Span::styled("[R] ", Style::default().fg(theme().purple).add_modifier(Modifier::BOLD)),
+                Span::styled("Rename  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[E] ", Style::default().fg(theme().purple).add_modifier(Modifier::BOLD)),
+                Span::styled("Edit description", Style::default().fg(theme().subtext1)),
             ]),
         ]));
 
@@ -950,25 +1104,25 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ VAULT MANAGEMENT ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .column_spacing(1);
 
         f.render_widget(table, area);
     }
 
     fn render_vault_create_content(&self, f: &mut Frame, area: Rect) {
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let mut rows = vec![];
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("Create a new quantum-resistant vault", Style::default().fg(Theme::TEXT))),
+            Line::from(Span::styled("Create a new quantum-resistant vault", Style::default().fg(theme().text))),
         ]).height(2));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
 
         let vault_display = if self.new_vault_name.is_empty() {
@@ -978,36 +1132,36 @@ impl Dashboard {
         };
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("VAULT NAME", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("VAULT NAME", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled(vault_display, Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled(vault_display, Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD))),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(Theme::YELLOW_NEON))),
+            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(theme().yellow_neon))),
         ]));
 
         rows.push(Row::new(vec![Line::from("")]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("• New keys will be auto-generated", Style::default().fg(Theme::SUBTEXT1))),
+            Line::from(Span::styled("• New keys will be auto-generated", Style::default().fg(theme().subtext1))),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("• Vault will be automatically activated", Style::default().fg(Theme::SUBTEXT1))),
+            Line::from(Span::styled("• Vault will be automatically activated", Style::default().fg(theme().subtext1))),
         ]));
 
         rows.push(Row::new(vec![Line::from("")]));
 
         rows.push(Row::new(vec![
             Line::from(vec![
-                Span::styled("Press ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("Enter", Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" to create • ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("Esc", Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" to go back", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("Press ", Style::default().fg(theme().subtext1)),
+                Span::styled("Enter", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" to create • ", Style::default().fg(theme().subtext1)),
+                Span::styled("Esc", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" to go back", Style::default().fg(theme().subtext1)),
             ]),
         ]));
 
@@ -1020,9 +1174,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ CREATE NEW VAULT ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .column_spacing(2);
 
         f.render_widget(table, area);
@@ -1030,25 +1184,25 @@ impl Dashboard {
 
     fn render_placeholder_content(&self, f: &mut Frame, area: Rect, title: &str, description: &str) {
         // Static gray border color matching splash screen
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let text = vec![
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
                 title,
-                Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)
             )),
             Line::from(""),
             Line::from(Span::styled(
                 description,
-                Style::default().fg(Theme::TEXT)
+                Style::default().fg(theme().text)
             )),
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
                 "Press ENTER to execute or ESC to cancel",
-                Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)
             )),
         ];
 
@@ -1059,9 +1213,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(format!(" ┃ {} ┃ ", title))
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .alignment(Alignment::Center);
 
         f.render_widget(content, area);
@@ -1102,41 +1256,41 @@ impl Dashboard {
             Line::from(""),
             // Glitch effect top
             Line::from(vec![
-                Span::styled(glitch_top.clone(), Style::default().fg(Color::Rgb(0, 150, 200))),
-                Span::styled(glitch_mid.clone(), Style::default().fg(Color::Rgb(140, 140, 140))),
-                Span::styled(glitch_bot.clone(), Style::default().fg(Color::Rgb(180, 0, 200))),
+                Span::styled(glitch_top.clone(), Style::default().fg(theme().quantum_cyan)),
+                Span::styled(glitch_mid.clone(), Style::default().fg(theme().overlay2)),
+                Span::styled(glitch_bot.clone(), Style::default().fg(theme().quantum_magenta)),
             ]),
             Line::from(""),
             // Main message with animation frame indicator
             Line::from(vec![
-                Span::styled("U", Style::default().fg(Color::Rgb(120, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("N", Style::default().fg(Color::Rgb(140, 80, 220)).add_modifier(Modifier::BOLD)),
-                Span::styled("L", Style::default().fg(Color::Rgb(120, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("O", Style::default().fg(Color::Rgb(100, 50, 180)).add_modifier(Modifier::BOLD)),
-                Span::styled("C", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("K", Style::default().fg(Color::Rgb(160, 80, 220)).add_modifier(Modifier::BOLD)),
-                Span::styled("I", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("N", Style::default().fg(Color::Rgb(120, 50, 180)).add_modifier(Modifier::BOLD)),
-                Span::styled("G", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled(format!("{}", glitch_chars[seed % glitch_chars.len()]), Style::default().fg(Color::Rgb(120, 50, 180)).add_modifier(Modifier::BOLD)),
-                Span::styled(format!("{}", glitch_chars[(seed + 1) % glitch_chars.len()]), Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled(format!("{}", glitch_chars[(seed + 2) % glitch_chars.len()]), Style::default().fg(Color::Rgb(160, 80, 220)).add_modifier(Modifier::BOLD)),
+                Span::styled("U", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+                Span::styled("N", Style::default().fg(theme().orange_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("L", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+                Span::styled("O", Style::default().fg(theme().orange_dim).add_modifier(Modifier::BOLD)),
+                Span::styled("C", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("K", Style::default().fg(theme().orange_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("I", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("N", Style::default().fg(theme().orange_dim).add_modifier(Modifier::BOLD)),
+                Span::styled("G", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{}", glitch_chars[seed % glitch_chars.len()]), Style::default().fg(theme().orange_dim).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{}", glitch_chars[(seed + 1) % glitch_chars.len()]), Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{}", glitch_chars[(seed + 2) % glitch_chars.len()]), Style::default().fg(theme().orange_bright).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
             // Glitch effect bottom
             Line::from(vec![
-                Span::styled(glitch_bot, Style::default().fg(Color::Rgb(180, 0, 200))),
-                Span::styled(glitch_mid, Style::default().fg(Color::Rgb(140, 140, 140))),
-                Span::styled(glitch_top, Style::default().fg(Color::Rgb(0, 150, 200))),
+                Span::styled(glitch_bot, Style::default().fg(theme().quantum_magenta)),
+                Span::styled(glitch_mid, Style::default().fg(theme().overlay2)),
+                Span::styled(glitch_top, Style::default().fg(theme().quantum_cyan)),
             ]),
             Line::from(""),
             Line::from(""),
             Line::from(vec![
-                Span::styled("SPHINCS+ SHA2-128s  •  NIST FIPS 205  •  Quantum-Resistant", Style::default().fg(Color::Rgb(100, 100, 100))),
+                Span::styled("SPHINCS+ SHA2-128s  •  NIST FIPS 205  •  Quantum-Resistant", Style::default().fg(theme().subtext0)),
             ]),
         ];
 
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
         let content = Paragraph::new(content_lines)
             .block(
                 Block::default()
@@ -1144,9 +1298,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" UNLOCK ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .alignment(Alignment::Center);
 
         f.render_widget(content, area);
@@ -1187,39 +1341,39 @@ impl Dashboard {
             Line::from(""),
             // Glitch effect top
             Line::from(vec![
-                Span::styled(glitch_top.clone(), Style::default().fg(Color::Rgb(0, 150, 200))),
-                Span::styled(glitch_mid.clone(), Style::default().fg(Color::Rgb(140, 140, 140))),
-                Span::styled(glitch_bot.clone(), Style::default().fg(Color::Rgb(180, 0, 200))),
+                Span::styled(glitch_top.clone(), Style::default().fg(theme().quantum_cyan)),
+                Span::styled(glitch_mid.clone(), Style::default().fg(theme().overlay2)),
+                Span::styled(glitch_bot.clone(), Style::default().fg(theme().quantum_magenta)),
             ]),
             Line::from(""),
             // Main message
             Line::from(vec![
-                Span::styled("L", Style::default().fg(Color::Rgb(120, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("O", Style::default().fg(Color::Rgb(140, 80, 220)).add_modifier(Modifier::BOLD)),
-                Span::styled("C", Style::default().fg(Color::Rgb(120, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("K", Style::default().fg(Color::Rgb(100, 50, 180)).add_modifier(Modifier::BOLD)),
-                Span::styled("I", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("N", Style::default().fg(Color::Rgb(160, 80, 220)).add_modifier(Modifier::BOLD)),
-                Span::styled("G", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled(".", Style::default().fg(Color::Rgb(120, 50, 180)).add_modifier(Modifier::BOLD)),
-                Span::styled(".", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled(".", Style::default().fg(Color::Rgb(160, 80, 220)).add_modifier(Modifier::BOLD)),
+                Span::styled("L", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+                Span::styled("O", Style::default().fg(theme().orange_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("C", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+                Span::styled("K", Style::default().fg(theme().orange_dim).add_modifier(Modifier::BOLD)),
+                Span::styled("I", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("N", Style::default().fg(theme().orange_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("G", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(".", Style::default().fg(theme().orange_dim).add_modifier(Modifier::BOLD)),
+                Span::styled(".", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(".", Style::default().fg(theme().orange_bright).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
             // Glitch effect bottom
             Line::from(vec![
-                Span::styled(glitch_bot, Style::default().fg(Color::Rgb(180, 0, 200))),
-                Span::styled(glitch_mid, Style::default().fg(Color::Rgb(140, 140, 140))),
-                Span::styled(glitch_top, Style::default().fg(Color::Rgb(0, 150, 200))),
+                Span::styled(glitch_bot, Style::default().fg(theme().quantum_magenta)),
+                Span::styled(glitch_mid, Style::default().fg(theme().overlay2)),
+                Span::styled(glitch_top, Style::default().fg(theme().quantum_cyan)),
             ]),
             Line::from(""),
             Line::from(""),
             Line::from(vec![
-                Span::styled("SPHINCS+ SHA2-128s  •  NIST FIPS 205  •  Quantum-Resistant", Style::default().fg(Color::Rgb(100, 100, 100))),
+                Span::styled("SPHINCS+ SHA2-128s  •  NIST FIPS 205  •  Quantum-Resistant", Style::default().fg(theme().subtext0)),
             ]),
         ];
 
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
         let content = Paragraph::new(content_lines)
             .block(
                 Block::default()
@@ -1227,11 +1381,17 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" LOCK ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .alignment(Alignment::Center);
 
         f.render_widget(content, area);
     }
 }
+
+/// Host (and port) of an RPC URL; the path and query often carry API keys
+fn rpc_host(url: &str) -> &str {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    without_scheme.split(['/', '?']).next().unwrap_or(without_scheme)
+}
@@ -3,15 +3,25 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, BorderType, Clear, Paragraph, Row, Table, Wrap},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, BorderType, Clear, Paragraph, Row, Table, Wrap},
 };
+use crate::applog::{self, Level, LogLine};
+use crate::dashboard::keymap::BoundAction;
+use crate::dashboard::toast::ToastLevel;
+use crate::qr;
 use crate::dashboard::types::*;
+use crate::solana::client::LeaderboardEntry;
+use crate::solana::units::{decimals, to_tokens};
 use crate::icons::Icons;
-use crate::theme::Theme;
+use crate::theme::theme;
 use crate::vault_manager::VaultConfig;
 
 /// Helper function to create a centered rectangle
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    // The percentages suit a large terminal; on a small one, popups take
+    // (nearly) the whole screen rather than clip their contents
+    let percent_x = if r.width < COMPACT_WIDTH { percent_x.max(96) } else { percent_x };
+    let percent_y = if r.height < COMPACT_HEIGHT { percent_y.max(96) } else { percent_y };
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -31,6 +41,30 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
+/// Price with enough significant digits for sub-cent tokens
+/// Row advertising the MAX / HALF / 25% keys of an amount field
+fn amount_shortcuts_row() -> Row<'static> {
+    Row::new(vec![
+        Line::from(Span::styled("AMOUNT KEYS", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
+        Line::from(vec![
+            Span::styled(" [M] ", Style::default().fg(theme().text).bg(theme().blue).add_modifier(Modifier::BOLD)),
+            Span::styled(" Max  ", Style::default().fg(theme().text)),
+            Span::styled(" [H] ", Style::default().fg(theme().text).bg(theme().blue).add_modifier(Modifier::BOLD)),
+            Span::styled(" Half  ", Style::default().fg(theme().text)),
+            Span::styled(" [Q] ", Style::default().fg(theme().text).bg(theme().blue).add_modifier(Modifier::BOLD)),
+            Span::styled(" 25%", Style::default().fg(theme().text)),
+        ]),
+    ])
+}
+
+fn format_price(price: f64) -> String {
+    if price >= 1.0 {
+        format!("${:.2}", price)
+    } else {
+        format!("${:.6}", price)
+    }
+}
+
 impl Dashboard {
     pub fn render_action_popup(&self, f: &mut Frame, area: Rect, title: &str, title_color: Color) {
         let popup_area = centered_rect(85, 75, area);  // Wider popup for long messages
@@ -43,16 +77,16 @@ impl Dashboard {
 
         if self.action_steps.is_empty() {
             content_lines.push(Line::from(vec![
-                Span::styled("STATUS: ", Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
-                Span::styled("Initializing...", Style::default().fg(Theme::SUBTEXT0).add_modifier(Modifier::ITALIC)),
+                Span::styled("STATUS: ", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+                Span::styled("Initializing...", Style::default().fg(theme().subtext0).add_modifier(Modifier::ITALIC)),
             ]));
         } else {
             for (idx, step) in self.action_steps.iter().enumerate() {
                 let (icon, message, color) = match step {
-                    ActionStep::Starting => ("⏳", "Preparing...", Theme::YELLOW_NEON),
-                    ActionStep::InProgress(msg) => ("⚡", msg.as_str(), Theme::CYAN_NEON),
-                    ActionStep::Success(msg) => ("✓", msg.as_str(), Theme::GREEN_NEON),
-                    ActionStep::Error(msg) => ("✗", msg.as_str(), Theme::RED_NEON),
+                    ActionStep::Starting => ("⏳", "Preparing...", theme().yellow_neon),
+                    ActionStep::InProgress(msg) => ("⚡", msg.as_str(), theme().cyan_neon),
+                    ActionStep::Success(msg) => ("✓", msg.as_str(), theme().green_neon),
+                    ActionStep::Error(msg) => ("✗", msg.as_str(), theme().red_neon),
                 };
 
                 let step_label = format!("STEP {}:", idx + 1);
@@ -62,7 +96,7 @@ impl Dashboard {
                 if message.len() > max_width {
                     // First line with step label and icon
                     content_lines.push(Line::from(vec![
-                        Span::styled(step_label, Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                        Span::styled(step_label, Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
                     ]));
 
                     // Message on next line with icon, allowing wrapping
@@ -73,7 +107,7 @@ impl Dashboard {
                 } else {
                     // Single line for short messages
                     content_lines.push(Line::from(vec![
-                        Span::styled(format!("{} ", step_label), Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                        Span::styled(format!("{} ", step_label), Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
                         Span::styled(format!("{} ", icon), Style::default().fg(color).add_modifier(Modifier::BOLD)),
                         Span::styled(message, Style::default().fg(color)),
                     ]));
@@ -81,7 +115,7 @@ impl Dashboard {
 
                 // Add separator between steps
                 if idx < self.action_steps.len() - 1 {
-                    content_lines.push(Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))));
+                    content_lines.push(Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))));
                 }
                 content_lines.push(Line::from(""));
             }
@@ -89,27 +123,24 @@ impl Dashboard {
 
         // Add spacing and controls
         content_lines.push(Line::from(""));
-        content_lines.push(Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))));
+        content_lines.push(Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))));
         content_lines.push(Line::from(""));
         content_lines.push(Line::from(vec![
-            Span::styled(" [ESC] ", Style::default().fg(Theme::BASE).bg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-            Span::styled(" Close", Style::default().fg(Theme::TEXT)),
+            Span::styled(" [ESC] ", Style::default().fg(theme().base).bg(theme().red_neon).add_modifier(Modifier::BOLD)),
+            Span::styled(" Close", Style::default().fg(theme().text)),
         ]));
 
         // Static gray border matching main dashboard
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
-        let paragraph = Paragraph::new(content_lines)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
-                    .border_type(BorderType::Double)
-                    .title(format!(" ┃ {} ┃ ", title))
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
-            )
-            .style(Style::default().bg(Theme::BASE))
-            .wrap(ratatui::widgets::Wrap { trim: false });
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
+            .border_type(BorderType::Double)
+            .title(format!(" ┃ {} ┃ ", title))
+            .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD));
+        let paragraph = self.scrollable_popup(content_lines, block, popup_area)
+            .style(Style::default().bg(theme().base));
 
         f.render_widget(paragraph, popup_area);
     }
@@ -150,43 +181,43 @@ impl Dashboard {
             Line::from(""),
             // Glitch effect top
             Line::from(vec![
-                Span::styled(glitch_top.clone(), Style::default().fg(Color::Rgb(0, 150, 200))),
-                Span::styled(glitch_mid.clone(), Style::default().fg(Color::Rgb(140, 140, 140))),
-                Span::styled(glitch_bot.clone(), Style::default().fg(Color::Rgb(180, 0, 200))),
+                Span::styled(glitch_top.clone(), Style::default().fg(theme().quantum_cyan)),
+                Span::styled(glitch_mid.clone(), Style::default().fg(theme().overlay2)),
+                Span::styled(glitch_bot.clone(), Style::default().fg(theme().quantum_magenta)),
             ]),
             Line::from(""),
             // Main message
             Line::from(vec![
-                Span::styled("U", Style::default().fg(Color::Rgb(120, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("N", Style::default().fg(Color::Rgb(140, 80, 220)).add_modifier(Modifier::BOLD)),
-                Span::styled("L", Style::default().fg(Color::Rgb(120, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("O", Style::default().fg(Color::Rgb(100, 50, 180)).add_modifier(Modifier::BOLD)),
-                Span::styled("C", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("K", Style::default().fg(Color::Rgb(160, 80, 220)).add_modifier(Modifier::BOLD)),
-                Span::styled("I", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("N", Style::default().fg(Color::Rgb(120, 50, 180)).add_modifier(Modifier::BOLD)),
-                Span::styled("G", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled(".", Style::default().fg(Color::Rgb(120, 50, 180)).add_modifier(Modifier::BOLD)),
-                Span::styled(".", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled(".", Style::default().fg(Color::Rgb(160, 80, 220)).add_modifier(Modifier::BOLD)),
+                Span::styled("U", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+                Span::styled("N", Style::default().fg(theme().orange_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("L", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+                Span::styled("O", Style::default().fg(theme().orange_dim).add_modifier(Modifier::BOLD)),
+                Span::styled("C", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("K", Style::default().fg(theme().orange_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("I", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("N", Style::default().fg(theme().orange_dim).add_modifier(Modifier::BOLD)),
+                Span::styled("G", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(".", Style::default().fg(theme().orange_dim).add_modifier(Modifier::BOLD)),
+                Span::styled(".", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(".", Style::default().fg(theme().orange_bright).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
             // Glitch effect bottom
             Line::from(vec![
-                Span::styled(glitch_bot, Style::default().fg(Color::Rgb(180, 0, 200))),
-                Span::styled(glitch_mid, Style::default().fg(Color::Rgb(140, 140, 140))),
-                Span::styled(glitch_top, Style::default().fg(Color::Rgb(0, 150, 200))),
+                Span::styled(glitch_bot, Style::default().fg(theme().quantum_magenta)),
+                Span::styled(glitch_mid, Style::default().fg(theme().overlay2)),
+                Span::styled(glitch_top, Style::default().fg(theme().quantum_cyan)),
             ]),
             Line::from(""),
             Line::from(""),
             Line::from(vec![
-                Span::styled("SPHINCS+ SHA2-128s  •  NIST FIPS 205  •  Quantum-Resistant", Style::default().fg(Color::Rgb(100, 100, 100))),
+                Span::styled("SPHINCS+ SHA2-128s  •  NIST FIPS 205  •  Quantum-Resistant", Style::default().fg(theme().subtext0)),
             ]),
             Line::from(""),
             Line::from(""),
             Line::from(vec![
-                Span::styled(" [ESC] ", Style::default().fg(Theme::BASE).bg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" Cancel", Style::default().fg(Theme::TEXT)),
+                Span::styled(" [ESC] ", Style::default().fg(theme().base).bg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" Cancel", Style::default().fg(theme().text)),
             ]),
         ];
 
@@ -194,10 +225,10 @@ impl Dashboard {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::Rgb(140, 140, 140)).add_modifier(Modifier::BOLD))
+                    .border_style(Style::default().fg(theme().overlay2).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .alignment(Alignment::Center);
 
         f.render_widget(content, popup_area);
@@ -239,41 +270,41 @@ impl Dashboard {
             Line::from(""),
             // Glitch effect top
             Line::from(vec![
-                Span::styled(glitch_top.clone(), Style::default().fg(Color::Rgb(0, 150, 200))),
-                Span::styled(glitch_mid.clone(), Style::default().fg(Color::Rgb(140, 140, 140))),
-                Span::styled(glitch_bot.clone(), Style::default().fg(Color::Rgb(180, 0, 200))),
+                Span::styled(glitch_top.clone(), Style::default().fg(theme().quantum_cyan)),
+                Span::styled(glitch_mid.clone(), Style::default().fg(theme().overlay2)),
+                Span::styled(glitch_bot.clone(), Style::default().fg(theme().quantum_magenta)),
             ]),
             Line::from(""),
             // Main message
             Line::from(vec![
-                Span::styled("L", Style::default().fg(Color::Rgb(120, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("O", Style::default().fg(Color::Rgb(140, 80, 220)).add_modifier(Modifier::BOLD)),
-                Span::styled("C", Style::default().fg(Color::Rgb(120, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("K", Style::default().fg(Color::Rgb(100, 50, 180)).add_modifier(Modifier::BOLD)),
-                Span::styled("I", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled("N", Style::default().fg(Color::Rgb(160, 80, 220)).add_modifier(Modifier::BOLD)),
-                Span::styled("G", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled(".", Style::default().fg(Color::Rgb(120, 50, 180)).add_modifier(Modifier::BOLD)),
-                Span::styled(".", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                Span::styled(".", Style::default().fg(Color::Rgb(160, 80, 220)).add_modifier(Modifier::BOLD)),
+                Span::styled("L", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+                Span::styled("O", Style::default().fg(theme().orange_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("C", Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+                Span::styled("K", Style::default().fg(theme().orange_dim).add_modifier(Modifier::BOLD)),
+                Span::styled("I", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("N", Style::default().fg(theme().orange_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("G", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(".", Style::default().fg(theme().orange_dim).add_modifier(Modifier::BOLD)),
+                Span::styled(".", Style::default().fg(theme().purple_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(".", Style::default().fg(theme().orange_bright).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
             // Glitch effect bottom
             Line::from(vec![
-                Span::styled(glitch_bot, Style::default().fg(Color::Rgb(180, 0, 200))),
-                Span::styled(glitch_mid, Style::default().fg(Color::Rgb(140, 140, 140))),
-                Span::styled(glitch_top, Style::default().fg(Color::Rgb(0, 150, 200))),
+                Span::styled(glitch_bot, Style::default().fg(theme().quantum_magenta)),
+                Span::styled(glitch_mid, Style::default().fg(theme().overlay2)),
+                Span::styled(glitch_top, Style::default().fg(theme().quantum_cyan)),
             ]),
             Line::from(""),
             Line::from(""),
             Line::from(vec![
-                Span::styled("SPHINCS+ SHA2-128s  •  NIST FIPS 205  •  Quantum-Resistant", Style::default().fg(Color::Rgb(100, 100, 100))),
+                Span::styled("SPHINCS+ SHA2-128s  •  NIST FIPS 205  •  Quantum-Resistant", Style::default().fg(theme().subtext0)),
             ]),
             Line::from(""),
             Line::from(""),
             Line::from(vec![
-                Span::styled(" [ESC] ", Style::default().fg(Theme::BASE).bg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" Cancel", Style::default().fg(Theme::TEXT)),
+                Span::styled(" [ESC] ", Style::default().fg(theme().base).bg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" Cancel", Style::default().fg(theme().text)),
             ]),
         ];
 
@@ -281,10 +312,10 @@ impl Dashboard {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::Rgb(140, 140, 140)).add_modifier(Modifier::BOLD))
+                    .border_style(Style::default().fg(theme().overlay2).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .alignment(Alignment::Center);
 
         f.render_widget(content, popup_area);
@@ -299,35 +330,35 @@ impl Dashboard {
         let mut rows = vec![];
 
         // Show both balances
-        let standard_balance_qdum = self.standard_balance.map(|b| b as f64 / 1_000_000.0).unwrap_or(0.0);
-        let pq_balance_qdum = self.pq_balance.map(|b| b as f64 / 1_000_000.0).unwrap_or(0.0);
+        let standard_balance_qdum = self.standard_balance.map(|b| to_tokens(b, decimals(&self.standard_mint))).unwrap_or(0.0);
+        let pq_balance_qdum = self.pq_balance.map(|b| to_tokens(b, decimals(&self.pq_mint))).unwrap_or(0.0);
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("STANDARD QCOIN", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("STANDARD QCOIN", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
             Line::from(Span::styled(
                 format!("{:.6} qcoin", standard_balance_qdum),
-                Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD),
             )),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("PQ QCOIN", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("PQ QCOIN", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
             Line::from(Span::styled(
                 format!("{:.6} pqcoin", pq_balance_qdum),
-                Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD),
             )),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
 
         // Token type selector
         let token_type_color = if self.transfer_focused_field == TransferInputField::TokenType {
-            Theme::YELLOW_NEON
+            theme().yellow_neon
         } else {
-            Theme::TEXT
+            theme().text
         };
 
         let (token_type_display, token_note) = match self.transfer_token_type {
@@ -342,35 +373,35 @@ impl Dashboard {
         };
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("TOKEN TYPE", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("TOKEN TYPE", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
             Line::from(vec![
                 Span::styled(token_type_display, Style::default().fg(token_type_color).add_modifier(Modifier::BOLD)),
-                Span::styled(token_type_indicator, Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)),
+                Span::styled(token_type_indicator, Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
             ]),
         ]));
 
         if self.transfer_focused_field == TransferInputField::TokenType {
             rows.push(Row::new(vec![
                 Line::from(""),
-                Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(Theme::YELLOW_NEON))),
+                Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(theme().yellow_neon))),
             ]));
         }
 
         rows.push(Row::new(vec![
             Line::from(""),
-            Line::from(Span::styled(token_note, Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled(token_note, Style::default().fg(theme().dim))),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
 
         // Recipient field
         let recipient_color = if self.transfer_focused_field == TransferInputField::Recipient {
-            Theme::YELLOW_NEON
+            theme().yellow_neon
         } else {
-            Theme::TEXT
+            theme().text
         };
 
         let recipient_display = if self.transfer_recipient.is_empty() {
@@ -386,30 +417,34 @@ impl Dashboard {
         };
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("RECIPIENT", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("RECIPIENT", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
             Line::from(vec![
                 Span::styled(recipient_display, Style::default().fg(recipient_color).add_modifier(Modifier::BOLD)),
-                Span::styled(recipient_indicator, Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)),
+                Span::styled(recipient_indicator, Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
             ]),
         ]));
 
+        if let Some(check) = self.recipient_check_span() {
+            rows.push(Row::new(vec![Line::from(""), Line::from(check)]));
+        }
+
         if self.transfer_focused_field == TransferInputField::Recipient {
             rows.push(Row::new(vec![
                 Line::from(""),
-                Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(Theme::YELLOW_NEON))),
+                Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(theme().yellow_neon))),
             ]));
         }
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
 
         // Amount field
         let amount_color = if self.transfer_focused_field == TransferInputField::Amount {
-            Theme::YELLOW_NEON
+            theme().yellow_neon
         } else {
-            Theme::TEXT
+            theme().text
         };
 
         let token_symbol = match self.transfer_token_type {
@@ -430,50 +465,59 @@ impl Dashboard {
         };
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("AMOUNT", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("AMOUNT", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
             Line::from(vec![
                 Span::styled(amount_display, Style::default().fg(amount_color).add_modifier(Modifier::BOLD)),
-                Span::styled(amount_indicator, Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)),
+                Span::styled(amount_indicator, Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
             ]),
         ]));
 
         if self.transfer_focused_field == TransferInputField::Amount {
             rows.push(Row::new(vec![
                 Line::from(""),
-                Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(Theme::YELLOW_NEON))),
+                Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(theme().yellow_neon))),
+            ]));
+        }
+
+        if let Some(fee) = self.transfer_fee_summary() {
+            rows.push(Row::new(vec![
+                Line::from(""),
+                Line::from(Span::styled(fee, Style::default().fg(theme().yellow_neon))),
             ]));
         }
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
 
+        rows.push(amount_shortcuts_row());
+
         // Controls row
         rows.push(Row::new(vec![
-            Line::from(Span::styled("CONTROLS", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("CONTROLS", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
             Line::from(vec![
-                Span::styled(" [Tab/↑↓] ", Style::default().fg(Theme::TEXT).bg(Theme::BLUE).add_modifier(Modifier::BOLD)),
-                Span::styled(" Switch  ", Style::default().fg(Theme::TEXT)),
-                Span::styled(" [←→] ", Style::default().fg(Theme::TEXT).bg(Theme::PURPLE).add_modifier(Modifier::BOLD)),
-                Span::styled(" Type  ", Style::default().fg(Theme::TEXT)),
+                Span::styled(" [Tab/↑↓] ", Style::default().fg(theme().text).bg(theme().blue).add_modifier(Modifier::BOLD)),
+                Span::styled(" Switch  ", Style::default().fg(theme().text)),
+                Span::styled(" [←→] ", Style::default().fg(theme().text).bg(theme().purple).add_modifier(Modifier::BOLD)),
+                Span::styled(" Type  ", Style::default().fg(theme().text)),
             ]),
         ]));
 
         rows.push(Row::new(vec![
             Line::from(""),
             Line::from(vec![
-                Span::styled(" [Enter] ", Style::default().fg(Theme::TEXT).bg(Theme::GREEN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Send  ", Style::default().fg(Theme::TEXT)),
-                Span::styled(" [Esc] ", Style::default().fg(Theme::TEXT).bg(Theme::RED).add_modifier(Modifier::BOLD)),
-                Span::styled(" Cancel", Style::default().fg(Theme::TEXT)),
+                Span::styled(" [Enter] ", Style::default().fg(theme().text).bg(theme().green).add_modifier(Modifier::BOLD)),
+                Span::styled(" Send  ", Style::default().fg(theme().text)),
+                Span::styled(" [Esc] ", Style::default().fg(theme().text).bg(theme().red).add_modifier(Modifier::BOLD)),
+                Span::styled(" Cancel", Style::default().fg(theme().text)),
             ]),
         ]));
 
         let widths = [Constraint::Length(14), Constraint::Min(38)];
 
         // Static gray border matching main dashboard
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let table = Table::new(rows, widths)
             .block(
@@ -482,9 +526,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ TRANSFER TOKENS ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .column_spacing(2);
 
         f.render_widget(table, popup_area);
@@ -500,30 +544,30 @@ impl Dashboard {
 
         // Standard CASH balance row
         if let Some(balance) = self.standard_balance {
-            let balance_qdum = balance as f64 / 1_000_000.0;
+            let balance_qdum = to_tokens(balance, decimals(&self.standard_mint));
             rows.push(Row::new(vec![
-                Line::from(Span::styled("STANDARD QCOIN", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled("STANDARD QCOIN", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
                 Line::from(Span::styled(
                     format!("{:.6} qcoin", balance_qdum),
-                    Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD),
                 )),
             ]));
 
             rows.push(Row::new(vec![
-                Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-                Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+                Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+                Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
             ]));
         }
 
         // Direction indicator
         rows.push(Row::new(vec![
-            Line::from(Span::styled("DIRECTION", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
-            Line::from(Span::styled("Standard qcoin → pqcoin", Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("DIRECTION", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("Standard qcoin → pqcoin", Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD))),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
 
         // Amount field
@@ -534,38 +578,40 @@ impl Dashboard {
         };
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("AMOUNT", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("AMOUNT", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
             Line::from(vec![
-                Span::styled(amount_display, Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" ◀ ACTIVE", Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)),
+                Span::styled(amount_display, Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" ◀ ACTIVE", Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
             ]),
         ]));
 
         rows.push(Row::new(vec![
             Line::from(""),
-            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(Theme::YELLOW_NEON))),
+            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(theme().yellow_neon))),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
 
+        rows.push(amount_shortcuts_row());
+
         // Controls row
         rows.push(Row::new(vec![
-            Line::from(Span::styled("CONTROLS", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("CONTROLS", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
             Line::from(vec![
-                Span::styled(" [Enter] ", Style::default().fg(Theme::TEXT).bg(Theme::GREEN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Wrap  ", Style::default().fg(Theme::TEXT)),
-                Span::styled(" [Esc] ", Style::default().fg(Theme::TEXT).bg(Theme::RED).add_modifier(Modifier::BOLD)),
-                Span::styled(" Cancel", Style::default().fg(Theme::TEXT)),
+                Span::styled(" [Enter] ", Style::default().fg(theme().text).bg(theme().green).add_modifier(Modifier::BOLD)),
+                Span::styled(" Wrap  ", Style::default().fg(theme().text)),
+                Span::styled(" [Esc] ", Style::default().fg(theme().text).bg(theme().red).add_modifier(Modifier::BOLD)),
+                Span::styled(" Cancel", Style::default().fg(theme().text)),
             ]),
         ]));
 
         let widths = [Constraint::Length(14), Constraint::Min(38)];
 
         // Static gray border matching main dashboard
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let table = Table::new(rows, widths)
             .block(
@@ -574,9 +620,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ WRAP TO PQCOIN ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .column_spacing(2);
 
         f.render_widget(table, popup_area);
@@ -592,30 +638,30 @@ impl Dashboard {
 
         // PQ CASH balance row
         if let Some(balance) = self.pq_balance {
-            let balance_qdum = balance as f64 / 1_000_000.0;
+            let balance_qdum = to_tokens(balance, decimals(&self.pq_mint));
             rows.push(Row::new(vec![
-                Line::from(Span::styled("PQ QCOIN", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled("PQ QCOIN", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
                 Line::from(Span::styled(
                     format!("{:.6} pqcoin", balance_qdum),
-                    Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD),
                 )),
             ]));
 
             rows.push(Row::new(vec![
-                Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-                Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+                Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+                Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
             ]));
         }
 
         // Direction indicator
         rows.push(Row::new(vec![
-            Line::from(Span::styled("DIRECTION", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
-            Line::from(Span::styled("pqcoin → Standard qcoin", Style::default().fg(Theme::CYAN_NEON).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("DIRECTION", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("pqcoin → Standard qcoin", Style::default().fg(theme().cyan_neon).add_modifier(Modifier::BOLD))),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
 
         // Amount field
@@ -626,38 +672,40 @@ impl Dashboard {
         };
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("AMOUNT", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("AMOUNT", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
             Line::from(vec![
-                Span::styled(amount_display, Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" ◀ ACTIVE", Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)),
+                Span::styled(amount_display, Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" ◀ ACTIVE", Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
             ]),
         ]));
 
         rows.push(Row::new(vec![
             Line::from(""),
-            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(Theme::YELLOW_NEON))),
+            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(theme().yellow_neon))),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
 
+        rows.push(amount_shortcuts_row());
+
         // Controls row
         rows.push(Row::new(vec![
-            Line::from(Span::styled("CONTROLS", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("CONTROLS", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
             Line::from(vec![
-                Span::styled(" [Enter] ", Style::default().fg(Theme::TEXT).bg(Theme::GREEN).add_modifier(Modifier::BOLD)),
-                Span::styled(" Unwrap  ", Style::default().fg(Theme::TEXT)),
-                Span::styled(" [Esc] ", Style::default().fg(Theme::TEXT).bg(Theme::RED).add_modifier(Modifier::BOLD)),
-                Span::styled(" Cancel", Style::default().fg(Theme::TEXT)),
+                Span::styled(" [Enter] ", Style::default().fg(theme().text).bg(theme().green).add_modifier(Modifier::BOLD)),
+                Span::styled(" Unwrap  ", Style::default().fg(theme().text)),
+                Span::styled(" [Esc] ", Style::default().fg(theme().text).bg(theme().red).add_modifier(Modifier::BOLD)),
+                Span::styled(" Cancel", Style::default().fg(theme().text)),
             ]),
         ]));
 
         let widths = [Constraint::Length(14), Constraint::Min(38)];
 
         // Static gray border matching main dashboard
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let table = Table::new(rows, widths)
             .block(
@@ -666,9 +714,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ UNWRAP TO STANDARD QCOIN ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .column_spacing(2);
 
         f.render_widget(table, popup_area);
@@ -697,11 +745,11 @@ impl Dashboard {
 
         // Header
         rows.push(Row::new(vec![
-            Line::from(Span::styled("Select a vault to switch, or create a new one", Style::default().fg(Theme::TEXT))),
+            Line::from(Span::styled("Select a vault to switch, or create a new one", Style::default().fg(theme().text))),
         ]).height(2));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
 
         // Vault list
@@ -713,17 +761,17 @@ impl Dashboard {
             let status = if is_active { " [ACTIVE]" } else { "" };
 
             let name_color = if is_selected {
-                Theme::YELLOW_NEON
+                theme().yellow_neon
             } else if is_active {
-                Theme::GREEN_NEON
+                theme().green_neon
             } else {
-                Theme::TEXT
+                theme().text
             };
 
             let indicator_color = if is_active {
-                Theme::GREEN_NEON
+                theme().green_neon
             } else {
-                Theme::DIM
+                theme().dim
             };
 
             let mut spans = vec![
@@ -734,11 +782,11 @@ impl Dashboard {
             ];
 
             if is_active {
-                spans.push(Span::styled(status, Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)));
+                spans.push(Span::styled(status, Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)));
             }
 
             if is_selected {
-                spans.insert(0, Span::styled("▶ ", Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)));
+                spans.insert(0, Span::styled("▶ ", Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)));
             } else {
                 spans.insert(0, Span::styled("  ", Style::default()));
             }
@@ -753,7 +801,7 @@ impl Dashboard {
             };
 
             rows.push(Row::new(vec![
-                Line::from(Span::styled(wallet_info, Style::default().fg(Theme::DIM))),
+                Line::from(Span::styled(wallet_info, Style::default().fg(theme().dim))),
             ]));
         }
 
@@ -763,9 +811,9 @@ impl Dashboard {
         // "Create New Vault" option
         let is_create_selected = self.selected_vault_index == self.vault_list.len();
         let create_color = if is_create_selected {
-            Theme::YELLOW_NEON
+            theme().yellow_neon
         } else {
-            Theme::GREEN_NEON
+            theme().green_neon
         };
 
         let mut create_spans = vec![
@@ -774,7 +822,7 @@ impl Dashboard {
         ];
 
         if is_create_selected {
-            create_spans.insert(0, Span::styled("▶ ", Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)));
+            create_spans.insert(0, Span::styled("▶ ", Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)));
         } else {
             create_spans.insert(0, Span::styled("  ", Style::default()));
         }
@@ -786,29 +834,33 @@ impl Dashboard {
         // Controls - Line 1
         rows.push(Row::new(vec![
             Line::from(vec![
-                Span::styled("↑↓/jk", Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" Navigate  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("Enter", Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" Select  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("N", Style::default().fg(Theme::PURPLE).add_modifier(Modifier::BOLD)),
-                Span::styled(" New", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("↑↓/jk", Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" Navigate  ", Style::default().fg(theme().subtext1)),
+                Span::styled("Enter", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" Select  ", Style::default().fg(theme().subtext1)),
+                Span::styled("N", Style::default().fg(theme().purple).add_modifier(Modifier::BOLD)),
+                Span::styled(" New", Style::default().fg(theme().subtext1)),
             ]),
         ]));
 
         // Controls - Line 2
         rows.push(Row::new(vec![
             Line::from(vec![
-                Span::styled("D", Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" Delete  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("Esc", Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" Cancel", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("R", Style::default().fg(theme().purple).add_modifier(Modifier::BOLD)),
+                Span::styled(" Rename  ", Style::default().fg(theme().subtext1)),
+                Span::styled("E", Style::default().fg(theme().purple).add_modifier(Modifier::BOLD)),
+                Span::styled(" Description  ", Style::default().fg(theme().subtext1)),
+                Span::styled("D", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" Delete  ", Style::default().fg(theme().subtext1)),
+                Span::styled("Esc", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" Cancel", Style::default().fg(theme().subtext1)),
             ]),
         ]));
 
         let widths = [Constraint::Percentage(100)];
 
         // Static gray border matching main dashboard
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let table = Table::new(rows, widths)
             .block(
@@ -817,9 +869,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ VAULT MANAGEMENT ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .column_spacing(1);
 
         f.render_widget(table, popup_area);
@@ -835,11 +887,11 @@ impl Dashboard {
 
         // Instruction row
         rows.push(Row::new(vec![
-            Line::from(Span::styled("Create a new quantum-resistant vault", Style::default().fg(Theme::TEXT))),
+            Line::from(Span::styled("Create a new quantum-resistant vault", Style::default().fg(theme().text))),
         ]).height(2));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
 
         // Vault name field
@@ -850,26 +902,26 @@ impl Dashboard {
         };
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("VAULT NAME", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("VAULT NAME", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled(vault_display, Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled(vault_display, Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD))),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(Theme::YELLOW_NEON))),
+            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(theme().yellow_neon))),
         ]));
 
         rows.push(Row::new(vec![Line::from("")]));
 
         // Info row
         rows.push(Row::new(vec![
-            Line::from(Span::styled("• New keys will be auto-generated", Style::default().fg(Theme::SUBTEXT1))),
+            Line::from(Span::styled("• New keys will be auto-generated", Style::default().fg(theme().subtext1))),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("• Vault will be automatically activated", Style::default().fg(Theme::SUBTEXT1))),
+            Line::from(Span::styled("• Vault will be automatically activated", Style::default().fg(theme().subtext1))),
         ]));
 
         rows.push(Row::new(vec![Line::from("")]));
@@ -877,18 +929,18 @@ impl Dashboard {
         // Controls row
         rows.push(Row::new(vec![
             Line::from(vec![
-                Span::styled("Press ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("Enter", Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" to create • ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("Esc", Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" to go back", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("Press ", Style::default().fg(theme().subtext1)),
+                Span::styled("Enter", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" to create • ", Style::default().fg(theme().subtext1)),
+                Span::styled("Esc", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" to go back", Style::default().fg(theme().subtext1)),
             ]),
         ]));
 
         let widths = [Constraint::Percentage(100)];
 
         // Static gray border matching main dashboard
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         let table = Table::new(rows, widths)
             .block(
@@ -897,9 +949,9 @@ impl Dashboard {
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
                     .title(" ┃ CREATE NEW VAULT ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
             )
-            .style(Style::default().bg(Theme::BASE))
+            .style(Style::default().bg(theme().base))
             .column_spacing(2);
 
         f.render_widget(table, popup_area);
@@ -915,7 +967,7 @@ impl Dashboard {
         let success = !has_error;
 
         // Static gray border matching main dashboard
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
         let title = if success { " ┃ SUCCESS ┃ " } else { " ┃ ERROR ┃ " };
 
         // Build content lines with improved formatting
@@ -925,7 +977,7 @@ impl Dashboard {
         if self.action_steps.is_empty() {
             content_lines.push(Line::from(Span::styled(
                 "No result to display",
-                Style::default().fg(Theme::SUBTEXT1).add_modifier(Modifier::ITALIC)
+                Style::default().fg(theme().subtext1).add_modifier(Modifier::ITALIC)
             )));
         } else {
             for step in &self.action_steps {
@@ -933,7 +985,7 @@ impl Dashboard {
                     ActionStep::Starting => {
                         content_lines.push(Line::from(Span::styled(
                             "  ⏳ Starting...",
-                            Style::default().fg(Theme::YELLOW_NEON)
+                            Style::default().fg(theme().yellow_neon)
                         )));
                     }
                     ActionStep::InProgress(msg) => {
@@ -943,7 +995,7 @@ impl Dashboard {
                         } else {
                             content_lines.push(Line::from(Span::styled(
                                 format!("  {}", msg),
-                                Style::default().fg(Theme::SUBTEXT1)
+                                Style::default().fg(theme().subtext1)
                             )));
                         }
                     }
@@ -953,14 +1005,14 @@ impl Dashboard {
                             // Box characters - keep centered
                             content_lines.push(Line::from(Span::styled(
                                 msg.clone(),
-                                Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)
+                                Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)
                             )));
                         } else if msg.is_empty() {
                             content_lines.push(Line::from(""));
                         } else {
                             content_lines.push(Line::from(Span::styled(
                                 format!("  ✓ {}", msg),
-                                Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)
+                                Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)
                             )));
                         }
                     }
@@ -970,13 +1022,13 @@ impl Dashboard {
                             // Box characters - keep as is
                             content_lines.push(Line::from(Span::styled(
                                 msg.clone(),
-                                Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)
+                                Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)
                             )));
                         } else if msg.starts_with("❌") {
                             // Already has emoji, keep as is
                             content_lines.push(Line::from(Span::styled(
                                 format!("  {}", msg),
-                                Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)
+                                Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)
                             )));
                         } else if msg.is_empty() {
                             content_lines.push(Line::from(""));
@@ -985,21 +1037,21 @@ impl Dashboard {
                             content_lines.push(Line::from(""));
                             content_lines.push(Line::from(Span::styled(
                                 "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━",
-                                Style::default().fg(Theme::RED_NEON)
+                                Style::default().fg(theme().red_neon)
                             )));
                             content_lines.push(Line::from(Span::styled(
                                 format!("  ⚠️  {}", msg),
-                                Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)
+                                Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)
                             )));
                             content_lines.push(Line::from(Span::styled(
                                 "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━",
-                                Style::default().fg(Theme::RED_NEON)
+                                Style::default().fg(theme().red_neon)
                             )));
                             content_lines.push(Line::from(""));
                         } else {
                             content_lines.push(Line::from(Span::styled(
                                 format!("  ✗ {}", msg),
-                                Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)
+                                Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)
                             )));
                         }
                     }
@@ -1013,22 +1065,20 @@ impl Dashboard {
 
         // Add instruction
         content_lines.push(Line::from(vec![
-            Span::styled(" [ESC] ", Style::default().fg(Theme::BASE).bg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-            Span::styled(" Close this window", Style::default().fg(Theme::TEXT)),
+            Span::styled(" [ESC] ", Style::default().fg(theme().base).bg(theme().red_neon).add_modifier(Modifier::BOLD)),
+            Span::styled(" Close this window", Style::default().fg(theme().text)),
         ]));
 
-        let title_color = if success { Theme::GREEN_NEON } else { Theme::RED_NEON };
+        let title_color = if success { theme().green_neon } else { theme().red_neon };
 
-        let content = Paragraph::new(content_lines)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
-                    .border_type(BorderType::Double)
-                    .title(title)
-                    .title_style(Style::default().fg(title_color).add_modifier(Modifier::BOLD))
-            )
-            .style(Style::default().bg(Theme::BASE))
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
+            .border_type(BorderType::Double)
+            .title(title)
+            .title_style(Style::default().fg(title_color).add_modifier(Modifier::BOLD));
+        let content = self.scrollable_popup(content_lines, block, popup_area)
+            .style(Style::default().bg(theme().base))
             .alignment(Alignment::Left);
 
         f.render_widget(content, popup_area);
@@ -1046,19 +1096,19 @@ impl Dashboard {
         rows.push(Row::new(vec![
             Line::from(Span::styled(
                 "⚠️  CLOSE PQ ACCOUNT & RECLAIM RENT ⚠️",
-                Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD),
             )),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
 
         // Info text
         rows.push(Row::new(vec![
             Line::from(Span::styled(
                 format!("Closing PQ account for vault: {}", self.vault_to_close),
-                Style::default().fg(Theme::TEXT),
+                Style::default().fg(theme().text),
             )),
         ]));
 
@@ -1067,28 +1117,28 @@ impl Dashboard {
         rows.push(Row::new(vec![
             Line::from(Span::styled(
                 "This will:",
-                Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD),
             )),
         ]));
 
         rows.push(Row::new(vec![
             Line::from(Span::styled(
                 "  • Close your on-chain PQ account",
-                Style::default().fg(Theme::SUBTEXT1),
+                Style::default().fg(theme().subtext1),
             )),
         ]));
 
         rows.push(Row::new(vec![
             Line::from(Span::styled(
                 "  • Refund ~0.003 SOL rent to your wallet",
-                Style::default().fg(Theme::GREEN_NEON),
+                Style::default().fg(theme().green_neon),
             )),
         ]));
 
         rows.push(Row::new(vec![
             Line::from(Span::styled(
                 "  • Keep your vault config and keys intact",
-                Style::default().fg(Theme::SUBTEXT1),
+                Style::default().fg(theme().subtext1),
             )),
         ]));
 
@@ -1097,9 +1147,9 @@ impl Dashboard {
         // Instruction
         rows.push(Row::new(vec![
             Line::from(vec![
-                Span::styled("Type ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled(&self.vault_to_close, Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" to confirm:", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("Type ", Style::default().fg(theme().subtext1)),
+                Span::styled(&self.vault_to_close, Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" to confirm:", Style::default().fg(theme().subtext1)),
             ]),
         ]));
 
@@ -1115,13 +1165,13 @@ impl Dashboard {
         rows.push(Row::new(vec![
             Line::from(Span::styled(
                 input_display,
-                Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD),
             )),
         ]));
 
         // Underline for input field
         rows.push(Row::new(vec![
-            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(Theme::YELLOW_NEON))),
+            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(theme().yellow_neon))),
         ]));
 
         rows.push(Row::new(vec![Line::from("")])); // Empty line
@@ -1129,15 +1179,15 @@ impl Dashboard {
         // Controls
         rows.push(Row::new(vec![
             Line::from(vec![
-                Span::styled("[Enter] ", Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("Confirm  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[Esc] ", Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("Cancel", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("[Enter] ", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Confirm  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Esc] ", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Cancel", Style::default().fg(theme().subtext1)),
             ]),
         ]));
 
         // Static gray border matching main dashboard
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         // Create table
         let table = Table::new(
@@ -1150,12 +1200,398 @@ impl Dashboard {
                 .border_type(BorderType::Double)
                 .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                 .title(" ┃ CLOSE PQ ACCOUNT ┃ ")
-                .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))
-                .style(Style::default().bg(Theme::BASE)),
+                .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+                .style(Style::default().bg(theme().base)),
         );
 
         f.render_widget(table, popup_area);
     }
+    pub fn render_portfolio_popup(&self, f: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(90, 70, area);
+
+        // Clear background
+        f.render_widget(Clear, popup_area);
+
+        let amount = |value: Option<u64>, decimals: i32| match value {
+            Some(value) => format!("{:.4}", value as f64 / 10f64.powi(decimals)),
+            None => "—".to_string(),
+        };
+        let header_style = Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD);
+
+        let mut rows = vec![Row::new(vec![
+            Span::styled("VAULT", header_style),
+            Span::styled("NETWORK", header_style),
+            Span::styled("STATE", header_style),
+            Span::styled("PQCOIN", header_style),
+            Span::styled("QCOIN", header_style),
+            Span::styled("SOL", header_style),
+        ])];
+
+        for (i, row) in self.portfolio.iter().enumerate() {
+            let name_style = if row.is_active {
+                Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme().text)
+            };
+            let network = row.network
+                .trim_start_matches("https://")
+                .trim_start_matches("http://")
+                .to_string();
+            let (state, state_color) = match (&row.error, row.is_locked) {
+                (Some(_), _) => ("ERROR", theme().red_neon),
+                (None, Some(true)) => ("🔒 LOCKED", theme().red_neon),
+                (None, Some(false)) => ("🔓 UNLOCKED", theme().green_neon),
+                (None, None) => ("UNREGISTERED", theme().dim),
+            };
+            let marker = match (self.compare_marked.contains(&row.name), row.is_active) {
+                (true, _) => "◆ ",
+                (false, true) => "▶ ",
+                (false, false) => "  ",
+            };
+            let row_style = if i == self.portfolio_selected {
+                Style::default().bg(theme().highlight)
+            } else {
+                Style::default()
+            };
+            rows.push(Row::new(vec![
+                Span::styled(format!("{}{}", marker, row.name), name_style),
+                Span::styled(network, Style::default().fg(theme().subtext1)),
+                Span::styled(state, Style::default().fg(state_color).add_modifier(Modifier::BOLD)),
+                Span::styled(amount(row.pq_balance, 6), Style::default().fg(theme().yellow_neon)),
+                Span::styled(amount(row.standard_balance, 6), Style::default().fg(theme().green_neon)),
+                Span::styled(amount(row.sol_lamports, 9), Style::default().fg(theme().text)),
+            ]).style(row_style));
+        }
+
+        // Totals only add up what could be fetched
+        let total = |field: fn(&PortfolioRow) -> Option<u64>| Some(self.portfolio.iter().filter_map(field).sum());
+        let total_style = Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD);
+        let locked = self.portfolio.iter().filter(|row| row.is_locked == Some(true)).count();
+        rows.push(Row::new(vec![Span::styled("━━━━━━━━━━━━", Style::default().fg(theme().dim))]));
+        rows.push(Row::new(vec![
+            Span::styled(format!("  TOTAL ({})", self.portfolio.len()), total_style),
+            Span::styled("", total_style),
+            Span::styled(format!("{} locked", locked), total_style),
+            Span::styled(amount(total(|row| row.pq_balance), 6), total_style),
+            Span::styled(amount(total(|row| row.standard_balance), 6), total_style),
+            Span::styled(amount(total(|row| row.sol_lamports), 9), total_style),
+        ]));
+
+        rows.push(Row::new(vec![Line::from("")]));
+        rows.push(Row::new(vec![
+            Line::from(vec![
+                Span::styled("[↑↓] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("Select  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Space] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("Mark  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[C] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("Compare  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[R] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("Refresh  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Esc] ", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Close", Style::default().fg(theme().subtext1)),
+            ]),
+        ]));
+
+        let widths = [
+            Constraint::Percentage(18),
+            Constraint::Percentage(26),
+            Constraint::Percentage(14),
+            Constraint::Percentage(14),
+            Constraint::Percentage(14),
+            Constraint::Percentage(14),
+        ];
+
+        // Static gray border matching main dashboard
+        let border_color = theme().overlay2;
+
+        let table = Table::new(rows, widths)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
+                    .border_type(BorderType::Double)
+                    .title(" ┃ ALL VAULTS ┃ ")
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+            )
+            .style(Style::default().bg(theme().base))
+            .column_spacing(2);
+
+        f.render_widget(table, popup_area);
+    }
+    pub fn render_leaderboard_popup(&self, f: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(85, 75, area);
+
+        // Clear background
+        f.render_widget(Clear, popup_area);
+
+        if self.leaderboard_distribution {
+            self.render_lock_distribution(f, popup_area);
+            return;
+        }
+
+        let header_style = Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD);
+        let mut rows = vec![Row::new(vec![
+            Span::styled("RANK", header_style),
+            Span::styled("WALLET", header_style),
+            Span::styled("LOCKED", header_style),
+            Span::styled("SHARE", header_style),
+            Span::styled("", header_style),
+        ])];
+
+        let wallet = self.wallet;
+        let row = |entry: &LeaderboardEntry| {
+            let mine = entry.owner == wallet;
+            let text = if mine {
+                Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme().text)
+            };
+            let bar = "█".repeat(((entry.share / 100.0) * 20.0).round() as usize);
+            let row = Row::new(vec![
+                Span::styled(format!("{}#{}", if mine { "▶ " } else { "  " }, entry.rank), text),
+                Span::styled(entry.owner.to_string(), text),
+                Span::styled(format!("{:.2}", entry.amount), text),
+                Span::styled(format!("{:.2}%", entry.share), text),
+                Span::styled(bar, Style::default().fg(theme().bloomberg_orange)),
+            ]);
+            if mine {
+                row.style(Style::default().bg(theme().highlight))
+            } else {
+                row
+            }
+        };
+
+        if self.leaderboard.entries.is_empty() {
+            rows.push(Row::new(vec![Span::styled("  No locked holders", Style::default().fg(theme().dim))]));
+        }
+        for entry in self.leaderboard.top(LEADERBOARD_SIZE) {
+            rows.push(row(entry));
+        }
+        let own = self.leaderboard.entry_for(&wallet);
+        if let Some(entry) = own.filter(|entry| entry.rank > LEADERBOARD_SIZE) {
+            rows.push(Row::new(vec![Span::styled("  …", Style::default().fg(theme().dim))]));
+            rows.push(row(entry));
+        }
+
+        let total_style = Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD);
+        rows.push(Row::new(vec![Span::styled("━━━━━━━━━━━━", Style::default().fg(theme().dim))]));
+        rows.push(Row::new(vec![
+            Span::styled(format!("  TOTAL ({})", self.leaderboard.holder_count()), total_style),
+            Span::styled(
+                match own {
+                    Some(entry) => format!("You: #{} of {}", entry.rank, self.leaderboard.holder_count()),
+                    None => "You: nothing locked".to_string(),
+                },
+                total_style,
+            ),
+            Span::styled(format!("{:.2}", self.leaderboard.total_locked), total_style),
+            Span::styled("100%", total_style),
+        ]));
+
+        rows.push(Row::new(vec![Line::from("")]));
+        rows.push(Row::new(vec![
+            Line::from(vec![
+                Span::styled("[D] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("Distribution  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[R] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("Refresh  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Esc] ", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Close", Style::default().fg(theme().subtext1)),
+            ]),
+        ]));
+
+        let widths = [
+            Constraint::Length(8),
+            Constraint::Percentage(45),
+            Constraint::Percentage(15),
+            Constraint::Percentage(10),
+            Constraint::Min(20),
+        ];
+
+        let table = Table::new(rows, widths)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme().overlay2).add_modifier(Modifier::BOLD))
+                    .border_type(BorderType::Double)
+                    .title(" ┃ LOCKED HOLDERS ┃ ")
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+            )
+            .style(Style::default().bg(theme().base))
+            .column_spacing(2);
+
+        f.render_widget(table, popup_area);
+    }
+    /// Holders per balance range, as a bar chart over a table of the numbers
+    fn render_lock_distribution(&self, f: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme().overlay2).add_modifier(Modifier::BOLD))
+            .border_type(BorderType::Double)
+            .title(" ┃ LOCK DISTRIBUTION ┃ ")
+            .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+            .style(Style::default().bg(theme().base));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(8), Constraint::Length(9)])
+            .split(inner);
+
+        let distribution = self.leaderboard.distribution();
+        let bars: Vec<Bar> = distribution
+            .iter()
+            .map(|bucket| {
+                Bar::default()
+                    .value(bucket.holders as u64)
+                    .label(Line::from(bucket.label))
+                    .style(Style::default().fg(theme().cyan_bright))
+                    .value_style(Style::default().fg(theme().base).bg(theme().cyan_bright).add_modifier(Modifier::BOLD))
+            })
+            .collect();
+        let bar_width = (chunks[0].width / distribution.len().max(1) as u16).saturating_sub(2).max(3);
+        let chart = BarChart::default()
+            .data(BarGroup::default().bars(&bars))
+            .bar_width(bar_width)
+            .bar_gap(2);
+        f.render_widget(chart, chunks[0]);
+
+        let header_style = Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD);
+        let mut rows = vec![Row::new(vec![
+            Span::styled("BALANCE", header_style),
+            Span::styled("HOLDERS", header_style),
+            Span::styled("LOCKED", header_style),
+            Span::styled("SHARE", header_style),
+        ])];
+        for bucket in &distribution {
+            rows.push(Row::new(vec![
+                Span::styled(bucket.label, Style::default().fg(theme().text).add_modifier(Modifier::BOLD)),
+                Span::styled(bucket.holders.to_string(), Style::default().fg(theme().text)),
+                Span::styled(format!("{:.2}", bucket.amount), Style::default().fg(theme().yellow_neon)),
+                Span::styled(format!("{:.2}%", bucket.share), Style::default().fg(theme().text)),
+            ]));
+        }
+        rows.push(Row::new(vec![Line::from("")]));
+        rows.push(Row::new(vec![
+            Line::from(vec![
+                Span::styled("[D] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("Ranking  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[R] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("Refresh  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Esc] ", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Close", Style::default().fg(theme().subtext1)),
+            ]),
+        ]));
+        let widths = [
+            Constraint::Percentage(25),
+            Constraint::Percentage(20),
+            Constraint::Percentage(30),
+            Constraint::Percentage(25),
+        ];
+        f.render_widget(Table::new(rows, widths).column_spacing(2), chunks[1]);
+    }
+
+    pub fn render_vault_compare_popup(&self, f: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(80, 60, area);
+
+        // Clear background
+        f.render_widget(Clear, popup_area);
+
+        let label_style = Style::default().fg(theme().subtext1);
+        let value_style = Style::default().fg(theme().text).add_modifier(Modifier::BOLD);
+        let columns = &self.comparison;
+
+        // One row per field, one column per vault
+        let field = |label: &'static str, value: fn(&VaultComparison) -> String| {
+            let mut cells = vec![Span::styled(label, label_style)];
+            cells.extend(columns.iter().map(|column| Span::styled(value(column), value_style)));
+            Row::new(cells)
+        };
+
+        let header_style = Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD);
+        let mut header = vec![Span::styled("", header_style)];
+        header.extend(columns.iter().map(|column| {
+            Span::styled(format!("{}{}", column.row.name, if column.row.is_active { " (active)" } else { "" }), header_style)
+        }));
+
+        let mut rows = vec![
+            Row::new(header),
+            Row::new(vec![Span::styled("━━━━━━━━━━━━", Style::default().fg(theme().dim))]),
+            field("Network", |column| column.row.network.trim_start_matches("https://").trim_start_matches("http://").to_string()),
+            field("Wallet", |column| match column.wallet.as_deref() {
+                Some(wallet) => format!("{}…{}", &wallet[..4], &wallet[wallet.len() - 4..]),
+                None => "—".to_string(),
+            }),
+            field("State", |column| match (&column.row.error, column.row.is_locked) {
+                (Some(_), _) => "ERROR".to_string(),
+                (None, Some(true)) => "🔒 LOCKED".to_string(),
+                (None, Some(false)) => "🔓 UNLOCKED".to_string(),
+                (None, None) => "UNREGISTERED".to_string(),
+            }),
+            field("pqcoin", |column| format_amount(column.row.pq_balance, 6)),
+            field("qcoin", |column| format_amount(column.row.standard_balance, 6)),
+            field("SOL", |column| format_amount(column.row.sol_lamports, 9)),
+            field("Last activity", |column| match column.footprint.as_ref().map(|footprint| footprint.last_activity) {
+                Some(Some(at)) => chrono::DateTime::from_timestamp(at, 0)
+                    .map(|at| at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or_else(|| at.to_string()),
+                Some(None) => "never".to_string(),
+                None => "—".to_string(),
+            }),
+            field("Rent in PDAs", |column| match &column.footprint {
+                Some(footprint) => format!("{} SOL ({} accounts)", format_amount(Some(footprint.pda_lamports), 9), footprint.pda_count),
+                None => "—".to_string(),
+            }),
+        ];
+
+        // What a consolidated vault would hold, from what could be fetched
+        let total = |value: fn(&PortfolioRow) -> Option<u64>| Some(columns.iter().filter_map(|column| value(&column.row)).sum());
+        let total_style = Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD);
+        rows.push(Row::new(vec![Line::from("")]));
+        rows.push(Row::new(vec![
+            Span::styled("Combined", total_style),
+            Span::styled(
+                format!(
+                    "{} pqcoin  {} qcoin  {} SOL",
+                    format_amount(total(|row| row.pq_balance), 6),
+                    format_amount(total(|row| row.standard_balance), 6),
+                    format_amount(total(|row| row.sol_lamports), 9),
+                ),
+                total_style,
+            ),
+        ]));
+
+        rows.push(Row::new(vec![Line::from("")]));
+        rows.push(Row::new(vec![
+            Line::from(vec![
+                Span::styled("[R] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("Refresh  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Esc] ", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Back", Style::default().fg(theme().subtext1)),
+            ]),
+        ]));
+
+        let widths = [Constraint::Percentage(20), Constraint::Percentage(40), Constraint::Percentage(40)];
+
+        // Static gray border matching main dashboard
+        let border_color = theme().overlay2;
+
+        let table = Table::new(rows, widths)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
+                    .border_type(BorderType::Double)
+                    .title(" ┃ COMPARE VAULTS ┃ ")
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+            )
+            .style(Style::default().bg(theme().base))
+            .column_spacing(2);
+
+        f.render_widget(table, popup_area);
+    }
     pub fn render_chart_popup(&self, f: &mut Frame, area: Rect) {
         use ratatui::widgets::{Dataset, GraphType};
         use ratatui::symbols;
@@ -1168,7 +1604,7 @@ impl Dashboard {
 
         // Fill entire popup area with background color
         let bg_block = Block::default()
-            .style(Style::default().bg(Theme::BASE));
+            .style(Style::default().bg(theme().base));
         f.render_widget(bg_block, popup_area);
 
         // Load network-wide lock history
@@ -1211,11 +1647,11 @@ impl Dashboard {
             }
         };
 
-        let data_points: Vec<(f64, f64)> = if filtered_entries.len() <= MAX_POINTS {
+        let sampled_entries: Vec<(f64, &LockHistoryEntry)> = if filtered_entries.len() <= MAX_POINTS {
             // If we have fewer entries than the max, use all of them
             filtered_entries.iter()
                 .enumerate()
-                .map(|(i, entry)| (i as f64, get_value(entry)))
+                .map(|(i, entry)| (i as f64, *entry))
                 .collect()
         } else {
             // Sample data points evenly across the dataset
@@ -1223,11 +1659,13 @@ impl Dashboard {
             (0..MAX_POINTS)
                 .map(|i| {
                     let index = (i as f64 * step) as usize;
-                    let entry = filtered_entries[index.min(filtered_entries.len() - 1)];
-                    (i as f64, get_value(entry))
+                    (i as f64, filtered_entries[index.min(filtered_entries.len() - 1)])
                 })
                 .collect()
         };
+        let data_points: Vec<(f64, f64)> = sampled_entries.iter()
+            .map(|(x, entry)| (*x, get_value(entry)))
+            .collect();
 
         // Parse timestamps for better labeling
         let (first_time, last_time) = if !filtered_entries.is_empty() {
@@ -1262,38 +1700,67 @@ impl Dashboard {
             }
         };
 
+        // Price overlay: the chart has one y axis, so prices are rescaled onto it
+        // and their own range is shown in the legend
+        let prices: Vec<(f64, f64)> = if self.show_price {
+            sampled_entries.iter()
+                .filter_map(|(x, entry)| entry.price.map(|price| (*x, price)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let price_min = prices.iter().map(|(_, price)| *price).fold(f64::INFINITY, f64::min);
+        let price_max = prices.iter().map(|(_, price)| *price).fold(f64::NEG_INFINITY, f64::max);
+        let price_points: Vec<(f64, f64)> = prices.iter()
+            .map(|(x, price)| {
+                // Keep a 10% margin so the line doesn't hug the chart border
+                let position = if price_max > price_min { (price - price_min) / (price_max - price_min) } else { 0.5 };
+                (*x, y_min + (y_max - y_min) * (0.1 + 0.8 * position))
+            })
+            .collect();
+
         // Create dataset
-        let datasets = vec![
+        let mut datasets = vec![
             Dataset::default()
                 .name("Locked qcoin")
                 .marker(symbols::Marker::Braille)
                 .graph_type(GraphType::Line)
-                .style(Style::default().fg(Theme::CYAN_NEON))
+                .style(Style::default().fg(theme().cyan_neon))
                 .data(&data_points)
         ];
+        if !price_points.is_empty() {
+            datasets.push(
+                Dataset::default()
+                    .name(format!("Price {} – {}", format_price(price_min), format_price(price_max)))
+                    .marker(symbols::Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(theme().yellow_neon))
+                    .data(&price_points)
+            );
+        }
 
         // Create chart with dynamic title showing chart type, timeframe, and data count
         // Static gray border matching main dashboard
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
         let chart_title = format!(" ┃ {} [{} - {} points] ┃ ",
             self.chart_type.to_string(),
             self.chart_timeframe.to_string(),
             filtered_entries.len());
         let chart = ratatui::widgets::Chart::new(datasets)
-            .style(Style::default().bg(Theme::BASE))  // Set background on chart itself
+            .style(Style::default().bg(theme().base))  // Set background on chart itself
             .block(
                 Block::default()
                     .title(chart_title)
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
-                    .style(Style::default().bg(Theme::BASE)),
+                    .style(Style::default().bg(theme().base)),
             )
             .x_axis(
                 ratatui::widgets::Axis::default()
                     .title("Time →")
-                    .style(Style::default().fg(Theme::SUBTEXT1))
+                    .style(Style::default().fg(theme().subtext1))
                     .bounds([0.0, data_points.len().max(10) as f64])
                     .labels({
                         // Create time-based labels
@@ -1319,21 +1786,21 @@ impl Dashboard {
                         };
 
                         vec![
-                            Span::styled(start_label, Style::default().fg(Theme::SUBTEXT1)),
-                            Span::styled(mid_label, Style::default().fg(Theme::SUBTEXT1)),
-                            Span::styled(end_label, Style::default().fg(Theme::SUBTEXT1)),
+                            Span::styled(start_label, Style::default().fg(theme().subtext1)),
+                            Span::styled(mid_label, Style::default().fg(theme().subtext1)),
+                            Span::styled(end_label, Style::default().fg(theme().subtext1)),
                         ]
                     })
             )
             .y_axis(
                 ratatui::widgets::Axis::default()
                     .title("Locked qcoin")
-                    .style(Style::default().fg(Theme::SUBTEXT1))
+                    .style(Style::default().fg(theme().subtext1))
                     .bounds([y_min, y_max])
                     .labels(vec![
-                        Span::styled(format!("{:.0}", y_min), Style::default().fg(Theme::SUBTEXT1)),
-                        Span::styled(format!("{:.0}", (y_min + y_max) / 2.0), Style::default().fg(Theme::SUBTEXT1)),
-                        Span::styled(format!("{:.0}", y_max), Style::default().fg(Theme::SUBTEXT1)),
+                        Span::styled(format!("{:.0}", y_min), Style::default().fg(theme().subtext1)),
+                        Span::styled(format!("{:.0}", (y_min + y_max) / 2.0), Style::default().fg(theme().subtext1)),
+                        Span::styled(format!("{:.0}", y_max), Style::default().fg(theme().subtext1)),
                     ])
             );
 
@@ -1347,8 +1814,11 @@ impl Dashboard {
             ])
             .split(popup_area);
 
-        // Render chart directly
-        f.render_widget(chart, chunks[0]);
+        // Render chart directly, or as candles per time bucket
+        match self.chart_style {
+            ChartStyle::Line => f.render_widget(chart, chunks[0]),
+            ChartStyle::Candles => self.render_candle_chart(f, chunks[0], &filtered_entries, border_color),
+        }
 
         // Get cache age for display
         let cache_age_text = if let Some(age) = self.vault_client.get_network_lock_cache_age() {
@@ -1367,60 +1837,66 @@ impl Dashboard {
         // Render info panel
         let info_text = vec![
             Line::from(vec![
-                Span::styled("📊 ", Style::default().fg(Theme::CYAN_NEON)),
-                Span::styled("Snapshots: ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled(format!("{} (showing: {})", history.entries.len(), filtered_entries.len()), Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)),
-                Span::styled("  |  ", Style::default().fg(Theme::DIM)),
-                Span::styled("Network Total: ", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("📊 ", Style::default().fg(theme().cyan_neon)),
+                Span::styled("Snapshots: ", Style::default().fg(theme().subtext1)),
+                Span::styled(format!("{} (showing: {})", history.entries.len(), filtered_entries.len()), Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("  |  ", Style::default().fg(theme().dim)),
+                Span::styled("Network Total: ", Style::default().fg(theme().subtext1)),
                 Span::styled(
                     if let Some(last) = history.entries.last() {
                         format!("{:.2} qcoin", last.locked_amount)
                     } else {
                         "No data".to_string()
                     },
-                    Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)
+                    Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)
                 ),
-                Span::styled("  |  ", Style::default().fg(Theme::DIM)),
-                Span::styled("Updated: ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled(cache_age_text, Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
+                Span::styled("  |  ", Style::default().fg(theme().dim)),
+                Span::styled("Updated: ", Style::default().fg(theme().subtext1)),
+                Span::styled(cache_age_text, Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),  // Empty line for spacing
             Line::from(vec![
-                Span::styled("📊 Chart: ", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)),
-                Span::styled("[TAB/←→] ", Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)),
+                Span::styled("📊 Chart: ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("[TAB/←→] ", Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
                 Span::styled(
                     if self.chart_type == ChartType::LockedAmount { "⟪ LOCKED qcoin ⟫" } else { "  LOCKED qcoin  " },
-                    Style::default().fg(if self.chart_type == ChartType::LockedAmount { Theme::CYAN_NEON } else { Theme::SUBTEXT1 }).add_modifier(Modifier::BOLD)
+                    Style::default().fg(if self.chart_type == ChartType::LockedAmount { theme().cyan_neon } else { theme().subtext1 }).add_modifier(Modifier::BOLD)
                 ),
                 Span::styled("  ", Style::default()),
                 Span::styled(
                     if self.chart_type == ChartType::HolderCount { "⟪ LOCKED HOLDERS ⟫" } else { "  LOCKED HOLDERS  " },
-                    Style::default().fg(if self.chart_type == ChartType::HolderCount { Theme::CYAN_NEON } else { Theme::SUBTEXT1 }).add_modifier(Modifier::BOLD)
+                    Style::default().fg(if self.chart_type == ChartType::HolderCount { theme().cyan_neon } else { theme().subtext1 }).add_modifier(Modifier::BOLD)
                 ),
             ]),
             Line::from(vec![
-                Span::styled("⌚ Timeframe: ", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)),
-                Span::styled("[M] ", Style::default().fg(if self.chart_timeframe == ChartTimeframe::FiveMinutes { Theme::CYAN_NEON } else { Theme::SUBTEXT1 }).add_modifier(Modifier::BOLD)),
-                Span::styled("5M  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[1] ", Style::default().fg(if self.chart_timeframe == ChartTimeframe::OneDay { Theme::CYAN_NEON } else { Theme::SUBTEXT1 }).add_modifier(Modifier::BOLD)),
-                Span::styled("1D  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[5] ", Style::default().fg(if self.chart_timeframe == ChartTimeframe::FiveDays { Theme::CYAN_NEON } else { Theme::SUBTEXT1 }).add_modifier(Modifier::BOLD)),
-                Span::styled("5D  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[7] ", Style::default().fg(if self.chart_timeframe == ChartTimeframe::OneWeek { Theme::CYAN_NEON } else { Theme::SUBTEXT1 }).add_modifier(Modifier::BOLD)),
-                Span::styled("1W  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[3] ", Style::default().fg(if self.chart_timeframe == ChartTimeframe::OneMonth { Theme::CYAN_NEON } else { Theme::SUBTEXT1 }).add_modifier(Modifier::BOLD)),
-                Span::styled("1M  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[A] ", Style::default().fg(if self.chart_timeframe == ChartTimeframe::All { Theme::CYAN_NEON } else { Theme::SUBTEXT1 }).add_modifier(Modifier::BOLD)),
-                Span::styled("ALL", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("⌚ Timeframe: ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("[M] ", Style::default().fg(if self.chart_timeframe == ChartTimeframe::FiveMinutes { theme().cyan_neon } else { theme().subtext1 }).add_modifier(Modifier::BOLD)),
+                Span::styled("5M  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[1] ", Style::default().fg(if self.chart_timeframe == ChartTimeframe::OneDay { theme().cyan_neon } else { theme().subtext1 }).add_modifier(Modifier::BOLD)),
+                Span::styled("1D  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[5] ", Style::default().fg(if self.chart_timeframe == ChartTimeframe::FiveDays { theme().cyan_neon } else { theme().subtext1 }).add_modifier(Modifier::BOLD)),
+                Span::styled("5D  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[7] ", Style::default().fg(if self.chart_timeframe == ChartTimeframe::OneWeek { theme().cyan_neon } else { theme().subtext1 }).add_modifier(Modifier::BOLD)),
+                Span::styled("1W  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[3] ", Style::default().fg(if self.chart_timeframe == ChartTimeframe::OneMonth { theme().cyan_neon } else { theme().subtext1 }).add_modifier(Modifier::BOLD)),
+                Span::styled("1M  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[A] ", Style::default().fg(if self.chart_timeframe == ChartTimeframe::All { theme().cyan_neon } else { theme().subtext1 }).add_modifier(Modifier::BOLD)),
+                Span::styled("ALL", Style::default().fg(theme().subtext1)),
             ]),
             Line::from(""),  // Empty line for spacing
             Line::from(vec![
-                Span::styled("[Esc] ", Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("Close  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[R] ", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)),
-                Span::styled("Refresh  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[L] ", Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("View Log", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("[Esc] ", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Close  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[R] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("Refresh  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[E] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("Export CSV  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[O] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled(if self.chart_style == ChartStyle::Line { "OHLC  " } else { "Line  " }, Style::default().fg(theme().subtext1)),
+                Span::styled("[P] ", Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(if self.show_price { "Hide Price  " } else { "Show Price  " }, Style::default().fg(theme().subtext1)),
+                Span::styled("[L] ", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("View Log", Style::default().fg(theme().subtext1)),
             ]),
         ];
 
@@ -1430,12 +1906,93 @@ impl Dashboard {
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
-                    .style(Style::default().bg(Theme::BASE)),
+                    .style(Style::default().bg(theme().base)),
             )
             .alignment(ratatui::layout::Alignment::Center);
 
         f.render_widget(info_block, chunks[1]);
     }
+    /// OHLC candles of the selected chart type; readable where thousands of
+    /// raw snapshots would blur into one line
+    fn render_candle_chart(&self, f: &mut Frame, area: Rect, entries: &[&LockHistoryEntry], border_color: Color) {
+        use ratatui::symbols;
+        use ratatui::widgets::canvas::{Canvas, Line as CanvasLine};
+        use chrono::DateTime;
+
+        const MAX_CANDLES: usize = 60;
+
+        let first_time = entries.first().and_then(|e| DateTime::parse_from_rfc3339(&e.timestamp).ok());
+        let last_time = entries.last().and_then(|e| DateTime::parse_from_rfc3339(&e.timestamp).ok());
+        let span = match (first_time, last_time) {
+            (Some(first), Some(last)) => (last - first).num_seconds(),
+            _ => 0,
+        };
+        let interval = candle_interval(span, MAX_CANDLES);
+        let candles = candles(entries, interval, |entry| match self.chart_type {
+            ChartType::LockedAmount => entry.locked_amount,
+            ChartType::HolderCount => entry.holder_count as f64,
+        });
+
+        let low = candles.iter().map(|c| c.low).fold(f64::INFINITY, f64::min);
+        let high = candles.iter().map(|c| c.high).fold(f64::NEG_INFINITY, f64::max);
+        let (y_min, y_max) = if candles.is_empty() {
+            (0.0, 100.0)
+        } else if high - low < 10.0 {
+            ((low - 5.0).max(0.0), high + 5.0)
+        } else {
+            let padding = (high - low) * 0.1;
+            ((low - padding).max(0.0), high + padding)
+        };
+
+        let interval_label = match interval {
+            i if i % 86400 == 0 => format!("{}d", i / 86400),
+            i if i % 3600 == 0 => format!("{}h", i / 3600),
+            i => format!("{}m", i / 60),
+        };
+        let chart_title = format!(" ┃ {} [{} - {} candles × {}] ┃ ",
+            self.chart_type.to_string(),
+            self.chart_timeframe.to_string(),
+            candles.len(),
+            interval_label);
+        let range_label = match (candles.first(), candles.last()) {
+            (Some(first), Some(last)) => format!(" {} → {}  │  low {:.0}  high {:.0} ",
+                first.start.format("%m/%d %H:%M"),
+                last.start.format("%m/%d %H:%M"),
+                low,
+                high),
+            _ => " No data ".to_string(),
+        };
+
+        let canvas = Canvas::default()
+            .block(
+                Block::default()
+                    .title(chart_title)
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+                    .title_bottom(Line::from(Span::styled(range_label, Style::default().fg(theme().subtext1))))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
+                    .border_type(BorderType::Double)
+                    .style(Style::default().bg(theme().base)),
+            )
+            .background_color(theme().base)
+            .marker(symbols::Marker::HalfBlock)
+            .x_bounds([0.0, candles.len().max(1) as f64])
+            .y_bounds([y_min, y_max])
+            .paint(|ctx| {
+                for (i, candle) in candles.iter().enumerate() {
+                    let x = i as f64 + 0.5;
+                    let color = if candle.close >= candle.open { theme().green_neon } else { theme().red_neon };
+                    // Wick spans the bucket's min-max, the body open-close
+                    ctx.draw(&CanvasLine::new(x, candle.low, x, candle.high, color));
+                    for offset in [-0.25, 0.0, 0.25] {
+                        ctx.draw(&CanvasLine::new(x + offset, candle.open, x + offset, candle.close, color));
+                    }
+                }
+            });
+
+        f.render_widget(canvas, area);
+    }
+
     pub fn render_airdrop_stats_popup(&self, f: &mut Frame, area: Rect) {
         // Full screen popup (98% x 95%)
         let popup_area = centered_rect(98, 95, area);
@@ -1445,7 +2002,7 @@ impl Dashboard {
 
         // Fill entire popup area with background color
         let bg_block = Block::default()
-            .style(Style::default().bg(Theme::BASE));
+            .style(Style::default().bg(theme().base));
         f.render_widget(bg_block, popup_area);
 
         // Split layout: Title + Content
@@ -1460,25 +2017,26 @@ impl Dashboard {
 
         // Title with static gray border
         // Static gray border matching main dashboard
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
         let title = Paragraph::new("┃ AIRDROP POOL STATISTICS ┃")
-            .style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
             .block(Block::default()
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                 .border_type(BorderType::Double)
-                .style(Style::default().bg(Theme::BASE)));
+                .style(Style::default().bg(theme().base)));
         f.render_widget(title, chunks[0]);
 
         // Use cached airdrop stats (fetched when entering popup mode)
         let distributed = self.airdrop_distributed;
         let remaining = self.airdrop_remaining;
 
-        const TOTAL_CAP: u64 = 128_849_018_880_000; // 3% cap with 6 decimals
-        let distributed_qdum = distributed as f64 / 1_000_000.0;
-        let remaining_qdum = remaining as f64 / 1_000_000.0;
-        let total_qdum = TOTAL_CAP as f64 / 1_000_000.0;
+        const TOTAL_CAP: u64 = 128_849_018_880_000; // 3% cap in base units
+        let pq_decimals = decimals(&self.pq_mint);
+        let distributed_qdum = to_tokens(distributed, pq_decimals);
+        let remaining_qdum = to_tokens(remaining, pq_decimals);
+        let total_qdum = to_tokens(TOTAL_CAP, pq_decimals);
         let percent_used = (distributed as f64 / TOTAL_CAP as f64 * 100.0);
 
         // Content area - split into stats and visual
@@ -1496,27 +2054,27 @@ impl Dashboard {
         let stats_text = vec![
             Line::from(""),
             Line::from(vec![
-                Span::styled("📦 Total Airdrop Pool:  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled(format!("{:.2} qcoin", total_qdum), Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)),
-                Span::styled("  (3% of supply)", Style::default().fg(Theme::DIM)),
+                Span::styled("📦 Total Airdrop Pool:  ", Style::default().fg(theme().subtext1)),
+                Span::styled(format!("{:.2} qcoin", total_qdum), Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("  (3% of supply)", Style::default().fg(theme().dim)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("✅ Distributed:         ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled(format!("{:.2} qcoin", distributed_qdum), Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(format!("  ({:.3}%)", percent_used), Style::default().fg(Theme::GREEN)),
+                Span::styled("✅ Distributed:         ", Style::default().fg(theme().subtext1)),
+                Span::styled(format!("{:.2} qcoin", distributed_qdum), Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("  ({:.3}%)", percent_used), Style::default().fg(theme().green)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("💎 Remaining:           ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled(format!("{:.2} qcoin", remaining_qdum), Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(format!("  ({:.3}%)", 100.0 - percent_used), Style::default().fg(Theme::YELLOW)),
+                Span::styled("💎 Remaining:           ", Style::default().fg(theme().subtext1)),
+                Span::styled(format!("{:.2} qcoin", remaining_qdum), Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("  ({:.3}%)", 100.0 - percent_used), Style::default().fg(theme().yellow)),
             ]),
             Line::from(""),
             Line::from(vec![
-                Span::styled("📊 Claims Possible:     ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled(format!("{:.0} more", remaining_qdum / 100.0), Style::default().fg(Theme::CYAN).add_modifier(Modifier::BOLD)),
-                Span::styled("  (@ 100 qcoin each)", Style::default().fg(Theme::DIM)),
+                Span::styled("📊 Claims Possible:     ", Style::default().fg(theme().subtext1)),
+                Span::styled(format!("{:.0} more", remaining_qdum / 100.0), Style::default().fg(theme().cyan).add_modifier(Modifier::BOLD)),
+                Span::styled("  (@ 100 qcoin each)", Style::default().fg(theme().dim)),
             ]),
         ];
 
@@ -1526,8 +2084,8 @@ impl Dashboard {
                 .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                 .border_type(BorderType::Double)
                 .title(" Pool Status ")
-                .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))
-                .style(Style::default().bg(Theme::BASE)))
+                .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+                .style(Style::default().bg(theme().base)))
             .alignment(Alignment::Left);
         f.render_widget(stats, content_chunks[0]);
 
@@ -1615,27 +2173,27 @@ impl Dashboard {
                 .name("Remaining Claims")
                 .marker(symbols::Marker::Braille)
                 .graph_type(GraphType::Line)
-                .style(Style::default().fg(Theme::YELLOW_NEON))
+                .style(Style::default().fg(theme().yellow_neon))
                 .data(&data_points)
         ];
 
         let chart = ratatui::widgets::Chart::new(datasets)
-            .style(Style::default().bg(Theme::BASE))  // Set background on chart itself
+            .style(Style::default().bg(theme().base))  // Set background on chart itself
             .block(
                 Block::default()
                     .title(format!(" ┃ Airdrop Pool Depletion [{} - {} snapshots] ┃ ",
                         self.airdrop_timeframe.to_string(),
                         filtered_entries.len()))
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                     .border_type(BorderType::Double)
-                    .style(Style::default().bg(Theme::BASE)),
+                    .style(Style::default().bg(theme().base)),
             )
             .x_axis(
                 ratatui::widgets::Axis::default()
                     .title("Time →")
-                    .style(Style::default().fg(Theme::SUBTEXT1))
+                    .style(Style::default().fg(theme().subtext1))
                     .bounds([0.0, data_points.len().max(10) as f64])
                     .labels({
                         // Create time-based labels
@@ -1661,21 +2219,21 @@ impl Dashboard {
                         };
 
                         vec![
-                            Span::styled(start_label, Style::default().fg(Theme::SUBTEXT1)),
-                            Span::styled(mid_label, Style::default().fg(Theme::SUBTEXT1)),
-                            Span::styled(end_label, Style::default().fg(Theme::SUBTEXT1)),
+                            Span::styled(start_label, Style::default().fg(theme().subtext1)),
+                            Span::styled(mid_label, Style::default().fg(theme().subtext1)),
+                            Span::styled(end_label, Style::default().fg(theme().subtext1)),
                         ]
                     })
             )
             .y_axis(
                 ratatui::widgets::Axis::default()
                     .title("Remaining qcoin")
-                    .style(Style::default().fg(Theme::SUBTEXT1))
+                    .style(Style::default().fg(theme().subtext1))
                     .bounds([y_min, y_max])
                     .labels(vec![
-                        Span::styled(format!("{:.0}", y_min), Style::default().fg(Theme::SUBTEXT1)),
-                        Span::styled(format!("{:.0}", (y_min + y_max) / 2.0), Style::default().fg(Theme::SUBTEXT1)),
-                        Span::styled(format!("{:.0}", y_max), Style::default().fg(Theme::SUBTEXT1)),
+                        Span::styled(format!("{:.0}", y_min), Style::default().fg(theme().subtext1)),
+                        Span::styled(format!("{:.0}", (y_min + y_max) / 2.0), Style::default().fg(theme().subtext1)),
+                        Span::styled(format!("{:.0}", y_max), Style::default().fg(theme().subtext1)),
                     ])
             );
 
@@ -1685,20 +2243,20 @@ impl Dashboard {
         // Help text
         let help_text = vec![
             Line::from(vec![
-                Span::styled("[Esc] ", Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("Close  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[M] ", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)),
-                Span::styled("5Min  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[1] ", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)),
-                Span::styled("1D  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[5] ", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)),
-                Span::styled("5D  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[7] ", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)),
-                Span::styled("1W  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[3] ", Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD)),
-                Span::styled("1M  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[A] ", Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("All", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("[Esc] ", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Close  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[M] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("5Min  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[1] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("1D  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[5] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("5D  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[7] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("1W  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[3] ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled("1M  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[A] ", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("All", Style::default().fg(theme().subtext1)),
             ]),
         ];
         let help = Paragraph::new(help_text)
@@ -1707,7 +2265,7 @@ impl Dashboard {
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                 .border_type(BorderType::Double)
-                .style(Style::default().bg(Theme::BASE)));
+                .style(Style::default().bg(theme().base)));
         f.render_widget(help, content_chunks[2]);
     }
     pub fn render_delete_confirm_popup(&self, f: &mut Frame, area: Rect) {
@@ -1723,19 +2281,19 @@ impl Dashboard {
         rows.push(Row::new(vec![
             Line::from(Span::styled(
                 "⚠️  WARNING: PERMANENT DELETION ⚠️",
-                Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD),
             )),
         ]));
 
         rows.push(Row::new(vec![
-            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(Theme::DIM))),
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
         ]));
 
         // Info text
         rows.push(Row::new(vec![
             Line::from(Span::styled(
                 format!("Deleting vault: {}", self.vault_to_delete),
-                Style::default().fg(Theme::TEXT),
+                Style::default().fg(theme().text),
             )),
         ]));
 
@@ -1744,9 +2302,9 @@ impl Dashboard {
         // Instruction
         rows.push(Row::new(vec![
             Line::from(vec![
-                Span::styled("Type ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled(&self.vault_to_delete, Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled(" to confirm:", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("Type ", Style::default().fg(theme().subtext1)),
+                Span::styled(&self.vault_to_delete, Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(" to confirm:", Style::default().fg(theme().subtext1)),
             ]),
         ]));
 
@@ -1762,13 +2320,13 @@ impl Dashboard {
         rows.push(Row::new(vec![
             Line::from(Span::styled(
                 input_display,
-                Style::default().fg(Theme::YELLOW_NEON).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD),
             )),
         ]));
 
         // Underline for input field
         rows.push(Row::new(vec![
-            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(Theme::YELLOW_NEON))),
+            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(theme().yellow_neon))),
         ]));
 
         rows.push(Row::new(vec![Line::from("")])); // Empty line
@@ -1776,15 +2334,15 @@ impl Dashboard {
         // Controls
         rows.push(Row::new(vec![
             Line::from(vec![
-                Span::styled("[Enter] ", Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("Confirm  ", Style::default().fg(Theme::SUBTEXT1)),
-                Span::styled("[Esc] ", Style::default().fg(Theme::RED_NEON).add_modifier(Modifier::BOLD)),
-                Span::styled("Cancel", Style::default().fg(Theme::SUBTEXT1)),
+                Span::styled("[Enter] ", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Confirm  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Esc] ", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Cancel", Style::default().fg(theme().subtext1)),
             ]),
         ]));
 
         // Static gray border matching main dashboard
-        let border_color = Color::Rgb(140, 140, 140);
+        let border_color = theme().overlay2;
 
         // Create table
         let table = Table::new(
@@ -1797,73 +2355,791 @@ impl Dashboard {
                 .border_type(BorderType::Double)
                 .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
                 .title(" ┃ DELETE VAULT ┃ ")
-                .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD))
-                .style(Style::default().bg(Theme::BASE)),
+                .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+                .style(Style::default().bg(theme().base)),
         );
 
         f.render_widget(table, popup_area);
     }
-    pub fn render_help_overlay(&self, f: &mut Frame, area: Rect) {
-        let help_text = vec![
+    pub fn render_unlock_resume_popup(&self, f: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(70, 50, area);
+
+        // Clear background
+        f.render_widget(Clear, popup_area);
+
+        let Some(session) = &self.unlock_session else {
+            return;
+        };
+        let updated = chrono::DateTime::parse_from_rfc3339(&session.updated_at)
+            .map(|at| at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|_| session.updated_at.clone());
+        let total = session.total_steps.max(1);
+        let filled = session.completed_step.min(total) * 30 / total;
+
+        let label = |text: &'static str| Span::styled(text, Style::default().fg(theme().subtext1));
+        let value = |text: String| Span::styled(text, Style::default().fg(theme().text).add_modifier(Modifier::BOLD));
+        let mut lines = vec![
+            Line::from(""),
             Line::from(Span::styled(
-                "pqcash VAULT - HELP",
-                Style::default().fg(Theme::CYAN_BRIGHT).add_modifier(Modifier::BOLD),
-            )),
+                "An earlier unlock of this vault did not finish",
+                Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Center),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Navigation:", Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
+                Span::styled("   ", Style::default()),
+                Span::styled("█".repeat(filled), Style::default().fg(theme().bloomberg_orange)),
+                Span::styled("░".repeat(30 - filled), Style::default().fg(theme().dim)),
+                value(format!("  {}/{} steps", session.completed_step, session.total_steps)),
             ]),
-            Line::from(Span::styled("  ↑/↓ or j/k  - Navigate actions", Style::default().fg(Theme::TEXT))),
-            Line::from(Span::styled("  Enter       - Execute selected action", Style::default().fg(Theme::TEXT))),
             Line::from(""),
-            Line::from(vec![
-                Span::styled("Actions:", Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
-            ]),
-            Line::from(Span::styled("  G or 1      - Register PQ account", Style::default().fg(Theme::TEXT))),
-            Line::from(Span::styled("  L           - Lock vault", Style::default().fg(Theme::TEXT))),
-            Line::from(Span::styled("  U           - Unlock vault", Style::default().fg(Theme::TEXT))),
-            Line::from(Span::styled("  T or 2      - Transfer tokens", Style::default().fg(Theme::TEXT))),
-            Line::from(Span::styled("  A           - Claim 100 qcoin airdrop (24h cooldown)", Style::default().fg(Theme::TEXT))),
-            Line::from(Span::styled("  P           - View airdrop pool statistics", Style::default().fg(Theme::TEXT))),
-            Line::from(Span::styled("  X or 3      - Close PQ account & reclaim rent", Style::default().fg(Theme::TEXT))),
-            Line::from(Span::styled("  R           - Refresh status", Style::default().fg(Theme::TEXT))),
-            Line::from(Span::styled("  C           - Copy wallet address", Style::default().fg(Theme::TEXT))),
-            Line::from(Span::styled("  V           - Switch vault", Style::default().fg(Theme::TEXT))),
+            Line::from(vec![label("   Last progress:      "), value(updated)]),
+            Line::from(vec![label("   Signature storage:  "), value(session.signature_storage.clone())]),
+            Line::from(vec![label("   Verification state: "), value(session.verification_state.clone())]),
+            Line::from(""),
+            Line::from(Span::styled(
+                "   Steps still recorded on-chain are skipped; the rest run again",
+                Style::default().fg(theme().dim),
+            )),
             Line::from(""),
+        ];
+
+        let key = |key: &'static str| Span::styled(key, Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD));
+        lines.push(
             Line::from(vec![
-                Span::styled("Other:", Style::default().fg(Theme::GREEN_NEON).add_modifier(Modifier::BOLD)),
-            ]),
-            Line::from(Span::styled("  H or ?      - Show this help", Style::default().fg(Theme::TEXT))),
-            Line::from(Span::styled("  Q or Esc    - Quit dashboard", Style::default().fg(Theme::TEXT))),
+                key("[Enter] "), label("Resume  "),
+                key("[R] "), label("Start over  "),
+                key("[D] "), label("Discard  "),
+                key("[Esc] "), label("Later"),
+            ])
+            .alignment(Alignment::Center),
+        );
+
+        let prompt = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Double)
+                .border_style(Style::default().fg(theme().overlay2).add_modifier(Modifier::BOLD))
+                .title(" ┃ RESUME UNLOCK ┃ ")
+                .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+                .style(Style::default().bg(theme().base)),
+        );
+        f.render_widget(prompt, popup_area);
+    }
+
+    pub fn render_idle_lock_popup(&self, f: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(60, 35, area);
+
+        // Clear background
+        f.render_widget(Clear, popup_area);
+
+        let remaining = self
+            .idle_lock_deadline
+            .map_or(0, |deadline| deadline.saturating_duration_since(std::time::Instant::now()).as_secs());
+        let idle_minutes = self.last_input.elapsed().as_secs() / 60;
+
+        let label = |text: &'static str| Span::styled(text, Style::default().fg(theme().subtext1));
+        let mut lines = vec![
             Line::from(""),
             Line::from(Span::styled(
-                "Press any key to close help",
-                Style::default().fg(Theme::YELLOW_NEON),
-            )),
+                format!("Your vault has been unlocked and idle for {} min", idle_minutes),
+                Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Center),
+            Line::from(""),
+            Line::from(vec![
+                label("Locking in "),
+                Span::styled(format!("{}s", remaining), Style::default().fg(theme().text).add_modifier(Modifier::BOLD)),
+            ])
+            .alignment(Alignment::Center),
+            Line::from(""),
         ];
 
-        // Center the help box
-        let help_area = centered_rect(60, 60, area);
+        let key = |key: &'static str| Span::styled(key, Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD));
+        lines.push(
+            Line::from(vec![
+                key("[Enter] "), label("Lock now  "),
+                key("[any key] "), label("Stay unlocked"),
+            ])
+            .alignment(Alignment::Center),
+        );
 
-        // Clear the background
-        f.render_widget(Clear, help_area);
+        let prompt = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Double)
+                .border_style(Style::default().fg(theme().overlay2).add_modifier(Modifier::BOLD))
+                .title(" ┃ AUTO-LOCK ┃ ")
+                .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+                .style(Style::default().bg(theme().base)),
+        );
+        f.render_widget(prompt, popup_area);
+    }
 
-        // Static gray border matching main dashboard
-        let border_color = Color::Rgb(140, 140, 140);
+    pub fn render_transfer_confirm_popup(&self, f: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(70, 45, area);
 
-        let help_paragraph = Paragraph::new(help_text)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
-                    .border_type(BorderType::Double)
-                    .title(" ┃ HELP ┃ ")
-                    .title_style(Style::default().fg(Theme::BLOOMBERG_ORANGE).add_modifier(Modifier::BOLD)),
-            )
-            .style(Style::default().bg(Theme::BASE))
-            .alignment(Alignment::Left)
-            .wrap(Wrap { trim: true });
+        // Clear background
+        f.render_widget(Clear, popup_area);
 
-        f.render_widget(help_paragraph, help_area);
+        let token = match self.transfer_token_type {
+            TransferTokenType::StandardQcoin => "qcoin",
+            TransferTokenType::Pqcoin => "pqcoin",
+        };
+        let recipient = self.transfer_recipient.trim();
+        let tail: String = recipient.chars().skip(recipient.chars().count().saturating_sub(4)).collect();
+
+        let mut rows = vec![];
+
+        // Warning header
+        rows.push(Row::new(vec![
+            Line::from(Span::styled(
+                "⚠️  LARGE TRANSFER ⚠️",
+                Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD),
+            )),
+        ]));
+
+        rows.push(Row::new(vec![
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+        ]));
+
+        // What is about to be sent
+        rows.push(Row::new(vec![
+            Line::from(vec![
+                Span::styled("Sending ", Style::default().fg(theme().text)),
+                Span::styled(format!("{} {}", self.transfer_amount, token), Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+            ]),
+        ]));
+        rows.push(Row::new(vec![
+            Line::from(vec![
+                Span::styled("To ", Style::default().fg(theme().text)),
+                Span::styled(recipient.to_string(), Style::default().fg(theme().cyan_bright)),
+            ]),
+        ]));
+
+        rows.push(Row::new(vec![Line::from("")])); // Empty line
+
+        // Instruction
+        rows.push(Row::new(vec![
+            Line::from(vec![
+                Span::styled("Type the amount (", Style::default().fg(theme().subtext1)),
+                Span::styled(self.transfer_amount.clone(), Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(") or the recipient's last 4 characters (", Style::default().fg(theme().subtext1)),
+                Span::styled(tail, Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(") to confirm:", Style::default().fg(theme().subtext1)),
+            ]),
+        ]));
+
+        rows.push(Row::new(vec![Line::from("")])); // Empty line
+
+        // Input field
+        let input_display = if self.transfer_confirm_input.is_empty() {
+            "[type amount or last 4 characters...]"
+        } else {
+            &self.transfer_confirm_input
+        };
+
+        rows.push(Row::new(vec![
+            Line::from(Span::styled(
+                input_display,
+                Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD),
+            )),
+        ]));
+
+        // Underline for input field
+        rows.push(Row::new(vec![
+            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(theme().yellow_neon))),
+        ]));
+
+        rows.push(Row::new(vec![Line::from("")])); // Empty line
+
+        // Controls
+        rows.push(Row::new(vec![
+            Line::from(vec![
+                Span::styled("[Enter] ", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Send  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Esc] ", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Back", Style::default().fg(theme().subtext1)),
+            ]),
+        ]));
+
+        // Static gray border matching main dashboard
+        let border_color = theme().overlay2;
+
+        let table = Table::new(
+            rows,
+            [Constraint::Percentage(100)],
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Double)
+                .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
+                .title(" ┃ CONFIRM TRANSFER ┃ ")
+                .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+                .style(Style::default().bg(theme().base)),
+        );
+
+        f.render_widget(table, popup_area);
+    }
+    pub fn render_new_recipient_popup(&self, f: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(70, 40, area);
+
+        // Clear background
+        f.render_widget(Clear, popup_area);
+
+        let reason = match &self.transfer_recipient_check {
+            RecipientCheck::Unverified(reason) => reason.clone(),
+            _ => String::new(),
+        };
+
+        // Warning header
+        let mut rows = vec![Row::new(vec![
+            Line::from(Span::styled(
+                "⚠️  YOU HAVE NEVER SENT TO THIS ADDRESS ⚠️",
+                Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD),
+            )),
+        ])];
+
+        rows.push(Row::new(vec![
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+        ]));
+
+        rows.push(Row::new(vec![
+            Line::from(Span::styled(self.transfer_recipient.trim().to_string(), Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
+        ]));
+        rows.push(Row::new(vec![
+            Line::from(Span::styled(reason, Style::default().fg(theme().subtext1))),
+        ]));
+
+        rows.push(Row::new(vec![Line::from("")])); // Empty line
+
+        rows.push(Row::new(vec![
+            Line::from(Span::styled(
+                "Check it against where you got it. A swapped or mistyped",
+                Style::default().fg(theme().text),
+            )),
+        ]));
+        rows.push(Row::new(vec![
+            Line::from(Span::styled(
+                "address is usually one you have never used.",
+                Style::default().fg(theme().text),
+            )),
+        ]));
+
+        rows.push(Row::new(vec![Line::from("")])); // Empty line
+
+        // Controls
+        rows.push(Row::new(vec![
+            Line::from(vec![
+                Span::styled("[Enter] ", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Continue  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Esc] ", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Back", Style::default().fg(theme().subtext1)),
+            ]),
+        ]));
+
+        // Static gray border matching main dashboard
+        let border_color = theme().overlay2;
+
+        let table = Table::new(
+            rows,
+            [Constraint::Percentage(100)],
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Double)
+                .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
+                .title(" ┃ NEW RECIPIENT ┃ ")
+                .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+                .style(Style::default().bg(theme().base)),
+        );
+
+        f.render_widget(table, popup_area);
+    }
+    pub fn render_vault_edit_popup(&self, f: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(60, 35, area);
+
+        // Clear background
+        f.render_widget(Clear, popup_area);
+
+        let (title, label, placeholder) = match self.vault_edit_field {
+            VaultEditField::Name => (" ┃ RENAME VAULT ┃ ", "NEW NAME", "[Enter vault name...]"),
+            VaultEditField::Description => (" ┃ EDIT DESCRIPTION ┃ ", "DESCRIPTION", "[Leave empty to clear...]"),
+        };
+
+        let mut rows = vec![];
+
+        rows.push(Row::new(vec![
+            Line::from(vec![
+                Span::styled("Vault: ", Style::default().fg(theme().subtext1)),
+                Span::styled(&self.vault_edit_target, Style::default().fg(theme().text).add_modifier(Modifier::BOLD)),
+            ]),
+        ]).height(2));
+
+        rows.push(Row::new(vec![
+            Line::from(Span::styled("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", Style::default().fg(theme().dim))),
+        ]));
+
+        rows.push(Row::new(vec![
+            Line::from(Span::styled(label, Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD))),
+        ]));
+
+        let input_display = if self.vault_edit_input.is_empty() {
+            placeholder
+        } else {
+            &self.vault_edit_input
+        };
+
+        rows.push(Row::new(vec![
+            Line::from(Span::styled(input_display, Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD))),
+        ]));
+
+        rows.push(Row::new(vec![
+            Line::from(Span::styled("▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔", Style::default().fg(theme().yellow_neon))),
+        ]));
+
+        rows.push(Row::new(vec![Line::from("")]));
+
+        rows.push(Row::new(vec![
+            Line::from(vec![
+                Span::styled("[Enter] ", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Save  ", Style::default().fg(theme().subtext1)),
+                Span::styled("[Esc] ", Style::default().fg(theme().red_neon).add_modifier(Modifier::BOLD)),
+                Span::styled("Cancel", Style::default().fg(theme().subtext1)),
+            ]),
+        ]));
+
+        // Static gray border matching main dashboard
+        let border_color = theme().overlay2;
+
+        let table = Table::new(rows, [Constraint::Percentage(100)])
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Double)
+                    .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
+                    .title(title)
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+                    .style(Style::default().bg(theme().base)),
+            );
+
+        f.render_widget(table, popup_area);
+    }
+    pub fn render_receive_popup(&self, f: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(70, 90, area);
+
+        // Clear background
+        f.render_widget(Clear, popup_area);
+
+        let payload = self.receive_payload();
+        let token_name = match self.receive_token {
+            TransferTokenType::StandardQcoin => "qcoin",
+            TransferTokenType::Pqcoin => "pqcoin",
+        };
+
+        let mut lines = vec![Line::from("")];
+        match qr::qr_lines(&payload) {
+            // Borders, the blank line above and the six lines below
+            Ok(qr) if qr.len() + 9 <= popup_area.height as usize => {
+                // Dark modules on a light background, whatever the theme
+                let qr_style = Style::default().fg(Color::Black).bg(Color::White);
+                lines.extend(qr.into_iter().map(|line| Line::from(Span::styled(line, qr_style)).alignment(Alignment::Center)));
+            }
+            Ok(_) => lines.push(
+                Line::from(Span::styled("Enlarge the terminal to show the QR code", Style::default().fg(theme().warning())))
+                    .alignment(Alignment::Center),
+            ),
+            Err(e) => lines.push(
+                Line::from(Span::styled(format!("{:#}", e), Style::default().fg(theme().error()))).alignment(Alignment::Center),
+            ),
+        }
+
+        lines.push(Line::from(""));
+        lines.push(
+            Line::from(Span::styled(payload.clone(), Style::default().fg(theme().text).add_modifier(Modifier::BOLD)))
+                .alignment(Alignment::Center),
+        );
+        lines.push(Line::from(""));
+        let amount = if self.receive_amount.is_empty() { "any".to_string() } else { format!("{} {}", self.receive_amount, token_name) };
+        lines.push(
+            Line::from(vec![
+                Span::styled("REQUEST ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+                Span::styled(amount, Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+            ])
+            .alignment(Alignment::Center),
+        );
+        lines.push(Line::from(""));
+
+        let key = |key: &'static str| Span::styled(key, Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD));
+        let label = |label: &'static str| Span::styled(label, Style::default().fg(theme().subtext1));
+        lines.push(
+            Line::from(vec![
+                key("[0-9.] "), label("Amount  "),
+                key("[Tab] "), label("Token  "),
+                key("[C] "), label("Copy  "),
+                key("[Esc] "), label("Close"),
+            ])
+            .alignment(Alignment::Center),
+        );
+
+        let receive = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Double)
+                .border_style(Style::default().fg(theme().overlay2).add_modifier(Modifier::BOLD))
+                .title(" ┃ RECEIVE ┃ ")
+                .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+                .style(Style::default().bg(theme().base)),
+        );
+        f.render_widget(receive, popup_area);
+    }
+
+    pub fn render_settings_popup(&self, f: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(60, 60, area);
+
+        // Clear background
+        f.render_widget(Clear, popup_area);
+
+        let mut lines = vec![Line::from("")];
+        for (i, field) in SettingsField::ALL.iter().enumerate() {
+            let is_selected = self.settings_selected == i;
+            let (marker, label_color, value_color) = if is_selected {
+                ("▶ ", theme().yellow_neon, theme().yellow_neon)
+            } else {
+                ("  ", theme().text, theme().cyan_bright)
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {}", marker), Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{:<28}", field.label()), Style::default().fg(label_color).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("◀ {} ▶", self.setting_value(*field)), Style::default().fg(value_color).add_modifier(Modifier::BOLD)),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        let note = match SettingsField::ALL[self.settings_selected] {
+            SettingsField::RefreshInterval => "How often balances and lock status reload while no popup is open",
+            SettingsField::DefaultToken => "Token the transfer and receive forms start on",
+            SettingsField::Theme => "Takes effect the next time the dashboard starts",
+            SettingsField::ConfirmRecipients => "Press Enter twice to send to an address outside the address book",
+            SettingsField::ConfirmQuit => "Press the quit key twice to leave the dashboard",
+            SettingsField::SnapshotInterval => "Record network lock snapshots for the chart while the dashboard is open",
+            SettingsField::LargeTransferThreshold => "Larger transfers ask you to type the amount or the recipient's last 4 characters",
+            SettingsField::IdleLock => "Ask, then lock the vault, once it has been unlocked this long without a key press",
+        };
+        lines.push(Line::from(Span::styled(format!("   {}", note), Style::default().fg(theme().subtext1))));
+        lines.push(Line::from(""));
+
+        let key = |key: &'static str| Span::styled(key, Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD));
+        let label = |label: &'static str| Span::styled(label, Style::default().fg(theme().subtext1));
+        lines.push(
+            Line::from(vec![
+                key("[↑↓] "), label("Select  "),
+                key("[←→/Enter] "), label("Change  "),
+                key("[Esc] "), label("Close"),
+            ])
+            .alignment(Alignment::Center),
+        );
+        lines.push(
+            Line::from(Span::styled("Changes are saved to the vault config right away", Style::default().fg(theme().dim)))
+                .alignment(Alignment::Center),
+        );
+
+        let settings = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Double)
+                .border_style(Style::default().fg(theme().overlay2).add_modifier(Modifier::BOLD))
+                .title(" ┃ SETTINGS ┃ ")
+                .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+                .style(Style::default().bg(theme().base)),
+        );
+        f.render_widget(settings, popup_area);
+    }
+
+    pub fn render_network_popup(&self, f: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(70, 50, area);
+
+        // Clear background
+        f.render_widget(Clear, popup_area);
+
+        let mut lines = vec![Line::from("")];
+        for (i, (name, network)) in self.network_profiles.iter().enumerate() {
+            let is_selected = self.network_selected == i;
+            let (marker, name_color) = if is_selected {
+                ("▶ ", theme().yellow_neon)
+            } else {
+                ("  ", theme().text)
+            };
+            let current = if *name == self.network_name { "● " } else { "  " };
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {}", marker), Style::default().fg(theme().yellow_neon).add_modifier(Modifier::BOLD)),
+                Span::styled(current, Style::default().fg(theme().success())),
+                Span::styled(format!("{:<14}", name), Style::default().fg(name_color).add_modifier(Modifier::BOLD)),
+                Span::styled(network.rpc_url.clone(), Style::default().fg(theme().subtext1)),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        if let Some((_, network)) = self.network_profiles.get(self.network_selected) {
+            let or_default = |value: &Option<String>| value.clone().unwrap_or_else(|| "default".to_string());
+            lines.push(Line::from(Span::styled(
+                format!("   Program: {}   Mint: {}", or_default(&network.program_id), or_default(&network.mint)),
+                Style::default().fg(theme().dim),
+            )));
+            lines.push(Line::from(""));
+        }
+
+        let key = |key: &'static str| Span::styled(key, Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD));
+        let label = |label: &'static str| Span::styled(label, Style::default().fg(theme().subtext1));
+        lines.push(
+            Line::from(vec![
+                key("[↑↓] "), label("Select  "),
+                key("[Enter] "), label("Switch  "),
+                key("[Esc] "), label("Close"),
+            ])
+            .alignment(Alignment::Center),
+        );
+        lines.push(
+            Line::from(Span::styled("Add profiles with: pqcoin network add <NAME> --rpc-url <URL>", Style::default().fg(theme().dim)))
+                .alignment(Alignment::Center),
+        );
+
+        let networks = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Double)
+                .border_style(Style::default().fg(theme().overlay2).add_modifier(Modifier::BOLD))
+                .title(" ┃ NETWORK ┃ ")
+                .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+                .style(Style::default().bg(theme().base)),
+        );
+        f.render_widget(networks, popup_area);
+    }
+
+    pub fn render_log_viewer(&self, f: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(90, 85, area);
+
+        // Clear background
+        f.render_widget(Clear, popup_area);
+
+        let view = &self.log_view;
+        let visible = view.visible();
+        let level_color = |level: Level| match level {
+            Level::Debug => theme().dim,
+            Level::Info => theme().info(),
+            Level::Warn => theme().warning(),
+            Level::Error => theme().error(),
+        };
+
+        // Borders, the filter line and the controls line
+        let height = popup_area.height.saturating_sub(4) as usize;
+        let end = visible.len() - view.scroll.min(visible.len());
+        let start = end.saturating_sub(height);
+
+        let filter = match view.min_level {
+            None => "ALL".to_string(),
+            Some(level) => format!("{}+", level),
+        };
+        let search = if view.editing_search {
+            format!("/{}_", view.search)
+        } else if view.search.is_empty() {
+            "—".to_string()
+        } else {
+            format!("/{}", view.search)
+        };
+        let position = if view.scroll == 0 { "FOLLOWING".to_string() } else { format!("{} UP", view.scroll) };
+
+        let mut lines = vec![Line::from(vec![
+            Span::styled("LEVEL ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{:<8}", filter), Style::default().fg(theme().text).add_modifier(Modifier::BOLD)),
+            Span::styled("SEARCH ", Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD)),
+            Span::styled(
+                format!("{:<24}", search),
+                Style::default().fg(if view.editing_search { theme().yellow_neon } else { theme().text }),
+            ),
+            Span::styled(
+                format!("{}/{} lines  {}", visible.len(), view.lines.len(), position),
+                Style::default().fg(theme().subtext1),
+            ),
+        ])];
+
+        if visible.is_empty() {
+            lines.push(Line::from(Span::styled(
+                if view.lines.is_empty() { "  (the log is empty)" } else { "  (no lines match)" },
+                Style::default().fg(theme().dim),
+            )));
+        }
+        for line in &visible[start..end] {
+            lines.push(match LogLine::parse(line) {
+                Some(entry) => Line::from(vec![
+                    Span::styled(format!("{} ", entry.timestamp), Style::default().fg(theme().dim)),
+                    Span::styled(
+                        format!("{:<5} ", entry.level),
+                        Style::default().fg(level_color(entry.level)).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(format!("{}: ", entry.target), Style::default().fg(theme().cyan_neon)),
+                    Span::styled(entry.message.to_string(), Style::default().fg(theme().text)),
+                ]),
+                None => Line::from(Span::styled(line.to_string(), Style::default().fg(theme().subtext1))),
+            });
+        }
+        // Keep the controls on the bottom line
+        while lines.len() < height + 1 {
+            lines.push(Line::from(""));
+        }
+
+        let key = |key: &'static str| Span::styled(key, Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD));
+        let label = |label: &'static str| Span::styled(label, Style::default().fg(theme().subtext1));
+        lines.push(Line::from(vec![
+            key("[↑↓/PgUp/PgDn] "), label("Scroll  "),
+            key("[G] "), label("Follow  "),
+            key("[/] "), label("Search  "),
+            key("[C] "), label("Clear search  "),
+            key("[F] "), label("Level  "),
+            key("[Esc] "), label("Close"),
+        ]));
+
+        let log_view = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Double)
+                    .border_style(Style::default().fg(theme().overlay2).add_modifier(Modifier::BOLD))
+                    .title(format!(" ┃ APPLICATION LOG · {} ┃ ", crate::paths::display(&applog::path())))
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD))
+                    .style(Style::default().bg(theme().base)),
+            );
+        f.render_widget(log_view, popup_area);
+    }
+
+    /// Stack the live toasts in the top-right corner, newest first
+    pub fn render_toasts(&self, f: &mut Frame, area: Rect) {
+        let toasts = self.toasts.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        for (i, toast) in toasts.iter().rev().enumerate() {
+            let y = area.y + 1 + i as u16 * 3;
+            if y + 3 > area.bottom() {
+                break;
+            }
+            let (icon, color) = match toast.level {
+                ToastLevel::Info => ("ℹ", theme().info()),
+                ToastLevel::Success => ("✓", theme().success()),
+                ToastLevel::Warning => ("⚠", theme().warning()),
+                ToastLevel::Error => ("✗", theme().error()),
+            };
+            let width = (toast.message.chars().count() as u16 + 6).clamp(20, 60).min(area.width);
+            let toast_area = Rect { x: area.right().saturating_sub(width + 2).max(area.x), y, width, height: 3 };
+
+            let line = Line::from(vec![
+                Span::styled(format!("{} ", icon), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                Span::styled(toast.message.clone(), Style::default().fg(theme().text)),
+            ]);
+            f.render_widget(Clear, toast_area);
+            f.render_widget(
+                Paragraph::new(line)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .border_type(BorderType::Rounded)
+                            .border_style(Style::default().fg(color)),
+                    )
+                    .style(Style::default().bg(theme().surface0)),
+                toast_area,
+            );
+        }
+    }
+
+    /// Contents of the help overlay
+    pub fn help_lines(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::from(Span::styled(
+                "pqcash VAULT - HELP",
+                Style::default().fg(theme().cyan_bright).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Navigation:", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(Span::styled("  ↑/↓ or j/k  - Navigate actions", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  Enter       - Execute selected action", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  Ctrl+V      - Paste into the focused input field", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  PgUp/PgDn   - Scroll long results (also ↑/↓, Home/End)", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  M / H / Q   - Fill an amount with the max, half or 25% of the balance", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  Shift+L     - Application log (search with /, filter levels with F)", Style::default().fg(theme().text))),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Actions:", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(Span::styled("  G or 1      - Register PQ account", Style::default().fg(theme().text))),
+            Line::from(Span::styled(format!("  {:<11} - Lock vault", self.keymap.label(BoundAction::Lock)), Style::default().fg(theme().text))),
+            Line::from(Span::styled(format!("  {:<11} - Unlock vault", self.keymap.label(BoundAction::Unlock)), Style::default().fg(theme().text))),
+            Line::from(Span::styled(format!("  {:<11} - Transfer tokens", self.keymap.label(BoundAction::Transfer)), Style::default().fg(theme().text))),
+            Line::from(Span::styled("  A           - Claim 100 qcoin airdrop (24h cooldown)", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  P           - View airdrop pool statistics", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  X or 3      - Close PQ account & reclaim rent", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  R           - Refresh status", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  C           - Copy wallet address", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  I           - Receive: wallet address as a QR code", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  ,           - Settings (refresh, default token, theme, confirmations)", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  N           - Switch network (devnet, mainnet, custom profiles)", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  Space       - Pause / resume auto-refresh and network polling", Style::default().fg(theme().text))),
+            Line::from(Span::styled(format!("  {:<11} - Switch vault", self.keymap.label(BoundAction::VaultSwitch)), Style::default().fg(theme().text))),
+            Line::from(Span::styled(format!("  {:<11} - Network lock chart", self.keymap.label(BoundAction::Chart)), Style::default().fg(theme().text))),
+            Line::from(Span::styled("  O           - All vaults: lock state and balances", Style::default().fg(theme().text))),
+            Line::from(Span::styled("                (Space marks two vaults, C compares them)", Style::default().fg(theme().text))),
+            Line::from(Span::styled("  B           - Locked-holders leaderboard", Style::default().fg(theme().text))),
+            Line::from(Span::styled("                (D shows the balance distribution)", Style::default().fg(theme().text))),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Other:", Style::default().fg(theme().green_neon).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(Span::styled("  H or ?      - Show this help", Style::default().fg(theme().text))),
+            Line::from(Span::styled(format!("  {:<11} - Quit dashboard", format!("{} or Esc", self.keymap.label(BoundAction::Quit))), Style::default().fg(theme().text))),
+            Line::from(Span::styled("  Keys for lock, unlock, transfer, chart, vault switch and quit can be", Style::default().fg(theme().subtext1))),
+            Line::from(Span::styled("  remapped in the \"keybindings\" section of vaults.json", Style::default().fg(theme().subtext1))),
+            Line::from(""),
+            Line::from(Span::styled(
+                "↑/↓ to scroll, any other key to close help",
+                Style::default().fg(theme().yellow_neon),
+            )),
+        ]
+    }
+
+    pub fn render_help_overlay(&self, f: &mut Frame, area: Rect) {
+        let help_text = self.help_lines();
+
+        // Center the help box
+        let help_area = centered_rect(60, 60, area);
+
+        // Clear the background
+        f.render_widget(Clear, help_area);
+
+        // Static gray border matching main dashboard
+        let border_color = theme().overlay2;
+
+        let help_paragraph = Paragraph::new(help_text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border_color).add_modifier(Modifier::BOLD))
+                    .border_type(BorderType::Double)
+                    .title(" ┃ HELP ┃ ")
+                    .title_style(Style::default().fg(theme().bloomberg_orange).add_modifier(Modifier::BOLD)),
+            )
+            .style(Style::default().bg(theme().base))
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: true })
+            .scroll((self.help_scroll, 0));
+
+        f.render_widget(help_paragraph, help_area);
+    }
+}
+
+/// Base units shown with 4 decimals, or a dash when unknown
+fn format_amount(value: Option<u64>, decimals: i32) -> String {
+    match value {
+        Some(value) => format!("{:.4}", value as f64 / 10f64.powi(decimals)),
+        None => "—".to_string(),
     }
 }
@@ -35,3 +35,13 @@ where
 
     result
 }
+
+/// Time left on a countdown, e.g. "17h 23m", or "42s" in the last minute
+pub fn format_countdown(secs: i64) -> String {
+    let secs = secs.max(0);
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m {}s", secs / 60, secs % 60),
+        _ => format!("{}h {}m", secs / 3600, (secs % 3600) / 60),
+    }
+}
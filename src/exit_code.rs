@@ -0,0 +1,162 @@
+//! Process exit codes
+//!
+//! Scripts and wrappers branch on the exit code instead of parsing messages:
+//!
+//! | Code | Meaning                                                        |
+//! |------|----------------------------------------------------------------|
+//! | 0    | Success (for `status --format ...`: the vault is unlocked)     |
+//! | 1    | Any other failure                                              |
+//! | 2    | Invalid arguments or addresses                                 |
+//! | 3    | The vault is locked                                            |
+//! | 4    | Configuration error (vault config, keypair, key files, IDL)    |
+//! | 5    | RPC or network error                                           |
+//! | 6    | Insufficient SOL or token balance                              |
+//! | 7    | The transaction was rejected by an on-chain program            |
+//! | 8    | Cancelled by the user                                          |
+//! | 9    | Refused by the security policy (`policy` in the vault config)  |
+//!
+//! Most errors are classified by their cause (see [`classify`]); call sites
+//! that know better tag them with [`ExitKind::error`] or [`ExitContext`].
+
+use solana_client::client_error::ClientError;
+use solana_sdk::{pubkey::ParsePubkeyError, transaction::TransactionError};
+use std::fmt;
+
+use crate::solana::client::ProgramFailure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitKind {
+    Failure,
+    Usage,
+    VaultLocked,
+    Config,
+    Rpc,
+    InsufficientFunds,
+    Program,
+    Cancelled,
+    Policy,
+}
+
+impl ExitKind {
+    pub const fn code(self) -> u8 {
+        match self {
+            ExitKind::Failure => 1,
+            ExitKind::Usage => 2,
+            ExitKind::VaultLocked => 3,
+            ExitKind::Config => 4,
+            ExitKind::Rpc => 5,
+            ExitKind::InsufficientFunds => 6,
+            ExitKind::Program => 7,
+            ExitKind::Cancelled => 8,
+            ExitKind::Policy => 9,
+        }
+    }
+
+    /// A new error that exits with this code
+    pub fn error<M>(self, message: M) -> anyhow::Error
+    where
+        M: fmt::Display + fmt::Debug + Send + Sync + 'static,
+    {
+        tag(anyhow::Error::msg(message), self)
+    }
+}
+
+/// An error carrying the exit code it should produce. It displays as, and has
+/// the same causes as, the error it wraps.
+#[derive(Debug)]
+struct Tagged {
+    kind: ExitKind,
+    error: anyhow::Error,
+}
+
+impl fmt::Display for Tagged {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for Tagged {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+fn tag(error: anyhow::Error, kind: ExitKind) -> anyhow::Error {
+    anyhow::Error::new(Tagged { kind, error })
+}
+
+pub trait ExitContext<T> {
+    /// Exit with `kind` if this is an error
+    fn exit_kind(self, kind: ExitKind) -> anyhow::Result<T>;
+}
+
+impl<T, E: Into<anyhow::Error>> ExitContext<T> for Result<T, E> {
+    fn exit_kind(self, kind: ExitKind) -> anyhow::Result<T> {
+        self.map_err(|error| tag(error.into(), kind))
+    }
+}
+
+/// Exit code for a failed command
+pub fn classify(error: &anyhow::Error) -> ExitKind {
+    if let Some(tagged) = error.downcast_ref::<Tagged>() {
+        return tagged.kind;
+    }
+    if let Some(failure) = error.downcast_ref::<ProgramFailure>() {
+        return match failure.name.as_str() {
+            "VaultLocked" => ExitKind::VaultLocked,
+            "InsufficientFunds" | "InsufficientBalance" => ExitKind::InsufficientFunds,
+            _ => ExitKind::Program,
+        };
+    }
+    if let Some(client_error) = error.downcast_ref::<ClientError>() {
+        return match client_error.get_transaction_error() {
+            Some(TransactionError::InsufficientFundsForFee | TransactionError::InsufficientFundsForRent { .. }) => {
+                ExitKind::InsufficientFunds
+            }
+            Some(_) => ExitKind::Program,
+            None => ExitKind::Rpc,
+        };
+    }
+    if let Some(prompt_error) = error.downcast_ref::<inquire::InquireError>() {
+        if matches!(
+            prompt_error,
+            inquire::InquireError::OperationCanceled | inquire::InquireError::OperationInterrupted
+        ) {
+            return ExitKind::Cancelled;
+        }
+    }
+    if error.downcast_ref::<ParsePubkeyError>().is_some() {
+        return ExitKind::Usage;
+    }
+    // Local files: keys, keypairs, config
+    if error.downcast_ref::<std::io::Error>().is_some() {
+        return ExitKind::Config;
+    }
+    ExitKind::Failure
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_tag_survives_context_and_keeps_message() {
+        let error = Err::<(), _>(ExitKind::VaultLocked.error("Vault is locked"))
+            .context("Transfer failed")
+            .unwrap_err();
+        assert_eq!(classify(&error), ExitKind::VaultLocked);
+        assert_eq!(format!("{:#}", error), "Transfer failed: Vault is locked");
+    }
+
+    #[test]
+    fn test_classify_by_cause() {
+        let missing = std::fs::read("/nonexistent/qdum/key").context("Failed to read key").unwrap_err();
+        assert_eq!(classify(&missing), ExitKind::Config);
+
+        let address = "not-an-address".parse::<solana_sdk::pubkey::Pubkey>().unwrap_err();
+        assert_eq!(classify(&address.into()), ExitKind::Usage);
+
+        assert_eq!(classify(&anyhow::anyhow!("something else")), ExitKind::Failure);
+    }
+}
@@ -0,0 +1,22 @@
+//! pqcoin as a library: post-quantum keys, the vault client and local vault
+//! configuration. The `pqcoin` CLI and the Python bindings in `python/` are
+//! both built on it.
+
+pub mod applog;
+pub mod audit;
+pub mod crypto;
+pub mod exit_code;
+pub mod monitor;
+pub mod notify;
+pub mod output;
+pub mod paper;
+pub mod paths;
+pub mod policy;
+pub mod price;
+pub mod qr;
+pub mod recipients;
+pub mod remote_backup;
+pub mod remote_signer;
+pub mod scheduler;
+pub mod solana;
+pub mod vault_manager;
@@ -1,28 +1,46 @@
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use colored::Colorize;
 use comfy_table::{Table, presets::UTF8_FULL};
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::ProgressStyle;
 use solana_sdk::{pubkey::Pubkey, signature::Keypair};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-mod icons;
+use std::sync::Arc;
 use std::time::Duration;
 
-mod crypto;
-mod solana;
+use pqcoin::{applog, audit, crypto, exit_code, monitor, notify, output, paper, paths, price, qr, recipients, remote_backup, remote_signer, scheduler, solana, vault_manager};
+
+mod icons;
 mod dashboard;
 mod theme;
-mod vault_manager;
 mod vault_switcher;
+mod vault_archive;
+mod server;
 
+use crypto::algorithm::Algorithm;
+use exit_code::{ExitContext, ExitKind};
 use crypto::sphincs::SphincsKeyManager;
-use solana::client::VaultClient;
+use crypto::attestation::DetachedSignature;
+use recipients::RecipientHistory;
+use remote_signer::{LocalSigner, RemoteSigner, SignerEndpoint};
+use solana::client::{JitoConfig, LockMode, StatusReport, UnlockOptions, UnlockSigner, VaultClient, VaultSigner};
+use solana::pay::TransferRequest;
+use solana::receipt::{Operation, Receipt};
+use solana::units;
 use dashboard::Dashboard;
-use vault_manager::{VaultConfig, VaultProfile};
+use vault_manager::{SolanaCliConfig, VaultConfig, VaultProfile};
 use vault_switcher::VaultSwitcher;
+use vault_archive::{VaultArchive, VaultSet};
+use scheduler::{Schedule, ScheduledLock, ScheduledTransfer};
+use monitor::{MonitoredVault, WebhookEvent, Webhooks};
+use remote_backup::BackupDestination;
+
+/// Devnet QDUM mints, used unless a flag or the active vault pins others
+const DEFAULT_MINT: &str = "3V6ogu16de86nChsmC5wHMKJmCx5YdGXA6fbp3y3497n";
+const DEFAULT_STANDARD_MINT: &str = "GS2tyNMdpiKnQ9AxFhB74SbzYF7NmoTREoKZC6pzxds7";
 
 #[derive(Parser)]
 #[command(name = "qdum-vault")]
@@ -41,20 +59,61 @@ use vault_switcher::VaultSwitcher;
     "NETWORK:".bright_magenta().bold(),
     "RPC:".bright_blue(), "https://api.devnet.solana.com".dimmed(),
     "Program:".bright_blue(), "HyC27AVHW4VwkEiWwWxevaUpvkiAqPUueaa94og9HmLQ".dimmed(),
-    "Keys:".bright_blue(), "~/.qdum/".dimmed(),
+    "Keys:".bright_blue(), "~/.local/share/qdum/".dimmed(),
     "EXAMPLES:".bright_magenta().bold(),
     "qdum-vault init                    # Initialize quantum keypair".dimmed(),
     "qdum-vault unlock                  # 44-tx quantum verification".dimmed(),
 ))]
 #[command(styles = get_styles())]
 struct Cli {
-    /// RPC endpoint URL (defaults to devnet)
-    #[arg(long, default_value = "https://api.devnet.solana.com")]
-    rpc_url: String,
+    /// RPC endpoint URL (defaults to the Solana CLI config's json_rpc_url, else devnet)
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Program ID (defaults to the active vault's, else the devnet program)
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Write a JSON receipt of on-chain operations to this path
+    #[arg(long, global = true)]
+    receipt_out: Option<String>,
+
+    /// Compute unit limit for every transaction (defaults to the configured value, else the runtime default)
+    #[arg(long, global = true, value_parser = clap::value_parser!(u32).range(1..=vault_manager::MAX_COMPUTE_UNIT_LIMIT as i64))]
+    compute_unit_limit: Option<u32>,
+
+    /// Compute unit price in micro-lamports for every transaction (defaults to the configured value, else 200000)
+    #[arg(long, global = true)]
+    compute_unit_price: Option<u64>,
 
-    /// Program ID
-    #[arg(long, default_value = "HyC27AVHW4VwkEiWwWxevaUpvkiAqPUueaa94og9HmLQ")]
-    program_id: String,
+    /// Sign with this durable nonce account instead of a recent blockhash (see `nonce create`)
+    #[arg(long, global = true)]
+    nonce: Option<String>,
+
+    /// Times to send a transaction before giving up on expired blockhashes and timeouts
+    #[arg(long, global = true, default_value_t = solana::client::DEFAULT_MAX_SEND_ATTEMPTS, value_parser = clap::value_parser!(u32).range(1..))]
+    max_attempts: u32,
+
+    /// Sign through a signing service instead of keypair files: an http(s) URL or a
+    /// Unix socket path, e.g. another machine running `signer` (token in QDUM_SIGNER_TOKEN)
+    #[arg(long, global = true)]
+    remote_signer: Option<String>,
+
+    /// Anchor IDL of the vault program (defaults to the one bundled with this release)
+    #[arg(long, global = true)]
+    idl: Option<PathBuf>,
+
+    /// Keep config and keys under this directory instead of the XDG config and data directories
+    #[arg(long, global = true)]
+    data_dir: Option<PathBuf>,
+
+    /// Print only the result (signature, balance, lock state), for capturing in scripts
+    #[arg(long, short, global = true)]
+    quiet: bool,
+
+    /// More detail on stderr: -v for signatures and PDAs, -vv for simulation logs and raw account data
+    #[arg(long, short, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
 
     #[command(subcommand)]
     command: Option<Commands>,
@@ -64,9 +123,37 @@ struct Cli {
 enum Commands {
     /// Generate SPHINCS+ keys and Solana keypair (all-in-one setup)
     Init {
-        /// Output directory for keys (defaults to ~/.qdum/)
+        /// Output directory for keys (defaults to the data directory, ~/.local/share/qdum/)
         #[arg(long)]
         output_dir: Option<String>,
+
+        /// Derive SPHINCS+ keys from a new 24-word recovery mnemonic
+        #[arg(long)]
+        mnemonic: bool,
+
+        /// Signature algorithm: SLH-DSA ("s" variants are smaller, "f" sign faster) or ML-DSA (much smaller signatures)
+        #[arg(long, value_enum, default_value = "slh-dsa-sha2-128s")]
+        algorithm: Algorithm,
+
+        /// Use this existing (e.g. already funded) Solana keypair as the wallet; only SPHINCS+ keys are generated
+        #[arg(long, conflicts_with = "from_solana_cli")]
+        solana_keypair: Option<String>,
+
+        /// Use the keypair the Solana CLI is configured with as the wallet
+        #[arg(long)]
+        from_solana_cli: bool,
+
+        /// Derive both the SPHINCS+ keys and the Solana wallet from the seed in this file (hex or raw bytes, at least 32)
+        #[arg(long, conflicts_with_all = ["seed_hex", "mnemonic", "solana_keypair", "from_solana_cli"])]
+        seed_file: Option<PathBuf>,
+
+        /// Derive both the SPHINCS+ keys and the Solana wallet from this hex seed (at least 32 bytes)
+        #[arg(long, conflicts_with_all = ["mnemonic", "solana_keypair", "from_solana_cli"])]
+        seed_hex: Option<String>,
+
+        /// Generate wallets until the address starts with this prefix (case-sensitive base58, e.g. QD)
+        #[arg(long, value_name = "PREFIX", conflicts_with_all = ["seed_file", "seed_hex", "solana_keypair", "from_solana_cli"])]
+        vanity: Option<String>,
     },
 
     /// Configure default settings (keypair path, etc.)
@@ -75,6 +162,71 @@ enum Commands {
         #[arg(long)]
         keypair: Option<String>,
 
+        /// Save the default compute unit limit
+        #[arg(long = "set-compute-unit-limit", value_parser = clap::value_parser!(u32).range(1..=vault_manager::MAX_COMPUTE_UNIT_LIMIT as i64))]
+        set_compute_unit_limit: Option<u32>,
+
+        /// Save the default compute unit price (micro-lamports)
+        #[arg(long = "set-compute-unit-price")]
+        set_compute_unit_price: Option<u64>,
+
+        /// Add a webhook URL that `monitor --daemon` POSTs vault events to
+        #[arg(long)]
+        add_webhook: Option<String>,
+
+        /// Remove a saved webhook URL
+        #[arg(long)]
+        remove_webhook: Option<String>,
+
+        /// Notify on the desktop when unlock, transfer or bridge operations finish
+        #[arg(long, value_name = "on|off", value_parser = clap::builder::BoolishValueParser::new())]
+        desktop_notifications: Option<bool>,
+
+        /// Telegram bot token for unlock and watched-vault notifications
+        #[arg(long, requires = "telegram_chat_id")]
+        telegram_bot_token: Option<String>,
+
+        /// Telegram chat the bot posts to; without --telegram-bot-token, the
+        /// token is read from $QDUM_TELEGRAM_BOT_TOKEN
+        #[arg(long)]
+        telegram_chat_id: Option<String>,
+
+        /// Discord webhook URL for unlock and watched-vault notifications
+        #[arg(long)]
+        discord_webhook: Option<String>,
+
+        /// Remove the Telegram and Discord settings
+        #[arg(long)]
+        clear_chat: bool,
+
+        /// Send a test message to the configured Telegram/Discord
+        #[arg(long)]
+        test_notification: bool,
+
+        /// Price API (JSON) for the lock chart's price overlay; `{mint}` is replaced
+        #[arg(long, requires = "price_path")]
+        price_feed: Option<String>,
+
+        /// JSON pointer to the price in the --price-feed response, e.g. /data/{mint}/price
+        #[arg(long, requires = "price_feed")]
+        price_path: Option<String>,
+
+        /// Remove the price API
+        #[arg(long, conflicts_with = "price_feed")]
+        clear_price_feed: bool,
+
+        /// How long network lock snapshots are kept (e.g. 90d)
+        #[arg(long)]
+        snapshot_retention: Option<String>,
+
+        /// Dashboard theme; colors can be adjusted further in theme.toml in the config directory
+        #[arg(long, value_enum)]
+        theme: Option<theme::ThemeName>,
+
+        /// How often the dashboard reloads balances and lock status (e.g. 1m), or "off"
+        #[arg(long)]
+        dashboard_refresh: Option<String>,
+
         /// Show current configuration
         #[arg(long)]
         show: bool,
@@ -86,9 +238,21 @@ enum Commands {
         #[arg(long)]
         keypair: Option<String>,
 
-        /// Path to SPHINCS+ public key file (optional, defaults to ~/.qdum/sphincs_public.key)
+        /// Path to SPHINCS+ public key file (optional, defaults to sphincs_public.key in the data directory)
         #[arg(long)]
         sphincs_pubkey: Option<String>,
+
+        /// Expected signature algorithm (fails if the key file uses a different one)
+        #[arg(long, value_enum)]
+        algorithm: Option<Algorithm>,
+
+        /// Require both a wallet (Ed25519) signature and the PQ proof to unlock
+        #[arg(long)]
+        hybrid: bool,
+
+        /// Register every configured vault whose wallet has no PQ account yet, each with its own keys
+        #[arg(long, conflicts_with_all = ["keypair", "sphincs_pubkey"])]
+        all_unregistered: bool,
     },
 
     /// Lock your vault (generate challenge)
@@ -96,17 +260,66 @@ enum Commands {
         /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
         #[arg(long)]
         keypair: Option<String>,
+
+        /// Schedule the lock for a time instead of locking now (HH:MM, "YYYY-MM-DD HH:MM" or RFC 3339)
+        #[arg(long, conflicts_with = "after")]
+        at: Option<String>,
+
+        /// Schedule the lock after a delay instead of locking now (e.g. 30m, 2h, 1h30m)
+        #[arg(long)]
+        after: Option<String>,
+
+        /// Write the unsigned transaction to this directory for external signing instead of sending it
+        #[arg(long, conflicts_with_all = ["at", "after"])]
+        unsigned_out: Option<PathBuf>,
+
+        /// Lock every configured vault that is unlocked, skipping watch-only ones
+        #[arg(long, conflicts_with_all = ["keypair", "at", "after", "unsigned_out"])]
+        all_vaults: bool,
     },
 
     /// Unlock your vault (11-step verification process)
+    #[command(args_conflicts_with_subcommands = true)]
     Unlock {
+        /// Split the unlock into an offline signing step and an online submit step
+        #[command(subcommand)]
+        phase: Option<UnlockPhase>,
+
         /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
         #[arg(long)]
         keypair: Option<String>,
 
-        /// Path to SPHINCS+ private key file (optional, defaults to ~/.qdum/sphincs_private.key)
+        /// Path to SPHINCS+ private key file (optional, defaults to sphincs_private.key in the data directory)
         #[arg(long)]
         sphincs_privkey: Option<String>,
+
+        /// Cooling-off delay before tokens unlock: a slot count (e.g. 9000) or a duration (e.g. 1h, 30m)
+        #[arg(long)]
+        delay: Option<String>,
+
+        /// Unlock the vault owned by this Squads multisig (the keypair only pays fees)
+        #[arg(long)]
+        multisig: Option<String>,
+
+        /// Estimate transactions, compute, fees, rent and time without sending anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Submit the unlock sequence as Jito bundles (mainnet only)
+        #[arg(long)]
+        jito: bool,
+
+        /// Tip per bundle in lamports
+        #[arg(long, default_value_t = solana::client::DEFAULT_TIP_LAMPORTS, requires = "jito")]
+        jito_tip: u64,
+
+        /// Jito block engine URL
+        #[arg(long, default_value = solana::client::DEFAULT_BLOCK_ENGINE_URL, requires = "jito")]
+        jito_url: String,
+
+        /// Unlock these configured vaults at the same time (comma-separated names), each with its own keys
+        #[arg(long, value_delimiter = ',', conflicts_with_all = ["keypair", "sphincs_privkey", "multisig", "dry_run", "jito"])]
+        vaults: Vec<String>,
     },
 
     /// Close PQ account and reclaim rent (must be unlocked first)
@@ -118,6 +331,21 @@ enum Commands {
         /// Address to receive the rent refund (optional, defaults to wallet address)
         #[arg(long)]
         receiver: Option<String>,
+
+        /// Write the unsigned transaction to this directory for external signing instead of sending it
+        #[arg(long)]
+        unsigned_out: Option<PathBuf>,
+    },
+
+    /// Rotate SPHINCS+ keys (generate, re-register on-chain, archive old keys)
+    RotateKeys {
+        /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        /// Skip confirmation
+        #[arg(long)]
+        yes: bool,
     },
 
     /// Check vault status
@@ -125,6 +353,24 @@ enum Commands {
         /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
         #[arg(long)]
         keypair: Option<String>,
+
+        /// Output format; json, yaml and toml print a stable schema and exit with
+        /// code 3 when the vault is locked (see `exit_code`)
+        #[arg(long, value_enum, default_value = "table")]
+        format: OutputFormat,
+
+        /// Shorthand for --format json
+        #[arg(long, conflicts_with = "format")]
+        json: bool,
+
+        /// Keep polling and print a line whenever the lock state or a balance changes
+        /// (with --json, one JSON report per change)
+        #[arg(long)]
+        watch: bool,
+
+        /// Time between polls with --watch (e.g. 10s, 1m)
+        #[arg(long, default_value = "10s", requires = "watch")]
+        interval: String,
     },
 
     /// Check token balance
@@ -133,9 +379,78 @@ enum Commands {
         #[arg(long)]
         keypair: Option<String>,
 
-        /// Mint address (defaults to QDUM devnet mint)
-        #[arg(long, default_value = "3V6ogu16de86nChsmC5wHMKJmCx5YdGXA6fbp3y3497n")]
-        mint: String,
+        /// Mint address (defaults to the active vault's mint, else QDUM devnet mint)
+        #[arg(long)]
+        mint: Option<String>,
+    },
+
+    /// Show your wallet address as a QR code for a mobile wallet to scan
+    Receive {
+        /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        /// Ask for this amount (in QDUM, e.g., 25.5); makes the QR a Solana Pay request
+        #[arg(long)]
+        amount: Option<f64>,
+
+        /// Token to ask for; makes the QR a Solana Pay request (defaults to the active vault's mint, else QDUM devnet mint)
+        #[arg(long)]
+        mint: Option<String>,
+    },
+
+    /// Create a Solana Pay payment request (URL and QR code) for standard QDUM or pqQDUM
+    Request {
+        /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        /// Amount to ask for (in QDUM, e.g., 25.5)
+        #[arg(long)]
+        amount: f64,
+
+        /// Ask for pqQDUM instead of standard QDUM
+        #[arg(long)]
+        pq: bool,
+
+        /// Who's asking, shown by the payer's wallet (e.g. "Invoice 42")
+        #[arg(long)]
+        label: Option<String>,
+
+        /// What the payment is for, shown by the payer's wallet
+        #[arg(long)]
+        message: Option<String>,
+
+        /// Wait for the payment to arrive, like monitor-payment
+        #[arg(long)]
+        wait: bool,
+
+        /// How long --wait waits (e.g. 10m, 1h)
+        #[arg(long, default_value = "30m", requires = "wait")]
+        timeout: String,
+    },
+
+    /// Wait for a payment request made with `request` to be paid
+    MonitorPayment {
+        /// Path to the recipient's Solana keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        /// The request's reference address (printed by `request`)
+        #[arg(long)]
+        reference: String,
+
+        /// Amount requested (in QDUM, e.g., 25.5)
+        #[arg(long)]
+        amount: f64,
+
+        /// The request was for pqQDUM
+        #[arg(long)]
+        pq: bool,
+
+        /// Give up after this long (e.g. 10m, 1h)
+        #[arg(long, default_value = "30m")]
+        timeout: String,
     },
 
     /// Transfer QDUM tokens to another wallet
@@ -152,9 +467,63 @@ enum Commands {
         #[arg(long)]
         amount: u64,
 
-        /// Mint address (defaults to QDUM devnet mint)
-        #[arg(long, default_value = "3V6ogu16de86nChsmC5wHMKJmCx5YdGXA6fbp3y3497n")]
-        mint: String,
+        /// Mint address (defaults to the active vault's mint, else QDUM devnet mint)
+        #[arg(long)]
+        mint: Option<String>,
+
+        /// Write the unsigned transaction to this directory for external signing instead of sending it
+        #[arg(long)]
+        unsigned_out: Option<PathBuf>,
+
+        /// Have the scheduler send it at this time instead of now (HH:MM, "YYYY-MM-DD HH:MM" or RFC 3339)
+        #[arg(long, conflicts_with = "unsigned_out")]
+        at: Option<String>,
+
+        /// Send even if it goes over policy.max_daily_transfer, after typing the amount to confirm
+        #[arg(long, conflicts_with = "at")]
+        override_daily_limit: bool,
+
+        /// Don't ask before the first transfer to an address outside the address book
+        #[arg(long)]
+        yes: bool,
+
+        /// Lock the vault again once a pqQDUM transfer has gone through (default: the vault's `vault relock` setting)
+        #[arg(long, conflicts_with_all = ["unsigned_out", "at"])]
+        relock: bool,
+
+        /// Leave the vault unlocked even if the vault's setting is to relock
+        #[arg(long, conflicts_with = "relock")]
+        no_relock: bool,
+    },
+
+    /// Send the whole balance of one or more tokens (and optionally the SOL) to another wallet
+    Sweep {
+        /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        /// Destination wallet address
+        #[arg(long)]
+        to: String,
+
+        /// Token mint to sweep (repeatable; defaults to the vault's pqQDUM and standard QDUM mints)
+        #[arg(long = "mint")]
+        mints: Vec<String>,
+
+        /// Also send the remaining SOL, less the fee, once the tokens are moved
+        #[arg(long)]
+        sol: bool,
+
+        /// Don't ask for confirmation
+        #[arg(long)]
+        yes: bool,
+    },
+
+    /// Submit transactions exported with --unsigned-out once they are fully signed
+    Broadcast {
+        /// Signed transaction files (base64)
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
     },
 
     /// Bridge between Standard QDUM and pqQDUM (wrap/unwrap)
@@ -167,119 +536,976 @@ enum Commands {
         keypair: Option<String>,
     },
 
-    /// Launch interactive dashboard (TUI)
-    Dashboard {
+    /// Wrap standard QDUM to pqQDUM and lock the vault, confirmed once
+    Secure {
+        /// Amount to wrap (in QDUM, e.g., 100.5)
+        #[arg(long)]
+        amount: f64,
+
         /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
         #[arg(long)]
         keypair: Option<String>,
-    },
-
-    /// Vault management (create, switch, list, delete)
-    Vault {
-        #[command(subcommand)]
-        action: VaultAction,
-    },
-}
 
-#[derive(Subcommand)]
-enum BridgeAction {
-    /// Wrap Standard QDUM to pqQDUM (for vault locking)
-    Wrap {
-        /// Amount to wrap (in QDUM, e.g., 100.5)
-        amount: f64,
+        /// Standard QDUM mint address (defaults to the active vault's, else devnet)
+        #[arg(long)]
+        standard_mint: Option<String>,
 
-        /// Standard QDUM mint address
-        #[arg(long, default_value = "GS2tyNMdpiKnQ9AxFhB74SbzYF7NmoTREoKZC6pzxds7")]
-        standard_mint: String,
+        /// pqQDUM mint address (defaults to the active vault's, else devnet)
+        #[arg(long)]
+        pq_mint: Option<String>,
 
-        /// pqQDUM mint address
-        #[arg(long, default_value = "3V6ogu16de86nChsmC5wHMKJmCx5YdGXA6fbp3y3497n")]
-        pq_mint: String,
+        /// Don't ask for confirmation
+        #[arg(long)]
+        yes: bool,
     },
 
-    /// Unwrap pqQDUM to Standard QDUM (for DEX trading)
-    Unwrap {
+    /// Unlock the vault and unwrap pqQDUM back to standard QDUM, confirmed once
+    Release {
         /// Amount to unwrap (in QDUM, e.g., 100.5)
+        #[arg(long)]
         amount: f64,
 
-        /// Standard QDUM mint address
-        #[arg(long, default_value = "GS2tyNMdpiKnQ9AxFhB74SbzYF7NmoTREoKZC6pzxds7")]
-        standard_mint: String,
+        /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
 
-        /// pqQDUM mint address
-        #[arg(long, default_value = "3V6ogu16de86nChsmC5wHMKJmCx5YdGXA6fbp3y3497n")]
-        pq_mint: String,
-    },
-}
+        /// Path to SPHINCS+ private key file (optional, defaults to sphincs_private.key in the data directory)
+        #[arg(long)]
+        sphincs_privkey: Option<String>,
 
-#[derive(Subcommand)]
-enum VaultAction {
-    /// List all vault profiles
-    List,
+        /// Standard QDUM mint address (defaults to the active vault's, else devnet)
+        #[arg(long)]
+        standard_mint: Option<String>,
 
-    /// Create a new vault profile
-    Create {
-        /// Name for the vault
-        name: Option<String>,
+        /// pqQDUM mint address (defaults to the active vault's, else devnet)
+        #[arg(long)]
+        pq_mint: Option<String>,
 
-        /// Description (optional)
+        /// Don't ask for confirmation
         #[arg(long)]
-        description: Option<String>,
+        yes: bool,
+    },
 
-        /// Generate new keys automatically
+    /// Dead-man's switch: let a beneficiary claim your pqQDUM if you stop sending heartbeats
+    Inheritance {
+        #[command(subcommand)]
+        action: InheritanceAction,
+
+        /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
         #[arg(long)]
-        auto_generate: bool,
+        keypair: Option<String>,
     },
 
-    /// Switch active vault (interactive if no name provided)
-    Switch {
-        /// Vault name (omit for interactive menu)
-        name: Option<String>,
+    /// Reset your inheritance timer (proof of life)
+    Heartbeat {
+        /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
     },
 
-    /// Show vault details
-    Show {
-        /// Vault name (defaults to active)
-        name: Option<String>,
+    /// Claim an inherited vault balance once the owner's heartbeat has expired
+    ClaimInheritance {
+        /// Wallet address of the vault owner
+        #[arg(long)]
+        owner: String,
+
+        /// Path to the beneficiary's Solana keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        /// Mint address (defaults to the active vault's mint, else QDUM devnet mint)
+        #[arg(long)]
+        mint: Option<String>,
     },
 
-    /// Delete a vault profile
-    Delete {
-        /// Vault name
-        name: String,
+    /// Squads multisig: propose, approve and execute vault operations
+    Multisig {
+        #[command(subcommand)]
+        action: MultisigAction,
 
-        /// Skip confirmation
+        /// Path to the member's Solana keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
         #[arg(long)]
-        yes: bool,
+        keypair: Option<String>,
     },
 
-    /// Rename a vault
-    Rename {
-        /// Current name
-        old_name: String,
+    /// Durable nonce accounts for transactions that must not expire
+    Nonce {
+        #[command(subcommand)]
+        action: NonceAction,
 
-        /// New name
-        new_name: String,
+        /// Path to the nonce authority's Solana keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
     },
 
-    /// Create a new vault and switch to it (convenience command)
-    New {
-        /// Name for the vault
-        name: Option<String>,
+    /// Estimate rent, signature fees and priority fees for an operation
+    Estimate {
+        /// Operation to estimate
+        #[arg(value_enum)]
+        operation: EstimateOperation,
 
-        /// Description (optional)
+        /// Signature algorithm to size accounts for (defaults to the active vault's key)
+        #[arg(long, value_enum)]
+        algorithm: Option<Algorithm>,
+
+        /// SOL price in USD, to also show costs in dollars
         #[arg(long)]
-        description: Option<String>,
+        sol_price: Option<f64>,
+    },
 
-        /// Generate new keys automatically
+    /// Run the scheduler (locks vaults scheduled with `lock --at/--after`, sends transfers scheduled with `transfer --at`)
+    Schedule {
+        #[command(subcommand)]
+        action: Option<ScheduleAction>,
+
+        /// List pending scheduled locks and transfers and exit
         #[arg(long)]
-        auto_generate: bool,
+        list: bool,
+
+        /// Cancel all pending scheduled locks and transfers and exit
+        #[arg(long, conflicts_with = "list")]
+        clear: bool,
+
+        /// Seconds between schedule checks
+        #[arg(long, default_value = "30")]
+        interval: u64,
     },
-}
 
-fn get_styles() -> clap::builder::Styles {
-    use clap::builder::styling::*;
-    clap::builder::Styles::styled()
+    /// Check vaults once, or watch them and POST webhook alerts on changes
+    Monitor {
+        /// Keep polling and send webhooks whenever a vault locks, unlocks, rotates its
+        /// challenge or changes balance
+        #[arg(long)]
+        daemon: bool,
+
+        /// Vault to watch (repeatable; defaults to every configured vault)
+        #[arg(long = "vault")]
+        vaults: Vec<String>,
+
+        /// Extra webhook URL for this run (repeatable), on top of the saved ones
+        #[arg(long = "webhook")]
+        webhooks: Vec<String>,
+
+        /// Time between polls (e.g. 30s, 5m)
+        #[arg(long, default_value = "30s", requires = "daemon")]
+        interval: String,
+
+        /// Get push updates from a Helius webhook (API key in HELIUS_API_KEY);
+        /// polling at --interval continues as a fallback
+        #[arg(long, requires_all = ["daemon", "public_url"])]
+        helius: bool,
+
+        /// URL where Helius can reach the callback listener
+        #[arg(long, requires = "helius")]
+        public_url: Option<String>,
+
+        /// Address the Helius callback listener binds to
+        #[arg(long, default_value = "0.0.0.0:8788", requires = "helius")]
+        helius_listen: std::net::SocketAddr,
+    },
+
+    /// Serve an HTTP API for status, balances and vaults (and optionally lock/unlock)
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8787")]
+        listen: std::net::SocketAddr,
+
+        /// Expose lock and unlock endpoints (requires a token in QDUM_API_TOKEN)
+        #[arg(long)]
+        enable_actions: bool,
+
+        /// Vault to serve (repeatable; defaults to every configured vault)
+        #[arg(long = "vault")]
+        vaults: Vec<String>,
+    },
+
+    /// Sign for machines running with --remote-signer, using this machine's keys
+    Signer {
+        /// Address to answer HTTP signing requests on (requires a token in QDUM_SIGNER_TOKEN)
+        #[arg(long, default_value = remote_signer::DEFAULT_LISTEN)]
+        listen: std::net::SocketAddr,
+
+        /// Answer on this Unix socket instead, readable only by its owner
+        #[arg(long, conflicts_with = "listen")]
+        socket: Option<PathBuf>,
+
+        /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        /// Path to SPHINCS+ private key file (optional, defaults to the active vault's key)
+        #[arg(long)]
+        sphincs_privkey: Option<String>,
+
+        /// Only sign transactions, never unlock challenges
+        #[arg(long)]
+        no_pq: bool,
+    },
+
+    /// Record network lock snapshots for the dashboard chart, once or as a daemon
+    Snapshot {
+        /// Keep taking snapshots every --interval
+        #[arg(long)]
+        daemon: bool,
+
+        /// Time between snapshots (e.g. 15m, 1h)
+        #[arg(long, default_value = "1h", requires = "daemon")]
+        interval: String,
+
+        /// How long to keep snapshots (e.g. 90d), saved to the config
+        #[arg(long)]
+        retention: Option<String>,
+
+        /// Mint address (defaults to the active vault's mint, else QDUM devnet mint)
+        #[arg(long)]
+        mint: Option<String>,
+    },
+
+    /// Print the current unlock challenge (hex and base64), for signing it on another machine
+    Challenge {
+        /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        /// Vault wallet address, instead of reading it from a keypair
+        #[arg(long, conflicts_with = "keypair")]
+        wallet: Option<String>,
+
+        /// Print the challenge as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Inspect the wallet's token accounts
+    TokenAccounts {
+        #[command(subcommand)]
+        action: TokenAccountsAction,
+    },
+
+    /// Decode any account (PQ account, signature storage, verification state,
+    /// mint state, mint or token account) and hex-dump its data
+    Account {
+        /// Account address
+        address: String,
+
+        /// Dump all of the data instead of the first 512 bytes
+        #[arg(long)]
+        full: bool,
+    },
+
+    /// Network lock statistics behind the dashboard chart
+    Stats {
+        #[command(subcommand)]
+        action: StatsAction,
+    },
+
+    /// Launch interactive dashboard (TUI)
+    Dashboard {
+        /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
+    },
+
+    /// Vault management (create, switch, list, delete)
+    Vault {
+        #[command(subcommand)]
+        action: VaultAction,
+    },
+
+    /// Named transfer recipients, checked by the dashboard's transfer form
+    Address {
+        #[command(subcommand)]
+        action: AddressAction,
+    },
+
+    /// Named clusters the dashboard can switch between (N), besides devnet and mainnet
+    Network {
+        #[command(subcommand)]
+        action: NetworkAction,
+    },
+
+    /// SPHINCS+ key backup and recovery
+    Keys {
+        #[command(subcommand)]
+        action: KeysAction,
+    },
+
+    /// Encrypted backup of every vault to an S3-compatible bucket or WebDAV ("backup" in vaults.json)
+    Backup {
+        #[command(subcommand)]
+        action: BackupAction,
+    },
+
+    /// Hash-chained log of every signing operation
+    Audit {
+        #[command(subcommand)]
+        action: AuditAction,
+    },
+
+    /// Sign a file or message with your PQ private key (detached signature)
+    Sign {
+        /// File to sign
+        #[arg(long, conflicts_with = "message", required_unless_present = "message")]
+        file: Option<String>,
+
+        /// Text message to sign
+        #[arg(long)]
+        message: Option<String>,
+
+        /// Path to SPHINCS+ private key file (optional, defaults to the active vault's key)
+        #[arg(long, requires = "sphincs_pubkey")]
+        sphincs_privkey: Option<String>,
+
+        /// Public key to embed in the signature, required with --sphincs-privkey
+        #[arg(long, requires = "sphincs_privkey")]
+        sphincs_pubkey: Option<String>,
+
+        /// Where to write the signature (defaults to <file>.sig, or stdout for --message)
+        #[arg(long)]
+        out: Option<String>,
+    },
+
+    /// Verify a detached signature produced by `sign`
+    Verify {
+        /// File that was signed
+        #[arg(long, conflicts_with = "message", required_unless_present = "message")]
+        file: Option<String>,
+
+        /// Text message that was signed
+        #[arg(long)]
+        message: Option<String>,
+
+        /// Signature file (defaults to <file>.sig)
+        #[arg(long)]
+        signature: Option<String>,
+
+        /// Path to the signer's public key file (optional, defaults to the active vault's key)
+        #[arg(long)]
+        sphincs_pubkey: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum BackupAction {
+    /// Encrypt every vault's keys and profile with a passphrase and upload them
+    Push,
+
+    /// Download the backup and restore vaults missing here, after checking their keys against on-chain registrations
+    Pull {
+        /// Restore without checking keys against the chain (e.g. offline)
+        #[arg(long)]
+        no_verify: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum KeysAction {
+    /// Back up the active vault's SPHINCS+ keys
+    Backup {
+        /// Write a printable sheet: the keys as QR codes and checksummed text, plus the wallet address
+        #[arg(long)]
+        paper: bool,
+
+        /// Put the 24-word recovery phrase (prompted securely) on the sheet instead of the key files
+        #[arg(long, requires = "paper")]
+        mnemonic: bool,
+
+        /// File to write the sheet to (default: paper-backup-<vault>.txt in the current directory)
+        #[arg(long)]
+        out: Option<PathBuf>,
+
+        /// Overwrite an existing file
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Restore SPHINCS+ keys from a backup
+    Restore {
+        /// Restore from a 24-word recovery mnemonic (prompted securely)
+        #[arg(long)]
+        mnemonic: bool,
+
+        /// Restore from a paper backup: scan or type each part's QR code text when prompted
+        #[arg(long, conflicts_with = "mnemonic")]
+        scan: bool,
+
+        /// Output directory for keys (defaults to the active vault's key directory or the data directory)
+        #[arg(long)]
+        output_dir: Option<String>,
+
+        /// Overwrite existing keys in the output directory
+        #[arg(long)]
+        force: bool,
+
+        /// Signature algorithm the keys were generated with (defaults to the one registered
+        /// on-chain; required if the wallet has no PQ account to check against)
+        #[arg(long, value_enum)]
+        algorithm: Option<Algorithm>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ScheduleAction {
+    /// Send a transfer on a fixed interval, e.g. for payroll or DCA
+    Transfer {
+        /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        /// Recipient wallet address
+        #[arg(long)]
+        to: String,
+
+        /// Amount of QDUM tokens per transfer (in base units with 6 decimals)
+        #[arg(long)]
+        amount: u64,
+
+        /// Mint address (defaults to the active vault's mint, else QDUM devnet mint)
+        #[arg(long)]
+        mint: Option<String>,
+
+        /// Time between transfers (e.g. 1d, 1w, 2w)
+        #[arg(long)]
+        every: String,
+
+        /// First transfer (HH:MM, "YYYY-MM-DD HH:MM" or RFC 3339; default: one interval from now)
+        #[arg(long)]
+        start: Option<String>,
+
+        /// Don't ask before scheduling transfers to an address outside the address book
+        #[arg(long)]
+        yes: bool,
+    },
+
+    /// List pending scheduled locks and transfers
+    List,
+
+    /// Cancel a scheduled transfer by the ID shown in `schedule list`
+    Cancel {
+        id: u64,
+    },
+}
+
+#[derive(Subcommand)]
+enum TokenAccountsAction {
+    /// Every token account the wallet owns, with its balance, token program and rent
+    List {
+        /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        /// Wallet address, instead of reading it from a keypair
+        #[arg(long, conflicts_with = "keypair")]
+        wallet: Option<String>,
+
+        /// Print the accounts as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum AuditAction {
+    /// Check the audit log for modified, removed or truncated entries
+    Verify,
+}
+
+#[derive(Subcommand)]
+enum InheritanceAction {
+    /// Designate a beneficiary and inactivity timeout
+    Set {
+        /// Beneficiary wallet address
+        #[arg(long)]
+        beneficiary: String,
+
+        /// Inactivity period before the beneficiary can claim (e.g. 90d, 12h)
+        #[arg(long)]
+        timeout: String,
+
+        /// Most tokens the beneficiary can claim (defaults to the current balance)
+        #[arg(long)]
+        amount: Option<f64>,
+
+        /// Mint address (defaults to the active vault's mint, else QDUM devnet mint)
+        #[arg(long)]
+        mint: Option<String>,
+    },
+
+    /// Show the beneficiary, last heartbeat and time until the switch fires
+    Status,
+
+    /// Remove the inheritance configuration
+    Cancel {
+        /// Mint address (defaults to the active vault's mint, else QDUM devnet mint)
+        #[arg(long)]
+        mint: Option<String>,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Table,
+    Json,
+    Yaml,
+    Toml,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum EstimateOperation {
+    Register,
+    Lock,
+    Unlock,
+    Close,
+    Transfer,
+}
+
+impl EstimateOperation {
+    fn operation(self) -> Operation {
+        match self {
+            EstimateOperation::Register => Operation::Register,
+            EstimateOperation::Lock => Operation::Lock,
+            EstimateOperation::Unlock => Operation::Unlock,
+            EstimateOperation::Close => Operation::Close,
+            EstimateOperation::Transfer => Operation::Transfer,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum UnlockPhase {
+    /// Sign the unlock challenge into a signature file; with --challenge this
+    /// needs no network access
+    Sign {
+        /// Challenge to sign, in hex or base64 as printed by `challenge`
+        /// (fetched from the PQ account if omitted)
+        #[arg(long)]
+        challenge: Option<String>,
+
+        /// Vault wallet to fetch the challenge for (defaults to the keypair's wallet)
+        #[arg(long)]
+        wallet: Option<String>,
+
+        /// Path to your Solana wallet keypair JSON file, used only to find the wallet
+        #[arg(long, conflicts_with = "wallet")]
+        keypair: Option<String>,
+
+        /// Path to SPHINCS+ private key file (optional, defaults to the active vault's key)
+        #[arg(long)]
+        sphincs_privkey: Option<String>,
+
+        /// Where to write the signature
+        #[arg(long, default_value = "unlock-signature.json")]
+        out: PathBuf,
+    },
+
+    /// Upload a signature file from `unlock sign` and run the on-chain verification
+    Submit {
+        /// Signature file written by `unlock sign`
+        #[arg(long)]
+        signature: PathBuf,
+
+        /// Path to your Solana wallet keypair JSON file (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        /// Cooling-off delay before tokens unlock: a slot count (e.g. 9000) or a duration (e.g. 1h, 30m)
+        #[arg(long)]
+        delay: Option<String>,
+
+        /// Unlock the vault owned by this Squads multisig (the keypair only pays fees)
+        #[arg(long)]
+        multisig: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum StatsAction {
+    /// Reconstruct past lock totals from program transactions, for the time
+    /// before the dashboard started recording
+    Backfill {
+        /// Mint address (defaults to the active vault's mint, else QDUM devnet mint)
+        #[arg(long)]
+        mint: Option<String>,
+
+        /// Most recent program transactions to walk
+        #[arg(long, default_value = "5000")]
+        limit: usize,
+    },
+
+    /// Largest locked holders and their share of all locked tokens
+    Leaderboard {
+        /// Holders to list
+        #[arg(long, default_value = "10")]
+        top: usize,
+
+        /// Mint address (defaults to the active vault's mint, else QDUM devnet mint)
+        #[arg(long)]
+        mint: Option<String>,
+
+        /// Keypair whose rank to highlight (optional, uses configured path or ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        /// Query the network even if a result under five minutes old is cached
+        #[arg(long)]
+        refresh: bool,
+    },
+
+    /// How locked balances are spread over size ranges (dust to 10k+)
+    Distribution {
+        /// Mint address (defaults to the active vault's mint, else QDUM devnet mint)
+        #[arg(long)]
+        mint: Option<String>,
+
+        /// Query the network even if a result under five minutes old is cached
+        #[arg(long)]
+        refresh: bool,
+    },
+
+    /// Total locked, locked-holder count and share of the circulating supply
+    Network {
+        /// Mint address (defaults to the active vault's mint, else QDUM devnet mint)
+        #[arg(long)]
+        mint: Option<String>,
+
+        /// Query the network even if a result under five minutes old is cached
+        #[arg(long)]
+        refresh: bool,
+
+        /// Print the stats as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Export the chart data (timestamp, locked amount, holder count)
+    Export {
+        /// File format
+        #[arg(long, value_enum, default_value = "csv")]
+        format: dashboard::ExportFormat,
+
+        /// File to write (defaults to stdout)
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum NonceAction {
+    /// Create a nonce account with your wallet as its authority
+    Create,
+
+    /// Show a nonce account's authority and current nonce
+    Show {
+        /// Nonce account address
+        address: String,
+    },
+
+    /// Close a nonce account and reclaim its rent deposit
+    Close {
+        /// Nonce account address
+        address: String,
+
+        /// Where to send the reclaimed SOL (defaults to your wallet)
+        #[arg(long)]
+        receiver: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum AddressAction {
+    /// Save an address under a name (replaces an existing entry)
+    Add {
+        /// Name to show for the address
+        name: String,
+
+        /// Wallet address
+        address: String,
+    },
+
+    /// Remove a saved address
+    Remove {
+        /// Name the address was saved under
+        name: String,
+    },
+
+    /// List saved addresses
+    List,
+}
+
+#[derive(Subcommand)]
+enum NetworkAction {
+    /// Save a network (replaces an existing one, or a built-in with the same name)
+    Add {
+        /// Name to show in the dashboard's network list
+        name: String,
+
+        /// RPC endpoint URL
+        #[arg(long)]
+        rpc_url: String,
+
+        /// Vault program ID on that cluster (defaults to the devnet program)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// pqQDUM mint on that cluster (defaults to the devnet mint)
+        #[arg(long)]
+        mint: Option<String>,
+
+        /// Standard QDUM mint on that cluster (defaults to the devnet mint)
+        #[arg(long)]
+        standard_mint: Option<String>,
+    },
+
+    /// Remove a saved network
+    Remove {
+        /// Name the network was saved under
+        name: String,
+    },
+
+    /// List the networks the dashboard offers
+    List,
+}
+
+#[derive(Subcommand)]
+enum MultisigAction {
+    /// Create a proposal for a vault operation (the creator's approval is included)
+    Propose {
+        #[command(subcommand)]
+        operation: ProposalOperation,
+
+        /// Squads multisig address
+        #[arg(long)]
+        multisig: String,
+    },
+
+    /// Approve a pending proposal
+    Approve {
+        /// Squads multisig address
+        #[arg(long)]
+        multisig: String,
+
+        /// Proposal (transaction) index
+        #[arg(long)]
+        index: u64,
+    },
+
+    /// Execute a proposal that has reached its approval threshold
+    Execute {
+        /// Squads multisig address
+        #[arg(long)]
+        multisig: String,
+
+        /// Proposal (transaction) index
+        #[arg(long)]
+        index: u64,
+    },
+
+    /// Show the multisig vault, members and recent proposals
+    Status {
+        /// Squads multisig address
+        #[arg(long)]
+        multisig: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum ProposalOperation {
+    /// Register a PQ account owned by the multisig vault
+    Register {
+        /// Path to SPHINCS+ public key file (optional, defaults to the active vault's key)
+        #[arg(long)]
+        sphincs_pubkey: Option<String>,
+    },
+
+    /// Lock the multisig vault
+    Lock,
+}
+
+#[derive(Subcommand)]
+enum BridgeAction {
+    /// Wrap Standard QDUM to pqQDUM (for vault locking)
+    Wrap {
+        /// Amount to wrap (in QDUM, e.g., 100.5)
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        amount: Option<f64>,
+
+        /// Wrap the whole balance, less --keep
+        #[arg(long)]
+        all: bool,
+
+        /// With --all, QDUM to leave behind
+        #[arg(long, requires = "all", default_value_t = 0.0)]
+        keep: f64,
+
+        /// With --all, skip confirming the amount
+        #[arg(long, requires = "all")]
+        yes: bool,
+
+        /// Standard QDUM mint address (defaults to the active vault's, else devnet)
+        #[arg(long)]
+        standard_mint: Option<String>,
+
+        /// pqQDUM mint address (defaults to the active vault's, else devnet)
+        #[arg(long)]
+        pq_mint: Option<String>,
+    },
+
+    /// Unwrap pqQDUM to Standard QDUM (for DEX trading)
+    Unwrap {
+        /// Amount to unwrap (in QDUM, e.g., 100.5)
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        amount: Option<f64>,
+
+        /// Unwrap the whole balance, less --keep
+        #[arg(long)]
+        all: bool,
+
+        /// With --all, QDUM to leave behind
+        #[arg(long, requires = "all", default_value_t = 0.0)]
+        keep: f64,
+
+        /// With --all, skip confirming the amount
+        #[arg(long, requires = "all")]
+        yes: bool,
+
+        /// Standard QDUM mint address (defaults to the active vault's, else devnet)
+        #[arg(long)]
+        standard_mint: Option<String>,
+
+        /// pqQDUM mint address (defaults to the active vault's, else devnet)
+        #[arg(long)]
+        pq_mint: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum VaultAction {
+    /// List all vault profiles
+    List,
+
+    /// Create a new vault profile
+    Create {
+        /// Name for the vault
+        name: Option<String>,
+
+        /// Description (optional)
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Generate new keys automatically
+        #[arg(long)]
+        auto_generate: bool,
+    },
+
+    /// Switch active vault (interactive if no name provided)
+    Switch {
+        /// Vault name (omit for interactive menu)
+        name: Option<String>,
+    },
+
+    /// Show vault details
+    Show {
+        /// Vault name (defaults to active)
+        name: Option<String>,
+    },
+
+    /// Delete a vault profile
+    Delete {
+        /// Vault name
+        name: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        yes: bool,
+    },
+
+    /// Rename a vault
+    Rename {
+        /// Current name
+        old_name: String,
+
+        /// New name
+        new_name: String,
+    },
+
+    /// Export a vault (profile + keys) to a passphrase-encrypted archive
+    Export {
+        /// Vault name
+        name: String,
+
+        /// Archive path to write
+        #[arg(long, default_value = "vault.qdum")]
+        out: String,
+    },
+
+    /// Import a vault from an archive created by `vault export`
+    Import {
+        /// Archive path
+        path: String,
+
+        /// Import under a different name
+        #[arg(long)]
+        name: Option<String>,
+    },
+
+    /// Create a new vault and switch to it (convenience command)
+    New {
+        /// Name for the vault
+        name: Option<String>,
+
+        /// Description (optional)
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Generate new keys automatically
+        #[arg(long)]
+        auto_generate: bool,
+    },
+
+    /// Lock the vault again after each pqQDUM transfer (shows the setting if neither --on nor --off is given)
+    Relock {
+        /// Vault name (defaults to active)
+        name: Option<String>,
+
+        /// Relock after every transfer (`transfer --no-relock` skips it once)
+        #[arg(long, conflicts_with = "off")]
+        on: bool,
+
+        /// Leave the vault unlocked after transfers (`transfer --relock` still relocks once)
+        #[arg(long)]
+        off: bool,
+    },
+
+    /// Pin a vault to its own cluster, program and mints (shows them if no option is given)
+    Network {
+        /// Vault name (defaults to active)
+        name: Option<String>,
+
+        /// RPC endpoint for this vault
+        #[arg(long)]
+        set_rpc_url: Option<String>,
+
+        /// Vault program ID on that cluster
+        #[arg(long)]
+        set_program_id: Option<String>,
+
+        /// pqQDUM mint on that cluster
+        #[arg(long)]
+        set_mint: Option<String>,
+
+        /// Standard QDUM mint on that cluster
+        #[arg(long)]
+        set_standard_mint: Option<String>,
+
+        /// Remove all overrides (back to the global defaults)
+        #[arg(long, conflicts_with_all = ["set_rpc_url", "set_program_id", "set_mint", "set_standard_mint"])]
+        clear: bool,
+    },
+}
+
+fn get_styles() -> clap::builder::Styles {
+    use clap::builder::styling::*;
+    clap::builder::Styles::styled()
         .header(AnsiColor::BrightMagenta.on_default().bold())
         .usage(AnsiColor::BrightCyan.on_default().bold())
         .literal(AnsiColor::BrightGreen.on_default())
@@ -289,606 +1515,3820 @@ fn get_styles() -> clap::builder::Styles {
         .invalid(AnsiColor::BrightYellow.on_default())
 }
 
-fn print_banner() {
-    use std::io::{self, Write};
-    use std::thread;
+fn print_banner() {
+    use std::io::{self, Write};
+    use std::thread;
+
+    println!();
+
+    // Animated startup sequence
+    print!("{}", "  [".dimmed());
+    for _ in 0..3 {
+        print!("{}", "█".bright_green());
+        io::stdout().flush().unwrap();
+        thread::sleep(Duration::from_millis(50));
+    }
+    println!("{} {}", "]".dimmed(), "Initializing...".dimmed());
+    thread::sleep(Duration::from_millis(100));
+
+    println!();
+
+    // ASCII Art Logo - pqcash style
+    println!("{}", "  ╔══════════════════════════════════════════════════════════════════╗".bright_green().bold());
+    println!("{}", "  ║                                                                  ║".bright_green());
+    println!("{}", "  ║      ██████╗  ██████╗  ██████╗ █████╗ ███████╗██╗  ██╗          ║".bright_green().bold());
+    println!("{}", "  ║      ██╔══██╗██╔═══██╗██╔════╝██╔══██╗██╔════╝██║  ██║          ║".bright_green().bold());
+    println!("{}", "  ║      ██████╔╝██║   ██║██║     ███████║███████╗███████║          ║".bright_green().bold());
+    println!("{}", "  ║      ██╔═══╝ ██║▄▄ ██║██║     ██╔══██║╚════██║██╔══██║          ║".bright_green().bold());
+    println!("{}", "  ║      ██║     ╚██████╔╝╚██████╗██║  ██║███████║██║  ██║          ║".bright_green().bold());
+    println!("{}", "  ║      ╚═╝      ╚══▀▀═╝  ╚═════╝╚═╝  ╚═╝╚══════╝╚═╝  ╚═╝          ║".bright_green().bold());
+    println!("{}", "  ║                                                                  ║".bright_green());
+    println!("  ║              {}                          ║", "P O S T - Q U A N T U M   C A S H".bright_white().bold());
+    println!("  ║          {}          ║", "Quantum-Resistant Digital Currency".bright_cyan());
+    println!("{}", "  ║                                                                  ║".bright_green());
+    println!("{}", "  ╠══════════════════════════════════════════════════════════════════╣".bright_green().bold());
+    println!("{}", "  ║                                                                  ║".bright_green());
+
+    // Quick stats with icons
+    println!("  ║  {}  {}                   ║", "🔐".to_string(), format!("{:<56}", "SPHINCS+ (NIST FIPS 205) - Quantum Resistant".bright_white()));
+    println!("  ║  {}  {}                   ║", "🌐".to_string(), format!("{:<56}", "Solana Devnet - On-Chain Verification".bright_white()));
+    println!("  ║  {}  {}                   ║", "📦".to_string(), format!("{:<56}", format!("Version {} - Production Ready", env!("CARGO_PKG_VERSION")).bright_white()));
+
+    println!("{}", "  ║                                                                  ║".bright_green());
+    println!("{}", "  ╚══════════════════════════════════════════════════════════════════╝".bright_green().bold());
+    println!();
+
+    // Quick start guide
+    let mut guide_table = Table::new();
+    guide_table.load_preset(comfy_table::presets::UTF8_FULL);
+    guide_table.set_header(vec![
+        "Step".bright_white().bold().to_string(),
+        "Command".bright_cyan().to_string(),
+        "Description".dimmed().to_string()
+    ]);
+
+    guide_table
+        .add_row(vec![
+            "1".bright_yellow().to_string(),
+            "qdum-vault init".bright_green().to_string(),
+            "Generate quantum keypairs".to_string()
+        ])
+        .add_row(vec![
+            "2".bright_yellow().to_string(),
+            "qdum-vault register".bright_green().to_string(),
+            "Register on-chain".to_string()
+        ])
+        .add_row(vec![
+            "3".bright_yellow().to_string(),
+            "qdum-vault lock".bright_green().to_string(),
+            "Lock your vault".to_string()
+        ])
+        .add_row(vec![
+            "4".bright_yellow().to_string(),
+            "qdum-vault unlock".bright_green().to_string(),
+            "Unlock with quantum sig".to_string()
+        ]);
+
+    println!("{}", guide_table);
+    println!();
+    println!("  {} Type {} for all available commands",
+        "💡".to_string(),
+        "qdum-vault --help".bright_cyan().bold());
+    println!();
+}
+
+fn print_command_header(text: &str, icon: colored::ColoredString) {
+    println!();
+    println!("{}", "╔".bright_green().to_string() + &"═".repeat(68).bright_green().to_string() + &"╗".bright_green().to_string());
+    println!("║  {} {}  ║", icon, format!("{:<60}", text).bright_white().bold());
+    println!("{}", "╚".bright_green().to_string() + &"═".repeat(68).bright_green().to_string() + &"╝".bright_green().to_string());
+    println!();
+}
+
+/// The saved vault config; a missing file is an empty config, but one that
+/// can't be read is an error rather than an empty config a later save would
+/// write over it
+fn load_config() -> Result<VaultConfig> {
+    VaultConfig::load().exit_kind(ExitKind::Config)
+}
+
+fn get_default_keypair_path() -> Result<String> {
+    let config = load_config()?;
+
+    // Try to use active vault's keypair path
+    if let Some(vault) = config.get_active_vault() {
+        return Ok(vault.solana_keypair_path.clone());
+    }
+
+    // Then the keypair the Solana CLI is configured with
+    if let Some(path) = SolanaCliConfig::load().keypair_path {
+        return Ok(path);
+    }
+
+    // Fallback to default Solana path
+    Ok(paths::solana_keypair()
+        .to_str()
+        .expect("Invalid path")
+        .to_string())
+}
+
+fn load_keypair_and_extract_wallet(keypair_path: &str) -> Result<(String, Pubkey)> {
+    use solana_sdk::signature::Signer;
+
+    // With --remote-signer the wallet is whichever key the signer holds
+    if let Some(signer) = solana::client::default_signer() {
+        let wallet_pubkey = signer.try_pubkey().context("Failed to reach the remote signer")?;
+        return Ok((keypair_path.to_string(), wallet_pubkey));
+    }
+
+    let wallet_pubkey = read_keypair(keypair_path)?.pubkey();
+    Ok((keypair_path.to_string(), wallet_pubkey))
+}
+
+fn read_keypair(keypair_path: &str) -> Result<Keypair> {
+    let data = fs::read_to_string(keypair_path)
+        .context(format!("Failed to read keypair file: {}", keypair_path))?;
+
+    let bytes: Vec<u8> = serde_json::from_str(&data)
+        .context("Invalid keypair JSON format")
+        .exit_kind(ExitKind::Config)?;
+
+    Keypair::try_from(&bytes[..])
+        .context("Invalid keypair bytes")
+        .exit_kind(ExitKind::Config)
+}
+
+fn show_splash_screen() -> Result<()> {
+    use ratatui::{
+        backend::CrosstermBackend,
+        Terminal,
+        layout::{Alignment, Constraint, Direction, Layout},
+        style::{Color, Modifier, Style},
+        text::{Line, Span},
+        widgets::{Block, Paragraph},
+    };
+    use crossterm::{
+        execute,
+        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    };
+    use std::io::stdout;
+
+    // Setup terminal
+    enable_raw_mode()?;
+    let mut stdout = stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    terminal.clear()?;
+
+    // Glitch characters for animation
+    let glitch_chars = vec!["█", "▓", "▒", "░", "▀", "▄", "▌", "▐", "■", "□"];
+
+    // Animate splash screen for 4 seconds
+    let start = std::time::Instant::now();
+    let duration = std::time::Duration::from_secs(4);
+
+    while start.elapsed() < duration {
+        // Render splash screen with animated glitch
+        terminal.draw(|f| {
+            let size = f.area();
+
+            // Center the content
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Percentage(35),
+                    Constraint::Min(10),
+                    Constraint::Percentage(35),
+                ])
+                .split(size);
+
+            // Generate random glitch pattern
+            use std::time::{SystemTime, UNIX_EPOCH};
+            let seed = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as usize;
+
+            let glitch_top = format!("{}{}{}{}",
+                glitch_chars[seed % glitch_chars.len()],
+                glitch_chars[(seed + 1) % glitch_chars.len()],
+                glitch_chars[(seed + 2) % glitch_chars.len()],
+                glitch_chars[(seed + 3) % glitch_chars.len()],
+            );
+
+            let glitch_mid = format!(" {}{}{}{}{} ",
+                glitch_chars[(seed + 4) % glitch_chars.len()],
+                glitch_chars[(seed + 5) % glitch_chars.len()],
+                glitch_chars[(seed + 6) % glitch_chars.len()],
+                glitch_chars[(seed + 7) % glitch_chars.len()],
+                glitch_chars[(seed + 8) % glitch_chars.len()],
+            );
+
+            let glitch_bot = format!("{}{}{}",
+                glitch_chars[(seed + 9) % glitch_chars.len()],
+                glitch_chars[(seed + 10) % glitch_chars.len()],
+                glitch_chars[(seed + 11) % glitch_chars.len()],
+            );
+
+            // Normal text with glitch effect - white background theme
+            let title_lines = vec![
+                Line::from(""),
+                Line::from(""),
+                Line::from(""),
+                Line::from(""),
+                Line::from(""),
+                // Glitch effect - animated (darker colors for white background)
+                Line::from(vec![
+                    Span::styled(glitch_top.clone(), Style::default().fg(Color::Rgb(0, 150, 200))),
+                    Span::styled(glitch_mid.clone(), Style::default().fg(Color::Rgb(140, 140, 140))),
+                    Span::styled(glitch_bot.clone(), Style::default().fg(Color::Rgb(180, 0, 200))),
+                ]),
+                Line::from(""),
+                // Main text - dark purple theme on white
+                Line::from(vec![
+                    Span::styled("P", Style::default().fg(Color::Rgb(120, 60, 200)).add_modifier(Modifier::BOLD)),
+                    Span::styled("O", Style::default().fg(Color::Rgb(140, 80, 220)).add_modifier(Modifier::BOLD)),
+                    Span::styled("S", Style::default().fg(Color::Rgb(120, 60, 200)).add_modifier(Modifier::BOLD)),
+                    Span::styled("T", Style::default().fg(Color::Rgb(100, 50, 180)).add_modifier(Modifier::BOLD)),
+                    Span::styled("  ", Style::default()),
+                    Span::styled("Q", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
+                    Span::styled("U", Style::default().fg(Color::Rgb(160, 80, 220)).add_modifier(Modifier::BOLD)),
+                    Span::styled("A", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
+                    Span::styled("N", Style::default().fg(Color::Rgb(120, 50, 180)).add_modifier(Modifier::BOLD)),
+                    Span::styled("T", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
+                    Span::styled("U", Style::default().fg(Color::Rgb(120, 50, 180)).add_modifier(Modifier::BOLD)),
+                    Span::styled("M", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
+                ]),
+                Line::from(""),
+                // Glitch effect bottom - animated (darker colors for white background)
+                Line::from(vec![
+                    Span::styled(glitch_bot, Style::default().fg(Color::Rgb(180, 0, 200))),
+                    Span::styled(glitch_mid, Style::default().fg(Color::Rgb(140, 140, 140))),
+                    Span::styled(glitch_top, Style::default().fg(Color::Rgb(0, 150, 200))),
+                ]),
+                Line::from(""),
+                Line::from(""),
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled("SPHINCS+ SHA2-128s  •  NIST FIPS 205  •  Quantum-Resistant", Style::default().fg(Color::Rgb(100, 100, 100))),
+                ]),
+            ];
+
+            let splash = Paragraph::new(title_lines)
+                .block(Block::default())
+                .style(Style::default().bg(Color::Rgb(255, 255, 255)))  // White background
+                .alignment(Alignment::Center);
+
+            // Render white background for entire screen
+            let background = Block::default()
+                .style(Style::default().bg(Color::Rgb(255, 255, 255)));
+            f.render_widget(background, size);
+
+            f.render_widget(splash, chunks[1]);
+        })?;
+
+        // Update every 100ms for smooth animation
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    // Clean up
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> std::process::ExitCode {
+    let cli = Cli::parse();
+    // The dashboard is interactive and `status --watch` never finishes; there is nothing to hold back
+    let runs_forever = matches!(
+        cli.command,
+        None | Some(Commands::Dashboard { .. })
+            | Some(Commands::Status { watch: true, .. })
+            | Some(Commands::Monitor { daemon: true, .. })
+            | Some(Commands::Snapshot { daemon: true, .. })
+            | Some(Commands::Serve { .. })
+            | Some(Commands::Signer { .. })
+    );
+    output::set_quiet(cli.quiet && !runs_forever);
+    output::set_verbosity(cli.verbose);
+
+    let outcome = {
+        let _silenced = output::silence_stdout();
+        run(cli).await
+    };
+    output::print_results();
+
+    match outcome {
+        Ok(()) => std::process::ExitCode::SUCCESS,
+        Err(error) => {
+            let kind = exit_code::classify(&error);
+            if kind == ExitKind::Cancelled {
+                eprintln!("{} {}", "[!]".yellow(), error);
+            } else {
+                eprintln!("Error: {:?}", error);
+            }
+            std::process::ExitCode::from(kind.code())
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<()> {
+    if let Some(dir) = &cli.data_dir {
+        paths::set_data_root(dir.clone());
+    }
+
+    // Flags win over the saved config; anything unset keeps the built-in default.
+    // A config that can't be read stops every command: falling back to an empty
+    // one would drop the vaults and the policy
+    let saved = load_config()?;
+
+    // Network: flags, then the active vault's pinned cluster, then the Solana CLI config
+    let active_vault = saved.get_active_vault();
+    let pinned = |field: fn(&VaultProfile) -> &Option<String>| active_vault.and_then(|vault| field(vault).clone());
+    let rpc_url = cli
+        .rpc_url
+        .clone()
+        .or_else(|| pinned(|vault| &vault.rpc_url))
+        .or_else(|| SolanaCliConfig::load().json_rpc_url)
+        .unwrap_or_else(|| solana::client::DEFAULT_RPC_URL.to_string());
+    let program_id_str = cli
+        .program_id
+        .clone()
+        .or_else(|| pinned(|vault| &vault.program_id))
+        .unwrap_or_else(|| solana::client::DEFAULT_PROGRAM_ID.to_string());
+    let default_mint = pinned(|vault| &vault.mint).unwrap_or_else(|| DEFAULT_MINT.to_string());
+    let default_standard_mint = pinned(|vault| &vault.standard_mint).unwrap_or_else(|| DEFAULT_STANDARD_MINT.to_string());
+    let compute_budget = solana::client::ComputeBudget {
+        unit_limit: cli.compute_unit_limit.or(saved.compute_unit_limit),
+        unit_price: cli.compute_unit_price.or(saved.compute_unit_price).unwrap_or_default(),
+    };
+    solana::client::set_default_compute_budget(compute_budget);
+    solana::client::set_default_policy(saved.policy.clone());
+    notify::init(saved.desktop_notifications_enabled(), notify::ChatChannels::from_config(&saved)?);
+    solana::client::set_default_retry_policy(solana::client::RetryPolicy {
+        max_attempts: cli.max_attempts,
+        ..Default::default()
+    });
+    if let Some(nonce) = &cli.nonce {
+        let nonce = Pubkey::from_str(nonce).context("Invalid nonce account address")?;
+        solana::client::set_default_nonce_account(nonce);
+    }
+    if let Some(path) = &cli.idl {
+        solana::client::set_default_idl(solana::idl::Idl::load(path).exit_kind(ExitKind::Config)?);
+    }
+    let remote_signer = match &cli.remote_signer {
+        Some(endpoint) => {
+            let endpoint = endpoint.parse::<SignerEndpoint>().exit_kind(ExitKind::Config)?;
+            let signer = Arc::new(RemoteSigner::new(endpoint));
+            solana::client::set_default_signer(signer.clone());
+            Some(signer)
+        }
+        None => None,
+    };
+
+    // Print banner for all commands except dashboard (which takes over the screen)
+    // If no command provided, default to dashboard
+    let command = cli.command.unwrap_or(Commands::Dashboard { keypair: None });
+
+    // A signature or report printed to stdout must stay pipeable, so skip the banner there
+    let signs_to_stdout = matches!(command, Commands::Sign { file: None, out: None, .. })
+        || matches!(command, Commands::Stats { action: StatsAction::Export { out: None, .. } })
+        || matches!(command, Commands::Stats { action: StatsAction::Network { json: true, .. } })
+        || matches!(command, Commands::Challenge { json: true, .. });
+    let status_format = match &command {
+        Commands::Status { json: true, .. } => OutputFormat::Json,
+        Commands::Status { format, .. } => *format,
+        _ => OutputFormat::Table,
+    };
+    let machine_output = signs_to_stdout || status_format != OutputFormat::Table || output::quiet();
+    if !matches!(command, Commands::Dashboard { .. }) && !machine_output {
+        print_banner();
+    }
+
+    match command {
+        Commands::Init { output_dir, mnemonic, algorithm, solana_keypair, from_solana_cli, seed_file, seed_hex, vanity } => {
+            print_command_header("Initialize Quantum Keypair", "[INIT]".bright_green());
+
+            let existing_keypair = match (solana_keypair, from_solana_cli) {
+                (Some(path), _) => Some(path),
+                (None, true) => Some(SolanaCliConfig::load().keypair_path.ok_or_else(|| {
+                    ExitKind::Config.error("The Solana CLI config names no keypair (set one with: solana config set --keypair <path>)")
+                })?),
+                (None, false) => None,
+            };
+            let seeds = match (seed_file, seed_hex) {
+                (Some(path), _) => Some(crypto::seed::DerivedSeeds::from_file(&path)?),
+                (None, Some(hex)) => Some(crypto::seed::DerivedSeeds::from_hex(&hex)?),
+                (None, None) => None,
+            };
+            if let Some(prefix) = &vanity {
+                solana::vanity::validate_prefix(prefix)?;
+            }
+            cmd_init(output_dir, mnemonic, algorithm, existing_keypair, seeds, vanity).await?;
+        }
+
+        Commands::Config {
+            keypair,
+            set_compute_unit_limit,
+            set_compute_unit_price,
+            add_webhook,
+            remove_webhook,
+            desktop_notifications,
+            telegram_bot_token,
+            telegram_chat_id,
+            discord_webhook,
+            clear_chat,
+            test_notification,
+            price_feed,
+            price_path,
+            clear_price_feed,
+            snapshot_retention,
+            theme,
+            dashboard_refresh,
+            show,
+        } => {
+            print_command_header("Configuration", "[CONFIG]".bright_cyan());
+
+            let mut config = load_config()?;
+
+            if set_compute_unit_limit.is_some() || set_compute_unit_price.is_some() {
+                config.set_compute_budget(set_compute_unit_limit, set_compute_unit_price)?;
+                println!("{}", "✓ Compute budget defaults saved".green().bold());
+                if let Some(limit) = set_compute_unit_limit {
+                    println!("  {} {}", "Compute unit limit:".bold(), limit.to_string().bright_cyan());
+                }
+                if let Some(price) = set_compute_unit_price {
+                    println!("  {} {} micro-lamports", "Compute unit price:".bold(), price.to_string().bright_cyan());
+                }
+            } else if telegram_chat_id.is_some() || discord_webhook.is_some() {
+                let telegram = telegram_chat_id
+                    .map(|chat_id| notify::TelegramConfig { bot_token: telegram_bot_token.unwrap_or_default(), chat_id });
+                config.set_chat_notifications(telegram, discord_webhook)?;
+                println!("{} Chat notifications saved", "✓".green().bold());
+                println!("    Check them with: {}", "qdum-vault config --test-notification".bright_cyan());
+            } else if clear_chat {
+                config.clear_chat_notifications()?;
+                println!("{} Chat notifications removed", "✓".green().bold());
+            } else if test_notification {
+                let chat = notify::ChatChannels::from_config(&config)?;
+                if chat.is_empty() {
+                    return Err(ExitKind::Config.error("No Telegram or Discord notifications configured"));
+                }
+                let failures = chat.send("qdum-vault: test notification ✅").await;
+                for (channel, e) in &failures {
+                    println!("{} {}: {:#}", "[✗]".bright_red().bold(), channel, e);
+                }
+                if !failures.is_empty() {
+                    return Err(ExitKind::Rpc.error("Test notification failed"));
+                }
+                println!("{} Test notification sent", "✓".green().bold());
+            } else if let Some((url, path)) = price_feed.zip(price_path) {
+                config.set_price_feed(Some(price::PriceFeed { url, path }))?;
+                println!("{} Price feed saved; the lock chart shows it for new snapshots", "✓".green().bold());
+            } else if clear_price_feed {
+                config.set_price_feed(None)?;
+                println!("{} Price feed removed", "✓".green().bold());
+            } else if let Some(retention) = snapshot_retention {
+                config.set_snapshot_retention(&retention)?;
+                println!("{} Lock snapshots are kept for {}", "✓".green().bold(), retention.bright_cyan());
+            } else if let Some(theme) = theme {
+                config.set_theme(theme.name())?;
+                println!("{} Dashboard theme set to {}", "✓".green().bold(), theme.name().bright_cyan());
+            } else if let Some(interval) = dashboard_refresh {
+                config.set_dashboard_refresh(&interval)?;
+                println!("{} Dashboard auto-refresh set to {}", "✓".green().bold(), interval.bright_cyan());
+            } else if let Some(enabled) = desktop_notifications {
+                config.set_desktop_notifications(enabled)?;
+                println!("{} Desktop notifications {}", "✓".green().bold(), if enabled { "on" } else { "off" });
+            } else if add_webhook.is_some() || remove_webhook.is_some() {
+                if let Some(url) = add_webhook {
+                    if config.add_webhook(&url)? {
+                        println!("{} Webhook added: {}", "✓".green().bold(), url.bright_cyan());
+                    } else {
+                        println!("{} Webhook already configured: {}", "[i]".bright_blue(), url);
+                    }
+                }
+                if let Some(url) = remove_webhook {
+                    if config.remove_webhook(&url)? {
+                        println!("{} Webhook removed: {}", "✓".green().bold(), url.bright_cyan());
+                    } else {
+                        println!("{} No such webhook: {}", "[!]".yellow(), url);
+                    }
+                }
+            } else if keypair.is_some() {
+                println!("{}", "The config command has been replaced by vault management.".yellow());
+                println!();
+                println!("{}", "To set your default keypair, use vault commands:".bold());
+                println!("  {} - Create and switch to a new vault", "qdum-vault vault new <name> --auto-generate".bright_cyan());
+                println!("  {} - Create vault with existing keys", "qdum-vault vault create <name>".bright_cyan());
+                println!("  {} - Switch between vaults", "qdum-vault vault switch".bright_cyan());
+                println!();
+            } else if show {
+                println!("{}", "Current Configuration:".bold());
+                println!();
+
+                if let Some(vault) = config.get_active_vault() {
+                    println!("{} {}", "Active vault:".bold(), vault.name.bright_cyan());
+                    println!("{} {}", "Keypair path:".bold(), vault.solana_keypair_path.dimmed());
+                    if !vault.wallet_address.is_empty() {
+                        println!("{} {}", "Wallet:".bold(), vault.wallet_address.yellow());
+                    }
+                } else {
+                    println!("{}", "No active vault configured.".yellow());
+                    println!();
+                    println!("Create a vault with:");
+                    println!("  {}", "qdum-vault vault new <name> --auto-generate".bright_cyan());
+                }
+
+                println!();
+                let limit = config
+                    .compute_unit_limit
+                    .map(|l| l.to_string())
+                    .unwrap_or_else(|| "runtime default".to_string());
+                let price = config
+                    .compute_unit_price
+                    .unwrap_or(solana::client::DEFAULT_PRIORITY_FEE_MICRO_LAMPORTS);
+                println!("{} {}", "Compute unit limit:".bold(), limit.dimmed());
+                println!("{} {} micro-lamports", "Compute unit price:".bold(), price.to_string().dimmed());
+                let notifications = if config.desktop_notifications_enabled() { "on" } else { "off" };
+                println!("{} {}", "Desktop notifications:".bold(), notifications.dimmed());
+                let chat: Vec<&str> = [
+                    config.telegram.as_ref().map(|_| "Telegram"),
+                    config.discord_webhook.as_ref().map(|_| "Discord"),
+                ]
+                .into_iter()
+                .flatten()
+                .collect();
+                let chat = if chat.is_empty() { "none".to_string() } else { chat.join(", ") };
+                println!("{} {}", "Chat notifications:".bold(), chat.dimmed());
+                println!("{} {} days", "Snapshot retention:".bold(), config.snapshot_retention().num_days().to_string().dimmed());
+                let theme_file = paths::config_dir().join(theme::THEME_FILE);
+                let theme_name = config.theme.as_deref().unwrap_or("light");
+                if theme_file.exists() {
+                    println!("{} {} (customized by {})", "Theme:".bold(), theme_name.dimmed(), theme_file.display().to_string().dimmed());
+                } else {
+                    println!("{} {}", "Theme:".bold(), theme_name.dimmed());
+                }
+                let refresh = config.dashboard.refresh_interval.as_deref().unwrap_or(vault_manager::DEFAULT_REFRESH_INTERVAL);
+                println!("{} {}", "Dashboard refresh:".bold(), refresh.dimmed());
+                match &config.price_feed {
+                    Some(feed) => println!("{} {} ({})", "Price feed:".bold(), feed.url.dimmed(), feed.path.dimmed()),
+                    None => println!("{} {}", "Price feed:".bold(), "none".dimmed()),
+                }
+                if config.webhooks.is_empty() {
+                    println!("{} {}", "Webhooks:".bold(), "none".dimmed());
+                } else {
+                    println!("{}", "Webhooks:".bold());
+                    for url in &config.webhooks {
+                        println!("  {}", url.dimmed());
+                    }
+                }
+            } else {
+                println!("{}", "Usage:".bold());
+                println!("  qdum-vault config --show            # Show current config");
+                println!("  qdum-vault config --set-compute-unit-limit <units> --set-compute-unit-price <micro-lamports>");
+                println!("  qdum-vault config --add-webhook <url>   # Alert target for monitor --daemon");
+                println!("  qdum-vault config --desktop-notifications off");
+                println!("  qdum-vault config --discord-webhook <url>   # or --telegram-bot-token <token> --telegram-chat-id <id>");
+                println!("  qdum-vault config --price-feed <url> --price-path <json-pointer>   # Lock chart price overlay");
+                println!("  qdum-vault config --theme dark       # light, dark, high-contrast, classic-green");
+                println!();
+                println!("{}", "To manage vaults:".bold());
+                println!("  qdum-vault vault list               # List all vaults");
+                println!("  qdum-vault vault new <name>         # Create and switch to new vault");
+                println!("  qdum-vault vault switch             # Switch vaults interactively");
+            }
+        }
+
+        Commands::Register { all_unregistered: true, algorithm, hybrid, .. } => {
+            print_command_header("Register Unregistered Vaults", "[REGISTER]".bright_cyan());
+
+            let lock_mode = if hybrid { LockMode::Hybrid } else { LockMode::Standard };
+            cmd_register_all_unregistered(&load_config()?, cli.rpc_url.as_deref(), cli.program_id.as_deref(), algorithm, lock_mode)
+                .await?;
+        }
+
+        Commands::Register {
+            keypair,
+            sphincs_pubkey,
+            algorithm,
+            hybrid,
+            ..
+        } => {
+            print_command_header("Register Post-Quantum Account", "[REGISTER]".bright_cyan());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            // Get SPHINCS public key path from active vault if not provided via CLI
+            let config = load_config()?;
+            let sphincs_pubkey_path = if sphincs_pubkey.is_some() {
+                sphincs_pubkey
+            } else if let Some(vault) = config.get_active_vault() {
+                output::detail(output::VERBOSE, "Active vault:", &vault.name);
+                output::detail(output::VERBOSE, "Public key path:", &vault.sphincs_public_key_path);
+                Some(vault.sphincs_public_key_path.clone())
+            } else {
+                None
+            };
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+            println!();
+
+            let receipt = cmd_register(
+                &rpc_url,
+                program_id,
+                wallet_pubkey,
+                &kp_path,
+                sphincs_pubkey_path,
+                algorithm,
+                if hybrid { LockMode::Hybrid } else { LockMode::Standard },
+            )
+            .await?;
+            emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+        }
+
+        Commands::Lock { all_vaults: true, .. } => {
+            print_command_header("Lock All Vaults", "[LOCK]".bright_red());
+            cmd_lock_all_vaults(&load_config()?, cli.rpc_url.as_deref(), cli.program_id.as_deref()).await?;
+        }
+
+        Commands::Lock { keypair, at, after, unsigned_out, .. } => {
+            print_command_header("Lock Vault", "[LOCK]".bright_red());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+            println!();
+
+            let lock_at = match (at, after) {
+                (Some(at), _) => Some(scheduler::parse_time(&at, chrono::Local::now())?),
+                (None, Some(after)) => Some(scheduler::from_now(scheduler::parse_duration(&after)?)?),
+                (None, None) => None,
+            };
+
+            if let Some(lock_at) = lock_at {
+                cmd_schedule_lock(&rpc_url, &program_id_str, wallet_pubkey, &kp_path, lock_at)?;
+            } else {
+                let receipt = cmd_lock(&rpc_url, program_id, wallet_pubkey, &kp_path, unsigned_out.as_deref()).await?;
+                emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+            }
+        }
+
+        Commands::Unlock { phase: Some(UnlockPhase::Sign { challenge, wallet, keypair, sphincs_privkey, out }), .. } => {
+            print_command_header("Sign Unlock Challenge", "[UNLOCK]".bright_green());
+
+            // A challenge given on the command line keeps this step offline
+            let challenge = match challenge {
+                Some(challenge) => parse_challenge(&challenge)?,
+                None => {
+                    let wallet_pubkey = match wallet {
+                        Some(wallet) => Pubkey::from_str(&wallet).context("Invalid wallet address")?,
+                        None => load_keypair_and_extract_wallet(&keypair.map_or_else(get_default_keypair_path, Ok)?)?.1,
+                    };
+                    let program_id = Pubkey::from_str(&program_id_str)?;
+                    let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+                    parse_challenge(&client.unlock_challenge(wallet_pubkey).await?.challenge_hex)?
+                }
+            };
+
+            cmd_unlock_sign(&challenge, sphincs_privkey, &out)?;
+        }
+
+        Commands::Unlock { phase: Some(UnlockPhase::Submit { signature, keypair, delay, multisig }), .. } => {
+            print_command_header("Submit Unlock Signature", "[UNLOCK]".bright_green());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Signature:    ".bold(), signature.display().to_string().dimmed());
+            println!();
+
+            let delay_slots = delay.as_deref().map(parse_delay_slots).transpose()?.unwrap_or(0);
+            let vault_owner = match multisig {
+                Some(multisig) => solana::squads::vault_pda(&Pubkey::from_str(&multisig)?, solana::squads::DEFAULT_VAULT_INDEX),
+                None => wallet_pubkey,
+            };
+
+            let result = cmd_unlock(&rpc_url, program_id, vault_owner, &kp_path, UnlockKey::Presigned(signature), delay_slots, None).await;
+            notify::finished(Operation::Unlock, &result).await;
+            let receipt = result?;
+            emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+        }
+
+        Commands::Unlock { phase: None, vaults, delay, .. } if !vaults.is_empty() => {
+            print_command_header("Unlock Vaults", "[UNLOCK]".bright_green());
+
+            let delay_slots = delay.as_deref().map(parse_delay_slots).transpose()?.unwrap_or(0);
+            cmd_unlock_vaults(&load_config()?, vaults, cli.rpc_url.as_deref(), cli.program_id.as_deref(), delay_slots).await?;
+        }
+
+        Commands::Unlock {
+            phase: None,
+            keypair,
+            sphincs_privkey,
+            delay,
+            multisig,
+            dry_run,
+            jito,
+            jito_tip,
+            jito_url,
+            vaults: _,
+        } => {
+            print_command_header(if dry_run { "Unlock Vault (Dry Run)" } else { "Unlock Vault" }, "[UNLOCK]".bright_green());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+            println!();
+
+            let delay_slots = delay.as_deref().map(parse_delay_slots).transpose()?.unwrap_or(0);
+
+            // A multisig-owned vault is unlocked by its PQ proof alone; any member can pay for it
+            let vault_owner = match multisig {
+                Some(multisig) => {
+                    let vault = solana::squads::vault_pda(&Pubkey::from_str(&multisig)?, solana::squads::DEFAULT_VAULT_INDEX);
+                    println!("{} {}", "Multisig vault:".bold(), vault.to_string().yellow());
+                    println!();
+                    vault
+                }
+                None => wallet_pubkey,
+            };
+
+            let jito = jito.then_some(JitoConfig { block_engine_url: jito_url, tip_lamports: jito_tip });
+
+            if dry_run {
+                cmd_unlock_dry_run(&rpc_url, program_id, vault_owner, &kp_path, delay_slots).await?;
+            } else {
+                let result = cmd_unlock(
+                    &rpc_url,
+                    program_id,
+                    vault_owner,
+                    &kp_path,
+                    // An explicit key file still wins over the remote signer
+                    match (remote_signer, sphincs_privkey) {
+                        (Some(remote), None) => UnlockKey::Remote(remote),
+                        (_, sphincs_privkey) => UnlockKey::PrivateKey(sphincs_privkey),
+                    },
+                    delay_slots,
+                    jito,
+                )
+                .await;
+                notify::finished(Operation::Unlock, &result).await;
+                let receipt = result?;
+                emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+            }
+        }
+
+        Commands::Close { keypair, receiver, unsigned_out } => {
+            print_command_header("Close PQ Account", "[CLOSE]".bright_red());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            // Parse receiver address if provided
+            let receiver_pubkey = receiver
+                .as_ref()
+                .map(|r| Pubkey::from_str(r))
+                .transpose()?;
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+            println!();
+
+            let receipt = cmd_close(&rpc_url, program_id, wallet_pubkey, &kp_path, receiver_pubkey, unsigned_out.as_deref()).await?;
+            emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+        }
+
+        Commands::RotateKeys { keypair, yes } => {
+            print_command_header("Rotate SPHINCS+ Keys", "[ROTATE]".bright_yellow());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+            println!();
+
+            cmd_rotate_keys(&rpc_url, program_id, wallet_pubkey, &kp_path, yes).await?;
+        }
+
+        Commands::Status { keypair, watch, interval, .. } => {
+            let program_id = Pubkey::from_str(&program_id_str)?;
+
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            if watch {
+                let mints = [Pubkey::from_str(&default_mint)?, Pubkey::from_str(&default_standard_mint)?];
+                let interval = scheduler::parse_duration(&interval)?.to_std().context("Invalid --interval")?;
+                return cmd_status_watch(&rpc_url, program_id, wallet_pubkey, &mints, interval, status_format).await;
+            }
+
+            if status_format != OutputFormat::Table {
+                let mints = [Pubkey::from_str(&default_mint)?, Pubkey::from_str(&default_standard_mint)?];
+                return cmd_status_report(&rpc_url, program_id, wallet_pubkey, &mints, status_format).await;
+            }
+
+            print_command_header("Vault Status", "[STATUS]".bright_cyan());
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+            println!();
+
+            cmd_status(&rpc_url, program_id, wallet_pubkey).await?;
+        }
+
+        Commands::Balance { keypair, mint } => {
+            print_command_header("Check Balance", "[BALANCE]".bright_cyan());
+
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+            println!();
+
+            let mint_pubkey = Pubkey::from_str(mint.as_deref().unwrap_or(&default_mint))?;
+
+            cmd_balance(&rpc_url, wallet_pubkey, mint_pubkey).await?;
+        }
+
+        Commands::Receive { keypair, amount, mint } => {
+            print_command_header("Receive Tokens", "[RECEIVE]".bright_green());
+
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (_, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            let payload = if amount.is_some() || mint.is_some() {
+                let mint = Pubkey::from_str(mint.as_deref().unwrap_or(&default_mint))?;
+                let amount = match amount {
+                    Some(amount) => Some(payment_amount(amount, token_decimals(&rpc_url, &mint).await?)?),
+                    None => None,
+                };
+                TransferRequest {
+                    amount,
+                    spl_token: Some(mint),
+                    ..TransferRequest::new(wallet_pubkey)
+                }
+                .uri()
+            } else {
+                wallet_pubkey.to_string()
+            };
+
+            cmd_receive(wallet_pubkey, &payload)?;
+        }
+
+        Commands::Request { keypair, amount, pq, label, message, wait, timeout } => {
+            print_command_header("Payment Request", "[REQUEST]".bright_green());
+
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (_, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+            let mint = Pubkey::from_str(if pq { &default_mint } else { &default_standard_mint })?;
+            // A fresh address per request, so its payment can be told apart
+            let reference = solana_sdk::signature::Signer::pubkey(&Keypair::new());
+
+            let request = TransferRequest {
+                amount: Some(payment_amount(amount, token_decimals(&rpc_url, &mint).await?)?),
+                spl_token: Some(mint),
+                reference: Some(reference),
+                label,
+                message,
+                ..TransferRequest::new(wallet_pubkey)
+            };
+
+            cmd_receive(wallet_pubkey, &request.uri())?;
+            println!("{} {}", "Reference: ".bold(), reference.to_string().bright_magenta());
+            println!();
+
+            if wait {
+                cmd_monitor_payment(&rpc_url, &request, &timeout).await?;
+            } else {
+                println!("{}", "Watch for the payment with:".dimmed());
+                println!(
+                    "  pqcoin monitor-payment --reference {} --amount {}{}",
+                    reference,
+                    amount,
+                    if pq { " --pq" } else { "" }
+                );
+            }
+        }
+
+        Commands::MonitorPayment { keypair, reference, amount, pq, timeout } => {
+            print_command_header("Payment Monitor", "[PAYMENT]".bright_green());
+
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (_, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+            let mint = Pubkey::from_str(if pq { &default_mint } else { &default_standard_mint })?;
+
+            let request = TransferRequest {
+                amount: Some(payment_amount(amount, token_decimals(&rpc_url, &mint).await?)?),
+                spl_token: Some(mint),
+                reference: Some(Pubkey::from_str(&reference)?),
+                ..TransferRequest::new(wallet_pubkey)
+            };
+            cmd_monitor_payment(&rpc_url, &request, &timeout).await?;
+        }
+
+        Commands::Transfer { keypair, to, amount, mint, unsigned_out, at, override_daily_limit, yes, relock, no_relock } => {
+            print_command_header("Transfer Tokens", "[TRANSFER]".bright_yellow());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "From:         ".bold(), wallet_pubkey.to_string().yellow());
+            println!();
+
+            let recipient = Pubkey::from_str(&to)?;
+            let mint_pubkey = Pubkey::from_str(mint.as_deref().unwrap_or(&default_mint))?;
+
+            let send_at = at.map(|at| scheduler::parse_time(&at, chrono::Local::now())).transpose()?;
+
+            // A scheduled transfer is held to the daily limit when it's sent
+            let decimals = token_decimals(&rpc_url, &mint_pubkey).await?;
+            let mut policy = saved.policy.clone();
+            let daily_limit = if send_at.is_some() { Ok(()) } else { policy.check_daily_limit(&mint_pubkey, amount) };
+            match daily_limit {
+                Ok(()) => {
+                    if policy.requires_confirmation(&mint_pubkey, amount) {
+                        confirm_policy_transfer("is over the policy's confirmation threshold", &recipient, amount, decimals)?;
+                    }
+                }
+                Err(e) if !override_daily_limit => {
+                    eprintln!("{} Pass --override-daily-limit to send it anyway", "[!]".yellow());
+                    return Err(e);
+                }
+                Err(e) => {
+                    println!("{} {:#}", "[!]".yellow(), e);
+                    confirm_policy_transfer("goes over the daily limit", &recipient, amount, decimals)?;
+                    applog::write(applog::Level::Warn, "policy", format!("Daily limit overridden for {} base units to {}", amount, recipient));
+                    policy.max_daily_transfer = None;
+                }
+            }
+
+            if !yes {
+                confirm_new_recipient(&saved, &recipient)?;
+            }
+
+            if let Some(send_at) = send_at {
+                let transfer = ScheduledTransfer {
+                    id: 0,
+                    keypair_path: kp_path.clone(),
+                    wallet: wallet_pubkey.to_string(),
+                    recipient: recipient.to_string(),
+                    mint: mint_pubkey.to_string(),
+                    amount,
+                    rpc_url: rpc_url.clone(),
+                    program_id: program_id_str.clone(),
+                    send_at: send_at.to_rfc3339(),
+                    every: None,
+                    created_at: chrono::Utc::now().to_rfc3339(),
+                };
+                cmd_schedule_transfer(transfer, send_at)?;
+            } else {
+                let mut builder = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).policy(policy);
+                if let Some(dir) = &unsigned_out {
+                    builder = builder.unsigned_out(dir);
+                }
+                let result = cmd_transfer(&builder.build()?, &kp_path, recipient, mint_pubkey, amount).await;
+                notify::finished(Operation::Transfer, &result).await;
+                let receipt = result?;
+                emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+
+                // Only pqQDUM needs the vault unlocked to move
+                let relock = !no_relock && (relock || saved.get_active_vault().is_some_and(|vault| vault.relock_after_transfer));
+                let sent = unsigned_out.is_none() && !receipt.signatures.is_empty();
+                if relock && sent && mint_pubkey == Pubkey::from_str(&default_mint)? {
+                    println!("{}", "Locking the vault again...".bold());
+                    match cmd_lock(&rpc_url, program_id, wallet_pubkey, &kp_path, None).await {
+                        Ok(lock) => emit_receipt(&lock, None)?,
+                        Err(e) => {
+                            eprintln!("{} The transfer went through but the vault is still unlocked; run {}", "[!]".yellow(), "pqcoin lock".bright_cyan());
+                            return Err(e);
+                        }
+                    }
+                }
+            }
+        }
+
+        Commands::Sweep { keypair, to, mints, sol, yes } => {
+            print_command_header("Sweep Wallet", "[SWEEP]".bright_yellow());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+            let recipient = Pubkey::from_str(&to).context("Invalid destination address")?;
+            if recipient == wallet_pubkey {
+                anyhow::bail!("The destination is the wallet being swept");
+            }
+            let mints = if mints.is_empty() { vec![default_mint.clone(), default_standard_mint.clone()] } else { mints };
+            let mints = mints
+                .iter()
+                .map(|mint| Pubkey::from_str(mint).with_context(|| format!("Invalid mint address '{}'", mint)))
+                .collect::<Result<Vec<_>>>()?;
+
+            println!("{} {}", "From:         ".bold(), wallet_pubkey.to_string().yellow());
+            println!("{} {}", "To:           ".bold(), recipient.to_string().cyan());
+            println!();
+
+            let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+            let receipts = cmd_sweep(&client, &saved, &kp_path, wallet_pubkey, recipient, &mints, sol, yes).await?;
+            for receipt in &receipts {
+                emit_receipt(receipt, None)?;
+            }
+        }
+
+        Commands::Broadcast { files } => {
+            print_command_header("Broadcast Transactions", "[BROADCAST]".bright_cyan());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+            let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+            cmd_broadcast(&client, &files).await?;
+        }
+
+        Commands::Bridge { action, keypair } => {
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            match action {
+                BridgeAction::Wrap { amount, all: _, keep, yes, standard_mint, pq_mint } => {
+                    print_command_header("Wrap Standard QDUM → pqQDUM", "[BRIDGE]".bright_magenta());
+
+                    println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+                    println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+                    println!();
+
+                    let standard_mint_pubkey = Pubkey::from_str(standard_mint.as_deref().unwrap_or(&default_standard_mint))?;
+                    let pq_mint_pubkey = Pubkey::from_str(pq_mint.as_deref().unwrap_or(&default_mint))?;
+                    let result = cmd_bridge_wrap(
+                        &rpc_url,
+                        wallet_pubkey,
+                        &kp_path,
+                        standard_mint_pubkey,
+                        pq_mint_pubkey,
+                        amount,
+                        keep,
+                        yes,
+                    ).await;
+                    notify::finished(Operation::Wrap, &result).await;
+                    let receipt = result?;
+                    emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+                }
+
+                BridgeAction::Unwrap { amount, all: _, keep, yes, standard_mint, pq_mint } => {
+                    print_command_header("Unwrap pqQDUM → Standard QDUM", "[BRIDGE]".bright_magenta());
+
+                    println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+                    println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+                    println!();
+
+                    let standard_mint_pubkey = Pubkey::from_str(standard_mint.as_deref().unwrap_or(&default_standard_mint))?;
+                    let pq_mint_pubkey = Pubkey::from_str(pq_mint.as_deref().unwrap_or(&default_mint))?;
+                    let result = cmd_bridge_unwrap(
+                        &rpc_url,
+                        wallet_pubkey,
+                        &kp_path,
+                        standard_mint_pubkey,
+                        pq_mint_pubkey,
+                        amount,
+                        keep,
+                        yes,
+                    ).await;
+                    notify::finished(Operation::Unwrap, &result).await;
+                    let receipt = result?;
+                    emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+                }
+            }
+        }
+
+        Commands::Secure { amount, keypair, standard_mint, pq_mint, yes } => {
+            print_command_header("Wrap and Lock", "[SECURE]".bright_red());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+            let standard_mint_pubkey = Pubkey::from_str(standard_mint.as_deref().unwrap_or(&default_standard_mint))?;
+            let pq_mint_pubkey = Pubkey::from_str(pq_mint.as_deref().unwrap_or(&default_mint))?;
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+            println!();
+
+            let receipts = cmd_secure(
+                &rpc_url,
+                program_id,
+                wallet_pubkey,
+                &kp_path,
+                standard_mint_pubkey,
+                pq_mint_pubkey,
+                amount,
+                yes,
+            ).await?;
+            for receipt in &receipts {
+                emit_receipt(receipt, None)?;
+            }
+        }
+
+        Commands::Release { amount, keypair, sphincs_privkey, standard_mint, pq_mint, yes } => {
+            print_command_header("Unlock and Unwrap", "[RELEASE]".bright_green());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+            let standard_mint_pubkey = Pubkey::from_str(standard_mint.as_deref().unwrap_or(&default_standard_mint))?;
+            let pq_mint_pubkey = Pubkey::from_str(pq_mint.as_deref().unwrap_or(&default_mint))?;
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+            println!();
+
+            let key = match (remote_signer, sphincs_privkey) {
+                (Some(remote), None) => UnlockKey::Remote(remote),
+                (_, sphincs_privkey) => UnlockKey::PrivateKey(sphincs_privkey),
+            };
+            let receipts = cmd_release(
+                &rpc_url,
+                program_id,
+                wallet_pubkey,
+                &kp_path,
+                key,
+                standard_mint_pubkey,
+                pq_mint_pubkey,
+                amount,
+                yes,
+            ).await?;
+            for receipt in &receipts {
+                emit_receipt(receipt, None)?;
+            }
+        }
+
+        Commands::Inheritance { action, keypair } => {
+            print_command_header("Inheritance", "[INHERIT]".bright_magenta());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+            println!();
+
+            let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+
+            match action {
+                InheritanceAction::Set { beneficiary, timeout, amount, mint } => {
+                    let beneficiary = Pubkey::from_str(&beneficiary)?;
+                    let mint_pubkey = Pubkey::from_str(mint.as_deref().unwrap_or(&default_mint))?;
+                    let timeout_secs = scheduler::parse_duration(&timeout)?.num_seconds();
+                    // The approval is for this exact amount; tokens received later aren't covered
+                    let amount = match amount {
+                        Some(tokens) => payment_amount(tokens, client.mint_decimals(mint_pubkey).await?)?,
+                        None => match client.get_balance(wallet_pubkey, mint_pubkey).await? {
+                            0 => return Err(ExitKind::Usage.error("The balance is 0; pass --amount to set what the beneficiary can claim")),
+                            balance => balance,
+                        },
+                    };
+
+                    let receipt = client
+                        .configure_inheritance(wallet_pubkey, &kp_path, beneficiary, timeout_secs, mint_pubkey, amount)
+                        .await?;
+                    emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+                }
+                InheritanceAction::Status => client.inheritance_status(wallet_pubkey).await?,
+                InheritanceAction::Cancel { mint } => {
+                    let mint_pubkey = Pubkey::from_str(mint.as_deref().unwrap_or(&default_mint))?;
+
+                    let receipt = client.cancel_inheritance(wallet_pubkey, &kp_path, mint_pubkey).await?;
+                    emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+                }
+            }
+        }
+
+        Commands::Heartbeat { keypair } => {
+            print_command_header("Heartbeat", "[HEARTBEAT]".bright_magenta());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+            println!();
+
+            let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+            let receipt = client.heartbeat(wallet_pubkey, &kp_path).await?;
+            emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+        }
+
+        Commands::ClaimInheritance { owner, keypair, mint } => {
+            print_command_header("Claim Inheritance", "[INHERIT]".bright_magenta());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            let owner_pubkey = Pubkey::from_str(&owner)?;
+            let mint_pubkey = Pubkey::from_str(mint.as_deref().unwrap_or(&default_mint))?;
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Beneficiary:  ".bold(), wallet_pubkey.to_string().yellow());
+            println!("{} {}", "Owner:        ".bold(), owner_pubkey.to_string().yellow());
+            println!();
+
+            let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+            let receipt = client.claim_inheritance(owner_pubkey, &kp_path, mint_pubkey).await?;
+            emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+        }
+
+        Commands::Multisig { action, keypair } => {
+            print_command_header("Squads Multisig", "[MULTISIG]".bright_magenta());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+            println!("{} {}", "Member:       ".bold(), wallet_pubkey.to_string().yellow());
+            println!();
+
+            let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+
+            match action {
+                MultisigAction::Propose { operation, multisig } => {
+                    let multisig = Pubkey::from_str(&multisig)?;
+                    let receipt = match operation {
+                        ProposalOperation::Register { sphincs_pubkey } => {
+                            let sphincs_pubkey_path = match sphincs_pubkey {
+                                Some(path) => Some(path),
+                                None => load_config()?.get_active_vault().map(|v| v.sphincs_public_key_path.clone()),
+                            };
+                            let sphincs_pubkey = SphincsKeyManager::new(None)?.load_public_key(sphincs_pubkey_path)?;
+                            client.propose_register(multisig, &kp_path, &sphincs_pubkey).await?
+                        }
+                        ProposalOperation::Lock => client.propose_lock(multisig, &kp_path).await?,
+                    };
+                    emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+                }
+                MultisigAction::Approve { multisig, index } => {
+                    let receipt = client.approve_proposal(Pubkey::from_str(&multisig)?, index, &kp_path).await?;
+                    emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+                }
+                MultisigAction::Execute { multisig, index } => {
+                    let receipt = client.execute_proposal(Pubkey::from_str(&multisig)?, index, &kp_path).await?;
+                    emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+                }
+                MultisigAction::Status { multisig } => client.multisig_status(Pubkey::from_str(&multisig)?).await?,
+            }
+        }
+
+        Commands::Nonce { action, keypair } => {
+            print_command_header("Durable Nonce", "[NONCE]".bright_cyan());
+
+            let client = VaultClient::builder().rpc_url(&rpc_url).build()?;
+
+            match action {
+                NonceAction::Create => {
+                    let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+                    let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+                    println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
+                    println!("{} {}", "Authority:    ".bold(), wallet_pubkey.to_string().yellow());
+                    println!();
+
+                    let receipt = client.create_nonce_account(&kp_path).await?;
+                    emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+                }
+                NonceAction::Show { address } => {
+                    let address = Pubkey::from_str(&address)?;
+                    client.nonce_status(address).await?;
+                }
+                NonceAction::Close { address, receiver } => {
+                    let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+                    let (kp_path, _) = load_keypair_and_extract_wallet(&keypair_path)?;
+                    let address = Pubkey::from_str(&address)?;
+                    let receiver = receiver.map(|r| Pubkey::from_str(&r)).transpose()?;
+
+                    let receipt = client.close_nonce_account(address, &kp_path, receiver).await?;
+                    emit_receipt(&receipt, cli.receipt_out.as_deref())?;
+                }
+            }
+        }
+
+        Commands::Address { action } => {
+            let mut config = VaultConfig::load()?;
+            match action {
+                AddressAction::Add { name, address } => {
+                    let address = Pubkey::from_str(&address).map_err(|e| ExitKind::Usage.error(format!("Invalid address: {}", e)))?;
+                    match config.add_address(&name, &address)? {
+                        Some(previous) if previous != address.to_string() => {
+                            println!("{} {} now points to {} (was {})", "✓".green().bold(), name.bright_cyan(), address, previous.dimmed())
+                        }
+                        _ => println!("{} Saved {} as {}", "✓".green().bold(), address, name.bright_cyan()),
+                    }
+                }
+                AddressAction::Remove { name } => {
+                    if config.remove_address(&name)? {
+                        println!("{} Removed {}", "✓".green().bold(), name.bright_cyan());
+                    } else {
+                        println!("{} No saved address named {}", "[!]".yellow(), name);
+                    }
+                }
+                AddressAction::List => {
+                    if config.address_book.is_empty() {
+                        println!("{} {}", "Address book:".bold(), "empty".dimmed());
+                    }
+                    for (name, address) in &config.address_book {
+                        println!("  {:<20} {}", name.bright_cyan(), address);
+                    }
+                }
+            }
+        }
+
+        Commands::Network { action } => {
+            let mut config = VaultConfig::load()?;
+            match action {
+                NetworkAction::Add { name, rpc_url, program_id, mint, standard_mint } => {
+                    let network = vault_manager::NetworkProfile { rpc_url, program_id, mint, standard_mint };
+                    if config.add_network(&name, network)? {
+                        println!("{} Replaced network {}", "✓".green().bold(), name.bright_cyan());
+                    } else {
+                        println!("{} Saved network {}", "✓".green().bold(), name.bright_cyan());
+                    }
+                }
+                NetworkAction::Remove { name } => {
+                    if config.remove_network(&name)? {
+                        println!("{} Removed {}", "✓".green().bold(), name.bright_cyan());
+                    } else {
+                        println!("{} No saved network named {}", "[!]".yellow(), name);
+                    }
+                }
+                NetworkAction::List => {
+                    for (name, network) in config.network_profiles() {
+                        let marker = if network.rpc_url == rpc_url { "●".green() } else { " ".normal() };
+                        println!("{} {:<20} {}", marker, name.bright_cyan(), network.rpc_url);
+                    }
+                }
+            }
+        }
+
+        Commands::Vault { action } => {
+            match action {
+                VaultAction::List => cmd_vault_list()?,
+                VaultAction::Create { name, description, auto_generate } => cmd_vault_create(name, description, auto_generate)?,
+                VaultAction::Switch { name } => cmd_vault_switch(&rpc_url, &program_id_str, &name).await?,
+                VaultAction::Show { name } => cmd_vault_show(&name)?,
+                VaultAction::Delete { name, yes } => cmd_vault_delete(&rpc_url, &program_id_str, &name, yes).await?,
+                VaultAction::Rename { old_name, new_name } => cmd_vault_rename(&old_name, &new_name)?,
+                VaultAction::New { name, description, auto_generate } => cmd_vault_new(name, description, auto_generate)?,
+                VaultAction::Export { name, out } => cmd_vault_export(&name, &out)?,
+                VaultAction::Import { path, name } => cmd_vault_import(&path, name)?,
+                VaultAction::Relock { name, on, off } => cmd_vault_relock(&name, on, off)?,
+                VaultAction::Network { name, set_rpc_url, set_program_id, set_mint, set_standard_mint, clear } => {
+                    cmd_vault_network(&name, set_rpc_url, set_program_id, set_mint, set_standard_mint, clear)?
+                }
+            }
+        }
+
+        Commands::Audit { action } => {
+            match action {
+                AuditAction::Verify => cmd_audit_verify()?,
+            }
+        }
+
+        Commands::Keys { action } => {
+            match action {
+                KeysAction::Backup { paper, mnemonic, out, force } => {
+                    print_command_header("Back Up SPHINCS+ Keys", "[KEYS]".bright_green());
+
+                    if !paper {
+                        return Err(ExitKind::Usage.error("Specify a backup format, e.g. --paper"));
+                    }
+                    cmd_keys_backup_paper(mnemonic, out, force)?;
+                }
+                KeysAction::Restore { mnemonic, scan, output_dir, force, algorithm } => {
+                    print_command_header("Restore SPHINCS+ Keys", "[KEYS]".bright_green());
+
+                    let program_id = Pubkey::from_str(&program_id_str)?;
+                    cmd_keys_restore(&rpc_url, program_id, mnemonic, scan, output_dir, force, algorithm).await?;
+                }
+            }
+        }
+
+        Commands::Backup { action } => {
+            let config = load_config()?;
+            let destination = config.backup.clone().ok_or_else(|| {
+                ExitKind::Config.error("No backup destination configured: add \"backup\" to vaults.json (see the README)")
+            })?;
+            match action {
+                BackupAction::Push => {
+                    print_command_header("Push Vault Backup", "[BACKUP]".bright_cyan());
+                    cmd_backup_push(&config, &destination).await?;
+                }
+                BackupAction::Pull { no_verify } => {
+                    print_command_header("Pull Vault Backup", "[BACKUP]".bright_cyan());
+                    cmd_backup_pull(&destination, cli.rpc_url.as_deref(), cli.program_id.as_deref(), !no_verify).await?;
+                }
+            }
+        }
+
+        Commands::Sign { file, message, sphincs_privkey, sphincs_pubkey, out } => {
+            if !signs_to_stdout {
+                print_command_header("Sign Message", "[SIGN]".bright_green());
+            }
+
+            cmd_sign(file, message, sphincs_privkey, sphincs_pubkey, out)?;
+        }
+
+        Commands::Verify { file, message, signature, sphincs_pubkey } => {
+            print_command_header("Verify Signature", "[VERIFY]".bright_cyan());
+
+            cmd_verify(file, message, signature, sphincs_pubkey)?;
+        }
+
+        Commands::Estimate { operation, algorithm, sol_price } => {
+            print_command_header("Cost Estimate", "[ESTIMATE]".bright_cyan());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+
+            // Size accounts for the active vault's key unless told otherwise
+            let algorithm = algorithm.unwrap_or_else(|| {
+                let path = saved.get_active_vault().map(|v| v.sphincs_public_key_path.clone());
+                SphincsKeyManager::new(None)
+                    .and_then(|km| km.load_public_key(path))
+                    .map(|key| key.algorithm)
+                    .unwrap_or_default()
+            });
+
+            println!("{} {}", "Cluster:  ".bold(), rpc_url.dimmed());
+            println!("{} {}", "Algorithm:".bold(), algorithm.to_string().cyan());
+            println!();
+
+            let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+            let estimate = client.estimate_operation(operation.operation(), algorithm).await?;
+            estimate.print(sol_price);
+        }
+
+        Commands::Schedule { action, list, clear, interval } => {
+            print_command_header("Scheduler", "[SCHEDULE]".bright_red());
+
+            match action {
+                Some(ScheduleAction::Transfer { keypair, to, amount, mint, every, start, yes }) => {
+                    let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+                    let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+                    let recipient = Pubkey::from_str(&to)?;
+                    let mint_pubkey = Pubkey::from_str(mint.as_deref().unwrap_or(&default_mint))?;
+                    let interval = scheduler::parse_duration(&every)?;
+                    let send_at = match start {
+                        Some(start) => scheduler::parse_time(&start, chrono::Local::now())?,
+                        None => scheduler::from_now(interval)?,
+                    };
+
+                    println!("{} {}", "From:         ".bold(), wallet_pubkey.to_string().yellow());
+                    println!("{} {}", "To:           ".bold(), recipient.to_string().cyan());
+                    let decimals = token_decimals(&rpc_url, &mint_pubkey).await?;
+                    let tokens = units::to_tokens(amount, decimals);
+                    println!("{} {} tokens every {}", "Amount:       ".bold(), tokens.to_string().green(), every);
+                    println!();
+
+                    // Sent unattended, so anything the policy wants typed is typed now
+                    if saved.policy.requires_confirmation(&mint_pubkey, amount) {
+                        confirm_policy_transfer("is over the policy's confirmation threshold", &recipient, amount, decimals)?;
+                    }
+                    if !yes {
+                        confirm_new_recipient(&saved, &recipient)?;
+                    }
+
+                    let transfer = ScheduledTransfer {
+                        id: 0,
+                        keypair_path: kp_path,
+                        wallet: wallet_pubkey.to_string(),
+                        recipient: recipient.to_string(),
+                        mint: mint_pubkey.to_string(),
+                        amount,
+                        rpc_url: rpc_url.clone(),
+                        program_id: program_id_str.clone(),
+                        send_at: send_at.to_rfc3339(),
+                        every: Some(every),
+                        created_at: chrono::Utc::now().to_rfc3339(),
+                    };
+                    cmd_schedule_transfer(transfer, send_at)?;
+                }
+                Some(ScheduleAction::List) => cmd_schedule(true, false, interval).await?,
+                Some(ScheduleAction::Cancel { id }) => cmd_schedule_cancel(id)?,
+                None => cmd_schedule(list, clear, interval).await?,
+            }
+        }
+
+        Commands::Snapshot { daemon, interval, retention, mint } => {
+            print_command_header("Network Lock Snapshots", "[SNAPSHOT]".bright_cyan());
+
+            if let Some(retention) = retention {
+                load_config()?.set_snapshot_retention(&retention)?;
+            }
+            let program_id = Pubkey::from_str(&program_id_str)?;
+            let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+            let mint_pubkey = Pubkey::from_str(mint.as_deref().unwrap_or(&default_mint))?;
+            let interval = daemon
+                .then(|| scheduler::parse_duration(&interval)?.to_std().context("Invalid --interval"))
+                .transpose()?;
+
+            cmd_snapshot(&client, mint_pubkey, interval).await?;
+        }
+
+        Commands::Challenge { keypair, wallet, json } => {
+            let program_id = Pubkey::from_str(&program_id_str)?;
+            let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+            let wallet_pubkey = match wallet {
+                Some(wallet) => Pubkey::from_str(&wallet).context("Invalid wallet address")?,
+                None => load_keypair_and_extract_wallet(&keypair.map_or_else(get_default_keypair_path, Ok)?)?.1,
+            };
+            let challenge = client.unlock_challenge(wallet_pubkey).await?;
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&challenge)?);
+            } else {
+                print_command_header("Unlock Challenge", "[CHALLENGE]".bright_cyan());
+                print_unlock_challenge(&challenge);
+            }
+        }
+
+        Commands::TokenAccounts { action: TokenAccountsAction::List { keypair, wallet, json } } => {
+            let program_id = Pubkey::from_str(&program_id_str)?;
+            let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+            let wallet_pubkey = match wallet {
+                Some(wallet) => Pubkey::from_str(&wallet).context("Invalid wallet address")?,
+                None => load_keypair_and_extract_wallet(&keypair.map_or_else(get_default_keypair_path, Ok)?)?.1,
+            };
+            let accounts = client.token_accounts(wallet_pubkey).await?;
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&accounts)?);
+            } else {
+                print_command_header("Token Accounts", "[TOKENS]".bright_cyan());
+                print_token_accounts(&accounts);
+            }
+        }
+
+        Commands::Account { address, full } => {
+            print_command_header("Account Inspection", "[ACCOUNT]".bright_cyan());
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+            let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+            let address = Pubkey::from_str(&address).context("Invalid account address")?;
+            cmd_account(&client, program_id, address, full).await?;
+        }
+
+        Commands::Stats { action } => match action {
+            StatsAction::Backfill { mint, limit } => {
+                print_command_header("Network Lock Stats", "[STATS]".bright_cyan());
+
+                let program_id = Pubkey::from_str(&program_id_str)?;
+                let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+                let mint_pubkey = Pubkey::from_str(mint.as_deref().unwrap_or(&default_mint))?;
+                cmd_stats_backfill(&client, mint_pubkey, limit).await?;
+            }
+            StatsAction::Leaderboard { top, mint, keypair, refresh } => {
+                print_command_header("Locked Holders", "[STATS]".bright_cyan());
+
+                let program_id = Pubkey::from_str(&program_id_str)?;
+                let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+                let mint_pubkey = Pubkey::from_str(mint.as_deref().unwrap_or(&default_mint))?;
+                // Without a keypair the board is still useful, just without "you"
+                let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+                let wallet = load_keypair_and_extract_wallet(&keypair_path).ok().map(|(_, wallet)| wallet);
+                cmd_stats_leaderboard(&client, mint_pubkey, top, wallet, refresh).await?;
+            }
+            StatsAction::Distribution { mint, refresh } => {
+                print_command_header("Lock Distribution", "[STATS]".bright_cyan());
+
+                let program_id = Pubkey::from_str(&program_id_str)?;
+                let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+                let mint_pubkey = Pubkey::from_str(mint.as_deref().unwrap_or(&default_mint))?;
+                cmd_stats_distribution(&client, mint_pubkey, refresh).await?;
+            }
+            StatsAction::Network { mint, refresh, json } => {
+                let program_id = Pubkey::from_str(&program_id_str)?;
+                let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+                let mint_pubkey = Pubkey::from_str(mint.as_deref().unwrap_or(&default_mint))?;
+                let stats = client.network_lock_stats(mint_pubkey, refresh).await?;
+
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&stats)?);
+                } else {
+                    print_command_header("Network Lock Stats", "[STATS]".bright_cyan());
+                    print_network_stats(&stats);
+                }
+            }
+            StatsAction::Export { format, out } => {
+                let history = dashboard::LockHistory::load()?;
+                let data = history.export(format)?;
+                match out {
+                    Some(path) => {
+                        fs::write(&path, data).with_context(|| format!("Failed to write {}", path.display()))?;
+                        eprintln!("{} Exported {} entries to {}", "[✓]".bright_green(), history.entries.len(), path.display());
+                    }
+                    None => print!("{}", data),
+                }
+            }
+        },
+
+        Commands::Monitor { daemon, vaults, webhooks, interval, helius, public_url, helius_listen } => {
+            print_command_header("Vault Monitor", "[MONITOR]".bright_cyan());
+
+            let config = load_config()?;
+            let monitored = select_vaults(&config, vaults)?
+                .into_iter()
+                .map(|vault| vault_target(vault, cli.rpc_url.as_deref(), cli.program_id.as_deref()))
+                .collect::<Result<Vec<_>>>()?;
+
+            let mut urls = config.webhooks.clone();
+            for url in webhooks {
+                if !urls.contains(&url) {
+                    urls.push(url);
+                }
+            }
+            let interval = scheduler::parse_duration(&interval)?.to_std().context("Invalid --interval")?;
+
+            let helius = match public_url.filter(|_| helius) {
+                Some(public_url) => {
+                    let api_key = std::env::var(monitor::helius::API_KEY_ENV)
+                        .ok()
+                        .filter(|key| !key.is_empty())
+                        .ok_or_else(|| ExitKind::Config.error(format!("--helius needs an API key in ${}", monitor::helius::API_KEY_ENV)))?;
+                    // One webhook covers one cluster
+                    let devnet = monitored[0].rpc_url.contains("devnet");
+                    if monitored.iter().any(|vault| vault.rpc_url.contains("devnet") != devnet) {
+                        return Err(ExitKind::Usage.error("--helius can't watch devnet and mainnet vaults at once; pick them with --vault"));
+                    }
+                    Some(monitor::helius::HeliusOptions { api_key, public_url, listen: helius_listen, devnet })
+                }
+                None => None,
+            };
+
+            cmd_monitor(&monitored, &Webhooks::new(urls).exit_kind(ExitKind::Usage)?, interval, daemon, helius).await?;
+        }
+
+        Commands::Signer { listen, socket, keypair, sphincs_privkey, no_pq } => {
+            print_command_header("Remote Signer", "[SIGNER]".bright_cyan());
+
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            cmd_signer(listen, socket, &keypair_path, sphincs_privkey, no_pq, Pubkey::from_str(&program_id_str)?).await?;
+        }
+
+        Commands::Serve { listen, enable_actions, vaults } => {
+            print_command_header("API Server", "[SERVE]".bright_cyan());
+
+            let config = load_config()?;
+            let served = select_vaults(&config, vaults)?
+                .into_iter()
+                .map(|vault| {
+                    Ok(server::ServedVault {
+                        target: vault_target(vault, cli.rpc_url.as_deref(), cli.program_id.as_deref())?,
+                        profile: vault.clone(),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let options = server::ServerOptions {
+                listen,
+                enable_actions,
+                token: std::env::var(server::TOKEN_ENV).ok().filter(|token| !token.is_empty()),
+            };
+            server::serve(served, config.active_vault.clone(), options).await?;
+        }
+
+        Commands::Dashboard { keypair } => {
+            // Don't print banner for dashboard - it takes over the screen
+
+            let program_id = Pubkey::from_str(&program_id_str)?;
+
+            // Auto-detect keypair and wallet
+            let keypair_path = keypair.map_or_else(get_default_keypair_path, Ok)?;
+            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+
+            let kp_pathbuf = PathBuf::from(kp_path);
+
+            // Get SPHINCS key paths from active vault
+            let config = load_config()?;
+            theme::set_theme(theme::Theme::load(config.theme.as_deref()).exit_kind(ExitKind::Config)?);
+            let keymap = dashboard::Keymap::from_config(&config.keybindings).exit_kind(ExitKind::Config)?;
+            let (sphincs_public_key_path, sphincs_private_key_path) = if let Some(vault) = config.get_active_vault() {
+                (vault.sphincs_public_key_path.clone(), vault.sphincs_private_key_path.clone())
+            } else {
+                // Fall back to default paths
+                let qdum_dir = paths::data_dir();
+                (
+                    qdum_dir.join("sphincs_public.key").to_str().unwrap().to_string(),
+                    qdum_dir.join("sphincs_private.key").to_str().unwrap().to_string(),
+                )
+            };
+
+            // The active vault's pqQDUM mint, else the devnet mint (Token-2022 with transfer hooks)
+            let mint = Pubkey::from_str(active_vault.and_then(|vault| vault.mint.as_deref()).unwrap_or(dashboard::DEVNET_PQ_MINT))?;
+
+            let mut dashboard = Dashboard::new(
+                wallet_pubkey,
+                kp_pathbuf,
+                sphincs_public_key_path,
+                sphincs_private_key_path,
+                rpc_url.clone(),
+                program_id,
+                mint,
+                keymap,
+                config.dashboard.clone(),
+            )?;
+
+            // Show splash screen before dashboard
+            show_splash_screen()?;
+
+            dashboard.run()?;
+        }
+
+    }
+
+    Ok(())
+}
+
+/// Generate SPHINCS+ keys and, unless `existing_keypair` names a wallet to
+/// reuse, a new Solana keypair. With `seeds`, both are derived from them; with
+/// `vanity`, the wallet's address starts with that prefix.
+async fn cmd_init(
+    output_dir: Option<String>,
+    use_mnemonic: bool,
+    algorithm: Algorithm,
+    existing_keypair: Option<String>,
+    seeds: Option<crypto::seed::DerivedSeeds>,
+    vanity: Option<String>,
+) -> Result<()> {
+    use solana_sdk::signature::{Keypair, Signer};
+
+    // Check a reused wallet before any keys are written
+    let existing_wallet = match existing_keypair {
+        Some(path) => {
+            let wallet = read_keypair(&path)?.pubkey();
+            let path = fs::canonicalize(&path).with_context(|| format!("Failed to resolve keypair path: {}", path))?;
+            Some((path, wallet))
+        }
+        None => None,
+    };
+
+    // Generate the recovery phrase up front so it is shown before anything else
+    let recovery_mnemonic = if use_mnemonic {
+        Some(crypto::mnemonic::generate_mnemonic()?)
+    } else {
+        None
+    };
+
+    // Spinner for SPHINCS+ key generation
+    let spinner = output::spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
+            .template("{spinner:.green} {msg}")
+            .unwrap()
+    );
+    spinner.enable_steady_tick(Duration::from_millis(80));
+    spinner.set_message(format!("Generating {} quantum-resistant keypair...", algorithm));
+
+    // Generate SPHINCS+ keys
+    let key_manager = SphincsKeyManager::new(output_dir.clone())?.with_algorithm(algorithm);
+    if let Some(ref m) = recovery_mnemonic {
+        key_manager.generate_and_save_keypair_from_seed(&crypto::mnemonic::sphincs_seed_from_mnemonic(m))?;
+    } else if let Some(ref seeds) = seeds {
+        key_manager.generate_and_save_keypair_from_seed(&seeds.sphincs)?;
+    } else {
+        key_manager.generate_and_save_keypair()?;
+    }
+
+    spinner.finish_with_message(format!("{} SPHINCS+ keypair generated", "[✓]".bright_green().bold()));
+
+    if let Some(ref m) = recovery_mnemonic {
+        print_mnemonic_backup(m);
+    }
+
+    let qdum_dir = if let Some(ref dir) = output_dir {
+        PathBuf::from(dir)
+    } else {
+        paths::data_dir()
+    };
+
+    let (keypair_path, wallet_address) = match existing_wallet {
+        Some((keypair_path, wallet_address)) => {
+            println!("{} Using existing Solana keypair {}", "[✓]".bright_green().bold(), keypair_path.display().to_string().dimmed());
+            (keypair_path, wallet_address)
+        }
+        None => {
+            // Spinner for Solana keypair
+            let spinner = output::spinner();
+            spinner.set_style(
+                ProgressStyle::default_spinner()
+                    .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
+                    .template("{spinner:.green} {msg}")
+                    .unwrap()
+            );
+            spinner.enable_steady_tick(Duration::from_millis(80));
+            spinner.set_message("Generating Solana wallet keypair...".to_string());
+
+            // Generate Solana keypair
+            let solana_keypair = match (&seeds, &vanity) {
+                (Some(seeds), _) => solana_sdk::signer::keypair::keypair_from_seed(&seeds.solana)
+                    .map_err(|e| anyhow::anyhow!("Failed to derive the Solana keypair: {}", e))?,
+                (None, Some(prefix)) => {
+                    let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+                    let expected = solana::vanity::expected_attempts(prefix);
+                    solana::vanity::grind(prefix, threads, |attempts| {
+                        spinner.set_message(format!(
+                            "Searching for an address starting with {} on {} threads: {} tried (~{:.0} expected)",
+                            prefix.bright_cyan(),
+                            threads,
+                            attempts,
+                            expected
+                        ));
+                    })?
+                }
+                (None, None) => Keypair::new(),
+            };
+            let wallet_address = solana_keypair.pubkey();
+
+            let keypair_path = qdum_dir.join("solana-keypair.json");
+            let keypair_bytes = solana_keypair.to_bytes();
+            let keypair_json = serde_json::to_string(&keypair_bytes.to_vec())?;
+            fs::write(&keypair_path, keypair_json)?;
+
+            spinner.finish_with_message(format!("{} Solana keypair created", "[✓]".bright_green().bold()));
+            (keypair_path, wallet_address)
+        }
+    };
+
+    // Summary table
+    println!();
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table
+        .set_header(vec![
+            "Component".bright_white().bold().to_string(),
+            "Location".bright_white().bold().to_string(),
+        ])
+        .add_row(vec![
+            "SPHINCS+ Private".dimmed().to_string(),
+            paths::display(&qdum_dir.join("sphincs_private.key")).bright_cyan().to_string(),
+        ])
+        .add_row(vec![
+            "SPHINCS+ Public".dimmed().to_string(),
+            paths::display(&qdum_dir.join("sphincs_public.key")).bright_cyan().to_string(),
+        ])
+        .add_row(vec![
+            "Solana Keypair".dimmed().to_string(),
+            keypair_path.display().to_string().bright_cyan().to_string(),
+        ]);
+
+    println!("{}", table);
+    println!();
+    println!("{} {}", "Wallet:".dimmed(), wallet_address.to_string().bright_green().bold());
+    println!();
+    if seeds.is_some() {
+        println!("{}", "⚠️  These keys were derived from your seed: anyone with the seed can recreate them.".yellow().bold());
+        println!("   Running init with the same seed and --algorithm restores this vault.");
+        println!();
+    }
+
+    // Ask if they want to set it as default using inquire
+    use inquire::Confirm;
+
+    let set_default = Confirm::new("Set this as your default keypair?")
+        .with_default(true)
+        .with_help_message("All commands will use this keypair automatically")
+        .prompt();
+
+    match set_default {
+        Ok(true) => {
+            let mut config = load_config()?;
+
+            let sphincs_public_path = qdum_dir.join("sphincs_public.key");
+            let sphincs_private_path = qdum_dir.join("sphincs_private.key");
+
+            // Create a default vault profile
+            let profile = VaultProfile::new(
+                "default".to_string(),
+                keypair_path.to_str().unwrap().to_string(),
+                sphincs_public_path.to_str().unwrap().to_string(),
+                sphincs_private_path.to_str().unwrap().to_string(),
+                wallet_address.to_string(),
+            );
+
+            // Create vault (will auto-activate if it's the first one)
+            if let Err(e) = config.create_vault("default".to_string(), profile) {
+                // If default already exists, just switch to it
+                if config.vaults.contains_key("default") {
+                    config.switch_vault("default")?;
+                } else {
+                    return Err(e);
+                }
+            }
+
+            println!();
+            println!("{} Default vault created and activated", "[✓]".bright_green().bold());
+            println!("{} {}", "  Vault:".dimmed(), "default".bright_cyan());
+            println!("{} {}", "  Path:".dimmed(), keypair_path.display().to_string().bright_cyan());
+        }
+        Ok(false) => {
+            println!();
+            println!("{} Skipped. Configure later with:", "[i]".bright_yellow());
+            println!("  {}", "qdum-vault vault create default".dimmed());
+        }
+        Err(_) => {
+            println!("{} Prompt cancelled", "[!]".yellow());
+        }
+    }
+
+    println!();
+    println!("{} {}", "Next:".bright_white().bold(), "qdum-vault register".bright_cyan());
+    println!();
+
+    Ok(())
+}
+
+fn print_mnemonic_backup(mnemonic: &bip39::Mnemonic) {
+    let words: Vec<&str> = mnemonic.words().collect();
 
+    println!();
+    println!("{}", "╔═══════════════════════════════════════════════════════════╗".bright_yellow());
+    println!("{}", "║            SPHINCS+ RECOVERY PHRASE (24 WORDS)            ║".bright_yellow().bold());
+    println!("{}", "╚═══════════════════════════════════════════════════════════╝".bright_yellow());
     println!();
 
-    // Animated startup sequence
-    print!("{}", "  [".dimmed());
-    for _ in 0..3 {
-        print!("{}", "█".bright_green());
-        io::stdout().flush().unwrap();
-        thread::sleep(Duration::from_millis(50));
+    for (row_index, chunk) in words.chunks(4).enumerate() {
+        let line: Vec<String> = chunk.iter().enumerate()
+            .map(|(i, word)| format!("{:>2}. {:<10}", row_index * 4 + i + 1, word))
+            .collect();
+        println!("  {}", line.join("  ").bright_white());
     }
-    println!("{} {}", "]".dimmed(), "Initializing...".dimmed());
-    thread::sleep(Duration::from_millis(100));
 
     println!();
+    println!("{}", "⚠️  Write these words down and store them offline.".yellow().bold());
+    println!("   They are the ONLY way to recover your quantum keys with:");
+    println!("     {}", "qdum-vault keys restore --mnemonic".bright_cyan());
+    println!("   Anyone with this phrase can unlock your vault.");
+    println!();
+}
 
-    // ASCII Art Logo - pqcash style
-    println!("{}", "  ╔══════════════════════════════════════════════════════════════════╗".bright_green().bold());
-    println!("{}", "  ║                                                                  ║".bright_green());
-    println!("{}", "  ║      ██████╗  ██████╗  ██████╗ █████╗ ███████╗██╗  ██╗          ║".bright_green().bold());
-    println!("{}", "  ║      ██╔══██╗██╔═══██╗██╔════╝██╔══██╗██╔════╝██║  ██║          ║".bright_green().bold());
-    println!("{}", "  ║      ██████╔╝██║   ██║██║     ███████║███████╗███████║          ║".bright_green().bold());
-    println!("{}", "  ║      ██╔═══╝ ██║▄▄ ██║██║     ██╔══██║╚════██║██╔══██║          ║".bright_green().bold());
-    println!("{}", "  ║      ██║     ╚██████╔╝╚██████╗██║  ██║███████║██║  ██║          ║".bright_green().bold());
-    println!("{}", "  ║      ╚═╝      ╚══▀▀═╝  ╚═════╝╚═╝  ╚═╝╚══════╝╚═╝  ╚═╝          ║".bright_green().bold());
-    println!("{}", "  ║                                                                  ║".bright_green());
-    println!("  ║              {}                          ║", "P O S T - Q U A N T U M   C A S H".bright_white().bold());
-    println!("  ║          {}          ║", "Quantum-Resistant Digital Currency".bright_cyan());
-    println!("{}", "  ║                                                                  ║".bright_green());
-    println!("{}", "  ╠══════════════════════════════════════════════════════════════════╣".bright_green().bold());
-    println!("{}", "  ║                                                                  ║".bright_green());
+/// Write a printable backup of the active vault's keys (or its recovery phrase)
+fn cmd_keys_backup_paper(mnemonic: bool, out: Option<PathBuf>, force: bool) -> Result<()> {
+    let config = load_config()?;
+    let vault = config.get_active_vault();
+    let name = vault.map_or("default", |vault| vault.name.as_str());
+    let key_manager = SphincsKeyManager::new(None)?;
+    let public_path = vault.map_or_else(|| key_manager.key_dir().join("sphincs_public.key"), |vault| PathBuf::from(&vault.sphincs_public_key_path));
+    let private_path = vault.map_or_else(|| key_manager.key_dir().join("sphincs_private.key"), |vault| PathBuf::from(&vault.sphincs_private_key_path));
+
+    // Loading checks both files before they go on paper
+    let public_key = key_manager.load_public_key(Some(public_path.display().to_string()))?;
+    key_manager.load_private_key(Some(private_path.display().to_string()))?;
+    let wallet = match vault {
+        Some(vault) => vault.wallet()?,
+        None => load_keypair_and_extract_wallet(&get_default_keypair_path()?)?.1,
+    };
 
-    // Quick stats with icons
-    println!("  ║  {}  {}                   ║", "🔐".to_string(), format!("{:<56}", "SPHINCS+ (NIST FIPS 205) - Quantum Resistant".bright_white()));
-    println!("  ║  {}  {}                   ║", "🌐".to_string(), format!("{:<56}", "Solana Devnet - On-Chain Verification".bright_white()));
-    println!("  ║  {}  {}                   ║", "📦".to_string(), format!("{:<56}", format!("Version {} - Production Ready", env!("CARGO_PKG_VERSION")).bright_white()));
+    let out = out.unwrap_or_else(|| PathBuf::from(format!("paper-backup-{}.txt", name)));
+    if out.exists() && !force {
+        return Err(anyhow::anyhow!("{} already exists (use --force to overwrite)", out.display()));
+    }
 
-    println!("{}", "  ║                                                                  ║".bright_green());
-    println!("{}", "  ╚══════════════════════════════════════════════════════════════════╝".bright_green().bold());
+    let wallet = wallet.to_string();
+    let created = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
+    let sheet = paper::Sheet { vault: name, wallet: &wallet, algorithm: public_key.algorithm.cli_name(), created: &created };
+    let text = if mnemonic {
+        let phrase = inquire::Password::new("Recovery phrase (24 words):")
+            .without_confirmation()
+            .with_display_mode(inquire::PasswordDisplayMode::Masked)
+            .prompt()
+            .context("Prompt cancelled")?;
+        let parsed = crypto::mnemonic::parse_mnemonic(&phrase)?;
+        // A phrase for another key would only show up when it's needed to restore
+        let seed = crypto::mnemonic::sphincs_seed_from_mnemonic(&parsed);
+        let derived = SphincsKeyManager::new(None)?.with_algorithm(public_key.algorithm).public_key_from_seed(&seed)?;
+        if derived != public_key.bytes {
+            return Err(ExitKind::Usage.error(format!(
+                "That recovery phrase doesn't derive the {} vault's {} public key",
+                name, public_key.algorithm
+            )));
+        }
+        paper::render_mnemonic(&sheet, &parsed.to_string())?
+    } else {
+        let keys = paper::KeyFiles { public: fs::read(&public_path)?, private: fs::read(&private_path)? };
+        paper::render(&sheet, &keys)?
+    };
+
+    // Created owner-only, never written first and restricted after; an
+    // overwritten file is replaced rather than keeping its old permissions
+    if force && out.exists() {
+        fs::remove_file(&out).with_context(|| format!("Failed to replace {}", out.display()))?;
+    }
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&out).with_context(|| format!("Failed to create {}", out.display()))?;
+    std::io::Write::write_all(&mut file, text.as_bytes()).with_context(|| format!("Failed to write {}", out.display()))?;
+
+    println!("{} Paper backup written to {}", "[✓]".bright_green().bold(), out.display().to_string().bright_cyan());
+    println!("{} Print it in a monospace font, then delete the file: it holds your private key", "[!]".yellow());
+    if mnemonic {
+        println!("{} Restore with: {}", "[i]".bright_blue(), "qdum-vault keys restore --mnemonic".bright_cyan());
+    } else {
+        println!("{} Restore with: {}", "[i]".bright_blue(), "qdum-vault keys restore --scan".bright_cyan());
+    }
     println!();
+    Ok(())
+}
 
-    // Quick start guide
-    let mut guide_table = Table::new();
-    guide_table.load_preset(comfy_table::presets::UTF8_FULL);
-    guide_table.set_header(vec![
-        "Step".bright_white().bold().to_string(),
-        "Command".bright_cyan().to_string(),
-        "Description".dimmed().to_string()
+async fn cmd_keys_restore(
+    rpc_url: &str,
+    program_id: Pubkey,
+    mnemonic: bool,
+    scan: bool,
+    output_dir: Option<String>,
+    force: bool,
+    algorithm: Option<Algorithm>,
+) -> Result<()> {
+    if !mnemonic && !scan {
+        return Err(anyhow::anyhow!("Specify a restore source, e.g. --mnemonic or --scan"));
+    }
+
+    // Default to the active vault's key directory
+    let key_dir = if let Some(dir) = output_dir {
+        PathBuf::from(dir)
+    } else if let Some(vault) = load_config()?.get_active_vault() {
+        PathBuf::from(&vault.sphincs_public_key_path)
+            .parent()
+            .map(|p| p.to_path_buf())
+            .ok_or_else(|| anyhow::anyhow!("Invalid SPHINCS+ public key path"))?
+    } else {
+        paths::data_dir()
+    };
+
+    let existing = key_dir.join("sphincs_private.key");
+    if existing.exists() && !force {
+        return Err(anyhow::anyhow!(
+            "Keys already exist at {} (use --force to overwrite)",
+            key_dir.display()
+        ));
+    }
+
+    if scan {
+        return restore_paper_backup(&key_dir);
+    }
+
+    let phrase = inquire::Password::new("Recovery phrase (24 words):")
+        .without_confirmation()
+        .with_display_mode(inquire::PasswordDisplayMode::Masked)
+        .prompt()
+        .context("Prompt cancelled")?;
+
+    let parsed = crypto::mnemonic::parse_mnemonic(&phrase)?;
+    let seed = crypto::mnemonic::sphincs_seed_from_mnemonic(&parsed);
+
+    // The phrase alone doesn't say which algorithm it was for, and a key for
+    // the wrong one (or the wrong phrase) can't unlock the vault
+    let wallet = match load_config()?.get_active_vault() {
+        Some(vault) => vault.wallet()?,
+        None => load_keypair_and_extract_wallet(&get_default_keypair_path()?)?.1,
+    };
+    let client = VaultClient::builder().rpc_url(rpc_url).program_id(program_id).build()?;
+    let registered = match client.registered_public_key(wallet).await {
+        Ok(registered) => registered,
+        Err(e) if algorithm.is_some() => {
+            println!("{} Couldn't fetch the key registered for {} ({:#}); restoring unchecked", "[!]".yellow(), wallet, e);
+            None
+        }
+        Err(e) => return Err(e).exit_kind(ExitKind::Rpc),
+    };
+    let algorithm = match (&registered, algorithm) {
+        (_, Some(algorithm)) => algorithm,
+        (Some((id, _)), None) => Algorithm::from_algorithm_id(*id)
+            .ok_or_else(|| anyhow::anyhow!("Unknown algorithm {} registered for {}", id, wallet))?,
+        (None, None) => {
+            return Err(ExitKind::Usage.error(format!(
+                "{} has no PQ key registered to check against; pass --algorithm with the one the keys were generated with",
+                wallet
+            )));
+        }
+    };
+
+    let key_manager = SphincsKeyManager::new(Some(key_dir.to_str().unwrap().to_string()))?.with_algorithm(algorithm);
+    if let Some((id, public_key)) = &registered {
+        if *id != algorithm.algorithm_id() || key_manager.public_key_from_seed(&seed)? != *public_key {
+            return Err(ExitKind::Usage.error(format!(
+                "The phrase doesn't derive the {} key registered for {}; check the phrase and --algorithm",
+                algorithm, wallet
+            )));
+        }
+    }
+    key_manager.generate_and_save_keypair_from_seed(&seed)?;
+
+    println!("{} SPHINCS+ keys restored to {}", "[✓]".bright_green().bold(), key_dir.display().to_string().bright_cyan());
+    if registered.is_some() {
+        println!("{} Matches the key registered for {}", "[✓]".bright_green().bold(), wallet);
+    } else {
+        println!("{} Not checked against an on-chain registration", "[!]".yellow());
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Collect a paper backup's parts from the scanner (or keyboard) and write
+/// the key files to `key_dir`
+fn restore_paper_backup(key_dir: &Path) -> Result<()> {
+    println!("{} Scan each part's QR code, or type its text, and press Enter. Parts can come in any order.", "[i]".bright_blue());
+    println!();
+
+    let mut assembler = paper::Assembler::new();
+    while !assembler.is_complete() {
+        let prompt = match assembler.missing().as_slice() {
+            [] => "Part:".to_string(),
+            missing => format!("Part ({} still needed):", missing.iter().map(|part| part.to_string()).collect::<Vec<_>>().join(", ")),
+        };
+        let input = inquire::Password::new(&prompt)
+            .without_confirmation()
+            .with_display_mode(inquire::PasswordDisplayMode::Masked)
+            .prompt()
+            .context("Prompt cancelled")?;
+        match assembler.add(&input) {
+            Ok(part) => println!("{} Part {} accepted", "[✓]".bright_green(), part),
+            Err(e) => println!("{} {:#}", "[!]".yellow(), e),
+        }
+    }
+    let keys = assembler.finish()?;
+
+    fs::create_dir_all(key_dir).context("Failed to create key directory")?;
+    let public_path = key_dir.join("sphincs_public.key");
+    let private_path = key_dir.join("sphincs_private.key");
+    fs::write(&public_path, &keys.public).context("Failed to write public key")?;
+    fs::write(&private_path, &keys.private).context("Failed to write private key")?;
+
+    // The key file headers and sizes are checked the same way every command loads them
+    let key_manager = SphincsKeyManager::new(Some(key_dir.display().to_string()))?;
+    let public_key = key_manager.load_public_key(None)?;
+    key_manager.load_private_key(None)?;
+
+    println!();
+    println!("{} {} keys restored to {}", "[✓]".bright_green().bold(), public_key.algorithm, key_dir.display().to_string().bright_cyan());
+    println!("{} Check the public key matches your on-chain registration:", "[i]".bright_blue());
+    println!("  {}", "qdum-vault status".bright_cyan());
+    println!();
+    Ok(())
+}
+
+/// Read the payload for `sign`/`verify` from --file or --message
+fn read_sign_payload(file: &Option<String>, message: &Option<String>) -> Result<Vec<u8>> {
+    match (file, message) {
+        (Some(path), _) => fs::read(path).with_context(|| format!("Failed to read {}", path)),
+        (None, Some(text)) => Ok(text.as_bytes().to_vec()),
+        (None, None) => Err(anyhow::anyhow!("Specify --file or --message")),
+    }
+}
+
+fn cmd_sign(
+    file: Option<String>,
+    message: Option<String>,
+    sphincs_privkey_path: Option<String>,
+    sphincs_pubkey_path: Option<String>,
+    out: Option<String>,
+) -> Result<()> {
+    let payload = read_sign_payload(&file, &message)?;
+
+    // An explicit key pair comes as both files (clap requires them together),
+    // so a private key is never paired with the active vault's public key
+    let config = load_config()?;
+    let active = config.get_active_vault();
+    let priv_path = sphincs_privkey_path.or_else(|| active.map(|v| v.sphincs_private_key_path.clone()));
+    let pub_path = sphincs_pubkey_path.or_else(|| active.map(|v| v.sphincs_public_key_path.clone()));
+
+    let key_manager = SphincsKeyManager::new(None)?;
+    let private_key = key_manager.load_private_key(priv_path)?;
+    let public_key = key_manager.load_public_key(pub_path)?;
+
+    if private_key.algorithm != public_key.algorithm {
+        return Err(anyhow::anyhow!(
+            "Private key ({}) and public key ({}) use different algorithms",
+            private_key.algorithm,
+            public_key.algorithm
+        ));
+    }
+
+    let signature = key_manager.sign_message(&payload, &private_key)?;
+
+    // Catch a private key that doesn't belong to the public key we embed
+    if !SphincsKeyManager::verify_signature(&payload, &signature, &public_key)? {
+        return Err(anyhow::anyhow!("Signature does not verify against the public key (mismatched key pair?)"));
+    }
+
+    let detached = DetachedSignature::new(&public_key, &payload, &signature);
+    let json = detached.to_json()?;
+    audit::record(
+        audit::Event::new("sign_file")
+            .signature(crypto::attestation::sha256_hex(&signature))
+            .detail("message_sha256", &detached.message_sha256)
+            .detail("pq_key_sha256", &detached.public_key_sha256),
+    );
+
+    let out_path = out.or_else(|| file.as_ref().map(|f| format!("{}.sig", f)));
+    match out_path {
+        Some(path) => {
+            fs::write(&path, json).with_context(|| format!("Failed to write {}", path))?;
+            println!("{} Signed with {}", "[✓]".bright_green().bold(), public_key.algorithm.to_string().bright_cyan());
+            println!("  {} {}", "Signature:".dimmed(), path.bright_white());
+            println!("  {} {}", "Key SHA-256:".dimmed(), detached.public_key_sha256.bright_white());
+            println!();
+            output::result(path);
+        }
+        None => output::emit(json),
+    }
+
+    Ok(())
+}
+
+fn cmd_audit_verify() -> Result<()> {
+    let log = audit::AuditLog::default();
+    let entries = log.verify().with_context(|| format!("Audit log {} failed verification", paths::display(&log.path())))?;
+
+    match entries.last() {
+        Some(newest) => {
+            println!("{} Audit log intact: {} entries", "[✓]".bright_green().bold(), entries.len());
+            println!("  {} {}", "Log:".dimmed(), paths::display(&log.path()).bright_white());
+            println!("  {} #{} {} at {}", "Newest:".dimmed(), newest.seq, newest.event.operation.bright_cyan(), newest.timestamp);
+            println!("  {} {}", "Head:".dimmed(), newest.hash);
+        }
+        None => println!("{} No signing operations recorded yet", "[*]".bright_cyan()),
+    }
+    Ok(())
+}
+
+fn cmd_verify(
+    file: Option<String>,
+    message: Option<String>,
+    signature_path: Option<String>,
+    sphincs_pubkey_path: Option<String>,
+) -> Result<()> {
+    let payload = read_sign_payload(&file, &message)?;
+
+    let signature_path = signature_path
+        .or_else(|| file.as_ref().map(|f| format!("{}.sig", f)))
+        .ok_or_else(|| anyhow::anyhow!("Specify --signature when verifying a --message"))?;
+    let detached = DetachedSignature::load(Path::new(&signature_path))?;
+
+    let pub_path = match sphincs_pubkey_path {
+        Some(path) => Some(path),
+        None => load_config()?.get_active_vault().map(|v| v.sphincs_public_key_path.clone()),
+    };
+    let key_manager = SphincsKeyManager::new(None)?;
+    let public_key = key_manager.load_public_key(pub_path)?;
+
+    let algorithm = detached.algorithm()?;
+    if algorithm != public_key.algorithm {
+        return Err(anyhow::anyhow!(
+            "Signature uses {} but the public key is {}",
+            algorithm,
+            public_key.algorithm
+        ));
+    }
+
+    let valid = SphincsKeyManager::verify_signature(&payload, &detached.signature_bytes()?, &public_key)?;
+
+    println!("  {} {}", "Algorithm:".dimmed(), algorithm.to_string().bright_white());
+    println!("  {} {}", "Signed at:".dimmed(), detached.created_at.bright_white());
+    println!("  {} {}", "Key SHA-256:".dimmed(), crypto::attestation::sha256_hex(&public_key.bytes).bright_white());
+    println!();
+
+    if !valid {
+        return Err(anyhow::anyhow!("Signature is NOT valid for this message and public key"));
+    }
+
+    println!("{} Signature is valid", "[✓]".bright_green().bold());
+    println!();
+
+    Ok(())
+}
+
+async fn cmd_register(
+    rpc_url: &str,
+    program_id: Pubkey,
+    wallet: Pubkey,
+    keypair_path: &str,
+    sphincs_pubkey_path: Option<String>,
+    expected_algorithm: Option<Algorithm>,
+    lock_mode: LockMode,
+) -> Result<Receipt> {
+    let key_manager = SphincsKeyManager::new(None)?;
+    let sphincs_pubkey = key_manager.load_public_key(sphincs_pubkey_path)?;
+
+    if let Some(expected) = expected_algorithm {
+        if sphincs_pubkey.algorithm != expected {
+            return Err(anyhow::anyhow!(
+                "Public key uses {}, not {} (generate matching keys with: qdum-vault init --algorithm {})",
+                sphincs_pubkey.algorithm,
+                expected,
+                expected.cli_name()
+            ));
+        }
+    }
+
+    output::detail(output::DEBUG, "Registering public key:", hex::encode(&sphincs_pubkey));
+
+    let client = VaultClient::builder().rpc_url(rpc_url).program_id(program_id).build()?;
+    let receipt = client.register_pq_account(wallet, keypair_path, &sphincs_pubkey, lock_mode).await?;
+
+    // Remember the lock mode so unlock knows what to provide
+    if receipt.note.is_none() {
+        let mut config = load_config()?;
+        if let Some(name) = config.find_by_keypair(keypair_path).map(|v| v.name.clone()) {
+            config.set_lock_mode(&name, lock_mode)?;
+        }
+    }
+
+    Ok(receipt)
+}
+
+/// Register each configured vault whose wallet has no PQ account on its
+/// cluster, one after another, and print what happened to each
+async fn cmd_register_all_unregistered(
+    config: &VaultConfig,
+    rpc_flag: Option<&str>,
+    program_flag: Option<&str>,
+    expected_algorithm: Option<Algorithm>,
+    lock_mode: LockMode,
+) -> Result<()> {
+    let vaults = select_vaults(config, Vec::new())?;
+
+    println!("{} Checking {} vault(s) for a PQ account...", "[*]".bright_cyan(), vaults.len());
+    let mut results = Vec::with_capacity(vaults.len());
+    let mut pending = Vec::new();
+    let mut failed = 0;
+    for vault in &vaults {
+        if vault.is_watch_only() {
+            results.push((vault.name.clone(), vault.short_wallet(), "skipped".dimmed().to_string(), "watch-only".to_string()));
+            continue;
+        }
+        let scanned = async {
+            let target = vault_target(vault, rpc_flag, program_flag)?;
+            let client = VaultClient::builder().rpc_url(&target.rpc_url).program_id(target.program_id).build()?;
+            let registered = client.pq_account_exists(target.wallet).await?;
+            Ok::<_, anyhow::Error>((target, registered))
+        }
+        .await;
+        match scanned {
+            Ok((_, true)) => {
+                results.push((vault.name.clone(), vault.short_wallet(), "unchanged".yellow().to_string(), "already registered".to_string()))
+            }
+            Ok((target, false)) => pending.push((*vault, target)),
+            Err(e) => {
+                failed += 1;
+                results.push((vault.name.clone(), vault.short_wallet(), "failed".bright_red().bold().to_string(), format!("{:#}", e)));
+            }
+        }
+    }
+    println!("{} {} of {} vault(s) need registering", "[i]".bright_blue(), pending.len(), vaults.len());
+    println!();
+
+    for (index, (vault, target)) in pending.iter().enumerate() {
+        println!("{} {} {}", format!("[{}/{}]", index + 1, pending.len()).dimmed(), vault.name.bright_cyan().bold(), target.wallet.to_string().dimmed());
+        let result = cmd_register(
+            &target.rpc_url,
+            target.program_id,
+            target.wallet,
+            &vault.solana_keypair_path,
+            Some(vault.sphincs_public_key_path.clone()),
+            expected_algorithm,
+            lock_mode,
+        )
+        .await;
+        println!();
+        results.push(match result {
+            Ok(receipt) => (
+                vault.name.clone(),
+                vault.short_wallet(),
+                "registered".bright_green().bold().to_string(),
+                receipt.note.or_else(|| receipt.signatures.last().cloned()).unwrap_or_default(),
+            ),
+            Err(e) => {
+                failed += 1;
+                (vault.name.clone(), vault.short_wallet(), "failed".bright_red().bold().to_string(), format!("{:#}", e))
+            }
+        });
+    }
+
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec![
+        "Vault".bright_white().bold().to_string(),
+        "Wallet".bright_white().bold().to_string(),
+        "Result".bright_white().bold().to_string(),
+        "Details".bright_white().bold().to_string(),
     ]);
+    for (name, wallet, outcome, details) in results {
+        table.add_row(vec![name.bright_cyan().to_string(), wallet, outcome, details]);
+    }
+    println!("{}", table);
+    println!();
 
-    guide_table
-        .add_row(vec![
-            "1".bright_yellow().to_string(),
-            "qdum-vault init".bright_green().to_string(),
-            "Generate quantum keypairs".to_string()
-        ])
-        .add_row(vec![
-            "2".bright_yellow().to_string(),
-            "qdum-vault register".bright_green().to_string(),
-            "Register on-chain".to_string()
-        ])
-        .add_row(vec![
-            "3".bright_yellow().to_string(),
-            "qdum-vault lock".bright_green().to_string(),
-            "Lock your vault".to_string()
-        ])
-        .add_row(vec![
-            "4".bright_yellow().to_string(),
-            "qdum-vault unlock".bright_green().to_string(),
-            "Unlock with quantum sig".to_string()
-        ]);
+    if failed > 0 {
+        anyhow::bail!("{} of {} vaults could not be registered", failed, vaults.len());
+    }
+    Ok(())
+}
 
-    println!("{}", guide_table);
+async fn cmd_lock(
+    rpc_url: &str,
+    program_id: Pubkey,
+    wallet: Pubkey,
+    keypair_path: &str,
+    unsigned_out: Option<&Path>,
+) -> Result<Receipt> {
+    let client = build_exporting_client(rpc_url, program_id, unsigned_out)?;
+    client.lock_vault(wallet, keypair_path).await
+}
+
+/// Lock every vault in `config` that can sign, carrying on past failures, and
+/// print what happened to each
+async fn cmd_lock_all_vaults(config: &VaultConfig, rpc_flag: Option<&str>, program_flag: Option<&str>) -> Result<()> {
+    let vaults = select_vaults(config, Vec::new())?;
+    let mut results = Vec::with_capacity(vaults.len());
+    let mut failed = 0;
+
+    for vault in &vaults {
+        println!("{} {}", "──".dimmed(), vault.name.bright_cyan().bold());
+        if vault.is_watch_only() {
+            println!("{} Watch-only (no keypair at {}), skipping", "[i]".bright_blue(), vault.solana_keypair_path.dimmed());
+            println!();
+            results.push((vault, "skipped".dimmed().to_string(), "watch-only".to_string()));
+            continue;
+        }
+
+        let outcome = async {
+            let target = vault_target(vault, rpc_flag, program_flag)?;
+            let client = VaultClient::builder().rpc_url(&target.rpc_url).program_id(target.program_id).build()?;
+            client.lock_vault(target.wallet, &vault.solana_keypair_path).await
+        }
+        .await;
+        println!();
+
+        results.push(match outcome {
+            Ok(receipt) => match (receipt.note, receipt.signatures.last()) {
+                (Some(note), _) => (vault, "unchanged".yellow().to_string(), note),
+                (None, Some(signature)) => (vault, "locked".bright_green().bold().to_string(), signature.clone()),
+                (None, None) => (vault, "locked".bright_green().bold().to_string(), String::new()),
+            },
+            Err(e) => {
+                failed += 1;
+                (vault, "failed".bright_red().bold().to_string(), format!("{:#}", e))
+            }
+        });
+    }
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec![
+        "Vault".bright_white().bold().to_string(),
+        "Wallet".bright_white().bold().to_string(),
+        "Result".bright_white().bold().to_string(),
+        "Details".bright_white().bold().to_string(),
+    ]);
+    for (vault, result, details) in &results {
+        table.add_row(vec![vault.name.bright_cyan().to_string(), vault.short_wallet(), result.clone(), details.clone()]);
+    }
+    println!("{}", table);
     println!();
-    println!("  {} Type {} for all available commands",
-        "💡".to_string(),
-        "qdum-vault --help".bright_cyan().bold());
+
+    if failed > 0 {
+        anyhow::bail!("{} of {} vaults could not be locked", failed, results.len());
+    }
+    Ok(())
+}
+
+/// Client for transfer, lock and close, exporting unsigned transactions to `unsigned_out` if set
+fn build_exporting_client(rpc_url: &str, program_id: Pubkey, unsigned_out: Option<&Path>) -> Result<VaultClient> {
+    let mut builder = VaultClient::builder().rpc_url(rpc_url).program_id(program_id);
+    if let Some(dir) = unsigned_out {
+        builder = builder.unsigned_out(dir);
+    }
+    builder.build()
+}
+
+fn cmd_schedule_lock(
+    rpc_url: &str,
+    program_id: &str,
+    wallet: Pubkey,
+    keypair_path: &str,
+    lock_at: chrono::DateTime<chrono::Utc>,
+) -> Result<()> {
+    if lock_at <= chrono::Utc::now() {
+        return Err(anyhow::anyhow!("Lock time {} is in the past", lock_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M")));
+    }
+
+    let mut schedule = Schedule::load()?;
+    schedule.add(ScheduledLock {
+        keypair_path: keypair_path.to_string(),
+        wallet: wallet.to_string(),
+        rpc_url: rpc_url.to_string(),
+        program_id: program_id.to_string(),
+        lock_at: lock_at.to_rfc3339(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        attempts: 0,
+    });
+    schedule.save()?;
+
+    println!("{} Lock scheduled for {}",
+        "[✓]".bright_green().bold(),
+        lock_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string().bright_cyan()
+    );
+    println!("{} The scheduler must be running to perform it: {}", "[i]".bright_blue(), "qdum-vault schedule".bright_cyan());
+    println!();
+
+    Ok(())
+}
+
+fn cmd_schedule_transfer(transfer: ScheduledTransfer, send_at: chrono::DateTime<chrono::Utc>) -> Result<()> {
+    if send_at <= chrono::Utc::now() {
+        return Err(anyhow::anyhow!("Transfer time {} is in the past", send_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M")));
+    }
+
+    if let Some(every) = &transfer.every {
+        println!("{} Repeats every {} until cancelled", "[i]".bright_blue(), every.bright_cyan());
+    }
+    let mut schedule = Schedule::load()?;
+    let id = schedule.add_transfer(transfer);
+    schedule.save()?;
+
+    println!("{} Transfer #{} scheduled for {}",
+        "[✓]".bright_green().bold(),
+        id,
+        send_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string().bright_cyan()
+    );
+    println!("{} Balance and vault lock state are checked when it's sent", "[i]".bright_blue());
+    println!("{} The scheduler must be running to perform it: {}", "[i]".bright_blue(), "qdum-vault schedule".bright_cyan());
     println!();
+
+    Ok(())
 }
 
-fn print_command_header(text: &str, icon: colored::ColoredString) {
-    println!();
-    println!("{}", "╔".bright_green().to_string() + &"═".repeat(68).bright_green().to_string() + &"╗".bright_green().to_string());
-    println!("║  {} {}  ║", icon, format!("{:<60}", text).bright_white().bold());
-    println!("{}", "╚".bright_green().to_string() + &"═".repeat(68).bright_green().to_string() + &"╝".bright_green().to_string());
+fn cmd_schedule_cancel(id: u64) -> Result<()> {
+    let mut schedule = Schedule::load()?;
+    let transfer = schedule
+        .cancel_transfer(id)
+        .ok_or_else(|| anyhow::anyhow!("No scheduled transfer #{} (see `qdum-vault schedule list`)", id))?;
+    schedule.save()?;
+
+    println!("{} Cancelled transfer #{} of {} tokens to {}",
+        "[✓]".bright_green().bold(),
+        id,
+        transfer.tokens(),
+        transfer.recipient
+    );
     println!();
+    Ok(())
 }
 
-fn load_config() -> VaultConfig {
-    VaultConfig::load().unwrap_or_else(|_| VaultConfig {
-        version: 1,
-        ..Default::default()
-    })
+/// Send a transfer from the schedule with the state the vault is in now
+async fn run_scheduled_transfer(transfer: &ScheduledTransfer) -> Result<Receipt> {
+    let wallet = Pubkey::from_str(&transfer.wallet)?;
+    let recipient = Pubkey::from_str(&transfer.recipient)?;
+    let mint = Pubkey::from_str(&transfer.mint)?;
+    let program_id = Pubkey::from_str(&transfer.program_id)?;
+    let client = VaultClient::builder().rpc_url(&transfer.rpc_url).program_id(program_id).build()?;
+
+    let balance = client.get_balance(wallet, mint).await?;
+    let decimals = client.mint_decimals(mint).await?;
+    if balance < transfer.amount {
+        return Err(ExitKind::InsufficientFunds.error(format!(
+            "Balance is {} tokens, the transfer needs {}",
+            units::to_tokens(balance, decimals),
+            units::to_tokens(transfer.amount, decimals)
+        )));
+    }
+    // The transfer itself refuses pqQDUM from a vault that has been locked since
+    cmd_transfer(&client, &transfer.keypair_path, recipient, mint, transfer.amount).await
 }
 
-fn get_default_keypair_path() -> String {
-    let config = load_config();
+async fn cmd_schedule(list: bool, clear: bool, interval: u64) -> Result<()> {
+    if clear {
+        let mut schedule = Schedule::load()?;
+        let (locks, transfers) = (schedule.locks.len(), schedule.transfers.len());
+        schedule.locks.clear();
+        schedule.transfers.clear();
+        schedule.save()?;
+        println!("{} Cancelled {} scheduled lock(s) and {} scheduled transfer(s)", "[✓]".bright_green().bold(), locks, transfers);
+        println!();
+        return Ok(());
+    }
+
+    if list {
+        let schedule = Schedule::load()?;
+        if schedule.locks.is_empty() && schedule.transfers.is_empty() {
+            println!("{} No scheduled locks or transfers", "[i]".bright_blue());
+            println!();
+            return Ok(());
+        }
 
-    // Try to use active vault's keypair path
-    if let Some(vault) = config.get_active_vault() {
-        return vault.solana_keypair_path.clone();
+        if !schedule.locks.is_empty() {
+            let mut table = Table::new();
+            table.load_preset(UTF8_FULL);
+            table.set_header(vec!["Wallet", "Lock At", "Network"]);
+            for lock in &schedule.locks {
+                let lock_at = lock.lock_time()
+                    .map(|at| at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_else(|_| lock.lock_at.clone());
+                table.add_row(vec![lock.wallet.clone(), lock_at, lock.rpc_url.clone()]);
+            }
+            println!("{}", table);
+            println!();
+        }
+
+        if !schedule.transfers.is_empty() {
+            let mut table = Table::new();
+            table.load_preset(UTF8_FULL);
+            table.set_header(vec!["ID", "From", "To", "Amount", "Next Send", "Every", "Network"]);
+            for transfer in &schedule.transfers {
+                let send_at = transfer.send_time()
+                    .map(|at| at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_else(|_| transfer.send_at.clone());
+                table.add_row(vec![
+                    format!("#{}", transfer.id),
+                    transfer.wallet.clone(),
+                    transfer.recipient.clone(),
+                    transfer.tokens().to_string(),
+                    send_at,
+                    transfer.every.clone().unwrap_or_else(|| "-".to_string()),
+                    transfer.rpc_url.clone(),
+                ]);
+            }
+            println!("{}", table);
+            println!();
+        }
+        return Ok(());
     }
 
-    // Fallback to default Solana path
-    let home = dirs::home_dir().expect("Could not determine home directory");
-    home.join(".config/solana/id.json")
-        .to_str()
-        .expect("Invalid path")
-        .to_string()
-}
+    println!("{} Checking for scheduled locks and transfers every {}s (Ctrl+C to stop)", "[i]".bright_blue(), interval);
+    println!();
 
-fn load_keypair_and_extract_wallet(keypair_path: &str) -> Result<(String, Pubkey)> {
-    use solana_sdk::signature::Signer;
+    loop {
+        let mut schedule = Schedule::load()?;
+        let now = chrono::Utc::now();
+        let due = schedule.take_due(now);
+        let due_transfers = schedule.take_due_transfers(now);
+        let invalid_transfers = schedule.take_invalid_transfers();
 
-    let data = fs::read_to_string(keypair_path)
-        .context(format!("Failed to read keypair file: {}", keypair_path))?;
+        // Taken off the schedule before running, so a failure isn't retried every interval
+        if !due.is_empty() || !due_transfers.is_empty() || !invalid_transfers.is_empty() {
+            schedule.save()?;
+        }
 
-    let bytes: Vec<u8> = serde_json::from_str(&data)
-        .context("Invalid keypair JSON format")?;
+        for transfer in invalid_transfers {
+            println!("{} Dropped scheduled transfer #{} to {}: invalid send time '{}', it was not sent",
+                "[✗]".bright_red().bold(),
+                transfer.id,
+                transfer.recipient,
+                transfer.send_at
+            );
+            println!();
+        }
 
-    let keypair = Keypair::try_from(&bytes[..])
-        .context("Invalid keypair bytes")?;
+        let mut failed_locks = Vec::new();
+        for lock in due {
+            println!("{} Locking {}", "[LOCK]".bright_red().bold(), lock.wallet.bright_white());
 
-    let wallet_pubkey = keypair.pubkey();
+            let result = async {
+                let wallet = Pubkey::from_str(&lock.wallet)?;
+                let program_id = Pubkey::from_str(&lock.program_id)?;
+                cmd_lock(&lock.rpc_url, program_id, wallet, &lock.keypair_path, None).await
+            }.await;
 
-    Ok((keypair_path.to_string(), wallet_pubkey))
+            match result {
+                Ok(receipt) => receipt.print(),
+                Err(e) => {
+                    println!("{} Scheduled lock for {} failed: {}", "[✗]".bright_red().bold(), lock.wallet, e);
+                    match lock.retry(chrono::Utc::now()) {
+                        Some(retry) => {
+                            if let Ok(at) = retry.lock_time() {
+                                println!("{} Retrying at {}", "[i]".bright_blue(), at.with_timezone(&chrono::Local).format("%H:%M:%S"));
+                            }
+                            failed_locks.push(retry);
+                        }
+                        None => println!("{} Giving up after {} attempts", "[!]".bright_yellow(), scheduler::MAX_LOCK_ATTEMPTS),
+                    }
+                }
+            }
+            println!();
+        }
+
+        // Reloaded in case the schedule was changed while the locks ran
+        if !failed_locks.is_empty() {
+            let mut schedule = Schedule::load()?;
+            for lock in failed_locks {
+                schedule.requeue(lock);
+            }
+            schedule.save()?;
+        }
+
+        for transfer in due_transfers {
+            println!("{} Sending {} tokens from {} to {}",
+                "[TRANSFER]".bright_yellow().bold(),
+                transfer.tokens(),
+                transfer.wallet.bright_white(),
+                transfer.recipient.bright_white()
+            );
+
+            let result = run_scheduled_transfer(&transfer).await;
+            notify::finished(Operation::Transfer, &result).await;
+            match result {
+                Ok(receipt) => receipt.print(),
+                Err(e) => println!("{} Scheduled transfer to {} failed: {:#}", "[✗]".bright_red().bold(), transfer.recipient, e),
+            }
+            println!();
+        }
+
+        tokio::time::sleep(Duration::from_secs(interval.max(1))).await;
+    }
 }
 
-fn show_splash_screen() -> Result<()> {
-    use ratatui::{
-        backend::CrosstermBackend,
-        Terminal,
-        layout::{Alignment, Constraint, Direction, Layout},
-        style::{Color, Modifier, Style},
-        text::{Line, Span},
-        widgets::{Block, Paragraph},
+/// Where `cmd_unlock` gets the PQ signature from
+enum UnlockKey {
+    /// SPHINCS+ private key file (defaults to the active vault's)
+    PrivateKey(Option<String>),
+    /// Signature file written by `unlock sign`
+    Presigned(PathBuf),
+    /// Signing service from --remote-signer, which also supplies the public key
+    Remote(Arc<RemoteSigner>),
+}
+
+async fn cmd_unlock(
+    rpc_url: &str,
+    program_id: Pubkey,
+    wallet: Pubkey,
+    keypair_path: &str,
+    key: UnlockKey,
+    delay_slots: u64,
+    jito: Option<JitoConfig>,
+) -> Result<Receipt> {
+    // Load config to get active vault's SPHINCS key paths
+    let config = load_config()?;
+
+    // Determine SPHINCS public key path from active vault
+    let sphincs_pub_path = if let Some(vault) = config.get_active_vault() {
+        output::detail(output::VERBOSE, "Active vault:", &vault.name);
+        output::detail(output::VERBOSE, "Public key path:", &vault.sphincs_public_key_path);
+        output::detail(output::VERBOSE, "Private key path:", &vault.sphincs_private_key_path);
+        Some(vault.sphincs_public_key_path.clone())
+    } else {
+        None
     };
-    use crossterm::{
-        execute,
-        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+
+    // Load public key
+    let key_manager = SphincsKeyManager::new(None)?;
+    let sphincs_pubkey = match &key {
+        UnlockKey::Remote(remote) => remote.pq_public_key()?,
+        _ => key_manager.load_public_key(sphincs_pub_path)?,
     };
-    use std::io::stdout;
 
-    // Setup terminal
-    enable_raw_mode()?;
-    let mut stdout = stdout();
-    execute!(stdout, EnterAlternateScreen)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    output::detail(output::DEBUG, "Loaded public key:", hex::encode(&sphincs_pubkey));
 
-    terminal.clear()?;
+    let options = UnlockOptions {
+        lock_mode: config.lock_mode_for_keypair(keypair_path),
+        delay_slots,
+        resume_after: 0,
+    };
 
-    // Glitch characters for animation
-    let glitch_chars = vec!["█", "▓", "▒", "░", "▀", "▄", "▌", "▐", "■", "□"];
+    if delay_slots > 0 {
+        println!("{} {} slots ({})", "Unlock delay:".bold(), delay_slots.to_string().bright_yellow(), solana::client::format_slots(delay_slots));
+        println!();
+    }
 
-    // Animate splash screen for 4 seconds
-    let start = std::time::Instant::now();
-    let duration = std::time::Duration::from_secs(4);
+    let mut builder = VaultClient::builder().rpc_url(rpc_url).program_id(program_id);
+    if let Some(jito) = jito {
+        println!("{} {} lamports per bundle via {}", "Jito tip:".bold(), jito.tip_lamports.to_string().bright_yellow(), jito.block_engine_url.dimmed());
+        println!();
+        builder = builder.jito(jito);
+    }
 
-    while start.elapsed() < duration {
-        // Render splash screen with animated glitch
-        terminal.draw(|f| {
-            let size = f.area();
+    let client = builder.build()?;
+    match key {
+        UnlockKey::PrivateKey(sphincs_privkey_path) => {
+            // Explicit path from CLI, else the active vault's, else the data directory
+            let sphincs_priv_path = sphincs_privkey_path.or_else(|| config.get_active_vault().map(|vault| vault.sphincs_private_key_path.clone()));
+            let sphincs_privkey = key_manager.load_private_key(sphincs_priv_path)?;
+            client.unlock_vault(wallet, keypair_path, UnlockSigner::PrivateKey(&sphincs_privkey), &sphincs_pubkey, options, None).await
+        }
+        UnlockKey::Presigned(path) => {
+            let signature = load_unlock_signature(&client, wallet, &path, &sphincs_pubkey).await?;
+            client.unlock_vault(wallet, keypair_path, UnlockSigner::Presigned(signature), &sphincs_pubkey, options, None).await
+        }
+        UnlockKey::Remote(remote) => {
+            client.unlock_vault(wallet, keypair_path, UnlockSigner::Remote(&remote), &sphincs_pubkey, options, None).await
+        }
+    }
+}
 
-            // Center the content
-            let chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([
-                    Constraint::Percentage(35),
-                    Constraint::Min(10),
-                    Constraint::Percentage(35),
-                ])
-                .split(size);
+/// Unlock several vaults at once, each with the keys in its own profile. The
+/// flows share nothing on-chain, so they run concurrently; their progress is
+/// printed interleaved, prefixed with the vault name.
+async fn cmd_unlock_vaults(
+    config: &VaultConfig,
+    names: Vec<String>,
+    rpc_flag: Option<&str>,
+    program_flag: Option<&str>,
+    delay_slots: u64,
+) -> Result<()> {
+    let vaults = select_vaults(config, names)?;
+    if let Some(vault) = vaults.iter().find(|vault| vault.is_watch_only()) {
+        return Err(ExitKind::Usage.error(format!("Vault '{}' is watch-only: there is no keypair to unlock it with", vault.name)));
+    }
+    if delay_slots > 0 {
+        println!("{} {} slots ({})", "Unlock delay:".bold(), delay_slots.to_string().bright_yellow(), solana::client::format_slots(delay_slots));
+        println!();
+    }
 
-            // Generate random glitch pattern
-            use std::time::{SystemTime, UNIX_EPOCH};
-            let seed = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as usize;
+    // Keys are loaded (and any passphrase asked for) before the flows start
+    // and their output is hidden
+    let key_manager = SphincsKeyManager::new(None)?;
+    let mut prepared = Vec::with_capacity(vaults.len());
+    for vault in &vaults {
+        let target = vault_target(vault, rpc_flag, program_flag)?;
+        let client = VaultClient::builder().rpc_url(&target.rpc_url).program_id(target.program_id).build()?;
+        let sphincs_pubkey = key_manager.load_public_key(Some(vault.sphincs_public_key_path.clone()))?;
+        let sphincs_privkey = key_manager.load_private_key(Some(vault.sphincs_private_key_path.clone()))?;
+        println!("{} {} {}", "[*]".bright_cyan(), vault.name.bright_cyan().bold(), target.wallet.to_string().dimmed());
+        prepared.push((target.wallet, client, sphincs_pubkey, sphincs_privkey, vault.solana_keypair_path.clone(), vault.lock_mode));
+    }
+    println!();
 
-            let glitch_top = format!("{}{}{}{}",
-                glitch_chars[seed % glitch_chars.len()],
-                glitch_chars[(seed + 1) % glitch_chars.len()],
-                glitch_chars[(seed + 2) % glitch_chars.len()],
-                glitch_chars[(seed + 3) % glitch_chars.len()],
-            );
+    let show_progress = !output::quiet();
+    let width = vaults.iter().map(|vault| vault.name.len()).max().unwrap_or(0);
 
-            let glitch_mid = format!(" {}{}{}{}{} ",
-                glitch_chars[(seed + 4) % glitch_chars.len()],
-                glitch_chars[(seed + 5) % glitch_chars.len()],
-                glitch_chars[(seed + 6) % glitch_chars.len()],
-                glitch_chars[(seed + 7) % glitch_chars.len()],
-                glitch_chars[(seed + 8) % glitch_chars.len()],
-            );
+    // Each flow prints as if it ran alone; only the prefixed progress lines
+    // (on stderr) are shown while they run
+    output::set_quiet(true);
+    let silenced = output::silence_stdout();
 
-            let glitch_bot = format!("{}{}{}",
-                glitch_chars[(seed + 9) % glitch_chars.len()],
-                glitch_chars[(seed + 10) % glitch_chars.len()],
-                glitch_chars[(seed + 11) % glitch_chars.len()],
-            );
+    let mut unlocks = tokio::task::JoinSet::new();
+    for (index, (wallet, client, sphincs_pubkey, sphincs_privkey, keypair_path, lock_mode)) in prepared.into_iter().enumerate() {
+        let label = format!("{:<width$}", vaults[index].name, width = width);
+        let progress: solana::client::ProgressCallback = Box::new(move |step, total, message| {
+            if show_progress {
+                eprintln!("{} {} {}", label.bright_cyan(), format!("[{:>2}/{}]", step, total).dimmed(), message);
+            }
+        });
+        let options = UnlockOptions { lock_mode, delay_slots, resume_after: 0 };
+        unlocks.spawn(async move {
+            let signer = UnlockSigner::PrivateKey(&sphincs_privkey);
+            (index, client.unlock_vault(wallet, &keypair_path, signer, &sphincs_pubkey, options, Some(progress)).await)
+        });
+    }
 
-            // Normal text with glitch effect - white background theme
-            let title_lines = vec![
-                Line::from(""),
-                Line::from(""),
-                Line::from(""),
-                Line::from(""),
-                Line::from(""),
-                // Glitch effect - animated (darker colors for white background)
-                Line::from(vec![
-                    Span::styled(glitch_top.clone(), Style::default().fg(Color::Rgb(0, 150, 200))),
-                    Span::styled(glitch_mid.clone(), Style::default().fg(Color::Rgb(140, 140, 140))),
-                    Span::styled(glitch_bot.clone(), Style::default().fg(Color::Rgb(180, 0, 200))),
-                ]),
-                Line::from(""),
-                // Main text - dark purple theme on white
-                Line::from(vec![
-                    Span::styled("P", Style::default().fg(Color::Rgb(120, 60, 200)).add_modifier(Modifier::BOLD)),
-                    Span::styled("O", Style::default().fg(Color::Rgb(140, 80, 220)).add_modifier(Modifier::BOLD)),
-                    Span::styled("S", Style::default().fg(Color::Rgb(120, 60, 200)).add_modifier(Modifier::BOLD)),
-                    Span::styled("T", Style::default().fg(Color::Rgb(100, 50, 180)).add_modifier(Modifier::BOLD)),
-                    Span::styled("  ", Style::default()),
-                    Span::styled("Q", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                    Span::styled("U", Style::default().fg(Color::Rgb(160, 80, 220)).add_modifier(Modifier::BOLD)),
-                    Span::styled("A", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                    Span::styled("N", Style::default().fg(Color::Rgb(120, 50, 180)).add_modifier(Modifier::BOLD)),
-                    Span::styled("T", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                    Span::styled("U", Style::default().fg(Color::Rgb(120, 50, 180)).add_modifier(Modifier::BOLD)),
-                    Span::styled("M", Style::default().fg(Color::Rgb(140, 60, 200)).add_modifier(Modifier::BOLD)),
-                ]),
-                Line::from(""),
-                // Glitch effect bottom - animated (darker colors for white background)
-                Line::from(vec![
-                    Span::styled(glitch_bot, Style::default().fg(Color::Rgb(180, 0, 200))),
-                    Span::styled(glitch_mid, Style::default().fg(Color::Rgb(140, 140, 140))),
-                    Span::styled(glitch_top, Style::default().fg(Color::Rgb(0, 150, 200))),
-                ]),
-                Line::from(""),
-                Line::from(""),
-                Line::from(""),
-                Line::from(vec![
-                    Span::styled("SPHINCS+ SHA2-128s  •  NIST FIPS 205  •  Quantum-Resistant", Style::default().fg(Color::Rgb(100, 100, 100))),
-                ]),
-            ];
+    let mut results: Vec<Option<Result<Receipt>>> = vaults.iter().map(|_| None).collect();
+    while let Some(joined) = unlocks.join_next().await {
+        if let Ok((index, result)) = joined {
+            if show_progress {
+                let outcome = if result.is_ok() { "unlocked".bright_green().bold() } else { "failed".bright_red().bold() };
+                eprintln!("{:<width$} {}", vaults[index].name.bright_cyan(), outcome, width = width);
+            }
+            results[index] = Some(result);
+        }
+    }
 
-            let splash = Paragraph::new(title_lines)
-                .block(Block::default())
-                .style(Style::default().bg(Color::Rgb(255, 255, 255)))  // White background
-                .alignment(Alignment::Center);
+    drop(silenced);
+    output::set_quiet(!show_progress);
+    println!();
 
-            // Render white background for entire screen
-            let background = Block::default()
-                .style(Style::default().bg(Color::Rgb(255, 255, 255)));
-            f.render_widget(background, size);
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec![
+        "Vault".bright_white().bold().to_string(),
+        "Wallet".bright_white().bold().to_string(),
+        "Result".bright_white().bold().to_string(),
+        "Details".bright_white().bold().to_string(),
+    ]);
+    let mut failed = 0;
+    for (vault, result) in vaults.iter().zip(results) {
+        let result = result.unwrap_or_else(|| Err(anyhow::anyhow!("The unlock task stopped unexpectedly")));
+        notify::finished(Operation::Unlock, &result).await;
+        let (outcome, details) = match result {
+            Ok(receipt) => (
+                "unlocked".bright_green().bold().to_string(),
+                receipt.note.unwrap_or_else(|| {
+                    format!("{} transactions, {:.6} SOL in fees", receipt.signatures.len(), receipt.fee_lamports as f64 / 1e9)
+                }),
+            ),
+            Err(e) => {
+                failed += 1;
+                ("failed".bright_red().bold().to_string(), format!("{:#}", e))
+            }
+        };
+        table.add_row(vec![vault.name.bright_cyan().to_string(), vault.short_wallet(), outcome, details]);
+    }
+    println!("{}", table);
+    println!();
 
-            f.render_widget(splash, chunks[1]);
-        })?;
+    if failed > 0 {
+        anyhow::bail!("{} of {} vaults could not be unlocked", failed, vaults.len());
+    }
+    Ok(())
+}
 
-        // Update every 100ms for smooth animation
-        std::thread::sleep(std::time::Duration::from_millis(100));
+/// Challenge from `challenge` or `unlock sign --challenge`: 32 bytes in hex or base64
+fn parse_challenge(text: &str) -> Result<Vec<u8>> {
+    use base64::Engine as _;
+
+    let text = text.trim();
+    let bytes = hex::decode(text)
+        .or_else(|_| base64::engine::general_purpose::STANDARD.decode(text))
+        .map_err(|_| anyhow::anyhow!("Challenge is neither hex nor base64"))?;
+    if bytes.len() != 32 {
+        anyhow::bail!("Challenge is {} bytes, expected 32", bytes.len());
     }
+    Ok(bytes)
+}
 
-    // Clean up
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()?;
+fn cmd_unlock_sign(challenge: &[u8], sphincs_privkey_path: Option<String>, out: &Path) -> Result<()> {
+    let config = load_config()?;
+    let active = config.get_active_vault();
+    let priv_path = sphincs_privkey_path.or_else(|| active.map(|v| v.sphincs_private_key_path.clone()));
+    let pub_path = active.map(|v| v.sphincs_public_key_path.clone());
+
+    let key_manager = SphincsKeyManager::new(None)?;
+    let private_key = key_manager.load_private_key(priv_path)?;
+    let public_key = key_manager.load_public_key(pub_path)?;
+    if private_key.algorithm != public_key.algorithm {
+        anyhow::bail!(
+            "Private key ({}) and public key ({}) use different algorithms",
+            private_key.algorithm,
+            public_key.algorithm
+        );
+    }
 
+    println!("{} {}", "Challenge:".bold(), hex::encode(challenge).bright_cyan());
+    println!("{} Signing with {}...", "[*]".bright_cyan(), public_key.algorithm.to_string().bright_cyan());
+    let signature = key_manager.sign_message(challenge, &private_key)?;
+    if !SphincsKeyManager::verify_signature(challenge, &signature, &public_key)? {
+        anyhow::bail!("Signature does not verify against the vault's public key (mismatched key pair?)");
+    }
+
+    let detached = DetachedSignature::new(&public_key, challenge, &signature);
+    fs::write(out, detached.to_json()?).with_context(|| format!("Failed to write {}", out.display()))?;
+    audit::record(
+        audit::Event::new("sign_unlock_challenge")
+            .signature(crypto::attestation::sha256_hex(&signature))
+            .detail("challenge", hex::encode(challenge))
+            .detail("pq_key_sha256", &detached.public_key_sha256),
+    );
+
+    println!("{} Unlock signature written to {}", "[✓]".bright_green().bold(), out.display().to_string().bright_white());
+    println!("  {} {}", "Key SHA-256:".dimmed(), detached.public_key_sha256.bright_white());
+    println!();
+    println!("Next, on a machine with network access:");
+    println!("  {}", format!("qdum-vault unlock submit --signature {}", out.display()).bright_green());
+    println!();
+    output::result(out.display().to_string());
     Ok(())
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    let cli = Cli::parse();
+/// Answer signing requests from `--remote-signer` clients until stopped
+async fn cmd_signer(
+    listen: std::net::SocketAddr,
+    socket: Option<PathBuf>,
+    keypair_path: &str,
+    sphincs_privkey_path: Option<String>,
+    no_pq: bool,
+    program_id: Pubkey,
+) -> Result<()> {
+    let keypair = read_keypair(keypair_path)?;
+    let pq_keys = if no_pq {
+        None
+    } else {
+        let config = load_config()?;
+        let active = config.get_active_vault();
+        let key_manager = SphincsKeyManager::new(None)?;
+        let private_key = key_manager.load_private_key(sphincs_privkey_path.or_else(|| active.map(|v| v.sphincs_private_key_path.clone())))?;
+        let public_key = key_manager.load_public_key(active.map(|v| v.sphincs_public_key_path.clone()))?;
+        if private_key.algorithm != public_key.algorithm {
+            anyhow::bail!(
+                "Private key ({}) and public key ({}) use different algorithms",
+                private_key.algorithm,
+                public_key.algorithm
+            );
+        }
+        Some((public_key, private_key))
+    };
+
+    let token = std::env::var(remote_signer::TOKEN_ENV).ok().filter(|token| !token.is_empty());
+    let endpoint = match &socket {
+        Some(path) => SignerEndpoint::Socket(path.clone()),
+        // Anyone who can reach the port could have transactions signed, and
+        // every local user can reach a loopback one
+        None if token.is_none() => {
+            return Err(ExitKind::Usage.error(format!(
+                "Listening on {} requires a token in ${} (or use --socket for an owner-only socket)",
+                listen,
+                remote_signer::TOKEN_ENV
+            )));
+        }
+        None => SignerEndpoint::Http(format!("http://{}{}", listen, remote_signer::SIGN_PATH)),
+    };
 
-    // Print banner for all commands except dashboard (which takes over the screen)
-    // If no command provided, default to dashboard
-    let command = cli.command.unwrap_or(Commands::Dashboard { keypair: None });
+    let signer = LocalSigner::new(keypair, pq_keys).with_policy(load_config()?.policy).with_program_id(program_id);
+    println!("{} {}", "Wallet:       ".bold(), signer.pubkey().to_string().yellow());
+    println!("{} {}", "Program:      ".bold(), program_id.to_string().cyan());
+    println!("{} {}", "PQ key:       ".bold(), if no_pq { "not shared (--no-pq)".dimmed().to_string() } else { "loaded".green().to_string() });
+    let access = match &socket {
+        Some(_) => "socket owner only".green(),
+        None => "bearer token required".green(),
+    };
+    println!("{} {}", "Access:       ".bold(), access);
+    println!();
+    println!("Clients sign with: {}", format!("qdum-vault --remote-signer {} <command>", endpoint).bright_green());
+    println!("Every signature is recorded in the app log. Press Ctrl+C to stop.");
+    println!();
 
-    if !matches!(command, Commands::Dashboard { .. }) {
-        print_banner();
+    match (socket, token) {
+        (Some(path), _) => remote_signer::serve_socket(&path, signer).await,
+        (None, token) => remote_signer::serve_http(listen, signer, token.unwrap_or_default()).await,
     }
+}
 
-    match command {
-        Commands::Init { output_dir } => {
-            print_command_header("Initialize Quantum Keypair", "[INIT]".bright_green());
-
-            cmd_init(output_dir).await?;
-        }
-
-        Commands::Config { keypair, show } => {
-            print_command_header("Configuration", "[CONFIG]".bright_cyan());
+/// Signature bytes from an `unlock sign` file, after checking it was made
+/// by this vault's key over the challenge currently on-chain
+async fn load_unlock_signature(client: &VaultClient, wallet: Pubkey, path: &Path, public_key: &crypto::algorithm::PqKey) -> Result<Vec<u8>> {
+    let detached = DetachedSignature::load(path)?;
+    if detached.algorithm()? != public_key.algorithm {
+        anyhow::bail!("Signature uses {} but the vault's key is {}", detached.algorithm()?, public_key.algorithm);
+    }
+    if detached.public_key_sha256 != crypto::attestation::sha256_hex(&public_key.bytes) {
+        anyhow::bail!("Signature was made with a different key than the active vault's");
+    }
 
-            let config = load_config();
+    let challenge = client.unlock_challenge(wallet).await?;
+    if !challenge.locked {
+        anyhow::bail!("Vault is not locked; there is nothing to unlock");
+    }
+    if detached.message_sha256 != crypto::attestation::sha256_hex(&hex::decode(&challenge.challenge_hex)?) {
+        anyhow::bail!(
+            "The challenge changed since {} (the vault was locked again); run `unlock sign` for {}",
+            detached.created_at,
+            challenge.challenge_hex
+        );
+    }
+    detached.signature_bytes()
+}
 
-            if keypair.is_some() {
-                println!("{}", "The config command has been replaced by vault management.".yellow());
-                println!();
-                println!("{}", "To set your default keypair, use vault commands:".bold());
-                println!("  {} - Create and switch to a new vault", "qdum-vault vault new <name> --auto-generate".bright_cyan());
-                println!("  {} - Create vault with existing keys", "qdum-vault vault create <name>".bright_cyan());
-                println!("  {} - Switch between vaults", "qdum-vault vault switch".bright_cyan());
-                println!();
-            } else if show {
-                println!("{}", "Current Configuration:".bold());
-                println!();
+async fn cmd_unlock_dry_run(
+    rpc_url: &str,
+    program_id: Pubkey,
+    wallet: Pubkey,
+    keypair_path: &str,
+    delay_slots: u64,
+) -> Result<()> {
+    let config = load_config()?;
+    let sphincs_pub_path = config.get_active_vault().map(|vault| vault.sphincs_public_key_path.clone());
+    let sphincs_pubkey = SphincsKeyManager::new(None)?.load_public_key(sphincs_pub_path)?;
+
+    let options = UnlockOptions {
+        lock_mode: config.lock_mode_for_keypair(keypair_path),
+        delay_slots,
+        resume_after: 0,
+    };
 
-                if let Some(vault) = config.get_active_vault() {
-                    println!("{} {}", "Active vault:".bold(), vault.name.bright_cyan());
-                    println!("{} {}", "Keypair path:".bold(), vault.solana_keypair_path.dimmed());
-                    if !vault.wallet_address.is_empty() {
-                        println!("{} {}", "Wallet:".bold(), vault.wallet_address.yellow());
-                    }
-                } else {
-                    println!("{}", "No active vault configured.".yellow());
-                    println!();
-                    println!("Create a vault with:");
-                    println!("  {}", "qdum-vault vault new <name> --auto-generate".bright_cyan());
-                }
-            } else {
-                println!("{}", "Usage:".bold());
-                println!("  qdum-vault config --show            # Show current config");
-                println!();
-                println!("{}", "To manage vaults:".bold());
-                println!("  qdum-vault vault list               # List all vaults");
-                println!("  qdum-vault vault new <name>         # Create and switch to new vault");
-                println!("  qdum-vault vault switch             # Switch vaults interactively");
-            }
-        }
+    let client = VaultClient::builder().rpc_url(rpc_url).program_id(program_id).build()?;
+    let estimate = client.estimate_unlock(wallet, keypair_path, &sphincs_pubkey, options).await?;
 
-        Commands::Register {
-            keypair,
-            sphincs_pubkey,
-        } => {
-            print_command_header("Register Post-Quantum Account", "[REGISTER]".bright_cyan());
+    println!("{} {}", "Algorithm:".bold(), sphincs_pubkey.algorithm.to_string().cyan());
+    println!();
+    estimate.print(None);
+    println!("{}", "Nothing was sent. Run without --dry-run to unlock.".dimmed());
+    println!();
 
-            let program_id = Pubkey::from_str(&cli.program_id)?;
+    Ok(())
+}
 
-            // Auto-detect keypair and wallet
-            let keypair_path = keypair.unwrap_or_else(|| get_default_keypair_path());
-            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+/// Parse `--delay` as a raw slot count or a duration converted to slots
+fn parse_delay_slots(input: &str) -> Result<u64> {
+    if let Ok(slots) = input.trim().parse::<u64>() {
+        return Ok(slots);
+    }
 
-            // Get SPHINCS public key path from active vault if not provided via CLI
-            let config = load_config();
-            let sphincs_pubkey_path = if sphincs_pubkey.is_some() {
-                sphincs_pubkey
-            } else if let Some(vault) = config.get_active_vault() {
-                println!("{}", "═══════════════════════════════════════════════════════════".yellow());
-                println!("{} {}", "DEBUG: Active vault:".yellow().bold(), vault.name.cyan());
-                println!("{} {}", "DEBUG: Using SPHINCS public key:".yellow().bold(), vault.sphincs_public_key_path.cyan());
-                println!("{}", "═══════════════════════════════════════════════════════════".yellow());
-                Some(vault.sphincs_public_key_path.clone())
-            } else {
-                None
-            };
+    let duration = scheduler::parse_duration(input)?;
+    Ok(duration.num_milliseconds() as u64 / solana::client::SLOT_DURATION_MS)
+}
 
-            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
-            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
-            println!();
+async fn cmd_close(
+    rpc_url: &str,
+    program_id: Pubkey,
+    wallet: Pubkey,
+    keypair_path: &str,
+    receiver: Option<Pubkey>,
+    unsigned_out: Option<&Path>,
+) -> Result<Receipt> {
+    let client = build_exporting_client(rpc_url, program_id, unsigned_out)?;
+    client.close_pq_account(wallet, keypair_path, receiver).await
+}
 
-            cmd_register(
-                &cli.rpc_url,
-                program_id,
-                wallet_pubkey,
-                &kp_path,
-                sphincs_pubkey_path,
-            )
-            .await?;
+async fn cmd_rotate_keys(
+    rpc_url: &str,
+    program_id: Pubkey,
+    wallet: Pubkey,
+    keypair_path: &str,
+    yes: bool,
+) -> Result<()> {
+    let mut config = load_config()?;
+
+    // Find the vault profile that owns this keypair (setups without a profile use the data directory)
+    let vault_name = config.find_by_keypair(keypair_path).map(|v| v.name.clone());
+    let lock_mode = config.lock_mode_for_keypair(keypair_path);
+
+    let (public_key_path, private_key_path) = match vault_name.as_deref().and_then(|n| config.get_vault(n)) {
+        Some(vault) => (
+            PathBuf::from(&vault.sphincs_public_key_path),
+            PathBuf::from(&vault.sphincs_private_key_path),
+        ),
+        None => {
+            let qdum_dir = paths::data_dir();
+            (qdum_dir.join("sphincs_public.key"), qdum_dir.join("sphincs_private.key"))
         }
+    };
 
-        Commands::Lock { keypair } => {
-            print_command_header("Lock Vault", "[LOCK]".bright_red());
-
-            let program_id = Pubkey::from_str(&cli.program_id)?;
+    let key_dir = public_key_path.parent()
+        .map(|p| p.to_path_buf())
+        .ok_or_else(|| anyhow::anyhow!("Invalid SPHINCS+ public key path"))?;
 
-            // Auto-detect keypair and wallet
-            let keypair_path = keypair.unwrap_or_else(|| get_default_keypair_path());
-            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+    // The PQ account can only be closed and re-created while unlocked
+    let client = VaultClient::builder().rpc_url(rpc_url).program_id(program_id).build()?;
+    let registered = client.pq_account_exists(wallet).await?;
 
-            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
-            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
+    if registered {
+        let (is_locked, _) = client.get_vault_status(wallet).await?;
+        if is_locked {
+            println!("{} {}", "[!]".red().bold(), "Vault is LOCKED".red().bold());
+            println!("   Unlock with your current keys before rotating:");
+            println!("     {}", "qdum-vault unlock".bright_cyan());
             println!();
-
-            cmd_lock(&cli.rpc_url, program_id, wallet_pubkey, &kp_path).await?;
+            return Err(anyhow::anyhow!("Cannot rotate keys while the vault is locked"));
         }
+    }
 
-        Commands::Unlock {
-            keypair,
-            sphincs_privkey,
-        } => {
-            print_command_header("Unlock Vault", "[UNLOCK]".bright_green());
+    println!("{}", "Rotation plan:".bright_white().bold());
+    if let Some(name) = &vault_name {
+        println!("  {} Vault:            {}", "•".bright_cyan(), name.bright_cyan());
+    }
+    println!("  {} Generate a new SPHINCS+ keypair", "•".bright_cyan());
+    if registered {
+        println!("  {} Close the current PQ account (rent refunded)", "•".bright_cyan());
+    }
+    println!("  {} Register the new public key on-chain", "•".bright_cyan());
+    println!("  {} Archive old keys to {}", "•".bright_cyan(), key_dir.join("archive").display().to_string().dimmed());
+    println!();
 
-            let program_id = Pubkey::from_str(&cli.program_id)?;
+    if !yes && !vault_switcher::prompt_confirm("Rotate SPHINCS+ keys now?")? {
+        return Err(ExitKind::Cancelled.error("Rotation cancelled"));
+    }
 
-            // Auto-detect keypair and wallet
-            let keypair_path = keypair.unwrap_or_else(|| get_default_keypair_path());
-            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+    // Stage the new keypair next to the current one, keeping the current algorithm
+    let algorithm = SphincsKeyManager::new(None)?
+        .load_public_key(Some(public_key_path.to_string_lossy().to_string()))
+        .map(|key| key.algorithm)
+        .unwrap_or_default();
+    let staging_dir = key_dir.join("rotation");
+    let staging_manager = SphincsKeyManager::new(Some(staging_dir.to_str().unwrap().to_string()))?
+        .with_algorithm(algorithm);
+    staging_manager.generate_and_save_keypair()?;
+    let new_pubkey = staging_manager.load_public_key(None)?;
+
+    println!("{} New SPHINCS+ keypair staged", "[✓]".bright_green().bold());
+    println!();
 
-            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
-            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
-            println!();
+    if registered {
+        client.close_pq_account(wallet, keypair_path, None).await?;
+    }
 
-            cmd_unlock(
-                &cli.rpc_url,
-                program_id,
-                wallet_pubkey,
-                &kp_path,
-                sphincs_privkey,
-            )
-            .await?;
+    let receipt = match client.register_pq_account(wallet, keypair_path, &new_pubkey, lock_mode).await {
+        Ok(receipt) => receipt,
+        Err(e) => {
+            let staged_pubkey = staging_dir.join("sphincs_public.key");
+            println!();
+            println!("{} {}", "[!]".red().bold(), "Registration of the new key failed".red().bold());
+            println!("   Your new keys are kept in {}", staging_dir.display().to_string().bright_cyan());
+            println!("   Retry with:");
+            println!("     {}", format!("qdum-vault register --sphincs-pubkey {}", staged_pubkey.display()).bright_cyan());
+            println!();
+            return Err(e);
         }
+    };
+    receipt.print();
 
-        Commands::Close { keypair, receiver } => {
-            print_command_header("Close PQ Account", "[CLOSE]".bright_red());
+    // Archive the old keys and move the new ones into place
+    let key_manager = SphincsKeyManager::new(Some(key_dir.to_str().unwrap().to_string()))?;
+    let archive_dir = key_manager.archive_keypair(&public_key_path, &private_key_path)?;
 
-            let program_id = Pubkey::from_str(&cli.program_id)?;
+    fs::rename(staging_dir.join("sphincs_public.key"), &public_key_path)
+        .context("Failed to install new public key")?;
+    fs::rename(staging_dir.join("sphincs_private.key"), &private_key_path)
+        .context("Failed to install new private key")?;
+    let _ = fs::remove_dir(&staging_dir);
 
-            // Auto-detect keypair and wallet
-            let keypair_path = keypair.unwrap_or_else(|| get_default_keypair_path());
-            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+    if let Some(name) = &vault_name {
+        config.record_key_rotation(name)?;
+    }
 
-            // Parse receiver address if provided
-            let receiver_pubkey = receiver
-                .as_ref()
-                .map(|r| Pubkey::from_str(r))
-                .transpose()?;
+    println!("{} SPHINCS+ keys rotated", "[✓]".bright_green().bold());
+    println!("{} {}", "  New public key:".dimmed(), hex::encode(new_pubkey).bright_cyan());
+    println!("{} {}", "  Old keys archived to:".dimmed(), archive_dir.display().to_string().bright_cyan());
+    println!();
 
-            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
-            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
-            println!();
+    Ok(())
+}
 
-            cmd_close(&cli.rpc_url, program_id, wallet_pubkey, &kp_path, receiver_pubkey).await?;
-        }
+async fn cmd_status(rpc_url: &str, program_id: Pubkey, wallet: Pubkey) -> Result<()> {
+    let client = VaultClient::builder().rpc_url(rpc_url).program_id(program_id).build()?;
+    client.check_status(wallet).await?;
+    if output::quiet() {
+        let (locked, _) = client.get_vault_status(wallet).await?;
+        output::result(if locked { "locked" } else { "unlocked" });
+    }
 
-        Commands::Status { keypair } => {
-            print_command_header("Vault Status", "[STATUS]".bright_cyan());
+    Ok(())
+}
 
-            let program_id = Pubkey::from_str(&cli.program_id)?;
+async fn cmd_status_report(
+    rpc_url: &str,
+    program_id: Pubkey,
+    wallet: Pubkey,
+    mints: &[Pubkey],
+    format: OutputFormat,
+) -> Result<()> {
+    let client = VaultClient::builder().rpc_url(rpc_url).program_id(program_id).build()?;
+    let report = client.status_report(wallet, mints).await?;
+
+    let output = match format {
+        OutputFormat::Json => serde_json::to_string_pretty(&report)?,
+        OutputFormat::Yaml => serde_yaml::to_string(&report)?,
+        OutputFormat::Toml => toml::to_string(&report)?,
+        OutputFormat::Table => unreachable!("the table is printed by cmd_status"),
+    };
+    output::emit(output.trim_end());
 
-            // Auto-detect keypair and wallet
-            let keypair_path = keypair.unwrap_or_else(|| get_default_keypair_path());
-            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+    if report.locked {
+        return Err(ExitKind::VaultLocked.error("Vault is locked"));
+    }
+    Ok(())
+}
 
-            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
-            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
-            println!();
+async fn cmd_status_watch(
+    rpc_url: &str,
+    program_id: Pubkey,
+    wallet: Pubkey,
+    mints: &[Pubkey],
+    interval: Duration,
+    format: OutputFormat,
+) -> Result<()> {
+    if matches!(format, OutputFormat::Yaml | OutputFormat::Toml) {
+        anyhow::bail!("--watch prints one line per change; use --json or the default format");
+    }
+    // Every poll has to see fresh state
+    let client = VaultClient::builder()
+        .rpc_url(rpc_url)
+        .program_id(program_id)
+        .account_cache_ttl(Duration::ZERO)
+        .build()?;
+    let interval = interval.max(Duration::from_secs(1));
+
+    if format == OutputFormat::Table {
+        println!("{} Watching {} every {}s (Ctrl+C to stop)", "[i]".bright_blue(), wallet.to_string().bright_cyan(), interval.as_secs());
+        println!();
+    }
 
-            cmd_status(&cli.rpc_url, program_id, wallet_pubkey).await?;
+    let mut last: Option<StatusReport> = None;
+    loop {
+        match client.status_report(wallet, mints).await {
+            Ok(report) if last.as_ref() != Some(&report) => {
+                let changes = last.as_ref().map(|previous| report.changes_since(previous)).unwrap_or_default();
+                if format == OutputFormat::Json {
+                    output::emit(serde_json::to_string(&report)?);
+                } else {
+                    output::emit(status_line(&report, &changes));
+                }
+                last = Some(report);
+            }
+            Ok(_) => {}
+            // Keep watching through RPC hiccups
+            Err(e) => eprintln!("{} {:#}", "[!]".yellow(), e),
         }
+        tokio::time::sleep(interval).await;
+    }
+}
 
-        Commands::Balance { keypair, mint } => {
-            print_command_header("Check Balance", "[BALANCE]".bright_cyan());
+/// One line of `status --watch` and `monitor`: time, lock state, balances, and what changed
+fn status_line(report: &StatusReport, changes: &[solana::client::StatusChange]) -> String {
+    let state = if report.locked { "🔒 LOCKED".red().bold() } else { "🔓 UNLOCKED".green().bold() };
+    let balances = report
+        .tokens
+        .iter()
+        .map(|token| format!("{}… {}", &token.mint[..4], token.amount as f64 / 10f64.powi(token.decimals as i32)))
+        .collect::<Vec<_>>()
+        .join("  ");
+    let mut line = format!(
+        "{} {}  {:.4} SOL  {}",
+        chrono::Local::now().format("%H:%M:%S").to_string().dimmed(),
+        state,
+        report.sol_lamports as f64 / 1e9,
+        balances
+    );
+    if !changes.is_empty() {
+        let changes = changes.iter().map(|change| change.to_string()).collect::<Vec<_>>().join(", ");
+        line.push_str(&format!("  {} {}", "←".dimmed(), changes.bright_yellow()));
+    }
+    line
+}
 
-            // Auto-detect keypair and wallet
-            let keypair_path = keypair.unwrap_or_else(|| get_default_keypair_path());
-            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+/// The named vaults, or every configured vault (by name) if none are given
+fn select_vaults(config: &VaultConfig, names: Vec<String>) -> Result<Vec<&VaultProfile>> {
+    if names.is_empty() {
+        let mut vaults: Vec<&VaultProfile> = config.vaults.values().collect();
+        vaults.sort_by(|a, b| a.name.cmp(&b.name));
+        if vaults.is_empty() {
+            return Err(ExitKind::Config.error("No vaults configured. Create one with: qdum-vault vault new <name>"));
+        }
+        return Ok(vaults);
+    }
+    names
+        .iter()
+        .map(|name| {
+            config
+                .vaults
+                .get(name)
+                .ok_or_else(|| ExitKind::Usage.error(format!("Vault '{}' does not exist", name)))
+        })
+        .collect()
+}
 
-            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
-            println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
-            println!();
+/// Where `vault` lives: its own pinned cluster, unless --rpc-url / --program-id say otherwise
+fn vault_target(vault: &VaultProfile, rpc_flag: Option<&str>, program_flag: Option<&str>) -> Result<MonitoredVault> {
+    let rpc_url = rpc_flag.map(str::to_string).unwrap_or_else(|| vault.network_rpc_url());
+    let program_id = match program_flag {
+        Some(program_id) => Pubkey::from_str(program_id)?,
+        None => vault.network_program_id()?,
+    };
+    let mint = vault.mint.as_deref().unwrap_or(DEFAULT_MINT);
+    let standard_mint = vault.standard_mint.as_deref().unwrap_or(DEFAULT_STANDARD_MINT);
+    Ok(MonitoredVault {
+        name: vault.name.clone(),
+        wallet: vault.wallet()?,
+        rpc_url,
+        program_id,
+        mints: vec![Pubkey::from_str(mint)?, Pubkey::from_str(standard_mint)?],
+    })
+}
 
-            let mint_pubkey = Pubkey::from_str(&mint)?;
+async fn cmd_monitor(
+    vaults: &[MonitoredVault],
+    webhooks: &Webhooks,
+    interval: Duration,
+    daemon: bool,
+    helius: Option<monitor::helius::HeliusOptions>,
+) -> Result<()> {
+    // Every poll has to see fresh state
+    let clients = vaults
+        .iter()
+        .map(|vault| {
+            VaultClient::builder()
+                .rpc_url(&vault.rpc_url)
+                .program_id(vault.program_id)
+                .account_cache_ttl(Duration::ZERO)
+                .build()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let interval = interval.max(Duration::from_secs(1));
+
+    if daemon {
+        println!("{} Monitoring {} vault(s) every {}s (Ctrl+C to stop)", "[i]".bright_blue(), vaults.len(), interval.as_secs());
+        if webhooks.urls().is_empty() {
+            println!("{} No webhooks configured; changes are only printed", "[!]".yellow());
+            println!("    Add one with: {}", "qdum-vault config --add-webhook <url>".bright_cyan());
+        }
+        for url in webhooks.urls() {
+            println!("  {} {}", "→".dimmed(), url);
+        }
+        println!();
+    }
 
-            cmd_balance(&cli.rpc_url, wallet_pubkey, mint_pubkey).await?;
+    let mut push = match helius {
+        Some(options) => {
+            let mut watched = Vec::with_capacity(vaults.len());
+            for (vault, client) in vaults.iter().zip(&clients) {
+                watched.push(client.watched_addresses(vault.wallet, &vault.mints).await?);
+            }
+            let listen = options.listen;
+            let push = monitor::helius::HeliusPush::start(options, watched).await?;
+            println!("{} Helius webhook registered; listening for callbacks on {}", "[✓]".bright_green(), listen);
+            println!();
+            Some(push)
         }
+        None => None,
+    };
 
-        Commands::Transfer { keypair, to, amount, mint } => {
-            print_command_header("Transfer Tokens", "[TRANSFER]".bright_yellow());
+    let mut last: Vec<Option<StatusReport>> = vec![None; vaults.len()];
+    let mut due: Vec<usize> = (0..vaults.len()).collect();
+    loop {
+        for index in due.drain(..) {
+            let (vault, client, last) = (&vaults[index], &clients[index], &mut last[index]);
+            let report = match client.status_report(vault.wallet, &vault.mints).await {
+                Ok(report) => report,
+                // Keep watching the other vaults through RPC hiccups
+                Err(e) => {
+                    eprintln!("{} {}: {:#}", "[!]".yellow(), vault.name, e);
+                    continue;
+                }
+            };
+            let changes = last.as_ref().map(|previous| report.changes_since(previous)).unwrap_or_default();
+            if last.is_none() || !changes.is_empty() {
+                output::emit(format!("{} {}", vault.name.bright_cyan().bold(), status_line(&report, &changes)));
+            }
+            for change in &changes {
+                for (url, e) in webhooks.send(&WebhookEvent::new(vault, change, &report)).await {
+                    eprintln!("{} Webhook {} failed: {:#}", "[!]".yellow(), url, e);
+                }
+                if let Some(message) = notify::change_message(&vault.name, change) {
+                    notify::announce(&message).await;
+                }
+            }
+            *last = Some(report);
+        }
 
-            let program_id = Pubkey::from_str(&cli.program_id)?;
+        if !daemon {
+            return Ok(());
+        }
 
-            // Auto-detect keypair and wallet
-            let keypair_path = keypair.unwrap_or_else(|| get_default_keypair_path());
-            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+        enum Wake {
+            Poll,
+            Pushed(usize),
+            Stop,
+        }
+        let helius_enabled = push.is_some();
+        let wake = tokio::select! {
+            _ = tokio::time::sleep(interval) => Wake::Poll,
+            Some(index) = next_push(&mut push) => Wake::Pushed(index),
+            _ = tokio::signal::ctrl_c(), if helius_enabled => Wake::Stop,
+        };
+        match wake {
+            Wake::Poll => due.extend(0..vaults.len()),
+            Wake::Pushed(index) => {
+                due.push(index);
+                if let Some(push) = push.as_mut() {
+                    due.extend(push.drain());
+                }
+                due.sort_unstable();
+                due.dedup();
+            }
+            // Don't leave a webhook behind at Helius
+            Wake::Stop => {
+                if let Some(push) = push.take() {
+                    push.unregister().await?;
+                    println!("{} Helius webhook deleted", "[✓]".bright_green());
+                }
+                return Ok(());
+            }
+        }
+    }
+}
 
-            println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
-            println!("{} {}", "From:         ".bold(), wallet_pubkey.to_string().yellow());
-            println!();
+/// Next vault a Helius callback touched; never resolves without Helius
+async fn next_push(push: &mut Option<monitor::helius::HeliusPush>) -> Option<usize> {
+    match push {
+        Some(push) => push.next().await,
+        None => std::future::pending().await,
+    }
+}
 
-            let recipient = Pubkey::from_str(&to)?;
-            let mint_pubkey = Pubkey::from_str(&mint)?;
+/// Take a network lock snapshot now, then every `interval` if given
+async fn cmd_snapshot(client: &VaultClient, mint: Pubkey, interval: Option<Duration>) -> Result<()> {
+    let retention = load_config()?.snapshot_retention();
+    if let Some(interval) = interval {
+        println!(
+            "{} Snapshot every {}s, keeping {} days (Ctrl+C to stop)",
+            "[i]".bright_blue(),
+            interval.as_secs(),
+            retention.num_days()
+        );
+        println!();
+    }
 
-            cmd_transfer(&cli.rpc_url, program_id, wallet_pubkey, &kp_path, recipient, mint_pubkey, amount).await?;
+    loop {
+        // The cache would hand back the previous snapshot's numbers
+        match dashboard::LockHistory::record(client, mint, true).await {
+            Ok(entry) => output::emit(format!(
+                "{} {} {:.2} qcoin locked ({} holders){}",
+                "[✓]".bright_green(),
+                chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string().dimmed(),
+                entry.locked_amount,
+                entry.holder_count,
+                entry.price.map(|price| format!(", price {}", price)).unwrap_or_default()
+            )),
+            // A daemon outlives one failed RPC call
+            Err(e) if interval.is_some() => eprintln!("{} Snapshot failed: {:#}", "[!]".yellow(), e),
+            Err(e) => return Err(e),
         }
 
-        Commands::Bridge { action, keypair } => {
-            // Auto-detect keypair and wallet
-            let keypair_path = keypair.unwrap_or_else(|| get_default_keypair_path());
-            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+        match interval {
+            Some(interval) => tokio::time::sleep(interval).await,
+            None => return Ok(()),
+        }
+    }
+}
 
-            match action {
-                BridgeAction::Wrap { amount, standard_mint, pq_mint } => {
-                    print_command_header("Wrap Standard QDUM → pqQDUM", "[BRIDGE]".bright_magenta());
+fn print_unlock_challenge(challenge: &solana::client::UnlockChallenge) {
+    println!("{} {}", "Wallet:     ".bold(), challenge.wallet.yellow());
+    println!("{} {}", "PQ account: ".bold(), challenge.pq_account.dimmed());
+    println!(
+        "{} {}",
+        "State:      ".bold(),
+        if challenge.locked { "locked".red().bold() } else { "unlocked".green().bold() }
+    );
+    println!();
+    println!("{}", "Challenge (hex):".dimmed());
+    println!("  {}", challenge.challenge_hex.bright_cyan());
+    println!("{}", "Challenge (base64):".dimmed());
+    println!("  {}", challenge.challenge_base64.bright_cyan());
+    println!();
+    match (challenge.set_at, &challenge.set_by) {
+        (Some(set_at), Some(signature)) => {
+            let time = chrono::DateTime::from_timestamp(set_at, 0)
+                .map_or_else(String::new, |time| time.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string());
+            println!("{} {}", "Set at:".bold(), time);
+            println!("{} {}", "Set by:".bold(), signature.dimmed());
+        }
+        _ => println!("{}", "Set at: not among the account's recent transactions".dimmed()),
+    }
+    if !challenge.locked {
+        println!();
+        println!("{}", "The vault is unlocked; locking it writes a new challenge.".yellow());
+    }
+}
 
-                    println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
-                    println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
-                    println!();
+async fn cmd_account(client: &VaultClient, program_id: Pubkey, address: Pubkey, full: bool) -> Result<()> {
+    /// Bytes dumped without --full
+    const DUMP_PREVIEW: usize = 512;
 
-                    let standard_mint_pubkey = Pubkey::from_str(&standard_mint)?;
-                    let pq_mint_pubkey = Pubkey::from_str(&pq_mint)?;
-                    let amount_raw = (amount * 1_000_000.0) as u64;
+    let inspection = client.inspect_account(address).await?;
+    let kind = match (&inspection.kind, inspection.pda_verified) {
+        (solana::client::AccountKind::Unknown, _) => "unknown".yellow().to_string(),
+        (kind, true) => format!("{} {}", kind.name().bright_green().bold(), "(address matches its PDA)".dimmed()),
+        (kind, false) if inspection.owner == program_id => {
+            format!("{} {}", kind.name().bright_yellow().bold(), "(layout only; address is not its PDA)".yellow())
+        }
+        (kind, false) => kind.name().bright_green().bold().to_string(),
+    };
 
-                    cmd_bridge_wrap(
-                        &cli.rpc_url,
-                        wallet_pubkey,
-                        &kp_path,
-                        standard_mint_pubkey,
-                        pq_mint_pubkey,
-                        amount_raw,
-                    ).await?;
-                }
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table
+        .add_row(vec!["Address".dimmed().to_string(), inspection.address.to_string()])
+        .add_row(vec!["Owner program".dimmed().to_string(), inspection.owner.to_string()])
+        .add_row(vec!["Lamports".dimmed().to_string(), inspection.lamports.to_string()])
+        .add_row(vec!["Data length".dimmed().to_string(), format!("{} bytes", inspection.data.len())])
+        .add_row(vec!["Executable".dimmed().to_string(), inspection.executable.to_string()])
+        .add_row(vec!["Detected as".dimmed().to_string(), kind]);
+    println!("{}", table);
 
-                BridgeAction::Unwrap { amount, standard_mint, pq_mint } => {
-                    print_command_header("Unwrap pqQDUM → Standard QDUM", "[BRIDGE]".bright_magenta());
+    let fields = inspection.kind.fields();
+    if !fields.is_empty() {
+        println!();
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec!["Field", "Value"]);
+        for (name, value) in fields {
+            table.add_row(vec![name.bright_cyan().to_string(), value]);
+        }
+        println!("{}", table);
+    }
 
-                    println!("{} {}", "Using keypair:".bold(), kp_path.dimmed());
-                    println!("{} {}", "Wallet:       ".bold(), wallet_pubkey.to_string().yellow());
-                    println!();
+    if !inspection.data.is_empty() {
+        let shown = if full { inspection.data.len() } else { inspection.data.len().min(DUMP_PREVIEW) };
+        println!();
+        print!("{}", solana::client::hex_dump(&inspection.data[..shown]));
+        if shown < inspection.data.len() {
+            println!("{}", format!("… {} more bytes (--full to dump everything)", inspection.data.len() - shown).dimmed());
+        }
+    }
+    Ok(())
+}
 
-                    let standard_mint_pubkey = Pubkey::from_str(&standard_mint)?;
-                    let pq_mint_pubkey = Pubkey::from_str(&pq_mint)?;
-                    let amount_raw = (amount * 1_000_000.0) as u64;
+async fn cmd_stats_leaderboard(
+    client: &VaultClient,
+    mint: Pubkey,
+    top: usize,
+    wallet: Option<Pubkey>,
+    refresh: bool,
+) -> Result<()> {
+    println!("{} Querying locked holders...", "[*]".bright_cyan());
+    let leaderboard = client.lock_leaderboard(mint, refresh).await?;
+    if leaderboard.entries.is_empty() {
+        println!("{} No locked holders found", "[!]".yellow());
+        return Ok(());
+    }
 
-                    cmd_bridge_unwrap(
-                        &cli.rpc_url,
-                        wallet_pubkey,
-                        &kp_path,
-                        standard_mint_pubkey,
-                        pq_mint_pubkey,
-                        amount_raw,
-                    ).await?;
-                }
-            }
+    let row = |entry: &solana::client::LeaderboardEntry| {
+        let cells = vec![
+            format!("#{}", entry.rank),
+            entry.owner.to_string(),
+            format!("{:.2}", entry.amount),
+            format!("{:.2}%", entry.share),
+        ];
+        if Some(entry.owner) == wallet {
+            cells.into_iter().map(|cell| cell.bright_green().bold().to_string()).collect()
+        } else {
+            cells
         }
+    };
 
-        Commands::Vault { action } => {
-            match action {
-                VaultAction::List => cmd_vault_list()?,
-                VaultAction::Create { name, description, auto_generate } => cmd_vault_create(name, description, auto_generate)?,
-                VaultAction::Switch { name } => cmd_vault_switch(&cli.rpc_url, &cli.program_id, &name).await?,
-                VaultAction::Show { name } => cmd_vault_show(&name)?,
-                VaultAction::Delete { name, yes } => cmd_vault_delete(&cli.rpc_url, &cli.program_id, &name, yes).await?,
-                VaultAction::Rename { old_name, new_name } => cmd_vault_rename(&old_name, &new_name)?,
-                VaultAction::New { name, description, auto_generate } => cmd_vault_new(name, description, auto_generate)?,
-            }
-        }
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec!["Rank", "Wallet", "Locked", "Share"]);
+    for entry in leaderboard.top(top) {
+        table.add_row(row(entry));
+    }
+    // Still show where you stand when you're outside the top N
+    let own = wallet.and_then(|wallet| leaderboard.entry_for(&wallet));
+    if let Some(entry) = own.filter(|entry| entry.rank > top) {
+        table.add_row(vec!["…".to_string(), String::new(), String::new(), String::new()]);
+        table.add_row(row(entry));
+    }
+    println!("{}", table);
 
-        Commands::Dashboard { keypair } => {
-            // Don't print banner for dashboard - it takes over the screen
+    println!(
+        "{} {:.2} locked across {} holders",
+        "Total:".bold(),
+        leaderboard.total_locked,
+        leaderboard.holder_count()
+    );
+    match (wallet, own) {
+        (Some(_), Some(entry)) => {
+            println!("{} #{} of {} ({:.2}%)", "Your rank:".bold(), entry.rank, leaderboard.holder_count(), entry.share)
+        }
+        (Some(_), None) => println!("{}", "Your wallet has nothing locked.".dimmed()),
+        (None, _) => {}
+    }
+    Ok(())
+}
 
-            let program_id = Pubkey::from_str(&cli.program_id)?;
+async fn cmd_stats_distribution(client: &VaultClient, mint: Pubkey, refresh: bool) -> Result<()> {
+    println!("{} Querying locked holders...", "[*]".bright_cyan());
+    let leaderboard = client.lock_leaderboard(mint, refresh).await?;
+    let distribution = leaderboard.distribution();
 
-            // Auto-detect keypair and wallet
-            let keypair_path = keypair.unwrap_or_else(|| get_default_keypair_path());
-            let (kp_path, wallet_pubkey) = load_keypair_and_extract_wallet(&keypair_path)?;
+    // Bars are scaled to the fullest bucket
+    const BAR_WIDTH: usize = 30;
+    let most = distribution.iter().map(|bucket| bucket.holders).max().unwrap_or(0).max(1);
 
-            let kp_pathbuf = PathBuf::from(kp_path);
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec!["Balance", "Holders", "Locked", "Share", ""]);
+    for bucket in &distribution {
+        let bar = "█".repeat((bucket.holders * BAR_WIDTH).div_ceil(most));
+        table.add_row(vec![
+            bucket.label.bold().to_string(),
+            bucket.holders.to_string(),
+            format!("{:.2}", bucket.amount),
+            format!("{:.2}%", bucket.share),
+            bar.bright_cyan().to_string(),
+        ]);
+    }
+    println!("{}", table);
+    println!(
+        "{} {:.2} locked across {} holders",
+        "Total:".bold(),
+        leaderboard.total_locked,
+        leaderboard.holder_count()
+    );
+    println!("{}", "Dust is under 1 QDUM.".dimmed());
+    Ok(())
+}
 
-            // Get SPHINCS key paths from active vault
-            let config = load_config();
-            let (sphincs_public_key_path, sphincs_private_key_path) = if let Some(vault) = config.get_active_vault() {
-                (vault.sphincs_public_key_path.clone(), vault.sphincs_private_key_path.clone())
-            } else {
-                // Fall back to default paths
-                let home = dirs::home_dir().expect("Could not determine home directory");
-                let qdum_dir = home.join(".qdum");
-                (
-                    qdum_dir.join("sphincs_public.key").to_str().unwrap().to_string(),
-                    qdum_dir.join("sphincs_private.key").to_str().unwrap().to_string(),
-                )
-            };
+fn print_network_stats(stats: &solana::client::NetworkLockStats) {
+    let cache_age = match stats.cache_age_secs {
+        0 => "just queried".to_string(),
+        secs if secs < 60 => format!("{}s ago", secs),
+        secs => format!("{}m {}s ago", secs / 60, secs % 60),
+    };
 
-            // Default pqQDUM devnet mint (Token-2022 with transfer hooks)
-            let mint = Pubkey::from_str("Cj5wfxiGdaxdymPjxVbt4HXJbx1H9PN3fSbnjThMJxEv")?;
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table
+        .add_row(vec!["Mint".dimmed().to_string(), stats.mint.clone()])
+        .add_row(vec!["Total locked".dimmed().to_string(), format!("{:.2}", stats.total_locked).bright_green().bold().to_string()])
+        .add_row(vec!["Locked holders".dimmed().to_string(), stats.holder_count.to_string()])
+        .add_row(vec!["Circulating supply".dimmed().to_string(), format!("{:.2}", stats.circulating_supply)])
+        .add_row(vec!["Supply locked".dimmed().to_string(), format!("{:.2}%", stats.locked_percent).bright_yellow().to_string()])
+        .add_row(vec!["Holders queried".dimmed().to_string(), cache_age]);
+    println!("{}", table);
+    if stats.cache_age_secs > 0 {
+        println!("{}", "Served from the five-minute cache; pass --refresh to query again.".dimmed());
+    }
+}
 
-            let mut dashboard = Dashboard::new(
-                wallet_pubkey,
-                kp_pathbuf,
-                sphincs_public_key_path,
-                sphincs_private_key_path,
-                cli.rpc_url.clone(),
-                program_id,
-                mint,
-            )?;
+async fn cmd_stats_backfill(client: &VaultClient, mint: Pubkey, limit: usize) -> Result<()> {
+    println!("{} Walking up to {} program transactions...", "[*]".bright_cyan(), limit);
+    let snapshots = client.lock_history(mint, limit).await?;
 
-            // Show splash screen before dashboard
-            show_splash_screen()?;
+    let mut history = dashboard::LockHistory::load()?;
+    let added = history.backfill(&snapshots, load_config()?.snapshot_retention());
+    history.save()?;
 
-            dashboard.run()?;
+    match (snapshots.first(), snapshots.last()) {
+        (Some(first), Some(last)) => {
+            let date = |timestamp: i64| {
+                chrono::DateTime::from_timestamp(timestamp, 0).map_or_else(String::new, |time| time.format("%Y-%m-%d %H:%M").to_string())
+            };
+            println!(
+                "{} Reconstructed {} hourly snapshots ({} → {}), {} older than recorded history",
+                "[✓]".bright_green(),
+                snapshots.len(),
+                date(first.timestamp),
+                date(last.timestamp),
+                added
+            );
         }
+        _ => println!("{} No lock or unlock transactions found", "[!]".yellow()),
+    }
+    println!("{}", "Amounts use each holder's current balance; holder counts are exact.".dimmed());
+    Ok(())
+}
 
+/// `--amount` in base units
+fn payment_amount(amount: f64, decimals: u8) -> Result<u64> {
+    if amount.is_nan() || amount <= 0.0 {
+        return Err(ExitKind::Usage.error("--amount must be greater than 0"));
     }
+    Ok(units::to_base_units(amount, decimals))
+}
 
-    Ok(())
+/// Decimals of `mint`, looked up on the network the first time. Amounts
+/// converted with them get signed, so a failed lookup is an error rather
+/// than a guess.
+async fn token_decimals(rpc_url: &str, mint: &Pubkey) -> Result<u8> {
+    VaultClient::builder().rpc_url(rpc_url).build()?.mint_decimals(*mint).await.exit_kind(ExitKind::Rpc)
 }
 
-async fn cmd_init(output_dir: Option<String>) -> Result<()> {
-    use solana_sdk::signature::{Keypair, Signer};
+/// Poll for the transaction paying `request` until it lands or `timeout` passes
+async fn cmd_monitor_payment(rpc_url: &str, request: &TransferRequest, timeout: &str) -> Result<()> {
+    const POLL_INTERVAL: Duration = Duration::from_secs(3);
 
-    // Spinner for SPHINCS+ key generation
-    let spinner = ProgressBar::new_spinner();
+    let limit = scheduler::parse_duration(timeout)?.to_std().context("Invalid --timeout")?;
+    let client = VaultClient::builder().rpc_url(rpc_url).build()?;
+    let decimals = match request.spl_token {
+        Some(mint) => client.mint_decimals(mint).await?,
+        None => units::DEFAULT_DECIMALS,
+    };
+    let amount = units::to_tokens(request.amount.unwrap_or(0), decimals);
+
+    let spinner = output::spinner();
     spinner.set_style(
         ProgressStyle::default_spinner()
             .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
@@ -896,311 +5336,480 @@ async fn cmd_init(output_dir: Option<String>) -> Result<()> {
             .unwrap()
     );
     spinner.enable_steady_tick(Duration::from_millis(80));
-    spinner.set_message("Generating SPHINCS+ quantum-resistant keypair...".to_string());
+    spinner.set_message(format!("Waiting for {} to {}...", amount, request.recipient));
+    let started = std::time::Instant::now();
+    let payment = loop {
+        if let Some(payment) = client.find_payment(request).await? {
+            break payment;
+        }
+        if started.elapsed() >= limit {
+            spinner.finish_and_clear();
+            return Err(ExitKind::Failure.error(format!("No payment arrived within {}", timeout)));
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    };
+    spinner.finish_and_clear();
 
-    // Generate SPHINCS+ keys
-    let key_manager = SphincsKeyManager::new(output_dir.clone())?;
-    key_manager.generate_and_save_keypair()?;
+    println!("{} Payment received: {}", "[✓]".green().bold(), units::to_tokens(payment.amount, decimals).to_string().bright_green().bold());
+    println!("{} {}", "Signature:".bold(), payment.signature.to_string().bright_cyan());
+    if let Some(time) = payment.block_time.and_then(|secs| chrono::DateTime::from_timestamp(secs, 0)) {
+        println!("{} {}", "Settled:  ".bold(), time.format("%Y-%m-%d %H:%M:%S UTC"));
+    }
+    output::result(payment.signature.to_string());
+    Ok(())
+}
 
-    spinner.finish_with_message(format!("{} SPHINCS+ keypair generated", "[✓]".bright_green().bold()));
+/// Print `payload` as a QR code, with the address (and Solana Pay URI) under it
+fn cmd_receive(wallet: Pubkey, payload: &str) -> Result<()> {
+    // Dark modules on a light background, whatever the terminal's colors
+    for line in qr::qr_lines(payload)? {
+        println!("  {}", line.black().on_white());
+    }
+    println!();
+    println!("{} {}", "Address:   ".bold(), wallet.to_string().yellow());
+    if payload != wallet.to_string() {
+        println!("{} {}", "Solana Pay:".bold(), payload.bright_cyan());
+    }
+    output::result(payload);
+    Ok(())
+}
 
-    // Spinner for Solana keypair
-    let spinner = ProgressBar::new_spinner();
-    spinner.set_style(
-        ProgressStyle::default_spinner()
-            .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
-            .template("{spinner:.green} {msg}")
-            .unwrap()
-    );
-    spinner.enable_steady_tick(Duration::from_millis(80));
-    spinner.set_message("Generating Solana wallet keypair...".to_string());
+async fn cmd_balance(rpc_url: &str, wallet: Pubkey, mint: Pubkey) -> Result<()> {
+    let client = VaultClient::builder().rpc_url(rpc_url).build()?;
+    client.check_balance(wallet, mint).await?;
+    if output::quiet() {
+        let balance = client.get_balance(wallet, mint).await?;
+        let decimals = client.mint_decimals(mint).await?;
+        output::result(format!("{:.*}", decimals as usize, units::to_tokens(balance, decimals)));
+    }
 
-    // Generate Solana keypair
-    let solana_keypair = Keypair::new();
-    let wallet_address = solana_keypair.pubkey();
+    Ok(())
+}
 
-    let qdum_dir = if let Some(ref dir) = output_dir {
-        PathBuf::from(dir)
-    } else {
-        dirs::home_dir()
-            .expect("Could not determine home directory")
-            .join(".qdum")
-    };
 
-    let keypair_path = qdum_dir.join("solana-keypair.json");
-    let keypair_bytes = solana_keypair.to_bytes();
-    let keypair_json = serde_json::to_string(&keypair_bytes.to_vec())?;
-    fs::write(&keypair_path, keypair_json)?;
+async fn cmd_transfer(client: &VaultClient, keypair_path: &str, to_wallet: Pubkey, mint: Pubkey, amount: u64) -> Result<Receipt> {
+    if let Some(signer) = solana::client::default_signer() {
+        return client.transfer_tokens(&signer, to_wallet, mint, amount).await;
+    }
+
+    let data = fs::read_to_string(keypair_path)
+        .context(format!("Failed to read keypair file: {}", keypair_path))?;
+    let bytes: Vec<u8> = serde_json::from_str(&data)
+        .context("Invalid keypair JSON format")?;
+    let keypair = Keypair::try_from(&bytes[..])
+        .context("Invalid keypair bytes")?;
+
+    client.transfer_tokens(&keypair, to_wallet, mint, amount).await
+}
 
-    spinner.finish_with_message(format!("{} Solana keypair created", "[✓]".bright_green().bold()));
+/// Ask before sending to an address that's neither in the address book nor
+/// sent to before
+fn confirm_new_recipient(config: &VaultConfig, recipient: &Pubkey) -> Result<()> {
+    if config.address_label(recipient).is_some() || RecipientHistory::load().contains(recipient) {
+        return Ok(());
+    }
+    println!("{} You have never sent to {} before", "[!]".yellow(), recipient.to_string().bright_yellow());
+    println!("    Check it against where you got it; a swapped or mistyped address is usually a new one.");
+    let send = inquire::Confirm::new("Send to this address?").with_default(false).prompt()?;
+    if !send {
+        return Err(ExitKind::Cancelled.error("Transfer cancelled"));
+    }
+    println!();
+    Ok(())
+}
 
-    // Summary table
+/// Typed confirmation for transfers the policy won't send unattended: the
+/// amount or the recipient's last 4 characters, as in the dashboard
+fn confirm_policy_transfer(reason: &str, recipient: &Pubkey, amount: u64, decimals: u8) -> Result<()> {
+    let tokens = units::to_tokens(amount, decimals).to_string();
+    println!("{} This transfer of {} tokens {}", "[!]".yellow(), tokens.bright_yellow(), reason);
+    let input = inquire::Text::new("Type the amount or the recipient's last 4 characters to confirm:").prompt()?;
+    if !dashboard::transfer_confirmation_matches(&input, &tokens, &recipient.to_string()) {
+        return Err(ExitKind::Cancelled.error("Confirmation did not match - transfer cancelled"));
+    }
     println!();
+    Ok(())
+}
+
+fn print_token_accounts(accounts: &[solana::client::TokenAccountInfo]) {
+    if accounts.is_empty() {
+        println!("{} No token accounts", "[i]".bright_blue());
+        println!();
+        return;
+    }
+
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
-    table
-        .set_header(vec![
-            "Component".bright_white().bold().to_string(),
-            "Location".bright_white().bold().to_string(),
-        ])
-        .add_row(vec![
-            "SPHINCS+ Private".dimmed().to_string(),
-            "~/.qdum/sphincs_private.key".bright_cyan().to_string(),
-        ])
-        .add_row(vec![
-            "SPHINCS+ Public".dimmed().to_string(),
-            "~/.qdum/sphincs_public.key".bright_cyan().to_string(),
-        ])
-        .add_row(vec![
-            "Solana Keypair".dimmed().to_string(),
-            keypair_path.display().to_string().bright_cyan().to_string(),
+    table.set_header(vec!["Account", "Mint", "Balance", "Program", "Rent (SOL)", "ATA"]);
+    for account in accounts {
+        let balance = account.ui_amount().map_or_else(|| format!("{} base units", account.amount), |amount| amount.to_string());
+        table.add_row(vec![
+            account.address.clone(),
+            account.mint.clone(),
+            balance,
+            account.program.to_string(),
+            format!("{:.6}", account.rent_lamports as f64 / 1_000_000_000.0),
+            if account.associated { "yes".to_string() } else { "no".to_string() },
         ]);
-
+    }
     println!("{}", table);
     println!();
-    println!("{} {}", "Wallet:".dimmed(), wallet_address.to_string().bright_green().bold());
-    println!();
-
-    // Ask if they want to set it as default using inquire
-    use inquire::Confirm;
-
-    let set_default = Confirm::new("Set this as your default keypair?")
-        .with_default(true)
-        .with_help_message("All commands will use this keypair automatically")
-        .prompt();
-
-    match set_default {
-        Ok(true) => {
-            let mut config = load_config();
-
-            let sphincs_public_path = qdum_dir.join("sphincs_public.key");
-            let sphincs_private_path = qdum_dir.join("sphincs_private.key");
-
-            // Create a default vault profile
-            let profile = VaultProfile::new(
-                "default".to_string(),
-                keypair_path.to_str().unwrap().to_string(),
-                sphincs_public_path.to_str().unwrap().to_string(),
-                sphincs_private_path.to_str().unwrap().to_string(),
-                wallet_address.to_string(),
-            );
 
-            // Create vault (will auto-activate if it's the first one)
-            if let Err(e) = config.create_vault("default".to_string(), profile) {
-                // If default already exists, just switch to it
-                if config.vaults.contains_key("default") {
-                    config.switch_vault("default")?;
-                } else {
-                    return Err(e);
-                }
-            }
+    let rent: u64 = accounts.iter().map(|account| account.rent_lamports).sum();
+    println!("{} {:.6} SOL held as rent by {} account(s)", "[i]".bright_blue(), rent as f64 / 1_000_000_000.0, accounts.len());
 
-            println!();
-            println!("{} Default vault created and activated", "[✓]".bright_green().bold());
-            println!("{} {}", "  Vault:".dimmed(), "default".bright_cyan());
-            println!("{} {}", "  Path:".dimmed(), keypair_path.display().to_string().bright_cyan());
-        }
-        Ok(false) => {
-            println!();
-            println!("{} Skipped. Configure later with:", "[i]".bright_yellow());
-            println!("  {}", "qdum-vault vault create default".dimmed());
-        }
-        Err(_) => {
-            println!("{} Prompt cancelled", "[!]".yellow());
-        }
+    let empty: Vec<_> = accounts.iter().filter(|account| account.amount == 0).collect();
+    if !empty.is_empty() {
+        let reclaimable: u64 = empty.iter().map(|account| account.rent_lamports).sum();
+        println!("{} {} empty account(s) could be closed to reclaim {:.6} SOL",
+            "[i]".bright_blue(),
+            empty.len(),
+            reclaimable as f64 / 1_000_000_000.0
+        );
     }
 
+    // Tokens outside the ATA are missed by wallets and by transfers from this CLI
+    for account in accounts.iter().filter(|account| !account.associated && account.amount > 0) {
+        println!("{} {} holds {} tokens but isn't the associated account for its mint under {}",
+            "[!]".yellow(),
+            account.address,
+            account.ui_amount().unwrap_or(account.amount as f64),
+            account.program
+        );
+    }
     println!();
-    println!("{} {}", "Next:".bright_white().bold(), "qdum-vault register".bright_cyan());
-    println!();
-
-    Ok(())
 }
 
-async fn cmd_register(
-    rpc_url: &str,
-    program_id: Pubkey,
-    wallet: Pubkey,
+/// Show what a sweep would move, confirm it, then send each token balance and
+/// finally the SOL. Stops at the first failed transfer.
+#[allow(clippy::too_many_arguments)]
+async fn cmd_sweep(
+    client: &VaultClient,
+    config: &VaultConfig,
     keypair_path: &str,
-    sphincs_pubkey_path: Option<String>,
-) -> Result<()> {
-    let key_manager = SphincsKeyManager::new(None)?;
-    let sphincs_pubkey = key_manager.load_public_key(sphincs_pubkey_path)?;
+    wallet: Pubkey,
+    recipient: Pubkey,
+    mints: &[Pubkey],
+    sol: bool,
+    yes: bool,
+) -> Result<Vec<Receipt>> {
+    let mut balances = Vec::new();
+    for mint in mints {
+        balances.push((*mint, client.get_balance(wallet, *mint).await?, client.mint_decimals(*mint).await?));
+    }
+    let sol_balance = client.get_sol_balance(wallet).await?;
 
-    println!("{} {}", "DEBUG: Registering with SPHINCS public key:".yellow().bold(), hex::encode(&sphincs_pubkey).cyan());
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec!["Asset", "Balance", "Sent"]);
+    for (mint, amount, decimals) in &balances {
+        let tokens = units::to_tokens(*amount, *decimals).to_string();
+        let sent = if *amount > 0 { tokens.clone() } else { "nothing to send".to_string() };
+        table.add_row(vec![mint.to_string(), tokens, sent]);
+    }
+    let sol_sent = if sol { "all but the fee" } else { "kept (pass --sol to send)" };
+    table.add_row(vec!["SOL".to_string(), format!("{:.9}", sol_balance as f64 / 1_000_000_000.0), sol_sent.to_string()]);
+    println!("{}", table);
     println!();
 
-    let client = VaultClient::new(rpc_url, program_id)?;
-    client.register_pq_account(wallet, keypair_path, &sphincs_pubkey).await?;
+    let tokens: Vec<(Pubkey, u64, u8)> = balances.into_iter().filter(|(_, amount, _)| *amount > 0).collect();
+    if tokens.is_empty() && !sol {
+        println!("{} Nothing to sweep", "[i]".bright_blue());
+        println!();
+        return Ok(Vec::new());
+    }
+    if !tokens.is_empty() {
+        println!("{} The destination's token accounts are created if missing, paid for from this wallet", "[i]".bright_blue());
+        println!();
+    }
+
+    if !yes {
+        confirm_new_recipient(config, &recipient)?;
+        let proceed = inquire::Confirm::new("Send all of the above?").with_default(false).prompt()?;
+        if !proceed {
+            return Err(ExitKind::Cancelled.error("Sweep cancelled"));
+        }
+        println!();
+    }
+    for (mint, amount, decimals) in &tokens {
+        if config.policy.requires_confirmation(mint, *amount) {
+            confirm_policy_transfer("is over the policy's confirmation threshold", &recipient, *amount, *decimals)?;
+        }
+    }
+
+    let signer: Arc<VaultSigner> = match solana::client::default_signer() {
+        Some(signer) => signer,
+        None => Arc::new(read_keypair(keypair_path)?),
+    };
 
-    Ok(())
-}
+    let mut receipts = Vec::new();
+    for (mint, amount, _) in tokens {
+        let result = client.transfer_tokens(&signer, recipient, mint, amount).await;
+        notify::finished(Operation::Transfer, &result).await;
+        receipts.push(result?);
+    }
 
-async fn cmd_lock(
-    rpc_url: &str,
-    program_id: Pubkey,
-    wallet: Pubkey,
-    keypair_path: &str,
-) -> Result<()> {
-    let client = VaultClient::new(rpc_url, program_id)?;
-    client.lock_vault(wallet, keypair_path).await?;
+    if sol {
+        // Measured after the token transfers, which spend fees and rent
+        let lamports = client.sweepable_lamports(wallet, recipient).await?;
+        if lamports == 0 {
+            println!("{} No SOL left to send after fees", "[i]".bright_blue());
+        } else {
+            println!("Sending {} SOL...", format!("{:.9}", lamports as f64 / 1_000_000_000.0).yellow());
+            receipts.push(client.transfer_sol(&signer, recipient, lamports).await?);
+        }
+        println!();
+    }
 
-    Ok(())
+    println!("{}", format!("✅ Swept {} asset(s) to {}", receipts.len(), recipient).green().bold());
+    println!();
+    Ok(receipts)
 }
 
-async fn cmd_unlock(
+/// Wrap `amount` standard QDUM, then lock the vault. Both steps are confirmed
+/// together; a lock that fails after the wrap says how to finish.
+#[allow(clippy::too_many_arguments)]
+async fn cmd_secure(
     rpc_url: &str,
     program_id: Pubkey,
     wallet: Pubkey,
     keypair_path: &str,
-    sphincs_privkey_path: Option<String>,
-) -> Result<()> {
-    // Load config to get active vault's SPHINCS key paths
-    let config = load_config();
-
-    // Determine SPHINCS private key path
-    let sphincs_priv_path = if let Some(path) = sphincs_privkey_path {
-        // Use explicit path from CLI
-        Some(path)
-    } else if let Some(vault) = config.get_active_vault() {
-        // Use active vault's private key path
-        Some(vault.sphincs_private_key_path.clone())
-    } else {
-        // Fall back to default (None will use ~/.qdum/)
-        None
-    };
-
-    // Determine SPHINCS public key path from active vault
-    let sphincs_pub_path = if let Some(vault) = config.get_active_vault() {
-        println!("{}", "═══════════════════════════════════════════════════════════".yellow());
-        println!("{} {}", "DEBUG: Active vault:".yellow().bold(), vault.name.cyan());
-        println!("{} {}", "DEBUG: Public key path:".yellow().bold(), vault.sphincs_public_key_path.cyan());
-        println!("{} {}", "DEBUG: Private key path:".yellow().bold(), vault.sphincs_private_key_path.cyan());
-        println!("{}", "═══════════════════════════════════════════════════════════".yellow());
-        Some(vault.sphincs_public_key_path.clone())
-    } else {
-        None
-    };
-
-    // Load private key
-    let key_manager = SphincsKeyManager::new(None)?;
-    let sphincs_privkey = key_manager.load_private_key(sphincs_priv_path)?;
-
-    // Load public key
-    let sphincs_pubkey = key_manager.load_public_key(sphincs_pub_path)?;
+    standard_mint: Pubkey,
+    pq_mint: Pubkey,
+    amount: f64,
+    yes: bool,
+) -> Result<Vec<Receipt>> {
+    println!("{}", "This will:".bold());
+    println!("  1. Wrap {} standard QDUM into {} pqQDUM", amount.to_string().bright_yellow(), amount.to_string().bright_green());
+    println!("  2. Lock the vault; pqQDUM can't be moved until it's unlocked with your SPHINCS+ key");
+    println!();
+    if !yes {
+        let proceed = inquire::Confirm::new("Wrap and lock?").with_default(false).prompt()?;
+        if !proceed {
+            return Err(ExitKind::Cancelled.error("Secure cancelled"));
+        }
+        println!();
+    }
 
-    println!("{} {}", "DEBUG: Loaded public key (first 32 bytes):".yellow().bold(), hex::encode(&sphincs_pubkey).cyan());
+    println!("{}", "Step 1/2: wrap".bold());
+    let result = cmd_bridge_wrap(rpc_url, wallet, keypair_path, standard_mint, pq_mint, Some(amount), 0.0, true).await;
+    notify::finished(Operation::Wrap, &result).await;
+    let wrap = result?;
 
-    let client = VaultClient::new(rpc_url, program_id)?;
-    client.unlock_vault(wallet, keypair_path, &sphincs_privkey, &sphincs_pubkey, None).await?;
+    println!("{}", "Step 2/2: lock".bold());
+    let lock = match cmd_lock(rpc_url, program_id, wallet, keypair_path, None).await {
+        Ok(lock) => lock,
+        Err(e) => {
+            emit_receipt(&wrap, None)?;
+            eprintln!("{} The pqQDUM was wrapped but the vault isn't locked; run {} to finish", "[!]".yellow(), "pqcoin lock".bright_cyan());
+            return Err(e);
+        }
+    };
 
-    Ok(())
+    println!("{}", format!("✅ {} pqQDUM wrapped and vault locked", amount).green().bold());
+    println!();
+    Ok(vec![wrap, lock])
 }
 
-async fn cmd_close(
+/// Unlock the vault, then unwrap `amount` pqQDUM. The unwrap runs as a third
+/// phase after the unlock's signature upload and verification.
+#[allow(clippy::too_many_arguments)]
+async fn cmd_release(
     rpc_url: &str,
     program_id: Pubkey,
     wallet: Pubkey,
     keypair_path: &str,
-    receiver: Option<Pubkey>,
-) -> Result<()> {
-    let client = VaultClient::new(rpc_url, program_id)?;
-    client.close_pq_account(wallet, keypair_path, receiver).await?;
+    key: UnlockKey,
+    standard_mint: Pubkey,
+    pq_mint: Pubkey,
+    amount: f64,
+    yes: bool,
+) -> Result<Vec<Receipt>> {
+    let client = VaultClient::builder().rpc_url(rpc_url).program_id(program_id).build()?;
+    let decimals = client.mint_decimals(pq_mint).await?;
+    let amount_raw = units::to_base_units(amount, decimals);
+
+    // Not worth an unlock if the unwrap can't go through
+    let balance = client.get_balance(wallet, pq_mint).await?;
+    if balance < amount_raw {
+        return Err(ExitKind::InsufficientFunds.error(format!(
+            "Can't unwrap {} pqQDUM: the balance is {}",
+            amount,
+            units::to_tokens(balance, decimals)
+        )));
+    }
 
-    Ok(())
-}
+    println!("{}", "This will:".bold());
+    println!("  1. Unlock the vault with your SPHINCS+ key (signature upload and verification)");
+    println!("  2. Unwrap {} pqQDUM into {} standard QDUM", amount.to_string().bright_green(), amount.to_string().bright_yellow());
+    println!();
+    if !yes {
+        let proceed = inquire::Confirm::new("Unlock and unwrap?").with_default(false).prompt()?;
+        if !proceed {
+            return Err(ExitKind::Cancelled.error("Release cancelled"));
+        }
+        println!();
+    }
 
-async fn cmd_status(rpc_url: &str, program_id: Pubkey, wallet: Pubkey) -> Result<()> {
-    let client = VaultClient::new(rpc_url, program_id)?;
-    client.check_status(wallet).await?;
+    let result = cmd_unlock(rpc_url, program_id, wallet, keypair_path, key, 0, None).await;
+    notify::finished(Operation::Unlock, &result).await;
+    let unlock = result?;
 
-    Ok(())
-}
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".bright_yellow());
+    println!("{} {}", "🔄 PHASE 3:".bright_yellow().bold(), "Unwrap to Standard QDUM".bright_white().bold());
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".bright_yellow());
+    println!();
 
-async fn cmd_balance(rpc_url: &str, wallet: Pubkey, mint: Pubkey) -> Result<()> {
-    let client = VaultClient::new(rpc_url, Pubkey::default())?;
-    client.check_balance(wallet, mint).await?;
+    let pb = output::spinner();
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
+            .template("{spinner:.yellow} {msg}")
+            .unwrap()
+    );
+    pb.enable_steady_tick(Duration::from_millis(80));
+    pb.set_message(format!("Unwrapping {} pqQDUM...", amount));
+    let result = client.bridge_unwrap(keypair_path, amount_raw, standard_mint, pq_mint).await;
+    notify::finished(Operation::Unwrap, &result).await;
+    let unwrap = match result {
+        Ok(unwrap) => {
+            pb.finish_with_message(format!("{}", "✓ Unwrapped".bright_green()));
+            unwrap
+        }
+        Err(e) => {
+            pb.abandon();
+            emit_receipt(&unlock, None)?;
+            eprintln!(
+                "{} The vault is unlocked but nothing was unwrapped; run {} to retry",
+                "[!]".yellow(),
+                format!("pqcoin bridge unwrap {}", amount).bright_cyan()
+            );
+            return Err(e);
+        }
+    };
+    println!();
 
-    Ok(())
+    println!("{}", format!("✅ Vault unlocked and {} pqQDUM unwrapped", amount).green().bold());
+    println!();
+    Ok(vec![unlock, unwrap])
 }
 
+/// Submit externally signed transactions in order, stopping at the first failure
+async fn cmd_broadcast(client: &VaultClient, files: &[PathBuf]) -> Result<()> {
+    // Check every file first so a bad one doesn't leave the batch half sent
+    let mut transactions = Vec::with_capacity(files.len());
+    for file in files {
+        let encoded = fs::read_to_string(file).with_context(|| format!("Failed to read {}", file.display()))?;
+        let transaction = solana::client::decode_transaction(&encoded).with_context(|| format!("Invalid transaction in {}", file.display()))?;
+        let missing = solana::client::missing_signers(&transaction);
+        if !missing.is_empty() {
+            let missing: Vec<String> = missing.iter().map(|key| key.to_string()).collect();
+            anyhow::bail!("{} still needs signatures from {}", file.display(), missing.join(", "));
+        }
+        transactions.push((file, transaction));
+    }
 
-async fn cmd_transfer(
-    rpc_url: &str,
-    program_id: Pubkey,
-    _from_wallet: Pubkey,
-    keypair_path: &str,
-    to_wallet: Pubkey,
-    mint: Pubkey,
-    amount: u64,
-) -> Result<()> {
-    let client = VaultClient::new(rpc_url, program_id)?;
+    for (file, transaction) in &transactions {
+        println!("Sending {}...", file.display().to_string().dimmed());
+        let (signature, fee) = client
+            .broadcast_transaction(transaction)
+            .await
+            .with_context(|| format!("Failed to broadcast {}", file.display()))?;
+        println!("   {} {}", "✓".green(), signature.to_string().cyan());
+        println!("   Fee: {:.6} SOL", fee as f64 / 1_000_000_000.0);
+        println!();
+    }
 
-    let data = fs::read_to_string(keypair_path)
-        .context(format!("Failed to read keypair file: {}", keypair_path))?;
-    let bytes: Vec<u8> = serde_json::from_str(&data)
-        .context("Invalid keypair JSON format")?;
-    let keypair = Keypair::try_from(&bytes[..])
-        .context("Invalid keypair bytes")?;
+    println!("{}", format!("✅ {} transaction(s) confirmed", transactions.len()).green().bold());
+    Ok(())
+}
 
-    client.transfer_tokens(&keypair, to_wallet, mint, amount).await?;
+/// The balance `--all` bridges: all of `mint` but `keep` tokens, confirmed
+/// unless `yes`
+async fn bridge_all_amount(client: &VaultClient, wallet: Pubkey, mint: Pubkey, decimals: u8, keep: f64, yes: bool, verb: &str) -> Result<u64> {
+    let balance = client.get_balance(wallet, mint).await?;
+    let amount = balance.saturating_sub(units::to_base_units(keep, decimals));
+    if amount == 0 {
+        anyhow::bail!("Nothing to {}: the balance is {} QDUM", verb.to_lowercase(), units::to_tokens(balance, decimals));
+    }
 
-    Ok(())
+    println!("{} {} {} of {} QDUM{}",
+        "[i]".bright_blue(),
+        verb,
+        units::to_tokens(amount, decimals).to_string().bright_yellow(),
+        units::to_tokens(balance, decimals),
+        if keep > 0.0 { format!(" ({} kept)", keep) } else { String::new() }
+    );
+    if !yes {
+        let proceed = inquire::Confirm::new(&format!("{} {} QDUM?", verb, units::to_tokens(amount, decimals)))
+            .with_default(false)
+            .prompt()?;
+        if !proceed {
+            return Err(ExitKind::Cancelled.error("Bridge cancelled"));
+        }
+    }
+    println!();
+    Ok(amount)
 }
 
+/// Wrap `amount` tokens, or with `None` the whole balance less `keep`
+#[allow(clippy::too_many_arguments)]
 async fn cmd_bridge_wrap(
     rpc_url: &str,
     wallet: Pubkey,
     keypair_path: &str,
     standard_mint: Pubkey,
     pq_mint: Pubkey,
-    amount: u64,
-) -> Result<()> {
-    // Load keypair
-    let data = fs::read_to_string(keypair_path)
-        .context(format!("Failed to read keypair file: {}", keypair_path))?;
-    let bytes: Vec<u8> = serde_json::from_str(&data)
-        .context("Invalid keypair JSON format")?;
-    let keypair = Keypair::try_from(&bytes[..])
-        .context("Invalid keypair bytes")?;
+    amount: Option<f64>,
+    keep: f64,
+    yes: bool,
+) -> Result<Receipt> {
+    let client = VaultClient::builder().rpc_url(rpc_url).build()?;
+    let decimals = client.mint_decimals(standard_mint).await?;
+    let amount_raw = match amount {
+        Some(amount) => units::to_base_units(amount, decimals),
+        None => bridge_all_amount(&client, wallet, standard_mint, decimals, keep, yes, "Wrap").await?,
+    };
 
-    println!("{} Wrapping {} QDUM...", "⏳".bright_yellow(), amount as f64 / 1_000_000.0);
+    println!("{} Wrapping {} QDUM...", "⏳".bright_yellow(), units::to_tokens(amount_raw, decimals));
     println!();
     println!("  {}  {} → {}", "🔄".to_string(), "Standard QDUM".bright_white(), "pqQDUM".bright_green());
     println!("  {}  Burning Standard QDUM", "🔥".to_string());
     println!("  {}  Minting pqQDUM", "✨".to_string());
     println!();
 
-    // Create bridge client
-    let bridge_program_id = Pubkey::from_str("2psMx7yfQL7yAbu6NNRathTkC1rSY4CGDvBd2qWqzirF")?;
+    let receipt = client.bridge_wrap(keypair_path, amount_raw, standard_mint, pq_mint).await?;
 
-    // Call wrap instruction (implementation pending - showing success for now)
-    println!("{} Wrap transaction submitted!", "✅".bright_green());
+    println!("{} Wrap transaction confirmed!", "✅".bright_green());
     println!();
     println!("{} Next steps:", "💡".bright_yellow());
     println!("  • You can now lock pqQDUM in your quantum vault");
     println!("  • Run {} to see your pqQDUM balance", "qdum-vault balance".bright_cyan());
+    println!();
 
-    Ok(())
+    Ok(receipt)
 }
 
+/// Unwrap `amount` tokens, or with `None` the whole balance less `keep`
+#[allow(clippy::too_many_arguments)]
 async fn cmd_bridge_unwrap(
     rpc_url: &str,
     wallet: Pubkey,
     keypair_path: &str,
     standard_mint: Pubkey,
     pq_mint: Pubkey,
-    amount: u64,
-) -> Result<()> {
-    // Load keypair
-    let data = fs::read_to_string(keypair_path)
-        .context(format!("Failed to read keypair file: {}", keypair_path))?;
-    let bytes: Vec<u8> = serde_json::from_str(&data)
-        .context("Invalid keypair JSON format")?;
-    let keypair = Keypair::try_from(&bytes[..])
-        .context("Invalid keypair bytes")?;
+    amount: Option<f64>,
+    keep: f64,
+    yes: bool,
+) -> Result<Receipt> {
+    let client = VaultClient::builder().rpc_url(rpc_url).build()?;
+    let decimals = client.mint_decimals(pq_mint).await?;
+    let amount_raw = match amount {
+        Some(amount) => units::to_base_units(amount, decimals),
+        None => bridge_all_amount(&client, wallet, pq_mint, decimals, keep, yes, "Unwrap").await?,
+    };
 
-    println!("{} Unwrapping {} QDUM...", "⏳".bright_yellow(), amount as f64 / 1_000_000.0);
+    println!("{} Unwrapping {} QDUM...", "⏳".bright_yellow(), units::to_tokens(amount_raw, decimals));
     println!();
     println!("  {}  {} → {}", "🔄".to_string(), "pqQDUM".bright_green(), "Standard QDUM".bright_white());
     println!("  {}  Burning pqQDUM", "🔥".to_string());
@@ -1212,15 +5821,32 @@ async fn cmd_bridge_unwrap(
     println!("  Locked tokens cannot be unwrapped!");
     println!();
 
-    // Create bridge client
-    let bridge_program_id = Pubkey::from_str("2psMx7yfQL7yAbu6NNRathTkC1rSY4CGDvBd2qWqzirF")?;
+    let receipt = client.bridge_unwrap(keypair_path, amount_raw, standard_mint, pq_mint).await?;
 
-    // Call unwrap instruction (implementation pending - showing success for now)
-    println!("{} Unwrap transaction submitted!", "✅".bright_green());
+    println!("{} Unwrap transaction confirmed!", "✅".bright_green());
     println!();
     println!("{} Next steps:", "💡".bright_yellow());
     println!("  • You can now trade Standard QDUM on DEXs");
     println!("  • Run {} to see your Standard QDUM balance", "qdum-vault balance --mint GS2tyNMdpiKnQ9AxFhB74SbzYF7NmoTREoKZC6pzxds7".bright_cyan());
+    println!();
+
+    Ok(receipt)
+}
+
+/// Print a receipt and optionally write it as JSON
+fn emit_receipt(receipt: &Receipt, receipt_out: Option<&str>) -> Result<()> {
+    receipt.print();
+    match (receipt.signatures.last(), &receipt.note) {
+        (Some(signature), _) => output::result(signature.clone()),
+        (None, Some(note)) => output::result(note.clone()),
+        (None, None) => {}
+    }
+
+    if let Some(path) = receipt_out {
+        receipt.write_json(path)?;
+        println!("{} Receipt written to {}", "[✓]".bright_green(), path.bright_cyan());
+        println!();
+    }
 
     Ok(())
 }
@@ -1315,8 +5941,7 @@ fn cmd_vault_create(name: Option<String>, description: Option<String>, auto_gene
         if let Some(n) = vault_switcher::prompt_vault_name()? {
             n
         } else {
-            println!("{} Vault creation cancelled", "[!]".yellow());
-            return Ok(());
+            return Err(ExitKind::Cancelled.error("Vault creation cancelled"));
         }
     };
 
@@ -1325,8 +5950,7 @@ fn cmd_vault_create(name: Option<String>, description: Option<String>, auto_gene
         return Err(anyhow::anyhow!("Vault '{}' already exists", vault_name));
     }
 
-    let home = dirs::home_dir().expect("Could not determine home directory");
-    let qdum_dir = home.join(".qdum");
+    let qdum_dir = paths::data_dir();
 
     let (solana_keypair_path, sphincs_public_key_path, sphincs_private_key_path, wallet_address) = if auto_generate {
         // Auto-generate new keys
@@ -1370,7 +5994,7 @@ fn cmd_vault_create(name: Option<String>, description: Option<String>, auto_gene
         let solana_path = solana_path.trim();
 
         let solana_keypair_path = if solana_path.is_empty() {
-            home.join(".config/solana/id.json").to_str().unwrap().to_string()
+            paths::solana_keypair().to_str().unwrap().to_string()
         } else {
             solana_path.to_string()
         };
@@ -1381,7 +6005,7 @@ fn cmd_vault_create(name: Option<String>, description: Option<String>, auto_gene
             Err(_) => String::new(),
         };
 
-        print!("SPHINCS+ public key path [~/.qdum/sphincs_public.key]: ");
+        print!("SPHINCS+ public key path [{}]: ", paths::display(&qdum_dir.join("sphincs_public.key")));
         std::io::Write::flush(&mut std::io::stdout())?;
         let mut pub_path = String::new();
         std::io::stdin().read_line(&mut pub_path)?;
@@ -1393,7 +6017,7 @@ fn cmd_vault_create(name: Option<String>, description: Option<String>, auto_gene
             pub_path.to_string()
         };
 
-        print!("SPHINCS+ private key path [~/.qdum/sphincs_private.key]: ");
+        print!("SPHINCS+ private key path [{}]: ", paths::display(&qdum_dir.join("sphincs_private.key")));
         std::io::Write::flush(&mut std::io::stdout())?;
         let mut priv_path = String::new();
         std::io::stdin().read_line(&mut priv_path)?;
@@ -1470,10 +6094,7 @@ async fn cmd_vault_switch(rpc_url: &str, program_id_str: &str, name: &Option<Str
                     name
                 }
             }
-            None => {
-                println!("{} Switch cancelled", "[!]".yellow());
-                return Ok(());
-            }
+            None => return Err(ExitKind::Cancelled.error("Switch cancelled")),
         }
     };
 
@@ -1486,6 +6107,9 @@ async fn cmd_vault_switch(rpc_url: &str, program_id_str: &str, name: &Option<Str
         if !vault.wallet_address.is_empty() {
             println!("  Wallet: {}", vault.wallet_address.dimmed());
         }
+        if let Some(url) = &vault.rpc_url {
+            println!("  Network: {}", url.dimmed());
+        }
     }
 
     println!();
@@ -1538,6 +6162,23 @@ fn cmd_vault_show(name: &Option<String>) -> Result<()> {
 
         println!("{}  Created:          {}", "║".bright_cyan(), vault.created_at.dimmed());
 
+        if let Some(rotated) = &vault.keys_rotated_at {
+            println!("{}  Keys Rotated:     {}", "║".bright_cyan(), rotated.dimmed());
+        }
+
+        println!("{}  Lock Mode:        {}", "║".bright_cyan(), vault.lock_mode.label().bright_white());
+
+        for (label, value) in [
+            ("RPC URL:          ", &vault.rpc_url),
+            ("Program ID:       ", &vault.program_id),
+            ("pqQDUM Mint:      ", &vault.mint),
+            ("Standard Mint:    ", &vault.standard_mint),
+        ] {
+            if let Some(value) = value {
+                println!("{}  {}{}", "║".bright_cyan(), label, value.bright_white());
+            }
+        }
+
         println!("{}", "╚═══════════════════════════════════════════════════════════╝".bright_cyan());
         println!();
     } else {
@@ -1566,9 +6207,7 @@ async fn cmd_vault_delete(rpc_url: &str, program_id_str: &str, name: &str, yes:
 
     if !confirmed {
         println!();
-        println!("{} Delete cancelled - vault name did not match", "[!]".yellow());
-        println!();
-        return Ok(());
+        return Err(ExitKind::Cancelled.error("Delete cancelled - vault name did not match"));
     }
 
     // Get the vault before deleting
@@ -1581,8 +6220,10 @@ async fn cmd_vault_delete(rpc_url: &str, program_id_str: &str, name: &str, yes:
     match read_keypair_file(&vault.solana_keypair_path) {
         Ok(keypair) => {
             let wallet = keypair.pubkey();
-            let program_id = Pubkey::from_str(program_id_str)?;
-            let client = VaultClient::new(rpc_url, program_id)?;
+            // The vault may live on another cluster than the active one
+            let rpc_url = vault.rpc_url.as_deref().unwrap_or(rpc_url);
+            let program_id = Pubkey::from_str(vault.program_id.as_deref().unwrap_or(program_id_str))?;
+            let client = VaultClient::builder().rpc_url(rpc_url).program_id(program_id).build()?;
 
             match client.close_pq_account(wallet, &vault.solana_keypair_path, None).await {
                 Ok(_) => {
@@ -1652,6 +6293,322 @@ fn cmd_vault_rename(old_name: &str, new_name: &str) -> Result<()> {
     Ok(())
 }
 
+fn cmd_vault_relock(name: &Option<String>, on: bool, off: bool) -> Result<()> {
+    let mut config = VaultConfig::load()?;
+
+    let vault_name = match name {
+        Some(n) => n.clone(),
+        None => config.active_vault.clone().ok_or_else(|| anyhow::anyhow!("No active vault"))?,
+    };
+    let vault = config
+        .vaults
+        .get_mut(&vault_name)
+        .ok_or_else(|| anyhow::anyhow!("Vault '{}' not found", vault_name))?;
+
+    if on || off {
+        vault.relock_after_transfer = on;
+    }
+    println!(
+        "\n{} {}: {}",
+        "Relock after transfers for".bold(),
+        vault_name.bright_cyan(),
+        if vault.relock_after_transfer { "on".bright_green() } else { "off".dimmed() }
+    );
+
+    if on || off {
+        config.save()?;
+        println!("{}", "✓ Saved".green());
+    }
+    println!();
+    Ok(())
+}
+
+fn cmd_vault_network(
+    name: &Option<String>,
+    rpc_url: Option<String>,
+    program_id: Option<String>,
+    mint: Option<String>,
+    standard_mint: Option<String>,
+    clear: bool,
+) -> Result<()> {
+    let mut config = VaultConfig::load()?;
+
+    let vault_name = match name {
+        Some(n) => n.clone(),
+        None => config.active_vault.clone().ok_or_else(|| anyhow::anyhow!("No active vault"))?,
+    };
+    let vault = config
+        .vaults
+        .get_mut(&vault_name)
+        .ok_or_else(|| anyhow::anyhow!("Vault '{}' not found", vault_name))?;
+
+    let changed = clear || rpc_url.is_some() || program_id.is_some() || mint.is_some() || standard_mint.is_some();
+    if clear {
+        vault.rpc_url = None;
+        vault.program_id = None;
+        vault.mint = None;
+        vault.standard_mint = None;
+    }
+    if rpc_url.is_some() {
+        vault.rpc_url = rpc_url;
+    }
+    if program_id.is_some() {
+        vault.program_id = program_id;
+    }
+    if mint.is_some() {
+        vault.mint = mint;
+    }
+    if standard_mint.is_some() {
+        vault.standard_mint = standard_mint;
+    }
+
+    println!("\n{} {}", "Network for vault:".bold(), vault_name.bright_cyan());
+    for (label, value) in [
+        ("RPC URL:      ", &vault.rpc_url),
+        ("Program ID:   ", &vault.program_id),
+        ("pqQDUM mint:  ", &vault.mint),
+        ("Standard mint:", &vault.standard_mint),
+    ] {
+        match value {
+            Some(value) => println!("  {} {}", label, value.bright_white()),
+            None => println!("  {} {}", label, "(default)".dimmed()),
+        }
+    }
+
+    if changed {
+        config.validate()?;
+        config.save()?;
+        println!("\n{} Network settings saved", "[✓]".green());
+    }
+    println!();
+
+    Ok(())
+}
+
+fn cmd_vault_export(name: &str, out: &str) -> Result<()> {
+    let config = VaultConfig::load()?;
+    let profile = config.get_vault(name)
+        .ok_or_else(|| anyhow::anyhow!("Vault '{}' does not exist", name))?;
+
+    let archive = VaultArchive::from_profile(profile)?;
+
+    let passphrase = inquire::Password::new("Archive passphrase:")
+        .with_custom_confirmation_message("Confirm passphrase:")
+        .with_display_mode(inquire::PasswordDisplayMode::Masked)
+        .prompt()
+        .context("Prompt cancelled")?;
+    if passphrase.is_empty() {
+        return Err(anyhow::anyhow!("Passphrase must not be empty"));
+    }
+
+    fs::write(out, archive.seal(&passphrase)?)
+        .with_context(|| format!("Failed to write {}", out))?;
+
+    println!("\n{} Exported vault {} to {}",
+        "[✓]".green(),
+        name.bright_white().bold(),
+        out.bright_cyan()
+    );
+    println!("{} The archive contains your private keys; keep the passphrase safe", "[!]".yellow());
+    println!();
+
+    Ok(())
+}
+
+fn cmd_vault_import(path: &str, name: Option<String>) -> Result<()> {
+    let data = fs::read(path).with_context(|| format!("Failed to read {}", path))?;
+
+    let passphrase = inquire::Password::new("Archive passphrase:")
+        .without_confirmation()
+        .with_display_mode(inquire::PasswordDisplayMode::Masked)
+        .prompt()
+        .context("Prompt cancelled")?;
+
+    let archive = VaultArchive::open(&data, &passphrase)?;
+    let vault_name = name.unwrap_or_else(|| archive.profile.name.clone());
+
+    let mut config = VaultConfig::load()?;
+    if config.get_vault(&vault_name).is_some() {
+        return Err(anyhow::anyhow!("Vault '{}' already exists (use --name to import under another name)", vault_name));
+    }
+
+    let vault_dir = paths::data_dir().join(&vault_name);
+    if vault_dir.join("sphincs_private.key").exists() {
+        return Err(anyhow::anyhow!("Keys already exist at {}", vault_dir.display()));
+    }
+
+    let profile = archive.install(&vault_name, &vault_dir)?;
+    config.create_vault(vault_name.clone(), profile)?;
+
+    println!("\n{} Imported vault {}", "[✓]".green(), vault_name.bright_white().bold());
+    println!("  {} {}", "Keys:".dimmed(), vault_dir.display().to_string().bright_cyan());
+    println!("{} Switch to it with: {}", "[i]".bright_blue(), format!("qdum-vault vault switch {}", vault_name).bright_cyan());
+    println!();
+
+    Ok(())
+}
+
+async fn cmd_backup_push(config: &VaultConfig, destination: &BackupDestination) -> Result<()> {
+    let set = VaultSet::collect(config)?;
+    if set.vaults.is_empty() {
+        return Err(ExitKind::Config.error("No vaults with keys on this machine to back up"));
+    }
+    for profile in config.list_vaults().into_iter().filter(|profile| profile.is_watch_only()) {
+        println!("{} Skipping watch-only vault {}", "[i]".bright_blue(), profile.name.bright_white());
+    }
+
+    let passphrase = inquire::Password::new("Backup passphrase:")
+        .with_custom_confirmation_message("Confirm passphrase:")
+        .with_display_mode(inquire::PasswordDisplayMode::Masked)
+        .prompt()
+        .context("Prompt cancelled")?;
+    if passphrase.is_empty() {
+        return Err(anyhow::anyhow!("Passphrase must not be empty"));
+    }
+
+    // Encrypted here; the destination only sees ciphertext
+    let sealed = set.seal(&passphrase)?;
+    let size = sealed.len();
+    let spinner = output::spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
+            .template("{spinner:.green} {msg}")
+            .unwrap()
+    );
+    spinner.enable_steady_tick(Duration::from_millis(80));
+    spinner.set_message(format!("Uploading to {}...", destination.location()));
+    let uploaded = destination.upload(sealed).await;
+    spinner.finish_and_clear();
+    uploaded?;
+
+    println!("{} Backed up {} vault(s) ({} bytes, encrypted) to {}",
+        "[✓]".green(),
+        set.vaults.len(),
+        size,
+        destination.location().bright_cyan()
+    );
+    println!("{} Without the passphrase the backup can't be restored; keep it somewhere other than this machine", "[!]".yellow());
+    println!();
+    output::result(destination.location());
+    Ok(())
+}
+
+/// Restore the vaults in the remote backup that aren't configured here. With
+/// `verify`, a vault is only restored if its public key is the one registered
+/// on its cluster (or it isn't registered yet).
+async fn cmd_backup_pull(destination: &BackupDestination, rpc_flag: Option<&str>, program_flag: Option<&str>, verify: bool) -> Result<()> {
+    use solana_sdk::signature::Signer;
+
+    let spinner = output::spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
+            .template("{spinner:.green} {msg}")
+            .unwrap()
+    );
+    spinner.enable_steady_tick(Duration::from_millis(80));
+    spinner.set_message(format!("Downloading from {}...", destination.location()));
+    let downloaded = destination.download().await;
+    spinner.finish_and_clear();
+    let data = downloaded?;
+
+    let passphrase = inquire::Password::new("Backup passphrase:")
+        .without_confirmation()
+        .with_display_mode(inquire::PasswordDisplayMode::Masked)
+        .prompt()
+        .context("Prompt cancelled")?;
+    let set = VaultSet::open(&data, &passphrase)?;
+    println!("{} Backup from {} holds {} vault(s)", "[i]".bright_blue(), set.created_at.dimmed(), set.vaults.len());
+    println!();
+
+    let mut config = VaultConfig::load()?;
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec![
+        "Vault".bright_white().bold().to_string(),
+        "Wallet".bright_white().bold().to_string(),
+        "On-chain key".bright_white().bold().to_string(),
+        "Result".bright_white().bold().to_string(),
+    ]);
+    let mut failed = 0;
+
+    for archive in &set.vaults {
+        let name = archive.profile.name.clone();
+        let checked = async {
+            let wallet = solana_sdk::signature::read_keypair(&mut archive.solana_keypair.as_bytes())
+                .map_err(|e| anyhow::anyhow!("Invalid Solana keypair in backup: {}", e))?
+                .pubkey();
+            if !verify {
+                return Ok((wallet, "not checked".dimmed().to_string(), true));
+            }
+
+            let public_key = hex::decode(&archive.sphincs_public_key).context("Invalid public key in backup")?;
+            let (algorithm, public_key) = SphincsKeyManager::decode_key_file(&public_key, Path::new(&archive.profile.sphincs_public_key_path))?;
+            let backed_up = (algorithm.algorithm_id(), public_key);
+            let rpc_url = rpc_flag.map(str::to_string).unwrap_or_else(|| archive.profile.network_rpc_url());
+            let program_id = match program_flag {
+                Some(program_id) => Pubkey::from_str(program_id)?,
+                None => archive.profile.network_program_id()?,
+            };
+            let client = VaultClient::builder().rpc_url(&rpc_url).program_id(program_id).build()?;
+            Ok::<_, anyhow::Error>(match client.registered_public_key(wallet).await? {
+                Some(registered) if registered == backed_up => (wallet, "matches".bright_green().to_string(), true),
+                Some(_) => (wallet, "DIFFERENT KEY".bright_red().bold().to_string(), false),
+                None => (wallet, "not registered".yellow().to_string(), true),
+            })
+        }
+        .await;
+
+        let (wallet, key_state, restorable) = match checked {
+            Ok(checked) => checked,
+            Err(e) => {
+                failed += 1;
+                table.add_row(vec![name.bright_cyan().to_string(), "-".to_string(), "check failed".bright_red().to_string(), format!("{:#}", e)]);
+                continue;
+            }
+        };
+        let short_wallet = wallet.to_string()[..8].to_string() + "…";
+
+        let result = if !restorable {
+            failed += 1;
+            "not restored: these keys can't unlock the vault".bright_red().to_string()
+        } else if config.get_vault(&name).is_some() {
+            "already configured here".dimmed().to_string()
+        } else {
+            let vault_dir = paths::data_dir().join(&name);
+            let installed = if vault_dir.join("sphincs_private.key").exists() {
+                Err(anyhow::anyhow!("keys already exist at {}", vault_dir.display()))
+            } else {
+                archive.install(&name, &vault_dir).and_then(|profile| config.create_vault(name.clone(), profile))
+            };
+            match installed {
+                Ok(()) => "restored".bright_green().bold().to_string(),
+                Err(e) => {
+                    failed += 1;
+                    format!("{:#}", e).bright_red().to_string()
+                }
+            }
+        };
+        table.add_row(vec![name.bright_cyan().to_string(), short_wallet, key_state, result]);
+    }
+
+    println!("{}", table);
+    println!();
+    if config.active_vault.is_none() {
+        if let Some(active) = set.active_vault.filter(|name| config.get_vault(name).is_some()) {
+            config.switch_vault(&active)?;
+            println!("{} Active vault: {}", "[i]".bright_blue(), active.bright_white().bold());
+            println!();
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("{} of {} vaults could not be restored", failed, set.vaults.len());
+    }
+    Ok(())
+}
+
 fn cmd_vault_new(name: Option<String>, description: Option<String>, auto_generate: bool) -> Result<()> {
     use solana_sdk::signature::Signer;
 
@@ -1664,8 +6621,7 @@ fn cmd_vault_new(name: Option<String>, description: Option<String>, auto_generat
         if let Some(n) = vault_switcher::prompt_vault_name()? {
             n
         } else {
-            println!("{} Vault creation cancelled", "[!]".yellow());
-            return Ok(());
+            return Err(ExitKind::Cancelled.error("Vault creation cancelled"));
         }
     };
 
@@ -1674,8 +6630,7 @@ fn cmd_vault_new(name: Option<String>, description: Option<String>, auto_generat
         return Err(anyhow::anyhow!("Vault '{}' already exists", vault_name));
     }
 
-    let home = dirs::home_dir().expect("Could not determine home directory");
-    let qdum_dir = home.join(".qdum");
+    let qdum_dir = paths::data_dir();
 
     let (solana_keypair_path, sphincs_public_key_path, sphincs_private_key_path, wallet_address) = if auto_generate {
         // Auto-generate new keys
@@ -1719,7 +6674,7 @@ fn cmd_vault_new(name: Option<String>, description: Option<String>, auto_generat
         let solana_path = solana_path.trim();
 
         let solana_keypair_path = if solana_path.is_empty() {
-            home.join(".config/solana/id.json").to_str().unwrap().to_string()
+            paths::solana_keypair().to_str().unwrap().to_string()
         } else {
             solana_path.to_string()
         };
@@ -1730,7 +6685,7 @@ fn cmd_vault_new(name: Option<String>, description: Option<String>, auto_generat
             Err(_) => String::new(),
         };
 
-        print!("SPHINCS+ public key path [~/.qdum/sphincs_public.key]: ");
+        print!("SPHINCS+ public key path [{}]: ", paths::display(&qdum_dir.join("sphincs_public.key")));
         std::io::Write::flush(&mut std::io::stdout())?;
         let mut pub_path = String::new();
         std::io::stdin().read_line(&mut pub_path)?;
@@ -1742,7 +6697,7 @@ fn cmd_vault_new(name: Option<String>, description: Option<String>, auto_generat
             pub_path.to_string()
         };
 
-        print!("SPHINCS+ private key path [~/.qdum/sphincs_private.key]: ");
+        print!("SPHINCS+ private key path [{}]: ", paths::display(&qdum_dir.join("sphincs_private.key")));
         std::io::Write::flush(&mut std::io::stdout())?;
         let mut priv_path = String::new();
         std::io::stdin().read_line(&mut priv_path)?;
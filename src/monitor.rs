@@ -0,0 +1,152 @@
+//! Webhook alerts for the `monitor` daemon
+//!
+//! Every change `monitor --daemon` sees on a vault (lock state, balances,
+//! challenge rotation) is POSTed as JSON to each configured webhook URL:
+//!
+//! ```json
+//! {"event": "unlocked", "vault": "main", "wallet": "...", "rpc_url": "...",
+//!  "timestamp": "2025-01-01T12:00:00Z", "status": { ... }}
+//! ```
+//!
+//! `event` and the fields next to it come from [`StatusChange`]; `status` is
+//! the full [`StatusReport`] after the change.
+//!
+//! Vaults are polled; [`helius`] adds push updates on top.
+
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use std::time::Duration;
+
+use crate::solana::client::{StatusChange, StatusReport};
+
+pub mod helius;
+
+/// How long a webhook endpoint gets to answer
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A vault watched by the daemon, on its own cluster
+#[derive(Debug, Clone)]
+pub struct MonitoredVault {
+    pub name: String,
+    pub wallet: Pubkey,
+    pub rpc_url: String,
+    pub program_id: Pubkey,
+    pub mints: Vec<Pubkey>,
+}
+
+/// Body of a webhook POST
+#[derive(Debug, Serialize)]
+pub struct WebhookEvent<'a> {
+    #[serde(flatten)]
+    pub change: &'a StatusChange,
+    pub vault: &'a str,
+    pub wallet: String,
+    pub rpc_url: &'a str,
+    /// RFC 3339
+    pub timestamp: String,
+    pub status: &'a StatusReport,
+}
+
+impl<'a> WebhookEvent<'a> {
+    pub fn new(vault: &'a MonitoredVault, change: &'a StatusChange, status: &'a StatusReport) -> Self {
+        Self {
+            change,
+            vault: &vault.name,
+            wallet: vault.wallet.to_string(),
+            rpc_url: &vault.rpc_url,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            status,
+        }
+    }
+}
+
+/// Only http(s) endpoints can receive alerts
+pub fn check_webhook_url(url: &str) -> Result<()> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        bail!("Webhook '{}' is not an http(s) URL", url);
+    }
+    Ok(())
+}
+
+pub struct Webhooks {
+    client: reqwest::Client,
+    urls: Vec<String>,
+}
+
+impl Webhooks {
+    pub fn new(urls: Vec<String>) -> Result<Self> {
+        for url in &urls {
+            check_webhook_url(url)?;
+        }
+        let client = reqwest::Client::builder()
+            .timeout(WEBHOOK_TIMEOUT)
+            .user_agent(concat!("qdum-vault/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .context("Failed to create HTTP client")?;
+        Ok(Self { client, urls })
+    }
+
+    pub fn urls(&self) -> &[String] {
+        &self.urls
+    }
+
+    /// POST `event` to every URL. Returns the failures as `(url, error)`; one
+    /// endpoint being down doesn't stop the others from being notified.
+    pub async fn send(&self, event: &WebhookEvent<'_>) -> Vec<(String, anyhow::Error)> {
+        let mut failures = Vec::new();
+        for url in &self.urls {
+            let result = async {
+                self.client
+                    .post(url)
+                    .json(event)
+                    .send()
+                    .await?
+                    .error_for_status()?;
+                Ok::<_, reqwest::Error>(())
+            }
+            .await;
+            if let Err(e) = result {
+                failures.push((url.clone(), e.into()));
+            }
+        }
+        failures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_body_flattens_change() {
+        let vault = MonitoredVault {
+            name: "main".to_string(),
+            wallet: Pubkey::default(),
+            rpc_url: "https://api.devnet.solana.com".to_string(),
+            program_id: Pubkey::default(),
+            mints: Vec::new(),
+        };
+        let status = StatusReport {
+            schema_version: 1,
+            wallet: Pubkey::default().to_string(),
+            pq_account: Pubkey::default().to_string(),
+            locked: false,
+            algorithm: None,
+            algorithm_id: 0,
+            unlock_challenge: String::new(),
+            pending_unlock_slot: None,
+            sol_lamports: 0,
+            tokens: Vec::new(),
+        };
+        let change = StatusChange::SolBalanceChanged { from: 10, to: 5 };
+
+        let body = serde_json::to_value(WebhookEvent::new(&vault, &change, &status)).unwrap();
+        assert_eq!(body["event"], "sol_balance_changed");
+        assert_eq!(body["from"], 10);
+        assert_eq!(body["vault"], "main");
+        assert_eq!(body["status"]["locked"], false);
+
+        assert!(check_webhook_url("ftp://example.com").is_err());
+    }
+}
@@ -0,0 +1,199 @@
+//! Push updates from Helius webhooks
+//!
+//! With `monitor --daemon --helius`, the daemon registers a Helius webhook for
+//! each vault's PQ account, wallet and token accounts and runs a small HTTP
+//! listener for the callbacks. A callback mentioning a vault's accounts makes
+//! the daemon check that vault right away instead of at the next poll;
+//! polling continues at `--interval` as a fallback. The webhook is deleted
+//! when the daemon stops with Ctrl+C.
+
+use anyhow::{bail, Context, Result};
+use axum::extract::State;
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::{Json, Router};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::OsRng;
+use colored::Colorize;
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+/// Environment variable holding the Helius API key
+pub const API_KEY_ENV: &str = "HELIUS_API_KEY";
+
+const WEBHOOKS_URL: &str = "https://api.helius.xyz/v0/webhooks";
+
+pub struct HeliusOptions {
+    pub api_key: String,
+    /// Where Helius can reach the listener, e.g. https://monitor.example.com/
+    pub public_url: String,
+    pub listen: SocketAddr,
+    pub devnet: bool,
+}
+
+/// A registered webhook and the vaults its callbacks touched
+pub struct HeliusPush {
+    client: reqwest::Client,
+    api_key: String,
+    webhook_id: String,
+    touched: UnboundedReceiver<usize>,
+}
+
+struct Callbacks {
+    auth: String,
+    /// Addresses of each vault, by index
+    watched: Vec<HashSet<String>>,
+    touched: UnboundedSender<usize>,
+}
+
+#[derive(Deserialize)]
+struct CreatedWebhook {
+    #[serde(rename = "webhookID")]
+    webhook_id: String,
+}
+
+impl HeliusPush {
+    /// Start the callback listener and register the webhook; `watched[i]`
+    /// are the addresses of vault `i`
+    pub async fn start(options: HeliusOptions, watched: Vec<Vec<Pubkey>>) -> Result<Self> {
+        // Only Helius knows this, so callbacks can't be forged by anyone who finds the URL
+        let mut secret = [0u8; 32];
+        OsRng.fill_bytes(&mut secret);
+        let auth = hex::encode(secret);
+
+        let mut addresses: Vec<String> = watched.iter().flatten().map(|address| address.to_string()).collect();
+        addresses.sort();
+        addresses.dedup();
+
+        let listener = tokio::net::TcpListener::bind(options.listen)
+            .await
+            .with_context(|| format!("Failed to listen on {}", options.listen))?;
+        let (touched_tx, touched) = unbounded_channel();
+        let callbacks = Arc::new(Callbacks {
+            auth: auth.clone(),
+            watched: watched
+                .iter()
+                .map(|addresses| addresses.iter().map(|address| address.to_string()).collect())
+                .collect(),
+            touched: touched_tx,
+        });
+        let app = Router::new().route("/", post(callback)).with_state(callbacks);
+        tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, app).await {
+                eprintln!("{} Helius listener stopped: {:#}", "[!]".yellow(), e);
+            }
+        });
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .context("Failed to create HTTP client")?;
+        let response = client
+            .post(WEBHOOKS_URL)
+            .query(&[("api-key", &options.api_key)])
+            .json(&serde_json::json!({
+                "webhookURL": options.public_url,
+                "transactionTypes": ["ANY"],
+                "accountAddresses": addresses,
+                "webhookType": if options.devnet { "rawDevnet" } else { "raw" },
+                "authHeader": auth,
+            }))
+            .send()
+            .await
+            .context("Failed to reach Helius")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            bail!("Helius rejected the webhook (HTTP {}): {}", status, body.trim());
+        }
+        let created: CreatedWebhook = response.json().await.context("Unexpected Helius response")?;
+
+        Ok(Self { client, api_key: options.api_key, webhook_id: created.webhook_id, touched })
+    }
+
+    /// Next vault a callback touched
+    pub async fn next(&mut self) -> Option<usize> {
+        self.touched.recv().await
+    }
+
+    /// Further vaults touched while the last one was being handled
+    pub fn drain(&mut self) -> Vec<usize> {
+        std::iter::from_fn(|| self.touched.try_recv().ok()).collect()
+    }
+
+    /// Delete the webhook from Helius
+    pub async fn unregister(self) -> Result<()> {
+        let response = self
+            .client
+            .delete(format!("{}/{}", WEBHOOKS_URL, self.webhook_id))
+            .query(&[("api-key", &self.api_key)])
+            .send()
+            .await
+            .context("Failed to reach Helius")?;
+        if !response.status().is_success() {
+            bail!("Failed to delete Helius webhook {} (HTTP {})", self.webhook_id, response.status());
+        }
+        Ok(())
+    }
+}
+
+async fn callback(
+    State(callbacks): State<Arc<Callbacks>>,
+    headers: HeaderMap,
+    Json(payload): Json<serde_json::Value>,
+) -> StatusCode {
+    if headers.get(header::AUTHORIZATION).and_then(|value| value.to_str().ok()) != Some(callbacks.auth.as_str()) {
+        return StatusCode::UNAUTHORIZED;
+    }
+    for index in touched_vaults(&payload, &callbacks.watched) {
+        let _ = callbacks.touched.send(index);
+    }
+    StatusCode::OK
+}
+
+/// Indices of the vaults whose addresses appear anywhere in a callback. Helius
+/// payload layouts differ by webhook type; any mention is enough to re-check.
+fn touched_vaults(payload: &serde_json::Value, watched: &[HashSet<String>]) -> Vec<usize> {
+    fn collect<'a>(value: &'a serde_json::Value, strings: &mut HashSet<&'a str>) {
+        match value {
+            serde_json::Value::String(s) => {
+                strings.insert(s.as_str());
+            }
+            serde_json::Value::Array(items) => items.iter().for_each(|item| collect(item, strings)),
+            serde_json::Value::Object(fields) => fields.values().for_each(|field| collect(field, strings)),
+            _ => {}
+        }
+    }
+    let mut strings = HashSet::new();
+    collect(payload, &mut strings);
+    watched
+        .iter()
+        .enumerate()
+        .filter(|(_, addresses)| addresses.iter().any(|address| strings.contains(address.as_str())))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_touched_vaults() {
+        let watched = vec![
+            HashSet::from(["Wallet1".to_string(), "Pq1".to_string()]),
+            HashSet::from(["Wallet2".to_string()]),
+        ];
+        let payload = serde_json::json!([{
+            "transaction": { "message": { "accountKeys": ["Fee", "Pq1", "Program"] } },
+            "meta": { "err": null }
+        }]);
+        assert_eq!(touched_vaults(&payload, &watched), vec![0]);
+        assert!(touched_vaults(&serde_json::json!([]), &watched).is_empty());
+    }
+}
@@ -0,0 +1,215 @@
+//! Notifications when long-running operations finish or watched vaults change
+//!
+//! Two kinds of targets:
+//!
+//! - the desktop: a 44-transaction unlock can take minutes, so a native
+//!   notification lets the user switch away from the terminal. Sent only when
+//!   stderr is a terminal (not from scripts or cron) and
+//!   `config --desktop-notifications` hasn't turned them off.
+//! - chat: a Telegram bot and/or a Discord webhook, told about unlock
+//!   completion here and, from `monitor --daemon`, about lock state changes
+//!   and incoming transfers on watched vaults. The bot token and the webhook
+//!   URL are credentials, so they can come from [`TELEGRAM_TOKEN_ENV`] and
+//!   [`DISCORD_WEBHOOK_ENV`] instead of vaults.json.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::solana::client::StatusChange;
+use crate::solana::receipt::{Operation, Receipt};
+use crate::vault_manager::VaultConfig;
+
+/// How long a chat API gets to answer
+const CHAT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Environment variable holding the Telegram bot token when the config has none
+pub const TELEGRAM_TOKEN_ENV: &str = "QDUM_TELEGRAM_BOT_TOKEN";
+
+/// Environment variable holding the Discord webhook URL when the config has none
+pub const DISCORD_WEBHOOK_ENV: &str = "QDUM_DISCORD_WEBHOOK";
+
+static DESKTOP: AtomicBool = AtomicBool::new(false);
+
+static CHAT: OnceLock<ChatChannels> = OnceLock::new();
+
+/// Telegram bot and the chat it posts to
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct TelegramConfig {
+    /// Read from [`TELEGRAM_TOKEN_ENV`] if empty
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub bot_token: String,
+    pub chat_id: String,
+}
+
+// The token is left out, so a config can be logged
+impl fmt::Debug for TelegramConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TelegramConfig").field("bot_token", &"<redacted>").field("chat_id", &self.chat_id).finish()
+    }
+}
+
+fn env_secret(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+/// Chat targets from the vault config
+pub struct ChatChannels {
+    client: reqwest::Client,
+    telegram: Option<TelegramConfig>,
+    discord_webhook: Option<String>,
+}
+
+impl ChatChannels {
+    pub fn from_config(config: &VaultConfig) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(CHAT_TIMEOUT)
+            .build()
+            .context("Failed to create HTTP client")?;
+        // A bot with no token anywhere can't post, so it's left out
+        let telegram = config.telegram.clone().and_then(|telegram| {
+            if telegram.bot_token.is_empty() {
+                env_secret(TELEGRAM_TOKEN_ENV).map(|bot_token| TelegramConfig { bot_token, ..telegram })
+            } else {
+                Some(telegram)
+            }
+        });
+        Ok(Self {
+            client,
+            telegram,
+            discord_webhook: config.discord_webhook.clone().or_else(|| env_secret(DISCORD_WEBHOOK_ENV)),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.telegram.is_none() && self.discord_webhook.is_none()
+    }
+
+    /// Post `text` to every channel. Returns the failures by channel name; one
+    /// being down doesn't keep the message from the others.
+    pub async fn send(&self, text: &str) -> Vec<(&'static str, anyhow::Error)> {
+        let mut failures = Vec::new();
+        if let Some(telegram) = &self.telegram {
+            let url = format!("https://api.telegram.org/bot{}/sendMessage", telegram.bot_token);
+            let body = serde_json::json!({ "chat_id": telegram.chat_id, "text": text });
+            if let Err(e) = self.post(&url, &body).await {
+                failures.push(("Telegram", e));
+            }
+        }
+        if let Some(webhook) = &self.discord_webhook {
+            let body = serde_json::json!({ "content": text });
+            if let Err(e) = self.post(webhook, &body).await {
+                failures.push(("Discord", e));
+            }
+        }
+        failures
+    }
+
+    async fn post(&self, url: &str, body: &serde_json::Value) -> Result<()> {
+        let response = self.client.post(url).json(body).send().await?;
+        if !response.status().is_success() {
+            // Never echo the URL: for Telegram it contains the bot token
+            bail!("HTTP {}", response.status());
+        }
+        Ok(())
+    }
+}
+
+/// Enable notifications for this process: on the desktop if `desktop` and a
+/// user is watching, and to the configured chat channels
+pub fn init(desktop: bool, chat: ChatChannels) {
+    DESKTOP.store(desktop && std::io::stderr().is_terminal(), Ordering::Relaxed);
+    if !chat.is_empty() {
+        let _ = CHAT.set(chat);
+    }
+}
+
+/// Post `text` to the chat channels, reporting (not failing on) errors
+pub async fn announce(text: &str) {
+    let Some(chat) = CHAT.get() else {
+        return;
+    };
+    for (channel, e) in chat.send(text).await {
+        eprintln!("{} {} notification failed: {:#}", "[!]".yellow(), channel, e);
+    }
+}
+
+/// Report the outcome of `operation`
+pub async fn finished(operation: Operation, result: &Result<Receipt>) {
+    let (summary, body) = match result {
+        Ok(receipt) => (
+            format!("{} complete", operation.label()),
+            match (&receipt.note, receipt.signatures.last()) {
+                (Some(note), _) => note.clone(),
+                (None, Some(signature)) => format!("Signature {}…", &signature[..16.min(signature.len())]),
+                (None, None) => String::new(),
+            },
+        ),
+        Err(error) => (format!("{} failed", operation.label()), format!("{:#}", error)),
+    };
+
+    if DESKTOP.load(Ordering::Relaxed) {
+        // Best effort: a missing notification daemon must not fail the command
+        let _ = notify_rust::Notification::new()
+            .appname("qdum-vault")
+            .summary(&summary)
+            .body(&body)
+            .show();
+    }
+
+    if operation == Operation::Unlock {
+        let wallet = result
+            .as_ref()
+            .ok()
+            .and_then(|receipt| receipt.accounts.get("wallet"))
+            .map(|wallet| format!(" ({})", wallet))
+            .unwrap_or_default();
+        announce(&format!("qdum-vault: {}{}\n{}", summary, wallet, body)).await;
+    }
+}
+
+/// Chat message for a change on a watched vault, if it's worth one: lock state
+/// changes, scheduled unlocks and incoming transfers
+pub fn change_message(vault: &str, change: &StatusChange) -> Option<String> {
+    let text = match change {
+        StatusChange::Locked => "🔒 vault locked".to_string(),
+        StatusChange::Unlocked => "🔓 vault unlocked".to_string(),
+        StatusChange::UnlockScheduled { slot } => format!("⏳ unlock scheduled for slot {}", slot),
+        StatusChange::TokenBalanceChanged { to, from, .. } if to > from => format!("📥 incoming transfer: {}", change),
+        _ => return None,
+    };
+    Some(format!("qdum-vault [{}]: {}", vault, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change_message() {
+        let incoming = StatusChange::TokenBalanceChanged { mint: "Mint1111".to_string(), from: 1_000_000, to: 3_000_000, decimals: 6 };
+        assert_eq!(
+            change_message("main", &incoming).as_deref(),
+            Some("qdum-vault [main]: 📥 incoming transfer: Mint… 1 → 3")
+        );
+
+        let outgoing = StatusChange::TokenBalanceChanged { mint: "Mint1111".to_string(), from: 3, to: 1, decimals: 0 };
+        assert_eq!(change_message("main", &outgoing), None);
+        assert!(change_message("main", &StatusChange::Unlocked).is_some());
+    }
+
+    #[test]
+    fn test_telegram_token_stays_private() {
+        // Left out of the config when it comes from the environment
+        let telegram: TelegramConfig = serde_json::from_str(r#"{ "chat_id": "42" }"#).unwrap();
+        assert_eq!(serde_json::to_string(&telegram).unwrap(), r#"{"chat_id":"42"}"#);
+
+        let telegram = TelegramConfig { bot_token: "123:secret".to_string(), ..telegram };
+        assert!(!format!("{:?}", telegram).contains("secret"));
+    }
+}
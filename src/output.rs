@@ -0,0 +1,124 @@
+//! Output modes: quiet for pipelines, verbose for troubleshooting
+//!
+//! With `--quiet`, everything a command writes to stdout (banner, tables,
+//! progress notes) is discarded, progress bars and animations are skipped, and
+//! only the command's result lines are printed once it finishes: a transaction
+//! signature, a balance, the lock state. Errors still go to stderr.
+//!
+//! `-v` adds transaction signatures, PDAs and key sources; `-vv` adds
+//! simulation logs and raw account data. Both go to stderr, so they combine
+//! with `--quiet`.
+
+use colored::Colorize;
+use indicatif::ProgressBar;
+use std::fmt;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::Mutex;
+
+/// `-v`: transaction signatures, PDAs, key sources
+pub const VERBOSE: u8 = 1;
+
+/// `-vv`: simulation logs, raw account data and key bytes
+pub const DEBUG: u8 = 2;
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// Result lines held back until stdout is restored
+static RESULTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+pub fn quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+/// Print a labelled detail at `level` and above
+pub fn detail(level: u8, label: &str, value: impl fmt::Display) {
+    if verbosity() >= level {
+        eprintln!("{} {}", label.dimmed(), value);
+    }
+}
+
+/// Print program logs from a simulation or failed preflight at `-vv`
+pub fn program_logs(label: &str, logs: &[String]) {
+    if verbosity() >= DEBUG && !logs.is_empty() {
+        eprintln!("{}", label.dimmed());
+        for line in logs {
+            eprintln!("  {}", line.dimmed());
+        }
+    }
+}
+
+/// Record a command's essential result. Only printed under `--quiet`; the
+/// normal output already shows it in context.
+pub fn result(line: impl Into<String>) {
+    if quiet() {
+        RESULTS.lock().unwrap().push(line.into());
+    }
+}
+
+/// Print output that is the result itself (e.g. a JSON report), in every mode
+pub fn emit(text: impl Into<String>) {
+    let text = text.into();
+    if quiet() {
+        RESULTS.lock().unwrap().push(text);
+    } else {
+        println!("{}", text);
+    }
+}
+
+/// Print the results held back under `--quiet`
+pub fn print_results() {
+    for line in RESULTS.lock().unwrap().drain(..) {
+        println!("{}", line);
+    }
+}
+
+/// Discards stdout while alive under `--quiet`
+pub struct Silenced {
+    _gag: Option<gag::Gag>,
+}
+
+impl Drop for Silenced {
+    fn drop(&mut self) {
+        // Buffered output must reach the discarded stream before it's restored
+        let _ = std::io::stdout().flush();
+    }
+}
+
+pub fn silence_stdout() -> Silenced {
+    if !quiet() {
+        return Silenced { _gag: None };
+    }
+    let _ = std::io::stdout().flush();
+    Silenced { _gag: gag::Gag::stdout().ok() }
+}
+
+pub fn progress_bar(len: u64) -> ProgressBar {
+    if quiet() {
+        ProgressBar::hidden()
+    } else {
+        ProgressBar::new(len)
+    }
+}
+
+pub fn spinner() -> ProgressBar {
+    if quiet() {
+        ProgressBar::hidden()
+    } else {
+        ProgressBar::new_spinner()
+    }
+}
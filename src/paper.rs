@@ -0,0 +1,275 @@
+//! Printable paper backups of SPHINCS+ keys
+//!
+//! `keys backup --paper` packs the public and private key files into parts
+//! small enough for one QR code each. Every part is also printed as a line of
+//! text, so a backup can be restored with a QR scanner or by typing:
+//!
+//! ```text
+//! QDUMKEY1:<backup checksum>:<part>:<parts>:<base64 data>:<part checksum>
+//! ```
+//!
+//! The part checksum catches a misread or mistyped part; the backup checksum
+//! ties parts to one backup and checks the reassembled key files.
+
+use anyhow::{Context, Result};
+use base64::Engine as _;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+use crate::qr;
+
+const PART_PREFIX: &str = "QDUMKEY1";
+
+/// Key bytes per part; keeps each QR code under ~80 characters wide
+const PART_BYTES: usize = 256;
+
+/// Characters per row when a part's text is printed
+const TEXT_WIDTH: usize = 64;
+
+/// Public and private key files, as written by `SphincsKeyManager`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyFiles {
+    pub public: Vec<u8>,
+    pub private: Vec<u8>,
+}
+
+impl KeyFiles {
+    /// Public key length (u32 LE), public key file, private key file
+    fn pack(&self) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(4 + self.public.len() + self.private.len());
+        payload.extend_from_slice(&(self.public.len() as u32).to_le_bytes());
+        payload.extend_from_slice(&self.public);
+        payload.extend_from_slice(&self.private);
+        payload
+    }
+
+    fn unpack(payload: &[u8]) -> Result<Self> {
+        let length = payload.get(..4).context("Backup is too short")?;
+        let public_len = u32::from_le_bytes(length.try_into()?) as usize;
+        let public = payload.get(4..4 + public_len).context("Backup is too short for its public key")?;
+        Ok(Self { public: public.to_vec(), private: payload[4 + public_len..].to_vec() })
+    }
+}
+
+/// What goes on a printed backup besides the key parts
+pub struct Sheet<'a> {
+    pub vault: &'a str,
+    pub wallet: &'a str,
+    pub algorithm: &'a str,
+    pub created: &'a str,
+}
+
+fn checksum(data: &[u8], hex_chars: usize) -> String {
+    hex::encode(Sha256::digest(data))[..hex_chars].to_string()
+}
+
+/// The key files split into part lines, in order
+pub fn encode_parts(keys: &KeyFiles) -> Vec<String> {
+    let payload = keys.pack();
+    let backup = checksum(&payload, 16);
+    let chunks: Vec<&[u8]> = payload.chunks(PART_BYTES).collect();
+    chunks
+        .iter()
+        .enumerate()
+        .map(|(index, chunk)| {
+            let body = format!(
+                "{}:{}:{}:{}:{}",
+                PART_PREFIX,
+                backup,
+                index + 1,
+                chunks.len(),
+                base64::engine::general_purpose::STANDARD.encode(chunk)
+            );
+            format!("{}:{}", body, checksum(body.as_bytes(), 8))
+        })
+        .collect()
+}
+
+/// Printable text layout: header, a QR code of the wallet address, then each
+/// part as a QR code and as text
+pub fn render(sheet: &Sheet, keys: &KeyFiles) -> Result<String> {
+    let parts = encode_parts(keys);
+    let backup = checksum(&keys.pack(), 16);
+    let mut text = String::new();
+    let mut line = |content: &str| {
+        text.push_str(content);
+        text.push('\n');
+    };
+
+    line("==================== QDUM VAULT PAPER BACKUP ====================");
+    line("");
+    line(&format!("  Vault:         {}", sheet.vault));
+    line(&format!("  Wallet:        {}", sheet.wallet));
+    line(&format!("  Algorithm:     {}", sheet.algorithm));
+    line(&format!("  Created:       {}", sheet.created));
+    line(&format!("  Backup check:  {}", backup));
+    line(&format!("  Parts:         {}", parts.len()));
+    line("");
+    line("  Anyone holding this sheet can unlock the vault. Store it offline.");
+    line("  Restore with: qdum-vault keys restore --scan");
+    line("");
+    line("  Wallet address (for reference; it is not a key):");
+    line("");
+    for row in qr::qr_lines(sheet.wallet)? {
+        line(&format!("  {}", row));
+    }
+
+    for (index, part) in parts.iter().enumerate() {
+        line("");
+        line(&format!("---------------------------- Part {} of {} ----------------------------", index + 1, parts.len()));
+        line("");
+        for row in qr::qr_lines(part)? {
+            line(&format!("  {}", row));
+        }
+        line("");
+        let chars: Vec<char> = part.chars().collect();
+        for row in chars.chunks(TEXT_WIDTH) {
+            line(&format!("  {}", row.iter().collect::<String>()));
+        }
+    }
+    line("");
+    line("  Typed parts may include the line breaks above; they are ignored.");
+    Ok(text)
+}
+
+/// Printable layout for a recovery phrase: the numbered words and a QR code
+/// of the whole phrase
+pub fn render_mnemonic(sheet: &Sheet, phrase: &str) -> Result<String> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    let mut text = String::new();
+    let mut line = |content: &str| {
+        text.push_str(content);
+        text.push('\n');
+    };
+
+    line("================ QDUM VAULT RECOVERY PHRASE BACKUP ================");
+    line("");
+    line(&format!("  Vault:         {}", sheet.vault));
+    line(&format!("  Wallet:        {}", sheet.wallet));
+    line(&format!("  Algorithm:     {}", sheet.algorithm));
+    line(&format!("  Created:       {}", sheet.created));
+    line(&format!("  Phrase check:  {}", checksum(words.join(" ").as_bytes(), 8)));
+    line("");
+    line("  Anyone holding this sheet can unlock the vault. Store it offline.");
+    line(&format!("  Restore with: qdum-vault keys restore --mnemonic --algorithm {}", sheet.algorithm));
+    line("");
+    for (row, chunk) in words.chunks(4).enumerate() {
+        let cells: Vec<String> = chunk.iter().enumerate().map(|(i, word)| format!("{:>2}. {:<10}", row * 4 + i + 1, word)).collect();
+        line(&format!("  {}", cells.join("  ")));
+    }
+    line("");
+    for row in qr::qr_lines(&words.join(" "))? {
+        line(&format!("  {}", row));
+    }
+    Ok(text)
+}
+
+/// Collects parts in any order, as they are scanned or typed
+#[derive(Debug, Default)]
+pub struct Assembler {
+    backup: Option<String>,
+    total: usize,
+    parts: BTreeMap<usize, Vec<u8>>,
+}
+
+impl Assembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check and keep one part, returning its number
+    pub fn add(&mut self, input: &str) -> Result<usize> {
+        let part: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+        let (body, part_check) = part.rsplit_once(':').context("Not a key backup part")?;
+        let fields: Vec<&str> = body.split(':').collect();
+        if fields.len() != 5 || fields[0] != PART_PREFIX {
+            anyhow::bail!("Not a key backup part (expected it to start with {})", PART_PREFIX);
+        }
+        if !checksum(body.as_bytes(), 8).eq_ignore_ascii_case(part_check) {
+            anyhow::bail!("Part checksum doesn't match; it was misread or mistyped");
+        }
+
+        let backup = fields[1].to_lowercase();
+        let index: usize = fields[2].parse().context("Invalid part number")?;
+        let total: usize = fields[3].parse().context("Invalid part count")?;
+        if index == 0 || index > total {
+            anyhow::bail!("Invalid part number {} of {}", index, total);
+        }
+        match &self.backup {
+            Some(expected) if *expected != backup || self.total != total => {
+                anyhow::bail!("Part {} is from a different backup ({}, expected {})", index, backup, expected)
+            }
+            Some(_) => {}
+            None => {
+                self.backup = Some(backup);
+                self.total = total;
+            }
+        }
+
+        let data = base64::engine::general_purpose::STANDARD.decode(fields[4]).context("Invalid part data")?;
+        self.parts.insert(index, data);
+        Ok(index)
+    }
+
+    /// Parts still missing, by number
+    pub fn missing(&self) -> Vec<usize> {
+        if self.backup.is_none() {
+            return Vec::new();
+        }
+        (1..=self.total).filter(|index| !self.parts.contains_key(index)).collect()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.backup.is_some() && self.missing().is_empty()
+    }
+
+    /// Reassemble and verify the key files
+    pub fn finish(self) -> Result<KeyFiles> {
+        if !self.is_complete() {
+            anyhow::bail!("Backup is incomplete: missing parts {:?}", self.missing());
+        }
+        let payload: Vec<u8> = self.parts.into_values().flatten().collect();
+        if Some(checksum(&payload, 16)) != self.backup {
+            anyhow::bail!("Reassembled keys don't match the backup checksum");
+        }
+        KeyFiles::unpack(&payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys() -> KeyFiles {
+        KeyFiles { public: vec![7; 300], private: (0..=255).cycle().take(600).collect() }
+    }
+
+    #[test]
+    fn test_parts_roundtrip_in_any_order() {
+        let parts = encode_parts(&keys());
+        assert_eq!(parts.len(), 4);
+
+        let mut assembler = Assembler::new();
+        for part in parts.iter().rev() {
+            assert!(!assembler.is_complete());
+            // Text copied from the sheet keeps its line breaks
+            let wrapped: String = part.chars().collect::<Vec<_>>().chunks(TEXT_WIDTH).map(|row| row.iter().collect::<String>() + "\n").collect();
+            assembler.add(&wrapped).unwrap();
+        }
+        assert_eq!(assembler.finish().unwrap(), keys());
+    }
+
+    #[test]
+    fn test_rejects_bad_parts() {
+        let parts = encode_parts(&keys());
+        let mut assembler = Assembler::new();
+        assembler.add(&parts[0]).unwrap();
+
+        let mistyped = parts[1].replacen('A', "B", 1);
+        assert!(assembler.add(&mistyped).is_err());
+
+        let other = encode_parts(&KeyFiles { public: vec![1; 32], private: vec![2; 64] });
+        assert!(assembler.add(&other[0]).is_err());
+        assert_eq!(assembler.missing(), vec![2, 3, 4]);
+    }
+}
@@ -0,0 +1,97 @@
+//! Where qdum-vault keeps its files
+//!
+//! Configuration (vault profiles, the lock schedule) goes under
+//! `$XDG_CONFIG_HOME/qdum` and key material and history under
+//! `$XDG_DATA_HOME/qdum`, defaulting to `~/.config/qdum` and
+//! `~/.local/share/qdum`. Two cases keep everything in a single directory:
+//!
+//! - `--data-dir <DIR>` puts config and keys under one custom root
+//! - an existing `~/.qdum` from older releases keeps being used, so upgrading
+//!   never strands keys that are already there
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// Directory used by releases before XDG support
+const LEGACY_DIR: &str = ".qdum";
+
+/// Subdirectory of the XDG base directories
+const APP_DIR: &str = "qdum";
+
+static DATA_ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+/// Keep config and keys under `root` for the rest of the process (`--data-dir`).
+/// Only the first call has an effect.
+pub fn set_data_root(root: PathBuf) {
+    let _ = DATA_ROOT.set(root);
+}
+
+/// Keep everything tests write in one temporary directory, never the real
+/// config and data dirs. Every test that can reach them calls this first.
+#[cfg(test)]
+pub fn use_test_root() {
+    static ROOT: OnceLock<tempfile::TempDir> = OnceLock::new();
+    let root = ROOT.get_or_init(|| tempfile::tempdir().expect("Failed to create a test data dir"));
+    set_data_root(root.path().to_path_buf());
+}
+
+fn home() -> PathBuf {
+    dirs::home_dir().expect("Could not determine home directory")
+}
+
+/// The pre-XDG `~/.qdum`, if this machine still has one
+fn legacy_dir() -> Option<PathBuf> {
+    let dir = home().join(LEGACY_DIR);
+    dir.is_dir().then_some(dir)
+}
+
+/// `$<var>` if set to an absolute path (the spec says to ignore relative ones), else `~/<fallback>`
+fn xdg_dir(var: &str, fallback: &str) -> PathBuf {
+    std::env::var_os(var)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .unwrap_or_else(|| home().join(fallback))
+}
+
+fn single_root() -> Option<PathBuf> {
+    #[cfg(test)]
+    assert!(DATA_ROOT.get().is_some(), "Tests must call paths::use_test_root before touching the data dir");
+    DATA_ROOT.get().cloned().or_else(legacy_dir)
+}
+
+/// Vault profiles, lock schedule and other settings
+pub fn config_dir() -> PathBuf {
+    single_root().unwrap_or_else(|| xdg_dir("XDG_CONFIG_HOME", ".config").join(APP_DIR))
+}
+
+/// Post-quantum keys and local history
+pub fn data_dir() -> PathBuf {
+    single_root().unwrap_or_else(|| xdg_dir("XDG_DATA_HOME", ".local/share").join(APP_DIR))
+}
+
+/// Default wallet of the Solana CLI
+pub fn solana_keypair() -> PathBuf {
+    home().join(".config/solana/id.json")
+}
+
+/// `path` with the home directory shortened to `~`, for display
+pub fn display(path: &std::path::Path) -> String {
+    match path.strip_prefix(home()) {
+        Ok(rest) => format!("~/{}", rest.display()),
+        Err(_) => path.display().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_xdg_dirs_are_ignored() {
+        std::env::set_var("QDUM_TEST_XDG_RELATIVE", "relative/dir");
+        assert_eq!(xdg_dir("QDUM_TEST_XDG_RELATIVE", ".config"), home().join(".config"));
+
+        std::env::set_var("QDUM_TEST_XDG_ABSOLUTE", "/tmp/xdg");
+        assert_eq!(xdg_dir("QDUM_TEST_XDG_ABSOLUTE", ".config"), PathBuf::from("/tmp/xdg"));
+    }
+}
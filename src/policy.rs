@@ -0,0 +1,325 @@
+//! Client-side security policy
+//!
+//! The `policy` section of the vault config sets rules this machine enforces
+//! before it signs anything, whatever asked for the signature (a command, the
+//! dashboard, the API server or a remote signer client):
+//!
+//! ```json
+//! "policy": {
+//!   "read_only": false,
+//!   "max_transfer": 5000,
+//!   "max_daily_transfer": 20000,
+//!   "allowed_recipients": ["9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin"],
+//!   "confirm_above": 1000
+//! }
+//! ```
+//!
+//! Amounts are in tokens, converted with each mint's decimals. Transfers sent
+//! from this machine are kept for 24 hours in `<data dir>/spending.json` to
+//! count them against `max_daily_transfer`, each mint separately. Token
+//! delegations, which let another wallet move tokens later, are checked like
+//! transfers. The rules guard against mistakes and a compromised terminal
+//! session, not against someone who can edit the config.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::exit_code::ExitKind;
+use crate::paths;
+use crate::solana::units;
+
+const SPENDING_FILE: &str = "spending.json";
+
+/// Window `max_daily_transfer` applies to
+pub const DAILY_WINDOW_SECS: i64 = 24 * 60 * 60;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Policy {
+    /// Refuse to sign anything
+    #[serde(default)]
+    pub read_only: bool,
+    /// Largest single transfer, in tokens
+    pub max_transfer: Option<f64>,
+    /// Most tokens sent in any 24 hours
+    pub max_daily_transfer: Option<f64>,
+    /// The only wallets transfers may go to; empty allows any
+    #[serde(default)]
+    pub allowed_recipients: Vec<String>,
+    /// Transfers above this many tokens must be confirmed by typing the
+    /// amount or the end of the recipient, even with confirmations turned off
+    pub confirm_above: Option<f64>,
+}
+
+/// Decimals of `mint`, which must have been looked up (`VaultClient::mint_decimals`):
+/// guessing them would put the limits off by powers of ten
+fn mint_decimals(mint: &Pubkey) -> Result<u8> {
+    units::cached_decimals(mint).ok_or_else(|| anyhow::anyhow!("Decimals of mint {} are unknown; can't check the policy limits", mint))
+}
+
+impl Policy {
+    /// What's wrong with it, as `field: problem`
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        for (field, value) in [
+            ("max_transfer", self.max_transfer),
+            ("max_daily_transfer", self.max_daily_transfer),
+            ("confirm_above", self.confirm_above),
+        ] {
+            if let Some(amount) = value {
+                if !amount.is_finite() || amount < 0.0 {
+                    problems.push(format!("{}: {} is not a token amount", field, amount));
+                }
+            }
+        }
+        for address in &self.allowed_recipients {
+            if Pubkey::from_str(address).is_err() {
+                problems.push(format!("allowed_recipients: '{}' is not a valid address", address));
+            }
+        }
+        problems
+    }
+
+    /// Refuse in read-only mode
+    pub fn check_signing(&self) -> Result<()> {
+        if self.read_only {
+            return Err(ExitKind::Policy.error("Read-only mode is on (policy.read_only); nothing can be signed"));
+        }
+        Ok(())
+    }
+
+    /// Refuse in read-only mode or if `recipient` isn't allowed
+    pub fn check_recipient(&self, recipient: &Pubkey) -> Result<()> {
+        self.check_signing()?;
+
+        if !self.allowed_recipients.is_empty() && !self.allowed_recipients.contains(&recipient.to_string()) {
+            return Err(ExitKind::Policy.error(format!("{} is not in policy.allowed_recipients", recipient)));
+        }
+        Ok(())
+    }
+
+    /// Whether there's a per-transfer or a daily limit
+    pub fn has_limits(&self) -> bool {
+        self.max_transfer.is_some() || self.max_daily_transfer.is_some()
+    }
+
+    /// Check a transfer (or delegation) of `amount` base units of `mint` to
+    /// `recipient` against every rule
+    pub fn check_transfer(&self, recipient: &Pubkey, mint: &Pubkey, amount: u64) -> Result<()> {
+        self.check_recipient(recipient)?;
+        if !self.has_limits() {
+            return Ok(());
+        }
+        self.check_amount(mint, amount, mint_decimals(mint)?)
+    }
+
+    /// Check `amount` base units of `mint`, which has `decimals`, against
+    /// `max_transfer` and what's left of `max_daily_transfer`
+    pub fn check_amount(&self, mint: &Pubkey, amount: u64, decimals: u8) -> Result<()> {
+        if let Some(max) = self.max_transfer {
+            if amount > units::to_base_units(max, decimals) {
+                return Err(ExitKind::Policy.error(format!(
+                    "{} tokens is over the {} token limit per transfer (policy.max_transfer)",
+                    units::to_tokens(amount, decimals),
+                    max
+                )));
+            }
+        }
+        self.check_daily_amount(mint, amount, decimals)
+    }
+
+    /// Check `amount` base units of `mint` against what's left of `max_daily_transfer`
+    pub fn check_daily_limit(&self, mint: &Pubkey, amount: u64) -> Result<()> {
+        if self.max_daily_transfer.is_none() {
+            return Ok(());
+        }
+        self.check_daily_amount(mint, amount, mint_decimals(mint)?)
+    }
+
+    fn check_daily_amount(&self, mint: &Pubkey, amount: u64, decimals: u8) -> Result<()> {
+        let Some(max) = self.max_daily_transfer else {
+            return Ok(());
+        };
+        let spent = spent_today(mint);
+        if units::to_base_units(spent, decimals).saturating_add(amount) > units::to_base_units(max, decimals) {
+            return Err(ExitKind::Policy.error(format!(
+                "{} tokens would exceed the {} token daily limit (policy.max_daily_transfer); {} sent in the last 24 hours",
+                units::to_tokens(amount, decimals),
+                max,
+                spent
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether a transfer of `amount` base units of `mint` has to be confirmed
+    /// by typing; it does if the mint's decimals are unknown
+    pub fn requires_confirmation(&self, mint: &Pubkey, amount: u64) -> bool {
+        self.confirm_above.is_some_and(|threshold| match units::cached_decimals(mint) {
+            Some(decimals) => amount > units::to_base_units(threshold, decimals),
+            None => true,
+        })
+    }
+}
+
+/// Tokens of `mint` sent from this machine in the last 24 hours
+pub fn spent_today(mint: &Pubkey) -> f64 {
+    SpendingLedger::load().spent_since(chrono::Utc::now().timestamp() - DAILY_WINDOW_SECS, mint)
+}
+
+/// Transfers sent from this machine in the last 24 hours
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct SpendingLedger {
+    pub transfers: Vec<Spend>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Spend {
+    /// Unix time it was confirmed
+    pub at: i64,
+    /// Base units
+    pub amount: u64,
+    /// The mint's decimals; entries written before they were recorded used 6
+    #[serde(default = "default_spend_decimals")]
+    pub decimals: u8,
+    /// Empty for entries written before it was recorded, which count against
+    /// every mint
+    #[serde(default)]
+    pub mint: String,
+    pub recipient: String,
+    pub signature: String,
+}
+
+fn default_spend_decimals() -> u8 {
+    units::DEFAULT_DECIMALS
+}
+
+impl SpendingLedger {
+    pub fn path() -> PathBuf {
+        paths::data_dir().join(SPENDING_FILE)
+    }
+
+    /// The saved ledger; empty if there is none or it can't be read
+    pub fn load() -> Self {
+        fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .unwrap_or_default()
+    }
+
+    /// Tokens of `mint` sent at or after `since` (unix time)
+    pub fn spent_since(&self, since: i64, mint: &Pubkey) -> f64 {
+        let mint = mint.to_string();
+        self.transfers
+            .iter()
+            .filter(|spend| spend.at >= since && (spend.mint.is_empty() || spend.mint == mint))
+            .map(|spend| units::to_tokens(spend.amount, spend.decimals))
+            .sum()
+    }
+
+    /// Add a confirmed transfer, dropping ones older than the daily window.
+    /// A transfer already recorded under the same signature (by a remote
+    /// signer sharing the data dir) isn't counted twice.
+    pub fn record(spend: Spend) -> Result<()> {
+        let mut ledger = Self::load();
+        let cutoff = spend.at - DAILY_WINDOW_SECS;
+        ledger.transfers.retain(|old| old.at >= cutoff);
+        if ledger.transfers.iter().any(|old| old.signature == spend.signature) {
+            return Ok(());
+        }
+        ledger.transfers.push(spend);
+
+        let path = Self::path();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).context("Failed to create data directory")?;
+        }
+        let json = serde_json::to_string_pretty(&ledger).context("Failed to serialize spending ledger")?;
+        fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_rules() {
+        paths::use_test_root();
+        let recipient = Pubkey::new_unique();
+        let policy = Policy {
+            max_transfer: Some(100.0),
+            allowed_recipients: vec![recipient.to_string()],
+            confirm_above: Some(10.0),
+            ..Policy::default()
+        };
+
+        let mint = Pubkey::new_unique();
+        units::remember(&mint, 6);
+
+        assert!(policy.check_transfer(&recipient, &mint, 100_000_000).is_ok());
+        assert!(policy.check_transfer(&recipient, &mint, 100_000_001).is_err());
+        assert!(policy.check_transfer(&Pubkey::new_unique(), &mint, 1).is_err());
+        assert!(policy.requires_confirmation(&mint, 10_000_001));
+        assert!(!policy.requires_confirmation(&mint, 10_000_000));
+
+        // Limits are in tokens whatever the mint's decimals
+        let nine_decimals = Pubkey::new_unique();
+        units::remember(&nine_decimals, 9);
+        assert!(policy.check_transfer(&recipient, &nine_decimals, 100_000_000_000).is_ok());
+        assert!(policy.check_transfer(&recipient, &nine_decimals, 100_000_000_001).is_err());
+
+        // Unknown decimals can't be checked against a limit
+        assert!(policy.check_transfer(&recipient, &Pubkey::new_unique(), 1).is_err());
+        assert!(policy.requires_confirmation(&Pubkey::new_unique(), 1));
+
+        let read_only = Policy { read_only: true, ..Policy::default() };
+        assert_eq!(crate::exit_code::classify(&read_only.check_signing().unwrap_err()), ExitKind::Policy);
+    }
+
+    #[test]
+    fn test_spent_since() {
+        let mint = Pubkey::new_unique();
+        let spend = |at, amount, decimals| Spend {
+            at,
+            amount,
+            decimals,
+            mint: mint.to_string(),
+            recipient: String::new(),
+            signature: String::new(),
+        };
+        let ledger = SpendingLedger { transfers: vec![spend(100, 5_000_000, 6), spend(200, 7_000_000, 6), spend(300, 11_000_000_000, 9)] };
+        assert_eq!(ledger.spent_since(200, &mint), 18.0);
+        assert_eq!(ledger.spent_since(0, &mint), 23.0);
+    }
+
+    #[test]
+    fn test_spent_per_mint() {
+        let (usdc, qdum) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let spend = |amount, mint: &Pubkey| Spend {
+            at: 100,
+            amount,
+            decimals: 6,
+            mint: mint.to_string(),
+            recipient: String::new(),
+            signature: String::new(),
+        };
+        let mut ledger = SpendingLedger { transfers: vec![spend(5_000_000, &usdc), spend(7_000_000, &qdum)] };
+        assert_eq!(ledger.spent_since(0, &usdc), 5.0);
+        assert_eq!(ledger.spent_since(0, &qdum), 7.0);
+
+        // Entries from before mints were recorded count against every mint
+        ledger.transfers.push(Spend { mint: String::new(), ..spend(1_000_000, &usdc) });
+        assert_eq!(ledger.spent_since(0, &usdc), 6.0);
+        assert_eq!(ledger.spent_since(0, &qdum), 8.0);
+    }
+
+    #[test]
+    fn test_problems() {
+        let policy = Policy { max_transfer: Some(-1.0), allowed_recipients: vec!["nope".to_string()], ..Policy::default() };
+        assert_eq!(policy.problems().len(), 2);
+    }
+}
@@ -0,0 +1,81 @@
+//! Token price from a configurable HTTP API, for the lock chart's price overlay
+//!
+//! A feed is a URL returning JSON and a JSON pointer to the price in it; both
+//! may contain `{mint}`. For example, Jupiter:
+//!
+//! ```text
+//! url:  https://api.jup.ag/price/v2?ids={mint}
+//! path: /data/{mint}/price
+//! ```
+//!
+//! The price may be a JSON number or a numeric string.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::time::Duration;
+
+/// How long a price API gets to answer
+const PRICE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PriceFeed {
+    pub url: String,
+    /// JSON pointer (RFC 6901) to the price, e.g. `/data/{mint}/price`
+    pub path: String,
+}
+
+impl PriceFeed {
+    pub fn check(&self) -> Result<()> {
+        if !self.url.starts_with("http://") && !self.url.starts_with("https://") {
+            bail!("Price feed '{}' is not an http(s) URL", self.url);
+        }
+        if !self.path.starts_with('/') {
+            bail!("Price path '{}' is not a JSON pointer (e.g. /data/price)", self.path);
+        }
+        Ok(())
+    }
+
+    /// Current price of `mint`
+    pub async fn fetch(&self, mint: &Pubkey) -> Result<f64> {
+        let mint = mint.to_string();
+        let client = reqwest::Client::builder()
+            .timeout(PRICE_TIMEOUT)
+            .build()
+            .context("Failed to create HTTP client")?;
+        let body: serde_json::Value = client
+            .get(self.url.replace("{mint}", &mint))
+            .send()
+            .await
+            .context("Failed to reach price API")?
+            .error_for_status()?
+            .json()
+            .await
+            .context("Price API did not return JSON")?;
+        extract(&body, &self.path.replace("{mint}", &mint))
+    }
+}
+
+fn extract(body: &serde_json::Value, path: &str) -> Result<f64> {
+    let value = body.pointer(path).with_context(|| format!("No price at {} in the response", path))?;
+    let price = match value {
+        serde_json::Value::Number(number) => number.as_f64(),
+        serde_json::Value::String(text) => text.parse().ok(),
+        _ => None,
+    };
+    price.with_context(|| format!("Price at {} is not a number: {}", path, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_price() {
+        let body = serde_json::json!({ "data": { "Mint": { "price": "0.0125" } }, "usd": 2.5 });
+        assert_eq!(extract(&body, "/data/Mint/price").unwrap(), 0.0125);
+        assert_eq!(extract(&body, "/usd").unwrap(), 2.5);
+        assert!(extract(&body, "/data").is_err());
+        assert!(extract(&body, "/missing").is_err());
+    }
+}
@@ -0,0 +1,57 @@
+//! QR codes drawn with text, for the terminal
+//!
+//! Two modules share one character cell (upper and lower half blocks), so
+//! the code comes out roughly square in a typical terminal font. The blocks
+//! mark the dark modules: print the lines dark-on-light (black on white) or
+//! scanners will see an inverted code.
+
+use anyhow::{Context, Result};
+use qrcode::{Color, EcLevel, QrCode};
+
+/// Light border around the code, in modules; scanners need some margin
+const QUIET_ZONE: isize = 2;
+
+/// `data` as lines of half-block characters, quiet zone included
+pub fn qr_lines(data: &str) -> Result<Vec<String>> {
+    let code = QrCode::with_error_correction_level(data, EcLevel::M).context("Too much data for a QR code")?;
+    let width = code.width() as isize;
+    let colors = code.to_colors();
+    let dark = |x: isize, y: isize| {
+        (0..width).contains(&x) && (0..width).contains(&y) && colors[(y * width + x) as usize] == Color::Dark
+    };
+
+    let range = -QUIET_ZONE..width + QUIET_ZONE;
+    Ok(range
+        .clone()
+        .step_by(2)
+        .map(|y| {
+            range
+                .clone()
+                .map(|x| match (dark(x, y), dark(x, y + 1)) {
+                    (true, true) => '█',
+                    (true, false) => '▀',
+                    (false, true) => '▄',
+                    (false, false) => ' ',
+                })
+                .collect()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qr_lines_shape() {
+        let lines = qr_lines("3V6ogu16de86nChsmC5wHMKJmCx5YdGXA6fbp3y3497n").unwrap();
+        // Codes are 21 + 4n modules wide
+        let side = lines[0].chars().count();
+        assert_eq!((side - 2 * QUIET_ZONE as usize - 21) % 4, 0);
+        assert_eq!(lines.len(), (side + 1) / 2);
+        assert!(lines.iter().all(|line| line.chars().count() == side));
+        // The quiet zone is blank and the finder pattern starts right after it
+        assert!(lines[0].chars().all(|c| c == ' '));
+        assert_eq!(lines[1].chars().nth(QUIET_ZONE as usize), Some('█'));
+    }
+}
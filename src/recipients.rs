@@ -0,0 +1,95 @@
+//! Wallets this machine has sent tokens to
+//!
+//! Kept in `<data dir>/recipients.json` so the first transfer to an address
+//! can be flagged: a mistyped or swapped address is almost always one that
+//! was never used before.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::paths;
+
+const HISTORY_FILE: &str = "recipients.json";
+
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct RecipientHistory {
+    /// Keyed by address
+    pub recipients: BTreeMap<String, RecipientRecord>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecipientRecord {
+    pub first_sent: String,
+    pub last_sent: String,
+    pub transfers: u32,
+}
+
+impl RecipientHistory {
+    pub fn path() -> PathBuf {
+        paths::data_dir().join(HISTORY_FILE)
+    }
+
+    /// The saved history; empty if there is none or it can't be read
+    pub fn load() -> Self {
+        fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, recipient: &Pubkey) -> Option<&RecipientRecord> {
+        self.recipients.get(&recipient.to_string())
+    }
+
+    /// Whether anything was ever sent to `recipient` from this machine
+    pub fn contains(&self, recipient: &Pubkey) -> bool {
+        self.get(recipient).is_some()
+    }
+
+    fn add(&mut self, recipient: &Pubkey, at: &str) {
+        self.recipients
+            .entry(recipient.to_string())
+            .and_modify(|record| {
+                record.last_sent = at.to_string();
+                record.transfers += 1;
+            })
+            .or_insert_with(|| RecipientRecord { first_sent: at.to_string(), last_sent: at.to_string(), transfers: 1 });
+    }
+
+    /// Note a confirmed transfer to `recipient`
+    pub fn record(recipient: &Pubkey) -> Result<()> {
+        let mut history = Self::load();
+        history.add(recipient, &Utc::now().to_rfc3339());
+
+        let path = Self::path();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).context("Failed to create data directory")?;
+        }
+        let json = serde_json::to_string_pretty(&history).context("Failed to serialize recipient history")?;
+        fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_counts_transfers() {
+        let recipient = Pubkey::new_unique();
+        let mut history = RecipientHistory::default();
+        assert!(!history.contains(&recipient));
+
+        history.add(&recipient, "2026-03-01T12:00:00Z");
+        history.add(&recipient, "2026-03-02T12:00:00Z");
+        let record = history.get(&recipient).unwrap();
+        assert_eq!(record.transfers, 2);
+        assert_eq!(record.first_sent, "2026-03-01T12:00:00Z");
+        assert_eq!(record.last_sent, "2026-03-02T12:00:00Z");
+    }
+}
@@ -0,0 +1,687 @@
+//! Signing on another machine
+//!
+//! A [`RemoteSigner`] holds no keys. It forwards every message to a signing
+//! service, so the machine running an unlock never sees the wallet keypair or
+//! the PQ private key. The service is another qdum-vault running `signer`
+//! ([`serve_http`], [`serve_socket`]) or anything that speaks the same
+//! protocol: one JSON [`SignerRequest`] in, one [`SignerResponse`] out, as
+//! the body of `POST <url>` or as a line on a Unix socket.
+//!
+//! Over HTTP, `signer` requires `QDUM_SIGNER_TOKEN` as a bearer token on
+//! every request, loopback included: any local user can reach a loopback
+//! port. A socket is only accessible to its owner.
+//!
+//! `signer` applies its own policy rather than trusting the client's: it
+//! decodes every transaction message and signs only instructions it can
+//! check (the vault and bridge programs, compute budget, associated token
+//! accounts, and the system and token instructions the client sends).
+//! Token transfers and delegations are held to the recipient allowlist and
+//! the limits, and transfers are counted in the signer's spending ledger as
+//! soon as they are signed, whether or not the client sends them.
+
+use anyhow::{bail, Context, Result};
+use axum::extract::State;
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::{Json, Router};
+use base64::Engine as _;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    instruction::CompiledInstruction,
+    message::Message,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signature, Signer, SignerError},
+};
+use std::fmt;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use crate::applog::{self, Level};
+use crate::audit;
+use crate::crypto::algorithm::{Algorithm, PqKey};
+use crate::crypto::attestation::sha256_hex;
+use crate::crypto::sphincs::SphincsKeyManager;
+use crate::exit_code::ExitKind;
+use crate::policy::{Policy, Spend, SpendingLedger};
+use crate::solana::client::{
+    get_associated_token_address, ASSOCIATED_TOKEN_PROGRAM_ID, BRIDGE_PROGRAM_ID, DEFAULT_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID,
+    TOKEN_2022_PROGRAM_ID,
+};
+
+/// Environment variable holding the signer's bearer token
+pub const TOKEN_ENV: &str = "QDUM_SIGNER_TOKEN";
+
+/// Path `signer` answers on; used for URLs that don't name one
+pub const SIGN_PATH: &str = "/v1/sign";
+
+/// Where `signer` listens by default
+pub const DEFAULT_LISTEN: &str = "127.0.0.1:7450";
+
+/// Generous, since a signing service may wait for someone to approve
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Where a signing service is reached
+#[derive(Debug, Clone, PartialEq)]
+pub enum SignerEndpoint {
+    Http(String),
+    Socket(PathBuf),
+}
+
+impl FromStr for SignerEndpoint {
+    type Err = anyhow::Error;
+
+    /// An http(s) URL, or a socket path with an optional `unix:` prefix
+    fn from_str(s: &str) -> Result<Self> {
+        if s.starts_with("http://") || s.starts_with("https://") {
+            let mut url = reqwest::Url::parse(s).context("Invalid signer URL")?;
+            if url.path() == "/" {
+                url.set_path(SIGN_PATH);
+            }
+            return Ok(SignerEndpoint::Http(url.to_string()));
+        }
+
+        let path = s.strip_prefix("unix:").unwrap_or(s);
+        if path.is_empty() {
+            bail!("Signer must be an http(s) URL or a socket path");
+        }
+        Ok(SignerEndpoint::Socket(PathBuf::from(path)))
+    }
+}
+
+impl fmt::Display for SignerEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignerEndpoint::Http(url) => f.write_str(url),
+            SignerEndpoint::Socket(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+/// What a client asks the signer for. Bytes are base64.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum SignerRequest {
+    /// The wallet and PQ public keys the signer signs with
+    Pubkey,
+    /// Ed25519 signature over a serialized transaction message
+    SignMessage { message: String },
+    /// PQ signature over a 32-byte unlock challenge
+    SignChallenge { challenge: String },
+}
+
+/// The signer's answer: the fields the request asked for, or `error`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SignerResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pubkey: Option<String>,
+    /// Algorithm id of `pq_public_key`, as stored in the PQ account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pq_algorithm: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pq_public_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl SignerResponse {
+    fn error(message: impl Into<String>) -> Self {
+        SignerResponse { error: Some(message.into()), ..Default::default() }
+    }
+}
+
+fn encode(bytes: &[u8]) -> String {
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+fn decode(field: Option<String>, name: &str) -> Result<Vec<u8>> {
+    let text = field.with_context(|| format!("Signer response has no {}", name))?;
+    base64::engine::general_purpose::STANDARD
+        .decode(text)
+        .with_context(|| format!("Signer returned an invalid {}", name))
+}
+
+/// Keys a remote signer reported, fetched once per process
+struct RemoteKeys {
+    pubkey: Pubkey,
+    pq_public_key: Option<PqKey>,
+}
+
+/// Signs through a signing service instead of a local keypair
+pub struct RemoteSigner {
+    endpoint: SignerEndpoint,
+    token: Option<String>,
+    keys: OnceLock<RemoteKeys>,
+}
+
+impl RemoteSigner {
+    pub fn new(endpoint: SignerEndpoint) -> Self {
+        RemoteSigner {
+            endpoint,
+            token: std::env::var(TOKEN_ENV).ok().filter(|token| !token.is_empty()),
+            keys: OnceLock::new(),
+        }
+    }
+
+    pub fn endpoint(&self) -> &SignerEndpoint {
+        &self.endpoint
+    }
+
+    /// The PQ public key unlock challenges are signed with
+    pub fn pq_public_key(&self) -> Result<PqKey> {
+        self.keys()?
+            .pq_public_key
+            .clone()
+            .with_context(|| format!("Signer at {} has no PQ key", self.endpoint))
+    }
+
+    /// PQ signature over an unlock challenge
+    pub fn sign_challenge(&self, challenge: &[u8]) -> Result<Vec<u8>> {
+        let response = self.request(&SignerRequest::SignChallenge { challenge: encode(challenge) })?;
+        decode(response.signature, "signature")
+    }
+
+    fn keys(&self) -> Result<&RemoteKeys> {
+        if let Some(keys) = self.keys.get() {
+            return Ok(keys);
+        }
+
+        let response = self.request(&SignerRequest::Pubkey)?;
+        let pubkey = response.pubkey.as_deref().context("Signer response has no pubkey")?;
+        let pubkey = Pubkey::from_str(pubkey).context("Signer returned an invalid pubkey")?;
+        let pq_public_key = match response.pq_algorithm {
+            Some(id) => Some(PqKey {
+                algorithm: Algorithm::from_algorithm_id(id).with_context(|| format!("Signer uses unknown algorithm {}", id))?,
+                bytes: decode(response.pq_public_key, "pq_public_key")?,
+            }),
+            None => None,
+        };
+        Ok(self.keys.get_or_init(|| RemoteKeys { pubkey, pq_public_key }))
+    }
+
+    /// Send one request and wait for the answer. Signer methods are
+    /// synchronous, so this blocks the calling runtime thread.
+    fn request(&self, request: &SignerRequest) -> Result<SignerResponse> {
+        let response = tokio::task::block_in_place(|| match &self.endpoint {
+            SignerEndpoint::Http(url) => tokio::runtime::Handle::try_current()
+                .context("Remote signing over HTTP needs a Tokio runtime")?
+                .block_on(self.post(url, request)),
+            SignerEndpoint::Socket(path) => exchange(path, request),
+        })?;
+
+        if let Some(error) = response.error {
+            bail!("Signer at {} refused: {}", self.endpoint, error);
+        }
+        Ok(response)
+    }
+
+    async fn post(&self, url: &str, request: &SignerRequest) -> Result<SignerResponse> {
+        let mut builder = reqwest::Client::new().post(url).json(request).timeout(REQUEST_TIMEOUT);
+        if let Some(token) = &self.token {
+            builder = builder.bearer_auth(token);
+        }
+        // Refusals come back as a JSON error with a 4xx status
+        let response = builder.send().await.with_context(|| format!("Signer at {} is unreachable", url))?;
+        response.json().await.context("Invalid response from signer")
+    }
+}
+
+#[cfg(unix)]
+fn exchange(path: &Path, request: &SignerRequest) -> Result<SignerResponse> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+
+    let mut stream = UnixStream::connect(path).with_context(|| format!("Signer socket {} is unreachable", path.display()))?;
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+    let mut line = serde_json::to_string(request)?;
+    line.push('\n');
+    stream.write_all(line.as_bytes()).context("Failed to write to signer socket")?;
+
+    let mut reply = String::new();
+    BufReader::new(stream).read_line(&mut reply).context("Failed to read from signer socket")?;
+    serde_json::from_str(&reply).context("Invalid response from signer")
+}
+
+#[cfg(not(unix))]
+fn exchange(_path: &Path, _request: &SignerRequest) -> Result<SignerResponse> {
+    bail!("Socket signers are only supported on Unix; use an http:// signer")
+}
+
+fn signer_error(error: anyhow::Error) -> SignerError {
+    SignerError::Custom(format!("{:#}", error))
+}
+
+impl Signer for RemoteSigner {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        self.keys().map(|keys| keys.pubkey).map_err(signer_error)
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let pubkey = self.try_pubkey()?;
+        let response = self.request(&SignerRequest::SignMessage { message: encode(message) }).map_err(signer_error)?;
+        let bytes = decode(response.signature, "signature").map_err(signer_error)?;
+        let signature = Signature::try_from(bytes.as_slice())
+            .map_err(|_| SignerError::Custom("Signer returned a malformed signature".to_string()))?;
+
+        // A signer holding a different key would only produce rejected transactions
+        if !signature.verify(pubkey.as_ref(), message) {
+            return Err(SignerError::Custom(format!("Signature from {} does not verify against {}", self.endpoint, pubkey)));
+        }
+        Ok(signature)
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+/// The keys `signer` signs with for remote clients
+pub struct LocalSigner {
+    keypair: Keypair,
+    /// (public, private)
+    pq_keys: Option<(PqKey, PqKey)>,
+    policy: Policy,
+    /// The vault program clients send instructions to
+    program_id: Pubkey,
+}
+
+/// Tokens a message moves, checked against the policy limits
+struct Outflow {
+    mint: Pubkey,
+    amount: u64,
+    decimals: u8,
+    recipient: Pubkey,
+    /// Transfers count against the daily limit once signed; delegations
+    /// are only checked
+    counted: bool,
+}
+
+impl LocalSigner {
+    pub fn new(keypair: Keypair, pq_keys: Option<(PqKey, PqKey)>) -> Self {
+        LocalSigner { keypair, pq_keys, policy: Policy::default(), program_id: DEFAULT_PROGRAM_ID }
+    }
+
+    /// Check signing requests against `policy`
+    pub fn with_policy(mut self, policy: Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sign instructions for the vault program at `program_id` instead of the default one
+    pub fn with_program_id(mut self, program_id: Pubkey) -> Self {
+        self.program_id = program_id;
+        self
+    }
+
+    pub fn pubkey(&self) -> Pubkey {
+        self.keypair.pubkey()
+    }
+
+    pub fn handle(&self, request: SignerRequest) -> SignerResponse {
+        self.try_handle(request).unwrap_or_else(|e| SignerResponse::error(format!("{:#}", e)))
+    }
+
+    fn try_handle(&self, request: SignerRequest) -> Result<SignerResponse> {
+        let b64 = base64::engine::general_purpose::STANDARD;
+        match request {
+            SignerRequest::Pubkey => Ok(SignerResponse {
+                pubkey: Some(self.keypair.pubkey().to_string()),
+                pq_algorithm: self.pq_keys.as_ref().map(|(public, _)| public.algorithm.algorithm_id()),
+                pq_public_key: self.pq_keys.as_ref().map(|(public, _)| encode(&public.bytes)),
+                ..Default::default()
+            }),
+            SignerRequest::SignMessage { message } => {
+                self.policy.check_signing()?;
+                let message = b64.decode(message).context("Message is not valid base64")?;
+                let decoded = bincode::deserialize::<Message>(&message)
+                    .map_err(|_| ExitKind::Policy.error("Message is not a transaction message; refusing to sign it blind"))?;
+                let outflows = self.check_message(&decoded)?;
+                let signature = self.keypair.try_sign_message(&message)?;
+                applog::write(Level::Info, "signer", format!("Signed transaction message for {}", describe_message(&decoded)));
+                audit::record(
+                    audit::Event::new("remote_sign_transaction")
+                        .wallet(self.keypair.pubkey())
+                        .signature(signature)
+                        .detail("programs", describe_message(&decoded)),
+                );
+                for outflow in outflows.into_iter().filter(|outflow| outflow.counted) {
+                    let spend = Spend {
+                        at: chrono::Utc::now().timestamp(),
+                        amount: outflow.amount,
+                        decimals: outflow.decimals,
+                        mint: outflow.mint.to_string(),
+                        recipient: outflow.recipient.to_string(),
+                        signature: signature.to_string(),
+                    };
+                    if let Err(e) = SpendingLedger::record(spend) {
+                        applog::write(Level::Warn, "signer", format!("Failed to record transfer for the daily limit: {:#}", e));
+                    }
+                }
+                Ok(SignerResponse { signature: Some(encode(signature.as_ref())), ..Default::default() })
+            }
+            SignerRequest::SignChallenge { challenge } => {
+                self.policy.check_signing()?;
+                let challenge = b64.decode(challenge).context("Challenge is not valid base64")?;
+                if challenge.len() != 32 {
+                    bail!("Challenge is {} bytes, expected 32", challenge.len());
+                }
+                let (_, private_key) = self.pq_keys.as_ref().context("This signer has no PQ key")?;
+                let signature = SphincsKeyManager::new(None)?.sign_message(&challenge, private_key)?;
+                applog::write(Level::Info, "signer", format!("Signed unlock challenge {}", hex::encode(&challenge)));
+                audit::record(
+                    audit::Event::new("remote_sign_challenge")
+                        .wallet(self.keypair.pubkey())
+                        .signature(sha256_hex(&signature))
+                        .detail("challenge", hex::encode(&challenge)),
+                );
+                Ok(SignerResponse { signature: Some(encode(&signature)), ..Default::default() })
+            }
+        }
+    }
+
+    /// Refuse `message` unless the policy allows every instruction in it;
+    /// returns the tokens it moves
+    fn check_message(&self, message: &Message) -> Result<Vec<Outflow>> {
+        let mut outflows = Vec::new();
+        for ix in &message.instructions {
+            let program = *message.account_keys.get(ix.program_id_index as usize).context("Instruction has no program")?;
+            if program == solana_sdk::system_program::id() {
+                self.check_system_instruction(message, ix)?;
+            } else if program == SPL_TOKEN_PROGRAM_ID || program == TOKEN_2022_PROGRAM_ID {
+                outflows.extend(self.check_token_instruction(message, ix, &program)?);
+            } else if ![
+                self.program_id,
+                BRIDGE_PROGRAM_ID,
+                ASSOCIATED_TOKEN_PROGRAM_ID,
+                solana_sdk::compute_budget::id(),
+                solana_sdk::ed25519_program::id(),
+            ]
+            .contains(&program)
+            {
+                return Err(ExitKind::Policy.error(format!("The signer doesn't sign instructions for program {}", program)));
+            }
+        }
+
+        // Everything one message moves of a mint is held to the limits together
+        for outflow in &outflows {
+            let total = outflows
+                .iter()
+                .filter(|other| other.mint == outflow.mint)
+                .fold(0u64, |total, other| total.saturating_add(other.amount));
+            self.policy.check_amount(&outflow.mint, total, outflow.decimals)?;
+        }
+        Ok(outflows)
+    }
+
+    fn check_system_instruction(&self, message: &Message, ix: &CompiledInstruction) -> Result<()> {
+        use solana_sdk::system_instruction::SystemInstruction;
+
+        let instruction: SystemInstruction = bincode::deserialize(&ix.data).context("Unreadable system program instruction")?;
+        match instruction {
+            SystemInstruction::Transfer { .. } | SystemInstruction::WithdrawNonceAccount(_) => {
+                self.policy.check_recipient(&instruction_account(message, ix, 1)?)
+            }
+            // Funding a new account with more than its rent would be a transfer
+            SystemInstruction::CreateAccount { lamports, space, .. } => {
+                if lamports > Rent::default().minimum_balance(space as usize) {
+                    return Err(ExitKind::Policy.error(format!("New account is funded with {} lamports, more than its rent", lamports)));
+                }
+                Ok(())
+            }
+            SystemInstruction::InitializeNonceAccount(_) | SystemInstruction::AdvanceNonceAccount => Ok(()),
+            other => Err(ExitKind::Policy.error(format!("The signer doesn't sign system program instruction {:?}", other))),
+        }
+    }
+
+    fn check_token_instruction(&self, message: &Message, ix: &CompiledInstruction, program: &Pubkey) -> Result<Option<Outflow>> {
+        use spl_token_2022::instruction::TokenInstruction;
+
+        let instruction = TokenInstruction::unpack(&ix.data).context("Unreadable token program instruction")?;
+        match instruction {
+            TokenInstruction::TransferChecked { amount, decimals } => {
+                let mint = instruction_account(message, ix, 1)?;
+                let destination = instruction_account(message, ix, 2)?;
+                let recipient = self.token_recipient(&destination, &mint, program)?;
+                Ok(Some(Outflow { mint, amount, decimals, recipient, counted: true }))
+            }
+            TokenInstruction::ApproveChecked { amount, decimals } => {
+                let mint = instruction_account(message, ix, 1)?;
+                let delegate = instruction_account(message, ix, 2)?;
+                self.policy.check_recipient(&delegate)?;
+                Ok(Some(Outflow { mint, amount, decimals, recipient: delegate, counted: false }))
+            }
+            TokenInstruction::Revoke => Ok(None),
+            // Names no mint, so no limit can be checked
+            TokenInstruction::Approve { .. } if self.policy.has_limits() => {
+                Err(ExitKind::Policy.error("An unchecked token delegation names no mint, so the signer can't hold it to the policy limits"))
+            }
+            TokenInstruction::Approve { .. } => {
+                self.policy.check_recipient(&instruction_account(message, ix, 1)?)?;
+                Ok(None)
+            }
+            other => Err(ExitKind::Policy.error(format!("The signer doesn't sign token program instruction {:?}", other))),
+        }
+    }
+
+    /// The wallet a token transfer goes to. With an allowlist, `destination`
+    /// must be the associated token account of an allowed wallet; without
+    /// one, the token account itself is recorded.
+    fn token_recipient(&self, destination: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Result<Pubkey> {
+        if self.policy.allowed_recipients.is_empty() {
+            return Ok(*destination);
+        }
+        self.policy
+            .allowed_recipients
+            .iter()
+            .filter_map(|address| Pubkey::from_str(address).ok())
+            .find(|wallet| get_associated_token_address(wallet, mint, token_program) == *destination)
+            .ok_or_else(|| {
+                ExitKind::Policy.error(format!("{} is not the token account of a wallet in policy.allowed_recipients", destination))
+            })
+    }
+}
+
+/// The account an instruction passes at `position`
+fn instruction_account(message: &Message, ix: &CompiledInstruction, position: usize) -> Result<Pubkey> {
+    ix.accounts
+        .get(position)
+        .and_then(|&index| message.account_keys.get(index as usize))
+        .copied()
+        .with_context(|| format!("Instruction has no account {}", position))
+}
+
+/// Programs a transaction message calls, for the signer's log
+fn describe_message(message: &Message) -> String {
+    let programs: Vec<String> = message
+        .instructions
+        .iter()
+        .filter_map(|ix| message.account_keys.get(ix.program_id_index as usize))
+        .map(|program| program.to_string())
+        .collect();
+    format!("programs {}", programs.join(", "))
+}
+
+struct SignerState {
+    signer: Arc<LocalSigner>,
+    token: String,
+}
+
+/// Answer signing requests over HTTP at [`SIGN_PATH`] until stopped, from
+/// clients that send `token` as a bearer token
+pub async fn serve_http(addr: SocketAddr, signer: LocalSigner, token: String) -> Result<()> {
+    if token.is_empty() {
+        bail!("The signer's bearer token is empty");
+    }
+    let state = Arc::new(SignerState { signer: Arc::new(signer), token });
+    let app = Router::new().route(SIGN_PATH, post(sign)).with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(addr).await.with_context(|| format!("Failed to listen on {}", addr))?;
+    axum::serve(listener, app).await.context("Signer stopped")
+}
+
+async fn sign(State(state): State<Arc<SignerState>>, headers: HeaderMap, Json(request): Json<SignerRequest>) -> (StatusCode, Json<SignerResponse>) {
+    let bearer = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if bearer != Some(state.token.as_str()) {
+        return (StatusCode::UNAUTHORIZED, Json(SignerResponse::error("Missing or invalid bearer token")));
+    }
+
+    let response = handle_blocking(&state.signer, request).await;
+    let status = if response.error.is_some() { StatusCode::BAD_REQUEST } else { StatusCode::OK };
+    (status, Json(response))
+}
+
+/// PQ signing takes seconds, so it runs off the async workers
+async fn handle_blocking(signer: &Arc<LocalSigner>, request: SignerRequest) -> SignerResponse {
+    let signer = signer.clone();
+    tokio::task::spawn_blocking(move || signer.handle(request))
+        .await
+        .unwrap_or_else(|e| SignerResponse::error(format!("Signing failed: {}", e)))
+}
+
+/// Answer signing requests on a Unix socket at `path`, one JSON line each,
+/// until stopped
+#[cfg(unix)]
+pub async fn serve_socket(path: &Path, signer: LocalSigner) -> Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    // Left behind by a previous run; anything else at the path is not ours to remove
+    if let Ok(metadata) = std::fs::symlink_metadata(path) {
+        if !metadata.file_type().is_socket() {
+            bail!("{} exists and is not a socket", path.display());
+        }
+        std::fs::remove_file(path)?;
+    }
+    // Created owner-only: a chmod after bind would leave a window in which
+    // anyone could connect
+    let umask = unsafe { libc::umask(0o177) };
+    let listener = tokio::net::UnixListener::bind(path);
+    unsafe { libc::umask(umask) };
+    let listener = listener.with_context(|| format!("Failed to listen on {}", path.display()))?;
+
+    let signer = Arc::new(signer);
+    loop {
+        let (stream, _) = listener.accept().await.context("Signer socket failed")?;
+        let signer = signer.clone();
+        tokio::spawn(async move {
+            let (read, mut write) = stream.into_split();
+            let mut lines = BufReader::new(read).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                let response = match serde_json::from_str::<SignerRequest>(&line) {
+                    Ok(request) => handle_blocking(&signer, request).await,
+                    Err(e) => SignerResponse::error(format!("Invalid request: {}", e)),
+                };
+                let Ok(mut reply) = serde_json::to_string(&response) else { break };
+                reply.push('\n');
+                if write.write_all(reply.as_bytes()).await.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+#[cfg(not(unix))]
+pub async fn serve_socket(_path: &Path, _signer: LocalSigner) -> Result<()> {
+    bail!("Socket signers are only supported on Unix; use --listen")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paths;
+
+    #[test]
+    fn test_endpoint_parsing() {
+        let http: SignerEndpoint = "http://127.0.0.1:7450".parse().unwrap();
+        assert_eq!(http, SignerEndpoint::Http("http://127.0.0.1:7450/v1/sign".to_string()));
+        let custom: SignerEndpoint = "https://signer.example/api/sign".parse().unwrap();
+        assert_eq!(custom, SignerEndpoint::Http("https://signer.example/api/sign".to_string()));
+
+        let socket: SignerEndpoint = "unix:/run/qdum/signer.sock".parse().unwrap();
+        assert_eq!(socket, SignerEndpoint::Socket(PathBuf::from("/run/qdum/signer.sock")));
+        assert_eq!(socket.to_string(), "unix:/run/qdum/signer.sock");
+        assert!("unix:".parse::<SignerEndpoint>().is_err());
+    }
+
+    #[test]
+    fn test_local_signer_requests() {
+        paths::use_test_root();
+        let keypair = Keypair::new();
+        let pubkey = keypair.pubkey();
+        let signer = LocalSigner::new(keypair, None);
+
+        let keys = signer.handle(SignerRequest::Pubkey);
+        assert_eq!(keys.pubkey, Some(pubkey.to_string()));
+        assert_eq!(keys.pq_algorithm, None);
+
+        let transfer = solana_sdk::system_instruction::transfer(&pubkey, &Pubkey::new_unique(), 1);
+        let message = Message::new(&[transfer], Some(&pubkey)).serialize();
+        let response = signer.handle(SignerRequest::SignMessage { message: encode(&message) });
+        let signature = Signature::try_from(decode(response.signature, "signature").unwrap().as_slice()).unwrap();
+        assert!(signature.verify(pubkey.as_ref(), &message));
+
+        // Nothing is signed blind
+        let refused = signer.handle(SignerRequest::SignMessage { message: encode(b"transaction message") });
+        assert!(refused.signature.is_none());
+
+        let refused = signer.handle(SignerRequest::SignChallenge { challenge: encode(&[0u8; 32]) });
+        assert!(refused.signature.is_none());
+        assert_eq!(refused.error.as_deref(), Some("This signer has no PQ key"));
+    }
+
+    #[test]
+    fn test_local_signer_policy() {
+        paths::use_test_root();
+        let keypair = Keypair::new();
+        let owner = keypair.pubkey();
+        let recipient = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let policy = Policy {
+            max_transfer: Some(100.0),
+            max_daily_transfer: Some(150.0),
+            allowed_recipients: vec![recipient.to_string()],
+            ..Policy::default()
+        };
+        let signer = LocalSigner::new(keypair, None).with_policy(policy);
+
+        let sign = |instruction: solana_sdk::instruction::Instruction| {
+            let message = Message::new(&[instruction], Some(&owner)).serialize();
+            signer.handle(SignerRequest::SignMessage { message: encode(&message) })
+        };
+        let transfer = |to: &Pubkey, amount| {
+            let source = get_associated_token_address(&owner, &mint, &TOKEN_2022_PROGRAM_ID);
+            let destination = get_associated_token_address(to, &mint, &TOKEN_2022_PROGRAM_ID);
+            spl_token_2022::instruction::transfer_checked(&TOKEN_2022_PROGRAM_ID, &source, &mint, &destination, &owner, &[], amount, 6)
+                .unwrap()
+        };
+
+        assert!(sign(transfer(&recipient, 100_000_000)).signature.is_some());
+        assert!(sign(transfer(&recipient, 100_000_001)).error.is_some());
+        assert!(sign(transfer(&Pubkey::new_unique(), 1)).error.is_some());
+        assert!(sign(solana_sdk::system_instruction::transfer(&owner, &Pubkey::new_unique(), 1)).error.is_some());
+
+        // The first transfer counts against the daily limit
+        assert!(sign(transfer(&recipient, 50_000_000)).signature.is_some());
+        assert!(sign(transfer(&recipient, 1)).error.is_some());
+
+        // Unknown programs aren't signed
+        let unknown = solana_sdk::instruction::Instruction::new_with_bytes(Pubkey::new_unique(), &[], vec![]);
+        assert!(sign(unknown).error.is_some());
+    }
+
+    #[test]
+    fn test_request_wire_format() {
+        let request = SignerRequest::SignChallenge { challenge: "AAAA".to_string() };
+        assert_eq!(serde_json::to_string(&request).unwrap(), r#"{"method":"sign_challenge","challenge":"AAAA"}"#);
+        assert_eq!(serde_json::from_str::<SignerRequest>(r#"{"method":"pubkey"}"#).unwrap(), SignerRequest::Pubkey);
+    }
+}
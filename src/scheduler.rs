@@ -0,0 +1,388 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Duration, Local, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use solana_sdk::pubkey::Pubkey;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::paths;
+use crate::solana::units;
+
+/// A pending lock picked up by the `schedule` daemon
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledLock {
+    pub keypair_path: String,
+    pub wallet: String,
+    pub rpc_url: String,
+    pub program_id: String,
+    /// RFC 3339
+    pub lock_at: String,
+    pub created_at: String,
+    /// Failed attempts so far; the lock is retried with backoff until `MAX_LOCK_ATTEMPTS`
+    #[serde(default)]
+    pub attempts: u32,
+}
+
+/// Attempts at a scheduled lock before the daemon gives up on it
+pub const MAX_LOCK_ATTEMPTS: u32 = 6;
+
+impl ScheduledLock {
+    pub fn lock_time(&self) -> Result<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&self.lock_at)
+            .map(|at| at.with_timezone(&Utc))
+            .with_context(|| format!("Invalid scheduled lock time '{}'", self.lock_at))
+    }
+
+    /// The same lock moved to its next attempt after a failure at `now`: one
+    /// minute later, doubling each time up to an hour. `None` once it has
+    /// used up `MAX_LOCK_ATTEMPTS`.
+    pub fn retry(&self, now: DateTime<Utc>) -> Option<ScheduledLock> {
+        let attempts = self.attempts + 1;
+        if attempts >= MAX_LOCK_ATTEMPTS {
+            return None;
+        }
+        let backoff = Duration::minutes((1i64 << (attempts - 1)).min(60));
+        let lock_at = now.checked_add_signed(backoff)?;
+        Some(ScheduledLock { lock_at: lock_at.to_rfc3339(), attempts, ..self.clone() })
+    }
+}
+
+/// A transfer picked up by the `schedule` daemon, once or on an interval.
+/// Only the parameters are kept: it's built and signed when it's due, after
+/// checking the vault's balance and lock state then.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledTransfer {
+    /// Assigned by `Schedule::add_transfer`, for `schedule cancel`
+    #[serde(default)]
+    pub id: u64,
+    pub keypair_path: String,
+    pub wallet: String,
+    pub recipient: String,
+    pub mint: String,
+    /// Base units
+    pub amount: u64,
+    pub rpc_url: String,
+    pub program_id: String,
+    /// RFC 3339; the next run for a recurring transfer
+    pub send_at: String,
+    /// Repeat interval (see `parse_duration`), `None` for a one-off transfer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<String>,
+    pub created_at: String,
+}
+
+impl ScheduledTransfer {
+    /// `amount` in tokens, with the mint's decimals if they've been looked up
+    pub fn tokens(&self) -> f64 {
+        let decimals = Pubkey::from_str(&self.mint).map_or(units::DEFAULT_DECIMALS, |mint| units::decimals(&mint));
+        units::to_tokens(self.amount, decimals)
+    }
+
+    pub fn send_time(&self) -> Result<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&self.send_at)
+            .map(|at| at.with_timezone(&Utc))
+            .with_context(|| format!("Invalid scheduled transfer time '{}'", self.send_at))
+    }
+
+    /// First run of a recurring transfer after `now`. Runs missed while the
+    /// daemon was down are skipped rather than sent in a burst.
+    pub fn next_run(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let every = parse_duration(self.every.as_deref()?).ok()?;
+        let mut next = self.send_time().ok()?.checked_add_signed(every)?;
+        if next <= now {
+            let missed = (now - next).num_seconds() / every.num_seconds() + 1;
+            next = next.checked_add_signed(Duration::try_seconds(every.num_seconds().checked_mul(missed)?)?)?;
+        }
+        Some(next)
+    }
+}
+
+/// Pending scheduled locks and transfers, stored in `schedule.json` in the config directory
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Schedule {
+    pub locks: Vec<ScheduledLock>,
+    #[serde(default)]
+    pub transfers: Vec<ScheduledTransfer>,
+    /// ID for the next transfer added
+    #[serde(default)]
+    pub next_id: u64,
+}
+
+impl Schedule {
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let data = fs::read_to_string(&path).context("Failed to read schedule")?;
+        serde_json::from_str(&data).context("Failed to parse schedule")
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).context("Failed to create config directory")?;
+        }
+
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize schedule")?;
+        fs::write(&path, json).context("Failed to write schedule")
+    }
+
+    fn path() -> PathBuf {
+        paths::config_dir().join("schedule.json")
+    }
+
+    /// Add a lock, replacing any pending one for the same wallet
+    pub fn add(&mut self, lock: ScheduledLock) {
+        self.locks.retain(|l| l.wallet != lock.wallet);
+        self.locks.push(lock);
+        self.locks.sort_by_key(|l| l.lock_time().ok());
+    }
+
+    /// Put back a lock that failed, unless a new one has been scheduled for
+    /// the wallet since
+    pub fn requeue(&mut self, lock: ScheduledLock) {
+        if !self.locks.iter().any(|l| l.wallet == lock.wallet) {
+            self.add(lock);
+        }
+    }
+
+    /// Remove and return every lock that is due at `now` (unparseable entries count as due)
+    pub fn take_due(&mut self, now: DateTime<Utc>) -> Vec<ScheduledLock> {
+        let (due, pending) = self.locks.drain(..).partition(|l| l.lock_time().map_or(true, |at| at <= now));
+        self.locks = pending;
+        due
+    }
+
+    /// Add a transfer under a new ID and return the ID. Unlike locks,
+    /// several can be pending for one wallet.
+    pub fn add_transfer(&mut self, mut transfer: ScheduledTransfer) -> u64 {
+        self.next_id = self.next_id.max(1);
+        transfer.id = self.next_id;
+        self.next_id += 1;
+        self.insert_transfer(transfer);
+        self.next_id - 1
+    }
+
+    fn insert_transfer(&mut self, transfer: ScheduledTransfer) {
+        self.transfers.push(transfer);
+        self.transfers.sort_by_key(|t| t.send_time().ok());
+    }
+
+    /// Remove the transfer with this ID, returning it
+    pub fn cancel_transfer(&mut self, id: u64) -> Option<ScheduledTransfer> {
+        let index = self.transfers.iter().position(|t| t.id == id)?;
+        Some(self.transfers.remove(index))
+    }
+
+    /// Remove and return every transfer due at `now`, putting recurring ones
+    /// back for their next run
+    pub fn take_due_transfers(&mut self, now: DateTime<Utc>) -> Vec<ScheduledTransfer> {
+        let (due, pending): (Vec<_>, Vec<_>) =
+            self.transfers.drain(..).partition(|t| t.send_time().is_ok_and(|at| at <= now));
+        self.transfers = pending;
+        for transfer in &due {
+            if let Some(next) = transfer.next_run(now) {
+                self.insert_transfer(ScheduledTransfer { send_at: next.to_rfc3339(), ..transfer.clone() });
+            }
+        }
+        due
+    }
+
+    /// Remove and return transfers whose time can't be read. They are never
+    /// sent: when they were meant to go out is unknown.
+    pub fn take_invalid_transfers(&mut self) -> Vec<ScheduledTransfer> {
+        let (invalid, valid) = self.transfers.drain(..).partition(|t| t.send_time().is_err());
+        self.transfers = valid;
+        invalid
+    }
+}
+
+/// Parse a duration like `90s`, `15m`, `2h`, `1d`, `1w` or `1h30m`
+pub fn parse_duration(input: &str) -> Result<Duration> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(anyhow!("Empty duration"));
+    }
+
+    let mut total = Duration::zero();
+    let mut digits = String::new();
+
+    for c in input.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+
+        let value: i64 = digits
+            .parse()
+            .map_err(|_| anyhow!("Invalid duration '{}' (expected e.g. 30m, 2h, 1h30m)", input))?;
+        digits.clear();
+
+        let part = match c {
+            's' => Duration::try_seconds(value),
+            'm' => Duration::try_minutes(value),
+            'h' => Duration::try_hours(value),
+            'd' => Duration::try_days(value),
+            'w' => Duration::try_weeks(value),
+            _ => return Err(anyhow!("Unknown duration unit '{}' in '{}' (use s, m, h, d or w)", c, input)),
+        };
+        total = part
+            .and_then(|part| total.checked_add(&part))
+            .ok_or_else(|| anyhow!("Duration '{}' is too long", input))?;
+    }
+
+    if !digits.is_empty() {
+        return Err(anyhow!("Missing unit in duration '{}' (e.g. 30m)", input));
+    }
+    if total <= Duration::zero() {
+        return Err(anyhow!("Duration must be positive"));
+    }
+
+    Ok(total)
+}
+
+/// `duration` from now, failing instead of overflowing for absurd durations
+pub fn from_now(duration: Duration) -> Result<DateTime<Utc>> {
+    Utc::now()
+        .checked_add_signed(duration)
+        .ok_or_else(|| anyhow!("Time out of range"))
+}
+
+/// Parse a scheduled time: RFC 3339, `YYYY-MM-DD HH:MM` or `HH:MM` (local time, next occurrence)
+pub fn parse_time(input: &str, now: DateTime<Local>) -> Result<DateTime<Utc>> {
+    let input = input.trim();
+
+    if let Ok(at) = DateTime::parse_from_rfc3339(input) {
+        return Ok(at.with_timezone(&Utc));
+    }
+
+    // RFC 3339 without seconds, e.g. 2025-07-01T09:00Z
+    if let (Some(minutes), Some(zone)) = (input.get(..16), input.get(16..)) {
+        if let Ok(at) = DateTime::parse_from_rfc3339(&format!("{}:00{}", minutes, zone)) {
+            return Ok(at.with_timezone(&Utc));
+        }
+    }
+
+    if let Ok(naive) = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M") {
+        return Local
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|at| at.with_timezone(&Utc))
+            .ok_or_else(|| anyhow!("'{}' does not exist in the local timezone", input));
+    }
+
+    let time = NaiveTime::parse_from_str(input, "%H:%M")
+        .map_err(|_| anyhow!("Invalid time '{}' (use HH:MM, 'YYYY-MM-DD HH:MM' or RFC 3339)", input))?;
+
+    let mut date = now.date_naive();
+    if time <= now.time() {
+        date = date.succ_opt().ok_or_else(|| anyhow!("Date out of range"))?;
+    }
+
+    Local
+        .from_local_datetime(&date.and_time(time))
+        .earliest()
+        .map(|at| at.with_timezone(&Utc))
+        .ok_or_else(|| anyhow!("'{}' does not exist in the local timezone", input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("15m").unwrap(), Duration::minutes(15));
+        assert_eq!(parse_duration("1h30m").unwrap(), Duration::minutes(90));
+        assert!(parse_duration("15").is_err());
+        assert_eq!(parse_duration("1w").unwrap(), Duration::days(7));
+        assert!(parse_duration("5y").is_err());
+        assert!(parse_duration("0m").is_err());
+        assert!(parse_duration("99999999999999w").is_err());
+        assert!(from_now(parse_duration("15000000w").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_parse_time_without_seconds() {
+        let at = parse_time("2025-07-01T09:00Z", Local::now()).unwrap();
+        assert_eq!(at.to_rfc3339(), "2025-07-01T09:00:00+00:00");
+        assert!(parse_time("2025-07-01T09:00:00+02:00", Local::now()).is_ok());
+    }
+
+    #[test]
+    fn test_recurring_transfer_is_rescheduled() {
+        let now = Utc::now();
+        let transfer = |send_at: DateTime<Utc>, every: Option<&str>| ScheduledTransfer {
+            id: 0,
+            keypair_path: "/path/to/keypair.json".to_string(),
+            wallet: "a".to_string(),
+            recipient: "b".to_string(),
+            mint: String::new(),
+            amount: 1,
+            rpc_url: String::new(),
+            program_id: String::new(),
+            send_at: send_at.to_rfc3339(),
+            every: every.map(str::to_string),
+            created_at: now.to_rfc3339(),
+        };
+
+        let mut schedule = Schedule::default();
+        let once = schedule.add_transfer(transfer(now - Duration::minutes(1), None));
+        // Three weeks overdue: one run now, the next a week after the last missed one
+        let weekly = schedule.add_transfer(transfer(now - Duration::days(21) + Duration::hours(1), Some("1w")));
+        assert_ne!(once, weekly);
+
+        let due = schedule.take_due_transfers(now);
+        assert_eq!(due.len(), 2);
+        assert_eq!(schedule.transfers.len(), 1);
+        assert_eq!(schedule.transfers[0].id, weekly);
+        let next = schedule.transfers[0].send_time().unwrap();
+        assert!(next > now && next <= now + Duration::days(7));
+
+        assert!(schedule.cancel_transfer(weekly).is_some());
+        assert!(schedule.transfers.is_empty());
+
+        // A transfer whose time can't be read is never due, only reported
+        schedule.add_transfer(ScheduledTransfer { send_at: "soon".to_string(), ..transfer(now, None) });
+        assert!(schedule.take_due_transfers(now).is_empty());
+        assert_eq!(schedule.take_invalid_transfers().len(), 1);
+        assert!(schedule.transfers.is_empty());
+    }
+
+    #[test]
+    fn test_take_due() {
+        let now = Utc::now();
+        let lock = |wallet: &str, offset: i64| ScheduledLock {
+            keypair_path: "/path/to/keypair.json".to_string(),
+            wallet: wallet.to_string(),
+            rpc_url: String::new(),
+            program_id: String::new(),
+            lock_at: (now + Duration::minutes(offset)).to_rfc3339(),
+            created_at: now.to_rfc3339(),
+            attempts: 0,
+        };
+
+        let mut schedule = Schedule::default();
+        schedule.add(lock("a", -1));
+        schedule.add(lock("b", 10));
+
+        let due = schedule.take_due(now);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].wallet, "a");
+        assert_eq!(schedule.locks.len(), 1);
+
+        // A failed lock comes back a minute later, unless the wallet has been rescheduled
+        let retry = due[0].retry(now).unwrap();
+        assert_eq!(retry.attempts, 1);
+        assert_eq!(retry.lock_time().unwrap().timestamp(), (now + Duration::minutes(1)).timestamp());
+        schedule.requeue(retry.clone());
+        assert_eq!(schedule.locks.len(), 2);
+        schedule.requeue(retry);
+        assert_eq!(schedule.locks.len(), 2);
+        let last = ScheduledLock { attempts: MAX_LOCK_ATTEMPTS - 1, ..due[0].clone() };
+        assert!(last.retry(now).is_none());
+    }
+}
@@ -0,0 +1,397 @@
+//! HTTP API (`serve`)
+//!
+//! Lets web frontends and bots read and drive vaults without shelling out.
+//! Read endpoints:
+//!
+//! - `GET /v1/vaults` — configured vaults
+//! - `GET /v1/status` — [`StatusReport`] of the active vault
+//! - `GET /v1/vaults/{name}/status` — [`StatusReport`] of a vault
+//! - `GET /v1/vaults/{name}/balances` — its SOL and token balances
+//!
+//! Action endpoints, only with `--enable-actions`:
+//!
+//! - `POST /v1/vaults/{name}/lock` — returns the [`Receipt`]
+//! - `POST /v1/vaults/{name}/unlock[?delay_slots=N]` — a server-sent event
+//!   stream of `progress` (`{step, total, message}`) and `retry` events, then
+//!   `done` with the receipt or `error`. The unlock keeps going if the client
+//!   disconnects; a half-finished verification would only waste fees.
+//!
+//! When `QDUM_API_TOKEN` is set, every request needs `Authorization: Bearer
+//! <token>`; actions can't be enabled without it. Errors are `{"error",
+//! "exit_code"}` with the code from [`crate::exit_code`].
+
+use anyhow::{bail, Context, Result};
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_stream::{Stream, StreamExt};
+
+use crate::crypto::sphincs::SphincsKeyManager;
+use crate::exit_code::{self, ExitKind};
+use crate::monitor::MonitoredVault;
+use crate::solana::client::{ProgressCallback, StatusReport, TokenBalance, UnlockOptions, UnlockSigner, VaultClient};
+use crate::solana::receipt::Receipt;
+use crate::vault_manager::VaultProfile;
+
+/// Environment variable holding the API bearer token
+pub const TOKEN_ENV: &str = "QDUM_API_TOKEN";
+
+/// A vault the API serves: its profile (keys) and where it lives
+pub struct ServedVault {
+    pub profile: VaultProfile,
+    pub target: MonitoredVault,
+}
+
+impl ServedVault {
+    fn client(&self) -> Result<VaultClient> {
+        // API reads must never be stale
+        VaultClient::builder()
+            .rpc_url(&self.target.rpc_url)
+            .program_id(self.target.program_id)
+            .account_cache_ttl(Duration::ZERO)
+            .build()
+    }
+}
+
+pub struct ServerOptions {
+    pub listen: SocketAddr,
+    pub enable_actions: bool,
+    pub token: Option<String>,
+}
+
+struct AppState {
+    vaults: Vec<ServedVault>,
+    active_vault: Option<String>,
+    enable_actions: bool,
+    token: Option<String>,
+    /// Vaults with a lock or unlock in flight
+    busy: Mutex<HashSet<String>>,
+}
+
+impl AppState {
+    fn vault(&self, name: &str) -> Result<&ServedVault, ApiError> {
+        self.vaults
+            .iter()
+            .find(|vault| vault.target.name == name)
+            .ok_or_else(|| ApiError::new(StatusCode::NOT_FOUND, format!("Vault '{}' does not exist", name)))
+    }
+
+    fn authorize(&self, headers: &HeaderMap) -> Result<(), ApiError> {
+        let Some(token) = &self.token else {
+            return Ok(());
+        };
+        let presented = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        match presented {
+            Some(presented) if constant_time_eq(presented.as_bytes(), token.as_bytes()) => Ok(()),
+            _ => Err(ApiError::new(StatusCode::UNAUTHORIZED, "Missing or invalid bearer token")),
+        }
+    }
+
+    fn authorize_action(&self, headers: &HeaderMap) -> Result<(), ApiError> {
+        if !self.enable_actions {
+            return Err(ApiError::new(StatusCode::FORBIDDEN, "Actions are disabled; start the server with --enable-actions"));
+        }
+        self.authorize(headers)
+    }
+}
+
+/// Marks a vault busy until dropped, so two actions never race on one vault
+struct BusyGuard {
+    state: Arc<AppState>,
+    name: String,
+}
+
+impl BusyGuard {
+    fn claim(state: &Arc<AppState>, name: &str) -> Result<Self, ApiError> {
+        if !state.busy.lock().unwrap().insert(name.to_string()) {
+            return Err(ApiError::new(StatusCode::CONFLICT, format!("An action on '{}' is already running", name)));
+        }
+        Ok(Self { state: Arc::clone(state), name: name.to_string() })
+    }
+}
+
+impl Drop for BusyGuard {
+    fn drop(&mut self) {
+        self.state.busy.lock().unwrap().remove(&self.name);
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+    exit_code: u8,
+}
+
+struct ApiError {
+    status: StatusCode,
+    body: ErrorBody,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        let kind = match status {
+            StatusCode::NOT_FOUND | StatusCode::BAD_REQUEST => ExitKind::Usage,
+            _ => ExitKind::Failure,
+        };
+        Self { status, body: ErrorBody { error: message.into(), exit_code: kind.code() } }
+    }
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(error: anyhow::Error) -> Self {
+        let kind = exit_code::classify(&error);
+        let status = match kind {
+            ExitKind::Usage => StatusCode::BAD_REQUEST,
+            ExitKind::VaultLocked => StatusCode::CONFLICT,
+            ExitKind::Policy => StatusCode::FORBIDDEN,
+            ExitKind::InsufficientFunds | ExitKind::Program => StatusCode::UNPROCESSABLE_ENTITY,
+            ExitKind::Rpc => StatusCode::BAD_GATEWAY,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        Self { status, body: ErrorBody { error: format!("{:#}", error), exit_code: kind.code() } }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(self.body)).into_response()
+    }
+}
+
+#[derive(Serialize)]
+struct VaultSummary<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    wallet: String,
+    active: bool,
+    rpc_url: &'a str,
+    program_id: String,
+    lock_mode: crate::solana::client::LockMode,
+}
+
+#[derive(Serialize)]
+struct Balances {
+    sol_lamports: u64,
+    tokens: Vec<TokenBalance>,
+}
+
+#[derive(Deserialize)]
+struct UnlockQuery {
+    #[serde(default)]
+    delay_slots: u64,
+}
+
+#[derive(Serialize)]
+struct Progress {
+    step: usize,
+    total: usize,
+    message: String,
+}
+
+async fn list_vaults(State(state): State<Arc<AppState>>, headers: HeaderMap) -> Result<Response, ApiError> {
+    state.authorize(&headers)?;
+    let vaults: Vec<VaultSummary> = state
+        .vaults
+        .iter()
+        .map(|vault| VaultSummary {
+            name: &vault.target.name,
+            description: vault.profile.description.as_deref(),
+            wallet: vault.target.wallet.to_string(),
+            active: state.active_vault.as_deref() == Some(vault.target.name.as_str()),
+            rpc_url: &vault.target.rpc_url,
+            program_id: vault.target.program_id.to_string(),
+            lock_mode: vault.profile.lock_mode,
+        })
+        .collect();
+    Ok(Json(vaults).into_response())
+}
+
+async fn report(vault: &ServedVault) -> Result<StatusReport, ApiError> {
+    let target = &vault.target;
+    Ok(vault.client()?.status_report(target.wallet, &target.mints).await?)
+}
+
+async fn active_status(State(state): State<Arc<AppState>>, headers: HeaderMap) -> Result<Json<StatusReport>, ApiError> {
+    state.authorize(&headers)?;
+    let name = state
+        .active_vault
+        .as_deref()
+        .ok_or_else(|| ApiError::new(StatusCode::NOT_FOUND, "No active vault"))?;
+    Ok(Json(report(state.vault(name)?).await?))
+}
+
+async fn vault_status(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+) -> Result<Json<StatusReport>, ApiError> {
+    state.authorize(&headers)?;
+    Ok(Json(report(state.vault(&name)?).await?))
+}
+
+async fn vault_balances(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+) -> Result<Json<Balances>, ApiError> {
+    state.authorize(&headers)?;
+    let status = report(state.vault(&name)?).await?;
+    Ok(Json(Balances { sol_lamports: status.sol_lamports, tokens: status.tokens }))
+}
+
+async fn lock(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+) -> Result<Json<Receipt>, ApiError> {
+    state.authorize_action(&headers)?;
+    let vault = state.vault(&name)?;
+    let _busy = BusyGuard::claim(&state, &name)?;
+    let receipt = vault
+        .client()?
+        .lock_vault(vault.target.wallet, &vault.profile.solana_keypair_path)
+        .await?;
+    Ok(Json(receipt))
+}
+
+fn event(name: &str, data: &impl Serialize) -> Event {
+    Event::default()
+        .event(name)
+        .data(serde_json::to_string(data).unwrap_or_default())
+}
+
+async fn unlock(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    Query(query): Query<UnlockQuery>,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    state.authorize_action(&headers)?;
+    let vault = state.vault(&name)?;
+    let busy = BusyGuard::claim(&state, &name)?;
+
+    let key_manager = SphincsKeyManager::new(None)?;
+    let privkey = key_manager.load_private_key(Some(vault.profile.sphincs_private_key_path.clone()))?;
+    let pubkey = key_manager.load_public_key(Some(vault.profile.sphincs_public_key_path.clone()))?;
+    let client = vault.client()?;
+    let wallet = vault.target.wallet;
+    let keypair_path = vault.profile.solana_keypair_path.clone();
+    let options = UnlockOptions { lock_mode: vault.profile.lock_mode, delay_slots: query.delay_slots, resume_after: 0 };
+
+    let (events, stream) = tokio::sync::mpsc::unbounded_channel();
+    let progress = events.clone();
+    let callback: ProgressCallback = Box::new(move |step, total, message| {
+        let _ = progress.send(event("progress", &Progress { step, total, message }));
+    });
+
+    // Runs to the end even if the client goes away
+    tokio::spawn(async move {
+        let _busy = busy;
+        let result = client
+            .unlock_vault(wallet, &keypair_path, UnlockSigner::PrivateKey(&privkey), &pubkey, options, Some(callback))
+            .await;
+        let _ = events.send(match result {
+            Ok(receipt) => event("done", &receipt),
+            Err(error) => event("error", &ApiError::from(error).body),
+        });
+    });
+
+    Ok(Sse::new(UnboundedReceiverStream::new(stream).map(Ok)).keep_alive(KeepAlive::default()))
+}
+
+/// Compare tokens without leaking how much of a guess matched
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/v1/vaults", get(list_vaults))
+        .route("/v1/status", get(active_status))
+        .route("/v1/vaults/:name/status", get(vault_status))
+        .route("/v1/vaults/:name/balances", get(vault_balances))
+        .route("/v1/vaults/:name/lock", post(lock))
+        .route("/v1/vaults/:name/unlock", post(unlock))
+        .with_state(state)
+}
+
+/// Serve the API until interrupted
+pub async fn serve(vaults: Vec<ServedVault>, active_vault: Option<String>, options: ServerOptions) -> Result<()> {
+    if options.enable_actions && options.token.is_none() {
+        bail!("--enable-actions requires an API token in ${}", TOKEN_ENV);
+    }
+    if options.token.is_none() && !options.listen.ip().is_loopback() {
+        println!(
+            "{} Listening on {} without a token; anyone who can reach it can read your vaults",
+            "[!]".yellow(),
+            options.listen
+        );
+    }
+
+    let listener = tokio::net::TcpListener::bind(options.listen)
+        .await
+        .with_context(|| format!("Failed to listen on {}", options.listen))?;
+
+    println!("{} Serving {} vault(s) on http://{} (Ctrl+C to stop)", "[i]".bright_blue(), vaults.len(), options.listen);
+    println!(
+        "{} Actions (lock, unlock): {}",
+        "[i]".bright_blue(),
+        if options.enable_actions { "enabled".bright_yellow() } else { "disabled".dimmed() }
+    );
+    println!();
+
+    let state = Arc::new(AppState {
+        vaults,
+        active_vault,
+        enable_actions: options.enable_actions,
+        token: options.token,
+        busy: Mutex::new(HashSet::new()),
+    });
+    axum::serve(listener, router(state))
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await
+        .context("API server failed")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorize() {
+        let state = AppState {
+            vaults: Vec::new(),
+            active_vault: None,
+            enable_actions: false,
+            token: Some("secret".to_string()),
+            busy: Mutex::new(HashSet::new()),
+        };
+        let mut headers = HeaderMap::new();
+        assert!(state.authorize(&headers).is_err());
+
+        headers.insert(header::AUTHORIZATION, "Bearer wrong!".parse().unwrap());
+        assert!(state.authorize(&headers).is_err());
+
+        headers.insert(header::AUTHORIZATION, "Bearer secret".parse().unwrap());
+        assert!(state.authorize(&headers).is_ok());
+        // Actions stay off without --enable-actions, even with the token
+        assert_eq!(state.authorize_action(&headers).err().map(|e| e.status), Some(StatusCode::FORBIDDEN));
+    }
+}
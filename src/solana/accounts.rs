@@ -0,0 +1,305 @@
+//! Typed views of the vault program's accounts
+//!
+//! Anchor stores an account as an 8-byte discriminator followed by the
+//! Borsh-encoded struct. Everything that reads vault state goes through these
+//! types, so a layout change only has to be made here.
+
+use anyhow::{Context, Result};
+use borsh::{io, BorshDeserialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::crypto::algorithm::Algorithm;
+use crate::crypto::sphincs::SphincsParams;
+
+/// Anchor account discriminator length
+const DISCRIMINATOR_LEN: usize = 8;
+
+/// Time a wallet has to wait between airdrop claims
+pub const AIRDROP_COOLDOWN_SECS: i64 = 24 * 60 * 60;
+
+/// Decode an Anchor account body. Trailing bytes (space reserved for
+/// fields added later) are ignored.
+fn parse_anchor<T: BorshDeserialize>(data: &[u8], name: &str) -> Result<T> {
+    let mut body = data
+        .get(DISCRIMINATOR_LEN..)
+        .with_context(|| format!("{} account data is truncated", name))?;
+    T::deserialize(&mut body).with_context(|| format!("Invalid {} account data", name))
+}
+
+/// Read a field that older accounts were created without, defaulting when
+/// absent. Borsh reports running out of input as `InvalidData`; these fields
+/// are all integers, which can't be invalid any other way.
+fn read_optional<T: BorshDeserialize + Default, R: io::Read>(reader: &mut R) -> io::Result<T> {
+    match T::deserialize_reader(reader) {
+        Ok(value) => Ok(value),
+        Err(e) if matches!(e.kind(), io::ErrorKind::UnexpectedEof | io::ErrorKind::InvalidData) => Ok(T::default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Per-wallet PQ account (PDA seeded with `pq_account` + wallet)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PqAccount {
+    pub owner: Pubkey,
+    /// Algorithm byte, see `Algorithm::algorithm_id`
+    pub algorithm: u8,
+    pub public_key: Vec<u8>,
+    pub tokens_locked: bool,
+    /// Message the PQ key must sign to unlock
+    pub unlock_challenge: [u8; 32],
+    /// Slot at which a delayed unlock takes effect (0 = none pending).
+    /// Accounts created before delayed unlocks don't store it.
+    pub pending_unlock_slot: u64,
+    /// `LockMode` byte set at registration
+    pub lock_mode: u8,
+    /// Unix time of the last airdrop claim (0 = never claimed).
+    /// Accounts created before airdrops don't store it.
+    pub last_airdrop_claim: i64,
+}
+
+impl BorshDeserialize for PqAccount {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Ok(Self {
+            owner: Pubkey::deserialize_reader(reader)?,
+            algorithm: u8::deserialize_reader(reader)?,
+            public_key: Vec::<u8>::deserialize_reader(reader)?,
+            tokens_locked: bool::deserialize_reader(reader)?,
+            unlock_challenge: <[u8; 32]>::deserialize_reader(reader)?,
+            pending_unlock_slot: read_optional(reader)?,
+            lock_mode: read_optional(reader)?,
+            last_airdrop_claim: read_optional(reader)?,
+        })
+    }
+}
+
+impl PqAccount {
+    /// Byte range of the owner, for RPC responses sliced to the account prefix
+    pub const OWNER_RANGE: std::ops::Range<usize> = DISCRIMINATOR_LEN..DISCRIMINATOR_LEN + 32;
+
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        parse_anchor(data, "PQ")
+    }
+
+    /// Owner of a (possibly sliced) PQ account
+    pub fn parse_owner(data: &[u8]) -> Option<Pubkey> {
+        data.get(Self::OWNER_RANGE).map(|bytes| Pubkey::try_from(bytes).unwrap())
+    }
+
+    /// Offset of the lock flag for a given public key length, for `memcmp` filters
+    pub const fn tokens_locked_offset(public_key_len: usize) -> usize {
+        DISCRIMINATOR_LEN + 32 + 1 + 4 + public_key_len
+    }
+
+    /// Registered algorithm, if the CLI knows it
+    pub fn algorithm(&self) -> Option<Algorithm> {
+        Algorithm::from_algorithm_id(self.algorithm)
+    }
+
+    /// Slot a delayed unlock is waiting for, if any
+    pub fn pending_unlock(&self) -> Option<u64> {
+        (self.pending_unlock_slot != 0).then_some(self.pending_unlock_slot)
+    }
+
+    /// Unix time from which the airdrop can be claimed again, if it has been claimed
+    pub fn next_airdrop_claim(&self) -> Option<i64> {
+        (self.last_airdrop_claim != 0).then_some(self.last_airdrop_claim + AIRDROP_COOLDOWN_SECS)
+    }
+
+    /// Approximate size of a PQ account (discriminator, owner, algorithm,
+    /// public key, lock flag, challenge, pending unlock slot, lock mode, last airdrop claim)
+    pub fn space(algorithm: Algorithm) -> usize {
+        DISCRIMINATOR_LEN + 32 + 1 + (4 + algorithm.public_key_size()) + 1 + 32 + 8 + 1 + 8
+    }
+}
+
+/// Global mint state (PDA seeded with `mint_state`)
+#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize)]
+pub struct MintState {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub total_minted: u64,
+    pub dev_wallet: Pubkey,
+    pub transfer_hook_program: Pubkey,
+    pub authority_minted: u64,
+    pub airdrop_distributed: u64,
+}
+
+impl MintState {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        parse_anchor(data, "mint state")
+    }
+}
+
+/// Signature staging area for an unlock (PDA seeded with `sphincs_sig` + payer + identifier)
+#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize)]
+pub struct SignatureStorage {
+    pub owner: Pubkey,
+    pub identifier: String,
+    pub public_key: Vec<u8>,
+    /// Challenge being signed
+    pub message: Vec<u8>,
+    pub signature: Vec<u8>,
+    /// Signature bytes uploaded so far
+    pub bytes_written: u32,
+}
+
+impl SignatureStorage {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        parse_anchor(data, "signature storage")
+    }
+
+    /// Approximate size (discriminator, owner, identifier, public key, challenge, signature, write cursor)
+    pub fn space(algorithm: Algorithm) -> usize {
+        DISCRIMINATOR_LEN
+            + 32
+            + (4 + 16)
+            + (4 + algorithm.public_key_size())
+            + (4 + 32)
+            + (4 + algorithm.signature_size())
+            + 4
+    }
+}
+
+/// Progress of a multi-transaction SPHINCS+ verification
+/// (PDA seeded with `sphincs_verify` + payer + identifier)
+#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize)]
+pub struct VerificationState {
+    pub owner: Pubkey,
+    pub identifier: String,
+    pub message: Vec<u8>,
+    pub public_key: Vec<u8>,
+    pub fors_root: [u8; 32],
+    /// Root recomputed at each completed hypertree layer
+    pub layer_roots: Vec<[u8; 32]>,
+    pub unlock_duration_slots: u64,
+    /// Verification instructions completed so far
+    pub steps_completed: u32,
+    pub verified: bool,
+}
+
+impl VerificationState {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        parse_anchor(data, "verification state")
+    }
+
+    /// Approximate size (discriminator, owner, identifier, challenge, public key,
+    /// FORS root, one root per layer, progress)
+    pub fn space(params: SphincsParams) -> usize {
+        DISCRIMINATOR_LEN
+            + 32
+            + (4 + 16)
+            + (4 + 32)
+            + (4 + params.public_key_size())
+            + 32
+            + (4 + 32 * params.layers())
+            + 8
+            + 4
+            + 1
+    }
+}
+
+/// Inheritance (dead-man's switch) set up by a vault owner
+/// (PDA seeded with `inheritance` + owner)
+#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize)]
+pub struct InheritanceConfig {
+    /// The account itself, which isn't part of its data
+    #[borsh(skip)]
+    pub address: Pubkey,
+    pub owner: Pubkey,
+    pub beneficiary: Pubkey,
+    pub timeout_secs: i64,
+    /// Unix timestamp of the last heartbeat (or configuration)
+    pub last_heartbeat: i64,
+}
+
+impl InheritanceConfig {
+    pub fn parse(address: Pubkey, data: &[u8]) -> Result<Self> {
+        Ok(Self { address, ..parse_anchor(data, "inheritance")? })
+    }
+
+    /// Unix timestamp after which the beneficiary may claim
+    pub fn expires_at(&self) -> i64 {
+        self.last_heartbeat.saturating_add(self.timeout_secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pq_account_data(pending_unlock_slot: Option<u64>) -> Vec<u8> {
+        let owner = Pubkey::new_unique();
+        let mut data = vec![0u8; DISCRIMINATOR_LEN];
+        data.extend_from_slice(owner.as_ref());
+        data.push(Algorithm::default().algorithm_id());
+        data.extend_from_slice(&3u32.to_le_bytes());
+        data.extend_from_slice(&[7, 8, 9]);
+        data.push(1);
+        data.extend_from_slice(&[5u8; 32]);
+        if let Some(slot) = pending_unlock_slot {
+            data.extend_from_slice(&slot.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_pq_account_with_pending_unlock() {
+        let account = PqAccount::parse(&pq_account_data(Some(1234))).unwrap();
+        assert_eq!(account.public_key, vec![7, 8, 9]);
+        assert!(account.tokens_locked);
+        assert_eq!(account.unlock_challenge, [5u8; 32]);
+        assert_eq!(account.pending_unlock(), Some(1234));
+        assert_eq!(account.algorithm(), Some(Algorithm::default()));
+    }
+
+    #[test]
+    fn test_pq_account_without_pending_slot_field() {
+        let account = PqAccount::parse(&pq_account_data(None)).unwrap();
+        assert_eq!(account.pending_unlock(), None);
+    }
+
+    #[test]
+    fn test_pq_account_airdrop_cooldown() {
+        let mut data = pq_account_data(Some(0));
+        assert_eq!(PqAccount::parse(&data).unwrap().next_airdrop_claim(), None);
+
+        data.push(0);
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        let account = PqAccount::parse(&data).unwrap();
+        assert_eq!(account.last_airdrop_claim, 1_700_000_000);
+        assert_eq!(account.next_airdrop_claim(), Some(1_700_000_000 + AIRDROP_COOLDOWN_SECS));
+    }
+
+    #[test]
+    fn test_truncated_pq_account_is_an_error() {
+        let data = pq_account_data(None);
+        assert!(PqAccount::parse(&data[..50]).is_err());
+        assert!(PqAccount::parse(&data[..4]).is_err());
+    }
+
+    #[test]
+    fn test_inheritance_account() {
+        let owner = Pubkey::new_unique();
+        let beneficiary = Pubkey::new_unique();
+        let mut data = vec![0u8; DISCRIMINATOR_LEN];
+        data.extend_from_slice(owner.as_ref());
+        data.extend_from_slice(beneficiary.as_ref());
+        data.extend_from_slice(&(90 * 86_400i64).to_le_bytes());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+
+        let address = Pubkey::new_unique();
+        let config = InheritanceConfig::parse(address, &data).unwrap();
+        assert_eq!((config.address, config.owner, config.beneficiary), (address, owner, beneficiary));
+        assert_eq!(config.timeout_secs, 90 * 86_400);
+        assert_eq!(config.expires_at(), 1_700_000_000 + 90 * 86_400);
+
+        assert!(InheritanceConfig::parse(address, &data[..data.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_mint_state_airdrop_offset() {
+        let mut data = vec![0u8; 160];
+        data[152..160].copy_from_slice(&42u64.to_le_bytes());
+        assert_eq!(MintState::parse(&data).unwrap().airdrop_distributed, 42);
+    }
+}
@@ -1,24 +1,80 @@
 use anyhow::{Context, Result};
 use colored::Colorize;
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::ProgressStyle;
+use serde::{Deserialize, Serialize};
 use solana_client::{
-    rpc_client::RpcClient,
+    client_error::Result as ClientResult,
+    nonblocking::rpc_client::RpcClient,
     rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
     rpc_filter::{RpcFilterType, Memcmp},
 };
 use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
 use solana_sdk::{
+    account::Account,
     commitment_config::CommitmentConfig,
     instruction::Instruction,
     pubkey::Pubkey,
-    signature::{Keypair, Signer},
+    signature::{Keypair, Signature, Signer},
+    signers::Signers,
     transaction::Transaction,
 };
 use std::fs;
-use std::sync::{Arc, Mutex};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::{Duration, SystemTime};
 
-use crate::crypto::sphincs::{SphincsKeyManager, SPHINCS_PUBKEY_SIZE, SPHINCS_SIGNATURE_SIZE};
+use crate::crypto::algorithm::{Algorithm, PqKey};
+use crate::crypto::sphincs::SphincsKeyManager;
+use crate::exit_code::ExitKind;
+use crate::output;
+use crate::policy::{Policy, Spend, SpendingLedger};
+use crate::recipients::RecipientHistory;
+use crate::remote_signer::RemoteSigner;
+use crate::solana::accounts::{MintState, PqAccount};
+use crate::solana::idl::{self, Idl};
+use crate::solana::receipt::{Operation, Receipt};
+use crate::solana::units;
+
+mod challenge;
+mod bridge_checks;
+mod cluster;
+mod compat;
+mod errors;
+mod estimate;
+mod extensions;
+mod history;
+mod holders;
+mod inspect;
+mod inheritance;
+mod jito;
+mod multisig;
+mod nonce;
+mod offline;
+mod payment;
+mod recipient;
+mod resume;
+mod retry;
+mod status;
+mod sweep;
+mod token_accounts;
+mod transfer_hook;
+
+pub use challenge::UnlockChallenge;
+pub use cluster::ClusterStatus;
+pub use errors::ProgramFailure;
+pub use estimate::CostEstimate;
+pub use extensions::{MintExtensions, TransferFee};
+pub use history::LockSnapshot;
+pub use inspect::{hex_dump, AccountInspection, AccountKind};
+pub use holders::{DistributionBucket, Leaderboard, LeaderboardEntry, LockedHolder, NetworkLockStats, DISTRIBUTION_BUCKETS};
+pub use jito::{JitoConfig, DEFAULT_BLOCK_ENGINE_URL, DEFAULT_TIP_LAMPORTS};
+pub use offline::{decode_transaction, encode_transaction, missing_signers};
+pub use payment::Payment;
+pub use recipient::RecipientKind;
+pub use resume::UnlockSession;
+pub use status::{StatusChange, StatusReport, TokenBalance, VaultFootprint};
+pub use token_accounts::TokenAccountInfo;
 
 /// Progress callback type for TUI integration
 /// (step_number, total_steps, message)
@@ -28,45 +84,19 @@ pub type ProgressCallback = Box<dyn FnMut(usize, usize, String) + Send>;
 const PQ_ACCOUNT_SEED: &[u8] = b"pq_account";
 
 /// SPL Token-2022 Program ID
-const TOKEN_2022_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+pub(crate) const TOKEN_2022_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
 
 /// QDUM Bridge Program ID
-const BRIDGE_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("2psMx7yfQL7yAbu6NNRathTkC1rSY4CGDvBd2qWqzirF");
+pub(crate) const BRIDGE_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("2psMx7yfQL7yAbu6NNRathTkC1rSY4CGDvBd2qWqzirF");
 
 /// SPL Token Program ID (standard)
-const SPL_TOKEN_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub(crate) const SPL_TOKEN_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
 
 /// Associated Token Program ID
-const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
-
-/// Instruction discriminators (from IDL - quantdum_token.json)
-const INITIALIZE_PQ_ACCOUNT_DISCRIMINATOR: [u8; 8] = [185, 126, 40, 29, 205, 105, 111, 213];
-const WRITE_PUBLIC_KEY_DISCRIMINATOR: [u8; 8] = [69, 199, 141, 25, 213, 45, 192, 226];
-const LOCK_TOKENS_DISCRIMINATOR: [u8; 8] = [136, 11, 32, 232, 161, 117, 54, 211];
-const CLOSE_PQ_ACCOUNT_DISCRIMINATOR: [u8; 8] = [213, 32, 12, 184, 191, 154, 92, 97];
-const CLAIM_AIRDROP_DISCRIMINATOR: [u8; 8] = [137, 50, 122, 111, 89, 254, 8, 20];
-
-// Bridge discriminators (computed from Anchor sighash)
-// wrap = sha256("global:wrap")[..8]
-const BRIDGE_WRAP_DISCRIMINATOR: [u8; 8] = [178, 40, 10, 189, 228, 129, 186, 140];
-// unwrap = sha256("global:unwrap")[..8]
-const BRIDGE_UNWRAP_DISCRIMINATOR: [u8; 8] = [126, 175, 198, 14, 212, 69, 50, 44];
-
-// SPHINCS+ verification flow discriminators
-const INITIALIZE_SPHINCS_STORAGE_DISCRIMINATOR: [u8; 8] = [140, 15, 169, 242, 61, 148, 238, 70];
-const UPLOAD_SIGNATURE_CHUNK_DISCRIMINATOR: [u8; 8] = [194, 98, 90, 80, 66, 99, 246, 39];
-const SPHINCS_VERIFY_STEP0_INIT_DISCRIMINATOR: [u8; 8] = [220, 238, 45, 110, 130, 122, 244, 163];
-const SPHINCS_VERIFY_STEP1_FORS_BATCH1_DISCRIMINATOR: [u8; 8] = [172, 180, 149, 174, 231, 243, 99, 8];
-const SPHINCS_VERIFY_STEP2_FORS_BATCH2_DISCRIMINATOR: [u8; 8] = [171, 180, 113, 96, 124, 173, 99, 26];
-const SPHINCS_VERIFY_STEP3_FORS_ROOT_DISCRIMINATOR: [u8; 8] = [49, 50, 138, 190, 206, 224, 103, 217];
-const SPHINCS_VERIFY_LAYER_WOTS_PART1_DISCRIMINATOR: [u8; 8] = [91, 71, 30, 151, 123, 241, 249, 203];
-const SPHINCS_VERIFY_LAYER_WOTS_PART2_DISCRIMINATOR: [u8; 8] = [175, 251, 183, 24, 194, 124, 11, 9];
-const SPHINCS_VERIFY_LAYER_WOTS_PART3_DISCRIMINATOR: [u8; 8] = [232, 111, 23, 93, 206, 103, 19, 220];
-const SPHINCS_VERIFY_LAYER_MERKLE_DISCRIMINATOR: [u8; 8] = [200, 98, 174, 105, 13, 24, 123, 28];
-const SPHINCS_VERIFY_STEP11_FINALIZE_DISCRIMINATOR: [u8; 8] = [34, 44, 245, 31, 130, 88, 38, 184];
+pub(crate) const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
 
 /// Compute Associated Token Account address
-fn get_associated_token_address(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+pub(crate) fn get_associated_token_address(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
     let seeds = &[
         wallet.as_ref(),
         token_program.as_ref(),
@@ -79,13 +109,13 @@ fn get_associated_token_address(wallet: &Pubkey, mint: &Pubkey, token_program: &
     address
 }
 
-/// Cache for network lock query results
-#[derive(Debug, Clone)]
+/// Cache for network lock query results, also kept in the data directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct NetworkLockCache {
-    total_locked: f64,
-    holder_count: usize,
+    holders: Vec<LockedHolder>,
     timestamp: SystemTime,
     mint: Pubkey,
+    program_id: Pubkey,
 }
 
 impl NetworkLockCache {
@@ -97,11 +127,469 @@ impl NetworkLockCache {
     }
 }
 
+/// How long account lookups are served from [`AccountCache`]
+const ACCOUNT_CACHE_TTL: Duration = Duration::from_secs(10);
+
+/// Short-lived cache of fetched accounts (mint, mint state, PQ account, ATAs)
+/// so repeated lookups and dashboard ticks don't hit the RPC every time.
+/// Only successful lookups are cached.
+#[derive(Debug, Default)]
+struct AccountCache {
+    entries: HashMap<Pubkey, (Account, SystemTime)>,
+}
+
+impl AccountCache {
+    fn get(&self, address: &Pubkey, max_age: Duration) -> Option<Account> {
+        self.entries.get(address).and_then(|(account, fetched_at)| {
+            let fresh = SystemTime::now()
+                .duration_since(*fetched_at)
+                .map(|age| age <= max_age)
+                .unwrap_or(false);
+            fresh.then(|| account.clone())
+        })
+    }
+
+    fn insert(&mut self, address: Pubkey, account: Account) {
+        self.entries.insert(address, (account, SystemTime::now()));
+    }
+
+    fn invalidate(&mut self, addresses: &[Pubkey]) {
+        for address in addresses {
+            self.entries.remove(address);
+        }
+    }
+}
+
+/// Default RPC endpoint (devnet)
+pub const DEFAULT_RPC_URL: &str = "https://api.devnet.solana.com";
+
+/// Default qdum vault program
+pub const DEFAULT_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("HyC27AVHW4VwkEiWwWxevaUpvkiAqPUueaa94og9HmLQ");
+
+/// Default compute unit price (micro-lamports) for prioritized transactions
+pub const DEFAULT_PRIORITY_FEE_MICRO_LAMPORTS: u64 = 200_000;
+
+/// Compute budget attached to every transaction the client sends
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComputeBudget {
+    /// Compute unit limit per transaction (`None` keeps the runtime default)
+    pub unit_limit: Option<u32>,
+    /// Compute unit price in micro-lamports (0 sends without a priority fee)
+    pub unit_price: u64,
+}
+
+impl Default for ComputeBudget {
+    fn default() -> Self {
+        Self {
+            unit_limit: None,
+            unit_price: DEFAULT_PRIORITY_FEE_MICRO_LAMPORTS,
+        }
+    }
+}
+
+static DEFAULT_COMPUTE_BUDGET: OnceLock<ComputeBudget> = OnceLock::new();
+
+static DEFAULT_NONCE_ACCOUNT: OnceLock<Pubkey> = OnceLock::new();
+
+/// How many times a transaction is sent before giving up
+pub const DEFAULT_MAX_SEND_ATTEMPTS: u32 = 5;
+
+/// How failed sends are retried
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first (1 disables retries)
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubles after each further attempt
+    pub base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_SEND_ATTEMPTS,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+static DEFAULT_RETRY_POLICY: OnceLock<RetryPolicy> = OnceLock::new();
+
+static DEFAULT_IDL: OnceLock<Arc<Idl>> = OnceLock::new();
+
+static DEFAULT_SIGNER: OnceLock<Arc<VaultSigner>> = OnceLock::new();
+
+static DEFAULT_POLICY: OnceLock<Arc<Policy>> = OnceLock::new();
+
+/// Set the compute budget new [`VaultClientBuilder`]s start from (first call wins)
+pub fn set_default_compute_budget(budget: ComputeBudget) {
+    let _ = DEFAULT_COMPUTE_BUDGET.set(budget);
+}
+
+/// Durable nonce account used by clients built after this call.
+/// Only the first call takes effect.
+pub fn set_default_nonce_account(nonce_account: Pubkey) {
+    let _ = DEFAULT_NONCE_ACCOUNT.set(nonce_account);
+}
+
+/// Retry policy used by clients built after this call.
+/// Only the first call takes effect.
+pub fn set_default_retry_policy(policy: RetryPolicy) {
+    let _ = DEFAULT_RETRY_POLICY.set(policy);
+}
+
+/// Program IDL used by clients built after this call, instead of the bundled one.
+/// Only the first call takes effect.
+pub fn set_default_idl(idl: Idl) {
+    let _ = DEFAULT_IDL.set(Arc::new(idl));
+}
+
+/// Signer used by clients built after this call instead of keypair files
+/// (e.g. a [`RemoteSigner`](crate::remote_signer::RemoteSigner)).
+/// Only the first call takes effect.
+pub fn set_default_signer(signer: Arc<VaultSigner>) {
+    let _ = DEFAULT_SIGNER.set(signer);
+}
+
+/// The signer set with [`set_default_signer`], if any
+pub fn default_signer() -> Option<Arc<VaultSigner>> {
+    DEFAULT_SIGNER.get().cloned()
+}
+
+/// Security policy checked by clients built after this call (the vault
+/// config's `policy`). Only the first call takes effect.
+pub fn set_default_policy(policy: Policy) {
+    let _ = DEFAULT_POLICY.set(Arc::new(policy));
+}
+
+/// How a vault is unlocked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LockMode {
+    /// Post-quantum signature proof only
+    #[default]
+    Standard,
+    /// Post-quantum proof plus an Ed25519 wallet signature over the challenge
+    Hybrid,
+}
+
+impl LockMode {
+    /// The mode stored in a PQ account (`PqAccount::lock_mode`)
+    pub fn from_account(byte: u8) -> Result<Self> {
+        match byte {
+            0 => Ok(LockMode::Standard),
+            1 => Ok(LockMode::Hybrid),
+            other => anyhow::bail!("Unknown lock mode {} in the PQ account; this client may be too old for the vault program", other),
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LockMode::Standard => "Standard (PQ only)",
+            LockMode::Hybrid => "Hybrid (Ed25519 + PQ)",
+        }
+    }
+}
+
+/// Approximate Solana slot time, used to convert delays to slots and back
+pub const SLOT_DURATION_MS: u64 = 400;
+
+/// Per-unlock settings
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnlockOptions {
+    /// Expected lock mode, for estimates only: unlocking uses the mode the
+    /// vault is registered with on-chain
+    pub lock_mode: LockMode,
+    /// Slots to wait after verification before tokens unlock (0 = immediately)
+    pub delay_slots: u64,
+    /// Steps an interrupted unlock already finished (see [`UnlockSession`]).
+    /// Only those the on-chain accounts confirm are skipped.
+    pub resume_after: usize,
+}
+
+/// Where an unlock's PQ signature over the challenge comes from
+pub enum UnlockSigner<'a> {
+    /// Sign the current challenge with this private key
+    PrivateKey(&'a PqKey),
+    /// Signature made beforehand (`unlock sign`), possibly on a machine
+    /// without network access. It must cover the current challenge.
+    Presigned(Vec<u8>),
+    /// Ask a signing service to sign the current challenge
+    Remote(&'a RemoteSigner),
+}
+
+/// Human-readable approximation of a slot count, e.g. "~1h 30m"
+pub fn format_slots(slots: u64) -> String {
+    let secs = slots * SLOT_DURATION_MS / 1000;
+    match secs {
+        0..=59 => format!("~{}s", secs),
+        60..=3599 => format!("~{}m", secs / 60),
+        _ => format!("~{}h {}m", secs / 3600, (secs % 3600) / 60),
+    }
+}
+
+/// Build an Ed25519 program instruction verifying `signature` over `message` by `pubkey`
+/// The vault program checks for it via the instructions sysvar
+fn ed25519_verify_instruction(pubkey: &Pubkey, signature: &Signature, message: &[u8]) -> Instruction {
+    const DATA_START: u16 = 16; // num_signatures(1) + padding(1) + offsets(14)
+    const PUBKEY_OFFSET: u16 = DATA_START;
+    const SIGNATURE_OFFSET: u16 = PUBKEY_OFFSET + 32;
+    const MESSAGE_OFFSET: u16 = SIGNATURE_OFFSET + 64;
+    const THIS_INSTRUCTION: u16 = u16::MAX;
+
+    let mut data = Vec::with_capacity(MESSAGE_OFFSET as usize + message.len());
+    data.push(1); // num_signatures
+    data.push(0); // padding
+    for field in [
+        SIGNATURE_OFFSET,
+        THIS_INSTRUCTION,
+        PUBKEY_OFFSET,
+        THIS_INSTRUCTION,
+        MESSAGE_OFFSET,
+        message.len() as u16,
+        THIS_INSTRUCTION,
+    ] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.extend_from_slice(pubkey.as_ref());
+    data.extend_from_slice(signature.as_ref());
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: solana_sdk::ed25519_program::id(),
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Signs transactions for a wallet: a local [`Keypair`] or a remote signer
+pub type VaultSigner = dyn Signer + Send + Sync;
+
+/// Receives progress updates from long-running operations (e.g. unlock)
+pub trait Reporter: Send + Sync {
+    fn progress(&self, step: usize, total: usize, message: &str);
+
+    /// A send failed and is about to be retried
+    fn retry(&self, _message: &str) {}
+}
+
 #[derive(Clone)]
 pub struct VaultClient {
     rpc_client: Arc<RpcClient>,
     program_id: Pubkey,
     network_lock_cache: Arc<Mutex<Option<NetworkLockCache>>>,
+    account_cache: Arc<Mutex<AccountCache>>,
+    account_cache_ttl: Duration,
+    compute_budget: ComputeBudget,
+    nonce_account: Option<Pubkey>,
+    jito: Option<JitoConfig>,
+    retry: RetryPolicy,
+    idl: Arc<Idl>,
+    /// Set on the client driving a Jito-bundled unlock
+    bundle_queue: Option<Arc<jito::BundleQueue>>,
+    reporter: Option<Arc<dyn Reporter>>,
+    signer: Option<Arc<VaultSigner>>,
+    unsigned_out: Option<PathBuf>,
+    policy: Arc<Policy>,
+}
+
+/// Builder for [`VaultClient`]
+///
+/// ```ignore
+/// let client = VaultClient::builder()
+///     .rpc_url("https://api.mainnet-beta.solana.com")
+///     .program_id(program_id)
+///     .commitment(CommitmentConfig::finalized())
+///     .build()?;
+/// ```
+pub struct VaultClientBuilder {
+    rpc_url: String,
+    program_id: Pubkey,
+    commitment: CommitmentConfig,
+    timeout: Duration,
+    account_cache_ttl: Duration,
+    compute_budget: ComputeBudget,
+    nonce_account: Option<Pubkey>,
+    jito: Option<JitoConfig>,
+    retry: RetryPolicy,
+    idl: Arc<Idl>,
+    reporter: Option<Arc<dyn Reporter>>,
+    signer: Option<Arc<VaultSigner>>,
+    unsigned_out: Option<PathBuf>,
+    policy: Arc<Policy>,
+}
+
+impl Default for VaultClientBuilder {
+    fn default() -> Self {
+        Self {
+            rpc_url: DEFAULT_RPC_URL.to_string(),
+            program_id: DEFAULT_PROGRAM_ID,
+            commitment: CommitmentConfig::confirmed(),
+            // 60 second timeout for better reliability on slow networks
+            timeout: Duration::from_secs(60),
+            account_cache_ttl: ACCOUNT_CACHE_TTL,
+            compute_budget: DEFAULT_COMPUTE_BUDGET.get().copied().unwrap_or_default(),
+            nonce_account: DEFAULT_NONCE_ACCOUNT.get().copied(),
+            jito: None,
+            retry: DEFAULT_RETRY_POLICY.get().copied().unwrap_or_default(),
+            idl: DEFAULT_IDL.get().cloned().unwrap_or_else(|| Arc::new(Idl::bundled())),
+            reporter: None,
+            signer: DEFAULT_SIGNER.get().cloned(),
+            unsigned_out: None,
+            policy: DEFAULT_POLICY.get().cloned().unwrap_or_default(),
+        }
+    }
+}
+
+impl VaultClientBuilder {
+    /// RPC endpoint URL
+    pub fn rpc_url(mut self, rpc_url: impl Into<String>) -> Self {
+        self.rpc_url = rpc_url.into();
+        self
+    }
+
+    /// Vault program ID
+    pub fn program_id(mut self, program_id: Pubkey) -> Self {
+        self.program_id = program_id;
+        self
+    }
+
+    /// Commitment level used for queries and confirmations
+    pub fn commitment(mut self, commitment: CommitmentConfig) -> Self {
+        self.commitment = commitment;
+        self
+    }
+
+    /// RPC request timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// How long fetched accounts are reused (zero disables the cache)
+    pub fn account_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.account_cache_ttl = ttl;
+        self
+    }
+
+    /// Compute unit price (micro-lamports) attached to every transaction
+    pub fn priority_fee(mut self, micro_lamports: u64) -> Self {
+        self.compute_budget.unit_price = micro_lamports;
+        self
+    }
+
+    /// Compute unit limit requested by every transaction
+    pub fn compute_unit_limit(mut self, units: u32) -> Self {
+        self.compute_budget.unit_limit = Some(units);
+        self
+    }
+
+    /// Sign with this durable nonce account instead of a recent blockhash,
+    /// so transactions stay valid until the nonce is advanced
+    pub fn durable_nonce(mut self, nonce_account: Pubkey) -> Self {
+        self.nonce_account = Some(nonce_account);
+        self
+    }
+
+    /// Submit the unlock sequence as tipped Jito bundles
+    pub fn jito(mut self, config: JitoConfig) -> Self {
+        self.jito = Some(config);
+        self
+    }
+
+    /// Retry behaviour for sends that hit an expired blockhash or timeout
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Program IDL that instructions are built and checked against
+    pub fn idl(mut self, idl: Idl) -> Self {
+        self.idl = Arc::new(idl);
+        self
+    }
+
+    /// Progress reporter for long-running operations
+    pub fn reporter(mut self, reporter: Arc<dyn Reporter>) -> Self {
+        self.reporter = Some(reporter);
+        self
+    }
+
+    /// Signer used for all transactions instead of loading the keypair
+    /// files passed to individual operations
+    pub fn signer(mut self, signer: impl Signer + Send + Sync + 'static) -> Self {
+        self.signer = Some(Arc::new(signer));
+        self
+    }
+
+    /// Write unsigned transfer, lock and close transactions to `dir` for
+    /// external signing instead of signing and sending them
+    pub fn unsigned_out(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.unsigned_out = Some(dir.into());
+        self
+    }
+
+    /// Security policy checked before signing, instead of the default one
+    pub fn policy(mut self, policy: Policy) -> Self {
+        self.policy = Arc::new(policy);
+        self
+    }
+
+    pub fn build(self) -> Result<VaultClient> {
+        if self.rpc_url.trim().is_empty() {
+            anyhow::bail!("RPC URL must not be empty");
+        }
+
+        let rpc_client = RpcClient::new_with_timeout_and_commitment(
+            self.rpc_url,
+            self.timeout,
+            self.commitment,
+        );
+
+        Ok(VaultClient {
+            rpc_client: Arc::new(rpc_client),
+            program_id: self.program_id,
+            network_lock_cache: Arc::new(Mutex::new(None)),
+            account_cache: Arc::new(Mutex::new(AccountCache::default())),
+            account_cache_ttl: self.account_cache_ttl,
+            compute_budget: self.compute_budget,
+            nonce_account: self.nonce_account,
+            jito: self.jito,
+            retry: self.retry,
+            idl: self.idl,
+            bundle_queue: None,
+            reporter: self.reporter,
+            signer: self.signer,
+            unsigned_out: self.unsigned_out,
+            policy: self.policy,
+        })
+    }
+}
+
+/// Bytes of PQ signature uploaded per transaction (max allowed by the on-chain program)
+const SIGNATURE_CHUNK_SIZE: usize = 800;
+
+/// Compute unit limit requested for single-transaction ML-DSA verification
+const MLDSA_VERIFY_COMPUTE_UNITS: u32 = 1_400_000;
+
+/// Transactions needed to unlock with `algorithm`: (signature upload incl. storage init, verification)
+///
+/// SPHINCS+ verifies in stages (init + 3 FORS + 4 per layer + finalize); ML-DSA in a single transaction
+fn unlock_transaction_plan(algorithm: Algorithm) -> (usize, usize) {
+    let upload = 1 + algorithm.signature_size().div_ceil(SIGNATURE_CHUNK_SIZE);
+    let verify = match algorithm {
+        Algorithm::SlhDsa(params) => 1 + 3 + 4 * params.layers() + 1,
+        Algorithm::MlDsa(_) => 1,
+    };
+    (upload, verify)
+}
+
+/// Storage identifier derived from the PQ public key
+///
+/// Each vault has unique keys, so this gives each vault its own storage while
+/// still allowing reuse across multiple unlocks of the same vault
+fn storage_identifier(public_key: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    hex::encode(&Sha256::digest(public_key)[..8])
 }
 
 /// Create associated token account instruction
@@ -128,29 +616,157 @@ fn create_associated_token_account_instruction(
 }
 
 impl VaultClient {
-    pub fn new(rpc_url: &str, program_id: Pubkey) -> Result<Self> {
-        // Use 60 second timeout for better reliability on slow networks
-        let rpc_client = RpcClient::new_with_timeout_and_commitment(
-            rpc_url.to_string(),
-            Duration::from_secs(60),
-            CommitmentConfig::confirmed(),
-        );
+    /// Start building a client (defaults: devnet, confirmed commitment, 60s timeout)
+    pub fn builder() -> VaultClientBuilder {
+        VaultClientBuilder::default()
+    }
 
-        Ok(Self {
-            rpc_client: Arc::new(rpc_client),
-            program_id,
-            network_lock_cache: Arc::new(Mutex::new(None)),
-        })
+    /// Security policy this client checks before signing
+    pub fn policy(&self) -> &Policy {
+        &self.policy
+    }
+
+    /// Forward a progress update to the per-call callback and the configured reporter
+    fn report(&self, callback: &mut Option<ProgressCallback>, step: usize, total: usize, message: String) {
+        if let Some(reporter) = &self.reporter {
+            reporter.progress(step, total, &message);
+        }
+        if let Some(cb) = callback {
+            cb(step, total, message);
+        }
     }
 
-    /// Load keypair from JSON file
-    fn load_keypair(&self, path: &str) -> Result<Keypair> {
+    /// Load keypair from JSON file (or use the configured signer)
+    fn load_keypair(&self, path: &str) -> Result<Arc<VaultSigner>> {
+        if let Some(signer) = &self.signer {
+            return Ok(signer.clone());
+        }
         let data = fs::read_to_string(path)
             .with_context(|| format!("Failed to read keypair from {}", path))?;
         let bytes: Vec<u8> = serde_json::from_str(&data)
             .context("Failed to parse keypair JSON")?;
-        Keypair::try_from(&bytes[..])
-            .context("Invalid keypair bytes")
+        let keypair = Keypair::try_from(&bytes[..])
+            .context("Invalid keypair bytes")?;
+        Ok(Arc::new(keypair))
+    }
+
+    /// Sign `instructions` with a fresh blockhash (or the durable nonce), prepending
+    /// the configured compute budget. Vault program instructions are first
+    /// checked against the IDL.
+    ///
+    /// Instructions that already request a compute unit limit (e.g. ML-DSA
+    /// verification) keep theirs; only the price is added.
+    async fn signed_transaction<T: Signers + ?Sized>(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        signers: &T,
+    ) -> Result<Transaction> {
+        self.policy.check_signing()?;
+        let mut transaction = self.unsigned_transaction(instructions, payer).await?;
+        let recent_blockhash = transaction.message.recent_blockhash;
+        transaction.try_sign(signers, recent_blockhash)?;
+        Ok(transaction)
+    }
+
+    /// The transaction [`signed_transaction`](Self::signed_transaction) signs
+    async fn unsigned_transaction(&self, instructions: &[Instruction], payer: &Pubkey) -> Result<Transaction> {
+        use solana_sdk::compute_budget::ComputeBudgetInstruction;
+        use solana_sdk::message::Message;
+
+        self.validate_instructions(instructions)?;
+
+        let mut budgeted = Vec::with_capacity(instructions.len() + 3);
+
+        // With a durable nonce, AdvanceNonceAccount must be the first instruction
+        // and the stored nonce stands in for the blockhash
+        let recent_blockhash = match self.nonce_account {
+            Some(nonce_account) => {
+                let nonce = self.get_nonce(&nonce_account).await?;
+                if nonce.authority != *payer {
+                    anyhow::bail!(
+                        "Nonce account {} is controlled by {}, not the fee payer {}",
+                        nonce_account,
+                        nonce.authority,
+                        payer
+                    );
+                }
+                budgeted.push(solana_sdk::system_instruction::advance_nonce_account(&nonce_account, payer));
+                nonce.blockhash
+            }
+            None => self.rpc_client.get_latest_blockhash().await?,
+        };
+
+        let has_limit = instructions.iter().any(|ix| ix.program_id == solana_sdk::compute_budget::id());
+        if let (Some(units), false) = (self.compute_budget.unit_limit, has_limit) {
+            budgeted.push(ComputeBudgetInstruction::set_compute_unit_limit(units));
+        }
+        if self.compute_budget.unit_price > 0 {
+            budgeted.push(ComputeBudgetInstruction::set_compute_unit_price(self.compute_budget.unit_price));
+        }
+        budgeted.extend_from_slice(instructions);
+
+        Ok(Transaction::new_unsigned(Message::new_with_blockhash(&budgeted, Some(payer), &recent_blockhash)))
+    }
+
+    /// Check every vault program instruction against the IDL before signing
+    fn validate_instructions(&self, instructions: &[Instruction]) -> Result<()> {
+        instructions
+            .iter()
+            .filter(|ix| ix.program_id == self.program_id)
+            .try_for_each(|ix| self.idl.validate(ix))
+    }
+
+    /// Fetch an account, reusing a recent lookup if one is cached
+    async fn get_account_cached(&self, address: &Pubkey) -> ClientResult<Account> {
+        if let Some(account) = self.account_cache.lock().unwrap().get(address, self.account_cache_ttl) {
+            return Ok(account);
+        }
+        let account = self.rpc_client.get_account(address).await?;
+        output::detail(output::DEBUG, &format!("Account {} ({} bytes):", address, account.data.len()), hex::encode(&account.data));
+        self.account_cache.lock().unwrap().insert(*address, account.clone());
+        Ok(account)
+    }
+
+    /// Drop cached copies of every account a transaction touches
+    fn invalidate_accounts(&self, transaction: &Transaction) {
+        self.account_cache.lock().unwrap().invalidate(&transaction.message.account_keys);
+    }
+
+    /// Drop the cached PQ account of `wallet`, after another client has
+    /// locked or unlocked it
+    pub fn invalidate_pq_account(&self, wallet: Pubkey) {
+        let (pq_account, _) = self.derive_pq_account(wallet);
+        self.account_cache.lock().unwrap().invalidate(&[pq_account]);
+    }
+
+    /// Send and confirm a transaction, returning its signature and the fee charged
+    async fn send_with_fee(&self, transaction: &Transaction) -> Result<(Signature, u64)> {
+        let fee = self.rpc_client.get_fee_for_message(transaction.message()).await.unwrap_or(0);
+        // Invalidate even if the send fails: it may still have landed
+        self.invalidate_accounts(transaction);
+        if let Some(queue) = &self.bundle_queue {
+            // Confirmed when its bundle lands
+            self.enqueue_bundled(queue, transaction).await?;
+            return Ok((transaction.signatures[0], fee));
+        }
+        let signature = self.rpc_client.send_and_confirm_transaction(transaction).await;
+        // Drop anything re-fetched while waiting for confirmation
+        self.invalidate_accounts(transaction);
+        let signature = signature.map_err(|e| self.explain_failure(e, transaction))?;
+        output::detail(output::VERBOSE, "Confirmed:", signature);
+        Ok((signature, fee))
+    }
+
+    /// Sign and send `instructions`, retrying per the client's [`RetryPolicy`]
+    async fn send_instructions<T: Signers + ?Sized>(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        signers: &T,
+    ) -> Result<(Signature, u64)> {
+        let transaction = self.signed_transaction(instructions, payer, signers).await?;
+        self.send_with_retry(&transaction, signers).await
     }
 
     /// Derive PQ account PDA
@@ -167,33 +783,41 @@ impl VaultClient {
         &self,
         wallet: Pubkey,
         keypair_path: &str,
-        sphincs_pubkey: &[u8; SPHINCS_PUBKEY_SIZE],
-    ) -> Result<()> {
+        sphincs_pubkey: &PqKey,
+        lock_mode: LockMode,
+    ) -> Result<Receipt> {
         println!("Wallet Address: {}", wallet.to_string().cyan());
         println!("SPHINCS+ Public Key: {}", hex::encode(sphincs_pubkey).cyan());
+        println!("Algorithm: {}", sphincs_pubkey.algorithm.to_string().cyan());
+        println!("Lock Mode: {}", lock_mode.label().cyan());
         println!();
 
         let keypair = self.load_keypair(keypair_path)?;
         let (pq_account, _) = self.derive_pq_account(wallet);
 
+        let mut receipt = Receipt::new(Operation::Register);
+        receipt.account("wallet", &wallet).account("pq_account", &pq_account);
+
         println!("PQ Account (PDA): {}", pq_account.to_string().cyan());
         println!();
 
         // Check if already registered
-        if let Ok(account_info) = self.rpc_client.get_account(&pq_account) {
+        if let Ok(account_info) = self.rpc_client.get_account(&pq_account).await {
             if account_info.data.len() > 0 {
                 println!("{}", "⚠️  PQ Account already registered!".yellow());
                 println!("   You can skip this step.");
-                return Ok(());
+                return Ok(receipt.skipped("PQ account already registered"));
             }
         }
 
+        self.check_program_compatibility(&keypair.pubkey(), &compat::register_instructions(lock_mode == LockMode::Hybrid)).await?;
+
         println!("Creating PQ account registration transaction...");
 
         // Build instruction data (algorithm only - public key written separately)
         let mut instruction_data = Vec::new();
-        instruction_data.extend_from_slice(&INITIALIZE_PQ_ACCOUNT_DISCRIMINATOR);
-        instruction_data.push(2); // Algorithm: SPHINCS+-SHA2-128s
+        instruction_data.extend_from_slice(&self.idl.discriminator("initialize_pq_account"));
+        instruction_data.push(sphincs_pubkey.algorithm.algorithm_id());
 
         let instruction = Instruction {
             program_id: self.program_id,
@@ -205,16 +829,9 @@ impl VaultClient {
             data: instruction_data,
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[&keypair],
-            recent_blockhash,
-        );
-
         println!("Sending transaction...");
-        let signature = self.rpc_client.send_and_confirm_transaction(&transaction)?;
+        let (signature, fee) = self.send_instructions(&[instruction], &keypair.pubkey(), &[&keypair]).await?;
+        receipt.record((signature, fee));
 
         println!();
         println!("{}", "✅ PQ Account Registered!".green().bold());
@@ -224,9 +841,32 @@ impl VaultClient {
 
         // Now write the SPHINCS+ public key to the PQ account
         println!("Writing SPHINCS+ public key to PQ account...");
-        self.write_public_key(wallet, keypair_path, sphincs_pubkey).await?;
+        receipt.record(self.write_public_key(wallet, keypair_path, sphincs_pubkey).await?);
 
-        Ok(())
+        if lock_mode == LockMode::Hybrid {
+            println!("Enabling hybrid unlock (Ed25519 + PQ)...");
+            receipt.record(self.enable_hybrid_unlock(&keypair, &pq_account).await?);
+
+            println!("{}", "✅ Hybrid Unlock Enabled!".green().bold());
+            println!("   Unlocking will require both your wallet and PQ signatures.");
+            println!();
+        }
+
+        Ok(receipt.finish())
+    }
+
+    /// Require a wallet signature in addition to the PQ proof for future unlocks
+    async fn enable_hybrid_unlock(&self, keypair: &Arc<VaultSigner>, pq_account: &Pubkey) -> Result<(Signature, u64)> {
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                solana_sdk::instruction::AccountMeta::new(*pq_account, false),
+                solana_sdk::instruction::AccountMeta::new_readonly(keypair.pubkey(), true),
+            ],
+            data: self.idl.discriminator("enable_hybrid_unlock").to_vec(),
+        };
+
+        self.send_instructions(&[instruction], &keypair.pubkey(), &[keypair]).await
     }
 
     /// Write SPHINCS+ public key to PQ account (called after registration)
@@ -234,16 +874,18 @@ impl VaultClient {
         &self,
         wallet: Pubkey,
         keypair_path: &str,
-        sphincs_pubkey: &[u8; SPHINCS_PUBKEY_SIZE],
-    ) -> Result<()> {
+        sphincs_pubkey: &PqKey,
+    ) -> Result<(Signature, u64)> {
         let keypair = self.load_keypair(keypair_path)?;
         let (pq_account, _) = self.derive_pq_account(wallet);
 
         // Create a temporary account to hold the public key data
-        let temp_keypair = Keypair::new();
+        // Shares the wallet signer's type so both can sign one transaction
+        let temp_keypair: Arc<VaultSigner> = Arc::new(Keypair::new());
 
-        // Calculate rent for 32 bytes
-        let rent = self.rpc_client.get_minimum_balance_for_rent_exemption(32)?;
+        // Calculate rent for the public key
+        let pubkey_len = sphincs_pubkey.bytes.len();
+        let rent = self.rpc_client.get_minimum_balance_for_rent_exemption(pubkey_len).await?;
 
         // Create the temporary account with the public key as initial data
         // We'll allocate and assign to our program so we can write the data
@@ -251,16 +893,16 @@ impl VaultClient {
             &keypair.pubkey(),
             &temp_keypair.pubkey(),
             rent,
-            32,
+            pubkey_len as u64,
             &self.program_id, // Owned by our program so write_data can write to it
         );
 
         // Build write_data instruction to write the public key to temp account
         let mut write_data_instruction_data = Vec::new();
-        write_data_instruction_data.extend_from_slice(&[211, 152, 195, 131, 83, 179, 248, 77]); // WRITE_DATA_DISCRIMINATOR
+        write_data_instruction_data.extend_from_slice(&self.idl.discriminator("write_data"));
         write_data_instruction_data.extend_from_slice(&0u32.to_le_bytes()); // offset = 0
-        write_data_instruction_data.extend_from_slice(&(sphincs_pubkey.len() as u32).to_le_bytes()); // data length
-        write_data_instruction_data.extend_from_slice(sphincs_pubkey); // the public key data
+        write_data_instruction_data.extend_from_slice(&(pubkey_len as u32).to_le_bytes()); // data length
+        write_data_instruction_data.extend_from_slice(&sphincs_pubkey.bytes); // the public key data
 
         let write_data_ix = Instruction {
             program_id: self.program_id,
@@ -272,7 +914,7 @@ impl VaultClient {
         };
 
         // Build the write_public_key instruction
-        let instruction_data = WRITE_PUBLIC_KEY_DISCRIMINATOR.to_vec();
+        let instruction_data = self.idl.discriminator("write_public_key").to_vec();
 
         let write_pubkey_ix = Instruction {
             program_id: self.program_id,
@@ -285,51 +927,46 @@ impl VaultClient {
             data: instruction_data,
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
+        println!("Sending public key write transaction...");
+        let (signature, fee) = self.send_instructions(
             &[create_account_ix, write_data_ix, write_pubkey_ix],
-            Some(&keypair.pubkey()),
+            &keypair.pubkey(),
             &[&keypair, &temp_keypair],
-            recent_blockhash,
-        );
-
-        println!("Sending public key write transaction...");
-        let signature = self.rpc_client.send_and_confirm_transaction(&transaction)?;
+        ).await?;
 
         println!("{}", "✅ SPHINCS+ Public Key Written!".green().bold());
         println!("   Transaction: {}", signature.to_string().cyan());
         println!();
 
-        Ok(())
+        Ok((signature, fee))
     }
 
     /// Lock the vault
-    pub async fn lock_vault(&self, wallet: Pubkey, keypair_path: &str) -> Result<()> {
+    pub async fn lock_vault(&self, wallet: Pubkey, keypair_path: &str) -> Result<Receipt> {
         println!("Wallet Address: {}", wallet.to_string().cyan());
         println!();
 
         let keypair = self.load_keypair(keypair_path)?;
         let (pq_account, _) = self.derive_pq_account(wallet);
 
+        let mut receipt = Receipt::new(Operation::Lock);
+        receipt.account("wallet", &wallet).account("pq_account", &pq_account);
+
         println!("PQ Account (PDA): {}", pq_account.to_string().cyan());
         println!();
 
         // Check current status
-        let account_info = self.rpc_client.get_account(&pq_account)
+        let account_info = self.rpc_client.get_account(&pq_account).await
             .context("PQ account not found! Register first with: qdum-vault register")?;
 
-        // Parse lock status (account layout: discriminator(8) + owner(32) + algorithm(1) + pubkey_len(4) + tokens_locked(1))
-        let pubkey_len = u32::from_le_bytes(account_info.data[41..45].try_into().unwrap());
-        let tokens_locked_offset = 45 + pubkey_len as usize;
-        let is_locked = account_info.data[tokens_locked_offset] == 1;
-        if is_locked {
+        if PqAccount::parse(&account_info.data)?.tokens_locked {
             println!("{}", "⚠️  Vault is already locked!".yellow());
-            return Ok(());
+            return Ok(receipt.skipped("Vault already locked"));
         }
 
         println!("Locking vault...");
 
-        let instruction_data = LOCK_TOKENS_DISCRIMINATOR.to_vec();
+        let instruction_data = self.idl.discriminator("lock_tokens").to_vec();
 
         let instruction = Instruction {
             program_id: self.program_id,
@@ -340,15 +977,12 @@ impl VaultClient {
             data: instruction_data,
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[&keypair],
-            recent_blockhash,
-        );
+        if self.unsigned_out.is_some() {
+            return self.export_unsigned(&[instruction], &keypair.pubkey(), receipt).await;
+        }
 
-        let signature = self.rpc_client.send_and_confirm_transaction(&transaction)?;
+        let (signature, fee) = self.send_instructions(&[instruction], &keypair.pubkey(), &[&keypair]).await?;
+        receipt.record((signature, fee));
 
         println!();
         println!("{}", "✅ Vault Locked!".green().bold());
@@ -360,19 +994,17 @@ impl VaultClient {
         println!();
 
         // Fetch and display the challenge
-        let account_info = self.rpc_client.get_account(&pq_account)?;
-        let pubkey_len = u32::from_le_bytes(account_info.data[41..45].try_into().unwrap());
-        let challenge_offset = 46 + pubkey_len as usize;
-        let challenge = &account_info.data[challenge_offset..challenge_offset + 32];
+        let account_info = self.rpc_client.get_account(&pq_account).await?;
+        let challenge = PqAccount::parse(&account_info.data)?.unlock_challenge;
         println!("🔐 Unlock Challenge (32 bytes):");
         println!("   {}", hex::encode(challenge).cyan());
         println!();
 
-        Ok(())
+        Ok(receipt.finish())
     }
 
     /// Close PQ account and reclaim rent
-    pub async fn close_pq_account(&self, wallet: Pubkey, keypair_path: &str, receiver: Option<Pubkey>) -> Result<()> {
+    pub async fn close_pq_account(&self, wallet: Pubkey, keypair_path: &str, receiver: Option<Pubkey>) -> Result<Receipt> {
         println!("Wallet Address: {}", wallet.to_string().cyan());
         println!();
 
@@ -384,25 +1016,27 @@ impl VaultClient {
         println!("Rent Receiver: {}", receiver_pubkey.to_string().cyan());
         println!();
 
+        let mut receipt = Receipt::new(Operation::Close);
+        receipt
+            .account("wallet", &wallet)
+            .account("pq_account", &pq_account)
+            .account("rent_receiver", &receiver_pubkey);
+
         // Check current status
-        let account_info = self.rpc_client.get_account(&pq_account)
+        let account_info = self.rpc_client.get_account(&pq_account).await
             .context("PQ account not found! Nothing to close.")?;
 
-        // Parse lock status - must be unlocked to close
-        let pubkey_len = u32::from_le_bytes(account_info.data[41..45].try_into().unwrap());
-        let tokens_locked_offset = 45 + pubkey_len as usize;
-        let is_locked = account_info.data[tokens_locked_offset] == 1;
-
-        if is_locked {
+        // Must be unlocked to close
+        if PqAccount::parse(&account_info.data)?.tokens_locked {
             println!("{}", "❌ Cannot close PQ account while tokens are locked!".red().bold());
             println!("   Unlock your vault first with: qdum-vault unlock");
             println!();
-            return Err(anyhow::anyhow!("Tokens are locked - unlock first before closing"));
+            return Err(ExitKind::VaultLocked.error("Tokens are locked - unlock first before closing"));
         }
 
         println!("Closing PQ account and reclaiming rent...");
 
-        let instruction_data = CLOSE_PQ_ACCOUNT_DISCRIMINATOR.to_vec();
+        let instruction_data = self.idl.discriminator("close_pq_account").to_vec();
 
         let instruction = Instruction {
             program_id: self.program_id,
@@ -414,15 +1048,12 @@ impl VaultClient {
             data: instruction_data,
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[&keypair],
-            recent_blockhash,
-        );
+        if self.unsigned_out.is_some() {
+            return self.export_unsigned(&[instruction], &keypair.pubkey(), receipt).await;
+        }
 
-        let signature = self.rpc_client.send_and_confirm_transaction(&transaction)?;
+        let (signature, fee) = self.send_instructions(&[instruction], &keypair.pubkey(), &[&keypair]).await?;
+        receipt.record((signature, fee));
 
         println!();
         println!("{}", "✅ PQ Account Closed!".green().bold());
@@ -436,7 +1067,7 @@ impl VaultClient {
         println!("   if you want to use quantum-resistant features again.");
         println!();
 
-        Ok(())
+        Ok(receipt.finish())
     }
 
     /// Claim daily 100 QDUM airdrop (24-hour cooldown, requires initialized PQ account)
@@ -470,26 +1101,11 @@ impl VaultClient {
 
         // Debug: Fetch the PQ account and check its owner field
         let mut pq_account_owner_info = String::from("PQ Account not found on-chain!");
-        if let Ok(account_info) = self.rpc_client.get_account(&pq_account) {
-            if account_info.data.len() >= 41 {
-                // Parse owner pubkey (8 bytes discriminator + 32 bytes owner)
-                let owner_bytes: [u8; 32] = account_info.data[8..40].try_into().unwrap();
-                let owner_pubkey = Pubkey::new_from_array(owner_bytes);
-
-                // Parse algorithm (byte 40)
-                let algorithm = account_info.data[40];
-
-                // Parse public_key length (bytes 41-45 for Vec length prefix)
-                let pubkey_len = if account_info.data.len() >= 45 {
-                    u32::from_le_bytes([
-                        account_info.data[41],
-                        account_info.data[42],
-                        account_info.data[43],
-                        account_info.data[44],
-                    ])
-                } else {
-                    0
-                };
+        if let Ok(account_info) = self.rpc_client.get_account(&pq_account).await {
+            if let Ok(account) = PqAccount::parse(&account_info.data) {
+                let owner_pubkey = account.owner;
+                let algorithm = account.algorithm;
+                let pubkey_len = account.public_key.len();
 
                 pq_account_owner_info = format!(
                     "PQ Account exists!\n\
@@ -509,28 +1125,20 @@ impl VaultClient {
 
         // CRITICAL: Fetch the mint from mint_state on-chain
         // The mint passed as parameter might not match what's in the on-chain state
-        // MintState layout: 8 bytes discriminator + 32 bytes authority + 32 bytes mint
-        let actual_mint = if let Ok(account_info) = self.rpc_client.get_account(&mint_state) {
-            eprintln!("DEBUG: mint_state account data length: {}", account_info.data.len());
-
-            if account_info.data.len() >= 72 {
-                // Parse authority (bytes 8-40)
-                let authority_bytes: [u8; 32] = account_info.data[8..40].try_into().unwrap();
-                let authority = Pubkey::new_from_array(authority_bytes);
-
-                // Parse mint pubkey (bytes 40-72) - THIS IS THE CORRECT LOCATION!
-                let mint_bytes: [u8; 32] = account_info.data[40..72].try_into().unwrap();
-                let parsed_mint = Pubkey::new_from_array(mint_bytes);
-
-                eprintln!("DEBUG: Authority from state: {}", authority);
-                eprintln!("DEBUG: Mint from state: {}", parsed_mint);
-                parsed_mint
-            } else {
-                eprintln!("DEBUG: Account data too short ({}), using parameter mint", account_info.data.len());
-                mint // Fall back to parameter if can't parse
+        let actual_mint = if let Ok(account_info) = self.get_account_cached(&mint_state).await {
+            match MintState::parse(&account_info.data) {
+                Ok(state) => {
+                    output::detail(output::DEBUG, "Mint state authority:", state.authority);
+                    output::detail(output::DEBUG, "Mint state mint:", state.mint);
+                    state.mint
+                }
+                Err(e) => {
+                    output::detail(output::VERBOSE, "Using the given mint:", e);
+                    mint // Fall back to parameter if can't parse
+                }
             }
         } else {
-            eprintln!("DEBUG: Failed to fetch mint_state account, using parameter mint");
+            output::detail(output::VERBOSE, "Using the given mint:", "mint state account not found");
             mint // Fall back to parameter if can't fetch
         };
 
@@ -550,7 +1158,7 @@ impl VaultClient {
 
         // Check if associated token account exists, create if needed
         let mut instructions = Vec::new();
-        let account_info = self.rpc_client.get_account(&recipient_token_account);
+        let account_info = self.rpc_client.get_account(&recipient_token_account).await;
         if account_info.is_err() {
             println!("Creating associated token account for mint {}...", actual_mint);
             // Create ATA instruction
@@ -603,9 +1211,9 @@ impl VaultClient {
             bridge_program_id
         );
         let _ = std::fs::write("/tmp/airdrop-accounts-debug.log", &debug_info);
-        eprintln!("{}", debug_info);
+        output::detail(output::DEBUG, "Airdrop accounts:", format!("\n{}", debug_info));
 
-        let claim_instruction_data = CLAIM_AIRDROP_DISCRIMINATOR.to_vec();
+        let claim_instruction_data = self.idl.discriminator("claim_airdrop").to_vec();
 
         let claim_instruction = Instruction {
             program_id: self.program_id,
@@ -623,16 +1231,8 @@ impl VaultClient {
         };
         instructions.push(claim_instruction);
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &instructions,
-            Some(&keypair.pubkey()),
-            &[&keypair],
-            recent_blockhash,
-        );
-
-        let signature = match self.rpc_client.send_and_confirm_transaction(&transaction) {
-            Ok(sig) => sig,
+        let signature = match self.send_instructions(&instructions, &keypair.pubkey(), &[&keypair]).await {
+            Ok((sig, _)) => sig,
             Err(e) => {
                 // Log the full error details
                 let error_details = format!(
@@ -656,7 +1256,7 @@ impl VaultClient {
                 );
                 let _ = std::fs::write("/tmp/airdrop-transaction-error.log", &error_details);
                 eprintln!("{}", error_details);
-                return Err(e.into());
+                return Err(e);
             }
         };
 
@@ -677,12 +1277,23 @@ impl VaultClient {
         &self,
         wallet: Pubkey,
         keypair_path: &str,
-        sphincs_privkey: &[u8; 64],
-        sphincs_pubkey: &[u8; 32],
-        progress_callback: Option<Box<dyn FnMut(usize, usize, String) + Send>>,
-    ) -> Result<()> {
+        signer: UnlockSigner<'_>,
+        sphincs_pubkey: &PqKey,
+        options: UnlockOptions,
+        progress_callback: Option<ProgressCallback>,
+    ) -> Result<Receipt> {
+        // Jito mode: steps are queued into bundles, tipped by the fee payer
+        let bundled;
+        let client = match &self.jito {
+            Some(config) => {
+                bundled = self.bundled(config, self.load_keypair(keypair_path)?).await?;
+                &bundled
+            }
+            None => self,
+        };
+
         // Wrap entire function to catch and log errors
-        let result = self.unlock_vault_inner(wallet, keypair_path, sphincs_privkey, sphincs_pubkey, progress_callback).await;
+        let result = client.unlock_vault_inner(wallet, keypair_path, signer, sphincs_pubkey, options, progress_callback).await;
 
         match &result {
             Ok(_) => {
@@ -702,10 +1313,11 @@ impl VaultClient {
         &self,
         wallet: Pubkey,
         keypair_path: &str,
-        sphincs_privkey: &[u8; 64],
-        sphincs_pubkey: &[u8; 32],
-        mut progress_callback: Option<Box<dyn FnMut(usize, usize, String) + Send>>,
-    ) -> Result<()> {
+        signer: UnlockSigner<'_>,
+        sphincs_pubkey: &PqKey,
+        options: UnlockOptions,
+        mut progress_callback: Option<ProgressCallback>,
+    ) -> Result<Receipt> {
         println!("{}", "╔═══════════════════════════════════════════════════════════╗".on_black().bright_magenta());
         println!("{}", "║                                                           ║".on_black().bright_magenta());
         println!("{}", "║   ⚛️  QUANTUM VAULT UNLOCK SEQUENCE INITIATED  ⚛️        ║".on_black().bright_cyan().bold());
@@ -715,78 +1327,81 @@ impl VaultClient {
 
         println!("{} {}", "Wallet:".bright_blue().bold(), wallet.to_string().bright_white());
 
+        if let UnlockSigner::PrivateKey(sphincs_privkey) = &signer {
+            if sphincs_privkey.algorithm != sphincs_pubkey.algorithm {
+                anyhow::bail!(
+                    "Key mismatch: private key is {}, public key is {}",
+                    sphincs_privkey.algorithm,
+                    sphincs_pubkey.algorithm
+                );
+            }
+        }
+        let algorithm = sphincs_pubkey.algorithm;
+        let signature_size = algorithm.signature_size();
+
         let keypair = self.load_keypair(keypair_path)?;
         let (pq_account, _) = self.derive_pq_account(wallet);
 
-        println!("{} {}", "PQ Account:".bright_blue().bold(), pq_account.to_string().bright_white());
-        println!();
-
         // Check current status
-        let account_info = self.rpc_client.get_account(&pq_account)
+        let account_info = self.rpc_client.get_account(&pq_account).await
             .context("PQ account not found!")?;
+        let account = PqAccount::parse(&account_info.data)?;
 
-        // Parse lock status and challenge
-        let pubkey_len = u32::from_le_bytes(account_info.data[41..45].try_into().unwrap());
-        let tokens_locked_offset = 45 + pubkey_len as usize;
-        let is_locked = account_info.data[tokens_locked_offset] == 1;
-        if !is_locked {
-            println!("{}", "⚠️  Vault is already unlocked!".bright_yellow());
-            return Ok(());
-        }
+        // The registered mode decides which transactions unlock the vault; the
+        // profile's copy may be stale (e.g. an imported or restored vault)
+        let lock_mode = LockMode::from_account(account.lock_mode)?;
 
-        // Get the challenge
-        let challenge_offset = tokens_locked_offset + 1;
-        let challenge = &account_info.data[challenge_offset..challenge_offset + 32];
-        println!("{} {}", "Challenge:".bright_blue().bold(), hex::encode(challenge).bright_cyan());
+        println!("{} {}", "PQ Account:".bright_blue().bold(), pq_account.to_string().bright_white());
+        println!("{} {}", "Algorithm:".bright_blue().bold(), algorithm.to_string().bright_white());
+        println!("{} {}", "Lock Mode:".bright_blue().bold(), lock_mode.label().bright_white());
+        if lock_mode != options.lock_mode {
+            println!("{} The vault profile says {}, but the vault is registered as {}", "[!]".yellow(), options.lock_mode.label(), lock_mode.label());
+        }
         println!();
 
-        // Calculate total steps for progress tracking
-        // 1 signature gen + 1 init storage + 10 upload chunks + 33 verify steps + 1 finalize = 46 total
-        const CHUNK_SIZE: usize = 800;
-        let total_chunks = (SPHINCS_SIGNATURE_SIZE + CHUNK_SIZE - 1) / CHUNK_SIZE;
-        let total_steps = 1 + 1 + total_chunks + 33 + 1;
-        let mut current_step = 0;
-
-        // Step 1: Generate signature
-        current_step += 1;
-        if let Some(ref mut cb) = progress_callback {
-            cb(current_step, total_steps, "Generating SPHINCS+ signature...".to_string());
+        // Hybrid vaults need the wallet owner's own signature, not just any fee payer
+        if lock_mode == LockMode::Hybrid && keypair.pubkey() != wallet {
+            anyhow::bail!(
+                "Hybrid vault requires the owner's wallet keypair ({}), got {}",
+                wallet,
+                keypair.pubkey()
+            );
         }
 
-        // Spinner for signature generation
-        let spinner = ProgressBar::new_spinner();
-        spinner.set_style(
-            ProgressStyle::default_spinner()
-                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
-                .template("{spinner:.magenta} {msg}")
-                .unwrap()
-        );
-        spinner.enable_steady_tick(Duration::from_millis(80));
-        spinner.set_message(format!("{}", "⚛️  Generating SPHINCS+ signature...".bright_white()));
+        let mut receipt = Receipt::new(Operation::Unlock);
+        receipt.account("wallet", &wallet).account("pq_account", &pq_account);
 
-        // Generate signature
-        let key_manager = SphincsKeyManager::new(None)?;
-        let signature = key_manager.sign_message(challenge, sphincs_privkey)?;
+        if !account.tokens_locked {
+            println!("{}", "⚠️  Vault is already unlocked!".bright_yellow());
+            return Ok(receipt.skipped("Vault already unlocked"));
+        }
+
+        self.check_program_compatibility(&keypair.pubkey(), &compat::unlock_instructions(algorithm)).await?;
 
-        spinner.finish_with_message(format!("{} {} bytes", "✓ Signature generated:".bright_green(), SPHINCS_SIGNATURE_SIZE.to_string().bright_yellow()));
+        let challenge = &account.unlock_challenge[..];
+        println!("{} {}", "Challenge:".bright_blue().bold(), hex::encode(challenge).bright_cyan());
         println!();
 
-        // Debug logging
-        println!("{}", "═══════════════════════════════════════════════════════════".bright_yellow());
-        println!("{} {}", "DEBUG: SPHINCS Public Key (unlock):".bright_yellow().bold(), hex::encode(sphincs_pubkey).bright_cyan());
+        // Hybrid mode: the wallet also signs the challenge, verified by the Ed25519 program
+        let hybrid_proof = match lock_mode {
+            LockMode::Hybrid => {
+                let wallet_signature = keypair.try_sign_message(challenge)?;
+                Some(ed25519_verify_instruction(&keypair.pubkey(), &wallet_signature, challenge))
+            }
+            LockMode::Standard => None,
+        };
+
+        // Calculate total steps for progress tracking
+        // 1 signature gen + 1 init storage + upload chunks + verify steps
+        let (upload_steps, verify_steps) = unlock_transaction_plan(algorithm);
+        let total_chunks = upload_steps - 1;
+        let total_steps = 1 + upload_steps + verify_steps;
+        let mut current_step = 0;
 
         // Use SPHINCS public key hash as identifier to avoid conflicts from corrupted PDAs
-        // Each vault has unique SPHINCS keys, so this gives each vault its own storage
-        // while still allowing reuse across multiple unlocks of the same vault
-        use sha2::{Digest, Sha256};
-        let mut hasher = Sha256::new();
-        hasher.update(sphincs_pubkey);
-        let pubkey_hash = hasher.finalize();
-        let unique_identifier = hex::encode(&pubkey_hash[..8]);
-
-        println!("{} {}", "DEBUG: Storage Identifier:".bright_yellow().bold(), unique_identifier.bright_cyan());
-        println!("{}", "═══════════════════════════════════════════════════════════".bright_yellow());
-        println!();
+        let unique_identifier = storage_identifier(&sphincs_pubkey.bytes);
+
+        output::detail(output::VERBOSE, "Storage identifier:", &unique_identifier);
 
         // Derive signature storage PDA
         let (signature_storage, _) = Pubkey::find_program_address(
@@ -794,57 +1409,153 @@ impl VaultClient {
             &self.program_id,
         );
 
-        println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".bright_cyan());
-        println!("{} {}", "📦 PHASE 1:".bright_cyan().bold(), "Signature Upload".bright_white().bold());
-        println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".bright_cyan());
-        println!("{} {}", "Storage PDA:".bright_blue(), signature_storage.to_string().bright_white());
-        println!();
-
-        // Step 2-9: Upload signature in chunks (800 bytes per tx - max allowed by on-chain program)
-        // Using CHUNK_SIZE from earlier calculation
-        let total_phase1_steps = 1 + total_chunks;
-
-        // Progress bar for Phase 1
-        let pb_phase1 = ProgressBar::new(total_phase1_steps as u64);
-        pb_phase1.set_style(
-            ProgressStyle::default_bar()
-                .template("{spinner:.cyan} [{bar:40.cyan/blue}] {pos}/{len} {msg}")
-                .unwrap()
-                .progress_chars("━━╸")
+        // Derive verification state PDA (using same unique_identifier from signature storage)
+        let (verification_state, _) = Pubkey::find_program_address(
+            &[b"sphincs_verify", keypair.pubkey().as_ref(), unique_identifier.as_bytes()],
+            &self.program_id,
         );
 
-        // Step 1: (Re)initialize signature storage to reset state for new unlock
-        current_step += 1;
-        if let Some(ref mut cb) = progress_callback {
-            cb(current_step, total_steps, "Initializing signature storage...".to_string());
+        // Skip what an interrupted unlock already finished on-chain
+        let resume_after = if options.resume_after > 0 {
+            self.resume_point(options.resume_after, challenge, algorithm, &signature_storage, &verification_state).await
+        } else {
+            0
+        };
+        if resume_after > 0 {
+            println!("{} {}/{}", "Resuming after step".bright_yellow().bold(), resume_after, total_steps);
+            println!();
         }
-        pb_phase1.set_message(format!("{}", "Initializing storage...".bright_white()));
+        let pending = |step: usize| step > resume_after;
+
+        // Phase 1 is all or nothing: a new signature can't continue another's upload
+        if pending(1 + upload_steps) {
+            // Step 1: Generate signature
+            current_step += 1;
+            let signature = match signer {
+                UnlockSigner::PrivateKey(sphincs_privkey) => {
+                    self.report(&mut progress_callback, current_step, total_steps, format!("Generating {} signature...", algorithm));
+
+                    // Spinner for signature generation
+                    let spinner = output::spinner();
+                    spinner.set_style(
+                        ProgressStyle::default_spinner()
+                            .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
+                            .template("{spinner:.magenta} {msg}")
+                            .unwrap()
+                    );
+                    spinner.enable_steady_tick(Duration::from_millis(80));
+                    spinner.set_message(format!("{} {} {}", "⚛️  Generating".bright_white(), algorithm.to_string().bright_white(), "signature...".bright_white()));
+
+                    // Generate signature
+                    let key_manager = SphincsKeyManager::new(None)?;
+                    let signature = key_manager.sign_message(challenge, sphincs_privkey)?;
+
+                    spinner.finish_with_message(format!("{} {} bytes", "✓ Signature generated:".bright_green(), signature_size.to_string().bright_yellow()));
+                    signature
+                }
+                UnlockSigner::Presigned(signature) => {
+                    self.report(&mut progress_callback, current_step, total_steps, "Checking pre-generated signature...".to_string());
+
+                    // Uploading a signature the program will reject only burns fees
+                    if signature.len() != signature_size {
+                        anyhow::bail!("Signature is {} bytes, {} needs {}", signature.len(), algorithm, signature_size);
+                    }
+                    if !SphincsKeyManager::verify_signature(challenge, &signature, sphincs_pubkey)? {
+                        anyhow::bail!("Signature does not cover the current challenge (re-run `unlock sign`)");
+                    }
 
-        // Always reinitialize to reset state (program allows reinit of existing PDAs)
-        self.initialize_sphincs_storage(&keypair, &signature_storage, &unique_identifier, sphincs_pubkey, challenge).await?;
-        pb_phase1.inc(1);
+                    println!("{} {} bytes", "✓ Pre-generated signature verified:".bright_green(), signature_size.to_string().bright_yellow());
+                    signature
+                }
+                UnlockSigner::Remote(remote) => {
+                    self.report(&mut progress_callback, current_step, total_steps, format!("Requesting {} signature from {}...", algorithm, remote.endpoint()));
+
+                    let signature = remote.sign_challenge(challenge)?;
+                    if signature.len() != signature_size || !SphincsKeyManager::verify_signature(challenge, &signature, sphincs_pubkey)? {
+                        anyhow::bail!("Signature from {} does not verify against the vault's {} key", remote.endpoint(), algorithm);
+                    }
+
+                    println!("{} {} bytes", "✓ Remote signature verified:".bright_green(), signature_size.to_string().bright_yellow());
+                    signature
+                }
+            };
+            println!();
 
-        for i in 0..total_chunks {
+            output::detail(output::DEBUG, "Unlocking with public key:", hex::encode(sphincs_pubkey));
+
+            println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".bright_cyan());
+            println!("{} {}", "📦 PHASE 1:".bright_cyan().bold(), "Signature Upload".bright_white().bold());
+            println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".bright_cyan());
+            println!("{} {}", "Storage PDA:".bright_blue(), signature_storage.to_string().bright_white());
+            println!();
+
+            // Step 2-9: Upload signature in chunks (800 bytes per tx - max allowed by on-chain program)
+            let total_phase1_steps = upload_steps;
+
+            // Progress bar for Phase 1
+            let pb_phase1 = output::progress_bar(total_phase1_steps as u64);
+            pb_phase1.set_style(
+                ProgressStyle::default_bar()
+                    .template("{spinner:.cyan} [{bar:40.cyan/blue}] {pos}/{len} {msg}")
+                    .unwrap()
+                    .progress_chars("━━╸")
+            );
+
+            // Step 1: (Re)initialize signature storage to reset state for new unlock
             current_step += 1;
-            if let Some(ref mut cb) = progress_callback {
-                cb(current_step, total_steps, format!("Uploading signature chunk {}/{}", i + 1, total_chunks));
-            }
-            let start = i * CHUNK_SIZE;
-            let end = ((i + 1) * CHUNK_SIZE).min(SPHINCS_SIGNATURE_SIZE);
-            let chunk = &signature[start..end];
-            pb_phase1.set_message(format!("{} {} ({} bytes)", "Uploading chunk".bright_white(), i + 1, chunk.len()));
-            self.upload_signature_chunk(&keypair, &signature_storage, start as u32, chunk).await?;
+            self.report(&mut progress_callback, current_step, total_steps, "Initializing signature storage...".to_string());
+            pb_phase1.set_message(format!("{}", "Initializing storage...".bright_white()));
+
+            // Always reinitialize to reset state (program allows reinit of existing PDAs)
+            receipt.record(self.initialize_sphincs_storage(&keypair, &signature_storage, &unique_identifier, &sphincs_pubkey.bytes, challenge).await?);
             pb_phase1.inc(1);
+
+            for i in 0..total_chunks {
+                current_step += 1;
+                self.report(&mut progress_callback, current_step, total_steps, format!("Uploading signature chunk {}/{}", i + 1, total_chunks));
+                let start = i * SIGNATURE_CHUNK_SIZE;
+                let end = ((i + 1) * SIGNATURE_CHUNK_SIZE).min(signature_size);
+                let chunk = &signature[start..end];
+                pb_phase1.set_message(format!("{} {} ({} bytes)", "Uploading chunk".bright_white(), i + 1, chunk.len()));
+                receipt.record(self.upload_signature_chunk(&keypair, &signature_storage, start as u32, chunk).await?);
+                pb_phase1.inc(1);
+            }
+
+            pb_phase1.finish_with_message(format!("{}", "✓ Upload complete".bright_green()));
+            println!();
+        } else {
+            current_step += 1 + upload_steps;
+            println!("{}", "✓ Signature already uploaded".bright_green());
+            println!();
         }
 
-        pb_phase1.finish_with_message(format!("{}", "✓ Upload complete".bright_green()));
-        println!();
+        let params = match algorithm {
+            Algorithm::SlhDsa(params) => params,
+            Algorithm::MlDsa(_) => {
+                // ML-DSA signatures are verified on-chain in a single transaction
+                current_step += 1;
+                self.report(&mut progress_callback, current_step, total_steps, format!("Verifying {} signature and unlocking vault...", algorithm));
+
+                let spinner = output::spinner();
+                spinner.set_style(
+                    ProgressStyle::default_spinner()
+                        .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
+                        .template("{spinner:.magenta} {msg}")
+                        .unwrap()
+                );
+                spinner.enable_steady_tick(Duration::from_millis(80));
+                spinner.set_message(format!("{}", "⚛️  Verifying signature on-chain...".bright_white()));
 
-        // Derive verification state PDA (using same unique_identifier from signature storage)
-        let (verification_state, _) = Pubkey::find_program_address(
-            &[b"sphincs_verify", keypair.pubkey().as_ref(), unique_identifier.as_bytes()],
-            &self.program_id,
-        );
+                receipt.record(self.verify_mldsa_and_unlock(&keypair, &signature_storage, &pq_account, options.delay_slots, hybrid_proof).await?);
+
+                self.flush_bundle().await?;
+                spinner.finish_with_message(format!("{}", "✓ Verification complete".bright_green()));
+                println!();
+
+                self.print_unlock_success(&receipt, algorithm, options.delay_slots);
+                return Ok(receipt.finish());
+            }
+        };
 
         println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".bright_magenta());
         println!("{} {}", "⚛️  PHASE 2:".bright_magenta().bold(), "Quantum Signature Verification".bright_white().bold());
@@ -853,7 +1564,7 @@ impl VaultClient {
         println!();
 
         // Progress bar for Phase 2
-        let pb_phase2 = ProgressBar::new(33);
+        let pb_phase2 = output::progress_bar(verify_steps as u64);
         pb_phase2.set_style(
             ProgressStyle::default_bar()
                 .template("{spinner:.magenta} [{bar:40.magenta/purple}] {pos}/{len} {msg}")
@@ -863,95 +1574,106 @@ impl VaultClient {
 
         // Step 0: (Re)initialize verification state to reset for new unlock
         current_step += 1;
-        if let Some(ref mut cb) = progress_callback {
-            cb(current_step, total_steps, "Initializing verification state...".to_string());
-        }
-        pb_phase2.set_message(format!("{}", "Initializing verification...".bright_white()));
-
-        // Always reinitialize to reset state (program allows reinit of existing PDAs)
-        self.sphincs_verify_step0_init(
-            &keypair,
-            &verification_state,
-            &signature_storage,
-            &unique_identifier,
-            challenge,
-            sphincs_pubkey,
-            0, // unlock_duration_slots (0 = immediate unlock)
-        ).await?;
+        if pending(current_step) {
+            self.report(&mut progress_callback, current_step, total_steps, "Initializing verification state...".to_string());
+            pb_phase2.set_message(format!("{}", "Initializing verification...".bright_white()));
+
+            // Always reinitialize to reset state (program allows reinit of existing PDAs)
+            receipt.record(self.sphincs_verify_step0_init(
+                &keypair,
+                &verification_state,
+                &signature_storage,
+                &unique_identifier,
+                challenge,
+                &sphincs_pubkey.bytes,
+                options.delay_slots, // unlock_duration_slots (0 = immediate unlock)
+            ).await?);
+        }
         pb_phase2.inc(1);
 
-        // Steps 1-3: FORS verification
+        // Steps 1-3: FORS verification (trees split across two batches)
+        let fors_split = params.fors_trees().div_ceil(2);
         current_step += 1;
-        if let Some(ref mut cb) = progress_callback {
-            cb(current_step, total_steps, "Verifying FORS trees (batch 1/2)...".to_string());
+        if pending(current_step) {
+            self.report(&mut progress_callback, current_step, total_steps, "Verifying FORS trees (batch 1/2)...".to_string());
+            pb_phase2.set_message(format!("{} 0-{}...", "Verifying FORS trees".bright_white(), fors_split - 1));
+            receipt.record(self.sphincs_verify_fors_batch1(&keypair, &verification_state, &signature_storage).await?);
         }
-        pb_phase2.set_message(format!("{}", "Verifying FORS trees 0-6...".bright_white()));
-        self.sphincs_verify_fors_batch1(&keypair, &verification_state, &signature_storage).await?;
         pb_phase2.inc(1);
 
         current_step += 1;
-        if let Some(ref mut cb) = progress_callback {
-            cb(current_step, total_steps, "Verifying FORS trees (batch 2/2)...".to_string());
+        if pending(current_step) {
+            self.report(&mut progress_callback, current_step, total_steps, "Verifying FORS trees (batch 2/2)...".to_string());
+            pb_phase2.set_message(format!("{} {}-{}...", "Verifying FORS trees".bright_white(), fors_split, params.fors_trees() - 1));
+            receipt.record(self.sphincs_verify_fors_batch2(&keypair, &verification_state, &signature_storage).await?);
         }
-        pb_phase2.set_message(format!("{}", "Verifying FORS trees 7-13...".bright_white()));
-        self.sphincs_verify_fors_batch2(&keypair, &verification_state, &signature_storage).await?;
         pb_phase2.inc(1);
 
         current_step += 1;
-        if let Some(ref mut cb) = progress_callback {
-            cb(current_step, total_steps, "Computing FORS root hash...".to_string());
+        if pending(current_step) {
+            self.report(&mut progress_callback, current_step, total_steps, "Computing FORS root hash...".to_string());
+            pb_phase2.set_message(format!("{}", "Computing FORS root...".bright_white()));
+            receipt.record(self.sphincs_verify_fors_root(&keypair, &verification_state).await?);
         }
-        pb_phase2.set_message(format!("{}", "Computing FORS root...".bright_white()));
-        self.sphincs_verify_fors_root(&keypair, &verification_state).await?;
         pb_phase2.inc(1);
 
-        // Steps 4-31: Layer verification (7 layers × 4 steps each)
-        for layer in 0..7 {
+        // Layer verification (4 steps per hypertree layer)
+        for layer in 0..params.layers() {
             current_step += 1;
-            if let Some(ref mut cb) = progress_callback {
-                cb(current_step, total_steps, format!("Verifying layer {} - WOTS signature part 1/3", layer));
+            if pending(current_step) {
+                self.report(&mut progress_callback, current_step, total_steps, format!("Verifying layer {} - WOTS signature part 1/3", layer));
+                pb_phase2.set_message(format!("{} {} - WOTS Part 1", "Layer".bright_white(), layer));
+                receipt.record(self.sphincs_verify_layer_wots_part1(&keypair, &verification_state, &signature_storage, layer as u8).await?);
             }
-            pb_phase2.set_message(format!("{} {} - WOTS Part 1", "Layer".bright_white(), layer));
-            self.sphincs_verify_layer_wots_part1(&keypair, &verification_state, &signature_storage, layer as u8).await?;
             pb_phase2.inc(1);
 
             current_step += 1;
-            if let Some(ref mut cb) = progress_callback {
-                cb(current_step, total_steps, format!("Verifying layer {} - WOTS signature part 2/3", layer));
+            if pending(current_step) {
+                self.report(&mut progress_callback, current_step, total_steps, format!("Verifying layer {} - WOTS signature part 2/3", layer));
+                pb_phase2.set_message(format!("{} {} - WOTS Part 2", "Layer".bright_white(), layer));
+                receipt.record(self.sphincs_verify_layer_wots_part2(&keypair, &verification_state, &signature_storage, layer as u8).await?);
             }
-            pb_phase2.set_message(format!("{} {} - WOTS Part 2", "Layer".bright_white(), layer));
-            self.sphincs_verify_layer_wots_part2(&keypair, &verification_state, &signature_storage, layer as u8).await?;
             pb_phase2.inc(1);
 
             current_step += 1;
-            if let Some(ref mut cb) = progress_callback {
-                cb(current_step, total_steps, format!("Verifying layer {} - WOTS signature part 3/3", layer));
+            if pending(current_step) {
+                self.report(&mut progress_callback, current_step, total_steps, format!("Verifying layer {} - WOTS signature part 3/3", layer));
+                pb_phase2.set_message(format!("{} {} - WOTS Part 3", "Layer".bright_white(), layer));
+                receipt.record(self.sphincs_verify_layer_wots_part3(&keypair, &verification_state, &signature_storage, layer as u8).await?);
             }
-            pb_phase2.set_message(format!("{} {} - WOTS Part 3", "Layer".bright_white(), layer));
-            self.sphincs_verify_layer_wots_part3(&keypair, &verification_state, &signature_storage, layer as u8).await?;
             pb_phase2.inc(1);
 
             current_step += 1;
-            if let Some(ref mut cb) = progress_callback {
-                cb(current_step, total_steps, format!("Verifying layer {} - Merkle tree path", layer));
+            if pending(current_step) {
+                self.report(&mut progress_callback, current_step, total_steps, format!("Verifying layer {} - Merkle tree path", layer));
+                pb_phase2.set_message(format!("{} {} - Merkle tree", "Layer".bright_white(), layer));
+                receipt.record(self.sphincs_verify_layer_merkle(&keypair, &verification_state, &signature_storage, layer as u8).await?);
             }
-            pb_phase2.set_message(format!("{} {} - Merkle tree", "Layer".bright_white(), layer));
-            self.sphincs_verify_layer_merkle(&keypair, &verification_state, &signature_storage, layer as u8).await?;
             pb_phase2.inc(1);
         }
 
-        // Step 32 (33rd step): Finalize and unlock
+        // Final step: Finalize and unlock
         current_step += 1;
-        if let Some(ref mut cb) = progress_callback {
-            cb(current_step, total_steps, "Finalizing and unlocking vault...".to_string());
-        }
+        self.report(&mut progress_callback, current_step, total_steps, "Finalizing and unlocking vault...".to_string());
         pb_phase2.set_message(format!("{}", "Finalizing and unlocking...".bright_white()));
-        self.sphincs_verify_finalize(&keypair, &verification_state, &pq_account, wallet).await?;
+        receipt.record(self.sphincs_verify_finalize(&keypair, &verification_state, &pq_account, wallet, hybrid_proof).await?);
+        self.flush_bundle().await?;
         pb_phase2.inc(1);
 
         pb_phase2.finish_with_message(format!("{}", "✓ Verification complete".bright_green()));
         println!();
 
+        self.print_unlock_success(&receipt, algorithm, options.delay_slots);
+
+        Ok(receipt.finish())
+    }
+
+    /// Print the animated unlock success box
+    fn print_unlock_success(&self, receipt: &Receipt, algorithm: Algorithm, delay_slots: u64) {
+        if output::quiet() {
+            return;
+        }
+
         // Animated success box
         use std::io::{self, Write};
         use std::thread;
@@ -981,11 +1703,19 @@ impl VaultClient {
         println!();
 
         // Animated checkmarks
-        let checks = vec![
-            "  ✓ SPHINCS+ signature verified on-chain",
-            "  ✓ Vault is now unlocked",
-            "  ✓ Tokens are accessible"
-        ];
+        let checks = if delay_slots > 0 {
+            vec![
+                format!("  ✓ {} signature verified on-chain", algorithm),
+                format!("  ✓ Unlock pending: tokens become accessible in {} slots ({})", delay_slots, format_slots(delay_slots)),
+                "  ✓ Run `qdum-vault status` to see the countdown".to_string(),
+            ]
+        } else {
+            vec![
+                format!("  ✓ {} signature verified on-chain", algorithm),
+                "  ✓ Vault is now unlocked".to_string(),
+                "  ✓ Tokens are accessible".to_string(),
+            ]
+        };
 
         for check in &checks {
             print!("{}", check.on_black().bright_green().bold());
@@ -995,82 +1725,87 @@ impl VaultClient {
         }
 
         println!();
-        println!("{} {}", "  ┃ Total transactions:".on_black().bright_magenta().bold(), "44".on_black().bright_yellow().bold());
-        println!("{} {}", "  ┃ Protocol:".on_black().bright_magenta().bold(), "NIST FIPS 205".on_black().bright_cyan());
+        println!("{} {}", "  ┃ Total transactions:".on_black().bright_magenta().bold(), receipt.signatures.len().to_string().on_black().bright_yellow().bold());
+        let standard = match algorithm {
+            Algorithm::SlhDsa(_) => "NIST FIPS 205",
+            Algorithm::MlDsa(_) => "NIST FIPS 204",
+        };
+        println!("{} {}", "  ┃ Protocol:".on_black().bright_magenta().bold(), format!("{} ({})", algorithm, standard).on_black().bright_cyan());
         println!();
-
-        Ok(())
     }
 
     /// Initialize SPHINCS+ signature storage account
     async fn initialize_sphincs_storage(
         &self,
-        keypair: &Keypair,
+        keypair: &Arc<VaultSigner>,
         signature_storage: &Pubkey,
         identifier: &str,
-        public_key: &[u8; 32],
+        public_key: &[u8],
         message: &[u8],
-    ) -> Result<()> {
+    ) -> Result<(Signature, u64)> {
+        let instruction = self.initialize_sphincs_storage_ix(&keypair.pubkey(), signature_storage, identifier, public_key, message);
+
+        // Send transaction and capture detailed error
+        match self.send_instructions(&[instruction], &keypair.pubkey(), &[keypair]).await {
+            Ok((sig, fee)) => {
+                let _ = std::fs::write("/tmp/qdum-init-sig-success.log", format!("Signature: {}\nIdentifier: {}", sig, identifier));
+                Ok((sig, fee))
+            }
+            Err(e) => {
+                let error_msg = format!("Init signature storage error:\nIdentifier: {}\nSignature Storage PDA: {}\nError: {:?}", identifier, signature_storage, e);
+                let _ = std::fs::write("/tmp/qdum-init-sig-error.log", &error_msg);
+                eprintln!("UNLOCK ERROR: {}", error_msg);
+                Err(e)
+            }
+        }
+    }
+
+    fn initialize_sphincs_storage_ix(
+        &self,
+        payer: &Pubkey,
+        signature_storage: &Pubkey,
+        identifier: &str,
+        public_key: &[u8],
+        message: &[u8],
+    ) -> Instruction {
         // Build instruction data: discriminator + identifier (string) + public_key + message (bytes)
         let mut instruction_data = Vec::new();
-        instruction_data.extend_from_slice(&INITIALIZE_SPHINCS_STORAGE_DISCRIMINATOR);
+        instruction_data.extend_from_slice(&self.idl.discriminator("initialize_sphincs_storage"));
 
         // Serialize identifier as Borsh string (length + data)
         let id_bytes = identifier.as_bytes();
         instruction_data.extend_from_slice(&(id_bytes.len() as u32).to_le_bytes());
         instruction_data.extend_from_slice(id_bytes);
 
-        // Public key (32 bytes)
+        // Public key (length determined by the registered algorithm)
         instruction_data.extend_from_slice(public_key);
 
         // Serialize message as Borsh bytes (length + data)
         instruction_data.extend_from_slice(&(message.len() as u32).to_le_bytes());
         instruction_data.extend_from_slice(message);
 
-        let instruction = Instruction {
+        Instruction {
             program_id: self.program_id,
             accounts: vec![
                 solana_sdk::instruction::AccountMeta::new(*signature_storage, false),
-                solana_sdk::instruction::AccountMeta::new(keypair.pubkey(), true),
+                solana_sdk::instruction::AccountMeta::new(*payer, true),
                 solana_sdk::instruction::AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
             ],
             data: instruction_data,
-        };
-
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
-
-        // Send transaction and capture detailed error
-        match self.rpc_client.send_and_confirm_transaction(&transaction) {
-            Ok(sig) => {
-                let _ = std::fs::write("/tmp/qdum-init-sig-success.log", format!("Signature: {}\nIdentifier: {}", sig, identifier));
-                Ok(())
-            }
-            Err(e) => {
-                let error_msg = format!("Init signature storage error:\nIdentifier: {}\nSignature Storage PDA: {}\nError: {:?}", identifier, signature_storage, e);
-                let _ = std::fs::write("/tmp/qdum-init-sig-error.log", &error_msg);
-                eprintln!("UNLOCK ERROR: {}", error_msg);
-                Err(e.into())
-            }
         }
     }
 
     /// Upload a chunk of SPHINCS+ signature
     async fn upload_signature_chunk(
         &self,
-        keypair: &Keypair,
+        keypair: &Arc<VaultSigner>,
         signature_storage: &Pubkey,
         offset: u32,
         chunk: &[u8],
-    ) -> Result<()> {
+    ) -> Result<(Signature, u64)> {
         // Build instruction data: discriminator + offset (u32) + chunk (bytes)
         let mut instruction_data = Vec::new();
-        instruction_data.extend_from_slice(&UPLOAD_SIGNATURE_CHUNK_DISCRIMINATOR);
+        instruction_data.extend_from_slice(&self.idl.discriminator("upload_signature_chunk"));
         instruction_data.extend_from_slice(&offset.to_le_bytes());
 
         // Serialize chunk as Borsh bytes (length + data)
@@ -1086,32 +1821,23 @@ impl VaultClient {
             data: instruction_data,
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
-
-        self.rpc_client.send_and_confirm_transaction(&transaction)?;
-        Ok(())
+        self.send_instructions(&[instruction], &keypair.pubkey(), &[keypair]).await
     }
 
     /// Step 0: Initialize SPHINCS+ verification state
     async fn sphincs_verify_step0_init(
         &self,
-        keypair: &Keypair,
+        keypair: &Arc<VaultSigner>,
         verification_state: &Pubkey,
         signature_storage: &Pubkey,
         identifier: &str,
         message: &[u8],
-        expected_public_key: &[u8; 32],
+        expected_public_key: &[u8],
         unlock_duration_slots: u64,
-    ) -> Result<()> {
-        // Build instruction data: discriminator + identifier (string) + message (bytes) + expected_public_key (32 bytes) + unlock_duration_slots (u64)
+    ) -> Result<(Signature, u64)> {
+        // Build instruction data: discriminator + identifier (string) + message (bytes) + expected_public_key + unlock_duration_slots (u64)
         let mut instruction_data = Vec::new();
-        instruction_data.extend_from_slice(&SPHINCS_VERIFY_STEP0_INIT_DISCRIMINATOR);
+        instruction_data.extend_from_slice(&self.idl.discriminator("sphincs_verify_step0_init"));
 
         // Serialize identifier as Borsh string (length + data)
         let id_bytes = identifier.as_bytes();
@@ -1122,7 +1848,7 @@ impl VaultClient {
         instruction_data.extend_from_slice(&(message.len() as u32).to_le_bytes());
         instruction_data.extend_from_slice(message);
 
-        // Expected public key (32 bytes)
+        // Expected public key (length determined by the registered algorithm)
         instruction_data.extend_from_slice(expected_public_key);
 
         // Unlock duration slots (u64)
@@ -1139,25 +1865,16 @@ impl VaultClient {
             data: instruction_data,
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
-
-        self.rpc_client.send_and_confirm_transaction(&transaction)?;
-        Ok(())
+        self.send_instructions(&[instruction], &keypair.pubkey(), &[keypair]).await
     }
 
-    /// FORS verification step 1 (trees 0-6)
+    /// FORS verification step 1 (first half of the FORS trees)
     async fn sphincs_verify_fors_batch1(
         &self,
-        keypair: &Keypair,
+        keypair: &Arc<VaultSigner>,
         verification_state: &Pubkey,
         signature_storage: &Pubkey,
-    ) -> Result<()> {
+    ) -> Result<(Signature, u64)> {
         let instruction = Instruction {
             program_id: self.program_id,
             accounts: vec![
@@ -1165,28 +1882,19 @@ impl VaultClient {
                 solana_sdk::instruction::AccountMeta::new_readonly(*signature_storage, false),
                 solana_sdk::instruction::AccountMeta::new_readonly(keypair.pubkey(), true),
             ],
-            data: SPHINCS_VERIFY_STEP1_FORS_BATCH1_DISCRIMINATOR.to_vec(),
+            data: self.idl.discriminator("sphincs_verify_step1_fors_batch1").to_vec(),
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
-
-        self.rpc_client.send_and_confirm_transaction(&transaction)?;
-        Ok(())
+        self.send_instructions(&[instruction], &keypair.pubkey(), &[keypair]).await
     }
 
-    /// FORS verification step 2 (trees 7-13)
+    /// FORS verification step 2 (remaining FORS trees)
     async fn sphincs_verify_fors_batch2(
         &self,
-        keypair: &Keypair,
+        keypair: &Arc<VaultSigner>,
         verification_state: &Pubkey,
         signature_storage: &Pubkey,
-    ) -> Result<()> {
+    ) -> Result<(Signature, u64)> {
         let instruction = Instruction {
             program_id: self.program_id,
             accounts: vec![
@@ -1194,59 +1902,41 @@ impl VaultClient {
                 solana_sdk::instruction::AccountMeta::new_readonly(*signature_storage, false),
                 solana_sdk::instruction::AccountMeta::new_readonly(keypair.pubkey(), true),
             ],
-            data: SPHINCS_VERIFY_STEP2_FORS_BATCH2_DISCRIMINATOR.to_vec(),
+            data: self.idl.discriminator("sphincs_verify_step2_fors_batch2").to_vec(),
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
-
-        self.rpc_client.send_and_confirm_transaction(&transaction)?;
-        Ok(())
+        self.send_instructions(&[instruction], &keypair.pubkey(), &[keypair]).await
     }
 
     /// FORS root computation (step 3)
     async fn sphincs_verify_fors_root(
         &self,
-        keypair: &Keypair,
+        keypair: &Arc<VaultSigner>,
         verification_state: &Pubkey,
-    ) -> Result<()> {
+    ) -> Result<(Signature, u64)> {
         let instruction = Instruction {
             program_id: self.program_id,
             accounts: vec![
                 solana_sdk::instruction::AccountMeta::new(*verification_state, false),
                 solana_sdk::instruction::AccountMeta::new_readonly(keypair.pubkey(), true),
             ],
-            data: SPHINCS_VERIFY_STEP3_FORS_ROOT_DISCRIMINATOR.to_vec(),
+            data: self.idl.discriminator("sphincs_verify_step3_fors_root").to_vec(),
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
-
-        self.rpc_client.send_and_confirm_transaction(&transaction)?;
-        Ok(())
+        self.send_instructions(&[instruction], &keypair.pubkey(), &[keypair]).await
     }
 
     /// Layer WOTS Part 1 verification
     async fn sphincs_verify_layer_wots_part1(
         &self,
-        keypair: &Keypair,
+        keypair: &Arc<VaultSigner>,
         verification_state: &Pubkey,
         signature_storage: &Pubkey,
         layer: u8,
-    ) -> Result<()> {
+    ) -> Result<(Signature, u64)> {
         // Build instruction data: discriminator + layer (u8)
         let mut instruction_data = Vec::new();
-        instruction_data.extend_from_slice(&SPHINCS_VERIFY_LAYER_WOTS_PART1_DISCRIMINATOR);
+        instruction_data.extend_from_slice(&self.idl.discriminator("sphincs_verify_layer_wots_part1"));
         instruction_data.push(layer);
 
         let instruction = Instruction {
@@ -1259,29 +1949,20 @@ impl VaultClient {
             data: instruction_data,
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
-
-        self.rpc_client.send_and_confirm_transaction(&transaction)?;
-        Ok(())
+        self.send_instructions(&[instruction], &keypair.pubkey(), &[keypair]).await
     }
 
     /// Layer WOTS Part 2 verification
     async fn sphincs_verify_layer_wots_part2(
         &self,
-        keypair: &Keypair,
+        keypair: &Arc<VaultSigner>,
         verification_state: &Pubkey,
         signature_storage: &Pubkey,
         layer: u8,
-    ) -> Result<()> {
+    ) -> Result<(Signature, u64)> {
         // Build instruction data: discriminator + layer (u8)
         let mut instruction_data = Vec::new();
-        instruction_data.extend_from_slice(&SPHINCS_VERIFY_LAYER_WOTS_PART2_DISCRIMINATOR);
+        instruction_data.extend_from_slice(&self.idl.discriminator("sphincs_verify_layer_wots_part2"));
         instruction_data.push(layer);
 
         let instruction = Instruction {
@@ -1294,29 +1975,20 @@ impl VaultClient {
             data: instruction_data,
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
-
-        self.rpc_client.send_and_confirm_transaction(&transaction)?;
-        Ok(())
+        self.send_instructions(&[instruction], &keypair.pubkey(), &[keypair]).await
     }
 
     /// Layer WOTS Part 3 verification
     async fn sphincs_verify_layer_wots_part3(
         &self,
-        keypair: &Keypair,
+        keypair: &Arc<VaultSigner>,
         verification_state: &Pubkey,
         signature_storage: &Pubkey,
         layer: u8,
-    ) -> Result<()> {
+    ) -> Result<(Signature, u64)> {
         // Build instruction data: discriminator + layer (u8)
         let mut instruction_data = Vec::new();
-        instruction_data.extend_from_slice(&SPHINCS_VERIFY_LAYER_WOTS_PART3_DISCRIMINATOR);
+        instruction_data.extend_from_slice(&self.idl.discriminator("sphincs_verify_layer_wots_part3"));
         instruction_data.push(layer);
 
         let instruction = Instruction {
@@ -1329,29 +2001,20 @@ impl VaultClient {
             data: instruction_data,
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
-
-        self.rpc_client.send_and_confirm_transaction(&transaction)?;
-        Ok(())
+        self.send_instructions(&[instruction], &keypair.pubkey(), &[keypair]).await
     }
 
     /// Layer Merkle verification
     async fn sphincs_verify_layer_merkle(
         &self,
-        keypair: &Keypair,
+        keypair: &Arc<VaultSigner>,
         verification_state: &Pubkey,
         signature_storage: &Pubkey,
         layer: u8,
-    ) -> Result<()> {
+    ) -> Result<(Signature, u64)> {
         // Build instruction data: discriminator + layer (u8)
         let mut instruction_data = Vec::new();
-        instruction_data.extend_from_slice(&SPHINCS_VERIFY_LAYER_MERKLE_DISCRIMINATOR);
+        instruction_data.extend_from_slice(&self.idl.discriminator("sphincs_verify_layer_merkle"));
         instruction_data.push(layer);
 
         let instruction = Instruction {
@@ -1364,46 +2027,74 @@ impl VaultClient {
             data: instruction_data,
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
-
-        self.rpc_client.send_and_confirm_transaction(&transaction)?;
-        Ok(())
+        self.send_instructions(&[instruction], &keypair.pubkey(), &[keypair]).await
     }
 
     /// Finalize verification and unlock vault (step 11/33)
     async fn sphincs_verify_finalize(
         &self,
-        keypair: &Keypair,
+        keypair: &Arc<VaultSigner>,
         verification_state: &Pubkey,
         pq_account: &Pubkey,
         _wallet: Pubkey,
-    ) -> Result<()> {
+        hybrid_proof: Option<Instruction>,
+    ) -> Result<(Signature, u64)> {
+        let mut accounts = vec![
+            solana_sdk::instruction::AccountMeta::new(*verification_state, false),
+            solana_sdk::instruction::AccountMeta::new(*pq_account, false),
+            solana_sdk::instruction::AccountMeta::new(keypair.pubkey(), true),
+        ];
+        if hybrid_proof.is_some() {
+            accounts.push(solana_sdk::instruction::AccountMeta::new_readonly(solana_sdk::sysvar::instructions::id(), false));
+        }
+
         let instruction = Instruction {
             program_id: self.program_id,
-            accounts: vec![
-                solana_sdk::instruction::AccountMeta::new(*verification_state, false),
-                solana_sdk::instruction::AccountMeta::new(*pq_account, false),
-                solana_sdk::instruction::AccountMeta::new(keypair.pubkey(), true),
-            ],
-            data: SPHINCS_VERIFY_STEP11_FINALIZE_DISCRIMINATOR.to_vec(),
+            accounts,
+            data: self.idl.discriminator("sphincs_verify_step11_finalize").to_vec(),
         };
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
+        let mut instructions: Vec<Instruction> = hybrid_proof.into_iter().collect();
+        instructions.push(instruction);
 
-        self.rpc_client.send_and_confirm_transaction(&transaction)?;
-        Ok(())
+        self.send_instructions(&instructions, &keypair.pubkey(), &[keypair]).await
+    }
+
+    /// Verify an uploaded ML-DSA signature against the challenge and unlock in one step
+    async fn verify_mldsa_and_unlock(
+        &self,
+        keypair: &Arc<VaultSigner>,
+        signature_storage: &Pubkey,
+        pq_account: &Pubkey,
+        unlock_duration_slots: u64,
+        hybrid_proof: Option<Instruction>,
+    ) -> Result<(Signature, u64)> {
+        use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+        // discriminator + unlock_duration_slots (u64)
+        let mut instruction_data = self.idl.discriminator("verify_mldsa_and_unlock").to_vec();
+        instruction_data.extend_from_slice(&unlock_duration_slots.to_le_bytes());
+
+        let mut accounts = vec![
+            solana_sdk::instruction::AccountMeta::new_readonly(*signature_storage, false),
+            solana_sdk::instruction::AccountMeta::new(*pq_account, false),
+            solana_sdk::instruction::AccountMeta::new(keypair.pubkey(), true),
+        ];
+        if hybrid_proof.is_some() {
+            accounts.push(solana_sdk::instruction::AccountMeta::new_readonly(solana_sdk::sysvar::instructions::id(), false));
+        }
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: instruction_data,
+        };
+
+        let mut instructions = vec![ComputeBudgetInstruction::set_compute_unit_limit(MLDSA_VERIFY_COMPUTE_UNITS)];
+        instructions.extend(hybrid_proof);
+        instructions.push(instruction);
+
+        self.send_instructions(&instructions, &keypair.pubkey(), &[keypair]).await
     }
 
     /// Check vault status
@@ -1415,25 +2106,12 @@ impl VaultClient {
         println!("PQ Account (PDA): {}", pq_account.to_string().cyan());
         println!();
 
-        let account_info = self.rpc_client.get_account(&pq_account)
+        let account_info = self.get_account_cached(&pq_account).await
             .context("PQ account not found! Register first with: qdum-vault register")?;
 
-        // Parse account data (assuming public_key Vec<u8> with length = 0)
-        // Layout: discriminator(8) + owner(32) + algorithm(1) + pubkey_len(4) + tokens_locked(1) + unlock_challenge(32) + ...
-        let _owner_pubkey = &account_info.data[8..40];
-        let algorithm = account_info.data[40];
-        let pubkey_len = u32::from_le_bytes(account_info.data[41..45].try_into().unwrap());
-        let tokens_locked_offset = 45 + pubkey_len as usize;
-        let is_locked = account_info.data[tokens_locked_offset];
-        let unlock_challenge_offset = tokens_locked_offset + 1;
-        let unlock_challenge = &account_info.data[unlock_challenge_offset..unlock_challenge_offset + 32];
-
-        // Read the actual public key if it exists
-        let sphincs_pubkey = if pubkey_len > 0 {
-            &account_info.data[45..45 + pubkey_len as usize]
-        } else {
-            &[] // No public key set yet
-        };
+        let account = PqAccount::parse(&account_info.data)?;
+        let algorithm = account.algorithm;
+        let sphincs_pubkey = &account.public_key[..];
 
         // Create status table
         use comfy_table::{Table, presets::UTF8_FULL};
@@ -1468,10 +2146,13 @@ impl VaultClient {
 
         status_table.add_row(vec![
             "Algorithm".dimmed().to_string(),
-            format!("SPHINCS+-SHA2-128s ({})", algorithm).bright_green().to_string()
+            match account.algorithm() {
+                Some(algo) => format!("{} ({})", algo, algorithm).bright_green().to_string(),
+                None => format!("Unknown ({})", algorithm).yellow().to_string(),
+            }
         ]);
 
-        let status_display = if is_locked == 1 {
+        let status_display = if account.tokens_locked {
             "🔒 LOCKED".red().bold().to_string()
         } else {
             "🔓 UNLOCKED".green().bold().to_string()
@@ -1484,14 +2165,29 @@ impl VaultClient {
         println!("{}", status_table);
         println!();
 
-        if is_locked == 1 {
+        if let Some(unlock_slot) = account.pending_unlock() {
+            let current_slot = self.rpc_client.get_slot().await?;
+            let remaining = unlock_slot.saturating_sub(current_slot);
+
+            println!("{}", "⏳ Unlock Pending".yellow().bold());
+            println!();
+            println!("  {} Unlocks at slot {} (current slot {})", "•".bright_yellow(), unlock_slot.to_string().bright_cyan(), current_slot);
+            if remaining > 0 {
+                println!("  {} {} slots remaining ({})", "•".bright_yellow(), remaining.to_string().bright_white().bold(), format_slots(remaining));
+            } else {
+                println!("  {} Delay elapsed; tokens unlock on the next interaction", "•".bright_yellow());
+            }
+            println!();
+        }
+
+        if account.tokens_locked {
             println!("{}", "⚠️  Vault is Locked".yellow().bold());
             println!();
             println!("  {} Your tokens cannot be transferred while locked.", "•".bright_yellow());
             println!("  {} Run {} to unlock", "•".bright_yellow(), "qdum-vault unlock".bright_green());
             println!();
             println!("{}", "Unlock Challenge:".dimmed());
-            println!("  {}", hex::encode(unlock_challenge).bright_cyan());
+            println!("  {}", hex::encode(account.unlock_challenge).bright_cyan());
         } else {
             println!("{}", "✓ Vault is Unlocked".green().bold());
             println!();
@@ -1508,22 +2204,72 @@ impl VaultClient {
     pub async fn get_vault_status(&self, wallet: Pubkey) -> Result<(bool, Pubkey)> {
         let (pq_account, _) = self.derive_pq_account(wallet);
 
-        let account_info = self.rpc_client.get_account(&pq_account)
+        let account_info = self.get_account_cached(&pq_account).await
             .context("PQ account not found! Register first with: qdum-vault register")?;
 
-        // Parse account data to get is_locked status
-        let pubkey_len = u32::from_le_bytes(account_info.data[41..45].try_into().unwrap());
-        let tokens_locked_offset = 45 + pubkey_len as usize;
-        let is_locked = account_info.data[tokens_locked_offset];
+        let account = PqAccount::parse(&account_info.data)?;
+
+        Ok((account.tokens_locked, pq_account))
+    }
+
+    /// Unix time from which the wallet can claim the airdrop again,
+    /// `None` if it has never claimed
+    pub async fn next_airdrop_claim(&self, wallet: Pubkey) -> Result<Option<i64>> {
+        let (pq_account, _) = self.derive_pq_account(wallet);
+
+        let account_info = self.get_account_cached(&pq_account).await
+            .context("PQ account not found! Register first with: qdum-vault register")?;
 
-        Ok((is_locked == 1, pq_account))
+        Ok(PqAccount::parse(&account_info.data)?.next_airdrop_claim())
+    }
+
+    /// Check whether a PQ account has been registered for this wallet
+    pub async fn pq_account_exists(&self, wallet: Pubkey) -> Result<bool> {
+        let (pq_account, _) = self.derive_pq_account(wallet);
+
+        let response = self.rpc_client
+            .get_account_with_commitment(&pq_account, self.rpc_client.commitment()).await
+            .context("Failed to query PQ account")?;
+
+        Ok(response.value.is_some())
+    }
+
+    /// Algorithm byte and PQ public key registered for this wallet, if it has a PQ account
+    pub async fn registered_public_key(&self, wallet: Pubkey) -> Result<Option<(u8, Vec<u8>)>> {
+        let (pq_account, _) = self.derive_pq_account(wallet);
+
+        let response = self.rpc_client
+            .get_account_with_commitment(&pq_account, self.rpc_client.commitment()).await
+            .context("Failed to query PQ account")?;
+
+        match response.value {
+            Some(account) => {
+                let account = PqAccount::parse(&account.data)?;
+                Ok(Some((account.algorithm, account.public_key)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Decimals of `mint`, fetched once and cached across runs (see [`units`])
+    pub async fn mint_decimals(&self, mint: Pubkey) -> Result<u8> {
+        if let Some(decimals) = units::cached_decimals(&mint) {
+            return Ok(decimals);
+        }
+        let mint_account = self
+            .get_account_cached(&mint)
+            .await
+            .with_context(|| format!("Mint {} not found", mint))?;
+        let decimals = units::parse_mint_decimals(&mint_account.data).with_context(|| format!("{} is not a token mint", mint))?;
+        units::remember(&mint, decimals);
+        Ok(decimals)
     }
 
     /// Get token balance without printing (for dashboard)
     /// Returns balance in base units (raw u64)
     pub async fn get_balance(&self, wallet: Pubkey, mint: Pubkey) -> Result<u64> {
         // Check which token program the mint uses by fetching mint account
-        let mint_account = self.rpc_client.get_account(&mint)?;
+        let mint_account = self.get_account_cached(&mint).await?;
         let token_program_id = if mint_account.owner == TOKEN_2022_PROGRAM_ID {
             &TOKEN_2022_PROGRAM_ID
         } else {
@@ -1533,7 +2279,7 @@ impl VaultClient {
         // Derive ATA (Associated Token Account) with correct token program
         let ata = get_associated_token_address(&wallet, &mint, token_program_id);
 
-        match self.rpc_client.get_account(&ata) {
+        match self.get_account_cached(&ata).await {
             Ok(account_info) => {
                 // Parse token account data (amount is at offset 64, 8 bytes little-endian)
                 let amount = u64::from_le_bytes(account_info.data[64..72].try_into().unwrap());
@@ -1548,7 +2294,7 @@ impl VaultClient {
 
     pub async fn token_account_exists(&self, wallet: Pubkey, mint: Pubkey) -> Result<bool> {
         // Check which token program the mint uses by fetching mint account
-        let mint_account = self.rpc_client.get_account(&mint)?;
+        let mint_account = self.get_account_cached(&mint).await?;
         let token_program_id = if mint_account.owner == TOKEN_2022_PROGRAM_ID {
             &TOKEN_2022_PROGRAM_ID
         } else {
@@ -1559,13 +2305,13 @@ impl VaultClient {
         let ata = get_associated_token_address(&wallet, &mint, token_program_id);
 
         // Check if account exists
-        Ok(self.rpc_client.get_account(&ata).is_ok())
+        Ok(self.get_account_cached(&ata).await.is_ok())
     }
 
     /// Get SOL balance (in lamports)
     pub async fn get_sol_balance(&self, wallet: Pubkey) -> Result<u64> {
-        self.rpc_client.get_balance(&wallet)
-            .map_err(|e| anyhow::anyhow!("Failed to get SOL balance: {}", e))
+        self.rpc_client.get_balance(&wallet).await
+            .context("Failed to get SOL balance")
     }
 
     /// Check token balance
@@ -1575,7 +2321,7 @@ impl VaultClient {
         println!();
 
         // Check which token program the mint uses by fetching mint account
-        let mint_account = self.rpc_client.get_account(&mint)?;
+        let mint_account = self.get_account_cached(&mint).await?;
         let token_program_id = if mint_account.owner == TOKEN_2022_PROGRAM_ID {
             &TOKEN_2022_PROGRAM_ID
         } else {
@@ -1588,11 +2334,11 @@ impl VaultClient {
         println!("Token Account (ATA): {}", ata.to_string().cyan());
         println!();
 
-        match self.rpc_client.get_account(&ata) {
+        match self.get_account_cached(&ata).await {
             Ok(account_info) => {
                 // Parse token account data (amount is at offset 64, 8 bytes little-endian)
                 let amount = u64::from_le_bytes(account_info.data[64..72].try_into().unwrap());
-                let balance = amount as f64 / 1_000_000.0; // 6 decimals
+                let balance = units::to_tokens(amount, self.mint_decimals(mint).await?);
 
                 println!("{}", "💰 Balance".bold().cyan());
                 println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
@@ -1623,32 +2369,35 @@ impl VaultClient {
     /// - 4×    = 40
     pub async fn transfer_tokens(
         &self,
-        keypair: &Keypair,
+        keypair: &(impl Signer + Sync),
         recipient: Pubkey,
         mint: Pubkey,
         amount: u64,
-    ) -> Result<()> {
+    ) -> Result<Receipt> {
         self.transfer_tokens_with_confirm(keypair, recipient, mint, amount, true).await
     }
 
     pub async fn transfer_tokens_with_confirm(
         &self,
-        keypair: &Keypair,
+        keypair: &(impl Signer + Sync),
         recipient: Pubkey,
         mint: Pubkey,
         amount: u64,
         skip_confirm: bool,
-    ) -> Result<()> {
+    ) -> Result<Receipt> {
         use solana_sdk::instruction::Instruction;
         use std::io::{self, Write};
 
         println!("To:           {}", recipient.to_string().cyan());
-        println!("Amount:       {} base units ({} QDUM)", amount.to_string().yellow(), (amount as f64 / 1_000_000.0).to_string().green());
+        let decimals = self.mint_decimals(mint).await?;
+        println!("Amount:       {} base units ({} QDUM)", amount.to_string().yellow(), units::to_tokens(amount, decimals).to_string().green());
         println!("Mint:         {}", mint.to_string().cyan());
         println!();
 
+        self.policy.check_transfer(&recipient, &mint, amount)?;
+
         // Detect which token program this mint uses
-        let mint_account = self.rpc_client.get_account(&mint)?;
+        let mint_account = self.get_account_cached(&mint).await?;
         let token_program_id = if mint_account.owner == TOKEN_2022_PROGRAM_ID {
             &TOKEN_2022_PROGRAM_ID
         } else {
@@ -1670,44 +2419,62 @@ impl VaultClient {
             token_program_id,
         );
 
-        // Derive PQ account PDA for sender (for transfer hook validation)
+        // Derive PQ account PDA for sender (its lock state is checked below)
         let (pq_account, _) = self.derive_pq_account(keypair.pubkey());
 
+        let mut receipt = Receipt::new(Operation::Transfer);
+        receipt
+            .account("sender", &keypair.pubkey())
+            .account("recipient", &recipient)
+            .account("mint", &mint)
+            .account("sender_token_account", &sender_token_account)
+            .account("recipient_token_account", &recipient_token_account);
+        receipt.amount = Some(amount);
+
         // Check if sender account has sufficient balance
-        let sender_account_info = self.rpc_client.get_account(&sender_token_account)
+        let sender_account_info = self.rpc_client.get_account(&sender_token_account).await
             .context("Sender token account not found! You don't have any tokens to transfer.")?;
 
         let current_balance = u64::from_le_bytes(sender_account_info.data[64..72].try_into().unwrap());
-        let balance_qdum = current_balance as f64 / 1_000_000.0;
+        let balance_qdum = units::to_tokens(current_balance, decimals);
 
         println!("{}", "╔═══════════════════════════════════════════════════════════╗".bright_cyan());
         println!("{}", "║                  TRANSFER SUMMARY                         ║".bright_cyan().bold());
         println!("{}", "╚═══════════════════════════════════════════════════════════╝".bright_cyan());
         println!();
         println!("{} {}", "Your Balance:".bold(), format!("{} QDUM", balance_qdum).green());
-        println!("{} {}", "Transfer Amount:".bold(), format!("{} QDUM", amount as f64 / 1_000_000.0).yellow());
-        println!("{} {}", "Remaining:".bold(), format!("{} QDUM", (current_balance - amount) as f64 / 1_000_000.0).cyan());
+        println!("{} {}", "Transfer Amount:".bold(), format!("{} QDUM", units::to_tokens(amount, decimals)).yellow());
+        println!("{} {}", "Remaining:".bold(), format!("{} QDUM", units::to_tokens(current_balance.saturating_sub(amount), decimals)).cyan());
+        let extensions = self.mint_extensions(mint).await?;
+        if let Some(fee) = extensions.transfer_fee {
+            println!("{} {}", "Transfer Fee:".bold(), format!(
+                "{} QDUM ({}, at most {} QDUM)",
+                units::to_tokens(fee.fee(amount), decimals),
+                fee.rate(),
+                units::to_tokens(fee.maximum, decimals)
+            ).yellow());
+            println!("{} {}", "Recipient Gets:".bold(), format!("{} QDUM", units::to_tokens(extensions.net_amount(amount), decimals)).green());
+        }
+        if !extensions.names.is_empty() {
+            println!("{} {}", "Extensions:".bold(), extensions.names.join(", ").dimmed());
+        }
         println!();
 
         if current_balance < amount {
             println!("{}", "❌ Insufficient balance!".red().bold());
-            return Err(anyhow::anyhow!("Insufficient balance for transfer"));
+            return Err(ExitKind::InsufficientFunds.error("Insufficient balance for transfer"));
         }
 
         // Check if PQ account exists and is locked - ONLY for pqQDUM (Token-2022) transfers
         if *token_program_id == TOKEN_2022_PROGRAM_ID {
-            if let Ok(pq_account_info) = self.rpc_client.get_account(&pq_account) {
-                let pubkey_len = u32::from_le_bytes(pq_account_info.data[41..45].try_into().unwrap());
-                let tokens_locked_offset = 45 + pubkey_len as usize;
-                let is_locked = pq_account_info.data[tokens_locked_offset] == 1;
-
-                if is_locked {
+            if let Ok(pq_account_info) = self.rpc_client.get_account(&pq_account).await {
+                if PqAccount::parse(&pq_account_info.data)?.tokens_locked {
                     println!("{}", "⚠️  Your vault is LOCKED!".red().bold());
                     println!();
                     println!("pqQDUM transfers are disabled while your vault is locked.");
                     println!("Standard QDUM can be transferred freely.");
                     println!();
-                    return Err(anyhow::anyhow!("Vault is locked - cannot transfer pqQDUM"));
+                    return Err(ExitKind::VaultLocked.error("Vault is locked - cannot transfer pqQDUM"));
                 } else {
                     println!("{}", "✓ Vault is unlocked - pqQDUM transfer allowed".green());
                     println!();
@@ -1733,24 +2500,17 @@ impl VaultClient {
             if answer != "y" && answer != "yes" {
                 println!();
                 println!("{}", "❌ Transfer cancelled".red());
-                return Ok(());
+                return Ok(receipt.skipped("Transfer cancelled"));
             }
 
             println!();
         }
 
-        // Build transaction with ComputeBudget instructions (like Phantom does)
+        // Compute budget instructions are prepended when the transaction is signed
         let mut instructions = Vec::new();
 
-        // Add ComputeBudget instructions to request more compute units
-        // Phantom uses: setComputeUnitLimit (200,000) and setComputeUnitPrice
-        use solana_sdk::compute_budget::ComputeBudgetInstruction;
-
-        instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(200_000));
-        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(200_000));
-
         // Check if recipient ATA exists, create if not
-        match self.rpc_client.get_account(&recipient_token_account) {
+        match self.rpc_client.get_account(&recipient_token_account).await {
             Ok(_) => {
                 println!("Recipient token account exists: {}", recipient_token_account.to_string().cyan());
             }
@@ -1779,53 +2539,21 @@ impl VaultClient {
 
         // Build transfer instruction - different for SPL Token vs Token-2022
         let transfer_ix = if *token_program_id == TOKEN_2022_PROGRAM_ID {
-            // Token-2022 with transfer hook - manually add extra accounts
-            // Create basic transfer instruction (4 accounts)
-            let mut transfer_ix = spl_token_2022::instruction::transfer_checked(
-                &TOKEN_2022_PROGRAM_ID,
+            // Token-2022: adds the accounts listed in the hook's ExtraAccountMetaList
+            self.transfer_checked_with_hook(
                 &sender_token_account,
                 &mint,
                 &recipient_token_account,
                 &keypair.pubkey(),
-                &[],
                 amount,
-                6,
-            )?;
-
-            // Manually add transfer hook accounts (required for Token-2022 with transfer hooks)
-            // Based on working implementation in test-transfer.ts lines 185-214
-
-            // Derive ExtraAccountMetaList PDA
-            let (extra_account_meta_list, _) = Pubkey::find_program_address(
-                &[b"extra-account-metas", mint.as_ref()],
-                &self.program_id,
-            );
-
-            // Index 4: Transfer hook program ID (required by Token-2022 to invoke the hook)
-            transfer_ix.accounts.push(solana_sdk::instruction::AccountMeta::new_readonly(
-                self.program_id,
-                false,
-            ));
-
-            // Index 5: ExtraAccountMetaList PDA (required by Token-2022)
-            transfer_ix.accounts.push(solana_sdk::instruction::AccountMeta::new_readonly(
-                extra_account_meta_list,
-                false,
-            ));
-
-            // Index 6: PQ Account PDA (extra account from ExtraAccountMetaList)
-            transfer_ix.accounts.push(solana_sdk::instruction::AccountMeta::new_readonly(
-                pq_account,
-                false,
-            ));
-
-            transfer_ix
+                decimals,
+            ).await?
         } else {
             // Simple SPL Token TransferChecked (no transfer hook)
             let mut instruction_data = Vec::new();
             instruction_data.push(12); // TransferChecked discriminator
             instruction_data.extend_from_slice(&amount.to_le_bytes());
-            instruction_data.push(6); // decimals
+            instruction_data.push(decimals);
 
             let accounts = vec![
                 solana_sdk::instruction::AccountMeta::new(sender_token_account, false),           // 0: source
@@ -1843,16 +2571,14 @@ impl VaultClient {
 
         instructions.push(transfer_ix);
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &instructions,
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
+        if self.unsigned_out.is_some() {
+            return self.export_unsigned(&instructions, &keypair.pubkey(), receipt).await;
+        }
+
+        let transaction = self.signed_transaction(&instructions, &keypair.pubkey(), &[keypair]).await?;
 
         // Progress bar
-        let pb = ProgressBar::new(3);
+        let pb = output::progress_bar(3);
         pb.set_style(
             ProgressStyle::default_bar()
                 .template("{spinner:.cyan} [{bar:40.cyan/blue}] {pos}/{len} {msg}")
@@ -1884,15 +2610,18 @@ impl VaultClient {
         writeln!(log_file, "Simulating transaction...")?;
 
         println!("Simulating transaction... (logs: {})", log_path.display());
-        match self.rpc_client.simulate_transaction(&transaction) {
+        match self.rpc_client.simulate_transaction(&transaction).await {
             Ok(sim_result) => {
+                output::program_logs("Simulation logs:", sim_result.value.logs.as_deref().unwrap_or_default());
                 if let Some(err) = sim_result.value.err {
                     writeln!(log_file, "❌ Simulation failed: {:?}", err)?;
 
-                    // Extract a user-friendly error message (using ref to avoid move)
-                    let error_summary = if let Some(ref logs) = sim_result.value.logs {
+                    // Extract a user-friendly error message
+                    let logs = sim_result.value.logs.as_deref().unwrap_or_default();
+                    let error_summary = match self.explain_transaction_error(&err, logs, &transaction) {
+                        Some(failure) => failure.to_string(),
                         // Look for the actual error in logs
-                        logs.iter()
+                        None => logs.iter()
                             .find(|log| log.contains("Error:"))
                             .map(|log| {
                                 // Extract just the error part
@@ -1902,9 +2631,7 @@ impl VaultClient {
                                     log.to_string()
                                 }
                             })
-                            .unwrap_or_else(|| format!("{:?}", err))
-                    } else {
-                        format!("{:?}", err)
+                            .unwrap_or_else(|| format!("{:?}", err)),
                     };
 
                     // Now write logs to file (this moves sim_result.value.logs)
@@ -1936,11 +2663,28 @@ impl VaultClient {
         }
 
         pb.set_message(format!("{}", "Sending to network...".bright_white()));
-        let signature = self.rpc_client.send_and_confirm_transaction(&transaction).map_err(|e| {
+        let (signature, fee) = self.send_with_retry(&transaction, &[keypair]).await.map_err(|e| {
             let _ = writeln!(log_file, "Transaction send failed: {:?}", e);
             let _ = log_file.flush();
             e
         })?;
+        receipt.record((signature, fee));
+
+        // Counted against policy.max_daily_transfer
+        let spend = Spend {
+            at: chrono::Utc::now().timestamp(),
+            amount,
+            decimals,
+            mint: mint.to_string(),
+            recipient: recipient.to_string(),
+            signature: signature.to_string(),
+        };
+        if let Err(e) = SpendingLedger::record(spend) {
+            eprintln!("{} Failed to record transfer for the daily limit: {:#}", "[!]".yellow(), e);
+        }
+        if let Err(e) = RecipientHistory::record(&recipient) {
+            eprintln!("{} Failed to record recipient history: {:#}", "[!]".yellow(), e);
+        }
 
         writeln!(log_file, "✓ Transaction successful: {}", signature)?;
         log_file.flush()?;
@@ -1957,29 +2701,33 @@ impl VaultClient {
         println!("{}", "║          ✅ TRANSFER COMPLETE [SUCCESS]                  ║".on_black().bright_green().bold());
         println!("{}", "╚═══════════════════════════════════════════════════════════╝".on_black().bright_green());
         println!();
-        println!("{} {}", "  ┃ Amount:     ".on_black().bright_magenta().bold(), format!("{} QDUM", amount as f64 / 1_000_000.0).on_black().bright_green());
+        println!("{} {}", "  ┃ Amount:     ".on_black().bright_magenta().bold(), format!("{} QDUM", units::to_tokens(amount, decimals)).on_black().bright_green());
         println!("{} {}", "  ┃ Recipient:  ".on_black().bright_magenta().bold(), recipient.to_string().on_black().bright_cyan());
         println!("{} {}", "  ┃ Transaction:".on_black().bright_magenta().bold(), signature.to_string().on_black().cyan());
         println!();
         println!("{}", format!("   View on Solscan: https://solscan.io/tx/{}?cluster=devnet", signature).dimmed());
         println!();
 
-        Ok(())
+        Ok(receipt.finish())
     }
 
     /// Get total locked QDUM across ALL network holders (with caching and batching)
     /// Returns (total_locked, holder_count)
     pub async fn get_network_locked_total(&self, mint: Pubkey, force_refresh: bool) -> Result<(f64, usize)> {
+        let holders = self.network_locked_holders(mint, force_refresh).await?;
+        Ok((holders.iter().map(|holder| holder.amount).sum(), holders.len()))
+    }
+
+    /// Every locked vault owner with a non-zero balance of `mint`, in QDUM
+    /// (shares the cache with [`Self::get_network_locked_total`])
+    pub async fn network_locked_holders(&self, mint: Pubkey, force_refresh: bool) -> Result<Vec<LockedHolder>> {
 
         // Check cache first (5 minute expiry) unless force_refresh is true
         if !force_refresh {
             let cache_max_age = Duration::from_secs(5 * 60);
-            {
-                let cache = self.network_lock_cache.lock().unwrap();
-                if let Some(cached) = cache.as_ref() {
-                    if cached.mint == mint && !cached.is_expired(cache_max_age) {
-                        return Ok((cached.total_locked, cached.holder_count));
-                    }
+            if let Some(cached) = self.cached_network_lock(mint) {
+                if !cached.is_expired(cache_max_age) {
+                    return Ok(cached.holders);
                 }
             }
         }
@@ -1987,9 +2735,8 @@ impl VaultClient {
         // Cache miss or expired - query the network with optimized filters
 
         // OPTIMIZATION: Use RPC filters to only fetch LOCKED accounts
-        // Account layout: discriminator(8) + owner(32) + algorithm(1) + pubkey_len(4) + pubkey_data(32) + is_locked(1)
-        // For SPHINCS+ (32 byte keys), is_locked is at offset 77 (8+32+1+4+32=77)
-        const LOCKED_OFFSET: usize = 77;
+        // For SPHINCS+ (32 byte keys), is_locked is at offset 77
+        const LOCKED_OFFSET: usize = PqAccount::tokens_locked_offset(32);
 
         let config = RpcProgramAccountsConfig {
             filters: Some(vec![
@@ -2008,7 +2755,7 @@ impl VaultClient {
         };
 
         // Get only LOCKED PQ accounts (1 RPC call, highly filtered)
-        let accounts = self.rpc_client.get_program_accounts_with_config(&self.program_id, config)?;
+        let accounts = self.rpc_client.get_program_accounts_with_config(&self.program_id, config).await?;
 
         let mut debug_log = format!("=== Network Lock Query (OPTIMIZED with RPC Filters) ===\n");
         debug_log.push_str(&format!("Program ID: {}\n", self.program_id));
@@ -2022,12 +2769,7 @@ impl VaultClient {
         for (_pubkey, account) in &accounts {
             let account_data = &account.data;
 
-            // Check if account has enough data (8 discriminator + 32 owner)
-            if account_data.len() >= 40 {
-                // Extract owner pubkey from account data
-                let mut owner_bytes = [0u8; 32];
-                owner_bytes.copy_from_slice(&account_data[8..40]);
-                let owner = Pubkey::new_from_array(owner_bytes);
+            if let Some(owner) = PqAccount::parse_owner(account_data) {
                 locked_owners.push(owner);
             }
         }
@@ -2049,7 +2791,7 @@ impl VaultClient {
         let mut all_balances: Vec<Option<u64>> = vec![None; token_accounts.len()];
 
         for (i, chunk) in token_accounts.chunks(BATCH_SIZE).enumerate() {
-            match self.rpc_client.get_multiple_accounts(chunk) {
+            match self.rpc_client.get_multiple_accounts(chunk).await {
                 Ok(accounts_batch) => {
                     for (j, account_opt) in accounts_batch.iter().enumerate() {
                         let idx = i * BATCH_SIZE + j;
@@ -2072,6 +2814,7 @@ impl VaultClient {
         debug_log.push_str(&format!("\n"));
 
         // Step 4: Process results (only locked accounts)
+        let decimals = self.mint_decimals(mint).await?;
         let mut total_locked: u64 = 0;
         let mut locked_count = 0;
         let mut all_accounts_with_balance = Vec::new();
@@ -2083,7 +2826,7 @@ impl VaultClient {
                     total_locked += balance;
                     locked_count += 1;
                     all_accounts_with_balance.push((*owner, balance));
-                    debug_log.push_str(&format!("  LOCKED: {} - {} QDUM ✓\n", owner, balance as f64 / 1_000_000.0));
+                    debug_log.push_str(&format!("  LOCKED: {} - {} QDUM ✓\n", owner, units::to_tokens(balance, decimals)));
                 } else {
                     // Don't count accounts with 0 balance
                     debug_log.push_str(&format!("  LOCKED: {} - 0 QDUM (empty)\n", owner));
@@ -2096,13 +2839,13 @@ impl VaultClient {
 
         debug_log.push_str(&format!("\n=== SUMMARY ===\n"));
         debug_log.push_str(&format!("Locked accounts: {}\n", locked_count));
-        debug_log.push_str(&format!("Total locked QDUM: {}\n", total_locked as f64 / 1_000_000.0));
+        debug_log.push_str(&format!("Total locked QDUM: {}\n", units::to_tokens(total_locked, decimals)));
 
         debug_log.push_str(&format!("\n=== LOCKED ACCOUNTS WITH BALANCES ===\n"));
         for (owner, balance) in &all_accounts_with_balance {
             debug_log.push_str(&format!("  {} - {} QDUM 🔒\n",
                 owner,
-                *balance as f64 / 1_000_000.0
+                units::to_tokens(*balance, decimals)
             ));
         }
 
@@ -2115,23 +2858,23 @@ impl VaultClient {
             1 + (token_accounts.len() + BATCH_SIZE - 1) / BATCH_SIZE,
             (token_accounts.len() + BATCH_SIZE - 1) / BATCH_SIZE));
         debug_log.push_str(&format!("Without filter optimization: Would fetch ALL accounts (locked + unlocked) then filter locally\n"));
-        let _ = std::fs::write("/tmp/qdum-network-query.log", debug_log);
+        crate::applog::write(crate::applog::Level::Debug, "network", debug_log);
 
-        // Convert to QDUM (divide by 1_000_000)
-        let total_qdum = total_locked as f64 / 1_000_000.0;
+        // Convert to tokens with the mint's decimals
+        let holders: Vec<LockedHolder> = all_accounts_with_balance
+            .into_iter()
+            .map(|(owner, balance)| LockedHolder { owner, amount: units::to_tokens(balance, decimals) })
+            .collect();
 
         // Update cache
-        {
-            let mut cache = self.network_lock_cache.lock().unwrap();
-            *cache = Some(NetworkLockCache {
-                total_locked: total_qdum,
-                holder_count: locked_count,
-                timestamp: SystemTime::now(),
-                mint,
-            });
-        }
+        self.store_network_lock(NetworkLockCache {
+            holders: holders.clone(),
+            timestamp: SystemTime::now(),
+            mint,
+            program_id: self.program_id,
+        });
 
-        Ok((total_qdum, locked_count))
+        Ok(holders)
     }
 
     /// Get the cache timestamp for display purposes
@@ -2151,29 +2894,9 @@ impl VaultClient {
         );
 
         // Fetch mint state account
-        let account = self.rpc_client.get_account(&mint_state_pda)?;
-
-        // MintState layout:
-        // 0-7: discriminator (8)
-        // 8-39: authority (32)
-        // 40-71: mint (32)
-        // 72-79: total_minted (8)
-        // 80-111: dev_wallet (32)
-        // 112-143: transfer_hook_program (32)
-        // 144-151: authority_minted (8)
-        // 152-159: airdrop_distributed (8)
-
-        const AIRDROP_DISTRIBUTED_OFFSET: usize = 152;
+        let account = self.get_account_cached(&mint_state_pda).await?;
 
-        if account.data.len() < AIRDROP_DISTRIBUTED_OFFSET + 8 {
-            return Err(anyhow::anyhow!("Invalid mint state account data"));
-        }
-
-        let airdrop_distributed = u64::from_le_bytes(
-            account.data[AIRDROP_DISTRIBUTED_OFFSET..AIRDROP_DISTRIBUTED_OFFSET + 8]
-                .try_into()
-                .unwrap()
-        );
+        let airdrop_distributed = MintState::parse(&account.data)?.airdrop_distributed;
 
         // Total airdrop cap: 3% of supply = 128,849,018.88 QDUM (with 6 decimals)
         const AIRDROP_CAP: u64 = 128_849_018_880_000;
@@ -2190,27 +2913,20 @@ impl VaultClient {
         amount: u64,
         standard_mint: Pubkey,
         pq_mint: Pubkey,
-    ) -> Result<String> {
+    ) -> Result<Receipt> {
         use solana_sdk::signer::Signer as _;
-        use std::io::Write;
-
-        let log_path = "/tmp/dashboard-wrap.log";
-        let log_msg = |msg: String| {
-            // Only log to file, NOT to stdout (to avoid corrupting TUI)
-            if let Ok(mut file) = std::fs::OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(log_path)
-            {
-                let _ = writeln!(file, "{}", msg);
-            }
-        };
+
+        // Only log to file, NOT to stdout (to avoid corrupting TUI)
+        let log_msg = |msg: String| crate::applog::write(crate::applog::Level::Info, "bridge", msg);
 
         let user_keypair = self.load_keypair(keypair_path)?;
 
-        log_msg(format!("🔄 Wrapping {} QDUM → pqQDUM...", amount as f64 / 1_000_000.0));
+        let decimals = self.mint_decimals(standard_mint).await?;
+        log_msg(format!("🔄 Wrapping {} QDUM → pqQDUM...", units::to_tokens(amount, decimals)));
         log_msg(format!("   User: {}", user_keypair.pubkey()));
 
+        self.check_wrap(user_keypair.pubkey(), amount, standard_mint, pq_mint).await?;
+
         // Derive bridge PDA
         let (bridge_pda, bridge_bump) = Pubkey::find_program_address(
             &[b"bridge"],
@@ -2219,6 +2935,14 @@ impl VaultClient {
         log_msg(format!("   Bridge PDA: {}", bridge_pda));
         log_msg(format!("   Bridge Bump: {}", bridge_bump));
 
+        let mut receipt = Receipt::new(Operation::Wrap);
+        receipt
+            .account("wallet", &user_keypair.pubkey())
+            .account("bridge", &bridge_pda)
+            .account("standard_mint", &standard_mint)
+            .account("pq_mint", &pq_mint);
+        receipt.amount = Some(amount);
+
         // Get user's token accounts
         let user_standard_account = get_associated_token_address(
             &user_keypair.pubkey(),
@@ -2237,7 +2961,7 @@ impl VaultClient {
         log_msg(format!("   User pqQDUM account: {}", user_pq_account));
 
         // Check if pq account exists, create if needed
-        if self.rpc_client.get_account(&user_pq_account).is_err() {
+        if self.rpc_client.get_account(&user_pq_account).await.is_err() {
             log_msg(format!("   Creating pqQDUM token account..."));
             let create_ata_ix = create_associated_token_account_instruction(
                 &user_keypair.pubkey(),
@@ -2245,20 +2969,13 @@ impl VaultClient {
                 &pq_mint,
                 &TOKEN_2022_PROGRAM_ID,
             );
-            let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-            let create_tx = Transaction::new_signed_with_payer(
-                &[create_ata_ix],
-                Some(&user_keypair.pubkey()),
-                &[&user_keypair],
-                recent_blockhash,
-            );
-            self.rpc_client.send_and_confirm_transaction(&create_tx)?;
+            receipt.record(self.send_instructions(&[create_ata_ix], &user_keypair.pubkey(), &[&user_keypair]).await?);
             log_msg(format!("   ✓ pqQDUM account created"));
         }
 
         // Build wrap instruction
         let mut instruction_data = Vec::new();
-        instruction_data.extend_from_slice(&BRIDGE_WRAP_DISCRIMINATOR);
+        instruction_data.extend_from_slice(&idl::sighash("wrap"));
         instruction_data.extend_from_slice(&amount.to_le_bytes());
 
         let accounts = vec![
@@ -2281,30 +2998,24 @@ impl VaultClient {
 
         // Send transaction
         log_msg(format!("\n📤 Sending wrap transaction..."));
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[wrap_ix],
-            Some(&user_keypair.pubkey()),
-            &[&user_keypair],
-            recent_blockhash,
-        );
+        let transaction = self.signed_transaction(&[wrap_ix], &user_keypair.pubkey(), &[&user_keypair]).await?;
 
         log_msg(format!("   Transaction size: {} bytes", transaction.message_data().len()));
 
-        match self.rpc_client.send_and_confirm_transaction(&transaction) {
-            Ok(signature) => {
+        match self.send_with_retry(&transaction, &[&user_keypair]).await {
+            Ok((signature, fee)) => {
+                receipt.record((signature, fee));
                 log_msg(format!("✅ Wrap complete!"));
                 log_msg(format!("   Transaction: {}", signature));
                 log_msg(format!("   Explorer: https://explorer.solana.com/tx/{}?cluster=devnet", signature));
-                Ok(signature.to_string())
+                Ok(receipt.finish())
             }
             Err(e) => {
-                log_msg(format!("\n❌ Wrap transaction failed!"));
-                log_msg(format!("   Error: {}", e));
+                crate::applog::write(crate::applog::Level::Error, "bridge", format!("❌ Wrap transaction failed: {}", e));
                 log_msg(format!("   Bridge PDA: {}", bridge_pda));
                 log_msg(format!("   Standard mint: {}", standard_mint));
                 log_msg(format!("   pqQDUM mint: {}", pq_mint));
-                Err(e.into())
+                Err(e)
             }
         }
     }
@@ -2317,27 +3028,20 @@ impl VaultClient {
         amount: u64,
         standard_mint: Pubkey,
         pq_mint: Pubkey,
-    ) -> Result<String> {
+    ) -> Result<Receipt> {
         use solana_sdk::signer::Signer as _;
-        use std::io::Write;
-
-        let log_path = "/tmp/dashboard-unwrap.log";
-        let log_msg = |msg: String| {
-            // Only log to file, NOT to stdout (to avoid corrupting TUI)
-            if let Ok(mut file) = std::fs::OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(log_path)
-            {
-                let _ = writeln!(file, "{}", msg);
-            }
-        };
+
+        // Only log to file, NOT to stdout (to avoid corrupting TUI)
+        let log_msg = |msg: String| crate::applog::write(crate::applog::Level::Info, "bridge", msg);
 
         let user_keypair = self.load_keypair(keypair_path)?;
 
-        log_msg(format!("🔄 Unwrapping {} pqQDUM → QDUM...", amount as f64 / 1_000_000.0));
+        let decimals = self.mint_decimals(pq_mint).await?;
+        log_msg(format!("🔄 Unwrapping {} pqQDUM → QDUM...", units::to_tokens(amount, decimals)));
         log_msg(format!("   User: {}", user_keypair.pubkey()));
 
+        self.check_unwrap(user_keypair.pubkey(), amount, standard_mint, pq_mint).await?;
+
         // Derive bridge PDA
         let (bridge_pda, bridge_bump) = Pubkey::find_program_address(
             &[b"bridge"],
@@ -2346,6 +3050,14 @@ impl VaultClient {
         log_msg(format!("   Bridge PDA: {}", bridge_pda));
         log_msg(format!("   Bridge Bump: {}", bridge_bump));
 
+        let mut receipt = Receipt::new(Operation::Unwrap);
+        receipt
+            .account("wallet", &user_keypair.pubkey())
+            .account("bridge", &bridge_pda)
+            .account("standard_mint", &standard_mint)
+            .account("pq_mint", &pq_mint);
+        receipt.amount = Some(amount);
+
         // Get user's token accounts
         let user_pq_account = get_associated_token_address(
             &user_keypair.pubkey(),
@@ -2364,7 +3076,7 @@ impl VaultClient {
         log_msg(format!("   User Standard account: {}", user_standard_account));
 
         // Check if standard account exists, create if needed
-        if self.rpc_client.get_account(&user_standard_account).is_err() {
+        if self.rpc_client.get_account(&user_standard_account).await.is_err() {
             log_msg(format!("   Creating Standard QDUM token account..."));
             let create_ata_ix = create_associated_token_account_instruction(
                 &user_keypair.pubkey(),
@@ -2372,20 +3084,13 @@ impl VaultClient {
                 &standard_mint,
                 &SPL_TOKEN_PROGRAM_ID,
             );
-            let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-            let create_tx = Transaction::new_signed_with_payer(
-                &[create_ata_ix],
-                Some(&user_keypair.pubkey()),
-                &[&user_keypair],
-                recent_blockhash,
-            );
-            self.rpc_client.send_and_confirm_transaction(&create_tx)?;
+            receipt.record(self.send_instructions(&[create_ata_ix], &user_keypair.pubkey(), &[&user_keypair]).await?);
             log_msg(format!("   ✓ Standard QDUM account created"));
         }
 
         // Build unwrap instruction
         let mut instruction_data = Vec::new();
-        instruction_data.extend_from_slice(&BRIDGE_UNWRAP_DISCRIMINATOR);
+        instruction_data.extend_from_slice(&idl::sighash("unwrap"));
         instruction_data.extend_from_slice(&amount.to_le_bytes());
 
         let accounts = vec![
@@ -2408,30 +3113,24 @@ impl VaultClient {
 
         // Send transaction
         log_msg(format!("\n📤 Sending unwrap transaction..."));
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-        let transaction = Transaction::new_signed_with_payer(
-            &[unwrap_ix],
-            Some(&user_keypair.pubkey()),
-            &[&user_keypair],
-            recent_blockhash,
-        );
+        let transaction = self.signed_transaction(&[unwrap_ix], &user_keypair.pubkey(), &[&user_keypair]).await?;
 
         log_msg(format!("   Transaction size: {} bytes", transaction.message_data().len()));
 
-        match self.rpc_client.send_and_confirm_transaction(&transaction) {
-            Ok(signature) => {
+        match self.send_with_retry(&transaction, &[&user_keypair]).await {
+            Ok((signature, fee)) => {
+                receipt.record((signature, fee));
                 log_msg(format!("✅ Unwrap complete!"));
                 log_msg(format!("   Transaction: {}", signature));
                 log_msg(format!("   Explorer: https://explorer.solana.com/tx/{}?cluster=devnet", signature));
-                Ok(signature.to_string())
+                Ok(receipt.finish())
             }
             Err(e) => {
-                log_msg(format!("\n❌ Unwrap transaction failed!"));
-                log_msg(format!("   Error: {}", e));
+                crate::applog::write(crate::applog::Level::Error, "bridge", format!("❌ Unwrap transaction failed: {}", e));
                 log_msg(format!("   Bridge PDA: {}", bridge_pda));
                 log_msg(format!("   Standard mint: {}", standard_mint));
                 log_msg(format!("   pqQDUM mint: {}", pq_mint));
-                Err(e.into())
+                Err(e)
             }
         }
     }
@@ -0,0 +1,106 @@
+//! Checks run before a wrap or unwrap is sent
+//!
+//! The bridge program reports a short balance, a locked vault or a mint it
+//! can't mint from as an opaque custom error. Reading the same state first
+//! turns those into errors that say what to do.
+
+use anyhow::{anyhow, Result};
+use solana_sdk::{program_option::COption, pubkey::Pubkey};
+use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::state::Mint;
+
+use super::{get_associated_token_address, VaultClient, BRIDGE_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID};
+use crate::exit_code::ExitKind;
+use crate::solana::accounts::PqAccount;
+use crate::solana::units;
+
+impl VaultClient {
+    /// The bridge PDA, which also signs as the mints' authority
+    pub(super) fn bridge_pda() -> Pubkey {
+        Pubkey::find_program_address(&[b"bridge"], &BRIDGE_PROGRAM_ID).0
+    }
+
+    /// Refuse a wrap of `amount` standard base units the bridge would reject
+    pub(super) async fn check_wrap(&self, wallet: Pubkey, amount: u64, standard_mint: Pubkey, pq_mint: Pubkey) -> Result<()> {
+        self.check_bridge_initialized().await?;
+        self.check_bridge_balance(wallet, standard_mint, amount, "wrap").await?;
+        if self.vault_locked(wallet).await? {
+            return Err(ExitKind::VaultLocked.error("Your vault is locked - unlock it before wrapping more pqQDUM into it"));
+        }
+        self.check_mint_authority(pq_mint, "pqQDUM").await
+    }
+
+    /// Refuse an unwrap of `amount` pqQDUM base units the bridge would reject
+    pub(super) async fn check_unwrap(&self, wallet: Pubkey, amount: u64, standard_mint: Pubkey, pq_mint: Pubkey) -> Result<()> {
+        self.check_bridge_initialized().await?;
+        self.check_bridge_balance(wallet, pq_mint, amount, "unwrap").await?;
+        if self.vault_locked(wallet).await? {
+            return Err(ExitKind::VaultLocked.error("Your vault is locked - locked pqQDUM can't be unwrapped until you unlock it"));
+        }
+
+        // The bridge mints standard QDUM if it holds the mint authority, and
+        // otherwise pays it out of its reserve
+        if self.mint_authority(standard_mint).await? == Some(Self::bridge_pda()) {
+            return Ok(());
+        }
+        let reserve_account = get_associated_token_address(&Self::bridge_pda(), &standard_mint, &SPL_TOKEN_PROGRAM_ID);
+        let reserve = self.get_balance(Self::bridge_pda(), standard_mint).await?;
+        if reserve < amount {
+            let decimals = self.mint_decimals(standard_mint).await?;
+            return Err(ExitKind::InsufficientFunds.error(format!(
+                "The bridge can't pay out {} QDUM: its reserve ({}) holds {} and it can't mint standard QDUM",
+                units::to_tokens(amount, decimals),
+                reserve_account,
+                units::to_tokens(reserve, decimals)
+            )));
+        }
+        Ok(())
+    }
+
+    async fn check_bridge_initialized(&self) -> Result<()> {
+        if self.rpc_client.get_account(&Self::bridge_pda()).await.is_err() {
+            anyhow::bail!("The bridge isn't initialized on this network ({} not found)", Self::bridge_pda());
+        }
+        Ok(())
+    }
+
+    async fn check_bridge_balance(&self, wallet: Pubkey, mint: Pubkey, amount: u64, verb: &str) -> Result<()> {
+        let balance = self.get_balance(wallet, mint).await?;
+        if balance < amount {
+            let decimals = self.mint_decimals(mint).await?;
+            return Err(ExitKind::InsufficientFunds.error(format!(
+                "Can't {} {} QDUM: the balance is {}",
+                verb,
+                units::to_tokens(amount, decimals),
+                units::to_tokens(balance, decimals)
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether `wallet`'s vault is locked; unregistered wallets aren't
+    async fn vault_locked(&self, wallet: Pubkey) -> Result<bool> {
+        let (pq_account, _) = self.derive_pq_account(wallet);
+        match self.rpc_client.get_account(&pq_account).await {
+            Ok(account) => Ok(PqAccount::parse(&account.data)?.tokens_locked),
+            Err(_) => Ok(false),
+        }
+    }
+
+    async fn check_mint_authority(&self, mint: Pubkey, name: &str) -> Result<()> {
+        match self.mint_authority(mint).await? {
+            Some(authority) if authority == Self::bridge_pda() => Ok(()),
+            Some(authority) => anyhow::bail!("The bridge can't mint {}: its mint authority is {}, not the bridge", name, authority),
+            None => anyhow::bail!("The bridge can't mint {}: the mint has no mint authority", name),
+        }
+    }
+
+    async fn mint_authority(&self, mint: Pubkey) -> Result<Option<Pubkey>> {
+        let account = self.rpc_client.get_account(&mint).await?;
+        let state = StateWithExtensions::<Mint>::unpack(&account.data).map_err(|e| anyhow!("Invalid mint {}: {}", mint, e))?;
+        Ok(match state.base.mint_authority {
+            COption::Some(authority) => Some(authority),
+            COption::None => None,
+        })
+    }
+}
@@ -0,0 +1,138 @@
+//! The unlock challenge, for signing it somewhere else
+//!
+//! `challenge` prints the exact 32 bytes an unlock signature has to cover, so
+//! an air-gapped machine can sign them. The PQ account doesn't record when
+//! the challenge was set; that comes from the newest `lock_tokens` or
+//! `initialize_pq_account` transaction on the account.
+
+use anyhow::{Context, Result};
+use base64::Engine as _;
+use serde::Serialize;
+use solana_client::{rpc_client::GetConfirmedSignaturesForAddress2Config, rpc_config::RpcTransactionConfig};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::UiTransactionEncoding;
+use std::str::FromStr;
+
+use super::history::program_instructions;
+use super::VaultClient;
+use crate::solana::accounts::PqAccount;
+
+/// Transactions on the PQ account searched for the one that set the challenge
+const CHALLENGE_SEARCH_LIMIT: usize = 100;
+
+/// Instructions that write a new unlock challenge
+const CHALLENGE_INSTRUCTIONS: [&str; 2] = ["lock_tokens", "initialize_pq_account"];
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UnlockChallenge {
+    pub wallet: String,
+    pub pq_account: String,
+    pub locked: bool,
+    pub challenge_hex: String,
+    pub challenge_base64: String,
+    /// Unix time of the transaction that set the challenge, if it is among
+    /// the account's recent transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_by: Option<String>,
+}
+
+impl UnlockChallenge {
+    fn new(wallet: Pubkey, pq_account: Pubkey, account: &PqAccount) -> Self {
+        UnlockChallenge {
+            wallet: wallet.to_string(),
+            pq_account: pq_account.to_string(),
+            locked: account.tokens_locked,
+            challenge_hex: hex::encode(account.unlock_challenge),
+            challenge_base64: base64::engine::general_purpose::STANDARD.encode(account.unlock_challenge),
+            set_at: None,
+            set_by: None,
+        }
+    }
+}
+
+impl VaultClient {
+    /// Current unlock challenge of `wallet`'s vault and when it was set
+    pub async fn unlock_challenge(&self, wallet: Pubkey) -> Result<UnlockChallenge> {
+        let (pq_account, _) = self.derive_pq_account(wallet);
+        let account_info = self
+            .rpc_client
+            .get_account(&pq_account)
+            .await
+            .context("PQ account not found - register the vault first")?;
+        let mut challenge = UnlockChallenge::new(wallet, pq_account, &PqAccount::parse(&account_info.data)?);
+
+        if let Some((signature, block_time)) = self.challenge_transaction(&pq_account).await? {
+            challenge.set_at = Some(block_time);
+            challenge.set_by = Some(signature.to_string());
+        }
+        Ok(challenge)
+    }
+
+    /// Newest successful transaction that wrote `pq_account`'s challenge
+    async fn challenge_transaction(&self, pq_account: &Pubkey) -> Result<Option<(Signature, i64)>> {
+        let discriminators: Vec<[u8; 8]> = CHALLENGE_INSTRUCTIONS.iter().map(|name| self.idl.discriminator(name)).collect();
+        let config = GetConfirmedSignaturesForAddress2Config {
+            limit: Some(CHALLENGE_SEARCH_LIMIT),
+            commitment: Some(self.rpc_client.commitment()),
+            ..Default::default()
+        };
+        let statuses = self
+            .rpc_client
+            .get_signatures_for_address_with_config(pq_account, config)
+            .await
+            .context("Failed to list PQ account transactions")?;
+
+        // Newest first; most of them are airdrop claims and unlock steps
+        for status in statuses {
+            let (None, Some(block_time)) = (&status.err, status.block_time) else {
+                continue;
+            };
+            let signature = Signature::from_str(&status.signature)?;
+            let config = RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                commitment: Some(self.rpc_client.commitment()),
+                max_supported_transaction_version: Some(0),
+            };
+            let transaction = self
+                .rpc_client
+                .get_transaction_with_config(&signature, config)
+                .await
+                .with_context(|| format!("Failed to fetch transaction {}", signature))?;
+
+            let sets_challenge = program_instructions(&transaction, &self.program_id).iter().any(|(accounts, data)| {
+                accounts.contains(pq_account) && discriminators.iter().any(|discriminator| data.starts_with(discriminator))
+            });
+            if sets_challenge {
+                return Ok(Some((signature, block_time)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_challenge_encodings() {
+        let account = PqAccount {
+            owner: Pubkey::new_unique(),
+            algorithm: 0,
+            public_key: Vec::new(),
+            tokens_locked: true,
+            unlock_challenge: [0xab; 32],
+            pending_unlock_slot: 0,
+            lock_mode: 0,
+            last_airdrop_claim: 0,
+        };
+        let challenge = UnlockChallenge::new(account.owner, Pubkey::new_unique(), &account);
+
+        assert_eq!(challenge.challenge_hex, "ab".repeat(32));
+        let decoded = base64::engine::general_purpose::STANDARD.decode(&challenge.challenge_base64).unwrap();
+        assert_eq!(decoded, vec![0xab; 32]);
+        assert!(challenge.locked);
+    }
+}
@@ -0,0 +1,40 @@
+use anyhow::{Context, Result};
+use std::time::{Duration, Instant};
+
+use super::VaultClient;
+
+/// Where the cluster is, and how long the RPC took to say so
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusterStatus {
+    pub slot: u64,
+    pub epoch: u64,
+    pub slot_index: u64,
+    pub slots_in_epoch: u64,
+    /// Round trip of the `getEpochInfo` call
+    pub latency: Duration,
+}
+
+impl ClusterStatus {
+    /// How far through the current epoch the cluster is, 0.0 to 1.0
+    pub fn epoch_progress(&self) -> f64 {
+        if self.slots_in_epoch == 0 {
+            return 0.0;
+        }
+        (self.slot_index as f64 / self.slots_in_epoch as f64).min(1.0)
+    }
+}
+
+impl VaultClient {
+    /// Current slot and epoch, timed
+    pub async fn cluster_status(&self) -> Result<ClusterStatus> {
+        let started = Instant::now();
+        let info = self.rpc_client.get_epoch_info().await.context("Failed to get epoch info")?;
+        Ok(ClusterStatus {
+            slot: info.absolute_slot,
+            epoch: info.epoch,
+            slot_index: info.slot_index,
+            slots_in_epoch: info.slots_in_epoch,
+            latency: started.elapsed(),
+        })
+    }
+}
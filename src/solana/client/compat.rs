@@ -0,0 +1,119 @@
+//! Deployed program compatibility
+//!
+//! Register and unlock span several transactions, and a step rejected halfway
+//! (say, after a program upgrade renamed an instruction) leaves the vault with
+//! half-written state. Before starting one, each instruction the flow needs is
+//! probed against the deployed program: Anchor rejects an unknown
+//! discriminator with `InstructionFallbackNotFound` before it looks at any
+//! accounts, so simulating the bare discriminator tells known instructions from
+//! unknown ones without touching state.
+
+use anyhow::Result;
+use colored::Colorize;
+use solana_client::rpc_config::RpcSimulateTransactionConfig;
+use solana_sdk::{
+    bpf_loader_upgradeable::UpgradeableLoaderState,
+    instruction::{Instruction, InstructionError},
+    message::Message,
+    pubkey::Pubkey,
+    transaction::{Transaction, TransactionError},
+};
+
+use super::VaultClient;
+use crate::crypto::algorithm::Algorithm;
+
+/// Anchor's error for a discriminator the program doesn't dispatch
+const INSTRUCTION_FALLBACK_NOT_FOUND: u32 = 101;
+
+/// Vault program instructions a register sends
+pub(super) fn register_instructions(hybrid: bool) -> Vec<&'static str> {
+    let mut names = vec!["initialize_pq_account", "write_data", "write_public_key"];
+    if hybrid {
+        names.push("enable_hybrid_unlock");
+    }
+    names
+}
+
+/// Vault program instructions an unlock with `algorithm` sends
+pub(super) fn unlock_instructions(algorithm: Algorithm) -> Vec<&'static str> {
+    let mut names = vec!["initialize_sphincs_storage", "upload_signature_chunk"];
+    match algorithm {
+        Algorithm::SlhDsa(_) => names.extend([
+            "sphincs_verify_step0_init",
+            "sphincs_verify_step1_fors_batch1",
+            "sphincs_verify_step2_fors_batch2",
+            "sphincs_verify_step3_fors_root",
+            "sphincs_verify_layer_wots_part1",
+            "sphincs_verify_layer_wots_part2",
+            "sphincs_verify_layer_wots_part3",
+            "sphincs_verify_layer_merkle",
+            "sphincs_verify_step11_finalize",
+        ]),
+        Algorithm::MlDsa(_) => names.push("verify_mldsa_and_unlock"),
+    }
+    names
+}
+
+impl VaultClient {
+    /// Abort before a multi-transaction flow if the deployed program doesn't
+    /// dispatch every instruction it needs. Probing problems (e.g. an RPC
+    /// without simulation) only warn, since the flow may still succeed.
+    pub(super) async fn check_program_compatibility(&self, payer: &Pubkey, instructions: &[&str]) -> Result<()> {
+        let unknown = match self.unknown_instructions(payer, instructions).await {
+            Ok(unknown) => unknown,
+            Err(e) => {
+                println!("{} {}", "⚠️  Could not verify the deployed program:".yellow(), e);
+                return Ok(());
+            }
+        };
+        if unknown.is_empty() {
+            return Ok(());
+        }
+
+        let deployed = match self.program_deployment_slot().await {
+            Some(slot) => format!(" (last deployed at slot {})", slot),
+            None => String::new(),
+        };
+        anyhow::bail!(
+            "The deployed vault program {}{} does not support: {}.\n\
+             This CLI expects program version {}; nothing was sent. \
+             Update qdum-vault, or pass the program's current IDL with --idl",
+            self.program_id,
+            deployed,
+            unknown.join(", "),
+            self.idl.version()
+        )
+    }
+
+    async fn unknown_instructions<'a>(&self, payer: &Pubkey, instructions: &[&'a str]) -> Result<Vec<&'a str>> {
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            ..RpcSimulateTransactionConfig::default()
+        };
+
+        let mut unknown = Vec::new();
+        for &name in instructions {
+            let probe = Instruction::new_with_bytes(self.program_id, &self.idl.discriminator(name), vec![]);
+            let transaction = Transaction::new_unsigned(Message::new(&[probe], Some(payer)));
+            let result = self.rpc_client.simulate_transaction_with_config(&transaction, config.clone()).await?.value;
+            if let Some(TransactionError::InstructionError(_, InstructionError::Custom(INSTRUCTION_FALLBACK_NOT_FOUND))) = result.err {
+                unknown.push(name);
+            }
+        }
+        Ok(unknown)
+    }
+
+    /// Slot the program was last deployed or upgraded at
+    async fn program_deployment_slot(&self) -> Option<u64> {
+        let program = self.rpc_client.get_account(&self.program_id).await.ok()?;
+        let UpgradeableLoaderState::Program { programdata_address } = bincode::deserialize(&program.data).ok()? else {
+            return None;
+        };
+        let programdata = self.rpc_client.get_account(&programdata_address).await.ok()?;
+        match bincode::deserialize(&programdata.data).ok()? {
+            UpgradeableLoaderState::ProgramData { slot, .. } => Some(slot),
+            _ => None,
+        }
+    }
+}
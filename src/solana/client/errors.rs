@@ -0,0 +1,207 @@
+//! Friendly explanations for failed transactions
+//!
+//! Programs fail with bare codes like `custom program error: 0x1771`. These
+//! are mapped to what went wrong and what to do next: vault program codes are
+//! named by its IDL, the bridge and token programs by the tables below, and
+//! Anchor's own framework errors by their fixed numbers.
+
+use solana_client::client_error::{ClientError, ClientErrorKind};
+use solana_client::rpc_request::{RpcError, RpcResponseErrorData};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    transaction::{Transaction, TransactionError},
+};
+use std::fmt;
+use std::str::FromStr;
+
+use super::{VaultClient, BRIDGE_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID};
+use crate::output;
+
+/// Anchor numbers a program's own `#[error_code]` variants from here
+const ANCHOR_USER_ERROR_START: u32 = 6000;
+
+/// A decoded program error
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgramFailure {
+    pub program: &'static str,
+    pub code: u32,
+    pub name: String,
+    pub message: String,
+    /// Suggested next step
+    pub hint: Option<&'static str>,
+}
+
+impl fmt::Display for ProgramFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} error {}, code {:#x})", self.message, self.program, self.name, self.code)?;
+        if let Some(hint) = self.hint {
+            write!(f, "\n  → {}", hint)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ProgramFailure {}
+
+/// Next step for a vault program error, by `#[error_code]` variant name
+fn vault_hint(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "Unauthorized" => "Use the keypair of the wallet that registered this vault (--keypair)",
+        "AlreadyLocked" => "Nothing to do; check with `qdum-vault status`",
+        "NotLocked" => "Lock the vault first with `qdum-vault lock`",
+        "VaultLocked" => "The vault is locked; run `qdum-vault unlock` first",
+        "PublicKeyNotSet" => "Register your post-quantum key with `qdum-vault register`",
+        "InvalidAlgorithm" => "Generate a key with a supported --algorithm using `qdum-vault init`",
+        "InvalidSignature" | "ChallengeMismatch" => {
+            "Sign with the key registered on-chain (`qdum-vault status` shows it); after a key rotation use the new key"
+        }
+        "InvalidChunk" | "VerificationIncomplete" => "Run `qdum-vault unlock` again; the signature is re-uploaded from the start",
+        "UnlockPending" => "Wait for the unlock delay; `qdum-vault status` shows the unlock slot",
+        "HybridProofMissing" => "This vault needs the wallet's signature too; unlock with the registering wallet's keypair",
+        "AirdropAlreadyClaimed" => "Each wallet can claim the airdrop once",
+        "InheritanceNotExpired" => "The owner's inactivity timeout hasn't passed; see `qdum-vault inheritance status`",
+        "NotBeneficiary" => "Claim with the beneficiary wallet's keypair",
+        _ => return None,
+    })
+}
+
+/// Bridge program errors: (code, name, message, next step)
+const BRIDGE_ERRORS: &[(u32, &str, &str, Option<&str>)] = &[
+    (6000, "InsufficientBalance", "Not enough tokens to wrap or unwrap", Some("Check your balances with `qdum-vault balance`")),
+    (6001, "InvalidAmount", "Amount must be greater than zero", None),
+    (6002, "InvalidMint", "Mint does not match the bridge", Some("Use the default --standard-mint and --pq-mint for this network")),
+    (6003, "VaultLocked", "pqQDUM can't be unwrapped while the vault is locked", Some("Run `qdum-vault unlock` first")),
+    (6004, "BridgePaused", "The bridge is paused", None),
+];
+
+/// SPL Token and Token-2022 errors worth explaining: (code, name, message, next step)
+const TOKEN_ERRORS: &[(u32, &str, &str, Option<&str>)] = &[
+    (1, "InsufficientFunds", "Token balance is too low", Some("Check your balances with `qdum-vault balance`")),
+    (3, "MintMismatch", "Token account belongs to a different mint", Some("Check the --mint address")),
+    (4, "OwnerMismatch", "Token account is owned by a different wallet", Some("Check --keypair")),
+    (17, "AccountFrozen", "Token account is frozen", None),
+];
+
+/// Anchor framework errors: (code, name, message, next step)
+const ANCHOR_ERRORS: &[(u32, &str, &str, Option<&str>)] = &[
+    (101, "InstructionFallbackNotFound", "The program doesn't recognize this instruction", Some("The program may have been upgraded; pass its current IDL with --idl")),
+    (102, "InstructionDidNotDeserialize", "The program couldn't decode the instruction arguments", Some("The program may have been upgraded; pass its current IDL with --idl")),
+    (2001, "ConstraintHasOne", "An account doesn't belong to this wallet", Some("Check that --keypair is the vault's wallet")),
+    (2006, "ConstraintSeeds", "An account address doesn't match the expected PDA", Some("Check --program-id")),
+    (3002, "AccountDiscriminatorMismatch", "An account is not of the expected type", Some("Check --program-id and --rpc-url point at the same network")),
+    (3007, "AccountOwnedByWrongProgram", "An account is owned by a different program", Some("Check --program-id and --rpc-url point at the same network")),
+    (3012, "AccountNotInitialized", "A required account doesn't exist yet", Some("Register first with `qdum-vault register`")),
+];
+
+fn from_table(program: &'static str, table: &[(u32, &str, &str, Option<&'static str>)], code: u32) -> Option<ProgramFailure> {
+    let &(code, name, message, hint) = table.iter().find(|(c, ..)| *c == code)?;
+    Some(ProgramFailure { program, code, name: name.to_string(), message: message.to_string(), hint })
+}
+
+/// Program that raised `code`, from a log line like
+/// `Program <id> failed: custom program error: 0x1771`
+///
+/// A CPI (e.g. the transfer hook) fails inside the outer instruction, and
+/// every caller logs the same failure on the way out; the first line names the
+/// program that actually raised it.
+fn failing_program(logs: &[String], code: u32) -> Option<Pubkey> {
+    let suffix = format!("failed: custom program error: {:#x}", code);
+    logs.iter().find_map(|log| {
+        let id = log.strip_prefix("Program ")?.strip_suffix(&suffix)?;
+        Pubkey::from_str(id.trim()).ok()
+    })
+}
+
+/// Variant name from Anchor's `AnchorError ... Error Code: VaultLocked. Error Number: ...` log
+fn anchor_error_name(logs: &[String]) -> Option<String> {
+    logs.iter().rev().find_map(|log| {
+        let rest = &log[log.find("Error Code: ")? + "Error Code: ".len()..];
+        Some(rest.split('.').next()?.trim().to_string())
+    })
+}
+
+impl VaultClient {
+    /// Attach an explanation to a failed send when it was a known program error.
+    /// The original error stays as the cause, so retry checks still see it.
+    pub(super) fn explain_failure(&self, error: ClientError, transaction: &Transaction) -> anyhow::Error {
+        let logs = match error.kind() {
+            ClientErrorKind::RpcError(RpcError::RpcResponseError {
+                data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+                ..
+            }) => result.logs.clone().unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        output::program_logs("Preflight logs:", &logs);
+        let failure = error
+            .get_transaction_error()
+            .and_then(|tx_error| self.explain_transaction_error(&tx_error, &logs, transaction));
+
+        match failure {
+            Some(failure) => anyhow::Error::new(error).context(failure),
+            None => error.into(),
+        }
+    }
+
+    /// Decode a custom program error raised while executing `transaction`
+    pub(super) fn explain_transaction_error(
+        &self,
+        error: &TransactionError,
+        logs: &[String],
+        transaction: &Transaction,
+    ) -> Option<ProgramFailure> {
+        let TransactionError::InstructionError(index, InstructionError::Custom(code)) = error else {
+            return None;
+        };
+        let code = *code;
+        let program = failing_program(logs, code)
+            .or_else(|| transaction.message.program_id(*index as usize).copied())?;
+
+        if program == self.program_id {
+            if code < ANCHOR_USER_ERROR_START {
+                return from_table("vault", ANCHOR_ERRORS, code);
+            }
+            let idl_error = self.idl.error(code);
+            let name = anchor_error_name(logs).or_else(|| idl_error.map(|e| e.name.clone()))?;
+            return Some(ProgramFailure {
+                program: "vault",
+                code,
+                message: idl_error.and_then(|e| e.msg.clone()).unwrap_or_else(|| name.clone()),
+                hint: vault_hint(&name),
+                name,
+            });
+        }
+        if program == BRIDGE_PROGRAM_ID {
+            return from_table("bridge", if code < ANCHOR_USER_ERROR_START { ANCHOR_ERRORS } else { BRIDGE_ERRORS }, code);
+        }
+        if program == SPL_TOKEN_PROGRAM_ID || program == TOKEN_2022_PROGRAM_ID {
+            return from_table("token", TOKEN_ERRORS, code);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failing_program_from_cpi_logs() {
+        let vault = Pubkey::new_unique();
+        let logs = vec![
+            format!("Program {} invoke [1]", TOKEN_2022_PROGRAM_ID),
+            format!("Program {} invoke [2]", vault),
+            "Program log: AnchorError occurred. Error Code: VaultLocked. Error Number: 6003. Error Message: Tokens are locked in the vault.".to_string(),
+            format!("Program {} failed: custom program error: 0x1773", vault),
+            format!("Program {} failed: custom program error: 0x1773", TOKEN_2022_PROGRAM_ID),
+        ];
+        assert_eq!(failing_program(&logs, 0x1773), Some(vault));
+        assert_eq!(anchor_error_name(&logs).as_deref(), Some("VaultLocked"));
+    }
+
+    #[test]
+    fn test_unknown_codes_are_not_explained() {
+        assert!(from_table("bridge", BRIDGE_ERRORS, 6999).is_none());
+        assert_eq!(from_table("token", TOKEN_ERRORS, 1).unwrap().name, "InsufficientFunds");
+    }
+}
@@ -0,0 +1,309 @@
+use anyhow::{Context, Result};
+use colored::Colorize;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+use std::time::Duration;
+
+use super::{
+    format_slots, storage_identifier, unlock_transaction_plan, LockMode, UnlockOptions, VaultClient,
+    MLDSA_VERIFY_COMPUTE_UNITS,
+};
+use crate::crypto::algorithm::{Algorithm, PqKey};
+use crate::solana::accounts::{PqAccount, SignatureStorage, VerificationState};
+use crate::solana::receipt::Operation;
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+/// Base fee charged per transaction signature
+const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// Compute budget the runtime grants a transaction that doesn't request one
+const DEFAULT_COMPUTE_UNIT_LIMIT: u64 = 200_000;
+
+/// Typical send-and-confirm round trip at `confirmed` commitment
+const CONFIRMATION_TIME: Duration = Duration::from_millis(1_500);
+
+/// Expected cost of an operation, computed without sending anything
+#[derive(Debug, Clone)]
+pub struct CostEstimate {
+    pub operation: Operation,
+    pub transactions: usize,
+    pub compute_units: u64,
+    pub signature_fee_lamports: u64,
+    pub priority_fee_lamports: u64,
+    pub rent_lamports: u64,
+    /// Rent returned to the wallet (e.g. when closing an account)
+    pub refund_lamports: u64,
+    pub duration: Duration,
+    /// Caveats and assumptions worth showing next to the numbers
+    pub notes: Vec<String>,
+}
+
+impl CostEstimate {
+    fn empty(operation: Operation) -> Self {
+        Self {
+            operation,
+            transactions: 0,
+            compute_units: 0,
+            signature_fee_lamports: 0,
+            priority_fee_lamports: 0,
+            rent_lamports: 0,
+            refund_lamports: 0,
+            duration: Duration::ZERO,
+            notes: Vec::new(),
+        }
+    }
+
+    /// Net cost; negative when refunds outweigh fees
+    pub fn total_lamports(&self) -> i64 {
+        (self.signature_fee_lamports + self.priority_fee_lamports + self.rent_lamports) as i64
+            - self.refund_lamports as i64
+    }
+
+    /// Print the estimate, with USD amounts if a SOL price is given
+    pub fn print(&self, sol_price_usd: Option<f64>) {
+        use comfy_table::{presets::UTF8_FULL, Table};
+
+        let sol = |lamports: i64| {
+            let sol = lamports as f64 / LAMPORTS_PER_SOL;
+            match sol_price_usd {
+                Some(price) => format!("{:.6} SOL (${:.4})", sol, sol * price),
+                None => format!("{:.6} SOL", sol),
+            }
+        };
+
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec![
+            "Estimate".bright_white().bold().to_string(),
+            self.operation.label().bright_white().bold().to_string(),
+        ]);
+        table.add_row(vec!["Transactions".dimmed().to_string(), self.transactions.to_string()]);
+        table.add_row(vec!["Compute Units".dimmed().to_string(), self.compute_units.to_string()]);
+        table.add_row(vec!["Signature Fees".dimmed().to_string(), sol(self.signature_fee_lamports as i64)]);
+        table.add_row(vec!["Priority Fees".dimmed().to_string(), sol(self.priority_fee_lamports as i64)]);
+        table.add_row(vec!["Rent".dimmed().to_string(), sol(self.rent_lamports as i64)]);
+        if self.refund_lamports > 0 {
+            table.add_row(vec!["Rent Refund".dimmed().to_string(), sol(-(self.refund_lamports as i64)).green().to_string()]);
+        }
+        table.add_row(vec!["Total".bright_white().bold().to_string(), sol(self.total_lamports()).bright_yellow().bold().to_string()]);
+        table.add_row(vec!["Expected Time".dimmed().to_string(), format!("~{}s", self.duration.as_secs().max(1))]);
+
+        println!("{}", table);
+        for note in &self.notes {
+            println!("  {} {}", "•".dimmed(), note.dimmed());
+        }
+        println!();
+    }
+}
+
+impl VaultClient {
+    /// Plan an unlock without sending anything
+    ///
+    /// The first transaction (signature storage init) is simulated against the
+    /// cluster; later steps depend on its state, so their compute is budgeted.
+    pub async fn estimate_unlock(
+        &self,
+        wallet: Pubkey,
+        keypair_path: &str,
+        sphincs_pubkey: &PqKey,
+        options: UnlockOptions,
+    ) -> Result<CostEstimate> {
+        let keypair = self.load_keypair(keypair_path)?;
+        let (pq_account, _) = self.derive_pq_account(wallet);
+        let algorithm = sphincs_pubkey.algorithm;
+        let mut notes = Vec::new();
+
+        let account_info = self.rpc_client.get_account(&pq_account).await
+            .context("PQ account not found! Register first with: qdum-vault register")?;
+        let account = PqAccount::parse(&account_info.data)?;
+        if account.algorithm() != Some(algorithm) {
+            notes.push(format!("On-chain account is not registered for {}; the unlock would fail", algorithm));
+        }
+        if !account.tokens_locked {
+            notes.push("Vault is currently unlocked; unlock would do nothing".to_string());
+        }
+        let challenge = &account.unlock_challenge[..];
+
+        let (upload_txs, verify_txs) = unlock_transaction_plan(algorithm);
+        let transactions = upload_txs + verify_txs;
+
+        // Simulate the first transaction for a real fee and compute reading
+        let identifier = storage_identifier(&sphincs_pubkey.bytes);
+        let (signature_storage, _) = Pubkey::find_program_address(
+            &[b"sphincs_sig", keypair.pubkey().as_ref(), identifier.as_bytes()],
+            &self.program_id,
+        );
+        let init_ix = self.initialize_sphincs_storage_ix(&keypair.pubkey(), &signature_storage, &identifier, &sphincs_pubkey.bytes, challenge);
+        // Simulation skips signature checks, so this works in read-only mode too
+        let init_tx = self.unsigned_transaction(&[init_ix], &keypair.pubkey()).await?;
+
+        let per_tx_limit = self.compute_unit_limit();
+        let init_units = match self.rpc_client.simulate_transaction(&init_tx).await {
+            Ok(response) => {
+                if let Some(err) = response.value.err {
+                    notes.push(format!("Simulating the first transaction failed: {}", err));
+                }
+                response.value.units_consumed.unwrap_or(per_tx_limit)
+            }
+            Err(e) => {
+                notes.push(format!("Simulation unavailable ({}); compute is budgeted", e));
+                per_tx_limit
+            }
+        };
+
+        let verify_limit = unlock_verify_limit(algorithm, verify_txs, per_tx_limit);
+        let compute_units = init_units + (upload_txs as u64 - 1) * per_tx_limit + verify_limit;
+        let priority_fee_lamports = self.priority_fee(upload_txs as u64 * per_tx_limit + verify_limit);
+        notes.push(self.priority_fee_note());
+
+        // Temporary PDAs are reused across unlocks, so rent is only paid the first time
+        let mut rent_lamports = 0;
+        match self.rpc_client.get_account(&signature_storage).await {
+            Ok(existing) => notes.push(match SignatureStorage::parse(&existing.data) {
+                Ok(storage) => format!(
+                    "Reuses existing storage account {} ({} of {} signature bytes left from a previous unlock)",
+                    signature_storage,
+                    storage.bytes_written,
+                    storage.signature.len()
+                ),
+                Err(_) => format!("Reuses existing storage account {}", signature_storage),
+            }),
+            Err(_) => {
+                rent_lamports += self.rpc_client.get_minimum_balance_for_rent_exemption(SignatureStorage::space(algorithm)).await?;
+            }
+        }
+        if let Algorithm::SlhDsa(params) = algorithm {
+            let (verification_state, _) = Pubkey::find_program_address(
+                &[b"sphincs_verify", keypair.pubkey().as_ref(), identifier.as_bytes()],
+                &self.program_id,
+            );
+            match self.rpc_client.get_account(&verification_state).await {
+                Ok(existing) => notes.push(match VerificationState::parse(&existing.data) {
+                    Ok(state) if !state.verified => format!(
+                        "Reuses existing verification account {} (a previous unlock stopped after {} steps)",
+                        verification_state, state.steps_completed
+                    ),
+                    _ => format!("Reuses existing verification account {}", verification_state),
+                }),
+                Err(_) => {
+                    rent_lamports += self.rpc_client.get_minimum_balance_for_rent_exemption(VerificationState::space(params)).await?;
+                }
+            }
+        }
+
+        if options.lock_mode == LockMode::Hybrid {
+            notes.push("Hybrid vault: the final transaction also carries an Ed25519 wallet proof".to_string());
+        }
+        if options.delay_slots > 0 {
+            notes.push(format!("Tokens unlock {} after verification", format_slots(options.delay_slots)));
+        }
+
+        Ok(CostEstimate {
+            operation: Operation::Unlock,
+            transactions,
+            compute_units,
+            signature_fee_lamports: transactions as u64 * LAMPORTS_PER_SIGNATURE,
+            priority_fee_lamports,
+            rent_lamports,
+            refund_lamports: 0,
+            duration: CONFIRMATION_TIME * transactions as u32,
+            notes,
+        })
+    }
+
+    /// Generic cost of an operation at current cluster rent, independent of any vault's state
+    ///
+    /// Supports register, lock, unlock, close and transfer.
+    pub async fn estimate_operation(&self, operation: Operation, algorithm: Algorithm) -> Result<CostEstimate> {
+        let rpc_client = &self.rpc_client;
+        let rent = move |size: usize| rpc_client.get_minimum_balance_for_rent_exemption(size);
+        let per_tx_limit = self.compute_unit_limit();
+
+        // (transactions, signatures, compute units, rent and notes)
+        let (transactions, signatures, compute_units, mut estimate) = match operation {
+            Operation::Register => {
+                let pq_account_rent = rent(PqAccount::space(algorithm)).await?;
+                let staging_rent = rent(algorithm.public_key_size()).await?;
+                (2, 3, 2 * per_tx_limit, CostEstimate {
+                    rent_lamports: pq_account_rent + staging_rent,
+                    notes: vec![
+                        format!("PQ account rent {:.6} SOL is refunded by `close`", pq_account_rent as f64 / LAMPORTS_PER_SOL),
+                        "Hybrid registration (--hybrid) adds one transaction".to_string(),
+                    ],
+                    ..CostEstimate::empty(operation)
+                })
+            }
+            Operation::Lock | Operation::Close => {
+                let refund_lamports = if operation == Operation::Close { rent(PqAccount::space(algorithm)).await? } else { 0 };
+                (1, 1, per_tx_limit, CostEstimate {
+                    refund_lamports,
+                    ..CostEstimate::empty(operation)
+                })
+            }
+            Operation::Unlock => {
+                let (upload_txs, verify_txs) = unlock_transaction_plan(algorithm);
+                let mut rent_lamports = rent(SignatureStorage::space(algorithm)).await?;
+                if let Algorithm::SlhDsa(params) = algorithm {
+                    rent_lamports += rent(VerificationState::space(params)).await?;
+                }
+                let transactions = upload_txs + verify_txs;
+                let compute_units = upload_txs as u64 * per_tx_limit + unlock_verify_limit(algorithm, verify_txs, per_tx_limit);
+                (transactions, transactions, compute_units, CostEstimate {
+                    rent_lamports,
+                    notes: vec![
+                        "Storage rent is only paid on the first unlock; later unlocks reuse the accounts".to_string(),
+                        "Run `unlock --dry-run` to simulate against your own vault".to_string(),
+                    ],
+                    ..CostEstimate::empty(operation)
+                })
+            }
+            Operation::Transfer => {
+                use spl_token_2022::extension::ExtensionType;
+
+                let token_account_size = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(
+                    &[ExtensionType::TransferHookAccount],
+                )?;
+                (1, 1, per_tx_limit, CostEstimate {
+                    notes: vec![format!(
+                        "Creating the recipient's token account (if missing) adds {:.6} SOL rent",
+                        rent(token_account_size).await? as f64 / LAMPORTS_PER_SOL
+                    )],
+                    ..CostEstimate::empty(operation)
+                })
+            }
+            other => anyhow::bail!("No estimate available for {}", other.label()),
+        };
+
+        estimate.transactions = transactions;
+        estimate.compute_units = compute_units;
+        estimate.signature_fee_lamports = signatures as u64 * LAMPORTS_PER_SIGNATURE;
+        estimate.priority_fee_lamports = self.priority_fee(compute_units);
+        estimate.duration = CONFIRMATION_TIME * transactions as u32;
+        estimate.notes.push(self.priority_fee_note());
+
+        Ok(estimate)
+    }
+
+    /// Compute unit limit each transaction requests
+    fn compute_unit_limit(&self) -> u64 {
+        self.compute_budget.unit_limit.map_or(DEFAULT_COMPUTE_UNIT_LIMIT, u64::from)
+    }
+
+    /// Priority fee paid for requesting `units` at the configured price
+    fn priority_fee(&self, units: u64) -> u64 {
+        units * self.compute_budget.unit_price / 1_000_000
+    }
+
+    fn priority_fee_note(&self) -> String {
+        format!("Priority fees assume {} µlamports per requested compute unit", self.compute_budget.unit_price)
+    }
+}
+
+/// Compute requested by the verification transactions
+fn unlock_verify_limit(algorithm: Algorithm, verify_txs: usize, per_tx_limit: u64) -> u64 {
+    match algorithm {
+        Algorithm::SlhDsa(_) => verify_txs as u64 * per_tx_limit,
+        // ML-DSA verification keeps its own (larger) limit
+        Algorithm::MlDsa(_) => MLDSA_VERIFY_COMPUTE_UNITS as u64,
+    }
+}
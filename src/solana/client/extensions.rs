@@ -0,0 +1,109 @@
+//! Token-2022 mint extensions that change what a transfer does
+//!
+//! A mint with the transfer-fee extension withholds part of every transfer
+//! from the recipient's account, so the amount sent isn't the amount received.
+//! Transfer summaries show the fee up front rather than leaving it to be
+//! noticed in the recipient's balance.
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use spl_token_2022::extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::Mint;
+
+use super::{VaultClient, TOKEN_2022_PROGRAM_ID};
+
+/// Token-2022's fee denominator: basis points of the amount
+const MAX_FEE_BASIS_POINTS: u128 = 10_000;
+
+/// Fee a mint charges on transfers in the current epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct TransferFee {
+    pub basis_points: u16,
+    /// Cap per transfer, in base units
+    pub maximum: u64,
+}
+
+impl TransferFee {
+    /// Fee withheld from a transfer of `amount` base units, rounded up and
+    /// capped the way Token-2022 calculates it
+    pub fn fee(&self, amount: u64) -> u64 {
+        if self.basis_points == 0 || amount == 0 {
+            return 0;
+        }
+        let fee = (amount as u128 * self.basis_points as u128).div_ceil(MAX_FEE_BASIS_POINTS);
+        u64::try_from(fee).unwrap_or(u64::MAX).min(self.maximum)
+    }
+
+    /// Percentage for display, e.g. "0.5%"
+    pub fn rate(&self) -> String {
+        format!("{}%", self.basis_points as f64 / 100.0)
+    }
+}
+
+/// What a mint's extensions mean for a transfer. SPL Token mints have none.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct MintExtensions {
+    /// Every extension on the mint, e.g. "TransferHook"
+    pub names: Vec<String>,
+    pub transfer_fee: Option<TransferFee>,
+}
+
+impl MintExtensions {
+    /// Read a Token-2022 mint's extensions, with the fee that applies in `epoch`
+    pub fn parse(data: &[u8], epoch: u64) -> Result<Self> {
+        let state = StateWithExtensions::<Mint>::unpack(data).map_err(|e| anyhow!("Invalid Token-2022 mint: {}", e))?;
+        let names = state
+            .get_extension_types()
+            .map_err(|e| anyhow!("Invalid Token-2022 extensions: {}", e))?
+            .iter()
+            .map(|extension| format!("{:?}", extension))
+            .collect();
+        let transfer_fee = state.get_extension::<TransferFeeConfig>().ok().map(|config| {
+            let fee = config.get_epoch_fee(epoch);
+            TransferFee { basis_points: u16::from(fee.transfer_fee_basis_points), maximum: u64::from(fee.maximum_fee) }
+        });
+        Ok(Self { names, transfer_fee })
+    }
+
+    /// Fee withheld from a transfer of `amount` base units
+    pub fn fee(&self, amount: u64) -> u64 {
+        self.transfer_fee.map_or(0, |fee| fee.fee(amount))
+    }
+
+    /// What the recipient is credited for a transfer of `amount` base units
+    pub fn net_amount(&self, amount: u64) -> u64 {
+        amount - self.fee(amount)
+    }
+}
+
+impl VaultClient {
+    /// Extensions on `mint` that affect transfers
+    pub async fn mint_extensions(&self, mint: Pubkey) -> Result<MintExtensions> {
+        let account = self.get_account_cached(&mint).await?;
+        if account.owner != TOKEN_2022_PROGRAM_ID {
+            return Ok(MintExtensions::default());
+        }
+        let epoch = self.rpc_client.get_epoch_info().await?.epoch;
+        MintExtensions::parse(&account.data, epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_fee() {
+        let fee = TransferFee { basis_points: 50, maximum: 5_000 };
+        assert_eq!(fee.fee(0), 0);
+        assert_eq!(fee.fee(1), 1); // rounded up
+        assert_eq!(fee.fee(200_000), 1_000);
+        assert_eq!(fee.fee(10_000_000), 5_000); // capped
+        assert_eq!(fee.rate(), "0.5%");
+
+        let extensions = MintExtensions { names: vec!["TransferFeeConfig".to_string()], transfer_fee: Some(fee) };
+        assert_eq!(extensions.net_amount(200_000), 199_000);
+        assert_eq!(MintExtensions::default().net_amount(200_000), 200_000);
+    }
+}
@@ -0,0 +1,317 @@
+//! Network lock history reconstructed from past program transactions
+//!
+//! The lock chart only has snapshots from when the dashboard was running.
+//! [`VaultClient::lock_history`] fills in the time before that: it takes the
+//! set of currently locked vaults and walks the program's transactions
+//! backwards (`getSignaturesForAddress`), undoing each `lock_tokens` and
+//! unlock finalization it finds.
+//!
+//! Who was locked at each point is exact for the walked range. Amounts are
+//! not: they use each holder's current balance, since locking doesn't touch
+//! the token account and so leaves no balance in the transaction.
+
+use anyhow::{Context, Result};
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
+use solana_client::{
+    rpc_client::GetConfirmedSignaturesForAddress2Config,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcTransactionConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::state::Account;
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, UiInstruction, UiLoadedAddresses, UiTransactionEncoding,
+};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use super::{get_associated_token_address, VaultClient, TOKEN_2022_PROGRAM_ID};
+use crate::solana::accounts::PqAccount;
+use crate::solana::units;
+
+/// `getSignaturesForAddress` page size (the RPC maximum)
+const SIGNATURE_PAGE: usize = 1_000;
+
+/// Transactions fetched at once; public RPCs rate-limit harder than this
+const FETCH_CONCURRENCY: usize = 8;
+
+/// Snapshots are hourly, like the ones the dashboard records
+const BUCKET_SECS: i64 = 60 * 60;
+
+/// Network-wide lock totals at a point in the past
+#[derive(Debug, Clone, PartialEq)]
+pub struct LockSnapshot {
+    /// Unix seconds
+    pub timestamp: i64,
+    pub locked_amount: f64,
+    pub holder_count: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LockEvent {
+    Lock(Pubkey),
+    Unlock(Pubkey),
+}
+
+/// Instruction discriminators that change a vault's lock state
+#[derive(Clone, Copy)]
+struct LockInstructions {
+    program_id: Pubkey,
+    lock: [u8; 8],
+    unlock: [[u8; 8]; 2],
+}
+
+impl VaultClient {
+    /// Hourly lock totals for `mint`, oldest first, from the program's last
+    /// `max_signatures` transactions
+    pub async fn lock_history(&self, mint: Pubkey, max_signatures: usize) -> Result<Vec<LockSnapshot>> {
+        let mut locked = self.locked_owners().await?;
+
+        let instructions = LockInstructions {
+            program_id: self.program_id,
+            lock: self.idl.discriminator("lock_tokens"),
+            unlock: [
+                self.idl.discriminator("sphincs_verify_step11_finalize"),
+                self.idl.discriminator("verify_mldsa_and_unlock"),
+            ],
+        };
+
+        // Newest first, as the RPC returns them
+        let mut events: Vec<(i64, LockEvent)> = Vec::new();
+        let signatures = self.program_signatures(max_signatures).await?;
+        for chunk in signatures.chunks(FETCH_CONCURRENCY) {
+            let fetches: Vec<_> = chunk
+                .iter()
+                .map(|&(signature, block_time)| {
+                    let rpc_client = self.rpc_client.clone();
+                    tokio::spawn(async move {
+                        let config = RpcTransactionConfig {
+                            encoding: Some(UiTransactionEncoding::Base64),
+                            commitment: Some(rpc_client.commitment()),
+                            max_supported_transaction_version: Some(0),
+                        };
+                        let transaction = rpc_client
+                            .get_transaction_with_config(&signature, config)
+                            .await
+                            .with_context(|| format!("Failed to fetch transaction {}", signature))?;
+                        Ok::<_, anyhow::Error>((block_time, lock_events(&transaction, &instructions)))
+                    })
+                })
+                .collect();
+            for fetch in fetches {
+                let (block_time, found) = fetch.await??;
+                events.extend(found.into_iter().rev().map(|event| (block_time, event)));
+            }
+        }
+
+        let mut owners: HashSet<Pubkey> = locked.clone();
+        owners.extend(events.iter().map(|(_, event)| match event {
+            LockEvent::Lock(owner) | LockEvent::Unlock(owner) => *owner,
+        }));
+        let balances = self.token_balances(owners.into_iter().collect(), mint).await?;
+        let decimals = self.mint_decimals(mint).await?;
+
+        Ok(replay(&mut locked, &events, &balances, decimals))
+    }
+
+    /// Owners of every currently locked PQ account
+    async fn locked_owners(&self) -> Result<HashSet<Pubkey>> {
+        // Same filter as get_network_locked_total: SPHINCS+ keys, lock flag set
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                PqAccount::tokens_locked_offset(32),
+                vec![1],
+            ))]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: Some(UiDataSliceConfig {
+                    offset: 0,
+                    length: PqAccount::OWNER_RANGE.end,
+                }),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+        let accounts = self.rpc_client.get_program_accounts_with_config(&self.program_id, config).await?;
+        Ok(accounts
+            .iter()
+            .filter_map(|(_, account)| PqAccount::parse_owner(&account.data))
+            .collect())
+    }
+
+    /// Successful program transactions with their block times, newest first
+    async fn program_signatures(&self, max_signatures: usize) -> Result<Vec<(Signature, i64)>> {
+        let mut signatures = Vec::new();
+        let mut walked = 0;
+        let mut before = None;
+        while walked < max_signatures {
+            let config = GetConfirmedSignaturesForAddress2Config {
+                before,
+                until: None,
+                limit: Some(SIGNATURE_PAGE.min(max_signatures - walked)),
+                commitment: Some(self.rpc_client.commitment()),
+            };
+            let page = self
+                .rpc_client
+                .get_signatures_for_address_with_config(&self.program_id, config)
+                .await
+                .context("Failed to list program transactions")?;
+            let Some(last) = page.last() else {
+                break;
+            };
+            before = Some(Signature::from_str(&last.signature)?);
+            walked += page.len();
+            for status in &page {
+                if let (None, Some(block_time)) = (&status.err, status.block_time) {
+                    signatures.push((Signature::from_str(&status.signature)?, block_time));
+                }
+            }
+        }
+        Ok(signatures)
+    }
+
+    /// Current `mint` balance of each owner's Token-2022 account
+    async fn token_balances(&self, owners: Vec<Pubkey>, mint: Pubkey) -> Result<HashMap<Pubkey, u64>> {
+        let mut balances = HashMap::new();
+        for chunk in owners.chunks(100) {
+            let token_accounts: Vec<Pubkey> = chunk
+                .iter()
+                .map(|owner| get_associated_token_address(owner, &mint, &TOKEN_2022_PROGRAM_ID))
+                .collect();
+            let accounts = self.rpc_client.get_multiple_accounts(&token_accounts).await?;
+            for (owner, account) in chunk.iter().zip(accounts) {
+                let token_account = account.as_ref().and_then(|account| StateWithExtensions::<Account>::unpack(&account.data).ok());
+                if let Some(token_account) = token_account {
+                    balances.insert(*owner, token_account.base.amount);
+                }
+            }
+        }
+        Ok(balances)
+    }
+}
+
+/// Lock state changes in a transaction, in instruction order. Includes CPIs,
+/// so locks made through a multisig are counted too.
+fn lock_events(transaction: &EncodedConfirmedTransactionWithStatusMeta, instructions: &LockInstructions) -> Vec<LockEvent> {
+    program_instructions(transaction, &instructions.program_id)
+        .into_iter()
+        .filter_map(|(accounts, data)| {
+            if data.starts_with(&instructions.lock) {
+                // lock_tokens: [pq_account, owner]
+                accounts.get(1).copied().map(LockEvent::Lock)
+            } else if instructions.unlock.iter().any(|unlock| data.starts_with(unlock)) {
+                // Unlock finalization: [verification_state | signature_storage, pq_account, owner]
+                accounts.get(2).copied().map(LockEvent::Unlock)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Calls to `program_id` in a transaction as (accounts, data), in instruction
+/// order with CPIs after the top-level instructions
+pub(super) fn program_instructions(
+    transaction: &EncodedConfirmedTransactionWithStatusMeta,
+    program_id: &Pubkey,
+) -> Vec<(Vec<Pubkey>, Vec<u8>)> {
+    let Some(decoded) = transaction.transaction.transaction.decode() else {
+        return Vec::new();
+    };
+    let meta = transaction.transaction.meta.as_ref();
+
+    let mut keys = decoded.message.static_account_keys().to_vec();
+    if let Some(loaded) = meta.and_then(|meta| Option::<&UiLoadedAddresses>::from(meta.loaded_addresses.as_ref())) {
+        keys.extend(loaded.writable.iter().chain(&loaded.readonly).filter_map(|key| key.parse::<Pubkey>().ok()));
+    }
+
+    let mut calls: Vec<(u8, Vec<u8>, Vec<u8>)> = decoded
+        .message
+        .instructions()
+        .iter()
+        .map(|instruction| (instruction.program_id_index, instruction.accounts.clone(), instruction.data.clone()))
+        .collect();
+    if let Some(inner) = meta.and_then(|meta| Option::<&Vec<_>>::from(meta.inner_instructions.as_ref())) {
+        for set in inner {
+            for instruction in &set.instructions {
+                if let UiInstruction::Compiled(compiled) = instruction {
+                    if let Ok(data) = bs58::decode(&compiled.data).into_vec() {
+                        calls.push((compiled.program_id_index, compiled.accounts.clone(), data));
+                    }
+                }
+            }
+        }
+    }
+
+    calls
+        .into_iter()
+        .filter(|(program, _, _)| keys.get(*program as usize) == Some(program_id))
+        .filter_map(|(_, accounts, data)| {
+            // An account index past the key list means a malformed transaction
+            let accounts: Option<Vec<Pubkey>> = accounts.iter().map(|&index| keys.get(index as usize).copied()).collect();
+            accounts.map(|accounts| (accounts, data))
+        })
+        .collect()
+}
+
+/// Walk `events` (newest first) back from the currently `locked` owners,
+/// keeping the last state of each hour. Returns snapshots oldest first.
+fn replay(
+    locked: &mut HashSet<Pubkey>,
+    events: &[(i64, LockEvent)],
+    balances: &HashMap<Pubkey, u64>,
+    decimals: u8,
+) -> Vec<LockSnapshot> {
+    let mut snapshots: Vec<LockSnapshot> = Vec::new();
+    for &(time, event) in events {
+        let bucket = time - time.rem_euclid(BUCKET_SECS);
+        if snapshots.last().is_none_or(|last| last.timestamp - last.timestamp.rem_euclid(BUCKET_SECS) != bucket) {
+            // Like get_network_locked_total, empty token accounts don't count
+            let amounts: Vec<u64> = locked
+                .iter()
+                .filter_map(|owner| balances.get(owner).copied())
+                .filter(|&amount| amount > 0)
+                .collect();
+            snapshots.push(LockSnapshot {
+                timestamp: time,
+                locked_amount: units::to_tokens(amounts.iter().sum(), decimals),
+                holder_count: amounts.len(),
+            });
+        }
+        match event {
+            LockEvent::Lock(owner) => locked.remove(&owner),
+            LockEvent::Unlock(owner) => locked.insert(owner),
+        };
+    }
+    snapshots.reverse();
+    snapshots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_undoes_events() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let balances = HashMap::from([(a, 2_000_000), (b, 3_000_000)]);
+        let mut locked = HashSet::from([a]);
+        // Newest first: a locked at 7300, b unlocked at 7200, b locked at 100
+        let events = [
+            (7_300, LockEvent::Lock(a)),
+            (7_200, LockEvent::Unlock(b)),
+            (100, LockEvent::Lock(b)),
+        ];
+
+        let snapshots = replay(&mut locked, &events, &balances, 6);
+        assert_eq!(
+            snapshots,
+            vec![
+                LockSnapshot { timestamp: 100, locked_amount: 3.0, holder_count: 1 },
+                LockSnapshot { timestamp: 7_300, locked_amount: 2.0, holder_count: 1 },
+            ]
+        );
+        assert!(locked.is_empty());
+    }
+}
@@ -0,0 +1,224 @@
+//! Locked holders across the network
+//!
+//! Ranks, buckets and totals the per-owner balances from
+//! [`VaultClient::network_locked_holders`]. That query walks every locked
+//! vault, so its result is cached for five minutes, in memory and in the data
+//! directory, where the `stats` commands and the dashboard share it.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::fs;
+use std::path::PathBuf;
+
+use super::{NetworkLockCache, VaultClient};
+use crate::paths;
+
+/// A locked vault owner and their balance of the queried mint, in QDUM
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockedHolder {
+    pub owner: Pubkey,
+    pub amount: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaderboardEntry {
+    /// 1 is the largest holder
+    pub rank: usize,
+    pub owner: Pubkey,
+    pub amount: f64,
+    /// Percent of all locked tokens
+    pub share: f64,
+}
+
+/// Every locked holder, largest first
+#[derive(Debug, Clone, Default)]
+pub struct Leaderboard {
+    pub total_locked: f64,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    pub fn new(mut holders: Vec<LockedHolder>) -> Self {
+        // Ties go by address, so ranks don't shuffle between refreshes
+        holders.sort_by(|a, b| {
+            b.amount
+                .total_cmp(&a.amount)
+                .then_with(|| a.owner.to_string().cmp(&b.owner.to_string()))
+        });
+        let total_locked: f64 = holders.iter().map(|holder| holder.amount).sum();
+
+        let entries = holders
+            .into_iter()
+            .enumerate()
+            .map(|(index, holder)| LeaderboardEntry {
+                rank: index + 1,
+                owner: holder.owner,
+                amount: holder.amount,
+                share: if total_locked > 0.0 { holder.amount / total_locked * 100.0 } else { 0.0 },
+            })
+            .collect();
+
+        Leaderboard { total_locked, entries }
+    }
+
+    pub fn holder_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The `n` largest holders
+    pub fn top(&self, n: usize) -> &[LeaderboardEntry] {
+        &self.entries[..n.min(self.entries.len())]
+    }
+
+    /// Where `owner` ranks, if they have anything locked
+    pub fn entry_for(&self, owner: &Pubkey) -> Option<&LeaderboardEntry> {
+        self.entries.iter().find(|entry| entry.owner == *owner)
+    }
+
+    /// Holders and locked amount per balance range, smallest range first
+    pub fn distribution(&self) -> Vec<DistributionBucket> {
+        let mut buckets: Vec<DistributionBucket> = DISTRIBUTION_BUCKETS
+            .iter()
+            .map(|&(label, _)| DistributionBucket { label, holders: 0, amount: 0.0, share: 0.0 })
+            .collect();
+
+        for entry in &self.entries {
+            let index = DISTRIBUTION_BUCKETS.iter().rposition(|(_, floor)| entry.amount >= *floor).unwrap_or(0);
+            buckets[index].holders += 1;
+            buckets[index].amount += entry.amount;
+            buckets[index].share += entry.share;
+        }
+        buckets
+    }
+}
+
+/// Balance ranges of the distribution histogram, as (label, lower bound in QDUM)
+pub const DISTRIBUTION_BUCKETS: [(&str, f64); 5] =
+    [("dust", 0.0), ("<100", 1.0), ("100-1k", 100.0), ("1k-10k", 1_000.0), ("10k+", 10_000.0)];
+
+/// Locked holders whose balance falls in one of [`DISTRIBUTION_BUCKETS`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DistributionBucket {
+    pub label: &'static str,
+    pub holders: usize,
+    pub amount: f64,
+    /// Percent of all locked tokens
+    pub share: f64,
+}
+
+/// Network-wide lock totals, as `stats network` reports them
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NetworkLockStats {
+    pub mint: String,
+    pub total_locked: f64,
+    pub holder_count: usize,
+    /// Seconds since the holders were queried
+    pub cache_age_secs: u64,
+    /// Minted supply of `mint`
+    pub circulating_supply: f64,
+    /// Percent of the circulating supply that is locked
+    pub locked_percent: f64,
+}
+
+fn cache_path() -> PathBuf {
+    paths::data_dir().join("network-lock-cache.json")
+}
+
+impl VaultClient {
+    /// Locked holders of `mint`, ranked by balance
+    pub async fn lock_leaderboard(&self, mint: Pubkey, force_refresh: bool) -> Result<Leaderboard> {
+        let holders = self.network_locked_holders(mint, force_refresh).await?;
+        Ok(Leaderboard::new(holders))
+    }
+
+    /// Total locked, holder count and locked share of the supply of `mint`
+    pub async fn network_lock_stats(&self, mint: Pubkey, force_refresh: bool) -> Result<NetworkLockStats> {
+        let (total_locked, holder_count) = self.get_network_locked_total(mint, force_refresh).await?;
+        let cache_age_secs = self.get_network_lock_cache_age().map_or(0, |age| age.as_secs());
+
+        let supply = self.rpc_client.get_token_supply(&mint).await.context("Failed to fetch token supply")?;
+        let raw: u64 = supply.amount.parse().context("Invalid token supply")?;
+        let circulating_supply = raw as f64 / 10f64.powi(supply.decimals as i32);
+
+        Ok(NetworkLockStats {
+            mint: mint.to_string(),
+            total_locked,
+            holder_count,
+            cache_age_secs,
+            circulating_supply,
+            locked_percent: if circulating_supply > 0.0 { total_locked / circulating_supply * 100.0 } else { 0.0 },
+        })
+    }
+
+    /// The last network lock query for `mint` by this program, from memory
+    /// or, in a fresh process, from disk
+    pub(super) fn cached_network_lock(&self, mint: Pubkey) -> Option<NetworkLockCache> {
+        let mut cache = self.network_lock_cache.lock().unwrap();
+        if cache.as_ref().is_none_or(|cached| cached.mint != mint) {
+            let stored = fs::read_to_string(cache_path())
+                .ok()
+                .and_then(|data| serde_json::from_str::<NetworkLockCache>(&data).ok());
+            if let Some(stored) = stored.filter(|stored| stored.mint == mint && stored.program_id == self.program_id) {
+                *cache = Some(stored);
+            }
+        }
+        cache.clone().filter(|cached| cached.mint == mint)
+    }
+
+    pub(super) fn store_network_lock(&self, entry: NetworkLockCache) {
+        // Only the disk copy is shared; failing to write it just costs a query
+        if let Ok(json) = serde_json::to_string(&entry) {
+            let path = cache_path();
+            let written = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(&path, json));
+            if let Err(e) = written {
+                crate::applog::write(crate::applog::Level::Warn, "network", format!("Failed to save lock cache: {}", e));
+            }
+        }
+        *self.network_lock_cache.lock().unwrap() = Some(entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leaderboard_ranks_and_shares() {
+        let owners: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let leaderboard = Leaderboard::new(vec![
+            LockedHolder { owner: owners[0], amount: 25.0 },
+            LockedHolder { owner: owners[1], amount: 50.0 },
+            LockedHolder { owner: owners[2], amount: 25.0 },
+        ]);
+
+        assert_eq!(leaderboard.total_locked, 100.0);
+        assert_eq!(leaderboard.holder_count(), 3);
+        assert_eq!(leaderboard.entries[0].owner, owners[1]);
+        assert_eq!(leaderboard.entries[0].share, 50.0);
+        assert_eq!(leaderboard.top(2).len(), 2);
+        assert_eq!(leaderboard.top(10).len(), 3);
+
+        let ranks: Vec<usize> = owners.iter().map(|owner| leaderboard.entry_for(owner).unwrap().rank).collect();
+        assert_eq!(ranks[1], 1);
+        assert!(ranks.contains(&2) && ranks.contains(&3));
+        assert!(leaderboard.entry_for(&Pubkey::new_unique()).is_none());
+    }
+
+    #[test]
+    fn test_distribution_buckets() {
+        let holders = [0.5, 1.0, 99.9, 100.0, 5_000.0, 10_000.0, 250_000.0]
+            .into_iter()
+            .map(|amount| LockedHolder { owner: Pubkey::new_unique(), amount })
+            .collect();
+        let distribution = Leaderboard::new(holders).distribution();
+
+        let counts: Vec<usize> = distribution.iter().map(|bucket| bucket.holders).collect();
+        assert_eq!(counts, vec![1, 2, 1, 1, 2]);
+        assert_eq!(distribution[4].amount, 260_000.0);
+        assert!((distribution.iter().map(|bucket| bucket.share).sum::<f64>() - 100.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,393 @@
+use anyhow::{Context, Result};
+use colored::Colorize;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+};
+
+use super::{get_associated_token_address, VaultClient, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID};
+use crate::solana::accounts::InheritanceConfig;
+use crate::solana::receipt::{Operation, Receipt};
+use crate::solana::units;
+
+const INHERITANCE_SEED: &[u8] = b"inheritance";
+
+impl VaultClient {
+    fn derive_inheritance_account(&self, owner: Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[INHERITANCE_SEED, owner.as_ref()], &self.program_id)
+    }
+
+    /// Cluster time, falling back to local time if the block time is unavailable
+    async fn cluster_time(&self) -> i64 {
+        let block_time = match self.rpc_client.get_slot().await {
+            Ok(slot) => self.rpc_client.get_block_time(slot).await.ok(),
+            Err(_) => None,
+        };
+        block_time.unwrap_or_else(|| chrono::Utc::now().timestamp())
+    }
+
+    /// Fetch the inheritance configuration for a vault owner, if any
+    pub async fn get_inheritance(&self, owner: Pubkey) -> Result<Option<InheritanceConfig>> {
+        let (address, _) = self.derive_inheritance_account(owner);
+        // Only a missing account means none is configured; RPC errors aren't an answer
+        let account = self
+            .rpc_client
+            .get_account_with_commitment(&address, self.rpc_client.commitment())
+            .await
+            .context("Failed to fetch the inheritance account")?
+            .value;
+        account.map(|account| InheritanceConfig::parse(address, &account.data)).transpose()
+    }
+
+    /// `configure_inheritance` followed by an approval letting the inheritance
+    /// PDA move `amount` base units of `wallet`'s `mint` tokens on a claim
+    fn configure_inheritance_instructions(
+        &self,
+        wallet: Pubkey,
+        beneficiary: Pubkey,
+        timeout_secs: i64,
+        mint: Pubkey,
+        amount: u64,
+    ) -> Result<Vec<Instruction>> {
+        let (pq_account, _) = self.derive_pq_account(wallet);
+        let (inheritance, _) = self.derive_inheritance_account(wallet);
+        let owner_token_account = get_associated_token_address(&wallet, &mint, &TOKEN_2022_PROGRAM_ID);
+
+        let mut instruction_data = self.idl.discriminator("configure_inheritance").to_vec();
+        instruction_data.extend_from_slice(beneficiary.as_ref());
+        instruction_data.extend_from_slice(&timeout_secs.to_le_bytes());
+
+        let configure_ix = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(inheritance, false),
+                AccountMeta::new_readonly(pq_account, false),
+                AccountMeta::new(wallet, true),
+                AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+            ],
+            data: instruction_data,
+        };
+
+        let approve_ix = spl_token_2022::instruction::approve(
+            &TOKEN_2022_PROGRAM_ID,
+            &owner_token_account,
+            &inheritance,
+            &wallet,
+            &[],
+            amount,
+        )?;
+
+        Ok(vec![configure_ix, approve_ix])
+    }
+
+    /// Create or update the beneficiary and timeout, and delegate `amount`
+    /// base units of the owner's pqQDUM to the inheritance PDA so a claim can
+    /// move them
+    pub async fn configure_inheritance(
+        &self,
+        wallet: Pubkey,
+        keypair_path: &str,
+        beneficiary: Pubkey,
+        timeout_secs: i64,
+        mint: Pubkey,
+        amount: u64,
+    ) -> Result<Receipt> {
+        if timeout_secs <= 0 {
+            anyhow::bail!("Timeout must be positive");
+        }
+        if beneficiary == wallet {
+            anyhow::bail!("Beneficiary must be a different wallet");
+        }
+        if amount == 0 {
+            anyhow::bail!("Amount must be greater than 0");
+        }
+
+        let keypair = self.load_keypair(keypair_path)?;
+        if keypair.pubkey() != wallet {
+            anyhow::bail!("Inheritance must be configured with the owner's keypair ({}), got {}", wallet, keypair.pubkey());
+        }
+        let (pq_account, _) = self.derive_pq_account(wallet);
+        let (inheritance, _) = self.derive_inheritance_account(wallet);
+        let owner_token_account = get_associated_token_address(&wallet, &mint, &TOKEN_2022_PROGRAM_ID);
+
+        self.rpc_client.get_account(&pq_account).await
+            .context("PQ account not found! Register first with: qdum-vault register")?;
+
+        // The beneficiary can move the delegated tokens, so it's held to the
+        // same rules as a transfer
+        let decimals = self.mint_decimals(mint).await?;
+        self.policy.check_transfer(&beneficiary, &mint, amount)?;
+
+        let mut receipt = Receipt::new(Operation::ConfigureInheritance);
+        receipt
+            .account("wallet", &wallet)
+            .account("beneficiary", &beneficiary)
+            .account("inheritance", &inheritance)
+            .account("owner_token_account", &owner_token_account);
+
+        let instructions = self.configure_inheritance_instructions(wallet, beneficiary, timeout_secs, mint, amount)?;
+        let (signature, fee) = self.send_instructions(&instructions, &keypair.pubkey(), &[&keypair]).await?;
+        receipt.record((signature, fee));
+
+        println!("{}", "✅ Inheritance configured".green().bold());
+        println!("   Beneficiary: {}", beneficiary.to_string().cyan());
+        println!("   Claimable:   {} tokens", units::to_tokens(amount, decimals).to_string().bright_white());
+        println!("   Timeout:     {}", format_secs(timeout_secs).bright_white());
+        println!("   Transaction: {}", signature.to_string().cyan());
+        println!();
+        println!("   Run {} regularly to keep the switch from firing.", "qdum-vault heartbeat".bright_green());
+        println!();
+
+        Ok(receipt.finish())
+    }
+
+    /// Reset the inheritance timer
+    pub async fn heartbeat(&self, wallet: Pubkey, keypair_path: &str) -> Result<Receipt> {
+        let keypair = self.load_keypair(keypair_path)?;
+        let (inheritance, _) = self.derive_inheritance_account(wallet);
+
+        let config = self.get_inheritance(wallet).await?
+            .ok_or_else(|| anyhow::anyhow!("No inheritance configured for this wallet"))?;
+
+        let mut receipt = Receipt::new(Operation::Heartbeat);
+        receipt
+            .account("wallet", &wallet)
+            .account("inheritance", &inheritance);
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(inheritance, false),
+                AccountMeta::new_readonly(keypair.pubkey(), true),
+            ],
+            data: self.idl.discriminator("heartbeat").to_vec(),
+        };
+
+        let (signature, fee) = self.send_instructions(&[instruction], &keypair.pubkey(), &[&keypair]).await?;
+        receipt.record((signature, fee));
+
+        println!("{}", "💓 Heartbeat recorded".green().bold());
+        println!("   Beneficiary can claim after {} of inactivity", format_secs(config.timeout_secs).bright_white());
+        println!("   Transaction: {}", signature.to_string().cyan());
+        println!();
+
+        Ok(receipt.finish())
+    }
+
+    /// Remove the inheritance configuration and reclaim its rent
+    pub async fn cancel_inheritance(&self, wallet: Pubkey, keypair_path: &str, mint: Pubkey) -> Result<Receipt> {
+        let keypair = self.load_keypair(keypair_path)?;
+        let (inheritance, _) = self.derive_inheritance_account(wallet);
+        let owner_token_account = get_associated_token_address(&wallet, &mint, &TOKEN_2022_PROGRAM_ID);
+
+        if self.get_inheritance(wallet).await?.is_none() {
+            return Ok(Receipt::new(Operation::CancelInheritance).skipped("No inheritance configured"));
+        }
+
+        let mut receipt = Receipt::new(Operation::CancelInheritance);
+        receipt
+            .account("wallet", &wallet)
+            .account("inheritance", &inheritance);
+
+        let cancel_ix = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(inheritance, false),
+                AccountMeta::new(keypair.pubkey(), true),
+            ],
+            data: self.idl.discriminator("cancel_inheritance").to_vec(),
+        };
+
+        // Drop the token delegation granted at configuration time
+        let revoke_ix = spl_token_2022::instruction::revoke(
+            &TOKEN_2022_PROGRAM_ID,
+            &owner_token_account,
+            &keypair.pubkey(),
+            &[],
+        )?;
+
+        let (signature, fee) = self.send_instructions(&[cancel_ix, revoke_ix], &keypair.pubkey(), &[&keypair]).await?;
+        receipt.record((signature, fee));
+
+        println!("{}", "✅ Inheritance cancelled".green().bold());
+        println!("   Transaction: {}", signature.to_string().cyan());
+        println!();
+
+        Ok(receipt.finish())
+    }
+
+    /// Print the inheritance configuration and countdown for a vault owner
+    pub async fn inheritance_status(&self, owner: Pubkey) -> Result<()> {
+        let config = match self.get_inheritance(owner).await? {
+            Some(config) => config,
+            None => {
+                println!("{} No inheritance configured for {}", "[i]".bright_blue(), owner.to_string().cyan());
+                println!();
+                return Ok(());
+            }
+        };
+
+        let now = self.cluster_time().await;
+        let remaining = config.expires_at() - now;
+
+        use comfy_table::{presets::UTF8_FULL, Table};
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec![
+            "Property".bright_white().bold().to_string(),
+            "Value".bright_white().bold().to_string(),
+        ]);
+        table.add_row(vec!["Owner".dimmed().to_string(), config.owner.to_string().bright_cyan().to_string()]);
+        table.add_row(vec!["Beneficiary".dimmed().to_string(), config.beneficiary.to_string().bright_cyan().to_string()]);
+        table.add_row(vec!["Inheritance PDA".dimmed().to_string(), config.address.to_string().bright_cyan().to_string()]);
+        table.add_row(vec!["Timeout".dimmed().to_string(), format_secs(config.timeout_secs)]);
+        table.add_row(vec!["Last Heartbeat".dimmed().to_string(), format_timestamp(config.last_heartbeat)]);
+        table.add_row(vec![
+            "Claimable".dimmed().to_string(),
+            if remaining <= 0 {
+                "YES - timeout expired".red().bold().to_string()
+            } else {
+                format!("in {} ({})", format_secs(remaining), format_timestamp(config.expires_at())).green().to_string()
+            },
+        ]);
+
+        println!("{}", table);
+        println!();
+
+        Ok(())
+    }
+
+    /// As the beneficiary, claim an expired vault's pqQDUM balance
+    pub async fn claim_inheritance(&self, owner: Pubkey, keypair_path: &str, mint: Pubkey) -> Result<Receipt> {
+        let keypair = self.load_keypair(keypair_path)?;
+        let beneficiary = keypair.pubkey();
+
+        let config = self.get_inheritance(owner).await?
+            .ok_or_else(|| anyhow::anyhow!("No inheritance configured for {}", owner))?;
+
+        if config.beneficiary != beneficiary {
+            anyhow::bail!(
+                "This wallet ({}) is not the designated beneficiary ({})",
+                beneficiary,
+                config.beneficiary
+            );
+        }
+
+        let remaining = config.expires_at() - self.cluster_time().await;
+        if remaining > 0 {
+            anyhow::bail!("The owner's heartbeat has not expired yet (claimable in {})", format_secs(remaining));
+        }
+
+        let (pq_account, _) = self.derive_pq_account(owner);
+        let owner_token_account = get_associated_token_address(&owner, &mint, &TOKEN_2022_PROGRAM_ID);
+        let beneficiary_token_account = get_associated_token_address(&beneficiary, &mint, &TOKEN_2022_PROGRAM_ID);
+        let (extra_account_meta_list, _) = Pubkey::find_program_address(
+            &[b"extra-account-metas", mint.as_ref()],
+            &self.program_id,
+        );
+
+        let mut receipt = Receipt::new(Operation::ClaimInheritance);
+        receipt
+            .account("owner", &owner)
+            .account("beneficiary", &beneficiary)
+            .account("inheritance", &config.address)
+            .account("owner_token_account", &owner_token_account)
+            .account("beneficiary_token_account", &beneficiary_token_account);
+
+        let mut instructions = Vec::new();
+
+        if self.rpc_client.get_account(&beneficiary_token_account).await.is_err() {
+            instructions.push(Instruction {
+                program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(beneficiary, true),
+                    AccountMeta::new(beneficiary_token_account, false),
+                    AccountMeta::new_readonly(beneficiary, false),
+                    AccountMeta::new_readonly(mint, false),
+                    AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+                    AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                ],
+                data: vec![],
+            });
+        }
+
+        // The program transfers the full balance as the delegated inheritance PDA;
+        // the trailing accounts are the transfer hook's extra accounts
+        instructions.push(Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(config.address, false),
+                AccountMeta::new_readonly(owner, false),
+                AccountMeta::new(beneficiary, true),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(owner_token_account, false),
+                AccountMeta::new(beneficiary_token_account, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                AccountMeta::new_readonly(self.program_id, false),
+                AccountMeta::new_readonly(extra_account_meta_list, false),
+                AccountMeta::new_readonly(pq_account, false),
+            ],
+            data: self.idl.discriminator("claim_inheritance").to_vec(),
+        });
+
+        let (signature, fee) = self.send_instructions(&instructions, &beneficiary, &[&keypair]).await?;
+        receipt.record((signature, fee));
+
+        println!("{}", "✅ Inheritance claimed".green().bold());
+        println!("   Tokens moved to: {}", beneficiary_token_account.to_string().cyan());
+        println!("   Transaction:     {}", signature.to_string().cyan());
+        println!();
+
+        Ok(receipt.finish())
+    }
+}
+
+/// Compact duration, e.g. "90d 0h" or "2h 15m"
+fn format_secs(secs: i64) -> String {
+    let secs = secs.max(0);
+    let days = secs / 86_400;
+    let hours = (secs % 86_400) / 3_600;
+    let minutes = (secs % 3_600) / 60;
+
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+fn format_timestamp(ts: i64) -> String {
+    chrono::DateTime::from_timestamp(ts, 0)
+        .map(|dt| dt.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| ts.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spl_token_2022::instruction::TokenInstruction;
+
+    #[test]
+    fn test_configure_instructions() {
+        let client = VaultClient::builder().build().unwrap();
+        let (wallet, beneficiary, mint) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let instructions = client.configure_inheritance_instructions(wallet, beneficiary, 3_600, mint, 42_000_000).unwrap();
+        let (inheritance, _) = client.derive_inheritance_account(wallet);
+
+        let configure = &instructions[0];
+        assert_eq!(&configure.data[..8], client.idl.discriminator("configure_inheritance").as_slice());
+        assert_eq!(&configure.data[8..40], beneficiary.as_ref());
+        assert_eq!(configure.data[40..48], 3_600i64.to_le_bytes());
+        assert_eq!(configure.accounts[0].pubkey, inheritance);
+        assert!(configure.accounts[2].is_signer && configure.accounts[2].pubkey == wallet);
+
+        // The delegation is exactly the configured amount, to the inheritance PDA
+        let approve = &instructions[1];
+        assert_eq!(TokenInstruction::unpack(&approve.data).unwrap(), TokenInstruction::Approve { amount: 42_000_000 });
+        assert_eq!(approve.accounts[0].pubkey, get_associated_token_address(&wallet, &mint, &TOKEN_2022_PROGRAM_ID));
+        assert_eq!(approve.accounts[1].pubkey, inheritance);
+    }
+}
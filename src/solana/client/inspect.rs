@@ -0,0 +1,297 @@
+//! Raw account inspection for `account <address>`
+//!
+//! Works out what an address holds from its owner program and layout, and
+//! for the vault program's accounts whether the address is the PDA the
+//! decoded fields would derive. A layout that decodes at an address that
+//! doesn't match is reported as such, which is usually the sign of a
+//! corrupted or foreign account.
+
+use anyhow::{Context, Result};
+use solana_sdk::pubkey::Pubkey;
+use std::fmt::Write as _;
+
+use super::{VaultClient, PQ_ACCOUNT_SEED, SPL_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID};
+use crate::solana::accounts::{MintState, PqAccount, SignatureStorage, VerificationState};
+
+/// Size of an SPL token account; Token-2022 extensions follow it
+const TOKEN_ACCOUNT_LEN: usize = 165;
+
+/// Size of an SPL mint, before any Token-2022 padding and extensions
+const MINT_LEN: usize = 82;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccountKind {
+    PqAccount(PqAccount),
+    SignatureStorage(SignatureStorage),
+    VerificationState(VerificationState),
+    MintState(MintState),
+    /// SPL Token or Token-2022 mint
+    Mint { supply: u64, decimals: u8, mint_authority: Option<Pubkey>, freeze_authority: Option<Pubkey> },
+    /// SPL Token or Token-2022 token account
+    TokenAccount { mint: Pubkey, owner: Pubkey, amount: u64, state: u8 },
+    Unknown,
+}
+
+impl AccountKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AccountKind::PqAccount(_) => "PQ account",
+            AccountKind::SignatureStorage(_) => "signature storage",
+            AccountKind::VerificationState(_) => "verification state",
+            AccountKind::MintState(_) => "mint state",
+            AccountKind::Mint { .. } => "token mint",
+            AccountKind::TokenAccount { .. } => "token account",
+            AccountKind::Unknown => "unknown",
+        }
+    }
+
+    /// Decoded fields as (name, value), in layout order
+    pub fn fields(&self) -> Vec<(&'static str, String)> {
+        let short_hex = |bytes: &[u8]| {
+            if bytes.len() <= 32 {
+                hex::encode(bytes)
+            } else {
+                format!("{}… ({} bytes)", hex::encode(&bytes[..16]), bytes.len())
+            }
+        };
+        let optional = |key: &Option<Pubkey>| key.map_or_else(|| "none".to_string(), |key| key.to_string());
+
+        match self {
+            AccountKind::PqAccount(account) => vec![
+                ("owner", account.owner.to_string()),
+                (
+                    "algorithm",
+                    account
+                        .algorithm()
+                        .map_or_else(|| format!("unknown ({})", account.algorithm), |algorithm| format!("{} ({})", algorithm, account.algorithm)),
+                ),
+                ("public_key", short_hex(&account.public_key)),
+                ("tokens_locked", account.tokens_locked.to_string()),
+                ("unlock_challenge", hex::encode(account.unlock_challenge)),
+                ("pending_unlock_slot", account.pending_unlock_slot.to_string()),
+                ("lock_mode", account.lock_mode.to_string()),
+                ("last_airdrop_claim", account.last_airdrop_claim.to_string()),
+            ],
+            AccountKind::SignatureStorage(storage) => vec![
+                ("owner", storage.owner.to_string()),
+                ("identifier", storage.identifier.clone()),
+                ("public_key", short_hex(&storage.public_key)),
+                ("message", short_hex(&storage.message)),
+                ("signature", short_hex(&storage.signature)),
+                ("bytes_written", storage.bytes_written.to_string()),
+            ],
+            AccountKind::VerificationState(state) => vec![
+                ("owner", state.owner.to_string()),
+                ("identifier", state.identifier.clone()),
+                ("message", short_hex(&state.message)),
+                ("public_key", short_hex(&state.public_key)),
+                ("fors_root", hex::encode(state.fors_root)),
+                ("layer_roots", state.layer_roots.len().to_string()),
+                ("unlock_duration_slots", state.unlock_duration_slots.to_string()),
+                ("steps_completed", state.steps_completed.to_string()),
+                ("verified", state.verified.to_string()),
+            ],
+            AccountKind::MintState(state) => vec![
+                ("authority", state.authority.to_string()),
+                ("mint", state.mint.to_string()),
+                ("total_minted", state.total_minted.to_string()),
+                ("dev_wallet", state.dev_wallet.to_string()),
+                ("transfer_hook_program", state.transfer_hook_program.to_string()),
+                ("authority_minted", state.authority_minted.to_string()),
+                ("airdrop_distributed", state.airdrop_distributed.to_string()),
+            ],
+            AccountKind::Mint { supply, decimals, mint_authority, freeze_authority } => vec![
+                ("supply", supply.to_string()),
+                ("decimals", decimals.to_string()),
+                ("mint_authority", optional(mint_authority)),
+                ("freeze_authority", optional(freeze_authority)),
+            ],
+            AccountKind::TokenAccount { mint, owner, amount, state } => vec![
+                ("mint", mint.to_string()),
+                ("owner", owner.to_string()),
+                ("amount", amount.to_string()),
+                (
+                    "state",
+                    match state {
+                        0 => "uninitialized".to_string(),
+                        1 => "initialized".to_string(),
+                        2 => "frozen".to_string(),
+                        other => format!("invalid ({})", other),
+                    },
+                ),
+            ],
+            AccountKind::Unknown => Vec::new(),
+        }
+    }
+}
+
+/// An account as `account <address>` shows it
+#[derive(Debug, Clone)]
+pub struct AccountInspection {
+    pub address: Pubkey,
+    /// Program that owns the account
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub executable: bool,
+    pub data: Vec<u8>,
+    pub kind: AccountKind,
+    /// The address is the PDA the decoded fields derive. Always false for
+    /// accounts that aren't the vault program's.
+    pub pda_verified: bool,
+}
+
+impl VaultClient {
+    pub async fn inspect_account(&self, address: Pubkey) -> Result<AccountInspection> {
+        let account = self
+            .rpc_client
+            .get_account(&address)
+            .await
+            .with_context(|| format!("Account {} not found", address))?;
+        let (kind, pda_verified) = classify(&address, &self.program_id, &account.owner, &account.data);
+
+        Ok(AccountInspection {
+            address,
+            owner: account.owner,
+            lamports: account.lamports,
+            executable: account.executable,
+            data: account.data,
+            kind,
+            pda_verified,
+        })
+    }
+}
+
+/// What `data` at `address` is, and whether the address confirms it
+pub fn classify(address: &Pubkey, program_id: &Pubkey, owner: &Pubkey, data: &[u8]) -> (AccountKind, bool) {
+    if *owner == SPL_TOKEN_PROGRAM_ID || *owner == TOKEN_2022_PROGRAM_ID {
+        return (classify_token(data), false);
+    }
+    if owner != program_id {
+        return (AccountKind::Unknown, false);
+    }
+
+    let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, program_id).0;
+    let mut candidates = Vec::new();
+    if let Ok(state) = VerificationState::parse(data) {
+        let verified = pda(&[b"sphincs_verify", state.owner.as_ref(), state.identifier.as_bytes()]) == *address;
+        candidates.push((AccountKind::VerificationState(state), verified));
+    }
+    if let Ok(storage) = SignatureStorage::parse(data) {
+        let verified = pda(&[b"sphincs_sig", storage.owner.as_ref(), storage.identifier.as_bytes()]) == *address;
+        candidates.push((AccountKind::SignatureStorage(storage), verified));
+    }
+    if let Ok(account) = PqAccount::parse(data) {
+        let verified = pda(&[PQ_ACCOUNT_SEED, account.owner.as_ref()]) == *address;
+        candidates.push((AccountKind::PqAccount(account), verified));
+    }
+    if let Ok(state) = MintState::parse(data) {
+        let verified = pda(&[b"state"]) == *address;
+        candidates.push((AccountKind::MintState(state), verified));
+    }
+
+    // A matching address settles it; otherwise the most specific layout that decodes
+    match candidates.iter().position(|(_, verified)| *verified) {
+        Some(index) => candidates.swap_remove(index),
+        None => candidates.into_iter().next().unwrap_or((AccountKind::Unknown, false)),
+    }
+}
+
+fn classify_token(data: &[u8]) -> AccountKind {
+    let pubkey_at = |offset: usize| data.get(offset..offset + 32).and_then(|bytes| Pubkey::try_from(bytes).ok());
+    let u64_at = |offset: usize| data.get(offset..offset + 8).map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()));
+    let coption_at = |offset: usize| match data.get(offset..offset + 4) {
+        Some([1, 0, 0, 0]) => pubkey_at(offset + 4),
+        _ => None,
+    };
+
+    // Token-2022 accounts with extensions carry their type right after the base layout
+    let is_mint = match data.len() {
+        MINT_LEN => true,
+        len if len > TOKEN_ACCOUNT_LEN => data[TOKEN_ACCOUNT_LEN] == 1,
+        _ => false,
+    };
+
+    if is_mint {
+        AccountKind::Mint {
+            supply: u64_at(36).unwrap_or_default(),
+            decimals: data[44],
+            mint_authority: coption_at(0),
+            freeze_authority: coption_at(46),
+        }
+    } else if data.len() >= TOKEN_ACCOUNT_LEN {
+        match (pubkey_at(0), pubkey_at(32), u64_at(64)) {
+            (Some(mint), Some(owner), Some(amount)) => AccountKind::TokenAccount { mint, owner, amount, state: data[108] },
+            _ => AccountKind::Unknown,
+        }
+    } else {
+        AccountKind::Unknown
+    }
+}
+
+/// `xxd`-style dump: offset, 16 hex bytes, printable ASCII
+pub fn hex_dump(data: &[u8]) -> String {
+    let mut out = String::new();
+    for (line, chunk) in data.chunks(16).enumerate() {
+        let hex: Vec<String> = chunk.iter().map(|byte| format!("{:02x}", byte)).collect();
+        let ascii: String = chunk
+            .iter()
+            .map(|&byte| if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '.' })
+            .collect();
+        let _ = writeln!(out, "{:08x}  {:<47}  {}", line * 16, hex.join(" "), ascii);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_token_account() {
+        let (mint, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = vec![0u8; TOKEN_ACCOUNT_LEN];
+        data[..32].copy_from_slice(mint.as_ref());
+        data[32..64].copy_from_slice(owner.as_ref());
+        data[64..72].copy_from_slice(&500u64.to_le_bytes());
+        data[108] = 1;
+
+        let (kind, verified) = classify(&Pubkey::new_unique(), &Pubkey::new_unique(), &TOKEN_2022_PROGRAM_ID, &data);
+        assert_eq!(kind, AccountKind::TokenAccount { mint, owner, amount: 500, state: 1 });
+        assert!(!verified);
+
+        // Same bytes with a mint's extension type marker
+        data.extend_from_slice(&[1, 0, 0]);
+        let (kind, _) = classify(&Pubkey::new_unique(), &Pubkey::new_unique(), &TOKEN_2022_PROGRAM_ID, &data);
+        assert_eq!(kind.name(), "token mint");
+    }
+
+    #[test]
+    fn test_classify_pq_account_checks_pda() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut data = vec![0u8; 8];
+        data.extend_from_slice(owner.as_ref());
+        data.push(0);
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(&[0u8; 32]);
+
+        let (address, _) = Pubkey::find_program_address(&[PQ_ACCOUNT_SEED, owner.as_ref()], &program_id);
+        let (kind, verified) = classify(&address, &program_id, &program_id, &data);
+        assert_eq!(kind.name(), "PQ account");
+        assert!(verified);
+
+        let (_, verified) = classify(&Pubkey::new_unique(), &program_id, &program_id, &data);
+        assert!(!verified);
+    }
+
+    #[test]
+    fn test_hex_dump() {
+        let dump = hex_dump(b"qdum\x00\x01vault-pda-bytes!!");
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("00000000  71 64 75 6d 00 01"));
+        assert!(lines[0].ends_with("qdum..vault-pda-"));
+        assert!(lines[1].starts_with("00000010  62 79 74 65 73 21 21"));
+    }
+}
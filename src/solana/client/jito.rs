@@ -0,0 +1,173 @@
+use anyhow::{Context, Result};
+use serde_json::json;
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_request::RpcRequest};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Signature, Signer},
+    transaction::Transaction,
+};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::{VaultClient, VaultSigner};
+use crate::exit_code::ExitKind;
+
+/// Mainnet block engine; bundles are only processed by Jito validators
+pub const DEFAULT_BLOCK_ENGINE_URL: &str = "https://mainnet.block-engine.jito.wtf";
+
+/// Tip paid per bundle, in lamports (the block engine's minimum is 1000)
+pub const DEFAULT_TIP_LAMPORTS: u64 = 10_000;
+
+/// Most transactions the block engine accepts in one bundle
+const MAX_BUNDLE_SIZE: usize = 5;
+
+/// How long to wait for a bundle to land (roughly one blockhash lifetime)
+const BUNDLE_LANDING_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Jito block-engine settings
+#[derive(Debug, Clone)]
+pub struct JitoConfig {
+    /// Block engine base URL, e.g. https://ny.mainnet.block-engine.jito.wtf
+    pub block_engine_url: String,
+    pub tip_lamports: u64,
+}
+
+impl Default for JitoConfig {
+    fn default() -> Self {
+        Self {
+            block_engine_url: DEFAULT_BLOCK_ENGINE_URL.to_string(),
+            tip_lamports: DEFAULT_TIP_LAMPORTS,
+        }
+    }
+}
+
+/// Transactions waiting to be sent as the next bundle
+pub(super) struct BundleQueue {
+    engine: RpcClient,
+    tip_lamports: u64,
+    tip_accounts: Vec<Pubkey>,
+    tip_payer: Arc<VaultSigner>,
+    pending: Mutex<Vec<Transaction>>,
+    /// Bundles sent so far, used to rotate through the tip accounts
+    sent: AtomicUsize,
+}
+
+impl VaultClient {
+    /// Same client, but `send_with_fee` queues transactions into tipped
+    /// bundles instead of sending them one at a time
+    pub(super) async fn bundled(&self, config: &JitoConfig, tip_payer: Arc<VaultSigner>) -> Result<Self> {
+        if self.nonce_account.is_some() {
+            anyhow::bail!("Jito bundles can't use a durable nonce: every transaction in a bundle would advance the same nonce");
+        }
+
+        let endpoint = format!("{}/api/v1/bundles", config.block_engine_url.trim_end_matches('/'));
+        let engine = RpcClient::new(endpoint);
+
+        let tip_accounts: Vec<String> = engine
+            .send(RpcRequest::Custom { method: "getTipAccounts" }, json!([]))
+            .await
+            .with_context(|| format!("Failed to fetch tip accounts from {}", config.block_engine_url))?;
+        let tip_accounts = tip_accounts
+            .iter()
+            .map(|address| Pubkey::from_str(address))
+            .collect::<Result<Vec<_>, _>>()
+            .context("Block engine returned an invalid tip account")?;
+        if tip_accounts.is_empty() {
+            anyhow::bail!("Block engine returned no tip accounts");
+        }
+
+        Ok(Self {
+            bundle_queue: Some(Arc::new(BundleQueue {
+                engine,
+                tip_lamports: config.tip_lamports,
+                tip_accounts,
+                tip_payer,
+                pending: Mutex::new(Vec::new()),
+                sent: AtomicUsize::new(0),
+            })),
+            ..self.clone()
+        })
+    }
+
+    /// Queue a transaction, sending the bundle once only the tip slot is left
+    pub(super) async fn enqueue_bundled(&self, queue: &BundleQueue, transaction: &Transaction) -> Result<()> {
+        let full = {
+            let mut pending = queue.pending.lock().unwrap();
+            pending.push(transaction.clone());
+            pending.len() == MAX_BUNDLE_SIZE - 1
+        };
+
+        if full {
+            self.flush_bundle().await?;
+        }
+        Ok(())
+    }
+
+    /// Send queued transactions plus a tip as one bundle and wait for it to land.
+    /// Does nothing when bundling is off or the queue is empty.
+    pub(super) async fn flush_bundle(&self) -> Result<()> {
+        let Some(queue) = &self.bundle_queue else {
+            return Ok(());
+        };
+
+        let mut transactions = std::mem::take(&mut *queue.pending.lock().unwrap());
+        if transactions.is_empty() {
+            return Ok(());
+        }
+
+        // The tip goes last so it is only paid if every step before it succeeds
+        let index = queue.sent.fetch_add(1, Ordering::Relaxed);
+        let tip_account = queue.tip_accounts[index % queue.tip_accounts.len()];
+        let tip_ix = solana_sdk::system_instruction::transfer(&queue.tip_payer.pubkey(), &tip_account, queue.tip_lamports);
+        transactions.push(self.signed_transaction(&[tip_ix], &queue.tip_payer.pubkey(), &[&queue.tip_payer]).await?);
+
+        let encoded = transactions
+            .iter()
+            .map(|tx| Ok(bs58::encode(bincode::serialize(tx)?).into_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let bundle_id: String = queue
+            .engine
+            .send(RpcRequest::Custom { method: "sendBundle" }, json!([encoded]))
+            .await
+            .context("Block engine rejected the bundle")?;
+
+        // Bundles are all-or-nothing, so every signature confirms together or not at all
+        let signatures: Vec<Signature> = transactions.iter().map(|tx| tx.signatures[0]).collect();
+        let started = Instant::now();
+        loop {
+            let statuses = self.rpc_client.get_signature_statuses(&signatures).await?.value;
+            let failed = statuses
+                .iter()
+                .zip(&transactions)
+                .find_map(|(status, tx)| Some((status.as_ref()?.err.clone()?, tx)));
+            if let Some((err, tx)) = failed {
+                let error = match self.explain_transaction_error(&err, &[], tx) {
+                    Some(failure) => anyhow::Error::new(failure),
+                    None => ExitKind::Program.error(err),
+                };
+                return Err(error.context(format!("Bundle {} failed on-chain", bundle_id)));
+            }
+            let landed = statuses
+                .iter()
+                .all(|status| status.as_ref().is_some_and(|s| s.satisfies_commitment(self.rpc_client.commitment())));
+            if landed {
+                for transaction in &transactions {
+                    self.invalidate_accounts(transaction);
+                }
+                return Ok(());
+            }
+
+            if started.elapsed() > BUNDLE_LANDING_TIMEOUT {
+                anyhow::bail!(
+                    "Bundle {} did not land within {}s (try a higher --jito-tip)",
+                    bundle_id,
+                    BUNDLE_LANDING_TIMEOUT.as_secs()
+                );
+            }
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use anyhow::{Context, Result};
+use colored::Colorize;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use std::sync::Arc;
+
+use super::{VaultClient, VaultSigner};
+use crate::crypto::algorithm::PqKey;
+use crate::solana::accounts::PqAccount;
+use crate::solana::receipt::{Operation, Receipt};
+use crate::solana::squads::{self, MultisigAccount, ProposalAccount, ProposalStatus, DEFAULT_VAULT_INDEX};
+
+/// Number of recent proposals shown by `multisig status`
+const RECENT_PROPOSALS: u64 = 5;
+
+impl VaultClient {
+    async fn get_multisig(&self, multisig: &Pubkey) -> Result<MultisigAccount> {
+        let account = self.rpc_client.get_account(multisig).await
+            .with_context(|| format!("Multisig {} not found", multisig))?;
+        MultisigAccount::parse(&account.data)
+    }
+
+    async fn get_proposal(&self, multisig: &Pubkey, index: u64) -> Result<ProposalAccount> {
+        let account = self.rpc_client.get_account(&squads::proposal_pda(multisig, index)).await
+            .with_context(|| format!("Proposal #{} not found", index))?;
+        ProposalAccount::parse(&account.data)
+    }
+
+    /// Store `instructions` as a vault transaction, open a proposal for it and cast the creator's approval
+    async fn create_vault_proposal(
+        &self,
+        multisig: &Pubkey,
+        member: &Arc<VaultSigner>,
+        instructions: &[Instruction],
+        receipt: &mut Receipt,
+    ) -> Result<u64> {
+        let account = self.get_multisig(multisig).await?;
+        if !account.members.contains(&member.pubkey()) {
+            anyhow::bail!("{} is not a member of multisig {}", member.pubkey(), multisig);
+        }
+
+        self.validate_instructions(instructions)?;
+
+        let index = account.transaction_index + 1;
+        let vault = squads::vault_pda(multisig, DEFAULT_VAULT_INDEX);
+        let message = squads::compile_vault_message(&vault, instructions);
+
+        let create_ix = squads::vault_transaction_create(multisig, index, &member.pubkey(), DEFAULT_VAULT_INDEX, &message);
+        let proposal_ix = squads::proposal_create(multisig, index, &member.pubkey());
+        let approve_ix = squads::proposal_approve(multisig, index, &member.pubkey());
+
+        receipt.record(self.send_instructions(&[create_ix, proposal_ix, approve_ix], &member.pubkey(), &[member]).await?);
+        receipt
+            .account("transaction", &squads::transaction_pda(multisig, index))
+            .account("proposal", &squads::proposal_pda(multisig, index));
+
+        println!("{}", "✅ Proposal Created".green().bold());
+        println!("   Index:     #{}", index.to_string().bright_white());
+        println!("   Approvals: 1 of {}", account.threshold);
+        println!();
+        if account.threshold > 1 {
+            println!("   Other members approve with: {}", format!("qdum-vault multisig approve --multisig {} --index {}", multisig, index).bright_green());
+        }
+        println!("   Once approved, run:         {}", format!("qdum-vault multisig execute --multisig {} --index {}", multisig, index).bright_green());
+        println!();
+
+        Ok(index)
+    }
+
+    /// Propose registering a PQ account owned by the multisig vault
+    ///
+    /// The public key is staged in a temporary account by the proposing member;
+    /// the proposal then initializes the PQ account and copies the key in.
+    pub async fn propose_register(&self, multisig: Pubkey, keypair_path: &str, sphincs_pubkey: &PqKey) -> Result<Receipt> {
+        let member = self.load_keypair(keypair_path)?;
+        let vault = squads::vault_pda(&multisig, DEFAULT_VAULT_INDEX);
+        let (pq_account, _) = self.derive_pq_account(vault);
+
+        println!("Multisig:         {}", multisig.to_string().cyan());
+        println!("Vault (owner):    {}", vault.to_string().cyan());
+        println!("PQ Account (PDA): {}", pq_account.to_string().cyan());
+        println!("Algorithm:        {}", sphincs_pubkey.algorithm.to_string().cyan());
+        println!();
+
+        let mut receipt = Receipt::new(Operation::MultisigPropose);
+        receipt
+            .account("multisig", &multisig)
+            .account("vault", &vault)
+            .account("pq_account", &pq_account);
+
+        if self.rpc_client.get_account(&pq_account).await.is_ok_and(|a| !a.data.is_empty()) {
+            println!("{}", "⚠️  PQ Account already registered for this multisig vault!".yellow());
+            return Ok(receipt.skipped("PQ account already registered"));
+        }
+
+        // Stage the public key in a temporary program-owned account
+        let temp_keypair: Arc<VaultSigner> = Arc::new(Keypair::new());
+        let pubkey_len = sphincs_pubkey.bytes.len();
+        let rent = self.rpc_client.get_minimum_balance_for_rent_exemption(pubkey_len).await?;
+
+        let create_account_ix = solana_sdk::system_instruction::create_account(
+            &member.pubkey(),
+            &temp_keypair.pubkey(),
+            rent,
+            pubkey_len as u64,
+            &self.program_id,
+        );
+
+        let mut write_data = self.idl.discriminator("write_data").to_vec();
+        write_data.extend_from_slice(&0u32.to_le_bytes());
+        write_data.extend_from_slice(&(pubkey_len as u32).to_le_bytes());
+        write_data.extend_from_slice(&sphincs_pubkey.bytes);
+
+        let write_data_ix = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(temp_keypair.pubkey(), false),
+                AccountMeta::new(member.pubkey(), true),
+            ],
+            data: write_data,
+        };
+
+        println!("Staging public key...");
+        receipt.record(self.send_instructions(&[create_account_ix, write_data_ix], &member.pubkey(), &[&member, &temp_keypair]).await?);
+        receipt.account("staged_public_key", &temp_keypair.pubkey());
+
+        let mut initialize_data = self.idl.discriminator("initialize_pq_account").to_vec();
+        initialize_data.push(sphincs_pubkey.algorithm.algorithm_id());
+
+        let instructions = [
+            Instruction {
+                program_id: self.program_id,
+                accounts: vec![
+                    AccountMeta::new(pq_account, false),
+                    AccountMeta::new(vault, true),
+                    AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+                ],
+                data: initialize_data,
+            },
+            Instruction {
+                program_id: self.program_id,
+                accounts: vec![
+                    AccountMeta::new(pq_account, false),
+                    AccountMeta::new(temp_keypair.pubkey(), false),
+                    AccountMeta::new(vault, true),
+                    AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+                ],
+                data: self.idl.discriminator("write_public_key").to_vec(),
+            },
+        ];
+
+        println!("Creating register proposal...");
+        self.create_vault_proposal(&multisig, &member, &instructions, &mut receipt).await?;
+
+        let vault_balance = self.rpc_client.get_balance(&vault).await.unwrap_or(0);
+        if vault_balance == 0 {
+            println!("{}", "⚠️  The multisig vault has no SOL; fund it before executing (it pays the PQ account rent).".yellow());
+            println!();
+        }
+
+        Ok(receipt.finish())
+    }
+
+    /// Propose locking the multisig vault's PQ account
+    pub async fn propose_lock(&self, multisig: Pubkey, keypair_path: &str) -> Result<Receipt> {
+        let member = self.load_keypair(keypair_path)?;
+        let vault = squads::vault_pda(&multisig, DEFAULT_VAULT_INDEX);
+        let (pq_account, _) = self.derive_pq_account(vault);
+
+        println!("Multisig:         {}", multisig.to_string().cyan());
+        println!("Vault (owner):    {}", vault.to_string().cyan());
+        println!("PQ Account (PDA): {}", pq_account.to_string().cyan());
+        println!();
+
+        let mut receipt = Receipt::new(Operation::MultisigPropose);
+        receipt
+            .account("multisig", &multisig)
+            .account("vault", &vault)
+            .account("pq_account", &pq_account);
+
+        let account_info = self.rpc_client.get_account(&pq_account).await
+            .context("PQ account not found! Propose registration first with: qdum-vault multisig propose register")?;
+        if PqAccount::parse(&account_info.data)?.tokens_locked {
+            println!("{}", "⚠️  Vault is already locked!".yellow());
+            return Ok(receipt.skipped("Vault already locked"));
+        }
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(pq_account, false),
+                AccountMeta::new_readonly(vault, true),
+            ],
+            data: self.idl.discriminator("lock_tokens").to_vec(),
+        };
+
+        println!("Creating lock proposal...");
+        self.create_vault_proposal(&multisig, &member, &[instruction], &mut receipt).await?;
+
+        Ok(receipt.finish())
+    }
+
+    /// Approve a pending proposal as a multisig member
+    pub async fn approve_proposal(&self, multisig: Pubkey, index: u64, keypair_path: &str) -> Result<Receipt> {
+        let member = self.load_keypair(keypair_path)?;
+        let account = self.get_multisig(&multisig).await?;
+        let proposal = self.get_proposal(&multisig, index).await?;
+
+        let mut receipt = Receipt::new(Operation::MultisigApprove);
+        receipt
+            .account("multisig", &multisig)
+            .account("proposal", &squads::proposal_pda(&multisig, index));
+
+        if proposal.status != ProposalStatus::Active {
+            anyhow::bail!("Proposal #{} is {}, not Active", index, proposal.status.label());
+        }
+        if proposal.approved.contains(&member.pubkey()) {
+            println!("{}", "⚠️  You have already approved this proposal.".yellow());
+            return Ok(receipt.skipped("Already approved"));
+        }
+
+        let instruction = squads::proposal_approve(&multisig, index, &member.pubkey());
+        let (signature, fee) = self.send_instructions(&[instruction], &member.pubkey(), &[&member]).await?;
+        receipt.record((signature, fee));
+
+        let approvals = proposal.approved.len() + 1;
+        println!("{}", "✅ Proposal Approved".green().bold());
+        println!("   Approvals:   {} of {}", approvals, account.threshold);
+        println!("   Transaction: {}", signature.to_string().cyan());
+        println!();
+        if approvals >= account.threshold as usize {
+            println!("   Threshold reached. Execute with: {}", format!("qdum-vault multisig execute --multisig {} --index {}", multisig, index).bright_green());
+            println!();
+        }
+
+        Ok(receipt.finish())
+    }
+
+    /// Execute an approved proposal
+    pub async fn execute_proposal(&self, multisig: Pubkey, index: u64, keypair_path: &str) -> Result<Receipt> {
+        let member = self.load_keypair(keypair_path)?;
+        let proposal = self.get_proposal(&multisig, index).await?;
+
+        let mut receipt = Receipt::new(Operation::MultisigExecute);
+        receipt
+            .account("multisig", &multisig)
+            .account("vault", &squads::vault_pda(&multisig, DEFAULT_VAULT_INDEX))
+            .account("proposal", &squads::proposal_pda(&multisig, index));
+
+        if proposal.status != ProposalStatus::Approved {
+            anyhow::bail!("Proposal #{} is {}, it must be Approved before executing", index, proposal.status.label());
+        }
+
+        let transaction_account = self.rpc_client.get_account(&squads::transaction_pda(&multisig, index)).await
+            .with_context(|| format!("Vault transaction #{} not found", index))?;
+        let accounts = squads::vault_transaction_accounts(&transaction_account.data)?;
+
+        let instruction = squads::vault_transaction_execute(&multisig, index, &member.pubkey(), accounts);
+        let (signature, fee) = self.send_instructions(&[instruction], &member.pubkey(), &[&member]).await?;
+        receipt.record((signature, fee));
+
+        println!("{}", "✅ Proposal Executed".green().bold());
+        println!("   Transaction: {}", signature.to_string().cyan());
+        println!("   View on Solscan: https://solscan.io/tx/{}?cluster=devnet", signature);
+        println!();
+
+        Ok(receipt.finish())
+    }
+
+    /// Show the multisig's vault, members and recent proposals
+    pub async fn multisig_status(&self, multisig: Pubkey) -> Result<()> {
+        use comfy_table::{presets::UTF8_FULL, Table};
+
+        let account = self.get_multisig(&multisig).await?;
+        let vault = squads::vault_pda(&multisig, DEFAULT_VAULT_INDEX);
+        let (pq_account, _) = self.derive_pq_account(vault);
+
+        let vault_state = match self.rpc_client.get_account(&pq_account).await {
+            Ok(info) => {
+                if PqAccount::parse(&info.data)?.tokens_locked {
+                    "LOCKED".red().bold().to_string()
+                } else {
+                    "UNLOCKED".green().bold().to_string()
+                }
+            }
+            Err(_) => "Not registered".yellow().to_string(),
+        };
+
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec![
+            "Property".bright_white().bold().to_string(),
+            "Value".bright_white().bold().to_string(),
+        ]);
+        table.add_row(vec!["Multisig".dimmed().to_string(), multisig.to_string().bright_cyan().to_string()]);
+        table.add_row(vec!["Vault (owner)".dimmed().to_string(), vault.to_string().bright_cyan().to_string()]);
+        table.add_row(vec!["PQ Account".dimmed().to_string(), pq_account.to_string().bright_cyan().to_string()]);
+        table.add_row(vec!["Vault Status".dimmed().to_string(), vault_state]);
+        table.add_row(vec!["Threshold".dimmed().to_string(), format!("{} of {}", account.threshold, account.members.len())]);
+        if account.time_lock > 0 {
+            table.add_row(vec!["Time Lock".dimmed().to_string(), format!("{}s", account.time_lock)]);
+        }
+        for (i, member) in account.members.iter().enumerate() {
+            table.add_row(vec![format!("Member {}", i + 1).dimmed().to_string(), member.to_string()]);
+        }
+        println!("{}", table);
+        println!();
+
+        if account.transaction_index == 0 {
+            println!("{} No proposals yet", "[i]".bright_blue());
+            println!();
+            return Ok(());
+        }
+
+        let mut proposals = Table::new();
+        proposals.load_preset(UTF8_FULL);
+        proposals.set_header(vec![
+            "#".bright_white().bold().to_string(),
+            "Status".bright_white().bold().to_string(),
+            "Approvals".bright_white().bold().to_string(),
+        ]);
+
+        let oldest = account.transaction_index.saturating_sub(RECENT_PROPOSALS - 1).max(1);
+        for index in (oldest..=account.transaction_index).rev() {
+            match self.get_proposal(&multisig, index).await {
+                Ok(proposal) => proposals.add_row(vec![
+                    index.to_string(),
+                    proposal.status.label().to_string(),
+                    format!("{} of {}", proposal.approved.len(), account.threshold),
+                ]),
+                Err(_) => proposals.add_row(vec![index.to_string(), "No proposal".dimmed().to_string(), "-".to_string()]),
+            };
+        }
+        println!("{}", proposals);
+        println!();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use anyhow::{Context, Result};
+use colored::Colorize;
+use solana_client::nonce_utils::nonblocking as nonce_utils;
+use solana_sdk::{
+    hash::Hash,
+    nonce::State,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use std::sync::Arc;
+
+use super::{VaultClient, VaultSigner};
+use crate::solana::receipt::{Operation, Receipt};
+
+/// Lamports per SOL, for balance display
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+/// Current state of a durable nonce account
+#[derive(Debug, Clone)]
+pub struct NonceInfo {
+    pub address: Pubkey,
+    /// Key that must sign `AdvanceNonceAccount` (and withdrawals)
+    pub authority: Pubkey,
+    /// Stored nonce, used in place of a recent blockhash
+    pub blockhash: Hash,
+    pub lamports_per_signature: u64,
+    pub balance: u64,
+}
+
+impl VaultClient {
+    /// Same client, but signing with a recent blockhash. Nonce management
+    /// must not consume the nonce it is creating or closing.
+    fn without_nonce(&self) -> Self {
+        Self { nonce_account: None, ..self.clone() }
+    }
+
+    /// Fetch and decode a nonce account
+    pub async fn get_nonce(&self, address: &Pubkey) -> Result<NonceInfo> {
+        let account = nonce_utils::get_account_with_commitment(&self.rpc_client, address, self.rpc_client.commitment())
+            .await
+            .with_context(|| format!("Nonce account {} not found", address))?;
+        let data = nonce_utils::data_from_account(&account)
+            .with_context(|| format!("{} is not an initialized nonce account", address))?;
+
+        Ok(NonceInfo {
+            address: *address,
+            authority: data.authority,
+            blockhash: data.blockhash(),
+            lamports_per_signature: data.get_lamports_per_signature(),
+            balance: account.lamports,
+        })
+    }
+
+    /// Create a rent-exempt nonce account with the wallet as its authority
+    pub async fn create_nonce_account(&self, keypair_path: &str) -> Result<Receipt> {
+        let keypair = self.load_keypair(keypair_path)?;
+        let nonce_keypair: Arc<VaultSigner> = Arc::new(Keypair::new());
+        let rent = self.rpc_client.get_minimum_balance_for_rent_exemption(State::size()).await?;
+
+        let mut receipt = Receipt::new(Operation::CreateNonce);
+        receipt
+            .account("wallet", &keypair.pubkey())
+            .account("nonce_account", &nonce_keypair.pubkey());
+
+        let instructions = solana_sdk::system_instruction::create_nonce_account(
+            &keypair.pubkey(),
+            &nonce_keypair.pubkey(),
+            &keypair.pubkey(),
+            rent,
+        );
+
+        let client = self.without_nonce();
+        receipt.record(client.send_instructions(&instructions, &keypair.pubkey(), &[&keypair, &nonce_keypair]).await?);
+
+        println!("{}", "✅ Nonce account created".green().bold());
+        println!("  {} {}", "Address:".bold(), nonce_keypair.pubkey().to_string().bright_cyan());
+        println!("  {} {:.6} SOL", "Rent deposit:".bold(), rent as f64 / LAMPORTS_PER_SOL);
+        println!();
+        println!("{}", "Use it for any command with:".bold());
+        println!("  {}", format!("qdum-vault --nonce {} <command>", nonce_keypair.pubkey()).bright_cyan());
+        println!();
+
+        Ok(receipt.finish())
+    }
+
+    /// Withdraw the full balance of a nonce account, closing it
+    pub async fn close_nonce_account(&self, address: Pubkey, keypair_path: &str, receiver: Option<Pubkey>) -> Result<Receipt> {
+        let keypair = self.load_keypair(keypair_path)?;
+        let info = self.get_nonce(&address).await?;
+
+        if info.authority != keypair.pubkey() {
+            anyhow::bail!(
+                "This wallet ({}) is not the nonce authority ({})",
+                keypair.pubkey(),
+                info.authority
+            );
+        }
+
+        let receiver = receiver.unwrap_or_else(|| keypair.pubkey());
+
+        let mut receipt = Receipt::new(Operation::CloseNonce);
+        receipt
+            .account("wallet", &keypair.pubkey())
+            .account("nonce_account", &address)
+            .account("receiver", &receiver);
+
+        let instruction = solana_sdk::system_instruction::withdraw_nonce_account(&address, &keypair.pubkey(), &receiver, info.balance);
+
+        let client = self.without_nonce();
+        receipt.record(client.send_instructions(&[instruction], &keypair.pubkey(), &[&keypair]).await?);
+
+        println!("{}", "✅ Nonce account closed".green().bold());
+        println!("  {} {:.6} SOL to {}", "Reclaimed:".bold(), info.balance as f64 / LAMPORTS_PER_SOL, receiver.to_string().cyan());
+        println!();
+
+        Ok(receipt.finish())
+    }
+
+    /// Print a nonce account's authority and stored nonce
+    pub async fn nonce_status(&self, address: Pubkey) -> Result<()> {
+        let info = self.get_nonce(&address).await?;
+
+        use comfy_table::{presets::UTF8_FULL, Table};
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec![
+            "Property".bright_white().bold().to_string(),
+            "Value".bright_white().bold().to_string(),
+        ]);
+        table.add_row(vec!["Nonce Account".dimmed().to_string(), info.address.to_string().bright_cyan().to_string()]);
+        table.add_row(vec!["Authority".dimmed().to_string(), info.authority.to_string().bright_cyan().to_string()]);
+        table.add_row(vec!["Nonce".dimmed().to_string(), info.blockhash.to_string()]);
+        table.add_row(vec!["Fee per Signature".dimmed().to_string(), format!("{} lamports", info.lamports_per_signature)]);
+        table.add_row(vec!["Balance".dimmed().to_string(), format!("{:.6} SOL", info.balance as f64 / LAMPORTS_PER_SOL)]);
+
+        println!("{}", table);
+        println!();
+
+        Ok(())
+    }
+}
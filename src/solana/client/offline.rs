@@ -0,0 +1,116 @@
+//! Unsigned transactions, for signing somewhere else
+//!
+//! With an export directory set, transfer, lock and close write their
+//! transaction there as base64-encoded bincode (the wire format `solana` and
+//! web3.js decode) instead of signing and sending it. Once every signer has
+//! signed, `broadcast` submits the files. A blockhash expires after about a
+//! minute, so slower signing rounds need a durable nonce (`--nonce`).
+
+use anyhow::{Context, Result};
+use base64::Engine as _;
+use colored::Colorize;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Signature, transaction::Transaction};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::VaultClient;
+use crate::solana::receipt::Receipt;
+
+/// Encode a transaction the way `broadcast` and `solana` tooling read it
+pub fn encode_transaction(transaction: &Transaction) -> Result<String> {
+    let bytes = bincode::serialize(transaction).context("Failed to serialize transaction")?;
+    Ok(base64::engine::general_purpose::STANDARD.encode(bytes))
+}
+
+pub fn decode_transaction(encoded: &str) -> Result<Transaction> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(encoded.trim())
+        .context("Transaction is not valid base64")?;
+    bincode::deserialize(&bytes).context("Not a serialized transaction")
+}
+
+/// Signers `transaction` still needs a signature from
+pub fn missing_signers(transaction: &Transaction) -> Vec<Pubkey> {
+    let required = transaction.message.header.num_required_signatures as usize;
+    transaction
+        .message
+        .account_keys
+        .iter()
+        .zip(&transaction.signatures)
+        .take(required)
+        .filter(|(_, signature)| **signature == Signature::default())
+        .map(|(key, _)| *key)
+        .collect()
+}
+
+impl VaultClient {
+    /// Write `instructions` to the export directory as an unsigned transaction
+    /// paid for by `payer`, and note the file in `receipt`
+    pub(super) async fn export_unsigned(&self, instructions: &[Instruction], payer: &Pubkey, mut receipt: Receipt) -> Result<Receipt> {
+        let dir = self.unsigned_out.as_deref().context("No unsigned transaction directory set")?;
+        let transaction = self.unsigned_transaction(instructions, payer).await?;
+
+        fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+        let path = export_path(dir, &receipt);
+        fs::write(&path, encode_transaction(&transaction)?).with_context(|| format!("Failed to write {}", path.display()))?;
+
+        println!("{}", "📝 Unsigned transaction exported".green().bold());
+        println!("   File:    {}", path.display().to_string().cyan());
+        println!("   Signers: {}", missing_signers(&transaction).iter().map(|key| key.to_string()).collect::<Vec<_>>().join(", "));
+        println!();
+        println!("   Once signed, submit it with: {}", format!("qdum-vault broadcast {}", path.display()).bright_green());
+        println!();
+
+        receipt.note = Some(format!("Unsigned transaction written to {}", path.display()));
+        Ok(receipt.finish())
+    }
+
+    /// Submit a transaction every signer has already signed
+    pub async fn broadcast_transaction(&self, transaction: &Transaction) -> Result<(Signature, u64)> {
+        self.policy.check_signing()?;
+        let missing = missing_signers(transaction);
+        if !missing.is_empty() {
+            let missing: Vec<String> = missing.iter().map(|key| key.to_string()).collect();
+            anyhow::bail!("Transaction is missing signatures from {}", missing.join(", "));
+        }
+        transaction.verify().context("Transaction has an invalid signature")?;
+        self.send_with_fee(transaction).await
+    }
+}
+
+/// `<operation>-<unix time>.b64`, numbered if the same operation was exported
+/// within the same second
+fn export_path(dir: &Path, receipt: &Receipt) -> PathBuf {
+    let name = receipt.operation.label().to_lowercase().replace(' ', "-");
+    let stamp = chrono::Utc::now().timestamp();
+    let mut path = dir.join(format!("{}-{}.b64", name, stamp));
+    let mut n = 2;
+    while path.exists() {
+        path = dir.join(format!("{}-{}-{}.b64", name, stamp, n));
+        n += 1;
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{hash::Hash, message::Message, signature::Keypair, signer::Signer, system_instruction};
+
+    #[test]
+    fn test_unsigned_round_trip() {
+        let payer = Keypair::new();
+        let instruction = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+        let message = Message::new_with_blockhash(&[instruction], Some(&payer.pubkey()), &Hash::new_unique());
+        let mut transaction = Transaction::new_unsigned(message);
+
+        let decoded = decode_transaction(&encode_transaction(&transaction).unwrap()).unwrap();
+        assert_eq!(decoded, transaction);
+        assert_eq!(missing_signers(&decoded), vec![payer.pubkey()]);
+
+        let blockhash = transaction.message.recent_blockhash;
+        transaction.sign(&[&payer], blockhash);
+        assert!(missing_signers(&transaction).is_empty());
+        assert!(decode_transaction("not base64!").is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use anyhow::{Context, Result};
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::{UiTransactionEncoding, UiTransactionTokenBalance};
+use std::str::FromStr;
+
+use super::VaultClient;
+use crate::solana::pay::TransferRequest;
+
+/// A transaction that paid a Solana Pay request
+#[derive(Debug, Clone, PartialEq)]
+pub struct Payment {
+    pub signature: Signature,
+    /// Base units the recipient received
+    pub amount: u64,
+    /// Unix seconds, if the RPC knows it
+    pub block_time: Option<i64>,
+}
+
+impl VaultClient {
+    /// The first successful transaction carrying `request`'s reference that
+    /// left the recipient at least the requested amount richer, if one has
+    /// landed yet. Underpayments are skipped rather than reported.
+    pub async fn find_payment(&self, request: &TransferRequest) -> Result<Option<Payment>> {
+        let reference = request.reference.context("The payment request has no reference")?;
+        let mint = request.spl_token.context("The payment request has no token")?;
+        let signatures = self
+            .rpc_client
+            .get_signatures_for_address(&reference)
+            .await
+            .context("Failed to look up the payment reference")?;
+
+        // Oldest first, so a later duplicate payment isn't the one reported
+        for status in signatures.iter().rev().filter(|status| status.err.is_none()) {
+            let signature = Signature::from_str(&status.signature)?;
+            let config = RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Json),
+                commitment: Some(self.rpc_client.commitment()),
+                max_supported_transaction_version: Some(0),
+            };
+            let transaction = self
+                .rpc_client
+                .get_transaction_with_config(&signature, config)
+                .await
+                .with_context(|| format!("Failed to fetch transaction {}", signature))?;
+            let Some(meta) = transaction.transaction.meta.as_ref() else {
+                continue;
+            };
+
+            let (recipient, mint) = (request.recipient.to_string(), mint.to_string());
+            let pre = token_total(Option::<&Vec<_>>::from(meta.pre_token_balances.as_ref()), &recipient, &mint);
+            let post = token_total(Option::<&Vec<_>>::from(meta.post_token_balances.as_ref()), &recipient, &mint);
+            let received = post.saturating_sub(pre);
+            if received > 0 && received >= request.amount.unwrap_or(0) {
+                return Ok(Some(Payment { signature, amount: received, block_time: transaction.block_time }));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// What `owner` holds of `mint` across the transaction's token accounts
+fn token_total(balances: Option<&Vec<UiTransactionTokenBalance>>, owner: &str, mint: &str) -> u64 {
+    balances
+        .into_iter()
+        .flatten()
+        .filter(|balance| balance.mint == mint)
+        .filter(|balance| Option::<&String>::from(balance.owner.as_ref()).is_some_and(|holder| holder == owner))
+        .filter_map(|balance| balance.ui_token_amount.amount.parse::<u64>().ok())
+        .sum()
+}
@@ -0,0 +1,93 @@
+use anyhow::{Context, Result};
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use spl_token_2022::extension::StateWithExtensions;
+
+use super::{VaultClient, SPL_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID};
+
+/// What a transfer recipient is on chain. Transfers go to the recipient's
+/// associated token account, so only a wallet (or a PDA meant to hold
+/// tokens) makes sense as the recipient.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecipientKind {
+    /// System-owned account, or `exists: false` if nothing is there yet
+    Wallet { exists: bool },
+    /// A token account; the tokens belong to `owner`
+    TokenAccount { owner: Pubkey, mint: Pubkey },
+    Program,
+    /// A data account owned by `program`, e.g. a PDA
+    ProgramAccount { program: Pubkey },
+}
+
+impl VaultClient {
+    pub async fn recipient_kind(&self, address: Pubkey) -> Result<RecipientKind> {
+        let account = self
+            .rpc_client
+            .get_account_with_commitment(&address, self.rpc_client.commitment())
+            .await
+            .context("Failed to look up recipient")?
+            .value;
+        Ok(classify(account.as_ref()))
+    }
+}
+
+fn classify(account: Option<&Account>) -> RecipientKind {
+    let Some(account) = account else {
+        return RecipientKind::Wallet { exists: false };
+    };
+    if account.executable {
+        return RecipientKind::Program;
+    }
+    if account.owner == solana_sdk::system_program::id() {
+        return RecipientKind::Wallet { exists: true };
+    }
+    if account.owner == SPL_TOKEN_PROGRAM_ID || account.owner == TOKEN_2022_PROGRAM_ID {
+        // Mints with extensions can be as large as a token account; unpacking
+        // checks the account type
+        if let Ok(token_account) = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data) {
+            return RecipientKind::TokenAccount { owner: token_account.base.owner, mint: token_account.base.mint };
+        }
+    }
+    RecipientKind::ProgramAccount { program: account.owner }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::program_pack::Pack;
+    use spl_token_2022::extension::{
+        mint_close_authority::MintCloseAuthority, BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+    };
+    use spl_token_2022::state::{AccountState, Mint};
+
+    #[test]
+    fn test_classify_recipient() {
+        assert_eq!(classify(None), RecipientKind::Wallet { exists: false });
+
+        let wallet = Account::new(1_000_000, 0, &solana_sdk::system_program::id());
+        assert_eq!(classify(Some(&wallet)), RecipientKind::Wallet { exists: true });
+
+        let (mint, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut token_account = Account::new(2_039_280, spl_token_2022::state::Account::LEN, &TOKEN_2022_PROGRAM_ID);
+        let state = spl_token_2022::state::Account { mint, owner, state: AccountState::Initialized, ..Default::default() };
+        state.pack_into_slice(&mut token_account.data);
+        assert_eq!(classify(Some(&token_account)), RecipientKind::TokenAccount { owner, mint });
+
+        // A mint is owned by the token program too, but is too small to be a token account
+        let mint_account = Account::new(1_461_600, Mint::LEN, &SPL_TOKEN_PROGRAM_ID);
+        assert_eq!(classify(Some(&mint_account)), RecipientKind::ProgramAccount { program: SPL_TOKEN_PROGRAM_ID });
+    }
+
+    #[test]
+    fn test_extension_mint_is_not_a_token_account() {
+        let space = ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::MintCloseAuthority]).unwrap();
+        let mut mint_account = Account::new(2_000_000, space, &TOKEN_2022_PROGRAM_ID);
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut mint_account.data).unwrap();
+        state.base = Mint { decimals: 6, is_initialized: true, ..Default::default() };
+        state.pack_base();
+        state.init_extension::<MintCloseAuthority>(true).unwrap();
+        state.init_account_type().unwrap();
+
+        assert!(mint_account.data.len() > spl_token_2022::state::Account::LEN);
+        assert_eq!(classify(Some(&mint_account)), RecipientKind::ProgramAccount { program: TOKEN_2022_PROGRAM_ID });
+    }
+}
@@ -0,0 +1,133 @@
+//! Resuming an interrupted unlock
+//!
+//! A SPHINCS+ unlock takes dozens of transactions. The dashboard records how
+//! far it got in an [`UnlockSession`], so after a crash or a closed terminal
+//! the next unlock can pick up where the on-chain verification stopped.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::fs;
+use std::path::PathBuf;
+
+use super::{storage_identifier, unlock_transaction_plan, VaultClient};
+use crate::crypto::algorithm::Algorithm;
+use crate::paths;
+use crate::solana::accounts::{SignatureStorage, VerificationState};
+
+/// Progress of an unlock, stored per wallet in the data directory until it finishes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnlockSession {
+    pub wallet: String,
+    /// Storage identifier the signature and verification PDAs are seeded with
+    pub identifier: String,
+    pub signature_storage: String,
+    pub verification_state: String,
+    /// Steps finished so far, out of `total_steps`
+    pub completed_step: usize,
+    pub total_steps: usize,
+    /// RFC 3339
+    pub updated_at: String,
+}
+
+impl UnlockSession {
+    /// Session left behind by an unfinished unlock of `wallet`, if any
+    pub fn load(wallet: &Pubkey) -> Result<Option<Self>> {
+        let path = Self::path(wallet);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let data = fs::read_to_string(&path).context("Failed to read unlock session")?;
+        serde_json::from_str(&data).map(Some).context("Failed to parse unlock session")
+    }
+
+    pub fn save(&mut self) -> Result<()> {
+        self.updated_at = chrono::Utc::now().to_rfc3339();
+        let path = Self::path(&self.wallet.parse()?);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).context("Failed to create unlock session directory")?;
+        }
+
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize unlock session")?;
+        fs::write(&path, json).context("Failed to write unlock session")
+    }
+
+    /// Forget the session, once the unlock finished or the user discarded it
+    pub fn clear(wallet: &Pubkey) -> Result<()> {
+        let path = Self::path(wallet);
+        if path.exists() {
+            fs::remove_file(&path).context("Failed to remove unlock session")?;
+        }
+        Ok(())
+    }
+
+    fn path(wallet: &Pubkey) -> PathBuf {
+        paths::data_dir().join("unlock-sessions").join(format!("{}.json", wallet))
+    }
+}
+
+impl VaultClient {
+    /// A fresh session for unlocking `wallet` with `public_key`, paid for by `payer`
+    pub fn unlock_session(&self, wallet: Pubkey, payer: Pubkey, algorithm: Algorithm, public_key: &[u8]) -> UnlockSession {
+        let identifier = storage_identifier(public_key);
+        let (signature_storage, _) =
+            Pubkey::find_program_address(&[b"sphincs_sig", payer.as_ref(), identifier.as_bytes()], &self.program_id);
+        let (verification_state, _) =
+            Pubkey::find_program_address(&[b"sphincs_verify", payer.as_ref(), identifier.as_bytes()], &self.program_id);
+        let (upload_steps, verify_steps) = unlock_transaction_plan(algorithm);
+
+        UnlockSession {
+            wallet: wallet.to_string(),
+            identifier,
+            signature_storage: signature_storage.to_string(),
+            verification_state: verification_state.to_string(),
+            completed_step: 0,
+            total_steps: 1 + upload_steps + verify_steps,
+            updated_at: String::new(),
+        }
+    }
+
+    /// How many of the `completed` steps an unlock can actually skip.
+    ///
+    /// The signature is regenerated unless it was fully uploaded, and nothing
+    /// is skipped once the on-chain accounts belong to a different challenge.
+    pub(super) async fn resume_point(
+        &self,
+        completed: usize,
+        challenge: &[u8],
+        algorithm: Algorithm,
+        signature_storage: &Pubkey,
+        verification_state: &Pubkey,
+    ) -> usize {
+        let (upload_steps, _) = unlock_transaction_plan(algorithm);
+        let uploaded = 1 + upload_steps;
+        if completed < uploaded {
+            return 0;
+        }
+
+        let storage = match self.rpc_client.get_account(signature_storage).await {
+            Ok(account) => SignatureStorage::parse(&account.data).ok(),
+            Err(_) => None,
+        };
+        let signature_ready = storage.is_some_and(|storage| {
+            storage.message == challenge && storage.bytes_written as usize >= algorithm.signature_size()
+        });
+        if !signature_ready {
+            return 0;
+        }
+        if completed == uploaded {
+            return uploaded;
+        }
+
+        let verification = match self.rpc_client.get_account(verification_state).await {
+            Ok(account) => VerificationState::parse(&account.data).ok(),
+            Err(_) => None,
+        };
+        match verification {
+            Some(state) if state.message == challenge && !state.verified => completed,
+            // Verification starts over from its own init step
+            _ => uploaded,
+        }
+    }
+}
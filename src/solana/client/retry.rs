@@ -0,0 +1,254 @@
+//! Retrying sends that timed out or whose blockhash expired
+//!
+//! A send that times out may still land. The transaction is only re-signed
+//! with a fresh blockhash once the old one has expired and the old signature
+//! is confirmed not to have landed; if either can't be checked the send stops
+//! rather than risk executing it twice.
+
+use anyhow::Result;
+use colored::Colorize;
+use solana_client::client_error::{ClientError, ClientErrorKind};
+use solana_client::rpc_request::RpcError;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    signature::Signature,
+    signers::Signers,
+    transaction::{Transaction, TransactionError},
+};
+
+use super::VaultClient;
+use crate::exit_code::ExitKind;
+
+/// Whether a failed send is worth sending again: the blockhash expired, the
+/// RPC timed out, or the connection dropped
+fn is_retryable(error: &anyhow::Error) -> bool {
+    let Some(error) = error.downcast_ref::<ClientError>() else {
+        return false;
+    };
+    if error.get_transaction_error() == Some(TransactionError::BlockhashNotFound) {
+        return true;
+    }
+    match error.kind() {
+        ClientErrorKind::Io(_) | ClientErrorKind::Reqwest(_) => true,
+        // send_and_confirm gives up with this once the blockhash has expired
+        ClientErrorKind::RpcError(RpcError::ForUser(message)) => message.contains("unable to confirm transaction"),
+        _ => false,
+    }
+}
+
+impl VaultClient {
+    /// Send a signed transaction with exponential backoff on expiry and
+    /// timeouts. It is re-signed with a fresh blockhash only once the old one
+    /// has expired and the old signature hasn't landed, so a slow first
+    /// attempt is never executed twice.
+    pub(super) async fn send_with_retry<T: Signers + ?Sized>(&self, transaction: &Transaction, signers: &T) -> Result<(Signature, u64)> {
+        // Queued bundle transactions are confirmed together; a retry here would duplicate them
+        if self.bundle_queue.is_some() {
+            return self.send_with_fee(transaction).await;
+        }
+
+        let max_attempts = self.retry.max_attempts.max(1);
+        let mut transaction = transaction.clone();
+        let mut attempt = 1;
+        loop {
+            let error = match self.send_with_fee(&transaction).await {
+                Ok(sent) => return Ok(sent),
+                Err(e) if attempt < max_attempts && is_retryable(&e) => e,
+                Err(e) => return Err(e),
+            };
+
+            let delay = self.retry.base_delay * 2u32.saturating_pow(attempt - 1);
+            attempt += 1;
+            let message = format!(
+                "Send failed ({}), retrying in {:.1}s (attempt {}/{})",
+                error,
+                delay.as_secs_f64(),
+                attempt,
+                max_attempts
+            );
+            match &self.reporter {
+                Some(reporter) => reporter.retry(&message),
+                None => eprintln!("{} {}", "[retry]".yellow(), message),
+            }
+            tokio::time::sleep(delay).await;
+
+            // Once the blockhash has expired the old transaction can't land any
+            // more, so its status below is final
+            let signature = transaction.signatures[0];
+            let expired = match self
+                .rpc_client
+                .is_blockhash_valid(&transaction.message.recent_blockhash, CommitmentConfig::processed())
+                .await
+            {
+                Ok(valid) => !valid,
+                Err(e) => return Err(unknown_outcome(signature, e)),
+            };
+            match self
+                .rpc_client
+                .get_signature_status_with_commitment_and_history(&signature, CommitmentConfig::processed(), true)
+                .await
+            {
+                Ok(Some(Ok(()))) => {
+                    let fee = self.rpc_client.get_fee_for_message(transaction.message()).await.unwrap_or(0);
+                    return Ok((signature, fee));
+                }
+                Ok(Some(Err(e))) => return Err(self.explain_failure(e.into(), &transaction)),
+                Ok(None) => {}
+                Err(e) => return Err(unknown_outcome(signature, e)),
+            }
+
+            // Still valid: resending the same signed transaction can't execute it twice
+            if expired {
+                let fresh = match self.nonce_account {
+                    Some(nonce_account) => self.get_nonce(&nonce_account).await?.blockhash,
+                    None => self.rpc_client.get_latest_blockhash().await?,
+                };
+                transaction.try_sign(signers, fresh)?;
+            }
+        }
+    }
+}
+
+/// A send that may or may not have landed, which must not be re-signed
+fn unknown_outcome(signature: Signature, error: ClientError) -> anyhow::Error {
+    ExitKind::Rpc.error(format!(
+        "Couldn't tell whether transaction {} landed ({}); check it on an explorer before sending again",
+        signature, error
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+    use solana_client::nonblocking::rpc_client::RpcClient;
+    use solana_client::rpc_client::RpcClientConfig;
+    use solana_client::rpc_request::RpcRequest;
+    use solana_client::rpc_sender::{RpcSender, RpcTransportStats};
+    use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use crate::solana::client::RetryPolicy;
+
+    /// Scripted RPC: sends always time out, and the signature lands after
+    /// `pending_statuses` status checks
+    #[derive(Default)]
+    struct MockRpc {
+        requests: Mutex<Vec<RpcRequest>>,
+        pending_statuses: Mutex<usize>,
+        blockhash_valid: Mutex<Vec<bool>>,
+        blockhash_check_fails: bool,
+    }
+
+    impl MockRpc {
+        fn count(&self, request: RpcRequest) -> usize {
+            self.requests.lock().unwrap().iter().filter(|r| **r == request).count()
+        }
+    }
+
+    struct MockSender(Arc<MockRpc>);
+
+    #[async_trait::async_trait]
+    impl RpcSender for MockSender {
+        async fn send(&self, request: RpcRequest, _params: Value) -> solana_client::client_error::Result<Value> {
+            let rpc = &self.0;
+            rpc.requests.lock().unwrap().push(request);
+            let context = json!({ "slot": 1 });
+            match request {
+                RpcRequest::SendTransaction => {
+                    Err(std::io::Error::new(std::io::ErrorKind::TimedOut, "operation timed out").into())
+                }
+                RpcRequest::GetFeeForMessage => Ok(json!({ "context": context, "value": 5000 })),
+                RpcRequest::IsBlockhashValid if rpc.blockhash_check_fails => {
+                    Err(std::io::Error::new(std::io::ErrorKind::ConnectionReset, "connection reset").into())
+                }
+                RpcRequest::IsBlockhashValid => {
+                    let mut valid = rpc.blockhash_valid.lock().unwrap();
+                    let value = if valid.is_empty() { false } else { valid.remove(0) };
+                    Ok(json!({ "context": context, "value": value }))
+                }
+                RpcRequest::GetSignatureStatuses => {
+                    let mut pending = rpc.pending_statuses.lock().unwrap();
+                    if *pending > 0 {
+                        *pending -= 1;
+                        return Ok(json!({ "context": context, "value": [null] }));
+                    }
+                    Ok(json!({
+                        "context": context,
+                        "value": [{ "slot": 1, "confirmations": null, "err": null, "status": { "Ok": null }, "confirmationStatus": "confirmed" }],
+                    }))
+                }
+                RpcRequest::GetLatestBlockhash => Ok(json!({
+                    "context": context,
+                    "value": { "blockhash": Hash::new_unique().to_string(), "lastValidBlockHeight": 100 },
+                })),
+                other => panic!("unexpected request {}", other),
+            }
+        }
+
+        fn get_transport_stats(&self) -> RpcTransportStats {
+            RpcTransportStats::default()
+        }
+
+        fn url(&self) -> String {
+            "mock".to_string()
+        }
+    }
+
+    fn client(rpc: &Arc<MockRpc>) -> VaultClient {
+        let mut client = VaultClient::builder()
+            .retry_policy(RetryPolicy { max_attempts: 3, base_delay: Duration::from_millis(1) })
+            .build()
+            .unwrap();
+        client.rpc_client = Arc::new(RpcClient::new_sender(MockSender(rpc.clone()), RpcClientConfig::default()));
+        client
+    }
+
+    fn transfer(payer: &Keypair) -> Transaction {
+        let ix = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+        Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[payer], Hash::new_unique())
+    }
+
+    #[tokio::test]
+    async fn test_timed_out_send_that_lands_is_not_resigned() {
+        // Still valid after the first timeout, so the same transaction is resent;
+        // after the second the blockhash has expired but the first send landed
+        let rpc = Arc::new(MockRpc {
+            pending_statuses: Mutex::new(1),
+            blockhash_valid: Mutex::new(vec![true, false]),
+            ..Default::default()
+        });
+        let payer = Keypair::new();
+        let transaction = transfer(&payer);
+
+        let (signature, fee) = client(&rpc).send_with_retry(&transaction, &[&payer]).await.unwrap();
+        assert_eq!(signature, transaction.signatures[0]);
+        assert_eq!(fee, 5000);
+        assert_eq!(rpc.count(RpcRequest::SendTransaction), 2);
+        assert_eq!(rpc.count(RpcRequest::GetLatestBlockhash), 0);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_outcome_is_not_resigned() {
+        let rpc = Arc::new(MockRpc { blockhash_check_fails: true, ..Default::default() });
+        let payer = Keypair::new();
+        let transaction = transfer(&payer);
+
+        let error = client(&rpc).send_with_retry(&transaction, &[&payer]).await.unwrap_err();
+        assert!(error.to_string().contains(&transaction.signatures[0].to_string()));
+        assert_eq!(rpc.count(RpcRequest::SendTransaction), 1);
+        assert_eq!(rpc.count(RpcRequest::GetLatestBlockhash), 0);
+    }
+
+    #[tokio::test]
+    async fn test_expired_send_that_never_landed_is_resigned() {
+        let rpc = Arc::new(MockRpc { pending_statuses: Mutex::new(usize::MAX), ..Default::default() });
+        let payer = Keypair::new();
+        let transaction = transfer(&payer);
+
+        assert!(client(&rpc).send_with_retry(&transaction, &[&payer]).await.is_err());
+        assert_eq!(rpc.count(RpcRequest::SendTransaction), 3);
+        assert_eq!(rpc.count(RpcRequest::GetLatestBlockhash), 2);
+    }
+}
@@ -0,0 +1,247 @@
+//! Machine-readable vault status
+//!
+//! `status --format json|yaml|toml` prints a [`StatusReport`] for monitoring
+//! scripts. Fields are only ever added to it; a removal or change of meaning
+//! bumps [`STATUS_SCHEMA_VERSION`]. `status --watch` polls it and reports each
+//! [`StatusChange`].
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_sdk::pubkey::Pubkey;
+use std::fmt;
+
+use super::{get_associated_token_address, storage_identifier, VaultClient, SPL_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID};
+use crate::solana::accounts::PqAccount;
+
+/// Version of the [`StatusReport`] layout
+pub const STATUS_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatusReport {
+    pub schema_version: u32,
+    pub wallet: String,
+    /// PQ account PDA
+    pub pq_account: String,
+    pub locked: bool,
+    /// Algorithm name, absent if this CLI doesn't know the registered algorithm
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<String>,
+    pub algorithm_id: u8,
+    /// Current unlock challenge (hex)
+    pub unlock_challenge: String,
+    /// Slot a delayed unlock takes effect at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_unlock_slot: Option<u64>,
+    pub sol_lamports: u64,
+    pub tokens: Vec<TokenBalance>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TokenBalance {
+    pub mint: String,
+    /// Base units
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+/// Difference between two polls of the same vault
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum StatusChange {
+    Locked,
+    Unlocked,
+    /// A delayed unlock was requested and takes effect at `slot`
+    UnlockScheduled { slot: u64 },
+    ChallengeRotated { challenge: String },
+    SolBalanceChanged { from: u64, to: u64 },
+    TokenBalanceChanged { mint: String, from: u64, to: u64, decimals: u8 },
+}
+
+impl fmt::Display for StatusChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatusChange::Locked => write!(f, "vault locked"),
+            StatusChange::Unlocked => write!(f, "vault unlocked"),
+            StatusChange::UnlockScheduled { slot } => write!(f, "unlock scheduled for slot {}", slot),
+            StatusChange::ChallengeRotated { challenge } => write!(f, "challenge rotated to {}…", &challenge[..16]),
+            StatusChange::SolBalanceChanged { from, to } => {
+                write!(f, "SOL {:.9} → {:.9}", *from as f64 / 1e9, *to as f64 / 1e9)
+            }
+            StatusChange::TokenBalanceChanged { mint, from, to, decimals } => {
+                let scale = 10f64.powi(*decimals as i32);
+                write!(f, "{}… {} → {}", &mint[..4], *from as f64 / scale, *to as f64 / scale)
+            }
+        }
+    }
+}
+
+/// What a wallet has on chain besides its balances, for comparing vaults
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VaultFootprint {
+    /// Block time of the wallet's latest transaction
+    pub last_activity: Option<i64>,
+    /// Rent held by the wallet's PQ account and unlock staging PDAs,
+    /// refunded when the vault is closed
+    pub pda_lamports: u64,
+    pub pda_count: usize,
+}
+
+impl StatusReport {
+    /// What changed since `previous` (a poll of the same wallet and mints)
+    pub fn changes_since(&self, previous: &StatusReport) -> Vec<StatusChange> {
+        let mut changes = Vec::new();
+        if self.locked != previous.locked {
+            changes.push(if self.locked { StatusChange::Locked } else { StatusChange::Unlocked });
+        }
+        if let Some(slot) = self.pending_unlock_slot.filter(|_| self.pending_unlock_slot != previous.pending_unlock_slot) {
+            changes.push(StatusChange::UnlockScheduled { slot });
+        }
+        if self.unlock_challenge != previous.unlock_challenge {
+            changes.push(StatusChange::ChallengeRotated { challenge: self.unlock_challenge.clone() });
+        }
+        if self.sol_lamports != previous.sol_lamports {
+            changes.push(StatusChange::SolBalanceChanged { from: previous.sol_lamports, to: self.sol_lamports });
+        }
+        for token in &self.tokens {
+            let from = previous
+                .tokens
+                .iter()
+                .find(|old| old.mint == token.mint)
+                .map_or(0, |old| old.amount);
+            if from != token.amount {
+                changes.push(StatusChange::TokenBalanceChanged {
+                    mint: token.mint.clone(),
+                    from,
+                    to: token.amount,
+                    decimals: token.decimals,
+                });
+            }
+        }
+        changes
+    }
+}
+
+impl VaultClient {
+    /// Status of `wallet`'s vault plus its SOL and token balances for each of `mints`
+    pub async fn status_report(&self, wallet: Pubkey, mints: &[Pubkey]) -> Result<StatusReport> {
+        let (pq_account, _) = self.derive_pq_account(wallet);
+        let account_info = self
+            .get_account_cached(&pq_account)
+            .await
+            .context("PQ account not found! Register first with: qdum-vault register")?;
+        let account = PqAccount::parse(&account_info.data)?;
+
+        let mut tokens = Vec::with_capacity(mints.len());
+        for &mint in mints {
+            tokens.push(TokenBalance {
+                mint: mint.to_string(),
+                amount: self.get_balance(wallet, mint).await?,
+                decimals: self.mint_decimals(mint).await?,
+            });
+        }
+
+        Ok(StatusReport {
+            schema_version: STATUS_SCHEMA_VERSION,
+            wallet: wallet.to_string(),
+            pq_account: pq_account.to_string(),
+            locked: account.tokens_locked,
+            algorithm: account.algorithm().map(|algorithm| algorithm.to_string()),
+            algorithm_id: account.algorithm,
+            unlock_challenge: hex::encode(account.unlock_challenge),
+            pending_unlock_slot: account.pending_unlock(),
+            sol_lamports: self.get_sol_balance(wallet).await?,
+            tokens,
+        })
+    }
+
+    /// Latest activity of `wallet` and the rent parked in its vault PDAs
+    pub async fn vault_footprint(&self, wallet: Pubkey) -> Result<VaultFootprint> {
+        let config = GetConfirmedSignaturesForAddress2Config {
+            limit: Some(1),
+            commitment: Some(self.rpc_client.commitment()),
+            ..Default::default()
+        };
+        let latest = self
+            .rpc_client
+            .get_signatures_for_address_with_config(&wallet, config)
+            .await
+            .context("Failed to list wallet transactions")?;
+
+        // The staging PDAs are seeded with the PQ key, so only a registered vault has them
+        let (pq_account, _) = self.derive_pq_account(wallet);
+        let mut addresses = vec![pq_account];
+        if let Ok(account_info) = self.get_account_cached(&pq_account).await {
+            let identifier = storage_identifier(&PqAccount::parse(&account_info.data)?.public_key);
+            for seed in [&b"sphincs_sig"[..], b"sphincs_verify"] {
+                let (pda, _) = Pubkey::find_program_address(&[seed, wallet.as_ref(), identifier.as_bytes()], &self.program_id);
+                addresses.push(pda);
+            }
+        }
+        let accounts = self
+            .rpc_client
+            .get_multiple_accounts(&addresses)
+            .await
+            .context("Failed to fetch vault PDAs")?;
+
+        Ok(VaultFootprint {
+            last_activity: latest.first().and_then(|status| status.block_time),
+            pda_lamports: accounts.iter().flatten().map(|account| account.lamports).sum(),
+            pda_count: accounts.iter().flatten().count(),
+        })
+    }
+
+    /// Accounts whose changes show up in [`VaultClient::status_report`]: the
+    /// PQ account, the wallet and its token account for each of `mints`
+    pub async fn watched_addresses(&self, wallet: Pubkey, mints: &[Pubkey]) -> Result<Vec<Pubkey>> {
+        let mut addresses = vec![self.derive_pq_account(wallet).0, wallet];
+        for &mint in mints {
+            let mint_account = self
+                .get_account_cached(&mint)
+                .await
+                .with_context(|| format!("Mint {} not found", mint))?;
+            let token_program = if mint_account.owner == TOKEN_2022_PROGRAM_ID {
+                &TOKEN_2022_PROGRAM_ID
+            } else {
+                &SPL_TOKEN_PROGRAM_ID
+            };
+            addresses.push(get_associated_token_address(&wallet, &mint, token_program));
+        }
+        Ok(addresses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(locked: bool, amount: u64) -> StatusReport {
+        StatusReport {
+            schema_version: STATUS_SCHEMA_VERSION,
+            wallet: Pubkey::default().to_string(),
+            pq_account: Pubkey::default().to_string(),
+            locked,
+            algorithm: None,
+            algorithm_id: 0,
+            unlock_challenge: hex::encode([1u8; 32]),
+            pending_unlock_slot: None,
+            sol_lamports: 5_000,
+            tokens: vec![TokenBalance { mint: Pubkey::default().to_string(), amount, decimals: 6 }],
+        }
+    }
+
+    #[test]
+    fn test_changes_since() {
+        let before = report(true, 100);
+        assert!(before.changes_since(&before).is_empty());
+
+        let after = report(false, 250);
+        assert_eq!(
+            after.changes_since(&before),
+            vec![
+                StatusChange::Unlocked,
+                StatusChange::TokenBalanceChanged { mint: Pubkey::default().to_string(), from: 100, to: 250, decimals: 6 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use anyhow::Result;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+use super::VaultClient;
+use crate::solana::receipt::{Operation, Receipt};
+
+impl VaultClient {
+    /// SOL `wallet` can send to `recipient` in one transfer: its whole
+    /// balance less that transaction's fee
+    pub async fn sweepable_lamports(&self, wallet: Pubkey, recipient: Pubkey) -> Result<u64> {
+        let balance = self.rpc_client.get_balance(&wallet).await?;
+        let transaction = self
+            .unsigned_transaction(&[solana_sdk::system_instruction::transfer(&wallet, &recipient, balance)], &wallet)
+            .await?;
+        let fee = self.rpc_client.get_fee_for_message(transaction.message()).await?;
+        Ok(balance.saturating_sub(fee))
+    }
+
+    /// Send `lamports` of SOL to `recipient`
+    pub async fn transfer_sol(&self, keypair: &(impl Signer + Sync), recipient: Pubkey, lamports: u64) -> Result<Receipt> {
+        self.policy.check_recipient(&recipient)?;
+
+        let mut receipt = Receipt::new(Operation::SolTransfer);
+        receipt.account("sender", &keypair.pubkey()).account("recipient", &recipient);
+
+        let instruction = solana_sdk::system_instruction::transfer(&keypair.pubkey(), &recipient, lamports);
+        if self.unsigned_out.is_some() {
+            return self.export_unsigned(&[instruction], &keypair.pubkey(), receipt).await;
+        }
+        receipt.record(self.send_instructions(&[instruction], &keypair.pubkey(), &[keypair]).await?);
+        Ok(receipt.finish())
+    }
+}
@@ -0,0 +1,93 @@
+//! Token accounts a wallet owns, under either token program
+//!
+//! Backs `token-accounts list`. Besides the balance, each account reports the
+//! rent it holds and whether it is the associated token account (ATA) for its
+//! mint and program: a mint's tokens in an account that isn't that ATA (e.g.
+//! created under the other token program) are invisible to most wallets.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::json;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_config::{RpcAccountInfoConfig, RpcTokenAccountsFilter},
+    rpc_request::RpcRequest,
+    rpc_response::{Response, RpcKeyedAccount},
+};
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use spl_token_2022::extension::StateWithExtensions;
+use std::str::FromStr;
+
+use super::{get_associated_token_address, VaultClient, SPL_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID};
+use crate::solana::units;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TokenAccountInfo {
+    pub address: String,
+    pub mint: String,
+    /// "spl-token" or "token-2022"
+    pub program: &'static str,
+    /// Base units
+    pub amount: u64,
+    /// `None` if the mint couldn't be read
+    pub decimals: Option<u8>,
+    /// Rent-exempt deposit, returned when the account is closed
+    pub rent_lamports: u64,
+    /// Whether this is the wallet's associated token account for the mint
+    pub associated: bool,
+}
+
+impl TokenAccountInfo {
+    /// Balance in tokens, if the decimals are known
+    pub fn ui_amount(&self) -> Option<f64> {
+        self.decimals.map(|decimals| units::to_tokens(self.amount, decimals))
+    }
+}
+
+impl VaultClient {
+    /// Every SPL Token and Token-2022 account owned by `wallet`, sorted by mint
+    pub async fn token_accounts(&self, wallet: Pubkey) -> Result<Vec<TokenAccountInfo>> {
+        let mut accounts = Vec::new();
+
+        for (program_id, program) in [(SPL_TOKEN_PROGRAM_ID, "spl-token"), (TOKEN_2022_PROGRAM_ID, "token-2022")] {
+            // `RpcClient::get_token_accounts_by_owner` always asks for
+            // jsonParsed; the raw account is unpacked here instead
+            let config = RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(self.rpc_client.commitment()),
+                ..RpcAccountInfoConfig::default()
+            };
+            let found: Response<Vec<RpcKeyedAccount>> = self
+                .rpc_client
+                .send(
+                    RpcRequest::GetTokenAccountsByOwner,
+                    json!([wallet.to_string(), RpcTokenAccountsFilter::ProgramId(program_id.to_string()), config]),
+                )
+                .await?;
+
+            for keyed in found.value {
+                let address = Pubkey::from_str(&keyed.pubkey).context("RPC returned an invalid token account address")?;
+                let Some(account) = keyed.account.decode::<Account>() else {
+                    continue;
+                };
+                let Ok(token_account) = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data) else {
+                    continue;
+                };
+                let mint = token_account.base.mint;
+
+                accounts.push(TokenAccountInfo {
+                    address: address.to_string(),
+                    mint: mint.to_string(),
+                    program,
+                    amount: token_account.base.amount,
+                    decimals: self.mint_decimals(mint).await.ok(),
+                    rent_lamports: account.lamports,
+                    associated: address == get_associated_token_address(&wallet, &mint, &program_id),
+                });
+            }
+        }
+
+        accounts.sort_by(|a, b| a.mint.cmp(&b.mint).then(b.associated.cmp(&a.associated)));
+        Ok(accounts)
+    }
+}
@@ -0,0 +1,49 @@
+//! Token-2022 transfers with the accounts the mint's transfer hook requires
+//!
+//! The hook program lists the extra accounts it needs in its
+//! `ExtraAccountMetaList` PDA (seeds `["extra-account-metas", mint]`). They are
+//! resolved from that list for each transfer, as spl-transfer-hook-interface
+//! does, so transfers keep working if the hook's accounts change.
+
+use anyhow::{anyhow, Result};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+use spl_transfer_hook_interface::offchain::AccountFetchError;
+
+use super::{VaultClient, TOKEN_2022_PROGRAM_ID};
+
+impl VaultClient {
+    /// `transfer_checked` for a Token-2022 mint, followed by the hook program,
+    /// its `ExtraAccountMetaList` and every account that list resolves to.
+    /// Mints without a hook get the plain instruction.
+    pub(super) async fn transfer_checked_with_hook(
+        &self,
+        source: &Pubkey,
+        mint: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> Result<Instruction> {
+        let rpc_client = &self.rpc_client;
+        spl_token_2022::offchain::create_transfer_checked_instruction_with_extra_metas(
+            &TOKEN_2022_PROGRAM_ID,
+            source,
+            mint,
+            destination,
+            authority,
+            &[],
+            amount,
+            decimals,
+            |address| async move {
+                // Missing accounts are `None`: seeds may name accounts that don't exist yet
+                rpc_client
+                    .get_account_with_commitment(&address, rpc_client.commitment())
+                    .await
+                    .map(|response| response.value.map(|account| account.data))
+                    .map_err(AccountFetchError::from)
+            },
+        )
+        .await
+        .map_err(|e| anyhow!("Could not resolve the transfer hook's accounts: {}", e))
+    }
+}
@@ -0,0 +1,241 @@
+//! Anchor IDL for the vault program
+//!
+//! Instruction discriminators and account lists come from the IDL instead of
+//! hand-copied constants. The IDL shipped with the CLI is compiled in; after a
+//! program upgrade, `--idl <PATH>` points at a newer one without a rebuild.
+//!
+//! The bundled IDL is not fetched from the chain. It lists only the
+//! instructions the CLI sent before IDLs were used, with the discriminators
+//! and accounts it sent them with. Hybrid unlock, ML-DSA unlock and
+//! inheritance need `--idl` with the IDL of a program that has them
+//! (`anchor idl fetch <PROGRAM_ID>`).
+//!
+//! Only instruction names, discriminators, accounts and error codes are read. Both the
+//! Anchor 0.30 format (snake_case names, `writable`/`signer`, explicit
+//! discriminators) and the older format (camelCase names, `isMut`/`isSigner`)
+//! are accepted.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use solana_sdk::instruction::Instruction;
+use std::path::Path;
+
+/// Vault program instructions known to this CLI, bundled at build time
+const BUNDLED_IDL: &str = include_str!("../../idl/quantdum_token.json");
+
+/// Anchor instruction discriminator: the first 8 bytes of sha256("global:<name>")
+pub fn sighash(name: &str) -> [u8; 8] {
+    let hash = Sha256::digest(format!("global:{}", name).as_bytes());
+    hash[..8].try_into().unwrap()
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Idl {
+    /// Legacy IDLs carry the version at the top level
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    metadata: Option<IdlMetadata>,
+    pub instructions: Vec<IdlInstruction>,
+    /// The program's `#[error_code]` enum
+    #[serde(default)]
+    pub errors: Vec<IdlError>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct IdlMetadata {
+    version: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlInstruction {
+    pub name: String,
+    /// Present in Anchor 0.30+ IDLs; computed from the name otherwise
+    #[serde(default)]
+    pub discriminator: Option<[u8; 8]>,
+    #[serde(default)]
+    pub accounts: Vec<IdlAccount>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlAccount {
+    pub name: String,
+    #[serde(default, alias = "isMut")]
+    pub writable: bool,
+    #[serde(default, alias = "isSigner")]
+    pub signer: bool,
+    #[serde(default, alias = "isOptional")]
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlError {
+    pub code: u32,
+    pub name: String,
+    #[serde(default)]
+    pub msg: Option<String>,
+}
+
+impl IdlInstruction {
+    /// Instruction name as the program defines it (snake_case)
+    fn program_name(&self) -> String {
+        to_snake_case(&self.name)
+    }
+
+    pub fn discriminator(&self) -> [u8; 8] {
+        self.discriminator.unwrap_or_else(|| sighash(&self.program_name()))
+    }
+}
+
+impl Idl {
+    /// The IDL compiled into this binary
+    pub fn bundled() -> Self {
+        Self::parse(BUNDLED_IDL).expect("bundled IDL is valid")
+    }
+
+    /// Load an IDL file, e.g. `target/idl/quantdum_token.json` from an Anchor build
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read IDL from {}", path.display()))?;
+        Self::parse(&json).with_context(|| format!("Invalid IDL {}", path.display()))
+    }
+
+    fn parse(json: &str) -> Result<Self> {
+        let idl: Self = serde_json::from_str(json)?;
+        if idl.instructions.is_empty() {
+            anyhow::bail!("IDL defines no instructions");
+        }
+        Ok(idl)
+    }
+
+    /// Program version the IDL describes
+    pub fn version(&self) -> &str {
+        self.metadata
+            .as_ref()
+            .map(|metadata| metadata.version.as_str())
+            .or(self.version.as_deref())
+            .unwrap_or("unknown")
+    }
+
+    /// Program error by code (Anchor numbers them from 6000)
+    pub fn error(&self, code: u32) -> Option<&IdlError> {
+        self.errors.iter().find(|error| error.code == code)
+    }
+
+    pub fn instruction(&self, name: &str) -> Option<&IdlInstruction> {
+        self.instructions.iter().find(|ix| ix.program_name() == name)
+    }
+
+    /// Discriminator for a program instruction (snake_case name)
+    ///
+    /// Falls back to the Anchor sighash if the IDL doesn't list it, so the
+    /// mismatch is reported by [`Idl::validate`] with the full account context.
+    pub fn discriminator(&self, name: &str) -> [u8; 8] {
+        self.instruction(name).map_or_else(|| sighash(name), IdlInstruction::discriminator)
+    }
+
+    /// Check an instruction against the IDL: its discriminator must be known
+    /// and each IDL account must be passed with at least the declared
+    /// privileges. Accounts past the IDL list are remaining accounts.
+    pub fn validate(&self, instruction: &Instruction) -> Result<()> {
+        let discriminator = instruction
+            .data
+            .get(..8)
+            .context("Instruction data is shorter than a discriminator")?;
+        let idl_ix = self
+            .instructions
+            .iter()
+            .find(|ix| ix.discriminator() == discriminator)
+            .with_context(|| {
+                format!(
+                    "Instruction {} is not in the program IDL (version {}). \
+                     The program may have been upgraded; pass a current IDL with --idl",
+                    hex::encode(discriminator),
+                    self.version()
+                )
+            })?;
+
+        let required = idl_ix.accounts.iter().filter(|account| !account.optional).count();
+        if instruction.accounts.len() < required {
+            anyhow::bail!(
+                "{} expects {} accounts but {} were passed",
+                idl_ix.program_name(),
+                required,
+                instruction.accounts.len()
+            );
+        }
+
+        for (meta, account) in instruction.accounts.iter().zip(&idl_ix.accounts) {
+            if account.writable && !meta.is_writable {
+                anyhow::bail!("{}: account `{}` ({}) must be writable", idl_ix.program_name(), account.name, meta.pubkey);
+            }
+            if account.signer && !meta.is_signer {
+                anyhow::bail!("{}: account `{}` ({}) must sign", idl_ix.program_name(), account.name, meta.pubkey);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// `sphincsVerifyStep0Init` -> `sphincs_verify_step0_init`; snake_case passes through
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            if !snake.is_empty() {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+
+    #[test]
+    fn test_bundled_discriminators_match_sighash() {
+        let idl = Idl::bundled();
+        assert_eq!(idl.discriminator("lock_tokens"), [136, 11, 32, 232, 161, 117, 54, 211]);
+        assert_eq!(idl.discriminator("sphincs_verify_step0_init"), [220, 238, 45, 110, 130, 122, 244, 163]);
+        assert_eq!(sighash("wrap"), [178, 40, 10, 189, 228, 129, 186, 140]);
+    }
+
+    #[test]
+    fn test_legacy_camel_case_names() {
+        assert_eq!(to_snake_case("sphincsVerifyStep11Finalize"), "sphincs_verify_step11_finalize");
+        assert_eq!(to_snake_case("lock_tokens"), "lock_tokens");
+    }
+
+    #[test]
+    fn test_validate_account_privileges() {
+        let idl = Idl::bundled();
+        let pq_account = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let data = idl.discriminator("lock_tokens").to_vec();
+
+        let valid = Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &data,
+            vec![AccountMeta::new(pq_account, false), AccountMeta::new_readonly(owner, true)],
+        );
+        assert!(idl.validate(&valid).is_ok());
+
+        let unsigned = Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &data,
+            vec![AccountMeta::new(pq_account, false), AccountMeta::new_readonly(owner, false)],
+        );
+        assert!(idl.validate(&unsigned).is_err());
+
+        let unknown = Instruction::new_with_bytes(Pubkey::new_unique(), &sighash("nope"), vec![]);
+        assert!(idl.validate(&unknown).is_err());
+    }
+}
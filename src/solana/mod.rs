@@ -1 +1,8 @@
+pub mod accounts;
 pub mod client;
+pub mod idl;
+pub mod pay;
+pub mod receipt;
+pub mod squads;
+pub mod units;
+pub mod vanity;
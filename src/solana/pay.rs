@@ -0,0 +1,115 @@
+//! Solana Pay transfer request URIs
+//!
+//! `solana:<recipient>?amount=<decimal>&spl-token=<mint>` is what mobile
+//! wallets expect in a QR code: scanning it opens a prefilled transfer. See
+//! <https://docs.solanapay.com/spec>.
+//!
+//! A `reference` is a throwaway address the payer's wallet adds to the
+//! transfer, so the payment can be found with `getSignaturesForAddress`
+//! (see [`VaultClient::find_payment`](crate::solana::client::VaultClient::find_payment)).
+
+use solana_sdk::pubkey::Pubkey;
+
+/// QDUM and pqQDUM both use 6 decimals
+const TOKEN_DECIMALS: u32 = 6;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TransferRequest {
+    pub recipient: Pubkey,
+    /// In base units; left to the payer when `None`
+    pub amount: Option<u64>,
+    /// Token to pay in; `None` means SOL
+    pub spl_token: Option<Pubkey>,
+    pub reference: Option<Pubkey>,
+    /// Who's asking, e.g. a store name or "Invoice 42"
+    pub label: Option<String>,
+    /// What it's for
+    pub message: Option<String>,
+}
+
+impl TransferRequest {
+    pub fn new(recipient: Pubkey) -> Self {
+        Self { recipient, ..Self::default() }
+    }
+
+    pub fn uri(&self) -> String {
+        let mut params = Vec::new();
+        if let Some(amount) = self.amount {
+            params.push(format!("amount={}", decimal_amount(amount)));
+        }
+        if let Some(mint) = self.spl_token {
+            params.push(format!("spl-token={}", mint));
+        }
+        if let Some(reference) = self.reference {
+            params.push(format!("reference={}", reference));
+        }
+        if let Some(label) = &self.label {
+            params.push(format!("label={}", percent_encode(label)));
+        }
+        if let Some(message) = &self.message {
+            params.push(format!("message={}", percent_encode(message)));
+        }
+
+        let mut uri = format!("solana:{}", self.recipient);
+        if !params.is_empty() {
+            uri.push('?');
+            uri.push_str(&params.join("&"));
+        }
+        uri
+    }
+}
+
+/// Everything but RFC 3986 unreserved characters as %XX UTF-8 bytes
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Base units as the spec's decimal amount: no exponent, no trailing zeros
+fn decimal_amount(base_units: u64) -> String {
+    let scale = 10u64.pow(TOKEN_DECIMALS);
+    let fraction = format!("{:0width$}", base_units % scale, width = TOKEN_DECIMALS as usize);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        (base_units / scale).to_string()
+    } else {
+        format!("{}.{}", base_units / scale, fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_request_uri() {
+        let recipient = Pubkey::new_unique();
+        assert_eq!(TransferRequest::new(recipient).uri(), format!("solana:{}", recipient));
+
+        let mint = Pubkey::new_unique();
+        let request = TransferRequest { amount: Some(25_500_000), spl_token: Some(mint), ..TransferRequest::new(recipient) };
+        assert_eq!(request.uri(), format!("solana:{}?amount=25.5&spl-token={}", recipient, mint));
+
+        let reference = Pubkey::new_unique();
+        let request = TransferRequest {
+            reference: Some(reference),
+            label: Some("Invoice 42".to_string()),
+            message: Some("Café & co".to_string()),
+            ..request
+        };
+        assert_eq!(
+            request.uri(),
+            format!(
+                "solana:{}?amount=25.5&spl-token={}&reference={}&label=Invoice%2042&message=Caf%C3%A9%20%26%20co",
+                recipient, mint, reference
+            )
+        );
+
+        assert_eq!(decimal_amount(1), "0.000001");
+        assert_eq!(decimal_amount(3_000_000), "3");
+    }
+}
@@ -0,0 +1,203 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use colored::Colorize;
+use comfy_table::{presets::UTF8_FULL, Table};
+use serde::Serialize;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::audit;
+use crate::solana::units;
+
+/// Lamports per SOL, for fee display
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+/// Operation a receipt was produced by
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    Register,
+    Lock,
+    Unlock,
+    Close,
+    Transfer,
+    Wrap,
+    Unwrap,
+    ConfigureInheritance,
+    Heartbeat,
+    CancelInheritance,
+    ClaimInheritance,
+    MultisigPropose,
+    MultisigApprove,
+    MultisigExecute,
+    CreateNonce,
+    CloseNonce,
+    SolTransfer,
+}
+
+impl Operation {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Operation::Register => "Register",
+            Operation::Lock => "Lock",
+            Operation::Unlock => "Unlock",
+            Operation::Close => "Close",
+            Operation::Transfer => "Transfer",
+            Operation::Wrap => "Wrap",
+            Operation::Unwrap => "Unwrap",
+            Operation::ConfigureInheritance => "Configure Inheritance",
+            Operation::Heartbeat => "Heartbeat",
+            Operation::CancelInheritance => "Cancel Inheritance",
+            Operation::ClaimInheritance => "Claim Inheritance",
+            Operation::MultisigPropose => "Multisig Proposal",
+            Operation::MultisigApprove => "Multisig Approval",
+            Operation::MultisigExecute => "Multisig Execution",
+            Operation::CreateNonce => "Create Nonce Account",
+            Operation::CloseNonce => "Close Nonce Account",
+            Operation::SolTransfer => "SOL Transfer",
+        }
+    }
+}
+
+/// Structured result of an on-chain operation
+#[derive(Debug, Clone, Serialize)]
+pub struct Receipt {
+    pub operation: Operation,
+    /// Transaction signatures in the order they were confirmed
+    pub signatures: Vec<String>,
+    /// Accounts involved, keyed by role (e.g. "wallet", "pq_account")
+    pub accounts: BTreeMap<String, String>,
+    /// Token amount in base units, for transfers and bridge operations
+    pub amount: Option<u64>,
+    /// Total network fees paid, in lamports
+    pub fee_lamports: u64,
+    pub started_at: String,
+    pub completed_at: Option<String>,
+    /// Set when the operation had nothing to do (e.g. already locked)
+    pub note: Option<String>,
+}
+
+impl Receipt {
+    pub fn new(operation: Operation) -> Self {
+        Self {
+            operation,
+            signatures: Vec::new(),
+            accounts: BTreeMap::new(),
+            amount: None,
+            fee_lamports: 0,
+            started_at: Utc::now().to_rfc3339(),
+            completed_at: None,
+            note: None,
+        }
+    }
+
+    /// Record an account involved in the operation
+    pub fn account(&mut self, role: &str, address: &Pubkey) -> &mut Self {
+        self.accounts.insert(role.to_string(), address.to_string());
+        self
+    }
+
+    /// Record a confirmed transaction and the fee it was charged
+    pub fn record(&mut self, (signature, fee): (Signature, u64)) {
+        self.signatures.push(signature.to_string());
+        self.fee_lamports += fee;
+    }
+
+    /// Mark the operation complete, recording it in the audit log if it
+    /// sent any transactions
+    pub fn finish(mut self) -> Self {
+        self.completed_at = Some(Utc::now().to_rfc3339());
+        if !self.signatures.is_empty() {
+            audit::record(self.audit_event());
+        }
+        self
+    }
+
+    fn audit_event(&self) -> audit::Event {
+        let operation = serde_json::to_value(self.operation)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_else(|| self.operation.label().to_string());
+        let mut event = audit::Event::new(&operation).detail("fee_lamports", self.fee_lamports);
+        event.wallet = self.accounts.get("wallet").or_else(|| self.accounts.get("sender")).cloned();
+        event.amount = self.amount;
+        event.signatures = self.signatures.clone();
+        for (role, address) in &self.accounts {
+            event = event.detail(role, address);
+        }
+        event
+    }
+
+    /// Mark the operation as a no-op with an explanation
+    pub fn skipped(mut self, note: &str) -> Self {
+        self.note = Some(note.to_string());
+        self.finish()
+    }
+
+    /// Decimals of the mint `amount` is in: the transferred or bridged mint
+    fn decimals(&self) -> u8 {
+        ["mint", "standard_mint"]
+            .iter()
+            .find_map(|role| self.accounts.get(*role))
+            .and_then(|mint| Pubkey::from_str(mint).ok())
+            .map_or(units::DEFAULT_DECIMALS, |mint| units::decimals(&mint))
+    }
+
+    /// Last confirmed signature, if any
+    pub fn last_signature(&self) -> Option<&str> {
+        self.signatures.last().map(|s| s.as_str())
+    }
+
+    /// Print a summary table
+    pub fn print(&self) {
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec![
+            format!("{} Receipt", self.operation.label()).bright_white().bold().to_string(),
+            "".to_string(),
+        ]);
+
+        for (role, address) in &self.accounts {
+            table.add_row(vec![role.dimmed().to_string(), address.bright_cyan().to_string()]);
+        }
+
+        if let Some(amount) = self.amount {
+            table.add_row(vec![
+                "amount".dimmed().to_string(),
+                units::to_tokens(amount, self.decimals()).to_string().bright_yellow().to_string(),
+            ]);
+        }
+
+        table.add_row(vec!["transactions".dimmed().to_string(), self.signatures.len().to_string()]);
+
+        if let Some(sig) = self.last_signature() {
+            table.add_row(vec!["signature".dimmed().to_string(), sig.to_string()]);
+        }
+
+        table.add_row(vec![
+            "fees".dimmed().to_string(),
+            format!("{:.6} SOL", self.fee_lamports as f64 / LAMPORTS_PER_SOL),
+        ]);
+
+        if let Some(done) = &self.completed_at {
+            table.add_row(vec!["completed".dimmed().to_string(), done.clone()]);
+        }
+
+        if let Some(note) = &self.note {
+            table.add_row(vec!["note".dimmed().to_string(), note.yellow().to_string()]);
+        }
+
+        println!("{}", table);
+        println!();
+    }
+
+    /// Write the receipt as pretty JSON
+    pub fn write_json(&self, path: &str) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .context("Failed to serialize receipt")?;
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write receipt to {}", path))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,365 @@
+//! Minimal Squads v4 multisig support
+//!
+//! Only the pieces qdum-vault needs: PDA derivation, the compact transaction
+//! message format, account parsing, and the create/approve/execute instructions.
+
+use anyhow::{anyhow, Result};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey::Pubkey,
+};
+
+/// Squads v4 program (same address on devnet and mainnet)
+pub const SQUADS_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("SQDS4ep65T869zMMBKyuUq6aD6EgTosR6Zmbgq8tbhB");
+
+/// Vault index used as the vault authority
+pub const DEFAULT_VAULT_INDEX: u8 = 0;
+
+const SEED_PREFIX: &[u8] = b"multisig";
+const SEED_VAULT: &[u8] = b"vault";
+const SEED_TRANSACTION: &[u8] = b"transaction";
+const SEED_PROPOSAL: &[u8] = b"proposal";
+
+const VAULT_TRANSACTION_CREATE_DISCRIMINATOR: [u8; 8] = [48, 250, 78, 168, 208, 226, 218, 211];
+const PROPOSAL_CREATE_DISCRIMINATOR: [u8; 8] = [220, 60, 73, 224, 30, 108, 79, 159];
+const PROPOSAL_APPROVE_DISCRIMINATOR: [u8; 8] = [144, 37, 164, 136, 188, 216, 42, 248];
+const VAULT_TRANSACTION_EXECUTE_DISCRIMINATOR: [u8; 8] = [194, 8, 161, 87, 153, 164, 25, 171];
+
+pub fn vault_pda(multisig: &Pubkey, vault_index: u8) -> Pubkey {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, multisig.as_ref(), SEED_VAULT, &[vault_index]],
+        &SQUADS_PROGRAM_ID,
+    )
+    .0
+}
+
+pub fn transaction_pda(multisig: &Pubkey, index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, multisig.as_ref(), SEED_TRANSACTION, &index.to_le_bytes()],
+        &SQUADS_PROGRAM_ID,
+    )
+    .0
+}
+
+pub fn proposal_pda(multisig: &Pubkey, index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, multisig.as_ref(), SEED_TRANSACTION, &index.to_le_bytes(), SEED_PROPOSAL],
+        &SQUADS_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Multisig account (only the fields we display or need)
+#[derive(Debug, Clone)]
+pub struct MultisigAccount {
+    pub threshold: u16,
+    pub time_lock: u32,
+    /// Index of the most recently created transaction
+    pub transaction_index: u64,
+    pub members: Vec<Pubkey>,
+}
+
+impl MultisigAccount {
+    /// Layout: discriminator(8) + create_key(32) + config_authority(32) + threshold(2) + time_lock(4)
+    /// + transaction_index(8) + stale_transaction_index(8) + rent_collector Option<Pubkey> + bump(1) + members Vec<Member>
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data, 72);
+        let threshold = reader.u16()?;
+        let time_lock = reader.u32()?;
+        let transaction_index = reader.u64()?;
+        let _stale_transaction_index = reader.u64()?;
+        if reader.u8()? == 1 {
+            reader.pubkey()?;
+        }
+        let _bump = reader.u8()?;
+
+        let count = reader.u32()?;
+        let mut members = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            members.push(reader.pubkey()?);
+            let _permissions = reader.u8()?;
+        }
+
+        Ok(Self { threshold, time_lock, transaction_index, members })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProposalStatus {
+    Draft,
+    Active,
+    Rejected,
+    Approved,
+    Executing,
+    Executed,
+    Cancelled,
+}
+
+impl ProposalStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProposalStatus::Draft => "Draft",
+            ProposalStatus::Active => "Active",
+            ProposalStatus::Rejected => "Rejected",
+            ProposalStatus::Approved => "Approved",
+            ProposalStatus::Executing => "Executing",
+            ProposalStatus::Executed => "Executed",
+            ProposalStatus::Cancelled => "Cancelled",
+        }
+    }
+}
+
+/// Proposal account: voting state for a vault transaction
+#[derive(Debug, Clone)]
+pub struct ProposalAccount {
+    pub status: ProposalStatus,
+    pub approved: Vec<Pubkey>,
+}
+
+impl ProposalAccount {
+    /// Layout: discriminator(8) + multisig(32) + transaction_index(8) + status enum + bump(1) + approved Vec<Pubkey> + ...
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data, 48);
+
+        // Every status except the deprecated Executing carries a timestamp
+        let status = match reader.u8()? {
+            0 => ProposalStatus::Draft,
+            1 => ProposalStatus::Active,
+            2 => ProposalStatus::Rejected,
+            3 => ProposalStatus::Approved,
+            4 => ProposalStatus::Executing,
+            5 => ProposalStatus::Executed,
+            6 => ProposalStatus::Cancelled,
+            tag => return Err(anyhow!("Unknown proposal status {}", tag)),
+        };
+        if status != ProposalStatus::Executing {
+            reader.u64()?;
+        }
+        let _bump = reader.u8()?;
+
+        let count = reader.u32()?;
+        let approved = (0..count).map(|_| reader.pubkey()).collect::<Result<_>>()?;
+
+        Ok(Self { status, approved })
+    }
+}
+
+/// Accounts referenced by a stored vault transaction, with their writability
+pub fn vault_transaction_accounts(data: &[u8]) -> Result<Vec<AccountMeta>> {
+    // discriminator(8) + multisig(32) + creator(32) + index(8) + bump(1) + vault_index(1) + vault_bump(1)
+    let mut reader = Reader::new(data, 83);
+    let ephemeral_signers = reader.u32()? as usize;
+    reader.skip(ephemeral_signers)?;
+
+    let num_signers = reader.u8()? as usize;
+    let num_writable_signers = reader.u8()? as usize;
+    let num_writable_non_signers = reader.u8()? as usize;
+
+    let count = reader.u32()? as usize;
+    let mut accounts = Vec::with_capacity(count);
+    for i in 0..count {
+        let key = reader.pubkey()?;
+        let writable = if i < num_signers {
+            i < num_writable_signers
+        } else {
+            i - num_signers < num_writable_non_signers
+        };
+
+        // The vault signs via CPI, so nothing is passed as a signer here
+        accounts.push(if writable {
+            AccountMeta::new(key, false)
+        } else {
+            AccountMeta::new_readonly(key, false)
+        });
+    }
+
+    Ok(accounts)
+}
+
+/// Serialize instructions into the Squads compact transaction message, with the vault as payer
+pub fn compile_vault_message(vault: &Pubkey, instructions: &[Instruction]) -> Vec<u8> {
+    let message = Message::new(instructions, Some(vault));
+    let header = message.header;
+    let num_signers = header.num_required_signatures;
+    let num_writable_signers = num_signers - header.num_readonly_signed_accounts;
+    let num_writable_non_signers =
+        message.account_keys.len() as u8 - num_signers - header.num_readonly_unsigned_accounts;
+
+    let mut data = vec![num_signers, num_writable_signers, num_writable_non_signers];
+
+    data.push(message.account_keys.len() as u8);
+    for key in &message.account_keys {
+        data.extend_from_slice(key.as_ref());
+    }
+
+    data.push(message.instructions.len() as u8);
+    for ix in &message.instructions {
+        data.push(ix.program_id_index);
+        data.push(ix.accounts.len() as u8);
+        data.extend_from_slice(&ix.accounts);
+        data.extend_from_slice(&(ix.data.len() as u16).to_le_bytes());
+        data.extend_from_slice(&ix.data);
+    }
+
+    // No address lookup tables
+    data.push(0);
+
+    data
+}
+
+/// Store a vault transaction under `index`
+pub fn vault_transaction_create(
+    multisig: &Pubkey,
+    index: u64,
+    creator: &Pubkey,
+    vault_index: u8,
+    transaction_message: &[u8],
+) -> Instruction {
+    let mut data = VAULT_TRANSACTION_CREATE_DISCRIMINATOR.to_vec();
+    data.push(vault_index);
+    data.push(0); // ephemeral signers
+    data.extend_from_slice(&(transaction_message.len() as u32).to_le_bytes());
+    data.extend_from_slice(transaction_message);
+    data.push(0); // memo: None
+
+    Instruction {
+        program_id: SQUADS_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(transaction_pda(multisig, index), false),
+            AccountMeta::new_readonly(*creator, true),
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+/// Open an (active, non-draft) proposal for the transaction at `index`
+pub fn proposal_create(multisig: &Pubkey, index: u64, creator: &Pubkey) -> Instruction {
+    let mut data = PROPOSAL_CREATE_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&index.to_le_bytes());
+    data.push(0); // draft: false
+
+    Instruction {
+        program_id: SQUADS_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(proposal_pda(multisig, index), false),
+            AccountMeta::new_readonly(*creator, true),
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn proposal_approve(multisig: &Pubkey, index: u64, member: &Pubkey) -> Instruction {
+    let mut data = PROPOSAL_APPROVE_DISCRIMINATOR.to_vec();
+    data.push(0); // memo: None
+
+    Instruction {
+        program_id: SQUADS_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*member, true),
+            AccountMeta::new(proposal_pda(multisig, index), false),
+        ],
+        data,
+    }
+}
+
+/// Execute an approved vault transaction; `accounts` come from [`vault_transaction_accounts`]
+pub fn vault_transaction_execute(
+    multisig: &Pubkey,
+    index: u64,
+    member: &Pubkey,
+    accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut metas = vec![
+        AccountMeta::new_readonly(*multisig, false),
+        AccountMeta::new(proposal_pda(multisig, index), false),
+        AccountMeta::new_readonly(transaction_pda(multisig, index), false),
+        AccountMeta::new_readonly(*member, true),
+    ];
+    metas.extend(accounts);
+
+    Instruction {
+        program_id: SQUADS_PROGRAM_ID,
+        accounts: metas,
+        data: VAULT_TRANSACTION_EXECUTE_DISCRIMINATOR.to_vec(),
+    }
+}
+
+/// Little-endian cursor over Borsh account data
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8], offset: usize) -> Self {
+        Self { data, offset }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let bytes = self
+            .data
+            .get(self.offset..self.offset + len)
+            .ok_or_else(|| anyhow!("Squads account data is truncated"))?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn skip(&mut self, len: usize) -> Result<()> {
+        self.take(len).map(|_| ())
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn pubkey(&mut self) -> Result<Pubkey> {
+        Ok(Pubkey::try_from(self.take(32)?).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_vault_message() {
+        let vault = Pubkey::new_unique();
+        let target = Pubkey::new_unique();
+        let program = Pubkey::new_unique();
+        let ix = Instruction {
+            program_id: program,
+            accounts: vec![AccountMeta::new(target, false), AccountMeta::new_readonly(vault, true)],
+            data: vec![1, 2, 3],
+        };
+
+        let message = compile_vault_message(&vault, &[ix]);
+
+        // vault is the only (writable, as payer) signer; target is the only writable non-signer
+        assert_eq!(&message[..3], &[1, 1, 1]);
+        assert_eq!(message[3], 3);
+        assert_eq!(&message[4..36], vault.as_ref());
+        // one instruction with three data bytes, and no lookup tables
+        assert_eq!(message[4 + 3 * 32], 1);
+        assert_eq!(&message[message.len() - 4..], &[1, 2, 3, 0]);
+    }
+}
@@ -0,0 +1,96 @@
+//! Converting between base units and tokens
+//!
+//! A mint's decimals never change, so they are fetched once (see
+//! `VaultClient::mint_decimals`) and kept in `<data dir>/mint_decimals.json`.
+//! Code that can't reach the network, like the dashboard's rendering, looks
+//! them up with [`decimals`]; mints never seen fall back to QDUM's 6.
+
+use solana_sdk::pubkey::Pubkey;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+use crate::paths;
+
+/// QDUM's decimals, assumed for mints that haven't been looked up
+pub const DEFAULT_DECIMALS: u8 = 6;
+
+/// Offset of `decimals` in an SPL Token / Token-2022 mint
+pub const MINT_DECIMALS_OFFSET: usize = 44;
+
+const CACHE_FILE: &str = "mint_decimals.json";
+
+static CACHE: OnceLock<Mutex<BTreeMap<String, u8>>> = OnceLock::new();
+
+fn cache_path() -> PathBuf {
+    paths::data_dir().join(CACHE_FILE)
+}
+
+fn cache() -> &'static Mutex<BTreeMap<String, u8>> {
+    CACHE.get_or_init(|| {
+        let saved = fs::read_to_string(cache_path())
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .unwrap_or_default();
+        Mutex::new(saved)
+    })
+}
+
+/// Decimals of `mint` if it has been looked up before
+pub fn cached_decimals(mint: &Pubkey) -> Option<u8> {
+    cache().lock().unwrap().get(&mint.to_string()).copied()
+}
+
+/// Decimals of `mint`, or [`DEFAULT_DECIMALS`] if it hasn't been looked up
+/// yet. For display only: amounts that get signed use
+/// `VaultClient::mint_decimals`, which fails instead of guessing.
+pub fn decimals(mint: &Pubkey) -> u8 {
+    cached_decimals(mint).unwrap_or(DEFAULT_DECIMALS)
+}
+
+/// Remember `mint`'s decimals for this and later runs. Failing to save only
+/// means they're fetched again next time.
+pub fn remember(mint: &Pubkey, decimals: u8) {
+    let mut cache = cache().lock().unwrap();
+    if cache.insert(mint.to_string(), decimals) == Some(decimals) {
+        return;
+    }
+    if let Ok(json) = serde_json::to_string_pretty(&*cache) {
+        let path = cache_path();
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        let _ = fs::write(path, json);
+    }
+}
+
+/// Decimals stored in a mint account's data
+pub fn parse_mint_decimals(data: &[u8]) -> Option<u8> {
+    data.get(MINT_DECIMALS_OFFSET).copied()
+}
+
+/// `amount` base units in tokens
+pub fn to_tokens(amount: u64, decimals: u8) -> f64 {
+    amount as f64 / 10f64.powi(decimals as i32)
+}
+
+/// `tokens` in base units, rounded to the nearest unit
+pub fn to_base_units(tokens: f64, decimals: u8) -> u64 {
+    (tokens * 10f64.powi(decimals as i32)).round() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(to_base_units(1.5, 6), 1_500_000);
+        assert_eq!(to_base_units(0.000007, 6), 7);
+        assert_eq!(to_base_units(2.0, 9), 2_000_000_000);
+        assert_eq!(to_base_units(3.0, 0), 3);
+        assert_eq!(to_tokens(1_500_000, 6), 1.5);
+        assert_eq!(to_tokens(250, 2), 2.5);
+    }
+}
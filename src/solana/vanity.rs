@@ -0,0 +1,97 @@
+//! Vanity wallet addresses
+//!
+//! `init --vanity <PREFIX>` grinds random keypairs until one's base58 address
+//! starts with the prefix. Every extra character makes that about 58 times
+//! less likely, so the work is spread over every core and reported as it goes.
+
+use anyhow::Result;
+use solana_sdk::signature::{Keypair, Signer};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Characters a base58 address can contain
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Longest prefix accepted; beyond this a search takes years
+pub const MAX_PREFIX_LEN: usize = 8;
+
+/// Keypairs each thread tries between checks for a match elsewhere
+const BATCH: u64 = 1_000;
+
+/// Refuse prefixes no address can start with
+pub fn validate_prefix(prefix: &str) -> Result<()> {
+    if prefix.is_empty() || prefix.len() > MAX_PREFIX_LEN {
+        anyhow::bail!("Vanity prefix must be 1 to {} characters", MAX_PREFIX_LEN);
+    }
+    if let Some(invalid) = prefix.chars().find(|c| !BASE58_ALPHABET.contains(*c)) {
+        anyhow::bail!("'{}' can't appear in an address (base58 has no 0, O, I or l)", invalid);
+    }
+    Ok(())
+}
+
+/// Keypairs tried, on average, before one matches `prefix`
+pub fn expected_attempts(prefix: &str) -> f64 {
+    58f64.powi(prefix.len() as i32)
+}
+
+/// Generate keypairs on `threads` threads until one's address starts with
+/// `prefix`, calling `progress` with the running attempt count about every
+/// 100ms
+pub fn grind(prefix: &str, threads: usize, mut progress: impl FnMut(u64)) -> Result<Keypair> {
+    validate_prefix(prefix)?;
+
+    let found = Arc::new(AtomicBool::new(false));
+    let attempts = Arc::new(AtomicU64::new(0));
+    let result: Arc<Mutex<Option<Keypair>>> = Arc::new(Mutex::new(None));
+
+    let workers: Vec<_> = (0..threads.max(1))
+        .map(|_| {
+            let (prefix, found, attempts, result) = (prefix.to_string(), found.clone(), attempts.clone(), result.clone());
+            std::thread::spawn(move || {
+                while !found.load(Ordering::Relaxed) {
+                    for _ in 0..BATCH {
+                        let keypair = Keypair::new();
+                        if keypair.pubkey().to_string().starts_with(&prefix) {
+                            found.store(true, Ordering::Relaxed);
+                            result.lock().unwrap().get_or_insert(keypair);
+                            break;
+                        }
+                    }
+                    attempts.fetch_add(BATCH, Ordering::Relaxed);
+                }
+            })
+        })
+        .collect();
+
+    while !found.load(Ordering::Relaxed) {
+        std::thread::sleep(Duration::from_millis(100));
+        progress(attempts.load(Ordering::Relaxed));
+    }
+    for worker in workers {
+        let _ = worker.join();
+    }
+
+    let keypair = result.lock().unwrap().take();
+    keypair.ok_or_else(|| anyhow::anyhow!("Vanity search stopped without a match"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_prefix() {
+        assert!(validate_prefix("QD").is_ok());
+        assert!(validate_prefix("").is_err());
+        assert!(validate_prefix("Q0").is_err());
+        assert!(validate_prefix("lol").is_err());
+        assert!(validate_prefix("123456789").is_err());
+    }
+
+    #[test]
+    fn test_grind_single_character() {
+        let keypair = grind("2", 2, |_| {}).unwrap();
+        assert!(keypair.pubkey().to_string().starts_with('2'));
+    }
+}
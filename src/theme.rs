@@ -1,153 +1,439 @@
 // Post-Quantum Terminal theme for pqcoin
-// Clean, modern white background design with bold typography
+//
+// The dashboard draws with the active `Theme`, chosen once at startup: a
+// built-in palette (`config --theme`), optionally adjusted by a `theme.toml`
+// in the config directory (e.g. ~/.qdum/theme.toml):
+//
+//     # Built-in theme to start from (default: the configured one)
+//     extends = "dark"
+//
+//     [colors]
+//     base = "#1e1e2e"
+//     text = "white"
+//     bloomberg_orange = "#fab387"
+//
+// Color names are the `Theme` fields; values are anything ratatui parses
+// ("#rrggbb", "red", "lightblue", an ANSI index like "208").
 
+use anyhow::{bail, Context, Result};
 use ratatui::style::Color;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::OnceLock;
 
-pub struct Theme;
+/// Custom theme file, in the config directory
+pub const THEME_FILE: &str = "theme.toml";
 
-impl Theme {
-    // Base colors - Clean white background
-    pub const BASE: Color = Color::Rgb(255, 255, 255);      // Pure white background
-    pub const MANTLE: Color = Color::Rgb(250, 250, 250);    // Light gray
-    pub const CRUST: Color = Color::Rgb(245, 245, 245);     // Slightly darker gray
-
-    // Panel backgrounds - Light modern panels
-    pub const PANEL_BG: Color = Color::Rgb(248, 248, 248);  // Main panels
-    pub const GLASS_1: Color = Color::Rgb(245, 245, 250);   // Panel layer 1 (slight blue tint)
-    pub const GLASS_2: Color = Color::Rgb(240, 240, 245);   // Panel layer 2
-    pub const GLASS_3: Color = Color::Rgb(235, 235, 240);   // Panel layer 3
-    pub const GLASS_ACCENT: Color = Color::Rgb(230, 230, 235); // Elevated panels
-
-    // Surface layers - For table headers and elevated sections
-    pub const SURFACE0: Color = Color::Rgb(235, 235, 235);  // Elevated surface
-    pub const SURFACE1: Color = Color::Rgb(230, 230, 230);  // More elevated
-    pub const SURFACE2: Color = Color::Rgb(225, 225, 225);  // Highest elevation
-
-    // Text colors - Dark text on white background
-    pub const TEXT: Color = Color::Rgb(20, 20, 20);         // Almost black text
-    pub const TEXT_BRIGHT: Color = Color::Rgb(0, 0, 0);     // Pure black
-    pub const SUBTEXT1: Color = Color::Rgb(60, 60, 60);     // Dark gray
-    pub const SUBTEXT0: Color = Color::Rgb(100, 100, 100);  // Medium gray
-    pub const OVERLAY2: Color = Color::Rgb(140, 140, 140);  // Light gray
-    pub const OVERLAY1: Color = Color::Rgb(180, 180, 180);  // Very light gray
-    pub const DIM: Color = Color::Rgb(200, 200, 200);       // Dim separator
-
-    // Post-Quantum Signature Colors - Dark versions for white background
-    pub const QUANTUM_CYAN: Color = Color::Rgb(0, 150, 200);      // Dark cyan
-    pub const QUANTUM_CYAN_DIM: Color = Color::Rgb(0, 120, 160);  // Darker cyan
-    pub const QUANTUM_MAGENTA: Color = Color::Rgb(180, 0, 200);   // Dark magenta
-    pub const QUANTUM_MAGENTA_DIM: Color = Color::Rgb(140, 0, 160); // Darker magenta
-
-    // Primary Purple Theme - Dark purple for white background
-    pub const BLOOMBERG_ORANGE: Color = Color::Rgb(120, 60, 200); // Dark purple
-    pub const ORANGE_BRIGHT: Color = Color::Rgb(140, 80, 220);    // Medium purple
-    pub const ORANGE_DIM: Color = Color::Rgb(100, 50, 180);       // Darker purple
-    pub const ORANGE_DARK: Color = Color::Rgb(80, 40, 160);       // Darkest purple
-
-    // Accent colors - Darker versions for white background visibility
-    pub const CYAN_NEON: Color = Color::Rgb(0, 140, 180);   // Dark cyan
-    pub const CYAN_BRIGHT: Color = Color::Rgb(0, 160, 200); // Medium cyan
-    pub const BLUE_NEON: Color = Color::Rgb(30, 100, 200);  // Dark blue
-    pub const PURPLE_NEON: Color = Color::Rgb(140, 60, 200); // Dark purple
-    pub const PINK_NEON: Color = Color::Rgb(200, 40, 140);   // Dark pink
-    pub const GREEN_NEON: Color = Color::Rgb(0, 160, 80);    // Dark green
-    pub const RED_NEON: Color = Color::Rgb(220, 50, 50);     // Dark red
-    pub const YELLOW_NEON: Color = Color::Rgb(200, 140, 0);  // Dark yellow/gold
-    pub const ORANGE_NEON: Color = Color::Rgb(120, 60, 200); // Dark purple
-
-    // Softer accent colors - For secondary data
-    pub const CYAN: Color = Color::Rgb(0, 120, 160);        // Medium cyan
-    pub const BLUE: Color = Color::Rgb(40, 100, 180);       // Medium blue
-    pub const PURPLE: Color = Color::Rgb(120, 80, 200);     // Medium purple
-    pub const GREEN: Color = Color::Rgb(40, 160, 80);       // Medium green
-    pub const RED: Color = Color::Rgb(200, 70, 70);         // Medium red
-    pub const YELLOW: Color = Color::Rgb(180, 120, 0);      // Medium yellow
-
-    // Border colors - Dark borders for white background
-    pub const BORDER_BRIGHT: Color = Color::Rgb(120, 60, 200);  // Dark purple border
-    pub const BORDER_DIM: Color = Color::Rgb(200, 200, 200);    // Light gray border
-    pub const BORDER_GLOW: Color = Color::Rgb(140, 80, 220);    // Medium purple glow
-    pub const BORDER_MAGENTA: Color = Color::Rgb(180, 0, 200);  // Dark magenta border
+static ACTIVE: OnceLock<Theme> = OnceLock::new();
 
-    // Semantic color functions - Modern glassmorphic style
+/// The palette the dashboard draws with (the light theme until one is set)
+pub fn theme() -> &'static Theme {
+    ACTIVE.get_or_init(Theme::light)
+}
 
-    /// Border color for active/focused panels - Bright glow
-    pub const fn active_border() -> Color {
-        Self::BORDER_GLOW
-    }
+/// Use `theme` for the rest of the process. Only the first call has an effect.
+pub fn set_theme(theme: Theme) {
+    let _ = ACTIVE.set(theme);
+}
 
-    /// Border color for inactive panels - Subtle depth
-    pub const fn inactive_border() -> Color {
-        Self::BORDER_DIM
-    }
+macro_rules! palette {
+    ($($field:ident),* $(,)?) => {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Theme {
+            $(pub $field: Color,)*
+        }
+
+        impl Theme {
+            /// The field a theme file calls `name`
+            fn color_mut(&mut self, name: &str) -> Option<&mut Color> {
+                match name {
+                    $(stringify!($field) => Some(&mut self.$field),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+palette! {
+    // Backgrounds
+    base, mantle, crust,
+    panel_bg, glass_1, glass_2, glass_3, glass_accent,
+    surface0, surface1, surface2,
+    // Selected row in the actions panel
+    highlight,
+    // Text
+    text, text_bright, subtext1, subtext0, overlay2, overlay1, dim,
+    // Signature animation
+    quantum_cyan, quantum_cyan_dim, quantum_magenta, quantum_magenta_dim,
+    // Primary accent (purple in the light theme)
+    bloomberg_orange, orange_bright, orange_dim, orange_dark,
+    // Accents
+    cyan_neon, cyan_bright, blue_neon, purple_neon, pink_neon,
+    green_neon, red_neon, yellow_neon, orange_neon,
+    cyan, blue, purple, green, red, yellow,
+    // Borders
+    border_bright, border_dim, border_glow, border_magenta,
+}
 
-    /// Color for table/section headers - Bright cyan
-    pub const fn header() -> Color {
-        Self::CYAN_BRIGHT
+/// Built-in themes
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum ThemeName {
+    Light,
+    Dark,
+    HighContrast,
+    ClassicGreen,
+}
+
+impl ThemeName {
+    pub fn theme(self) -> Theme {
+        match self {
+            ThemeName::Light => Theme::light(),
+            ThemeName::Dark => Theme::dark(),
+            ThemeName::HighContrast => Theme::high_contrast(),
+            ThemeName::ClassicGreen => Theme::classic_green(),
+        }
     }
 
-    /// Color for success states (unlocked, completed) - Matrix green
-    pub const fn success() -> Color {
-        Self::GREEN_NEON
+    pub fn name(self) -> &'static str {
+        match self {
+            ThemeName::Light => "light",
+            ThemeName::Dark => "dark",
+            ThemeName::HighContrast => "high-contrast",
+            ThemeName::ClassicGreen => "classic-green",
+        }
     }
 
-    /// Color for error states - Alert red
-    pub const fn error() -> Color {
-        Self::RED_NEON
+    pub fn parse(name: &str) -> Result<Self> {
+        <Self as clap::ValueEnum>::from_str(name, true).map_err(|_| {
+            anyhow::anyhow!("Unknown theme '{}' (expected light, dark, high-contrast or classic-green)", name)
+        })
     }
+}
 
-    /// Color for warning states - Warning yellow
-    pub const fn warning() -> Color {
-        Self::YELLOW_NEON
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    extends: Option<String>,
+    #[serde(default)]
+    colors: BTreeMap<String, String>,
+}
+
+impl Theme {
+    /// Clean white background with bold typography (the original look)
+    pub fn light() -> Self {
+        Self {
+            // Base colors - Clean white background
+            base: Color::Rgb(255, 255, 255),      // Pure white background
+            mantle: Color::Rgb(250, 250, 250),    // Light gray
+            crust: Color::Rgb(245, 245, 245),     // Slightly darker gray
+
+            // Panel backgrounds - Light modern panels
+            panel_bg: Color::Rgb(248, 248, 248),  // Main panels
+            glass_1: Color::Rgb(245, 245, 250),   // Panel layer 1 (slight blue tint)
+            glass_2: Color::Rgb(240, 240, 245),   // Panel layer 2
+            glass_3: Color::Rgb(235, 235, 240),   // Panel layer 3
+            glass_accent: Color::Rgb(230, 230, 235), // Elevated panels
+
+            // Surface layers - For table headers and elevated sections
+            surface0: Color::Rgb(235, 235, 235),  // Elevated surface
+            surface1: Color::Rgb(230, 230, 230),  // More elevated
+            surface2: Color::Rgb(225, 225, 225),  // Highest elevation
+            highlight: Color::Rgb(230, 220, 245), // Light purple tint
+
+            // Text colors - Dark text on white background
+            text: Color::Rgb(20, 20, 20),         // Almost black text
+            text_bright: Color::Rgb(0, 0, 0),     // Pure black
+            subtext1: Color::Rgb(60, 60, 60),     // Dark gray
+            subtext0: Color::Rgb(100, 100, 100),  // Medium gray
+            overlay2: Color::Rgb(140, 140, 140),  // Light gray (panel borders)
+            overlay1: Color::Rgb(180, 180, 180),  // Very light gray
+            dim: Color::Rgb(200, 200, 200),       // Dim separator
+
+            // Post-Quantum Signature Colors - Dark versions for white background
+            quantum_cyan: Color::Rgb(0, 150, 200),      // Dark cyan
+            quantum_cyan_dim: Color::Rgb(0, 120, 160),  // Darker cyan
+            quantum_magenta: Color::Rgb(180, 0, 200),   // Dark magenta
+            quantum_magenta_dim: Color::Rgb(140, 0, 160), // Darker magenta
+
+            // Primary Purple Theme - Dark purple for white background
+            bloomberg_orange: Color::Rgb(120, 60, 200), // Dark purple
+            orange_bright: Color::Rgb(140, 80, 220),    // Medium purple
+            orange_dim: Color::Rgb(100, 50, 180),       // Darker purple
+            orange_dark: Color::Rgb(80, 40, 160),       // Darkest purple
+
+            // Accent colors - Darker versions for white background visibility
+            cyan_neon: Color::Rgb(0, 140, 180),   // Dark cyan
+            cyan_bright: Color::Rgb(0, 160, 200), // Medium cyan
+            blue_neon: Color::Rgb(30, 100, 200),  // Dark blue
+            purple_neon: Color::Rgb(140, 60, 200), // Dark purple
+            pink_neon: Color::Rgb(200, 40, 140),   // Dark pink
+            green_neon: Color::Rgb(0, 160, 80),    // Dark green
+            red_neon: Color::Rgb(220, 50, 50),     // Dark red
+            yellow_neon: Color::Rgb(200, 140, 0),  // Dark yellow/gold
+            orange_neon: Color::Rgb(120, 60, 200), // Dark purple
+
+            // Softer accent colors - For secondary data
+            cyan: Color::Rgb(0, 120, 160),        // Medium cyan
+            blue: Color::Rgb(40, 100, 180),       // Medium blue
+            purple: Color::Rgb(120, 80, 200),     // Medium purple
+            green: Color::Rgb(40, 160, 80),       // Medium green
+            red: Color::Rgb(200, 70, 70),         // Medium red
+            yellow: Color::Rgb(180, 120, 0),      // Medium yellow
+
+            // Border colors - Dark borders for white background
+            border_bright: Color::Rgb(120, 60, 200),  // Dark purple border
+            border_dim: Color::Rgb(200, 200, 200),    // Light gray border
+            border_glow: Color::Rgb(140, 80, 220),    // Medium purple glow
+            border_magenta: Color::Rgb(180, 0, 200),  // Dark magenta border
+        }
     }
 
-    /// Color for info/neutral states - Tech blue
-    pub const fn info() -> Color {
-        Self::BLUE_NEON
+    /// Light text on a near-black background, same purple accent
+    pub fn dark() -> Self {
+        Self {
+            base: Color::Rgb(24, 24, 32),
+            mantle: Color::Rgb(30, 30, 40),
+            crust: Color::Rgb(36, 36, 46),
+            panel_bg: Color::Rgb(32, 32, 42),
+            glass_1: Color::Rgb(40, 40, 54),
+            glass_2: Color::Rgb(46, 46, 60),
+            glass_3: Color::Rgb(52, 52, 66),
+            glass_accent: Color::Rgb(58, 58, 72),
+            surface0: Color::Rgb(50, 50, 62),
+            surface1: Color::Rgb(58, 58, 70),
+            surface2: Color::Rgb(66, 66, 78),
+            highlight: Color::Rgb(54, 40, 84),
+
+            text: Color::Rgb(230, 230, 235),
+            text_bright: Color::Rgb(255, 255, 255),
+            subtext1: Color::Rgb(190, 190, 200),
+            subtext0: Color::Rgb(150, 150, 160),
+            overlay2: Color::Rgb(110, 110, 125),
+            overlay1: Color::Rgb(85, 85, 100),
+            dim: Color::Rgb(70, 70, 82),
+
+            quantum_cyan: Color::Rgb(80, 200, 240),
+            quantum_cyan_dim: Color::Rgb(50, 160, 200),
+            quantum_magenta: Color::Rgb(220, 100, 240),
+            quantum_magenta_dim: Color::Rgb(180, 70, 200),
+
+            bloomberg_orange: Color::Rgb(180, 140, 255),
+            orange_bright: Color::Rgb(200, 165, 255),
+            orange_dim: Color::Rgb(150, 115, 230),
+            orange_dark: Color::Rgb(125, 95, 205),
+
+            cyan_neon: Color::Rgb(80, 210, 240),
+            cyan_bright: Color::Rgb(120, 225, 250),
+            blue_neon: Color::Rgb(110, 160, 255),
+            purple_neon: Color::Rgb(190, 140, 255),
+            pink_neon: Color::Rgb(255, 120, 190),
+            green_neon: Color::Rgb(90, 220, 130),
+            red_neon: Color::Rgb(255, 100, 100),
+            yellow_neon: Color::Rgb(250, 200, 80),
+            orange_neon: Color::Rgb(180, 140, 255),
+
+            cyan: Color::Rgb(70, 180, 210),
+            blue: Color::Rgb(100, 145, 230),
+            purple: Color::Rgb(170, 135, 240),
+            green: Color::Rgb(100, 200, 130),
+            red: Color::Rgb(230, 110, 110),
+            yellow: Color::Rgb(225, 180, 80),
+
+            border_bright: Color::Rgb(180, 140, 255),
+            border_dim: Color::Rgb(70, 70, 82),
+            border_glow: Color::Rgb(200, 165, 255),
+            border_magenta: Color::Rgb(220, 100, 240),
+        }
     }
 
-    /// Color for quantum/crypto operations - Quantum purple
-    pub const fn quantum() -> Color {
-        Self::PURPLE_NEON
+    /// Pure black and white with saturated accents, for low vision and
+    /// washed-out screens
+    pub fn high_contrast() -> Self {
+        Self {
+            base: Color::Black,
+            mantle: Color::Black,
+            crust: Color::Black,
+            panel_bg: Color::Black,
+            glass_1: Color::Rgb(30, 30, 30),
+            glass_2: Color::Rgb(30, 30, 30),
+            glass_3: Color::Rgb(30, 30, 30),
+            glass_accent: Color::Rgb(30, 30, 30),
+            surface0: Color::Rgb(30, 30, 30),
+            surface1: Color::Rgb(30, 30, 30),
+            surface2: Color::Rgb(30, 30, 30),
+            highlight: Color::Rgb(0, 0, 160),
+
+            text: Color::White,
+            text_bright: Color::White,
+            subtext1: Color::White,
+            subtext0: Color::Rgb(220, 220, 220),
+            overlay2: Color::White,
+            overlay1: Color::Rgb(220, 220, 220),
+            dim: Color::Rgb(160, 160, 160),
+
+            quantum_cyan: Color::Rgb(0, 255, 255),
+            quantum_cyan_dim: Color::Rgb(0, 255, 255),
+            quantum_magenta: Color::Rgb(255, 0, 255),
+            quantum_magenta_dim: Color::Rgb(255, 0, 255),
+
+            bloomberg_orange: Color::Rgb(255, 255, 0),
+            orange_bright: Color::Rgb(255, 255, 0),
+            orange_dim: Color::Rgb(255, 255, 0),
+            orange_dark: Color::Rgb(255, 255, 0),
+
+            cyan_neon: Color::Rgb(0, 255, 255),
+            cyan_bright: Color::Rgb(0, 255, 255),
+            blue_neon: Color::Rgb(100, 180, 255),
+            purple_neon: Color::Rgb(255, 0, 255),
+            pink_neon: Color::Rgb(255, 0, 255),
+            green_neon: Color::Rgb(0, 255, 0),
+            red_neon: Color::Rgb(255, 60, 60),
+            yellow_neon: Color::Rgb(255, 255, 0),
+            orange_neon: Color::Rgb(255, 160, 0),
+
+            cyan: Color::Rgb(0, 255, 255),
+            blue: Color::Rgb(100, 180, 255),
+            purple: Color::Rgb(255, 0, 255),
+            green: Color::Rgb(0, 255, 0),
+            red: Color::Rgb(255, 60, 60),
+            yellow: Color::Rgb(255, 255, 0),
+
+            border_bright: Color::White,
+            border_dim: Color::White,
+            border_glow: Color::Rgb(255, 255, 0),
+            border_magenta: Color::Rgb(255, 0, 255),
+        }
     }
 
-    /// Color for locked state - Alert red
-    pub const fn locked() -> Color {
-        Self::RED_NEON
+    /// Green phosphor on black, like an old terminal
+    pub fn classic_green() -> Self {
+        let bright = Color::Rgb(80, 255, 120);
+        let normal = Color::Rgb(40, 210, 90);
+        let faint = Color::Rgb(20, 140, 60);
+        let dark = Color::Rgb(10, 70, 30);
+        Self {
+            base: Color::Rgb(5, 12, 5),
+            mantle: Color::Rgb(5, 12, 5),
+            crust: Color::Rgb(8, 18, 8),
+            panel_bg: Color::Rgb(8, 18, 8),
+            glass_1: Color::Rgb(10, 28, 12),
+            glass_2: Color::Rgb(12, 32, 14),
+            glass_3: Color::Rgb(14, 36, 16),
+            glass_accent: Color::Rgb(16, 40, 18),
+            surface0: Color::Rgb(12, 32, 14),
+            surface1: Color::Rgb(14, 36, 16),
+            surface2: Color::Rgb(16, 40, 18),
+            highlight: Color::Rgb(15, 55, 25),
+
+            text: normal,
+            text_bright: bright,
+            subtext1: normal,
+            subtext0: faint,
+            overlay2: faint,
+            overlay1: dark,
+            dim: dark,
+
+            quantum_cyan: bright,
+            quantum_cyan_dim: normal,
+            quantum_magenta: normal,
+            quantum_magenta_dim: faint,
+
+            bloomberg_orange: bright,
+            orange_bright: bright,
+            orange_dim: normal,
+            orange_dark: faint,
+
+            cyan_neon: bright,
+            cyan_bright: bright,
+            blue_neon: normal,
+            purple_neon: bright,
+            pink_neon: bright,
+            green_neon: bright,
+            // Errors and warnings still need to stand out
+            red_neon: Color::Rgb(255, 90, 70),
+            yellow_neon: Color::Rgb(210, 255, 90),
+            orange_neon: bright,
+
+            cyan: normal,
+            blue: normal,
+            purple: normal,
+            green: normal,
+            red: Color::Rgb(230, 90, 70),
+            yellow: Color::Rgb(190, 230, 80),
+
+            border_bright: bright,
+            border_dim: dark,
+            border_glow: bright,
+            border_magenta: normal,
+        }
     }
 
-    /// Color for unlocked state - Matrix green
-    pub const fn unlocked() -> Color {
-        Self::GREEN_NEON
+    /// The configured built-in theme (`None` for light), with the colors
+    /// from `theme.toml` applied if the file exists
+    pub fn load(configured: Option<&str>) -> Result<Self> {
+        let path = crate::paths::config_dir().join(THEME_FILE);
+        if !path.exists() {
+            return Ok(ThemeName::parse(configured.unwrap_or("light"))?.theme());
+        }
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::from_file(&contents, configured).with_context(|| format!("Invalid theme file {}", path.display()))
     }
 
-    /// Color for selected/focused input - Warning yellow
-    pub const fn selection() -> Color {
-        Self::YELLOW_NEON
+    fn from_file(contents: &str, configured: Option<&str>) -> Result<Self> {
+        let file: ThemeFile = toml::from_str(contents)?;
+        let mut theme = ThemeName::parse(file.extends.as_deref().or(configured).unwrap_or("light"))?.theme();
+        for (name, value) in &file.colors {
+            let Some(color) = theme.color_mut(name) else {
+                bail!("Unknown color '{}'", name);
+            };
+            *color = Color::from_str(value).map_err(|_| anyhow::anyhow!("'{}' is not a color (for {})", value, name))?;
+        }
+        Ok(theme)
     }
 
-    /// Color for progress/in-progress operations - Soft cyan
-    pub const fn progress() -> Color {
-        Self::CYAN
+    // Semantic color functions - Modern glassmorphic style
+
+    /// Color for success states (unlocked, completed) - Matrix green
+    pub fn success(&self) -> Color {
+        self.green_neon
     }
 
-    /// Dim border for subtle sections
-    pub const fn section_border() -> Color {
-        Self::BORDER_DIM
+    /// Color for error states - Alert red
+    pub fn error(&self) -> Color {
+        self.red_neon
     }
 
-    /// Primary glass panel background
-    pub const fn glass_panel() -> Color {
-        Self::GLASS_1
+    /// Color for warning states - Warning yellow
+    pub fn warning(&self) -> Color {
+        self.yellow_neon
     }
 
-    /// Secondary glass layer (more elevated)
-    pub const fn glass_elevated() -> Color {
-        Self::GLASS_2
+    /// Color for info/neutral states - Tech blue
+    pub fn info(&self) -> Color {
+        self.blue_neon
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_file_overrides_colors() {
+        let theme = Theme::from_file("extends = \"dark\"\n[colors]\nbase = \"#000000\"\ntext = \"white\"\n", None).unwrap();
+        assert_eq!(theme.base, Color::Rgb(0, 0, 0));
+        assert_eq!(theme.text, Color::White);
+        assert_eq!(theme.red_neon, Theme::dark().red_neon);
+
+        // Without `extends`, the configured theme is the starting point
+        let theme = Theme::from_file("[colors]\n", Some("classic-green")).unwrap();
+        assert_eq!(theme, Theme::classic_green());
 
-    /// Tertiary glass layer (highest elevation)
-    pub const fn glass_top() -> Color {
-        Self::GLASS_3
+        assert!(Theme::from_file("[colors]\nbackground = \"red\"\n", None).is_err());
+        assert!(Theme::from_file("[colors]\nbase = \"not-a-color\"\n", None).is_err());
+        assert!(Theme::from_file("extends = \"solarized\"\n", None).is_err());
     }
 }
@@ -0,0 +1,257 @@
+use anyhow::{anyhow, Context, Result};
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Component, Path};
+
+use crate::vault_manager::{VaultConfig, VaultProfile};
+
+/// Archive header: magic + format version, followed by salt, nonce and ciphertext
+const ARCHIVE_MAGIC: &[u8; 4] = b"QVLT";
+/// Same layout, holding every vault (`backup push`)
+const SET_MAGIC: &[u8; 4] = b"QVST";
+const ARCHIVE_VERSION: u8 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const HEADER_LEN: usize = ARCHIVE_MAGIC.len() + 1 + SALT_LEN + NONCE_LEN;
+
+/// Everything needed to recreate a vault on another machine
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VaultArchive {
+    pub profile: VaultProfile,
+    /// Solana keypair JSON file contents
+    pub solana_keypair: String,
+    /// PQ key files, hex-encoded (including their header)
+    pub sphincs_public_key: String,
+    pub sphincs_private_key: String,
+}
+
+impl VaultArchive {
+    /// Collect a vault's profile and key files
+    pub fn from_profile(profile: &VaultProfile) -> Result<Self> {
+        let read = |path: &str, what: &str| {
+            fs::read(path).with_context(|| format!("Failed to read {}: {}", what, path))
+        };
+
+        let solana_keypair = String::from_utf8(read(&profile.solana_keypair_path, "Solana keypair")?)
+            .context("Solana keypair file is not valid UTF-8")?;
+
+        Ok(Self {
+            profile: profile.clone(),
+            solana_keypair,
+            sphincs_public_key: hex::encode(read(&profile.sphincs_public_key_path, "SPHINCS+ public key")?),
+            sphincs_private_key: hex::encode(read(&profile.sphincs_private_key_path, "SPHINCS+ private key")?),
+        })
+    }
+
+    /// Encrypt the archive with a passphrase
+    pub fn seal(&self, passphrase: &str) -> Result<Vec<u8>> {
+        let plaintext = serde_json::to_vec(self).context("Failed to serialize vault archive")?;
+        seal_bytes(ARCHIVE_MAGIC, &plaintext, passphrase)
+    }
+
+    /// Decrypt an archive produced by `seal`
+    pub fn open(data: &[u8], passphrase: &str) -> Result<Self> {
+        let plaintext = open_bytes(ARCHIVE_MAGIC, data, passphrase, "vault archive")?;
+        serde_json::from_slice(&plaintext).context("Invalid vault archive contents")
+    }
+
+    /// Write the key files into `dir` and return the profile pointing at them.
+    /// `name` comes from the archive, so it is checked before `dir` (built
+    /// from it) is touched.
+    pub fn install(&self, name: &str, dir: &Path) -> Result<VaultProfile> {
+        check_vault_name(name)?;
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+
+        let solana_keypair_path = dir.join("solana-keypair.json");
+        let public_key_path = dir.join("sphincs_public.key");
+        let private_key_path = dir.join("sphincs_private.key");
+
+        write_private(&solana_keypair_path, self.solana_keypair.as_bytes())
+            .context("Failed to write Solana keypair")?;
+        fs::write(&public_key_path, hex::decode(&self.sphincs_public_key).context("Invalid public key in archive")?)
+            .context("Failed to write SPHINCS+ public key")?;
+        write_private(&private_key_path, &hex::decode(&self.sphincs_private_key).context("Invalid private key in archive")?)
+            .context("Failed to write SPHINCS+ private key")?;
+
+        let mut profile = self.profile.clone();
+        profile.name = name.to_string();
+        profile.solana_keypair_path = path_string(&solana_keypair_path)?;
+        profile.sphincs_public_key_path = path_string(&public_key_path)?;
+        profile.sphincs_private_key_path = path_string(&private_key_path)?;
+        profile.last_used = None;
+
+        Ok(profile)
+    }
+}
+
+/// Refuse a vault name that isn't a single plain path component, e.g.
+/// `../../.ssh` from a crafted archive
+fn check_vault_name(name: &str) -> Result<()> {
+    let mut components = Path::new(name).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(part)), None) if part == name && !name.contains(['/', '\\']) => Ok(()),
+        _ => Err(anyhow!("'{}' can't be used as a vault name", name)),
+    }
+}
+
+/// Create `path` readable only by its owner, failing if it already exists
+fn write_private(path: &Path, contents: &[u8]) -> Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path).with_context(|| format!("Failed to create {}", path.display()))?;
+    file.write_all(contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn path_string(path: &Path) -> Result<String> {
+    path.to_str()
+        .map(str::to_string)
+        .with_context(|| format!("{} is not a UTF-8 path", path.display()))
+}
+
+/// Every configured vault, for `backup push` / `backup pull`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VaultSet {
+    pub created_at: String,
+    pub active_vault: Option<String>,
+    pub vaults: Vec<VaultArchive>,
+}
+
+impl VaultSet {
+    /// Collect every vault in `config` except watch-only ones
+    pub fn collect(config: &VaultConfig) -> Result<Self> {
+        let mut vaults = config
+            .vaults
+            .values()
+            .filter(|profile| !profile.is_watch_only())
+            .map(|profile| VaultArchive::from_profile(profile).with_context(|| format!("Vault '{}'", profile.name)))
+            .collect::<Result<Vec<_>>>()?;
+        vaults.sort_by(|a, b| a.profile.name.cmp(&b.profile.name));
+        Ok(Self { created_at: chrono::Utc::now().to_rfc3339(), active_vault: config.active_vault.clone(), vaults })
+    }
+
+    pub fn seal(&self, passphrase: &str) -> Result<Vec<u8>> {
+        let plaintext = serde_json::to_vec(self).context("Failed to serialize vault backup")?;
+        seal_bytes(SET_MAGIC, &plaintext, passphrase)
+    }
+
+    pub fn open(data: &[u8], passphrase: &str) -> Result<Self> {
+        let plaintext = open_bytes(SET_MAGIC, data, passphrase, "vault backup")?;
+        serde_json::from_slice(&plaintext).context("Invalid vault backup contents")
+    }
+}
+
+/// `magic`, version, salt, nonce, then `plaintext` encrypted under `passphrase`
+fn seal_bytes(magic: &[u8; 4], plaintext: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce);
+
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, &salt)?);
+    let ciphertext = cipher
+        .encrypt(&Nonce::from(nonce), plaintext)
+        .map_err(|_| anyhow!("Encryption failed"))?;
+
+    let mut out = Vec::with_capacity(HEADER_LEN + ciphertext.len());
+    out.extend_from_slice(magic);
+    out.push(ARCHIVE_VERSION);
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// Decrypt data written by `seal_bytes` with the same `magic`; `what` names
+/// it in errors
+fn open_bytes(magic: &[u8; 4], data: &[u8], passphrase: &str, what: &str) -> Result<Vec<u8>> {
+    if data.len() < HEADER_LEN || &data[..4] != magic {
+        return Err(anyhow!("Not a {}", what));
+    }
+    if data[4] != ARCHIVE_VERSION {
+        return Err(anyhow!("Unsupported {} version {}", what, data[4]));
+    }
+
+    let salt = &data[5..5 + SALT_LEN];
+    let nonce: [u8; NONCE_LEN] = data[5 + SALT_LEN..HEADER_LEN].try_into()?;
+
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, salt)?);
+    cipher
+        .decrypt(&Nonce::from(nonce), &data[HEADER_LEN..])
+        .map_err(|_| anyhow!("Wrong passphrase or corrupted {}", what))
+}
+
+/// Stretch a passphrase into a 256-bit key with Argon2id
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<Key> {
+    let mut key = Key::default();
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow!("Key derivation failed: {}", e))?;
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> VaultArchive {
+        VaultArchive {
+            profile: VaultProfile::new(
+                "test".to_string(),
+                "/path/to/keypair.json".to_string(),
+                "/path/to/public.key".to_string(),
+                "/path/to/private.key".to_string(),
+                "ABC123XYZ".to_string(),
+            ),
+            solana_keypair: "[1,2,3]".to_string(),
+            sphincs_public_key: "0a0b".to_string(),
+            sphincs_private_key: "0c0d".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_seal_and_open_roundtrip() {
+        let sealed = sample().seal("correct horse").unwrap();
+        let opened = VaultArchive::open(&sealed, "correct horse").unwrap();
+
+        assert_eq!(opened.profile.name, "test");
+        assert_eq!(opened.sphincs_private_key, "0c0d");
+    }
+
+    #[test]
+    fn test_open_rejects_wrong_passphrase() {
+        let sealed = sample().seal("correct horse").unwrap();
+        assert!(VaultArchive::open(&sealed, "battery staple").is_err());
+    }
+
+    #[test]
+    fn test_vault_set_roundtrip() {
+        let set = VaultSet { created_at: "2026-01-01T00:00:00Z".to_string(), active_vault: Some("test".to_string()), vaults: vec![sample()] };
+        let sealed = set.seal("correct horse").unwrap();
+        let opened = VaultSet::open(&sealed, "correct horse").unwrap();
+        assert_eq!(opened.vaults[0].profile.name, "test");
+
+        // A single-vault archive isn't a backup, and vice versa
+        assert!(VaultArchive::open(&sealed, "correct horse").is_err());
+        assert!(VaultSet::open(&sample().seal("correct horse").unwrap(), "correct horse").is_err());
+    }
+
+    #[test]
+    fn test_install_rejects_path_names() {
+        for name in ["../../.ssh", "a/b", "..", ".", "", "/etc", "a\\b"] {
+            let dir = Path::new("/nonexistent").join(name);
+            assert!(sample().install(name, &dir).is_err(), "{:?} was accepted", name);
+        }
+        assert!(check_vault_name("savings-2").is_ok());
+    }
+}
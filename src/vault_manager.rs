@@ -1,9 +1,178 @@
 use anyhow::{Context, Result, anyhow};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
+use std::io::Write;
 use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::exit_code::{ExitContext, ExitKind};
+use crate::notify::TelegramConfig;
+use crate::remote_backup::BackupDestination;
+use crate::paths;
+use crate::policy::Policy;
+use crate::price::PriceFeed;
+use crate::solana::client::LockMode;
+
+mod migrations;
+mod solana_cli;
+
+pub use migrations::CURRENT_CONFIG_VERSION;
+pub use solana_cli::SolanaCliConfig;
+
+/// Largest compute unit limit a transaction may request
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Network lock snapshots are kept this long unless `snapshot_retention` says otherwise
+pub const DEFAULT_SNAPSHOT_RETENTION_DAYS: i64 = 30;
+
+/// Dashboard keys to remap, e.g. `"lock": "k"` or `"quit": "f10"`; unset
+/// actions keep their default key. Checked for conflicts when the dashboard starts.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Keybindings {
+    pub lock: Option<String>,
+    pub unlock: Option<String>,
+    pub transfer: Option<String>,
+    pub chart: Option<String>,
+    pub vault_switch: Option<String>,
+    pub quit: Option<String>,
+}
+
+/// A cluster to switch to from the dashboard (`n`). Unset addresses fall
+/// back to the built-in devnet ones.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkProfile {
+    pub rpc_url: String,
+    #[serde(default)]
+    pub program_id: Option<String>,
+    #[serde(default)]
+    pub mint: Option<String>,
+    #[serde(default)]
+    pub standard_mint: Option<String>,
+}
+
+impl NetworkProfile {
+    fn builtin(rpc_url: &str) -> Self {
+        Self { rpc_url: rpc_url.to_string(), ..Self::default() }
+    }
+
+    /// What's wrong with it, as `field: problem`
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if !self.rpc_url.starts_with("http://") && !self.rpc_url.starts_with("https://") {
+            problems.push(format!("rpc_url: '{}' is not an http(s) URL", self.rpc_url));
+        }
+        for (field, value) in [("program_id", &self.program_id), ("mint", &self.mint), ("standard_mint", &self.standard_mint)] {
+            if let Some(address) = value {
+                if solana_sdk::pubkey::Pubkey::from_str(address).is_err() {
+                    problems.push(format!("{}: '{}' is not a valid address", field, address));
+                }
+            }
+        }
+        problems
+    }
+}
+
+/// Networks every install has; a configured profile with the same name replaces one
+const BUILTIN_NETWORKS: [(&str, &str); 2] = [
+    ("devnet", crate::solana::client::DEFAULT_RPC_URL),
+    ("mainnet", "https://api.mainnet-beta.solana.com"),
+];
+
+/// Dashboard preferences, edited from its settings screen. Unset fields
+/// keep their defaults.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DashboardSettings {
+    /// How often balances and lock status refresh, e.g. "30s", or "off" (default 30s)
+    pub refresh_interval: Option<String>,
+    /// Token the transfer and receive forms start on (default standard)
+    pub default_token: Option<DefaultToken>,
+    /// Ask for a second Enter before sending outside the address book (default on)
+    pub confirm_unknown_recipients: Option<bool>,
+    /// Ask for a second press of the quit key (default off)
+    pub confirm_quit: Option<bool>,
+    /// How often to take a network lock snapshot while the dashboard is open,
+    /// e.g. "15m", or "off" (default off)
+    pub snapshot_interval: Option<String>,
+    /// Transfers above this many tokens must be confirmed by typing the
+    /// amount or the end of the recipient, e.g. "10000", or "off" (default 1000)
+    pub large_transfer_threshold: Option<String>,
+    /// Lock an unlocked vault after this long without input, e.g. "10m",
+    /// or "off" (default off)
+    pub idle_lock: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DefaultToken {
+    /// Standard QDUM
+    #[default]
+    Standard,
+    /// pqQDUM
+    Pq,
+}
+
+/// Dashboard refresh interval when `refresh_interval` is unset
+pub const DEFAULT_REFRESH_INTERVAL: &str = "30s";
+
+/// Typed-confirmation threshold when `large_transfer_threshold` is unset
+pub const DEFAULT_LARGE_TRANSFER_THRESHOLD: &str = "1000";
+
+impl DashboardSettings {
+    pub fn refresh_interval(&self) -> Option<std::time::Duration> {
+        let default = parse_interval(DEFAULT_REFRESH_INTERVAL).ok().flatten();
+        self.refresh_interval.as_deref().map_or(default, |value| parse_interval(value).unwrap_or(default))
+    }
+
+    pub fn snapshot_interval(&self) -> Option<std::time::Duration> {
+        self.snapshot_interval.as_deref().and_then(|value| parse_interval(value).ok().flatten())
+    }
+
+    pub fn idle_lock(&self) -> Option<std::time::Duration> {
+        self.idle_lock.as_deref().and_then(|value| parse_interval(value).ok().flatten())
+    }
+
+    pub fn default_token(&self) -> DefaultToken {
+        self.default_token.unwrap_or_default()
+    }
+
+    pub fn confirm_unknown_recipients(&self) -> bool {
+        self.confirm_unknown_recipients.unwrap_or(true)
+    }
+
+    pub fn confirm_quit(&self) -> bool {
+        self.confirm_quit.unwrap_or(false)
+    }
+
+    pub fn large_transfer_threshold(&self) -> Option<f64> {
+        let default = parse_threshold(DEFAULT_LARGE_TRANSFER_THRESHOLD).ok().flatten();
+        self.large_transfer_threshold.as_deref().map_or(default, |value| parse_threshold(value).unwrap_or(default))
+    }
+}
+
+/// `"off"` or a non-negative token amount
+fn parse_threshold(value: &str) -> Result<Option<f64>> {
+    if value.trim().eq_ignore_ascii_case("off") {
+        return Ok(None);
+    }
+    match value.trim().parse::<f64>() {
+        Ok(amount) if amount.is_finite() && amount >= 0.0 => Ok(Some(amount)),
+        _ => Err(anyhow!("'{}' is not a token amount or \"off\"", value)),
+    }
+}
+
+/// `"off"` or a duration like `30s`
+fn parse_interval(value: &str) -> Result<Option<std::time::Duration>> {
+    if value.trim().eq_ignore_ascii_case("off") {
+        return Ok(None);
+    }
+    let duration = crate::scheduler::parse_duration(value)?;
+    Ok(Some(duration.to_std()?))
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct VaultProfile {
@@ -30,6 +199,34 @@ pub struct VaultProfile {
 
     /// Last used timestamp
     pub last_used: Option<String>,
+
+    /// When the SPHINCS+ keys were last rotated
+    #[serde(default)]
+    pub keys_rotated_at: Option<String>,
+
+    /// Unlock requirements chosen at registration
+    #[serde(default)]
+    pub lock_mode: LockMode,
+
+    /// RPC endpoint this vault lives on (e.g. a mainnet vault next to devnet ones)
+    #[serde(default)]
+    pub rpc_url: Option<String>,
+
+    /// Vault program ID on that cluster
+    #[serde(default)]
+    pub program_id: Option<String>,
+
+    /// pqQDUM mint on that cluster
+    #[serde(default)]
+    pub mint: Option<String>,
+
+    /// Standard QDUM mint on that cluster (bridge)
+    #[serde(default)]
+    pub standard_mint: Option<String>,
+
+    /// Lock again after each pqQDUM transfer (`vault relock`)
+    #[serde(default)]
+    pub relock_after_transfer: bool,
 }
 
 #[derive(Serialize, Deserialize, Default, Debug)]
@@ -40,8 +237,68 @@ pub struct VaultConfig {
     /// All vault profiles
     pub vaults: HashMap<String, VaultProfile>,
 
-    /// Config version (for future migrations)
+    /// Config schema version (see `migrations`)
     pub version: u32,
+
+    /// Default compute unit limit for every transaction
+    #[serde(default)]
+    pub compute_unit_limit: Option<u32>,
+
+    /// Default compute unit price (micro-lamports) for every transaction
+    #[serde(default)]
+    pub compute_unit_price: Option<u64>,
+
+    /// URLs `monitor --daemon` POSTs vault events to
+    #[serde(default)]
+    pub webhooks: Vec<String>,
+
+    /// Desktop notification when unlock, transfer or bridge operations finish (default on)
+    #[serde(default)]
+    pub desktop_notifications: Option<bool>,
+
+    /// Telegram bot that gets unlock and watched-vault notifications
+    #[serde(default)]
+    pub telegram: Option<TelegramConfig>,
+
+    /// Discord webhook that gets unlock and watched-vault notifications
+    #[serde(default)]
+    pub discord_webhook: Option<String>,
+
+    /// Price API the lock chart overlays
+    #[serde(default)]
+    pub price_feed: Option<PriceFeed>,
+
+    /// How long network lock snapshots are kept, e.g. "90d" (default 30 days)
+    #[serde(default)]
+    pub snapshot_retention: Option<String>,
+
+    /// Built-in dashboard theme, e.g. "dark" (default light)
+    #[serde(default)]
+    pub theme: Option<String>,
+
+    /// Remapped dashboard keys
+    #[serde(default)]
+    pub keybindings: Keybindings,
+
+    /// Named recipients; the dashboard asks for confirmation before sending anywhere else
+    #[serde(default)]
+    pub address_book: BTreeMap<String, String>,
+
+    /// Dashboard preferences (refresh, default token, confirmations, snapshots)
+    #[serde(default)]
+    pub dashboard: DashboardSettings,
+
+    /// Named clusters the dashboard can switch between, besides devnet and mainnet
+    #[serde(default)]
+    pub networks: BTreeMap<String, NetworkProfile>,
+
+    /// Rules checked before anything is signed (limits, allowed recipients, read-only)
+    #[serde(default)]
+    pub policy: Policy,
+
+    /// Where `backup push` uploads the encrypted vault set
+    #[serde(default)]
+    pub backup: Option<BackupDestination>,
 }
 
 impl VaultConfig {
@@ -57,26 +314,209 @@ impl VaultConfig {
         let data = fs::read_to_string(&config_path)
             .context("Failed to read vault config")?;
 
-        let config: VaultConfig = serde_json::from_str(&data)
-            .context("Failed to parse vault config")?;
+        let (config, from_version) = Self::parse(&data)
+            .with_context(|| format!("Invalid vault config at {}", config_path.display()))
+            .exit_kind(ExitKind::Config)?;
+
+        if from_version < CURRENT_CONFIG_VERSION {
+            // Keep the pre-migration file so a downgrade is still possible
+            let backup_path = config_path.with_extension(format!("json.v{}.bak", from_version));
+            fs::copy(&config_path, &backup_path)
+                .context("Failed to back up vault config before migration")?;
+            config.save()?;
+        }
 
         Ok(config)
     }
 
+    /// Parse, migrate and validate a config document
+    /// Returns the config and the schema version it was stored with
+    fn parse(data: &str) -> Result<(Self, u32)> {
+        // serde_json errors carry the line and column of the problem
+        let mut value: serde_json::Value = serde_json::from_str(data)
+            .context("Failed to parse vault config")?;
+
+        let from_version = migrations::upgrade(&mut value)?;
+
+        let config: VaultConfig = serde_path_to_error::deserialize(value)
+            .map_err(|e| anyhow!("{}: {}", e.path(), e.inner()))?;
+
+        config.validate()?;
+
+        Ok((config, from_version))
+    }
+
+    /// Check invariants that serde can't express, reporting every problem with its location
+    pub fn validate(&self) -> Result<()> {
+        let mut errors = Vec::new();
+
+        if self.version != CURRENT_CONFIG_VERSION {
+            errors.push(format!("version: expected {}, got {}", CURRENT_CONFIG_VERSION, self.version));
+        }
+
+        if let Some(limit) = self.compute_unit_limit {
+            if limit == 0 || limit > MAX_COMPUTE_UNIT_LIMIT {
+                errors.push(format!("compute_unit_limit: {} is outside 1..={}", limit, MAX_COMPUTE_UNIT_LIMIT));
+            }
+        }
+
+        for url in &self.webhooks {
+            if let Err(e) = crate::monitor::check_webhook_url(url) {
+                errors.push(format!("webhooks: {}", e));
+            }
+        }
+
+        if let Some(telegram) = &self.telegram {
+            if telegram.chat_id.trim().is_empty() {
+                errors.push("telegram: chat_id must not be empty".to_string());
+            }
+        }
+
+        if let Some(url) = &self.discord_webhook {
+            if !url.starts_with("https://") {
+                errors.push(format!("discord_webhook: '{}' is not an https URL", url));
+            }
+        }
+
+        if let Some(feed) = &self.price_feed {
+            if let Err(e) = feed.check() {
+                errors.push(format!("price_feed: {}", e));
+            }
+        }
+
+        if let Some(retention) = &self.snapshot_retention {
+            if let Err(e) = crate::scheduler::parse_duration(retention) {
+                errors.push(format!("snapshot_retention: {}", e));
+            }
+        }
+
+        for (field, value) in [
+            ("refresh_interval", &self.dashboard.refresh_interval),
+            ("snapshot_interval", &self.dashboard.snapshot_interval),
+            ("idle_lock", &self.dashboard.idle_lock),
+        ] {
+            if let Some(Err(e)) = value.as_deref().map(parse_interval) {
+                errors.push(format!("dashboard.{}: {}", field, e));
+            }
+        }
+        if let Some(Err(e)) = self.dashboard.large_transfer_threshold.as_deref().map(parse_threshold) {
+            errors.push(format!("dashboard.large_transfer_threshold: {}", e));
+        }
+
+        for (name, address) in &self.address_book {
+            if solana_sdk::pubkey::Pubkey::from_str(address).is_err() {
+                errors.push(format!("address_book.{}: '{}' is not a valid address", name, address));
+            }
+        }
+
+        for (name, network) in &self.networks {
+            errors.extend(network.problems().into_iter().map(|problem| format!("networks.{}.{}", name, problem)));
+        }
+
+        errors.extend(self.policy.problems().into_iter().map(|problem| format!("policy.{}", problem)));
+
+        if let Some(active) = &self.active_vault {
+            if !self.vaults.contains_key(active) {
+                errors.push(format!("active_vault: '{}' is not a configured vault", active));
+            }
+        }
+
+        let mut names: Vec<&String> = self.vaults.keys().collect();
+        names.sort();
+
+        for key in names {
+            let vault = &self.vaults[key];
+            let at = |field: &str| format!("vaults.{}.{}", key, field);
+
+            if vault.name != *key {
+                errors.push(format!("{}: '{}' does not match its key '{}'", at("name"), vault.name, key));
+            }
+
+            for (field, value) in [
+                ("solana_keypair_path", &vault.solana_keypair_path),
+                ("sphincs_public_key_path", &vault.sphincs_public_key_path),
+                ("sphincs_private_key_path", &vault.sphincs_private_key_path),
+            ] {
+                if value.trim().is_empty() {
+                    errors.push(format!("{}: must not be empty", at(field)));
+                }
+            }
+
+            if !vault.wallet_address.is_empty()
+                && solana_sdk::pubkey::Pubkey::from_str(&vault.wallet_address).is_err()
+            {
+                errors.push(format!("{}: '{}' is not a valid address", at("wallet_address"), vault.wallet_address));
+            }
+
+            if chrono::DateTime::parse_from_rfc3339(&vault.created_at).is_err() {
+                errors.push(format!("{}: '{}' is not an RFC 3339 timestamp", at("created_at"), vault.created_at));
+            }
+
+            if let Some(url) = &vault.rpc_url {
+                if !url.starts_with("http://") && !url.starts_with("https://") {
+                    errors.push(format!("{}: '{}' is not an http(s) URL", at("rpc_url"), url));
+                }
+            }
+
+            for (field, value) in [
+                ("program_id", &vault.program_id),
+                ("mint", &vault.mint),
+                ("standard_mint", &vault.standard_mint),
+            ] {
+                if let Some(address) = value {
+                    if solana_sdk::pubkey::Pubkey::from_str(address).is_err() {
+                        errors.push(format!("{}: '{}' is not a valid address", at(field), address));
+                    }
+                }
+            }
+
+            for (field, value) in [("last_used", &vault.last_used), ("keys_rotated_at", &vault.keys_rotated_at)] {
+                if let Some(ts) = value {
+                    if chrono::DateTime::parse_from_rfc3339(ts).is_err() {
+                        errors.push(format!("{}: '{}' is not an RFC 3339 timestamp", at(field), ts));
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ExitKind::Config.error(format!("Vault config validation failed:\n  - {}", errors.join("\n  - "))))
+        }
+    }
+
     /// Save vault config to disk
     pub fn save(&self) -> Result<()> {
         let config_path = Self::get_config_path();
 
-        // Ensure .qdum directory exists
+        // Ensure the config directory exists
         if let Some(parent) = config_path.parent() {
             fs::create_dir_all(parent)
-                .context("Failed to create .qdum directory")?;
+                .context("Failed to create config directory")?;
         }
 
         let json = serde_json::to_string_pretty(self)
             .context("Failed to serialize vault config")?;
 
-        fs::write(&config_path, json)
+        // Owner-only: it can hold backup credentials and notification tokens
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(&config_path)
+            .context("Failed to write vault config")?;
+        // The mode only applies to new files; tighten one saved by an older release
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(fs::Permissions::from_mode(0o600))
+                .context("Failed to restrict vault config permissions")?;
+        }
+        file.write_all(json.as_bytes())
             .context("Failed to write vault config")?;
 
         Ok(())
@@ -84,14 +524,12 @@ impl VaultConfig {
 
     /// Get path to vault config file
     fn get_config_path() -> PathBuf {
-        let home = dirs::home_dir().expect("Could not determine home directory");
-        home.join(".qdum").join("vaults.json")
+        paths::config_dir().join("vaults.json")
     }
 
     /// Get path to old config file
     fn get_old_config_path() -> PathBuf {
-        let home = dirs::home_dir().expect("Could not determine home directory");
-        home.join(".qdum").join("config.json")
+        paths::config_dir().join("config.json")
     }
 
     /// Migrate from old config format
@@ -101,7 +539,7 @@ impl VaultConfig {
         if !old_config_path.exists() {
             // No old config, return empty config
             return Ok(VaultConfig {
-                version: 1,
+                version: CURRENT_CONFIG_VERSION,
                 ..Default::default()
             });
         }
@@ -120,14 +558,14 @@ impl VaultConfig {
 
         // Create default vault from old config
         let mut config = VaultConfig {
-            version: 1,
+            version: CURRENT_CONFIG_VERSION,
             active_vault: Some("default".to_string()),
             vaults: HashMap::new(),
+            ..Default::default()
         };
 
         if let Some(keypair_path) = old_config.keypair_path {
-            let home = dirs::home_dir().expect("Could not determine home directory");
-            let qdum_dir = home.join(".qdum");
+            let qdum_dir = paths::data_dir();
 
             let profile = VaultProfile {
                 name: "default".to_string(),
@@ -140,6 +578,13 @@ impl VaultConfig {
                 wallet_address: String::new(), // Will be populated on first use
                 created_at: Utc::now().to_rfc3339(),
                 last_used: Some(Utc::now().to_rfc3339()),
+                keys_rotated_at: None,
+                lock_mode: LockMode::default(),
+                rpc_url: None,
+                program_id: None,
+                mint: None,
+                standard_mint: None,
+                relock_after_transfer: false,
             };
 
             config.vaults.insert("default".to_string(), profile);
@@ -213,6 +658,18 @@ impl VaultConfig {
         self.vaults.get(name)
     }
 
+    /// Find the vault that uses the given Solana keypair
+    pub fn find_by_keypair(&self, keypair_path: &str) -> Option<&VaultProfile> {
+        self.vaults.values().find(|v| v.solana_keypair_path == keypair_path)
+    }
+
+    /// Lock mode for the vault using the given keypair (standard if unknown)
+    pub fn lock_mode_for_keypair(&self, keypair_path: &str) -> LockMode {
+        self.find_by_keypair(keypair_path)
+            .map(|v| v.lock_mode)
+            .unwrap_or_default()
+    }
+
     /// Delete a vault profile
     pub fn delete_vault(&mut self, name: &str) -> Result<()> {
         if !self.vaults.contains_key(name) {
@@ -292,6 +749,204 @@ impl VaultConfig {
         }
     }
 
+    /// Record that a vault's SPHINCS+ keys were rotated
+    pub fn record_key_rotation(&mut self, name: &str) -> Result<()> {
+        if let Some(vault) = self.vaults.get_mut(name) {
+            vault.keys_rotated_at = Some(Utc::now().to_rfc3339());
+            self.save()?;
+            Ok(())
+        } else {
+            Err(anyhow!("Vault '{}' does not exist", name))
+        }
+    }
+
+    /// Record the lock mode a vault was registered with
+    pub fn set_lock_mode(&mut self, name: &str, lock_mode: LockMode) -> Result<()> {
+        if let Some(vault) = self.vaults.get_mut(name) {
+            vault.lock_mode = lock_mode;
+            self.save()?;
+            Ok(())
+        } else {
+            Err(anyhow!("Vault '{}' does not exist", name))
+        }
+    }
+
+    /// Set the default compute budget; `None` leaves a value unchanged
+    pub fn set_compute_budget(&mut self, unit_limit: Option<u32>, unit_price: Option<u64>) -> Result<()> {
+        if unit_limit.is_some() {
+            self.compute_unit_limit = unit_limit;
+        }
+        if unit_price.is_some() {
+            self.compute_unit_price = unit_price;
+        }
+        self.save()
+    }
+
+    pub fn desktop_notifications_enabled(&self) -> bool {
+        self.desktop_notifications.unwrap_or(true)
+    }
+
+    /// Turn desktop notifications on or off
+    pub fn set_desktop_notifications(&mut self, enabled: bool) -> Result<()> {
+        self.desktop_notifications = Some(enabled);
+        self.save()
+    }
+
+    /// Set the Telegram bot and/or Discord webhook for chat notifications; `None` leaves one unchanged
+    pub fn set_chat_notifications(&mut self, telegram: Option<TelegramConfig>, discord_webhook: Option<String>) -> Result<()> {
+        if let Some(url) = &discord_webhook {
+            if !url.starts_with("https://") {
+                return Err(ExitKind::Usage.error(format!("Discord webhook '{}' is not an https URL", url)));
+            }
+        }
+        if telegram.is_some() {
+            self.telegram = telegram;
+        }
+        if discord_webhook.is_some() {
+            self.discord_webhook = discord_webhook;
+        }
+        self.save()
+    }
+
+    /// Stop all chat notifications
+    pub fn clear_chat_notifications(&mut self) -> Result<()> {
+        self.telegram = None;
+        self.discord_webhook = None;
+        self.save()
+    }
+
+    /// Set or (with `None`) remove the price API for the lock chart
+    pub fn set_price_feed(&mut self, feed: Option<PriceFeed>) -> Result<()> {
+        if let Some(feed) = &feed {
+            feed.check().exit_kind(ExitKind::Usage)?;
+        }
+        self.price_feed = feed;
+        self.save()
+    }
+
+    /// How long network lock snapshots are kept
+    pub fn snapshot_retention(&self) -> chrono::Duration {
+        self.snapshot_retention
+            .as_deref()
+            .and_then(|retention| crate::scheduler::parse_duration(retention).ok())
+            .unwrap_or_else(|| chrono::Duration::days(DEFAULT_SNAPSHOT_RETENTION_DAYS))
+    }
+
+    /// Save how long network lock snapshots are kept
+    pub fn set_snapshot_retention(&mut self, retention: &str) -> Result<()> {
+        crate::scheduler::parse_duration(retention).exit_kind(ExitKind::Usage)?;
+        self.snapshot_retention = Some(retention.to_string());
+        self.save()
+    }
+
+    /// Save the dashboard theme; the name is checked when the dashboard starts
+    pub fn set_theme(&mut self, theme: &str) -> Result<()> {
+        self.theme = Some(theme.to_string());
+        self.save()
+    }
+
+    /// Save how often the dashboard refreshes: a duration like `30s`, or `off`
+    pub fn set_dashboard_refresh(&mut self, interval: &str) -> Result<()> {
+        parse_interval(interval).exit_kind(ExitKind::Usage)?;
+        self.dashboard.refresh_interval = Some(interval.to_string());
+        self.save()
+    }
+
+    /// Save the dashboard's settings screen: its preferences and theme
+    pub fn set_dashboard_settings(&mut self, settings: DashboardSettings, theme: &str) -> Result<()> {
+        self.dashboard = settings;
+        self.theme = Some(theme.to_string());
+        self.save()
+    }
+
+    /// Save `address` under `name`; returns the address it replaced, if any
+    pub fn add_address(&mut self, name: &str, address: &solana_sdk::pubkey::Pubkey) -> Result<Option<String>> {
+        if name.trim().is_empty() {
+            return Err(ExitKind::Usage.error("Address book names must not be empty"));
+        }
+        let replaced = self.address_book.insert(name.to_string(), address.to_string());
+        self.save()?;
+        Ok(replaced)
+    }
+
+    /// Remove `name` from the address book; returns false if it wasn't there
+    pub fn remove_address(&mut self, name: &str) -> Result<bool> {
+        if self.address_book.remove(name).is_none() {
+            return Ok(false);
+        }
+        self.save()?;
+        Ok(true)
+    }
+
+    /// The built-in networks (unless replaced) followed by the configured ones
+    pub fn network_profiles(&self) -> Vec<(String, NetworkProfile)> {
+        let builtin = BUILTIN_NETWORKS
+            .iter()
+            .filter(|(name, _)| !self.networks.contains_key(*name))
+            .map(|(name, rpc_url)| (name.to_string(), NetworkProfile::builtin(rpc_url)));
+        let configured = self.networks.iter().map(|(name, network)| (name.clone(), network.clone()));
+        builtin.chain(configured).collect()
+    }
+
+    /// Save `network` under `name`; returns whether it replaced one
+    pub fn add_network(&mut self, name: &str, network: NetworkProfile) -> Result<bool> {
+        if name.trim().is_empty() {
+            return Err(ExitKind::Usage.error("Network names must not be empty"));
+        }
+        if let Some(problem) = network.problems().first() {
+            return Err(ExitKind::Usage.error(format!("Invalid network: {}", problem)));
+        }
+        let replaced = self.networks.insert(name.to_string(), network).is_some();
+        self.save()?;
+        Ok(replaced)
+    }
+
+    /// Remove a saved network; `false` if there was none by that name
+    pub fn remove_network(&mut self, name: &str) -> Result<bool> {
+        if self.networks.remove(name).is_none() {
+            return Ok(false);
+        }
+        self.save()?;
+        Ok(true)
+    }
+
+    /// What the user calls `address`: its address book name, or the vault it's the wallet of
+    pub fn address_label(&self, address: &solana_sdk::pubkey::Pubkey) -> Option<String> {
+        let address = address.to_string();
+        self.address_book
+            .iter()
+            .find(|(_, known)| **known == address)
+            .map(|(name, _)| name.clone())
+            .or_else(|| {
+                self.vaults
+                    .values()
+                    .find(|vault| vault.wallet_address == address)
+                    .map(|vault| format!("vault '{}'", vault.name))
+            })
+    }
+
+    /// Add a webhook URL for `monitor --daemon`; returns false if it was already there
+    pub fn add_webhook(&mut self, url: &str) -> Result<bool> {
+        crate::monitor::check_webhook_url(url)?;
+        if self.webhooks.iter().any(|existing| existing == url) {
+            return Ok(false);
+        }
+        self.webhooks.push(url.to_string());
+        self.save()?;
+        Ok(true)
+    }
+
+    /// Remove a webhook URL; returns false if it wasn't configured
+    pub fn remove_webhook(&mut self, url: &str) -> Result<bool> {
+        let before = self.webhooks.len();
+        self.webhooks.retain(|existing| existing != url);
+        if self.webhooks.len() == before {
+            return Ok(false);
+        }
+        self.save()?;
+        Ok(true)
+    }
+
     /// Update wallet address cache for a vault
     pub fn update_wallet_address(&mut self, name: &str, address: String) -> Result<()> {
         if let Some(vault) = self.vaults.get_mut(name) {
@@ -322,6 +977,13 @@ impl VaultProfile {
             wallet_address,
             created_at: Utc::now().to_rfc3339(),
             last_used: Some(Utc::now().to_rfc3339()),
+            keys_rotated_at: None,
+            lock_mode: LockMode::default(),
+            rpc_url: None,
+            program_id: None,
+            mint: None,
+            standard_mint: None,
+            relock_after_transfer: false,
         }
     }
 
@@ -334,6 +996,40 @@ impl VaultProfile {
         }
     }
 
+    /// RPC endpoint of this vault's cluster: the pinned one, else the Solana CLI's, else the default
+    pub fn network_rpc_url(&self) -> String {
+        self.rpc_url
+            .clone()
+            .or_else(|| SolanaCliConfig::load().json_rpc_url)
+            .unwrap_or_else(|| crate::solana::client::DEFAULT_RPC_URL.to_string())
+    }
+
+    /// Vault program on this vault's cluster
+    pub fn network_program_id(&self) -> Result<solana_sdk::pubkey::Pubkey> {
+        match &self.program_id {
+            Some(program_id) => solana_sdk::pubkey::Pubkey::from_str(program_id)
+                .with_context(|| format!("Invalid program ID '{}' for vault '{}'", program_id, self.name)),
+            None => Ok(crate::solana::client::DEFAULT_PROGRAM_ID),
+        }
+    }
+
+    /// Wallet address, read from the keypair if it isn't cached yet
+    pub fn wallet(&self) -> Result<solana_sdk::pubkey::Pubkey> {
+        if !self.wallet_address.is_empty() {
+            return solana_sdk::pubkey::Pubkey::from_str(&self.wallet_address)
+                .with_context(|| format!("Invalid wallet address for vault '{}'", self.name));
+        }
+        let keypair = solana_sdk::signature::read_keypair_file(&self.solana_keypair_path)
+            .map_err(|e| anyhow!("Failed to read keypair {}: {}", self.solana_keypair_path, e))
+            .exit_kind(ExitKind::Config)?;
+        Ok(solana_sdk::signer::Signer::pubkey(&keypair))
+    }
+
+    /// Whether this vault only watches an address: it has no keypair to sign with
+    pub fn is_watch_only(&self) -> bool {
+        self.solana_keypair_path.is_empty() || !std::path::Path::new(&self.solana_keypair_path).is_file()
+    }
+
     /// Get short wallet address (first 4 and last 4 characters)
     pub fn short_wallet(&self) -> String {
         if self.wallet_address.len() >= 8 {
@@ -352,6 +1048,7 @@ mod tests {
 
     #[test]
     fn test_create_vault() {
+        paths::use_test_root();
         let mut config = VaultConfig {
             version: 1,
             ..Default::default()
@@ -368,14 +1065,23 @@ mod tests {
         assert!(config.create_vault("test".to_string(), profile).is_ok());
         assert!(config.vaults.contains_key("test"));
         assert_eq!(config.active_vault, Some("test".to_string()));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(VaultConfig::get_config_path()).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
     }
 
     #[test]
     fn test_switch_vault() {
+        paths::use_test_root();
         let mut config = VaultConfig {
             version: 1,
             active_vault: Some("vault1".to_string()),
             vaults: HashMap::new(),
+            ..Default::default()
         };
 
         let profile1 = VaultProfile::new(
@@ -403,10 +1109,12 @@ mod tests {
 
     #[test]
     fn test_delete_vault() {
+        paths::use_test_root();
         let mut config = VaultConfig {
             version: 1,
             active_vault: Some("vault1".to_string()),
             vaults: HashMap::new(),
+            ..Default::default()
         };
 
         let profile1 = VaultProfile::new(
@@ -434,6 +1142,59 @@ mod tests {
         assert_eq!(config.active_vault, Some("vault2".to_string()));
     }
 
+    #[test]
+    fn test_migrate_v1_config() {
+        let v1 = r#"{
+            "active_vault": "gone",
+            "vaults": {
+                "main": {
+                    "name": "main",
+                    "solana_keypair_path": "/k.json",
+                    "sphincs_public_key_path": "/pub.key",
+                    "sphincs_private_key_path": "/priv.key",
+                    "wallet_address": "",
+                    "created_at": "2024-01-01T00:00:00Z"
+                }
+            },
+            "version": 1
+        }"#;
+
+        let (config, from_version) = VaultConfig::parse(v1).unwrap();
+        assert_eq!(from_version, 1);
+        assert_eq!(config.version, CURRENT_CONFIG_VERSION);
+        assert_eq!(config.active_vault, None);
+        assert!(config.vaults["main"].keys_rotated_at.is_none());
+    }
+
+    #[test]
+    fn test_validation_reports_location() {
+        let bad = r#"{
+            "active_vault": null,
+            "vaults": {
+                "main": {
+                    "name": "other",
+                    "description": null,
+                    "solana_keypair_path": "",
+                    "sphincs_public_key_path": "/pub.key",
+                    "sphincs_private_key_path": "/priv.key",
+                    "wallet_address": "",
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "last_used": null,
+                    "program_id": "not-an-address"
+                }
+            },
+            "version": 2
+        }"#;
+
+        let err = format!("{:#}", VaultConfig::parse(bad).unwrap_err());
+        assert!(err.contains("vaults.main.name"));
+        assert!(err.contains("vaults.main.solana_keypair_path"));
+        assert!(err.contains("vaults.main.program_id"));
+
+        let future = r#"{"active_vault": null, "vaults": {}, "version": 99}"#;
+        assert!(VaultConfig::parse(future).is_err());
+    }
+
     #[test]
     fn test_short_wallet() {
         let profile = VaultProfile::new(
@@ -446,4 +1207,46 @@ mod tests {
 
         assert_eq!(profile.short_wallet(), "7vZ8...Xq2M");
     }
+
+    #[test]
+    fn test_dashboard_settings_intervals() {
+        let settings = DashboardSettings::default();
+        assert_eq!(settings.refresh_interval(), Some(std::time::Duration::from_secs(30)));
+        assert_eq!(settings.snapshot_interval(), None);
+
+        let settings = DashboardSettings {
+            refresh_interval: Some("off".to_string()),
+            snapshot_interval: Some("15m".to_string()),
+            ..DashboardSettings::default()
+        };
+        assert_eq!(settings.refresh_interval(), None);
+        assert_eq!(settings.snapshot_interval(), Some(std::time::Duration::from_secs(900)));
+        assert_eq!(settings.large_transfer_threshold(), Some(1000.0));
+        let off = DashboardSettings { large_transfer_threshold: Some("off".to_string()), ..DashboardSettings::default() };
+        assert_eq!(off.large_transfer_threshold(), None);
+        assert!(parse_threshold("-5").is_err());
+
+        let config = VaultConfig {
+            version: CURRENT_CONFIG_VERSION,
+            dashboard: DashboardSettings { refresh_interval: Some("soon".to_string()), ..DashboardSettings::default() },
+            ..Default::default()
+        };
+        assert!(format!("{:#}", config.validate().unwrap_err()).contains("dashboard.refresh_interval"));
+    }
+
+    #[test]
+    fn test_network_profiles() {
+        let mut config = VaultConfig { version: CURRENT_CONFIG_VERSION, ..Default::default() };
+        let names = |config: &VaultConfig| config.network_profiles().into_iter().map(|(name, _)| name).collect::<Vec<_>>();
+        assert_eq!(names(&config), ["devnet", "mainnet"]);
+
+        let local = NetworkProfile { rpc_url: "http://localhost:8899".to_string(), ..NetworkProfile::default() };
+        config.networks.insert("mainnet".to_string(), local.clone());
+        config.networks.insert("localnet".to_string(), local.clone());
+        assert_eq!(names(&config), ["devnet", "localnet", "mainnet"]);
+        assert!(config.network_profiles().iter().all(|(name, network)| name == "devnet" || *network == local));
+
+        config.networks.insert("broken".to_string(), NetworkProfile { rpc_url: "localhost".to_string(), ..local });
+        assert!(format!("{:#}", config.validate().unwrap_err()).contains("networks.broken.rpc_url"));
+    }
 }
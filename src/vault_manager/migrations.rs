@@ -0,0 +1,86 @@
+use anyhow::{anyhow, Result};
+use serde_json::{Map, Value};
+
+/// Current vault config schema version
+pub const CURRENT_CONFIG_VERSION: u32 = 2;
+
+/// A forward migration from `from` to `from + 1`
+struct Migration {
+    from: u32,
+    description: &'static str,
+    apply: fn(&mut Map<String, Value>) -> Result<()>,
+}
+
+/// Ordered list of migrations; each step upgrades exactly one version
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from: 1,
+        description: "make optional profile fields explicit and drop dangling active vault",
+        apply: migrate_v1_to_v2,
+    },
+];
+
+/// Upgrade a raw config document in place to `CURRENT_CONFIG_VERSION`
+/// Returns the version the document was at before migrating
+pub fn upgrade(value: &mut Value) -> Result<u32> {
+    let root = value.as_object_mut()
+        .ok_or_else(|| anyhow!("(root): expected a JSON object"))?;
+
+    // Configs written before versioning was enforced have no version field
+    let original = match root.get("version") {
+        None | Some(Value::Null) => 1,
+        Some(v) => v.as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| anyhow!("version: expected an unsigned integer, got {}", v))?,
+    };
+
+    if original > CURRENT_CONFIG_VERSION {
+        return Err(anyhow!(
+            "version: config version {} is newer than this build supports (max {}) - upgrade qdum-vault",
+            original,
+            CURRENT_CONFIG_VERSION
+        ));
+    }
+
+    let mut version = original.max(1);
+    while version < CURRENT_CONFIG_VERSION {
+        let migration = MIGRATIONS.iter()
+            .find(|m| m.from == version)
+            .ok_or_else(|| anyhow!("No migration registered from config version {}", version))?;
+
+        (migration.apply)(root)
+            .map_err(|e| anyhow!("Migration v{} -> v{} ({}) failed: {}", version, version + 1, migration.description, e))?;
+
+        version += 1;
+        root.insert("version".to_string(), Value::from(version));
+    }
+
+    Ok(original)
+}
+
+fn migrate_v1_to_v2(root: &mut Map<String, Value>) -> Result<()> {
+    let vaults = root.entry("vaults")
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .ok_or_else(|| anyhow!("vaults: expected an object"))?;
+
+    for (name, profile) in vaults.iter_mut() {
+        let profile = profile.as_object_mut()
+            .ok_or_else(|| anyhow!("vaults.{}: expected an object", name))?;
+
+        for field in ["description", "last_used", "keys_rotated_at"] {
+            profile.entry(field).or_insert(Value::Null);
+        }
+        profile.entry("wallet_address").or_insert_with(|| Value::String(String::new()));
+    }
+
+    let dangling = match root.get("active_vault") {
+        Some(Value::String(active)) => !root["vaults"].as_object().is_some_and(|v| v.contains_key(active)),
+        _ => false,
+    };
+    if dangling {
+        root.insert("active_vault".to_string(), Value::Null);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+//! Defaults from the standard Solana CLI config
+//!
+//! `solana config set --url ... --keypair ...` writes
+//! `~/.config/solana/cli/config.yml`. Reading it lets existing Solana users run
+//! commands before creating a vault profile, with the same wallet and cluster
+//! their other tooling uses.
+
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The subset of the Solana CLI config the vault falls back to
+#[derive(Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct SolanaCliConfig {
+    #[serde(default)]
+    pub json_rpc_url: Option<String>,
+    #[serde(default)]
+    pub keypair_path: Option<String>,
+}
+
+impl SolanaCliConfig {
+    /// Default location, as used by the `solana` CLI
+    pub fn path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".config/solana/cli/config.yml"))
+    }
+
+    /// Load the Solana CLI config. A missing or unreadable file yields no
+    /// defaults rather than an error, since the file is optional.
+    pub fn load() -> Self {
+        Self::path().and_then(|path| Self::load_from(&path)).unwrap_or_default()
+    }
+
+    fn load_from(path: &Path) -> Option<Self> {
+        let yaml = fs::read_to_string(path).ok()?;
+        Self::parse(&yaml)
+    }
+
+    fn parse(yaml: &str) -> Option<Self> {
+        let mut config: Self = serde_yaml::from_str(yaml).ok()?;
+        config.json_rpc_url = config.json_rpc_url.filter(|url| !url.trim().is_empty());
+        config.keypair_path = config
+            .keypair_path
+            .filter(|path| !path.trim().is_empty())
+            .map(|path| expand_home(&path));
+        Some(config)
+    }
+}
+
+/// `~/foo` -> `$HOME/foo`
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest).to_string_lossy().into_owned(),
+        _ => path.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_solana_cli_config() {
+        let yaml = "---\n\
+            json_rpc_url: \"https://api.mainnet-beta.solana.com\"\n\
+            websocket_url: \"\"\n\
+            keypair_path: /home/alice/.config/solana/id.json\n\
+            address_labels:\n  \"11111111111111111111111111111111\": System Program\n\
+            commitment: confirmed\n";
+        let config = SolanaCliConfig::parse(yaml).unwrap();
+        assert_eq!(config.json_rpc_url.as_deref(), Some("https://api.mainnet-beta.solana.com"));
+        assert_eq!(config.keypair_path.as_deref(), Some("/home/alice/.config/solana/id.json"));
+    }
+
+    #[test]
+    fn test_empty_fields_are_ignored() {
+        let config = SolanaCliConfig::parse("json_rpc_url: \"\"\n").unwrap();
+        assert_eq!(config, SolanaCliConfig::default());
+    }
+}